@@ -0,0 +1,8 @@
+(function () {
+  let testArr = [];
+  for (let a = 0; a < 20000; a++) {
+    testArr[a] = a;
+  }
+
+  return testArr;
+})();
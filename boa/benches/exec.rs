@@ -194,6 +194,18 @@ fn array_creation(c: &mut Criterion) {
     });
 }
 
+static ARRAY_FILL: &str = include_str!("bench_scripts/array_fill.js");
+
+fn array_fill(c: &mut Criterion) {
+    let mut engine = Context::new();
+
+    let nodes = Parser::new(ARRAY_FILL.as_bytes()).parse_all().unwrap();
+
+    c.bench_function("Array fill (Execution)", move |b| {
+        b.iter(|| black_box(&nodes).run(&mut engine).unwrap())
+    });
+}
+
 static ARRAY_POP: &str = include_str!("bench_scripts/array_pop.js");
 
 fn array_pop(c: &mut Criterion) {
@@ -326,6 +338,7 @@ criterion_group!(
     fibonacci,
     array_access,
     array_creation,
+    array_fill,
     array_pop,
     object_creation,
     object_prop_access_const,
@@ -116,6 +116,14 @@ fn array_creation(c: &mut Criterion) {
     });
 }
 
+static ARRAY_FILL: &str = include_str!("bench_scripts/array_fill.js");
+
+fn array_fill(c: &mut Criterion) {
+    c.bench_function("Array fill (Full)", move |b| {
+        b.iter(|| Context::new().eval(black_box(ARRAY_FILL)))
+    });
+}
+
 static ARRAY_POP: &str = include_str!("bench_scripts/array_pop.js");
 
 fn array_pop(c: &mut Criterion) {
@@ -203,6 +211,7 @@ criterion_group!(
     fibonacci,
     array_access,
     array_creation,
+    array_fill,
     array_pop,
     object_creation,
     object_prop_access_const,
@@ -2,6 +2,7 @@ use boa::{
     class::{Class, ClassBuilder},
     gc::{Finalize, Trace},
     property::Attribute,
+    value::TryFromJsArgs,
     Context, Result, Value,
 };
 
@@ -58,20 +59,13 @@ impl Class for Person {
 
     // This is what is called when we do `new Person()`
     fn constructor(_this: &Value, args: &[Value], ctx: &mut Context) -> Result<Self> {
-        // we get the first arguemnt of undefined if the first one is unavalable and call `to_string`.
+        // we unpack the arguments into a string and an integer.
         //
-        // This is equivalent to `String(arg)`.
-        let name = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
-        // we get the second arguemnt of undefined if the first one is unavalable and call `to_u32`.
-        //
-        // This is equivalent to `arg | 0`.
-        let age = args.get(1).cloned().unwrap_or_default().to_u32(ctx)?;
+        // If the arguments do not have these types, a `TypeError` is thrown.
+        let (name, age): (String, u32) = TryFromJsArgs::try_from_js_args(args, ctx)?;
 
         // we construct the the native struct `Person`
-        let person = Person {
-            name: name.to_string(),
-            age,
-        };
+        let person = Person { name, age };
 
         Ok(person) // and we return it.
     }
@@ -82,8 +76,8 @@ impl Class for Person {
         //
         // This function is added to `Person.prototype.sayHello()`
         class.method("sayHello", 0, Self::say_hello);
-        // we add a static mathod `is`, and here we use a closure, but it must be converible
-        // to a NativeFunction. it must not contain state, if it does it will give a compilation error.
+        // we add a static mathod `is`, and here we use a closure. It can capture Rust state, but
+        // not garbage collected values like `Value`.
         //
         // This function is added to `Person.is()`
         class.static_method("is", 1, |_this, args, _ctx| {
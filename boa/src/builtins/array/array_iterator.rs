@@ -77,7 +77,7 @@ impl ArrayIterator {
                 }
                 let len = array_iterator
                     .array
-                    .get_field("length")
+                    .get_field("length", ctx)?
                     .as_number()
                     .ok_or_else(|| ctx.construct_type_error("Not an array"))?
                     as u32;
@@ -91,16 +91,13 @@ impl ArrayIterator {
                         Ok(create_iter_result_object(ctx, index.into(), false))
                     }
                     ArrayIterationKind::Value => {
-                        let element_value = array_iterator.array.get_field(index);
+                        let element_value = array_iterator.array.get_field(index, ctx)?;
                         Ok(create_iter_result_object(ctx, element_value, false))
                     }
                     ArrayIterationKind::KeyAndValue => {
-                        let element_value = array_iterator.array.get_field(index);
-                        let result = Array::constructor(
-                            &Value::new_object(Some(ctx.global_object())),
-                            &[index.into(), element_value],
-                            ctx,
-                        )?;
+                        let element_value = array_iterator.array.get_field(index, ctx)?;
+                        let result =
+                            Array::create_array_from_list(vec![index.into(), element_value], ctx);
                         Ok(create_iter_result_object(ctx, result, false))
                     }
                 }
@@ -15,13 +15,16 @@ mod tests;
 
 use crate::{
     builtins::array::array_iterator::{ArrayIterationKind, ArrayIterator},
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
-    property::{Attribute, DataDescriptor},
-    value::{same_value_zero, Value},
+    builtins::{iterable::IteratorRecord, BuiltIn, Number},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    value::{same_value_zero, RcString, Value},
     BoaProfiler, Context, Result,
 };
-use std::cmp::{max, min};
+use std::{
+    cmp::{max, min, Ordering},
+    collections::BTreeMap,
+};
 
 /// JavaScript `Array` built-in implementation.
 #[derive(Debug, Clone, Copy)]
@@ -86,10 +89,31 @@ impl BuiltIn for Array {
         .method(Self::reduce_right, "reduceRight", 2)
         .method(Self::keys, "keys", 0)
         .method(Self::entries, "entries", 0)
+        .method(Self::sort, "sort", 1)
+        .method(Self::splice, "splice", 2)
+        .method(Self::flat, "flat", 0)
+        .method(Self::flat_map, "flatMap", 1)
+        .method(Self::copy_within, "copyWithin", 2)
+        .method(Self::find_last, "findLast", 1)
+        .method(Self::find_last_index, "findLastIndex", 1)
+        .method(Self::at, "at", 1)
         // Static Methods
         .static_method(Self::is_array, "isArray", 1)
+        .static_method(Self::from, "from", 1)
+        .static_method(Self::of, "of", 0)
         .build();
 
+        let species = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .callable(true)
+            .constructable(false)
+            .build();
+        array.borrow_mut().insert(
+            species,
+            AccessorDescriptor::new(Some(get_species), None, Attribute::CONFIGURABLE),
+        );
+
         (Self::NAME, array.into(), Self::attribute())
     }
 }
@@ -98,35 +122,37 @@ impl Array {
     const LENGTH: usize = 1;
 
     fn constructor(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // Set Prototype
-        let prototype = context.standard_objects().array_object().prototype();
-
-        this.as_object_mut()
-            .expect("this should be an array object")
-            .set_prototype_instance(prototype.into());
+        // When constructed, the prototype of `this` was taken from the `prototype` of
+        // `new.target`, which is a subclass of `Array` in `class List extends Array {}`.
+        // `Array` called as a function, like `Array(3)`, gets the global object as `this`, and
+        // creates a new array instead.
+        let new_array;
+        let this = if this.is_global() || !this.is_object() {
+            new_array = Self::new_array(context)?;
+            &new_array
+        } else {
+            this
+        };
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
         this.set_data(ObjectData::Array);
 
-        // add our arguments in
-        let mut length = args.len() as i32;
-        match args.len() {
-            1 if args[0].is_integer() => {
-                length = args[0].as_number().unwrap() as i32;
-                // TODO: It should not create an array of undefineds, but an empty array ("holy" array in V8) with length `n`.
-                for n in 0..length {
-                    this.set_field(n, Value::undefined());
+        // A single numeric argument is the length of an array full of holes.
+        let length = match args {
+            [length] if length.is_number() => {
+                let number = length.as_number().expect("length is a number");
+                if f64::from(number as u32) != number {
+                    return context.throw_range_error("invalid array length");
                 }
-            }
-            1 if args[0].is_double() => {
-                return context.throw_range_error("invalid array length");
+                number as u32
             }
             _ => {
                 for (n, value) in args.iter().enumerate() {
-                    this.set_field(n, value.clone());
+                    this.set_property(n, DataDescriptor::new(value.clone(), Attribute::all()));
                 }
+                args.len() as u32
             }
-        }
+        };
 
         // finally create length property
         let length = DataDescriptor::new(
@@ -141,6 +167,19 @@ impl Array {
 
     /// Creates a new `Array` instance.
     pub(crate) fn new_array(context: &Context) -> Result<Value> {
+        Ok(Self::create_array_from_list(Vec::new(), context))
+    }
+
+    /// Creates a new array object holding the given elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createarrayfromlist
+    pub(crate) fn create_array_from_list<I>(elements: I, context: &Context) -> Value
+    where
+        I: IntoIterator<Item = Value>,
+    {
         let array = Value::new_object(Some(
             &context
                 .realm()
@@ -153,20 +192,33 @@ impl Array {
             .as_object_mut()
             .expect("array object")
             .set_prototype_instance(context.standard_objects().array_object().prototype().into());
-        array.set_field("length", Value::from(0));
-        Ok(array)
+        let mut length = 0;
+        for (index, element) in elements.into_iter().enumerate() {
+            array.set_property(index, DataDescriptor::new(element, Attribute::all()));
+            length = index + 1;
+        }
+        let length = DataDescriptor::new(
+            length,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        array.set_property("length", length);
+        array
     }
 
     /// Utility function for creating array objects.
     ///
     /// `array_obj` can be any array with prototype already set (it will be wiped and
     /// recreated from `array_contents`)
-    pub(crate) fn construct_array(array_obj: &Value, array_contents: &[Value]) -> Result<Value> {
+    pub(crate) fn construct_array(
+        array_obj: &Value,
+        array_contents: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
         let array_obj_ptr = array_obj.clone();
 
         // Wipe existing contents of the array object
-        let orig_length = array_obj.get_field("length").as_number().unwrap() as i32;
-        for n in 0..orig_length {
+        let orig_length = array_obj.get_field("length", context)?.as_number().unwrap() as i32;
+        for n in (0..orig_length).rev() {
             array_obj_ptr.remove_property(n);
         }
 
@@ -178,25 +230,30 @@ impl Array {
         array_obj_ptr.set_property("length".to_string(), length);
 
         for (n, value) in array_contents.iter().enumerate() {
-            array_obj_ptr.set_field(n, value);
+            array_obj_ptr.set_field(n, value, context)?;
         }
         Ok(array_obj_ptr)
     }
 
     /// Utility function which takes an existing array object and puts additional
     /// values on the end, correctly rewriting the length
-    pub(crate) fn add_to_array_object(array_ptr: &Value, add_values: &[Value]) -> Result<Value> {
-        let orig_length = array_ptr.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn add_to_array_object(
+        array_ptr: &Value,
+        add_values: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let orig_length = array_ptr.get_field("length", context)?.as_number().unwrap() as i32;
 
         for (n, value) in add_values.iter().enumerate() {
             let new_index = orig_length.wrapping_add(n as i32);
-            array_ptr.set_field(new_index, value);
+            array_ptr.set_field(new_index, value, context)?;
         }
 
         array_ptr.set_field(
             "length",
             Value::from(orig_length.wrapping_add(add_values.len() as i32)),
-        );
+            context,
+        )?;
 
         Ok(array_ptr.clone())
     }
@@ -215,12 +272,10 @@ impl Array {
     pub(crate) fn is_array(
         _this: &Value,
         args: &[Value],
-        _interpreter: &mut Context,
+        interpreter: &mut Context,
     ) -> Result<Value> {
-        match args.get(0).and_then(|x| x.as_object()) {
-            Some(object) => Ok(Value::from(object.is_array())),
-            None => Ok(Value::from(false)),
-        }
+        let value = args.get(0).cloned().unwrap_or_default();
+        Ok(Self::is_array_value(&value, interpreter)?.into())
     }
 
     /// `Array.prototype.concat(...arguments)`
@@ -235,29 +290,57 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.concat
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/concat
-    pub(crate) fn concat(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            // If concat is called with no arguments, it returns the original array
-            return Ok(this.clone());
-        }
-
-        // Make a new array (using this object as the prototype basis for the new
-        // one)
-        let mut new_values: Vec<Value> = Vec::new();
-
-        let this_length = this.get_field("length").as_number().unwrap() as i32;
-        for n in 0..this_length {
-            new_values.push(this.get_field(n));
-        }
-
-        for concat_array in args {
-            let concat_length = concat_array.get_field("length").as_number().unwrap() as i32;
-            for n in 0..concat_length {
-                new_values.push(concat_array.get_field(n));
+    pub(crate) fn concat(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let array = Self::array_species_create(&object, 0, ctx)?;
+        let mut next = 0;
+        for item in std::iter::once(&Value::from(object)).chain(args) {
+            match item {
+                Value::Object(ref spread) if Self::is_concat_spreadable(item, ctx)? => {
+                    let len = spread.length_of_array_like(ctx)?;
+                    if (next + len) as f64 > Number::MAX_SAFE_INTEGER {
+                        return ctx.throw_type_error("concatenated array is too large");
+                    }
+                    for index in 0..len {
+                        let key = PropertyKey::from(index);
+                        if spread.has_property(&key, ctx)? {
+                            let value = spread.get(&key, item, ctx)?;
+                            array.create_data_property_or_throw(next + index, value, ctx)?;
+                        }
+                    }
+                    next += len;
+                }
+                _ => {
+                    if next as f64 >= Number::MAX_SAFE_INTEGER {
+                        return ctx.throw_type_error("concatenated array is too large");
+                    }
+                    array.create_data_property_or_throw(next, item.clone(), ctx)?;
+                    next += 1;
+                }
             }
         }
+        array.set_or_throw("length", next, ctx)?;
+        Ok(array.into())
+    }
 
-        Self::construct_array(this, &new_values)
+    /// `IsConcatSpreadable( O )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isconcatspreadable
+    fn is_concat_spreadable(value: &Value, ctx: &mut Context) -> Result<bool> {
+        let object = match value {
+            Value::Object(ref object) => object,
+            _ => return Ok(false),
+        };
+        let symbol = ctx.well_known_symbols().is_concat_spreadable_symbol();
+        let spreadable = object.get(&symbol.into(), value, ctx)?;
+        if spreadable.is_undefined() {
+            Self::is_array_value(value, ctx)
+        } else {
+            Ok(spreadable.to_boolean())
+        }
     }
 
     /// `Array.prototype.push( ...items )`
@@ -272,9 +355,18 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.push
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push
-    pub(crate) fn push(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let new_array = Self::add_to_array_object(this, args)?;
-        Ok(new_array.get_field("length"))
+    pub(crate) fn push(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        if (len + args.len()) as f64 > Number::MAX_SAFE_INTEGER {
+            return ctx.throw_type_error("Array.prototype.push: array length is too large");
+        }
+        for (index, item) in args.iter().enumerate() {
+            object.set_or_throw(len + index, item.clone(), ctx)?;
+        }
+        let len = len + args.len();
+        object.set_or_throw("length", len, ctx)?;
+        Ok(len.into())
     }
 
     /// `Array.prototype.pop()`
@@ -287,17 +379,18 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.pop
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/pop
-    pub(crate) fn pop(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let curr_length = this.get_field("length").as_number().unwrap() as i32;
-
-        if curr_length < 1 {
+    pub(crate) fn pop(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        if len == 0 {
+            object.set_or_throw("length", 0, ctx)?;
             return Ok(Value::undefined());
         }
-        let pop_index = curr_length.wrapping_sub(1);
-        let pop_value: Value = this.get_field(pop_index.to_string());
-        this.remove_property(pop_index);
-        this.set_field("length", Value::from(pop_index));
-        Ok(pop_value)
+        let index = len - 1;
+        let element = object.get(&index.into(), &object.clone().into(), ctx)?;
+        object.delete_property_or_throw(index, ctx)?;
+        object.set_or_throw("length", index, ctx)?;
+        Ok(element)
     }
 
     /// `Array.prototype.forEach( callbackFn [ , thisArg ] )`
@@ -318,10 +411,10 @@ impl Array {
         let callback_arg = args.get(0).expect("Could not get `callbackFn` argument.");
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         for i in 0..length {
-            let element = this.get_field(i);
+            let element = this.get_field(i, ctx)?;
             let arguments = [element, Value::from(i), this.clone()];
 
             ctx.call(callback_arg, &this_arg, &arguments)?;
@@ -344,22 +437,26 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/join
     pub(crate) fn join(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let separator = if args.is_empty() {
-            String::from(",")
+            RcString::from(",")
         } else {
             args.get(0)
                 .expect("Could not get argument")
                 .to_string(ctx)?
-                .to_string()
         };
 
-        let mut elem_strs = Vec::new();
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let mut result = Vec::new();
+        let length = this.get_field("length", ctx)?.as_number().unwrap() as i32;
         for n in 0..length {
-            let elem_str = this.get_field(n).to_string(ctx)?.to_string();
-            elem_strs.push(elem_str);
+            if n > 0 {
+                result.extend(separator.code_units());
+            }
+            let element = this.get_field(n, ctx)?;
+            if !element.is_null_or_undefined() {
+                result.extend(element.to_string(ctx)?.code_units());
+            }
         }
 
-        Ok(Value::from(elem_strs.join(&separator)))
+        Ok(Value::from(RcString::from(result)))
     }
 
     /// `Array.prototype.toString( separator )`
@@ -379,14 +476,11 @@ impl Array {
         let method_name = "join";
         let mut arguments = vec![Value::from(",")];
         // 2.
-        let mut method = this.get_field(method_name);
+        let mut method = this.get_field(method_name, ctx)?;
         // 3.
         if !method.is_function() {
-            method = ctx
-                .global_object()
-                .get_field("Object")
-                .get_field(PROTOTYPE)
-                .get_field("toString");
+            let object_prototype: Value = ctx.standard_objects().object_object().prototype().into();
+            method = object_prototype.get_field("toString", ctx)?;
 
             arguments = Vec::new();
         }
@@ -394,7 +488,7 @@ impl Array {
         let join = ctx.call(&method, this, &arguments)?;
 
         let string = if let Value::String(ref s) = join {
-            Value::from(s.as_str())
+            Value::from(s.clone())
         } else {
             Value::from("")
         };
@@ -414,8 +508,8 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.reverse
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reverse
     #[allow(clippy::else_if_without_else)]
-    pub(crate) fn reverse(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn reverse(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let len = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         let middle: i32 = len.wrapping_div(2);
 
@@ -425,17 +519,17 @@ impl Array {
             let upper_exists = this.has_field(upper);
             let lower_exists = this.has_field(lower);
 
-            let upper_value = this.get_field(upper);
-            let lower_value = this.get_field(lower);
+            let upper_value = this.get_field(upper, ctx)?;
+            let lower_value = this.get_field(lower, ctx)?;
 
             if upper_exists && lower_exists {
-                this.set_field(upper, lower_value);
-                this.set_field(lower, upper_value);
+                this.set_field(upper, lower_value, ctx)?;
+                this.set_field(lower, upper_value, ctx)?;
             } else if upper_exists {
-                this.set_field(lower, upper_value);
+                this.set_field(lower, upper_value, ctx)?;
                 this.remove_property(upper);
             } else if lower_exists {
-                this.set_field(upper, lower_value);
+                this.set_field(upper, lower_value, ctx)?;
                 this.remove_property(lower);
             }
         }
@@ -453,32 +547,27 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.shift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/shift
-    pub(crate) fn shift(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
-
+    pub(crate) fn shift(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
         if len == 0 {
-            this.set_field("length", 0);
+            object.set_or_throw("length", 0, ctx)?;
             return Ok(Value::undefined());
         }
 
-        let first: Value = this.get_field(0);
-
-        for k in 1..len {
-            let from = k;
-            let to = k.wrapping_sub(1);
-
-            let from_value = this.get_field(from);
-            if from_value.is_undefined() {
-                this.remove_property(to);
+        let receiver = Value::from(object.clone());
+        let first = object.get(&0.into(), &receiver, ctx)?;
+        for to in 0..len - 1 {
+            let from = PropertyKey::from(to + 1);
+            if object.has_property(&from, ctx)? {
+                let value = object.get(&from, &receiver, ctx)?;
+                object.set_or_throw(to, value, ctx)?;
             } else {
-                this.set_field(to, from_value);
+                object.delete_property_or_throw(to, ctx)?;
             }
         }
-
-        let final_index = len.wrapping_sub(1);
-        this.remove_property(final_index);
-        this.set_field("length", Value::from(final_index));
-
+        object.delete_property_or_throw(len - 1, ctx)?;
+        object.set_or_throw("length", len - 1, ctx)?;
         Ok(first)
     }
 
@@ -494,36 +583,32 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.unshift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/unshift
-    pub(crate) fn unshift(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
-
-        let arg_c: i32 = args.len() as i32;
-
-        if arg_c > 0 {
-            for k in (1..=len).rev() {
-                let from = k.wrapping_sub(1);
-                let to = k.wrapping_add(arg_c).wrapping_sub(1);
-
-                let from_value = this.get_field(from);
-                if from_value.is_undefined() {
-                    this.remove_property(to);
+    pub(crate) fn unshift(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        if !args.is_empty() {
+            if (len + args.len()) as f64 > Number::MAX_SAFE_INTEGER {
+                return ctx.throw_type_error("Array.prototype.unshift: array length is too large");
+            }
+            let receiver = Value::from(object.clone());
+            for index in (0..len).rev() {
+                let from = PropertyKey::from(index);
+                let to = index + args.len();
+                if object.has_property(&from, ctx)? {
+                    let value = object.get(&from, &receiver, ctx)?;
+                    object.set_or_throw(to, value, ctx)?;
                 } else {
-                    this.set_field(to, from_value);
+                    object.delete_property_or_throw(to, ctx)?;
                 }
             }
-            for j in 0..arg_c {
-                this.set_field(
-                    j,
-                    args.get(j as usize)
-                        .expect("Could not get argument")
-                        .clone(),
-                );
+            for (index, item) in args.iter().enumerate() {
+                object.set_or_throw(index, item.clone(), ctx)?;
             }
         }
 
-        let temp = len.wrapping_add(arg_c);
-        this.set_field("length", Value::from(temp));
-        Ok(Value::from(temp))
+        let len = len + args.len();
+        object.set_or_throw("length", len, ctx)?;
+        Ok(len.into())
     }
 
     /// `Array.prototype.every( callback, [ thisArg ] )`
@@ -552,10 +637,10 @@ impl Array {
             Value::undefined()
         };
         let mut i = 0;
-        let max_len = this.get_field("length").as_number().unwrap() as i32;
+        let max_len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
         let mut len = max_len;
         while i < len {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = interpreter.call(callback, &this_arg, &arguments)?;
             if !result.to_boolean() {
@@ -563,7 +648,7 @@ impl Array {
             }
             len = min(
                 max_len,
-                this.get_field("length").as_number().unwrap() as i32,
+                this.get_field("length", interpreter)?.as_number().unwrap() as i32,
             );
             i += 1;
         }
@@ -581,36 +666,23 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.map
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/map
-    pub(crate) fn map(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            return Err(Value::from(
-                "missing argument 0 when calling function Array.prototype.map",
-            ));
-        }
-
-        let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
-        let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
-
-        let length = this.get_field("length").to_length(context)?;
-
-        if length > 2usize.pow(32) - 1 {
-            return context.throw_range_error("Invalid array length");
+    pub(crate) fn map(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        let callback = Self::callback(args, ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let array = Self::array_species_create(&object, len, ctx)?;
+        let receiver = Value::from(object.clone());
+        for index in 0..len {
+            let key = PropertyKey::from(index);
+            if object.has_property(&key, ctx)? {
+                let element = object.get(&key, &receiver, ctx)?;
+                let arguments = [element, index.into(), receiver.clone()];
+                let mapped = ctx.call(&callback, &this_arg, &arguments)?;
+                array.create_data_property_or_throw(index, mapped, ctx)?;
+            }
         }
-
-        let new = Self::new_array(context)?;
-
-        let values: Vec<Value> = (0..length)
-            .map(|idx| {
-                let element = this.get_field(idx);
-                let args = [element, Value::from(idx), new.clone()];
-
-                context
-                    .call(&callback, &this_val, &args)
-                    .unwrap_or_else(|_| Value::undefined())
-            })
-            .collect();
-
-        Self::construct_array(&new, &values)
+        Ok(array.into())
     }
 
     /// `Array.prototype.indexOf( searchElement[, fromIndex ] )`
@@ -632,39 +704,9 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.indexof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/indexOf
-    pub(crate) fn index_of(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        // If no arguments, return -1. Not described in spec, but is what chrome does.
-        if args.is_empty() {
-            return Ok(Value::from(-1));
-        }
-
-        let search_element = args[0].clone();
-        let len = this.get_field("length").as_number().unwrap() as i32;
-
-        let mut idx = match args.get(1) {
-            Some(from_idx_ptr) => {
-                let from_idx = from_idx_ptr.as_number().unwrap() as i32;
-
-                if from_idx < 0 {
-                    len + from_idx
-                } else {
-                    from_idx
-                }
-            }
-            None => 0,
-        };
-
-        while idx < len {
-            let check_element = this.get_field(idx).clone();
-
-            if check_element.strict_equals(&search_element) {
-                return Ok(Value::from(idx));
-            }
-
-            idx += 1;
-        }
-
-        Ok(Value::from(-1))
+    pub(crate) fn index_of(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let index = Self::search(this, args, false, false, ctx)?;
+        Ok(index.map_or(Value::from(-1), Value::from))
     }
 
     /// `Array.prototype.lastIndexOf( searchElement[, fromIndex ] )`
@@ -685,42 +727,9 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.lastindexof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/lastIndexOf
-    pub(crate) fn last_index_of(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        // If no arguments, return -1. Not described in spec, but is what chrome does.
-        if args.is_empty() {
-            return Ok(Value::from(-1));
-        }
-
-        let search_element = args[0].clone();
-        let len = this
-            .get_field("length")
-            .as_number()
-            .expect("length was not a number") as i32;
-
-        let mut idx = match args.get(1) {
-            Some(from_idx_ptr) => {
-                let from_idx = from_idx_ptr.as_number().unwrap() as i32;
-
-                if from_idx >= 0 {
-                    min(from_idx, len - 1)
-                } else {
-                    len + from_idx
-                }
-            }
-            None => len - 1,
-        };
-
-        while idx >= 0 {
-            let check_element = this.get_field(idx).clone();
-
-            if check_element.strict_equals(&search_element) {
-                return Ok(Value::from(idx));
-            }
-
-            idx -= 1;
-        }
-
-        Ok(Value::from(-1))
+    pub(crate) fn last_index_of(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let index = Self::search(this, args, true, false, ctx)?;
+        Ok(index.map_or(Value::from(-1), Value::from))
     }
 
     /// `Array.prototype.find( callback, [thisArg] )`
@@ -743,9 +752,9 @@ impl Array {
         }
         let callback = &args[0];
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let len = this.get_field("length").as_number().unwrap() as i32;
+        let len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
         for i in 0..len {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element.clone(), Value::from(i), this.clone()];
             let result = interpreter.call(callback, &this_arg, &arguments)?;
             if result.to_boolean() {
@@ -782,10 +791,10 @@ impl Array {
 
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
 
         for i in 0..length {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element, Value::from(i), this.clone()];
 
             let result = interpreter.call(predicate_arg, &this_arg, &arguments)?;
@@ -810,7 +819,7 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.fill
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fill
     pub(crate) fn fill(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let len: i32 = this.get_field("length").as_number().unwrap() as i32;
+        let len: i32 = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         let default_value = Value::undefined();
         let value = args.get(0).unwrap_or(&default_value);
@@ -833,7 +842,7 @@ impl Array {
         };
 
         for i in start..fin {
-            this.set_field(i, value.clone());
+            this.set_field(i, value.clone(), ctx)?;
         }
 
         Ok(this.clone())
@@ -849,20 +858,144 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.includes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/includes
-    pub(crate) fn includes_value(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let search_element = args.get(0).cloned().unwrap_or_else(Value::undefined);
+    pub(crate) fn includes_value(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let index = Self::search(this, args, false, true, ctx)?;
+        Ok(index.is_some().into())
+    }
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+    /// Finds the index of `args[0]` in the array, searching from `args[1]` forwards, or
+    /// backwards if `reverse` is set. `includes` compares with `SameValueZero` and visits holes
+    /// as `undefined`, the others use strict equality and skip holes.
+    ///
+    /// The start index is clamped to the array before iterating, so an infinite `fromIndex`
+    /// doesn't walk the whole `u32` range.
+    fn search(
+        this: &Value,
+        args: &[Value],
+        reverse: bool,
+        same_value: bool,
+        ctx: &mut Context,
+    ) -> Result<Option<usize>> {
+        let object = this.to_object(ctx)?;
+        let receiver = Value::from(object.clone());
+        let len = object
+            .get(&"length".into(), &receiver, ctx)?
+            .to_length(ctx)?;
+        if len == 0 {
+            return Ok(None);
+        }
+        let target = args.get(0).cloned().unwrap_or_default();
+        let from = match args.get(1) {
+            Some(from) => from.to_integer(ctx)?,
+            None if reverse => len as f64 - 1.0,
+            None => 0.0,
+        };
+        let start = if reverse {
+            let start = if from >= 0.0 {
+                from.min(len as f64 - 1.0)
+            } else {
+                len as f64 + from
+            };
+            if start < 0.0 {
+                return Ok(None);
+            }
+            start as usize
+        } else {
+            let start = if from >= 0.0 {
+                from
+            } else {
+                (len as f64 + from).max(0.0)
+            };
+            if start >= len as f64 {
+                return Ok(None);
+            }
+            start as usize
+        };
+        let found = |element: &Value| {
+            if same_value {
+                same_value_zero(element, &target)
+            } else {
+                element.strict_equals(&target)
+            }
+        };
 
-        for idx in 0..length {
-            let check_element = this.get_field(idx).clone();
+        // Sparse arrays are never made dense again, so rather than visiting every index up to
+        // their length only the present elements are searched when that is not observable.
+        if let Some(elements) = Self::data_elements(&object, len) {
+            if reverse {
+                let index = elements
+                    .range(..=start)
+                    .rev()
+                    .find(|(_, element)| found(element))
+                    .map(|(index, _)| *index);
+                return Ok(index);
+            }
+            // Holes read as `undefined` when they are visited.
+            let mut next = start;
+            for (index, element) in elements.range(start..) {
+                if same_value && *index != next && target.is_undefined() {
+                    return Ok(Some(next));
+                }
+                if found(element) {
+                    return Ok(Some(*index));
+                }
+                next = index + 1;
+            }
+            if same_value && next < len && target.is_undefined() {
+                return Ok(Some(next));
+            }
+            return Ok(None);
+        }
 
-            if same_value_zero(&check_element, &search_element) {
-                return Ok(Value::from(true));
+        let indices: Box<dyn Iterator<Item = usize>> = if reverse {
+            Box::new((0..=start).rev())
+        } else {
+            Box::new(start..len)
+        };
+        for index in indices {
+            let key = PropertyKey::from(index);
+            if !same_value && !object.has_property(&key, ctx)? {
+                continue;
+            }
+            let element = object.get(&key, &receiver, ctx)?;
+            if found(&element) {
+                return Ok(Some(index));
             }
         }
+        Ok(None)
+    }
 
-        Ok(Value::from(false))
+    /// Collects the elements below `len` of `object`, including the ones inherited from its
+    /// prototypes, if they are all data properties of ordinary objects.
+    ///
+    /// Reading such elements can't run any code, so they can be searched without visiting every
+    /// index. Returns `None` when an element might be an accessor or an exotic property.
+    fn data_elements(object: &GcObject, len: usize) -> Option<BTreeMap<usize, Value>> {
+        if len > u32::MAX as usize {
+            return None;
+        }
+        let mut elements = BTreeMap::new();
+        let mut current = Some(object.clone());
+        while let Some(object) = current {
+            let object = object.borrow();
+            if object.is_proxy() || object.is_string() || object.is_typed_array() {
+                return None;
+            }
+            for (index, property) in object.index_properties() {
+                let index = index as usize;
+                if index >= len {
+                    continue;
+                }
+                match property {
+                    PropertyDescriptor::Data(ref data) => {
+                        elements.entry(index).or_insert_with(|| data.value());
+                    }
+                    PropertyDescriptor::Accessor(_) => return None,
+                }
+            }
+            current = object.prototype_instance().as_gc_object();
+        }
+        Some(elements)
     }
 
     /// `Array.prototype.slice( [begin[, end]] )`
@@ -879,38 +1012,23 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.slice
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/slice
-    pub(crate) fn slice(this: &Value, args: &[Value], interpreter: &mut Context) -> Result<Value> {
-        let new_array = Self::new_array(interpreter)?;
-        let len = this.get_field("length").as_number().unwrap() as i32;
-
-        let start = match args.get(0) {
-            Some(v) => v.as_number().unwrap() as i32,
-            None => 0,
-        };
-        let end = match args.get(1) {
-            Some(v) => v.as_number().unwrap() as i32,
-            None => len,
-        };
-
-        let from = if start < 0 {
-            max(len.wrapping_add(start), 0)
-        } else {
-            min(start, len)
-        };
-        let to = if end < 0 {
-            max(len.wrapping_add(end), 0)
-        } else {
-            min(end, len)
-        };
-
-        let span = max(to.wrapping_sub(from), 0);
-        let mut new_array_len: i32 = 0;
-        for i in from..from.wrapping_add(span) {
-            new_array.set_field(new_array_len, this.get_field(i));
-            new_array_len = new_array_len.wrapping_add(1);
+    pub(crate) fn slice(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        let start = Self::relative_index(args.first(), 0, len, ctx)?;
+        let end = Self::relative_index(args.get(1), len, len, ctx)?;
+        let count = end.saturating_sub(start);
+        let array = Self::array_species_create(&object, count, ctx)?;
+        let receiver = Value::from(object.clone());
+        for (to, from) in (start..end).enumerate() {
+            let key = PropertyKey::from(from);
+            if object.has_property(&key, ctx)? {
+                let value = object.get(&key, &receiver, ctx)?;
+                array.create_data_property_or_throw(to, value, ctx)?;
+            }
         }
-        new_array.set_field("length", Value::from(new_array_len));
-        Ok(new_array)
+        array.set_or_throw("length", count, ctx)?;
+        Ok(array.into())
     }
 
     /// `Array.prototype.filter( callback, [ thisArg ] )`
@@ -924,39 +1042,26 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.filter
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/filter
-    pub(crate) fn filter(this: &Value, args: &[Value], interpreter: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            return Err(Value::from(
-                "missing argument 0 when calling function Array.prototype.filter",
-            ));
-        }
-
-        let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
-        let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
-
-        let length = this.get_field("length").as_number().unwrap() as i32;
-
-        let new = Self::new_array(interpreter)?;
-
-        let values = (0..length)
-            .filter_map(|idx| {
-                let element = this.get_field(idx);
-
-                let args = [element.clone(), Value::from(idx), new.clone()];
-
-                let callback_result = interpreter
-                    .call(&callback, &this_val, &args)
-                    .unwrap_or_else(|_| Value::undefined());
-
-                if callback_result.to_boolean() {
-                    Some(element)
-                } else {
-                    None
+    pub(crate) fn filter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        let callback = Self::callback(args, ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let array = Self::array_species_create(&object, 0, ctx)?;
+        let receiver = Value::from(object.clone());
+        let mut to = 0;
+        for index in 0..len {
+            let key = PropertyKey::from(index);
+            if object.has_property(&key, ctx)? {
+                let element = object.get(&key, &receiver, ctx)?;
+                let arguments = [element.clone(), index.into(), receiver.clone()];
+                if ctx.call(&callback, &this_arg, &arguments)?.to_boolean() {
+                    array.create_data_property_or_throw(to, element, ctx)?;
+                    to += 1;
                 }
-            })
-            .collect::<Vec<Value>>();
-
-        Self::construct_array(&new, &values)
+            }
+        }
+        Ok(array.into())
     }
 
     /// Array.prototype.some ( callbackfn [ , thisArg ] )
@@ -987,10 +1092,10 @@ impl Array {
             Value::undefined()
         };
         let mut i = 0;
-        let max_len = this.get_field("length").as_number().unwrap() as i32;
+        let max_len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
         let mut len = max_len;
         while i < len {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = interpreter.call(callback, &this_arg, &arguments)?;
             if result.to_boolean() {
@@ -999,7 +1104,7 @@ impl Array {
             // the length of the array must be updated because the callback can mutate it.
             len = min(
                 max_len,
-                this.get_field("length").as_number().unwrap() as i32,
+                this.get_field("length", interpreter)?.as_number().unwrap() as i32,
             );
             i += 1;
         }
@@ -1024,7 +1129,9 @@ impl Array {
             _ => return interpreter.throw_type_error("Reduce was called without a callback"),
         };
         let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length").to_length(interpreter)?;
+        let mut length = this
+            .get_field("length", interpreter)?
+            .to_length(interpreter)?;
         if length == 0 && initial_value.is_undefined() {
             return interpreter
                 .throw_type_error("Reduce was called on an empty array and with no initial value");
@@ -1044,7 +1151,7 @@ impl Array {
                     "Reduce was called on an empty array and with no initial value",
                 );
             }
-            let result = this.get_field(k);
+            let result = this.get_field(k, interpreter)?;
             k += 1;
             result
         } else {
@@ -1052,12 +1159,21 @@ impl Array {
         };
         while k < length {
             if this.has_field(k) {
-                let arguments = [accumulator, this.get_field(k), Value::from(k), this.clone()];
+                let arguments = [
+                    accumulator,
+                    this.get_field(k, interpreter)?,
+                    Value::from(k),
+                    this.clone(),
+                ];
                 accumulator = interpreter.call(&callback, &Value::undefined(), &arguments)?;
                 /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
                 It may also be necessary to do this since shortening the array length does not
                 delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(length, this.get_field("length").to_length(interpreter)?);
+                length = min(
+                    length,
+                    this.get_field("length", interpreter)?
+                        .to_length(interpreter)?,
+                );
             }
             k += 1;
         }
@@ -1086,7 +1202,9 @@ impl Array {
             _ => return interpreter.throw_type_error("reduceRight was called without a callback"),
         };
         let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length").to_length(interpreter)?;
+        let mut length = this
+            .get_field("length", interpreter)?
+            .to_length(interpreter)?;
         if length == 0 {
             return if initial_value.is_undefined() {
                 interpreter.throw_type_error(
@@ -1116,7 +1234,7 @@ impl Array {
                     "reduceRight was called on an empty array and with no initial value",
                 );
             }
-            let result = this.get_field(k);
+            let result = this.get_field(k, interpreter)?;
             k = k.overflowing_sub(1).0;
             result
         } else {
@@ -1125,12 +1243,21 @@ impl Array {
         // usize::MAX is bigger than the maximum array size so we can use it check for integer undeflow
         while k != usize::MAX {
             if this.has_field(k) {
-                let arguments = [accumulator, this.get_field(k), Value::from(k), this.clone()];
+                let arguments = [
+                    accumulator,
+                    this.get_field(k, interpreter)?,
+                    Value::from(k),
+                    this.clone(),
+                ];
                 accumulator = interpreter.call(&callback, &Value::undefined(), &arguments)?;
                 /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
                 It may also be necessary to do this since shortening the array length does not
                 delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(length, this.get_field("length").to_length(interpreter)?);
+                length = min(
+                    length,
+                    this.get_field("length", interpreter)?
+                        .to_length(interpreter)?,
+                );
 
                 // move k to the last defined element if necessary or return if the length was set to 0
                 if k >= length {
@@ -1191,4 +1318,643 @@ impl Array {
     pub(crate) fn entries(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
         ArrayIterator::create_array_iterator(ctx, this.clone(), ArrayIterationKind::KeyAndValue)
     }
+
+    /// `IsArray( argument )`
+    ///
+    /// A proxy is an array if its target is one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isarray
+    pub(crate) fn is_array_value(value: &Value, ctx: &mut Context) -> Result<bool> {
+        let mut value = value.clone();
+        loop {
+            let target = match value.as_object() {
+                Some(object) => match object.as_proxy() {
+                    Some(proxy) => proxy.target().cloned(),
+                    None => return Ok(object.is_array()),
+                },
+                None => return Ok(false),
+            };
+            match target {
+                Some(target) => value = target.into(),
+                None => {
+                    return Err(ctx.construct_type_error(
+                        "Cannot perform 'IsArray' on a proxy that has been revoked",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// `ArrayCreate( length )`
+    ///
+    /// Creates an empty array with the given `length`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraycreate
+    pub(crate) fn array_create(length: usize, ctx: &mut Context) -> Result<GcObject> {
+        if length > u32::MAX as usize {
+            return Err(ctx.construct_range_error("invalid array length"));
+        }
+        let array = Self::create_array_from_list(Vec::new(), ctx);
+        let length = DataDescriptor::new(
+            length,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        array.set_property("length", length);
+        Ok(array.as_gc_object().expect("array is an object"))
+    }
+
+    /// `ArraySpeciesCreate( originalArray, length )`
+    ///
+    /// Creates an array with the `@@species` constructor of `original`, if it is an array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arrayspeciescreate
+    pub(crate) fn array_species_create(
+        original: &GcObject,
+        length: usize,
+        ctx: &mut Context,
+    ) -> Result<GcObject> {
+        let receiver = Value::from(original.clone());
+        if !Self::is_array_value(&receiver, ctx)? {
+            return Self::array_create(length, ctx);
+        }
+        let mut constructor = original.get(&"constructor".into(), &receiver, ctx)?;
+        if let Some(object) = constructor.as_gc_object() {
+            let species = ctx.well_known_symbols().species_symbol();
+            constructor = object.get(&species.into(), &constructor, ctx)?;
+            if constructor.is_null() {
+                constructor = Value::undefined();
+            }
+        }
+        match constructor {
+            Value::Undefined => Self::array_create(length, ctx),
+            Value::Object(ref object) if object.is_constructable() => {
+                let array = object.construct(&[length.into()], &constructor, ctx)?;
+                array.to_object(ctx)
+            }
+            _ => Err(ctx.construct_type_error("array species is not a constructor")),
+        }
+    }
+
+    /// Resolves a relative index argument against `len`, counting from the end if it is
+    /// negative and clamping it to `0..=len`. A missing or `undefined` argument is `default`.
+    fn relative_index(
+        value: Option<&Value>,
+        default: usize,
+        len: usize,
+        ctx: &mut Context,
+    ) -> Result<usize> {
+        let relative = match value {
+            Some(value) if !value.is_undefined() => value.to_integer(ctx)?,
+            _ => return Ok(default),
+        };
+        let index = if relative < 0.0 {
+            (len as f64 + relative).max(0.0)
+        } else {
+            relative.min(len as f64)
+        };
+        Ok(index as usize)
+    }
+
+    /// Gets the callback argument of the iteration methods, which must be a function.
+    fn callback(args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let callback = args.get(0).cloned().unwrap_or_default();
+        if !callback.is_function() {
+            return Err(ctx.construct_type_error("callback is not a function"));
+        }
+        Ok(callback)
+    }
+
+    /// `get Array [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-array-@@species
+    fn get_species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `Array.from( items [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// Creates an array from an iterable or array-like object, optionally mapping each element.
+    /// When called on another constructor, that constructor creates the result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/from
+    pub(crate) fn from(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let items = args.get(0).cloned().unwrap_or_default();
+        let map_function = args.get(1).cloned().unwrap_or_default();
+        if !map_function.is_undefined() && !map_function.is_function() {
+            return ctx.throw_type_error("Array.from: map function is not a function");
+        }
+        let this_arg = args.get(2).cloned().unwrap_or_default();
+        let constructor = match this {
+            Value::Object(ref object) if object.is_constructable() => Some(object.clone()),
+            _ => None,
+        };
+
+        let using_iterator = if items.is_null_or_undefined() {
+            Value::undefined()
+        } else {
+            let iterator_symbol = ctx.well_known_symbols().iterator_symbol();
+            items
+                .to_object(ctx)?
+                .get(&iterator_symbol.into(), &items, ctx)?
+        };
+
+        if !using_iterator.is_null_or_undefined() {
+            if !using_iterator.is_function() {
+                return ctx.throw_type_error("Array.from: @@iterator is not a function");
+            }
+            let array = match constructor {
+                Some(ref constructor) => constructor.construct(&[], this, ctx)?.to_object(ctx)?,
+                None => Self::array_create(0, ctx)?,
+            };
+            let iterator = ctx.call(&using_iterator, &items, &[])?;
+            if !iterator.is_object() {
+                return ctx.throw_type_error("Array.from: iterator is not an object");
+            }
+            let next_function = iterator.get_field("next", ctx)?;
+            let iterator_record = IteratorRecord::new(iterator, next_function);
+
+            let mut index = 0;
+            loop {
+                let next = iterator_record.next(ctx)?;
+                if next.is_done() {
+                    array.set_or_throw("length", index, ctx)?;
+                    return Ok(array.into());
+                }
+                let value = next.value();
+                let value = if map_function.is_undefined() {
+                    Ok(value)
+                } else {
+                    ctx.call(&map_function, &this_arg, &[value, index.into()])
+                };
+                let defined =
+                    value.and_then(|value| array.create_data_property_or_throw(index, value, ctx));
+                if let Err(error) = defined {
+                    return iterator_record.close(Err(error), ctx);
+                }
+                index += 1;
+            }
+        }
+
+        let array_like = items.to_object(ctx)?;
+        let len = array_like.length_of_array_like(ctx)?;
+        let array = match constructor {
+            Some(ref constructor) => constructor
+                .construct(&[len.into()], this, ctx)?
+                .to_object(ctx)?,
+            None => Self::array_create(len, ctx)?,
+        };
+        let receiver = Value::from(array_like.clone());
+        for index in 0..len {
+            let value = array_like.get(&index.into(), &receiver, ctx)?;
+            let value = if map_function.is_undefined() {
+                value
+            } else {
+                ctx.call(&map_function, &this_arg, &[value, index.into()])?
+            };
+            array.create_data_property_or_throw(index, value, ctx)?;
+        }
+        array.set_or_throw("length", len, ctx)?;
+        Ok(array.into())
+    }
+
+    /// `Array.of( ...items )`
+    ///
+    /// Creates an array holding the arguments. When called on another constructor, that
+    /// constructor creates the result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/of
+    pub(crate) fn of(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let len = args.len();
+        let array = match this {
+            Value::Object(ref constructor) if constructor.is_constructable() => constructor
+                .construct(&[len.into()], this, ctx)?
+                .to_object(ctx)?,
+            _ => Self::array_create(len, ctx)?,
+        };
+        for (index, value) in args.iter().enumerate() {
+            array.create_data_property_or_throw(index, value.clone(), ctx)?;
+        }
+        array.set_or_throw("length", len, ctx)?;
+        Ok(array.into())
+    }
+
+    /// `Array.prototype.at( index )`
+    ///
+    /// Returns the element at the given index, counting from the end of the array if the index
+    /// is negative.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-relative-indexing-method/#sec-array.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/at
+    pub(crate) fn at(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)? as f64;
+        let relative = args.get(0).cloned().unwrap_or_default().to_integer(ctx)?;
+        let index = if relative >= 0.0 {
+            relative
+        } else {
+            len + relative
+        };
+        if index < 0.0 || index >= len {
+            return Ok(Value::undefined());
+        }
+        object.get(&(index as usize).into(), this, ctx)
+    }
+
+    /// `Array.prototype.findLast( predicate [ , thisArg ] )`
+    ///
+    /// Returns the last element for which the predicate returns a truthy value, searching from
+    /// the end of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-find-from-last/#sec-array.prototype.findlast
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLast
+    pub(crate) fn find_last(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let found = Self::find_from_last(this, args, ctx)?;
+        Ok(found.map(|(_, value)| value).unwrap_or_default())
+    }
+
+    /// `Array.prototype.findLastIndex( predicate [ , thisArg ] )`
+    ///
+    /// Returns the index of the last element for which the predicate returns a truthy value,
+    /// or `-1` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-find-from-last/#sec-array.prototype.findlastindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLastIndex
+    pub(crate) fn find_last_index(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let found = Self::find_from_last(this, args, ctx)?;
+        Ok(found.map_or(Value::from(-1), |(index, _)| Value::from(index)))
+    }
+
+    /// Finds the last element for which `args[0]` returns a truthy value, visiting holes as
+    /// `undefined`.
+    fn find_from_last(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Option<(usize, Value)>> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        let predicate = Self::callback(args, ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let receiver = Value::from(object.clone());
+        for index in (0..len).rev() {
+            let value = object.get(&index.into(), &receiver, ctx)?;
+            let arguments = [value.clone(), index.into(), receiver.clone()];
+            if ctx.call(&predicate, &this_arg, &arguments)?.to_boolean() {
+                return Ok(Some((index, value)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// `Array.prototype.copyWithin( target, start [ , end ] )`
+    ///
+    /// Copies the elements from `start` to `end` over the elements starting at `target`,
+    /// handling overlapping ranges.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.copywithin
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/copyWithin
+    pub(crate) fn copy_within(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        let to =
+            Self::relative_index(Some(&args.get(0).cloned().unwrap_or_default()), 0, len, ctx)?;
+        let from =
+            Self::relative_index(Some(&args.get(1).cloned().unwrap_or_default()), 0, len, ctx)?;
+        let end = Self::relative_index(args.get(2), len, len, ctx)?;
+        let count = end.saturating_sub(from).min(len - to);
+
+        // Overlapping ranges where the target comes after the source are copied backwards.
+        let pairs: Box<dyn Iterator<Item = (usize, usize)>> = if from < to && to < from + count {
+            Box::new((0..count).rev().map(|offset| (from + offset, to + offset)))
+        } else {
+            Box::new((0..count).map(|offset| (from + offset, to + offset)))
+        };
+        let receiver = Value::from(object.clone());
+        for (from, to) in pairs {
+            let from = PropertyKey::from(from);
+            if object.has_property(&from, ctx)? {
+                let value = object.get(&from, &receiver, ctx)?;
+                object.set_or_throw(to, value, ctx)?;
+            } else {
+                object.delete_property_or_throw(to, ctx)?;
+            }
+        }
+        Ok(receiver)
+    }
+
+    /// `Array.prototype.splice( start, deleteCount, ...items )`
+    ///
+    /// Removes `deleteCount` elements from `start`, inserting `items` in their place, and
+    /// returns the removed elements in a new array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.splice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice
+    pub(crate) fn splice(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        let start =
+            Self::relative_index(Some(&args.get(0).cloned().unwrap_or_default()), 0, len, ctx)?;
+        let items = args.get(2..).unwrap_or_default();
+        let delete_count = match args.len() {
+            0 => 0,
+            1 => len - start,
+            _ => {
+                let delete_count = args[1].to_integer(ctx)?;
+                delete_count.max(0.0).min((len - start) as f64) as usize
+            }
+        };
+        if (len + items.len() - delete_count) as f64 > Number::MAX_SAFE_INTEGER {
+            return ctx.throw_type_error("Array.prototype.splice: array length is too large");
+        }
+
+        let receiver = Value::from(object.clone());
+        let removed = Self::array_species_create(&object, delete_count, ctx)?;
+        for index in 0..delete_count {
+            let from = PropertyKey::from(start + index);
+            if object.has_property(&from, ctx)? {
+                let value = object.get(&from, &receiver, ctx)?;
+                removed.create_data_property_or_throw(index, value, ctx)?;
+            }
+        }
+        removed.set_or_throw("length", delete_count, ctx)?;
+
+        // Move the elements after the deleted ones to their new position.
+        let tail = start..len - delete_count;
+        let moves: Box<dyn Iterator<Item = usize>> = if items.len() < delete_count {
+            Box::new(tail)
+        } else {
+            Box::new(tail.rev())
+        };
+        if items.len() != delete_count {
+            for index in moves {
+                let from = PropertyKey::from(index + delete_count);
+                let to = index + items.len();
+                if object.has_property(&from, ctx)? {
+                    let value = object.get(&from, &receiver, ctx)?;
+                    object.set_or_throw(to, value, ctx)?;
+                } else {
+                    object.delete_property_or_throw(to, ctx)?;
+                }
+            }
+        }
+        for index in (len - delete_count + items.len()..len).rev() {
+            object.delete_property_or_throw(index, ctx)?;
+        }
+
+        for (index, item) in items.iter().enumerate() {
+            object.set_or_throw(start + index, item.clone(), ctx)?;
+        }
+        object.set_or_throw("length", len - delete_count + items.len(), ctx)?;
+        Ok(removed.into())
+    }
+
+    /// `Array.prototype.flat( [ depth ] )`
+    ///
+    /// Creates a new array with the elements of nested arrays concatenated into it, up to
+    /// `depth` levels deep.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.flat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/flat
+    pub(crate) fn flat(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        let depth = match args.get(0) {
+            Some(depth) if !depth.is_undefined() => depth.to_integer(ctx)?.max(0.0),
+            _ => 1.0,
+        };
+        let array = Self::array_species_create(&object, 0, ctx)?;
+        Self::flatten_into_array(&array, &object, len, 0, depth, None, ctx)?;
+        Ok(array.into())
+    }
+
+    /// `Array.prototype.flatMap( mapperFunction [ , thisArg ] )`
+    ///
+    /// Maps each element with the mapper function and flattens the result by one level.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.flatmap
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/flatMap
+    pub(crate) fn flat_map(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        let mapper = Self::callback(args, ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let array = Self::array_species_create(&object, 0, ctx)?;
+        Self::flatten_into_array(
+            &array,
+            &object,
+            len,
+            0,
+            1.0,
+            Some((&mapper, &this_arg)),
+            ctx,
+        )?;
+        Ok(array.into())
+    }
+
+    /// `FlattenIntoArray( target, source, sourceLen, start, depth [ , mapperFunction, thisArg ] )`
+    ///
+    /// Returns the index after the last element written to `target`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-flattenintoarray
+    fn flatten_into_array(
+        target: &GcObject,
+        source: &GcObject,
+        source_len: usize,
+        start: usize,
+        depth: f64,
+        mapper: Option<(&Value, &Value)>,
+        ctx: &mut Context,
+    ) -> Result<usize> {
+        let receiver = Value::from(source.clone());
+        let mut target_index = start;
+        for source_index in 0..source_len {
+            let key = PropertyKey::from(source_index);
+            if !source.has_property(&key, ctx)? {
+                continue;
+            }
+            let mut element = source.get(&key, &receiver, ctx)?;
+            if let Some((mapper, this_arg)) = mapper {
+                let arguments = [element, source_index.into(), receiver.clone()];
+                element = ctx.call(mapper, this_arg, &arguments)?;
+            }
+            match element {
+                Value::Object(ref nested)
+                    if depth > 0.0 && Self::is_array_value(&element, ctx)? =>
+                {
+                    let nested_len = nested.length_of_array_like(ctx)?;
+                    target_index = Self::flatten_into_array(
+                        target,
+                        nested,
+                        nested_len,
+                        target_index,
+                        depth - 1.0,
+                        None,
+                        ctx,
+                    )?;
+                }
+                _ => {
+                    if target_index as f64 >= Number::MAX_SAFE_INTEGER {
+                        return Err(ctx.construct_type_error("flattened array is too large"));
+                    }
+                    target.create_data_property_or_throw(target_index, element, ctx)?;
+                    target_index += 1;
+                }
+            }
+        }
+        Ok(target_index)
+    }
+
+    /// `Array.prototype.sort( comparefn )`
+    ///
+    /// Sorts the elements of the array in place. The sort is stable, holes are removed and
+    /// `undefined` elements are moved to the end. Without a compare function, the elements are
+    /// compared as strings.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/sort
+    pub(crate) fn sort(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let compare_function = args.get(0).cloned().unwrap_or_default();
+        if !compare_function.is_undefined() && !compare_function.is_function() {
+            return ctx.throw_type_error("Array.prototype.sort: comparator is not a function");
+        }
+        let object = this.to_object(ctx)?;
+        let len = object.length_of_array_like(ctx)?;
+        let receiver = Value::from(object.clone());
+
+        let mut values = Vec::new();
+        let mut undefined_count = 0;
+        for index in 0..len {
+            let key = PropertyKey::from(index);
+            if object.has_property(&key, ctx)? {
+                match object.get(&key, &receiver, ctx)? {
+                    Value::Undefined => undefined_count += 1,
+                    value => values.push(value),
+                }
+            }
+        }
+
+        let sorted = Self::sort_values(values, |x, y| {
+            if compare_function.is_undefined() {
+                let x = x.to_string(ctx)?;
+                let y = y.to_string(ctx)?;
+                return Ok(x.cmp(&y));
+            }
+            let order = ctx
+                .call(
+                    &compare_function,
+                    &Value::undefined(),
+                    &[x.clone(), y.clone()],
+                )?
+                .to_number(ctx)?;
+            Ok(order.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+        })?;
+
+        let item_count = sorted.len() + undefined_count;
+        let undefineds = std::iter::repeat_n(Value::undefined(), undefined_count);
+        for (index, value) in sorted.into_iter().chain(undefineds).enumerate() {
+            object.set_or_throw(index, value, ctx)?;
+        }
+        for index in item_count..len {
+            object.delete_property_or_throw(index, ctx)?;
+        }
+        Ok(receiver)
+    }
+
+    /// Sorts `values` with a comparison that can fail, like one calling a user supplied compare
+    /// function.
+    ///
+    /// This is a merge sort, so it is stable and copes with inconsistent comparisons.
+    pub(crate) fn sort_values<F>(mut values: Vec<Value>, mut compare: F) -> Result<Vec<Value>>
+    where
+        F: FnMut(&Value, &Value) -> Result<Ordering>,
+    {
+        let len = values.len();
+        let mut merged = Vec::with_capacity(len);
+        let mut width = 1;
+        while width < len {
+            merged.clear();
+            let mut start = 0;
+            while start < len {
+                let middle = min(start + width, len);
+                let end = min(start + 2 * width, len);
+                let (mut left, mut right) = (start, middle);
+                while left < middle && right < end {
+                    if compare(&values[right], &values[left])? == Ordering::Less {
+                        merged.push(values[right].clone());
+                        right += 1;
+                    } else {
+                        merged.push(values[left].clone());
+                        left += 1;
+                    }
+                }
+                merged.extend_from_slice(&values[left..middle]);
+                merged.extend_from_slice(&values[right..end]);
+                start = end;
+            }
+            std::mem::swap(&mut values, &mut merged);
+            width *= 2;
+        }
+        Ok(values)
+    }
 }
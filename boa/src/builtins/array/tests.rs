@@ -1,4 +1,4 @@
-use crate::{forward, Context, Value};
+use crate::{forward, gc::force_collect, Context, Value};
 
 #[test]
 fn is_array() {
@@ -535,7 +535,7 @@ fn fill() {
 
     assert_eq!(
         forward(&mut engine, "a.fill().join()"),
-        String::from("\"undefined,undefined,undefined\"")
+        String::from("\",,\"")
     );
 
     // test object reference
@@ -1069,14 +1069,19 @@ fn call_array_constructor_with_one_argument() {
         var one = new Array("Hello, world!");
         "#;
     forward(&mut engine, init);
-    // let result = forward(&mut engine, "empty.length");
-    // assert_eq!(result, "0");
+    let result = forward(&mut engine, "empty.length");
+    assert_eq!(result, "0");
+
+    let result = forward(&mut engine, "five.length");
+    assert_eq!(result, "5");
+    let result = forward(&mut engine, "0 in five");
+    assert_eq!(result, "false");
 
-    // let result = forward(&mut engine, "five.length");
-    // assert_eq!(result, "5");
+    let result = forward(&mut engine, "one.length");
+    assert_eq!(result, "1");
 
-    // let result = forward(&mut engine, "one.length");
-    // assert_eq!(result, "1");
+    let result = forward(&mut engine, "try { new Array(-1) } catch (e) { e.name }");
+    assert_eq!(result, "\"RangeError\"");
 }
 
 #[test]
@@ -1234,3 +1239,463 @@ fn array_spread_non_iterable() {
     "#;
     assert_eq!(forward(&mut engine, init), "true");
 }
+
+#[test]
+fn length_grows_with_elements() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = [1, 2];
+        array[2] = 3;
+        array[9] = 10;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array.length"), "10");
+    assert_eq!(forward(&mut engine, "5 in array"), "false");
+    assert_eq!(forward(&mut engine, "array[9]"), "10");
+}
+
+#[test]
+fn length_truncates_elements() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = [1, 2, 3, 4, 5];
+        array.length = 2;
+        var sparse = [];
+        sparse[100] = "a";
+        sparse[5] = "b";
+        sparse.length = "6";
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array.length"), "2");
+    assert_eq!(forward(&mut engine, "2 in array"), "false");
+    assert_eq!(forward(&mut engine, "array.join()"), "\"1,2\"");
+    assert_eq!(forward(&mut engine, "sparse.length"), "6");
+    assert_eq!(forward(&mut engine, "100 in sparse"), "false");
+    assert_eq!(forward(&mut engine, "sparse[5]"), "\"b\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { array.length = 1.5 } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { array.length = -1 } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn large_arrays_keep_their_elements() {
+    let mut engine = Context::new();
+    // Each element store used to take time linear in the length of the array.
+    let init = r#"
+        var large = [];
+        for (var i = 0; i < 20000; i++) {
+            large[i] = { value: i };
+        }
+        "#;
+    forward(&mut engine, init);
+    force_collect();
+    forward(&mut engine, "large[5] = { value: -5 }; large.pop();");
+    force_collect();
+    let check = r#"
+        var sum = 0;
+        for (var i = 0; i < large.length; i++) {
+            sum += large[i].value;
+        }
+        sum
+        "#;
+    assert_eq!(forward(&mut engine, check), "199969991");
+    assert_eq!(forward(&mut engine, "large.length"), "19999");
+}
+
+#[test]
+fn length_respects_descriptors() {
+    let mut engine = Context::new();
+    let init = r#"
+        var pinned = [1, 2, 3];
+        Object.defineProperty(pinned, 1, { value: 7, configurable: false, enumerable: true });
+        var truncated = Reflect.set(pinned, "length", 0);
+        var fixed = [1, 2];
+        Object.defineProperty(fixed, "length", { writable: false });
+        var appended = Reflect.set(fixed, 2, 3);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "truncated"), "false");
+    assert_eq!(forward(&mut engine, "pinned.length"), "2");
+    assert_eq!(forward(&mut engine, "pinned[1]"), "7");
+    assert_eq!(forward(&mut engine, "pinned[0]"), "1");
+    assert_eq!(forward(&mut engine, "appended"), "false");
+    assert_eq!(forward(&mut engine, "fixed.length"), "2");
+    assert_eq!(forward(&mut engine, "2 in fixed"), "false");
+}
+
+#[test]
+fn search_with_infinite_from_index() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = [true, NaN, true];
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array.indexOf(true, -Infinity)"), "0");
+    assert_eq!(forward(&mut engine, "array.indexOf(true, Infinity)"), "-1");
+    assert_eq!(
+        forward(&mut engine, "array.lastIndexOf(true, -Infinity)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut engine, "array.lastIndexOf(true, Infinity)"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut engine, "array.includes(NaN, -Infinity)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Array(3).indexOf(undefined)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Array(3).includes(undefined)"),
+        "true"
+    );
+}
+
+#[test]
+fn search_sparse_arrays() {
+    let mut engine = Context::new();
+    let init = r#"
+        var sparse = [];
+        sparse[1e9] = 1;
+        sparse[5] = 2;
+        var inherited = [];
+        inherited[3e9] = 0;
+        var getter = [];
+        getter[1e6] = 0;
+        Object.defineProperty(getter, 3, { get() { return "get"; } });
+        var arrayLike = { length: 3e9, 2999999999: "last" };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "sparse.indexOf(1)"), "1000000000");
+    assert_eq!(forward(&mut engine, "sparse.indexOf(2, 6)"), "-1");
+    assert_eq!(forward(&mut engine, "sparse.lastIndexOf(2)"), "5");
+    assert_eq!(forward(&mut engine, "sparse.lastIndexOf(1, 1e9 - 1)"), "-1");
+    assert_eq!(forward(&mut engine, "sparse.indexOf(undefined)"), "-1");
+    assert_eq!(forward(&mut engine, "sparse.includes(1)"), "true");
+    assert_eq!(
+        forward(&mut engine, "sparse.includes(undefined, 5)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "[1, , 3].includes(undefined)"), "true");
+    assert_eq!(forward(&mut engine, "[1, 2].includes(undefined)"), "false");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Array.prototype[7] = 'p'; var found = inherited.indexOf('p'); delete Array.prototype[7]; found"
+        ),
+        "7"
+    );
+    assert_eq!(forward(&mut engine, "getter.indexOf('get')"), "3");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Array.prototype.indexOf.call(arrayLike, 'last')"
+        ),
+        "2999999999"
+    );
+}
+
+#[test]
+fn sort() {
+    let mut engine = Context::new();
+    let init = r#"
+        var strings = [3, 1, undefined, 10, 2];
+        strings[7] = 0;
+        strings.sort();
+        var numbers = [5, 1, 4].sort((a, b) => b - a);
+        var people = [
+            { name: "a", age: 2 },
+            { name: "b", age: 1 },
+            { name: "c", age: 2 },
+            { name: "d", age: 1 },
+        ];
+        people.sort((x, y) => x.age - y.age);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "strings.join()"), "\"0,1,10,2,3,,,\"");
+    assert_eq!(forward(&mut engine, "strings.length"), "8");
+    assert_eq!(forward(&mut engine, "5 in strings"), "true");
+    assert_eq!(forward(&mut engine, "6 in strings"), "false");
+    assert_eq!(forward(&mut engine, "numbers.join()"), "\"5,4,1\"");
+    assert_eq!(
+        forward(&mut engine, "people.map(p => p.name).join()"),
+        "\"b,d,a,c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { [].sort(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn splice() {
+    let mut engine = Context::new();
+    let init = r#"
+        var grown = [1, 2, 3, 4, 5];
+        var grownRemoved = grown.splice(1, 2, "a", "b", "c");
+        var shrunk = [1, 2, 3, 4, 5];
+        var shrunkRemoved = shrunk.splice(1, 3);
+        var tail = [1, 2, 3, 4, 5];
+        var tailRemoved = tail.splice(-2);
+        var untouched = [1, 2, 3];
+        var nothing = untouched.splice();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "grown.join()"), "\"1,a,b,c,4,5\"");
+    assert_eq!(forward(&mut engine, "grownRemoved.join()"), "\"2,3\"");
+    assert_eq!(forward(&mut engine, "shrunk.join()"), "\"1,5\"");
+    assert_eq!(forward(&mut engine, "shrunk.length"), "2");
+    assert_eq!(forward(&mut engine, "shrunkRemoved.join()"), "\"2,3,4\"");
+    assert_eq!(forward(&mut engine, "tail.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "tailRemoved.join()"), "\"4,5\"");
+    assert_eq!(forward(&mut engine, "untouched.length"), "3");
+    assert_eq!(forward(&mut engine, "nothing.length"), "0");
+}
+
+#[test]
+fn flat_and_flat_map() {
+    let mut engine = Context::new();
+    let init = r#"
+        var nested = [1, [2, [3, [4]]]];
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "nested.flat().length"), "3");
+    assert_eq!(forward(&mut engine, "nested.flat(2).length"), "4");
+    assert_eq!(
+        forward(&mut engine, "nested.flat(Infinity).join()"),
+        "\"1,2,3,4\""
+    );
+    assert_eq!(forward(&mut engine, "nested.flat(-1).length"), "2");
+    assert_eq!(
+        forward(&mut engine, "[1, 2].flatMap(x => [x, x * 2]).join()"),
+        "\"1,2,2,4\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[1, 2].flatMap(x => [[x]])[0].length"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { [].flatMap() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn copy_within() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "[1, 2, 3, 4, 5].copyWithin(0, 3).join()"),
+        "\"4,5,3,4,5\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[1, 2, 3, 4, 5].copyWithin(1, 0).join()"),
+        "\"1,1,2,3,4\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[1, 2, 3, 4, 5].copyWithin(-2, -3, -1).join()"),
+        "\"1,2,3,3,4\""
+    );
+}
+
+#[test]
+fn find_last_and_at() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = [1, 2, 3, 4];
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array.findLast(x => x < 3)"), "2");
+    assert_eq!(
+        forward(&mut engine, "array.findLast(x => x > 5)"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut engine, "array.findLastIndex(x => x < 3)"), "1");
+    assert_eq!(
+        forward(&mut engine, "array.findLastIndex(x => x > 5)"),
+        "-1"
+    );
+    assert_eq!(forward(&mut engine, "array.at(0)"), "1");
+    assert_eq!(forward(&mut engine, "array.at(-1)"), "4");
+    assert_eq!(forward(&mut engine, "array.at(4)"), "undefined");
+    assert_eq!(forward(&mut engine, "array.at(-5)"), "undefined");
+}
+
+#[test]
+fn from_and_of() {
+    let mut engine = Context::new();
+    let init = r#"
+        var fromString = Array.from("abc");
+        var fromArrayLike = Array.from({ length: 3, 0: "x" });
+        var mapped = Array.from([1, 2], x => x * 10);
+        var fromSet = Array.from(new Set([1, 1, 2]));
+        function Custom() {}
+        var custom = Array.of.call(Custom, 1, 2);
+        var closed = false;
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                next() { return { value: 1, done: false }; },
+                return() { closed = true; return {}; },
+            };
+        };
+        try { Array.from(iterable, () => { throw "stop"; }); } catch (e) {}
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "fromString.join()"), "\"a,b,c\"");
+    assert_eq!(forward(&mut engine, "fromArrayLike.length"), "3");
+    assert_eq!(forward(&mut engine, "fromArrayLike[0]"), "\"x\"");
+    assert_eq!(forward(&mut engine, "mapped.join()"), "\"10,20\"");
+    assert_eq!(forward(&mut engine, "fromSet.join()"), "\"1,2\"");
+    assert_eq!(forward(&mut engine, "Array.of(7).length"), "1");
+    assert_eq!(
+        forward(&mut engine, "Array.of(1, 2, 3).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(forward(&mut engine, "custom instanceof Custom"), "true");
+    assert_eq!(forward(&mut engine, "custom.length"), "2");
+    assert_eq!(forward(&mut engine, "closed"), "true");
+}
+
+#[test]
+fn species() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = [1, [2]];
+        array.constructor = {};
+        array.constructor[Symbol.species] = function (length) { this.created = length; };
+        var flattened = array.flat();
+        var spliced = array.splice(0, 1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Array[Symbol.species] === Array"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "flattened.created"), "0");
+    assert_eq!(forward(&mut engine, "flattened[1]"), "2");
+    assert_eq!(forward(&mut engine, "spliced.created"), "1");
+    assert_eq!(forward(&mut engine, "Array.isArray(spliced)"), "false");
+}
+
+#[test]
+fn species_subclasses() {
+    let mut engine = Context::new();
+    let init = r#"
+        class MyA extends Array {}
+        var array = new MyA(1, 2, 3);
+        var spreadable = { length: 2, 0: "a", 1: "b", [Symbol.isConcatSpreadable]: true };
+        var plain = [1, 2, 3];
+        plain.constructor = undefined;
+        "#;
+    forward(&mut engine, init);
+    for method in &[
+        "map(x => x)",
+        "filter(x => x > 1)",
+        "slice(1)",
+        "concat([4])",
+        "flat()",
+        "flatMap(x => [x])",
+        "splice(0, 0)",
+    ] {
+        assert_eq!(
+            forward(
+                &mut engine,
+                &format!("array.{}.constructor === MyA", method)
+            ),
+            "true",
+            "{}",
+            method
+        );
+        assert_eq!(
+            forward(&mut engine, &format!("Array.isArray(plain.{})", method)),
+            "true",
+            "{}",
+            method
+        );
+    }
+    assert_eq!(
+        forward(&mut engine, "array.map(x => x * 2).join()"),
+        "\"2,4,6\""
+    );
+    assert_eq!(
+        forward(&mut engine, "array.filter(x => x !== 2).join()"),
+        "\"1,3\""
+    );
+    assert_eq!(forward(&mut engine, "array.slice(-2).join()"), "\"2,3\"");
+    assert_eq!(
+        forward(&mut engine, "array.concat([4], 5, spreadable).join()"),
+        "\"1,2,3,4,5,a,b\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { [1].map(() => { throw new Error('callback') }) } catch (e) { e.message }"
+        ),
+        "\"callback\""
+    );
+}
+
+#[test]
+fn mutators_throw_on_failed_writes() {
+    let mut engine = Context::new();
+    for call in &[
+        "push(3)",
+        "pop()",
+        "shift()",
+        "unshift(0)",
+        "splice(0, 1)",
+        "splice(0, 0, 9)",
+    ] {
+        assert_eq!(
+            forward(
+                &mut engine,
+                &format!(
+                    "var frozen = Object.freeze([1, 2]); try {{ frozen.{} }} catch (e) {{ e.name }}",
+                    call
+                )
+            ),
+            "\"TypeError\"",
+            "{}",
+            call
+        );
+        assert_eq!(forward(&mut engine, "frozen.join()"), "\"1,2\"", "{}", call);
+    }
+    assert_eq!(
+        forward(
+            &mut engine,
+            "var sealed = Object.seal([1, 2]); try { sealed.pop() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"
+            var fixed = Object.defineProperty([1], 'length', { writable: false });
+            try { fixed.push(2) } catch (e) { e.name }
+            "#
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "var like = { length: 1, 0: 'a' }; Array.prototype.push.call(like, 'b') + like[1]"
+        ),
+        "\"2b\""
+    );
+}
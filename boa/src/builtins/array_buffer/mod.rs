@@ -0,0 +1,352 @@
+//! This module implements the global `ArrayBuffer` object.
+//!
+//! An array buffer holds a fixed-length block of raw binary data, which is read and written
+//! through typed arrays and `DataView`s. A buffer can be detached, after which its data is gone
+//! and every view on it throws.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
+
+use crate::{
+    builtins::{typed_array::TypedArrayKind, BuiltIn},
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
+    property::{AccessorDescriptor, Attribute},
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The data of an `ArrayBuffer` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ArrayBuffer {
+    /// The bytes of the buffer, `None` once it has been detached.
+    data: Option<Vec<u8>>,
+}
+
+impl BuiltIn for ArrayBuffer {
+    const NAME: &'static str = "ArrayBuffer";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let array_buffer_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().array_buffer_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::slice, "slice", 2)
+        .static_method(Self::is_view, "isView", 1)
+        .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+        .callable(false)
+        .build();
+
+        let get_byte_length = FunctionBuilder::new(context, Self::get_byte_length)
+            .name("get byteLength")
+            .callable(true)
+            .constructable(false)
+            .build();
+        context
+            .standard_objects()
+            .array_buffer_object()
+            .prototype()
+            .borrow_mut()
+            .insert(
+                "byteLength",
+                AccessorDescriptor::new(Some(get_byte_length), None, Attribute::CONFIGURABLE),
+            );
+
+        let species = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .callable(true)
+            .constructable(false)
+            .build();
+        array_buffer_object.borrow_mut().insert(
+            species,
+            AccessorDescriptor::new(Some(get_species), None, Attribute::CONFIGURABLE),
+        );
+
+        (Self::NAME, array_buffer_object.into(), Self::attribute())
+    }
+}
+
+impl ArrayBuffer {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Returns the bytes of the buffer, or `None` if it has been detached.
+    #[inline]
+    pub fn data(&self) -> Option<&[u8]> {
+        self.data.as_deref()
+    }
+
+    /// Returns the bytes of the buffer mutably, or `None` if it has been detached.
+    #[inline]
+    pub fn data_mut(&mut self) -> Option<&mut [u8]> {
+        self.data.as_deref_mut()
+    }
+
+    /// Returns the length of the buffer in bytes, which is `0` once it has been detached.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.data.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Returns `true` if the buffer has been detached.
+    #[inline]
+    pub fn is_detached(&self) -> bool {
+        self.data.is_none()
+    }
+
+    /// `DetachArrayBuffer( arrayBuffer )`
+    ///
+    /// Takes the bytes out of the buffer, which makes every view on it unusable. Returns `None`
+    /// if it was already detached.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-detacharraybuffer
+    #[inline]
+    pub fn detach(&mut self) -> Option<Vec<u8>> {
+        self.data.take()
+    }
+
+    /// Creates a new `ArrayBuffer` object that owns `data`, without copying it.
+    pub(crate) fn create(data: Vec<u8>, ctx: &Context) -> GcObject {
+        let prototype = ctx.standard_objects().array_buffer_object().prototype();
+        GcObject::new(Object::with_prototype(
+            prototype.into(),
+            ObjectData::ArrayBuffer(Self { data: Some(data) }),
+        ))
+    }
+
+    /// `AllocateArrayBuffer( constructor, byteLength )`
+    ///
+    /// Creates a new `ArrayBuffer` object holding `byte_length` zeroed bytes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatearraybuffer
+    pub(crate) fn allocate(byte_length: usize, ctx: &mut Context) -> Result<GcObject> {
+        let data = Self::create_byte_data_block(byte_length, ctx)?;
+        Ok(Self::create(data, ctx))
+    }
+
+    /// `CreateByteDataBlock( size )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createbytedatablock
+    fn create_byte_data_block(size: usize, ctx: &mut Context) -> Result<Vec<u8>> {
+        if size > u32::MAX as usize {
+            return Err(ctx.construct_range_error("Array buffer allocation failed"));
+        }
+        Ok(vec![0; size])
+    }
+
+    /// `GetValueFromBuffer( arrayBuffer, byteIndex, type, isTypedArray, order, isLittleEndian )`
+    ///
+    /// Reads an element of type `kind` at `byte_index`. The caller has to make sure the buffer
+    /// isn't detached and that the element is in bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getvaluefrombuffer
+    pub(crate) fn get_value_from_buffer(
+        &self,
+        byte_index: usize,
+        kind: TypedArrayKind,
+        little_endian: bool,
+    ) -> Value {
+        let data = self.data().expect("array buffer should not be detached");
+        let mut bytes = [0; 8];
+        let size = kind.element_size();
+        bytes[..size].copy_from_slice(&data[byte_index..byte_index + size]);
+        if !little_endian {
+            bytes[..size].reverse();
+        }
+        kind.raw_bytes_to_numeric(u64::from_le_bytes(bytes))
+    }
+
+    /// `SetValueInBuffer( arrayBuffer, byteIndex, type, value, isTypedArray, order, isLittleEndian )`
+    ///
+    /// Writes the raw element `raw`, created with [`TypedArrayKind::to_raw`], at `byte_index`.
+    /// The caller has to make sure the buffer isn't detached and that the element is in bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setvalueinbuffer
+    pub(crate) fn set_value_in_buffer(
+        &mut self,
+        byte_index: usize,
+        kind: TypedArrayKind,
+        raw: u64,
+        little_endian: bool,
+    ) {
+        let data = self
+            .data_mut()
+            .expect("array buffer should not be detached");
+        let size = kind.element_size();
+        let bytes = raw.to_le_bytes();
+        let target = &mut data[byte_index..byte_index + size];
+        target.copy_from_slice(&bytes[..size]);
+        if !little_endian {
+            target.reverse();
+        }
+    }
+
+    /// `ArrayBuffer( length )`
+    ///
+    /// Creates a new array buffer of `length` zeroed bytes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer-length
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let byte_length = args.get(0).cloned().unwrap_or_default().to_index(ctx)?;
+        let data = Self::create_byte_data_block(byte_length, ctx)?;
+        this.set_data(ObjectData::ArrayBuffer(Self { data: Some(data) }));
+        Ok(this.clone())
+    }
+
+    /// `get ArrayBuffer [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer-@@species
+    fn get_species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `ArrayBuffer.isView( arg )`
+    ///
+    /// Checks if the argument is a typed array or a `DataView`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.isview
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView
+    pub(crate) fn is_view(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(match args.get(0) {
+            Some(Value::Object(ref object)) => {
+                let object = object.borrow();
+                object.is_typed_array() || object.is_data_view()
+            }
+            _ => false,
+        }
+        .into())
+    }
+
+    /// `get ArrayBuffer.prototype.byteLength`
+    ///
+    /// Returns the length of the buffer in bytes, or `0` if it has been detached.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength
+    pub(crate) fn get_byte_length(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        if let Value::Object(ref object) = this {
+            if let Some(buffer) = object.borrow().as_array_buffer() {
+                return Ok(buffer.byte_length().into());
+            }
+        }
+
+        ctx.throw_type_error("'this' is not an ArrayBuffer")
+    }
+
+    /// `ArrayBuffer.prototype.slice( start, end )`
+    ///
+    /// Returns a new array buffer holding a copy of the bytes from `start` up to `end`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice
+    pub(crate) fn slice(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) => object,
+            _ => return ctx.throw_type_error("'this' is not an ArrayBuffer"),
+        };
+        let len = match object.borrow().as_array_buffer() {
+            Some(buffer) if buffer.is_detached() => None,
+            Some(buffer) => Some(buffer.byte_length()),
+            None => return ctx.throw_type_error("'this' is not an ArrayBuffer"),
+        };
+        let len = match len {
+            Some(len) => len,
+            None => return ctx.throw_type_error("ArrayBuffer is detached"),
+        };
+
+        let start = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(len, 0, ctx)?;
+        let end = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(len, len, ctx)?;
+        let new_len = end.saturating_sub(start);
+
+        let default = ctx.standard_objects().array_buffer_object().constructor();
+        let constructor = object.species_constructor(default, ctx)?;
+        let new = constructor.construct(&[new_len.into()], &constructor.clone().into(), ctx)?;
+        let new = match new {
+            Value::Object(ref new) if new.borrow().is_array_buffer() => new.clone(),
+            _ => return ctx.throw_type_error("species constructor did not return an ArrayBuffer"),
+        };
+        if GcObject::equals(&new, object) {
+            return ctx.throw_type_error("species constructor returned the same ArrayBuffer");
+        }
+
+        let bytes = match object
+            .borrow()
+            .as_array_buffer()
+            .and_then(ArrayBuffer::data)
+        {
+            Some(data) => data[start..start + new_len].to_vec(),
+            None => return ctx.throw_type_error("ArrayBuffer is detached"),
+        };
+        match new
+            .borrow_mut()
+            .as_array_buffer_mut()
+            .and_then(ArrayBuffer::data_mut)
+        {
+            Some(data) if data.len() >= new_len => data[..new_len].copy_from_slice(&bytes),
+            Some(_) => {
+                return ctx.throw_type_error("species constructor returned a too small ArrayBuffer")
+            }
+            None => return ctx.throw_type_error("ArrayBuffer is detached"),
+        }
+
+        Ok(new.into())
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{forward, Context, Value};
+
+#[test]
+fn construct() {
+    let mut engine = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var empty = new ArrayBuffer();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "buffer.byteLength"), "8");
+    assert_eq!(forward(&mut engine, "empty.byteLength"), "0");
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(buffer)"),
+        "\"[object ArrayBuffer]\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { ArrayBuffer(8) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new ArrayBuffer(-1) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn slice() {
+    let mut engine = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        new Uint8Array(buffer).set([1, 2, 3, 4, 5, 6, 7, 8]);
+        var middle = new Uint8Array(buffer.slice(2, -2));
+        var tail = new Uint8Array(buffer.slice(-3));
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "middle.join()"), "\"3,4,5,6\"");
+    assert_eq!(forward(&mut engine, "tail.join()"), "\"6,7,8\"");
+    assert_eq!(forward(&mut engine, "buffer.slice(5, 2).byteLength"), "0");
+
+    // The slice is a copy.
+    forward(&mut engine, "middle[0] = 42");
+    assert_eq!(forward(&mut engine, "new Uint8Array(buffer)[2]"), "3");
+}
+
+#[test]
+fn is_view() {
+    let mut engine = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "ArrayBuffer.isView(buffer)"), "false");
+    assert_eq!(
+        forward(&mut engine, "ArrayBuffer.isView(new Int16Array(buffer))"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "ArrayBuffer.isView(new DataView(buffer))"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "ArrayBuffer.isView([])"), "false");
+}
+
+#[test]
+fn host_buffer() {
+    let mut engine = Context::new();
+    let buffer = engine.create_array_buffer(vec![1, 2, 3, 4]);
+    let global = engine.global_object().clone();
+    global
+        .set_field("buffer", Value::from(buffer.clone()), &mut engine)
+        .unwrap();
+
+    assert_eq!(forward(&mut engine, "buffer.byteLength"), "4");
+    forward(&mut engine, "new Uint8Array(buffer)[1] = 42");
+    assert_eq!(
+        buffer.borrow().as_array_buffer().unwrap().data(),
+        Some(&[1, 42, 3, 4][..])
+    );
+
+    let view = forward(&mut engine, "var view = new Uint8Array(buffer, 2); view[0]");
+    assert_eq!(view, "3");
+    let data = buffer.borrow_mut().as_array_buffer_mut().unwrap().detach();
+    assert_eq!(data, Some(vec![1, 42, 3, 4]));
+    assert_eq!(forward(&mut engine, "buffer.byteLength"), "0");
+    assert_eq!(forward(&mut engine, "view.length"), "0");
+    assert_eq!(forward(&mut engine, "view[0]"), "undefined");
+    assert_eq!(
+        forward(&mut engine, "try { buffer.slice(0) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { view.fill(0) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
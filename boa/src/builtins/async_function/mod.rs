@@ -0,0 +1,144 @@
+//! This module implements the runtime of async functions.
+//!
+//! The body of an async function runs like the body of a generator, it is suspended at every
+//! `await` expression and resumed from the job queue once the awaited promise is settled. The
+//! promise returned by the async function is settled when the body completes.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-function-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+
+use crate::{
+    builtins::{
+        function::{get_captures, make_capturing_fn, new_captures, Captures},
+        generator::Generator,
+        promise::{Promise, PromiseCapability},
+    },
+    environment::lexical_environment::Environment,
+    exec::ResumeKind,
+    object::GcObject,
+    syntax::ast::node::RcStatementList,
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The state shared by the functions that resume an async function.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct AsyncFunctionContext {
+    /// The suspended body of the function.
+    coroutine: GcObject,
+    /// The promise returned by the function.
+    capability: PromiseCapability,
+}
+
+/// Runs the bodies of async functions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncFunction;
+
+impl AsyncFunction {
+    /// Starts running the body of an async function, in the given function environment.
+    ///
+    /// Returns the promise that is settled with the completion of the body.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-async-functions-abstract-operations-async-function-start
+    pub(crate) fn start(
+        ctx: &mut Context,
+        body: RcStatementList,
+        environment: Environment,
+    ) -> Result<Value> {
+        let constructor = ctx.standard_objects().promise_object().constructor();
+        let capability = Promise::new_capability(&constructor.into(), ctx)?;
+
+        let coroutine = Generator::create(ctx, Value::null(), body, environment, None)
+            .as_gc_object()
+            .expect("generator object");
+        let context = AsyncFunctionContext {
+            coroutine,
+            capability: capability.clone(),
+        };
+        Self::step(&context, ResumeKind::Next, Value::undefined(), ctx)?;
+
+        Ok(capability.promise().clone())
+    }
+
+    /// Resumes the body of an async function, and waits for the next awaited value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await
+    fn step(
+        context: &AsyncFunctionContext,
+        kind: ResumeKind,
+        value: Value,
+        ctx: &mut Context,
+    ) -> Result<()> {
+        let promise = match Generator::resume_object(&context.coroutine, kind, value, ctx) {
+            Ok((value, true)) => {
+                ctx.call(context.capability.resolve(), &Value::undefined(), &[value])?;
+                return Ok(());
+            }
+            Err(error) => {
+                ctx.call(context.capability.reject(), &Value::undefined(), &[error])?;
+                return Ok(());
+            }
+            Ok((awaited, false)) => {
+                let constructor = ctx.standard_objects().promise_object().constructor();
+                match Promise::promise_resolve(&constructor.into(), awaited, ctx) {
+                    Ok(promise) => promise,
+                    // The awaited value could not be converted to a promise, the error is thrown
+                    // from the `await` expression right away.
+                    Err(error) => return Self::step(context, ResumeKind::Throw, error, ctx),
+                }
+            }
+        };
+
+        let captures = new_captures(context.clone());
+        let on_fulfilled = make_capturing_fn(Self::fulfilled, captures.clone(), 1, ctx);
+        let on_rejected = make_capturing_fn(Self::rejected, captures, 1, ctx);
+        let promise = promise.as_gc_object().expect("promise object");
+        Promise::perform_then(
+            &promise,
+            &on_fulfilled.into(),
+            &on_rejected.into(),
+            None,
+            ctx,
+        );
+        Ok(())
+    }
+
+    /// Resumes the async function with the value of the awaited promise.
+    fn fulfilled(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let context: AsyncFunctionContext = get_captures(captures);
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::step(&context, ResumeKind::Next, value, ctx)?;
+        Ok(Value::undefined())
+    }
+
+    /// Resumes the async function by throwing the reason of the awaited promise.
+    fn rejected(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let context: AsyncFunctionContext = get_captures(captures);
+        let reason = args.get(0).cloned().unwrap_or_default();
+        Self::step(&context, ResumeKind::Throw, reason, ctx)?;
+        Ok(Value::undefined())
+    }
+}
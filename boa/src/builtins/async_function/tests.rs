@@ -0,0 +1,159 @@
+use crate::{forward, Context};
+
+#[test]
+fn returns_promise() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        async function f() { return 1; }
+        var p = f();
+        p.then(function (value) { result = value; });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "p.constructor === Promise"), "true");
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "1");
+}
+
+#[test]
+fn await_suspends() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        async function f() {
+            log.push("start");
+            var value = await Promise.resolve(1);
+            log.push("resumed " + value);
+            value += await 2;
+            log.push("resumed " + value);
+        }
+        f();
+        log.push("sync");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "log.join()"), "\"start,sync\"");
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"start,sync,resumed 1,resumed 3\""
+    );
+}
+
+#[test]
+fn rejection_is_thrown() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        async function f() {
+            try {
+                await Promise.reject("error");
+            } catch (e) {
+                return "caught " + e;
+            }
+        }
+        f().then(function (value) { result = value; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"caught error\"");
+}
+
+#[test]
+fn throw_rejects() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        async function f() {
+            await null;
+            throw "error";
+        }
+        f().catch(function (reason) { result = reason; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"error\"");
+}
+
+#[test]
+fn await_in_loop() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        async function sum(values) {
+            var total = 0;
+            for (var i = 0; i < values.length; i++) {
+                total += await values[i];
+            }
+            return total;
+        }
+        sum([1, Promise.resolve(2), 3]).then(function (value) { result = value; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "6");
+}
+
+#[test]
+fn arrows_and_methods() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result = [];
+        var double = async x => (await x) * 2;
+        var obj = {
+            value: 5,
+            async method() { return this.value; }
+        };
+        class A {
+            constructor() { this.value = "class"; }
+            async method() { return this.value; }
+            static async create() { return new A(); }
+        }
+        double(Promise.resolve(21))
+            .then(function (value) { result.push(value); return obj.method(); })
+            .then(function (value) { result.push(value); return A.create(); })
+            .then(function (a) { return a.method(); })
+            .then(function (value) { result.push(value); });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result.join()"), "\"42,5,class\"");
+}
+
+#[test]
+fn not_a_constructor() {
+    let mut engine = Context::new();
+    let init = r#"
+        async function f() {}
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "f.prototype"), "undefined");
+    assert_eq!(
+        forward(&mut engine, "try { new f(); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn call_frame_after_await() {
+    let mut engine = Context::new();
+    let init = r#"
+        var stacks = [];
+        async function f() {
+            stacks.push(new Error().stack);
+            await null;
+            stacks.push(new Error().stack);
+        }
+        f();
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "stacks.map(stack => stack.split('\\n')[1]).join('|')"
+        ),
+        "\"    at f (<anonymous>:4:25)|    at f (<anonymous>:6:25)\""
+    );
+    assert_eq!(forward(&mut engine, "stacks[0].split('\\n').length"), "2");
+    assert!(engine.call_stack().is_empty());
+}
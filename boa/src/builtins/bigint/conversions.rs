@@ -1,6 +1,7 @@
 use super::BigInt;
 
 use crate::{builtins::Number, Context, Value};
+use num_integer::Integer;
 use num_traits::cast::{FromPrimitive, ToPrimitive};
 
 use std::convert::TryFrom;
@@ -45,6 +46,34 @@ impl BigInt {
         self.0.to_f64().unwrap_or(std::f64::INFINITY)
     }
 
+    /// Converts the BigInt to a i128 type.
+    ///
+    /// Returns `None` if the BigInt is out of range.
+    #[inline]
+    pub fn to_i128(&self) -> Option<i128> {
+        self.0.to_i128()
+    }
+
+    /// Converts the BigInt to a u128 type.
+    ///
+    /// Returns `None` if the BigInt is out of range.
+    #[inline]
+    pub fn to_u128(&self) -> Option<u128> {
+        self.0.to_u128()
+    }
+
+    /// Converts the BigInt to a u64, wrapping it modulo 2^64.
+    ///
+    /// These are the bits of both `ToBigInt64` and `ToBigUint64`.
+    #[inline]
+    pub(crate) fn to_u64_wrapping(&self) -> u64 {
+        let modulus = num_bigint::BigInt::from(u64::MAX) + 1;
+        self.0
+            .mod_floor(&modulus)
+            .to_u64()
+            .expect("value modulo 2^64 should fit in a u64")
+    }
+
     #[inline]
     pub(crate) fn from_str(string: &str) -> Option<Self> {
         match num_bigint::BigInt::from_str(string) {
@@ -66,6 +95,18 @@ impl From<i32> for BigInt {
     }
 }
 
+impl From<i128> for BigInt {
+    fn from(n: i128) -> BigInt {
+        BigInt(num_bigint::BigInt::from(n))
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(n: u128) -> BigInt {
+        BigInt(num_bigint::BigInt::from(n))
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TryFromF64Error;
 
@@ -59,11 +59,16 @@ pub(crate) fn logger(msg: LogMessage, console_state: &Console) {
 
 /// This represents the `console` formatter.
 pub fn formatter(data: &[Value], ctx: &mut Context) -> Result<String> {
-    let target = data.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+    let target = data
+        .get(0)
+        .cloned()
+        .unwrap_or_default()
+        .to_string(ctx)?
+        .to_std_string_lossy();
 
     match data.len() {
         0 => Ok(String::new()),
-        1 => Ok(target.to_string()),
+        1 => Ok(target),
         _ => {
             let mut formatted = String::new();
             let mut arg_index = 1;
@@ -105,7 +110,7 @@ pub fn formatter(data: &[Value], ctx: &mut Context) -> Result<String> {
                                 .cloned()
                                 .unwrap_or_default()
                                 .to_string(ctx)?;
-                            formatted.push_str(&arg);
+                            formatted.push_str(&arg.to_std_string_lossy());
                             arg_index += 1
                         }
                         '%' => formatted.push('%'),
@@ -459,7 +464,7 @@ impl Console {
             None => "default".into(),
         };
 
-        if let Some(t) = ctx.console_mut().timer_map.remove(label.as_str()) {
+        if let Some(t) = ctx.console_mut().timer_map.remove(&label) {
             let time = Self::system_time_in_ms();
             logger(
                 LogMessage::Info(format!("{}: {} ms - timer removed", label, time - t)),
@@ -0,0 +1,548 @@
+//! This module implements the global `DataView` object.
+//!
+//! A data view reads and writes numbers of any type at any offset of an `ArrayBuffer`, in
+//! either byte order.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-dataview-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
+
+use crate::{
+    builtins::{array_buffer::ArrayBuffer, typed_array::TypedArrayKind, BuiltIn},
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData},
+    property::{AccessorDescriptor, Attribute},
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The data of a `DataView` object, a view on a range of an `ArrayBuffer`.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DataView {
+    buffer: GcObject,
+    byte_offset: usize,
+    byte_length: usize,
+}
+
+impl BuiltIn for DataView {
+    const NAME: &'static str = "DataView";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let data_view_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().data_view_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::get_big_int64, "getBigInt64", 1)
+        .method(Self::get_big_uint64, "getBigUint64", 1)
+        .method(Self::get_float32, "getFloat32", 1)
+        .method(Self::get_float64, "getFloat64", 1)
+        .method(Self::get_int8, "getInt8", 1)
+        .method(Self::get_int16, "getInt16", 1)
+        .method(Self::get_int32, "getInt32", 1)
+        .method(Self::get_uint8, "getUint8", 1)
+        .method(Self::get_uint16, "getUint16", 1)
+        .method(Self::get_uint32, "getUint32", 1)
+        .method(Self::set_big_int64, "setBigInt64", 2)
+        .method(Self::set_big_uint64, "setBigUint64", 2)
+        .method(Self::set_float32, "setFloat32", 2)
+        .method(Self::set_float64, "setFloat64", 2)
+        .method(Self::set_int8, "setInt8", 2)
+        .method(Self::set_int16, "setInt16", 2)
+        .method(Self::set_int32, "setInt32", 2)
+        .method(Self::set_uint8, "setUint8", 2)
+        .method(Self::set_uint16, "setUint16", 2)
+        .method(Self::set_uint32, "setUint32", 2)
+        .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+        .callable(false)
+        .build();
+
+        let prototype = context.standard_objects().data_view_object().prototype();
+        let get_buffer = FunctionBuilder::new(context, Self::get_buffer)
+            .name("get buffer")
+            .callable(true)
+            .constructable(false)
+            .build();
+        let get_byte_length = FunctionBuilder::new(context, Self::get_byte_length)
+            .name("get byteLength")
+            .callable(true)
+            .constructable(false)
+            .build();
+        let get_byte_offset = FunctionBuilder::new(context, Self::get_byte_offset)
+            .name("get byteOffset")
+            .callable(true)
+            .constructable(false)
+            .build();
+        let mut prototype = prototype.borrow_mut();
+        prototype.insert(
+            "buffer",
+            AccessorDescriptor::new(Some(get_buffer), None, Attribute::CONFIGURABLE),
+        );
+        prototype.insert(
+            "byteLength",
+            AccessorDescriptor::new(Some(get_byte_length), None, Attribute::CONFIGURABLE),
+        );
+        prototype.insert(
+            "byteOffset",
+            AccessorDescriptor::new(Some(get_byte_offset), None, Attribute::CONFIGURABLE),
+        );
+
+        (Self::NAME, data_view_object.into(), Self::attribute())
+    }
+}
+
+impl DataView {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// The `ArrayBuffer` object the view is on.
+    #[inline]
+    pub fn buffer(&self) -> &GcObject {
+        &self.buffer
+    }
+
+    /// Returns `true` if the buffer of the view has been detached.
+    #[inline]
+    pub fn is_detached(&self) -> bool {
+        self.buffer
+            .borrow()
+            .as_array_buffer()
+            .map_or(true, ArrayBuffer::is_detached)
+    }
+
+    /// `DataView( buffer [ , byteOffset [ , byteLength ] ] )`
+    ///
+    /// Creates a new view on `buffer`, from `byteOffset` up to the end of the buffer unless
+    /// `byteLength` is given.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview-buffer-byteoffset-bytelength
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let buffer = match args.get(0) {
+            Some(Value::Object(ref buffer)) if buffer.borrow().is_array_buffer() => buffer.clone(),
+            _ => return ctx.throw_type_error("DataView: first argument must be an ArrayBuffer"),
+        };
+        let offset = args.get(1).cloned().unwrap_or_default().to_index(ctx)?;
+        let buffer_byte_length = match buffer.borrow().as_array_buffer() {
+            Some(data) if !data.is_detached() => Some(data.byte_length()),
+            _ => None,
+        };
+        let buffer_byte_length = match buffer_byte_length {
+            Some(buffer_byte_length) => buffer_byte_length,
+            None => return ctx.throw_type_error("DataView: ArrayBuffer is detached"),
+        };
+        if offset > buffer_byte_length {
+            return ctx.throw_range_error(format!(
+                "DataView: start offset {} is outside the bounds of the buffer",
+                offset
+            ));
+        }
+
+        let byte_length = match args.get(2) {
+            None | Some(Value::Undefined) => buffer_byte_length - offset,
+            Some(byte_length) => {
+                let byte_length = byte_length.to_index(ctx)?;
+                if offset.saturating_add(byte_length) > buffer_byte_length {
+                    return ctx.throw_range_error(format!(
+                        "DataView: invalid byte length {}",
+                        byte_length
+                    ));
+                }
+                byte_length
+            }
+        };
+        // Converting the arguments could have detached the buffer.
+        if buffer
+            .borrow()
+            .as_array_buffer()
+            .map_or(true, ArrayBuffer::is_detached)
+        {
+            return ctx.throw_type_error("DataView: ArrayBuffer is detached");
+        }
+
+        this.set_data(ObjectData::DataView(Self {
+            buffer,
+            byte_offset: offset,
+            byte_length,
+        }));
+        Ok(this.clone())
+    }
+
+    /// Helper function to get the data of `this`, if it is a data view.
+    fn this_data_view(this: &Value, ctx: &mut Context) -> Result<Self> {
+        if let Value::Object(ref object) = this {
+            if let Some(data_view) = object.borrow().as_data_view() {
+                return Ok(data_view.clone());
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a DataView"))
+    }
+
+    /// `get DataView.prototype.buffer`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.buffer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer
+    pub(crate) fn get_buffer(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(Self::this_data_view(this, ctx)?.buffer.clone().into())
+    }
+
+    /// `get DataView.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength
+    pub(crate) fn get_byte_length(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let data_view = Self::this_data_view(this, ctx)?;
+        if data_view.is_detached() {
+            return ctx.throw_type_error("DataView: ArrayBuffer is detached");
+        }
+        Ok(data_view.byte_length.into())
+    }
+
+    /// `get DataView.prototype.byteOffset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.byteoffset
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteOffset
+    pub(crate) fn get_byte_offset(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let data_view = Self::this_data_view(this, ctx)?;
+        if data_view.is_detached() {
+            return ctx.throw_type_error("DataView: ArrayBuffer is detached");
+        }
+        Ok(data_view.byte_offset.into())
+    }
+
+    /// Returns the index in the buffer of an element of type `kind` at `request_index` of the
+    /// view, checking that the buffer isn't detached and that the element is in bounds.
+    fn get_view_index(
+        &self,
+        request_index: usize,
+        kind: TypedArrayKind,
+        ctx: &mut Context,
+    ) -> Result<usize> {
+        if self.is_detached() {
+            return Err(ctx.construct_type_error("DataView: ArrayBuffer is detached"));
+        }
+        if request_index.saturating_add(kind.element_size()) > self.byte_length {
+            return Err(ctx.construct_range_error("Offset is outside the bounds of the DataView"));
+        }
+        Ok(self.byte_offset + request_index)
+    }
+
+    /// `GetViewValue( view, requestIndex, isLittleEndian, type )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewvalue
+    fn get_view_value(
+        this: &Value,
+        args: &[Value],
+        kind: TypedArrayKind,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let data_view = Self::this_data_view(this, ctx)?;
+        let request_index = args.get(0).cloned().unwrap_or_default().to_index(ctx)?;
+        let little_endian = args.get(1).map_or(false, Value::to_boolean);
+        let byte_index = data_view.get_view_index(request_index, kind, ctx)?;
+
+        let buffer = data_view.buffer.borrow();
+        let buffer = buffer
+            .as_array_buffer()
+            .expect("DataView buffer should be an ArrayBuffer");
+        Ok(buffer.get_value_from_buffer(byte_index, kind, little_endian))
+    }
+
+    /// `SetViewValue( view, requestIndex, isLittleEndian, type, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setviewvalue
+    fn set_view_value(
+        this: &Value,
+        args: &[Value],
+        kind: TypedArrayKind,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let data_view = Self::this_data_view(this, ctx)?;
+        let request_index = args.get(0).cloned().unwrap_or_default().to_index(ctx)?;
+        let raw = kind.to_raw(&args.get(1).cloned().unwrap_or_default(), ctx)?;
+        let little_endian = args.get(2).map_or(false, Value::to_boolean);
+        let byte_index = data_view.get_view_index(request_index, kind, ctx)?;
+
+        let mut buffer = data_view.buffer.borrow_mut();
+        let buffer = buffer
+            .as_array_buffer_mut()
+            .expect("DataView buffer should be an ArrayBuffer");
+        buffer.set_value_in_buffer(byte_index, kind, raw, little_endian);
+        Ok(Value::undefined())
+    }
+
+    /// `DataView.prototype.getBigInt64( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getbigint64
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getBigInt64
+    pub(crate) fn get_big_int64(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::BigInt64, ctx)
+    }
+
+    /// `DataView.prototype.getBigUint64( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getbiguint64
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getBigUint64
+    pub(crate) fn get_big_uint64(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::BigUint64, ctx)
+    }
+
+    /// `DataView.prototype.getFloat32( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getfloat32
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat32
+    pub(crate) fn get_float32(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Float32, ctx)
+    }
+
+    /// `DataView.prototype.getFloat64( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getfloat64
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat64
+    pub(crate) fn get_float64(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Float64, ctx)
+    }
+
+    /// `DataView.prototype.getInt8( byteOffset )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getint8
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt8
+    pub(crate) fn get_int8(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Int8, ctx)
+    }
+
+    /// `DataView.prototype.getInt16( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getint16
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt16
+    pub(crate) fn get_int16(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Int16, ctx)
+    }
+
+    /// `DataView.prototype.getInt32( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getint32
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt32
+    pub(crate) fn get_int32(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Int32, ctx)
+    }
+
+    /// `DataView.prototype.getUint8( byteOffset )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getuint8
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint8
+    pub(crate) fn get_uint8(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Uint8, ctx)
+    }
+
+    /// `DataView.prototype.getUint16( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getuint16
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint16
+    pub(crate) fn get_uint16(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Uint16, ctx)
+    }
+
+    /// `DataView.prototype.getUint32( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getuint32
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint32
+    pub(crate) fn get_uint32(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Uint32, ctx)
+    }
+
+    /// `DataView.prototype.setBigInt64( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setbigint64
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setBigInt64
+    pub(crate) fn set_big_int64(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::BigInt64, ctx)
+    }
+
+    /// `DataView.prototype.setBigUint64( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setbiguint64
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setBigUint64
+    pub(crate) fn set_big_uint64(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::BigUint64, ctx)
+    }
+
+    /// `DataView.prototype.setFloat32( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setfloat32
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat32
+    pub(crate) fn set_float32(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Float32, ctx)
+    }
+
+    /// `DataView.prototype.setFloat64( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setfloat64
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat64
+    pub(crate) fn set_float64(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Float64, ctx)
+    }
+
+    /// `DataView.prototype.setInt8( byteOffset, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setint8
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt8
+    pub(crate) fn set_int8(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Int8, ctx)
+    }
+
+    /// `DataView.prototype.setInt16( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setint16
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt16
+    pub(crate) fn set_int16(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Int16, ctx)
+    }
+
+    /// `DataView.prototype.setInt32( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setint32
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt32
+    pub(crate) fn set_int32(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Int32, ctx)
+    }
+
+    /// `DataView.prototype.setUint8( byteOffset, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setuint8
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint8
+    pub(crate) fn set_uint8(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Uint8, ctx)
+    }
+
+    /// `DataView.prototype.setUint16( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setuint16
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint16
+    pub(crate) fn set_uint16(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Uint16, ctx)
+    }
+
+    /// `DataView.prototype.setUint32( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setuint32
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint32
+    pub(crate) fn set_uint32(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Uint32, ctx)
+    }
+}
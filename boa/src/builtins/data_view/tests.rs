@@ -0,0 +1,92 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct() {
+    let mut engine = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(16);
+        var view = new DataView(buffer, 4, 8);
+        var rest = new DataView(buffer, 10);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "view.buffer === buffer"), "true");
+    assert_eq!(forward(&mut engine, "view.byteOffset"), "4");
+    assert_eq!(forward(&mut engine, "view.byteLength"), "8");
+    assert_eq!(forward(&mut engine, "rest.byteLength"), "6");
+    assert_eq!(
+        forward(&mut engine, "try { new DataView({}) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new DataView(buffer, 17) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new DataView(buffer, 8, 9) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn endianness() {
+    let mut engine = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var view = new DataView(buffer);
+        var bytes = new Uint8Array(buffer);
+        view.setUint16(0, 0x1234);
+        view.setUint16(2, 0x1234, true);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "bytes[0]"), "18");
+    assert_eq!(forward(&mut engine, "bytes[1]"), "52");
+    assert_eq!(forward(&mut engine, "bytes[2]"), "52");
+    assert_eq!(forward(&mut engine, "bytes[3]"), "18");
+    assert_eq!(forward(&mut engine, "view.getUint16(2)"), "13330");
+    assert_eq!(forward(&mut engine, "view.getUint16(2, true)"), "4660");
+}
+
+#[test]
+fn get_set() {
+    let mut engine = Context::new();
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(8));
+        "#;
+    forward(&mut engine, init);
+    forward(&mut engine, "view.setInt8(0, -1)");
+    assert_eq!(forward(&mut engine, "view.getInt8(0)"), "-1");
+    assert_eq!(forward(&mut engine, "view.getUint8(0)"), "255");
+    forward(&mut engine, "view.setInt32(0, -2)");
+    assert_eq!(forward(&mut engine, "view.getInt32(0)"), "-2");
+    assert_eq!(forward(&mut engine, "view.getUint32(0)"), "4294967294");
+    forward(&mut engine, "view.setFloat32(0, 1.5, true)");
+    assert_eq!(forward(&mut engine, "view.getFloat32(0, true)"), "1.5");
+    forward(&mut engine, "view.setFloat64(0, Math.PI)");
+    assert_eq!(
+        forward(&mut engine, "view.getFloat64(0) === Math.PI"),
+        "true"
+    );
+    forward(&mut engine, "view.setBigInt64(0, -5n)");
+    assert_eq!(forward(&mut engine, "view.getBigInt64(0)"), "-5n");
+    assert_eq!(
+        forward(&mut engine, "view.getBigUint64(0)"),
+        "18446744073709551611n"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { view.getInt32(5) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { view.setBigInt64(0, 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
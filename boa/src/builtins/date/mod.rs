@@ -384,10 +384,12 @@ impl Date {
         let tv = match this_time_value(value, ctx) {
             Ok(dt) => dt.0,
             _ => match value.to_primitive(ctx, PreferredType::Default)? {
-                Value::String(ref str) => match chrono::DateTime::parse_from_rfc3339(&str) {
-                    Ok(dt) => Some(dt.naive_utc()),
-                    _ => None,
-                },
+                Value::String(ref str) => {
+                    match chrono::DateTime::parse_from_rfc3339(&str.to_std_string_lossy()) {
+                        Ok(dt) => Some(dt.naive_utc()),
+                        _ => None,
+                    }
+                }
                 tv => {
                     let tv = tv.to_number(ctx)?;
                     let secs = (tv / 1_000f64) as i64;
@@ -1265,7 +1267,7 @@ impl Date {
             return Ok(Value::number(f64::NAN));
         }
 
-        match DateTime::parse_from_rfc3339(&args[0].to_string(ctx)?) {
+        match DateTime::parse_from_rfc3339(&args[0].to_string(ctx)?.to_std_string_lossy()) {
             Ok(v) => Ok(Value::number(v.naive_utc().timestamp_millis() as f64)),
             _ => Ok(Value::number(f64::NAN)),
         }
@@ -0,0 +1,104 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! The `AggregateError` object represents several errors wrapped in a single error, like the
+//! rejection reasons of the promises passed to `Promise.any()`.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{error::Error, iterable::get_iterator, Array, BuiltIn},
+    object::{ConstructorBuilder, Object},
+    profiler::BoaProfiler,
+    property::Attribute,
+    Context, Result, Value,
+};
+
+/// JavaScript `AggregateError` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl BuiltIn for AggregateError {
+    const NAME: &'static str = "AggregateError";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let error_prototype = context.standard_objects().error_object().prototype();
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let aggregate_error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().aggregate_error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(error_prototype.into())
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .build();
+
+        (Self::NAME, aggregate_error_object.into(), Self::attribute())
+    }
+}
+
+impl AggregateError {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// Create a new error object.
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        if let Some(message) = args.get(1) {
+            if !message.is_undefined() {
+                this.set_field("message", message.to_string(ctx)?, ctx)?;
+            }
+        }
+
+        let iterator = get_iterator(ctx, args.get(0).cloned().unwrap_or_default())?;
+        let mut errors = Vec::new();
+        loop {
+            let next = iterator.next(ctx)?;
+            if next.is_done() {
+                break;
+            }
+            errors.push(next.value());
+        }
+        Self::set_errors(this, &errors, ctx)?;
+
+        Error::capture_stack(this, 1, ctx)?;
+        Ok(this.clone())
+    }
+
+    /// Creates a new `AggregateError` holding `errors`, with the given message.
+    pub(crate) fn create(errors: &[Value], message: &str, ctx: &mut Context) -> Result<Value> {
+        let prototype = ctx.standard_objects().aggregate_error_object().prototype();
+        let error: Value = Object::create(prototype.into()).into();
+        error.set_field("message", message, ctx)?;
+        Error::capture_stack(&error, 0, ctx)?;
+        Self::set_errors(&error, errors, ctx)?;
+        Ok(error)
+    }
+
+    /// Defines the `errors` property of an `AggregateError`.
+    fn set_errors(error: &Value, errors: &[Value], ctx: &mut Context) -> Result<()> {
+        let array = Array::new_array(ctx)?;
+        Array::construct_array(&array, errors, ctx)?;
+        error
+            .as_object_mut()
+            .expect("error object")
+            .insert_property(
+                "errors",
+                array,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+        Ok(())
+    }
+}
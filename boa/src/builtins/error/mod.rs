@@ -12,12 +12,15 @@
 
 use crate::{
     builtins::BuiltIn,
+    exec::CallFrame,
     object::{ConstructorBuilder, ObjectData},
     profiler::BoaProfiler,
-    property::Attribute,
+    property::{Attribute, DataDescriptor},
+    value::RcString,
     Context, Result, Value,
 };
 
+pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
@@ -28,6 +31,7 @@ pub(crate) mod uri;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use self::aggregate::AggregateError;
 pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
@@ -75,13 +79,41 @@ impl Error {
     /// Create a new error object.
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         if let Some(message) = args.get(0) {
-            this.set_field("message", message.to_string(ctx)?);
+            this.set_field("message", message.to_string(ctx)?, ctx)?;
+        }
+
+        Self::capture_stack(this, 1, ctx)?;
+        Ok(this.clone())
+    }
+
+    /// Records the frames of the call stack in the error object `this`, innermost first, and
+    /// sets its `stack` property, which lists them after the result of `toString()`.
+    ///
+    /// The `skip` innermost frames are left out. The error constructors pass `1`, so that their
+    /// own frame isn't part of the stack, while errors created without calling a constructor
+    /// pass `0`.
+    pub(crate) fn capture_stack(this: &Value, skip: usize, ctx: &mut Context) -> Result<()> {
+        if !this.is_object() {
+            return Ok(());
+        }
+        let frames: Vec<CallFrame> = ctx.call_stack().iter().rev().skip(skip).cloned().collect();
+
+        let mut stack = Self::to_string(this, &[], ctx)?.to_string(ctx)?.to_string();
+        for frame in &frames {
+            stack.push_str(&format!("\n    at {}", frame));
         }
 
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
-        Ok(this.clone())
+        this.set_data(ObjectData::Error(frames));
+        this.set_property(
+            "stack",
+            DataDescriptor::new(
+                stack,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+        Ok(())
     }
 
     /// `Error.prototype.toString()`
@@ -99,22 +131,18 @@ impl Error {
         if !this.is_object() {
             return context.throw_type_error("'this' is not an Object");
         }
-        let name = this.get_field("name");
-        let name_to_string;
+        let name = this.get_field("name", context)?;
         let name = if name.is_undefined() {
-            "Error"
+            RcString::from("Error")
         } else {
-            name_to_string = name.to_string(context)?;
-            name_to_string.as_str()
+            name.to_string(context)?
         };
 
-        let message = this.get_field("message");
-        let message_to_string;
+        let message = this.get_field("message", context)?;
         let message = if message.is_undefined() {
-            ""
+            RcString::default()
         } else {
-            message_to_string = message.to_string(context)?;
-            message_to_string.as_str()
+            message.to_string(context)?
         };
 
         if name.is_empty() {
@@ -122,7 +150,7 @@ impl Error {
         } else if message.is_empty() {
             Ok(name.into())
         } else {
-            Ok(format!("{}: {}", name, message).into())
+            Ok(name.concat(&RcString::from(": ")).concat(&message).into())
         }
     }
 }
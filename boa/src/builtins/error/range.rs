@@ -10,8 +10,8 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError
 
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData},
+    builtins::{error::Error, BuiltIn},
+    object::ConstructorBuilder,
     profiler::BoaProfiler,
     property::Attribute,
     Context, Result, Value,
@@ -56,12 +56,10 @@ impl RangeError {
     /// Create a new error object.
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         if let Some(message) = args.get(0) {
-            this.set_field("message", message.to_string(ctx)?);
+            this.set_field("message", message.to_string(ctx)?, ctx)?;
         }
 
-        // This value is used by console.log and other routines to match Object type
-        // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
+        Error::capture_stack(this, 1, ctx)?;
         Ok(this.clone())
     }
 }
@@ -78,3 +78,83 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn aggregate_error() {
+    let mut ctx = Context::new();
+    let init = r#"
+        let aggregate = new AggregateError([1, "two"], "message");
+    "#;
+    forward(&mut ctx, init);
+    assert_eq!(forward(&mut ctx, "aggregate.name"), "\"AggregateError\"");
+    assert_eq!(forward(&mut ctx, "aggregate.message"), "\"message\"");
+    assert_eq!(forward(&mut ctx, "aggregate.errors.join()"), "\"1,two\"");
+    assert_eq!(
+        forward(&mut ctx, "aggregate.toString()"),
+        "\"AggregateError: message\""
+    );
+}
+
+#[test]
+fn error_stack() {
+    let mut ctx = Context::new();
+    let init = r#"
+        function inner() {
+            return new RangeError("deep");
+        }
+        function outer() {
+            return inner();
+        }
+        var e = outer();
+        "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(
+        forward(&mut ctx, "e.stack"),
+        "\"RangeError: deep\n    at inner (<anonymous>:3:20)\n    at outer (<anonymous>:6:20)\""
+    );
+    assert_eq!(
+        forward(
+            &mut ctx,
+            "Object.getOwnPropertyDescriptor(e, 'stack').enumerable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn error_stack_script_name() {
+    let mut ctx = Context::new();
+    let init = "function f() {\n    null.x;\n}\n[0].forEach(f);";
+
+    let error = ctx.eval_script(init, "main.js").unwrap_err();
+    let stack = error.get_field("stack", &mut ctx).unwrap();
+
+    assert_eq!(
+        stack.as_string().unwrap().to_string(),
+        "TypeError: cannot convert 'null' or 'undefined' to object\n    at f (main.js:2:5)\n    at \
+         forEach (<anonymous>)\n    at main.js:4:1"
+    );
+    assert!(ctx.call_stack().is_empty());
+}
+
+#[test]
+fn error_stack_frames() {
+    let mut ctx = Context::new();
+
+    let error = ctx
+        .eval_script(
+            "function thrower() { throw new Error(); }\nthrower();",
+            "a.js",
+        )
+        .unwrap_err();
+    let error = error.as_object().unwrap();
+    let frames = error.as_error().unwrap();
+
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[0].function_name().unwrap(), "thrower");
+    assert_eq!(frames[0].script_name().unwrap(), "a.js");
+    assert_eq!(frames[0].position().unwrap().column_number(), 28);
+    assert_eq!(frames[1].function_name(), None);
+    assert_eq!(frames[1].position().unwrap().line_number(), 2);
+}
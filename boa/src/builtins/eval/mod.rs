@@ -0,0 +1,100 @@
+//! This module implements the global `eval` function.
+//!
+//! The `eval()` function evaluates JavaScript code represented as a string.
+//!
+//! A direct call, like `eval(code)`, evaluates the code in the scope of the caller, while any
+//! other call, like `(0, eval)(code)`, evaluates it in the global scope.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-eval-x
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+
+use crate::{
+    builtins::BuiltIn, environment::lexical_environment::new_declarative_environment,
+    object::FunctionBuilder, property::Attribute, syntax::Parser, BoaProfiler, Context, Executable,
+    Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `eval` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Eval;
+
+impl BuiltIn for Eval {
+    const NAME: &'static str = "eval";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let eval = FunctionBuilder::new(context, Self::eval)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+        context.set_eval_function(eval.clone());
+
+        (Self::NAME, eval.into(), Self::attribute())
+    }
+}
+
+impl Eval {
+    /// `eval( x )`
+    ///
+    /// This is only called for indirect calls, direct calls are recognized by the `Call` node.
+    fn eval(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::perform_eval(&args.get(0).cloned().unwrap_or_default(), false, ctx)
+    }
+
+    /// Evaluates `x` if it is a string, in the current scope if `direct` is `true`, or in the
+    /// global scope otherwise. Any other value is returned as is.
+    ///
+    /// Declarations with `let`, `const` and `class` are local to the evaluated code, while the
+    /// ones with `var` and `function` are added to the enclosing function or global scope.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn perform_eval(x: &Value, direct: bool, ctx: &mut Context) -> Result<Value> {
+        let source = match x {
+            Value::String(ref source) => source.to_std_string_lossy(),
+            _ => return Ok(x.clone()),
+        };
+
+        let statement_list = match Parser::new(source.as_bytes()).parse_all() {
+            Ok(statement_list) => statement_list,
+            Err(e) => return ctx.throw_syntax_error(e.to_string()),
+        };
+
+        // Only the global environment is kept for an indirect eval.
+        let environments = if direct {
+            Vec::new()
+        } else {
+            ctx.realm_mut().environment.split_off(1)
+        };
+        let outer = ctx
+            .realm()
+            .environment
+            .get_current_environment_ref()
+            .clone();
+        ctx.realm_mut()
+            .environment
+            .push(new_declarative_environment(Some(outer)));
+
+        let result = statement_list.run(ctx);
+
+        ctx.realm_mut().environment.pop();
+        ctx.realm_mut().environment.restore(environments);
+        result
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{forward, Context};
+
+#[test]
+fn direct_eval_sees_local_scope() {
+    let mut engine = Context::new();
+    let init = r#"
+        var x = "global";
+        function f(x) {
+            return eval("x");
+        }
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "f('local')"), "\"local\"");
+}
+
+#[test]
+fn indirect_eval_uses_global_scope() {
+    let mut engine = Context::new();
+    let init = r#"
+        var x = "global";
+        function f(x) {
+            return (0, eval)("x");
+        }
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "f('local')"), "\"global\"");
+}
+
+#[test]
+fn eval_declarations() {
+    let mut engine = Context::new();
+    forward(&mut engine, "eval('var a = 1; let b = 2;')");
+
+    assert_eq!(forward(&mut engine, "a"), "1");
+    assert_eq!(
+        forward(&mut engine, "try { b } catch (e) { e.name }"),
+        "\"ReferenceError\""
+    );
+}
+
+#[test]
+fn eval_result() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "eval('1 + 2')"), "3");
+    assert_eq!(forward(&mut engine, "eval(42)"), "42");
+    assert_eq!(forward(&mut engine, "var o = {}; eval(o) === o"), "true");
+    assert_eq!(forward(&mut engine, "eval()"), "undefined");
+    assert_eq!(forward(&mut engine, "typeof eval"), "\"function\"");
+    assert_eq!(forward(&mut engine, "eval.length"), "1");
+}
+
+#[test]
+fn eval_syntax_error() {
+    let mut engine = Context::new();
+    let scenario = r#"
+        try {
+            eval("1 +");
+        } catch (e) {
+            e.name;
+        }
+        "#;
+
+    assert_eq!(forward(&mut engine, scenario), "\"SyntaxError\"");
+}
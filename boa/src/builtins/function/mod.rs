@@ -14,14 +14,21 @@
 use crate::{
     builtins::{Array, BuiltIn},
     environment::lexical_environment::Environment,
-    object::{ConstructorBuilder, FunctionBuilder, Object, ObjectData, PROTOTYPE},
+    exec::Executable,
+    object::{
+        ConstructorBuilder, FunctionBuilder, GcObject, NativeObject, Object, ObjectData, PROTOTYPE,
+    },
     property::{Attribute, DataDescriptor},
-    syntax::ast::node::{FormalParameter, RcStatementList},
+    syntax::ast::node::{pattern::BindingKind, FormalParameter, RcStatementList},
+    value::RcString,
     BoaProfiler, Context, Result, Value,
 };
 use bitflags::bitflags;
-use gc::{unsafe_empty_trace, Finalize, Trace};
-use std::fmt::{self, Debug};
+use gc::{custom_trace, unsafe_empty_trace, Finalize, Gc, GcCell, Trace};
+use std::{
+    fmt::{self, Debug},
+    rc::Rc,
+};
 
 #[cfg(test)]
 mod tests;
@@ -48,12 +55,150 @@ impl Debug for BuiltInFunction {
     }
 }
 
+/// The state captured by a [`CapturingFunction`](struct.CapturingFunction.html).
+///
+/// The same captures can be shared by several functions, like the `resolve` and `reject`
+/// functions of a promise.
+pub type Captures = Gc<GcCell<Box<dyn NativeObject>>>;
+
+/// _fn(this, arguments, captures, ctx) -> ResultValue_ - The signature of a built-in function
+/// that captures some state.
+pub type CapturingNativeFunction = fn(&Value, &[Value], &Captures, &mut Context) -> Result<Value>;
+
+/// _Fn(this, arguments, ctx) -> ResultValue_ - A Rust closure that can be used as a built-in
+/// function.
+///
+/// Closures can capture Rust state, like handles to host resources. The captured variables are
+/// not traced by the garbage collector, so garbage collected values like `Value` should not be
+/// captured directly, as they would never be collected. Use
+/// [`FunctionBuilder::closure_with_captures`](../../object/struct.FunctionBuilder.html#method.closure_with_captures)
+/// to capture them instead.
+pub trait ClosureFunction: Fn(&Value, &[Value], &mut Context) -> Result<Value> + 'static {}
+
+impl<T> ClosureFunction for T where T: Fn(&Value, &[Value], &mut Context) -> Result<Value> + 'static {}
+
+/// The body of a [`CapturingFunction`](struct.CapturingFunction.html).
+type CapturingClosure = dyn Fn(&Value, &[Value], &Captures, &mut Context) -> Result<Value>;
+
+/// A built-in function created at runtime, together with the state it captured.
+#[derive(Clone, Finalize)]
+pub struct CapturingFunction {
+    function: Rc<CapturingClosure>,
+    captures: Captures,
+}
+
+unsafe impl Trace for CapturingFunction {
+    custom_trace!(this, {
+        mark(&this.captures);
+    });
+}
+
+impl Debug for CapturingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[native]")
+    }
+}
+
+impl CapturingFunction {
+    /// Creates a new `CapturingFunction`.
+    pub(crate) fn new(function: CapturingNativeFunction, captures: Captures) -> Self {
+        Self {
+            function: Rc::new(function),
+            captures,
+        }
+    }
+
+    /// Creates a new `CapturingFunction` from a closure that captures no garbage collected state.
+    pub(crate) fn from_closure<F>(function: F) -> Self
+    where
+        F: ClosureFunction,
+    {
+        Self {
+            function: Rc::new(move |this, args, _, ctx| function(this, args, ctx)),
+            captures: new_captures(()),
+        }
+    }
+
+    /// Creates a new `CapturingFunction` from a closure that is given a reference to `captures`
+    /// each time it is called.
+    pub(crate) fn from_closure_with_captures<F, C>(function: F, captures: C) -> Self
+    where
+        F: Fn(&Value, &[Value], &C, &mut Context) -> Result<Value> + 'static,
+        C: NativeObject,
+    {
+        Self {
+            function: Rc::new(move |this, args, captures, ctx| {
+                let captures = captures.borrow();
+                let state: &dyn NativeObject = &**captures;
+                let state = state
+                    .as_any()
+                    .downcast_ref::<C>()
+                    .expect("the captures of a closure should have the expected type");
+                function(this, args, state, ctx)
+            }),
+            captures: new_captures(captures),
+        }
+    }
+
+    /// Calls the function with its captured state.
+    pub(crate) fn call(&self, this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        (self.function)(this, args, &self.captures, ctx)
+    }
+}
+
+/// Creates new captures holding `state`.
+pub(crate) fn new_captures<T: NativeObject>(state: T) -> Captures {
+    Gc::new(GcCell::new(Box::new(state)))
+}
+
+/// Returns a copy of the state held by `captures`.
+///
+/// # Panics
+///
+/// Panics if the captures do not hold a `T`.
+pub(crate) fn get_captures<T: NativeObject + Clone>(captures: &Captures) -> T {
+    let captures = captures.borrow();
+    let state: &dyn NativeObject = &**captures;
+    state
+        .as_any()
+        .downcast_ref::<T>()
+        .expect("the captures of a function should have the expected type")
+        .clone()
+}
+
+/// Calls `f` with a mutable reference to the state held by `captures`.
+///
+/// # Panics
+///
+/// Panics if the captures do not hold a `T`.
+pub(crate) fn update_captures<T: NativeObject, R>(
+    captures: &Captures,
+    f: impl FnOnce(&mut T) -> R,
+) -> R {
+    let mut captures = captures.borrow_mut();
+    let state: &mut dyn NativeObject = &mut **captures;
+    let state = state
+        .as_mut_any()
+        .downcast_mut::<T>()
+        .expect("the captures of a function should have the expected type");
+    f(state)
+}
+
+/// Replaces the state held by `captures`.
+pub(crate) fn set_captures<T: NativeObject>(captures: &Captures, state: T) {
+    *captures.borrow_mut() = Box::new(state);
+}
+
 bitflags! {
     #[derive(Finalize, Default)]
     pub struct FunctionFlags: u8 {
         const CALLABLE = 0b0000_0001;
         const CONSTRUCTABLE = 0b0000_0010;
         const LEXICAL_THIS_MODE = 0b0000_0100;
+        const CLASS_CONSTRUCTOR = 0b0000_1000;
+        const DERIVED = 0b0001_0000;
+        const GENERATOR = 0b0010_0000;
+        const ASYNC = 0b0100_0000;
     }
 }
 
@@ -85,6 +230,26 @@ impl FunctionFlags {
     pub(crate) fn is_lexical_this_mode(&self) -> bool {
         self.contains(Self::LEXICAL_THIS_MODE)
     }
+
+    #[inline]
+    pub(crate) fn is_class_constructor(&self) -> bool {
+        self.contains(Self::CLASS_CONSTRUCTOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_derived(&self) -> bool {
+        self.contains(Self::DERIVED)
+    }
+
+    #[inline]
+    pub(crate) fn is_generator(&self) -> bool {
+        self.contains(Self::GENERATOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_async(&self) -> bool {
+        self.contains(Self::ASYNC)
+    }
 }
 
 unsafe impl Trace for FunctionFlags {
@@ -99,61 +264,59 @@ unsafe impl Trace for FunctionFlags {
 #[derive(Debug, Clone, Finalize, Trace)]
 pub enum Function {
     BuiltIn(BuiltInFunction, FunctionFlags),
+    Capturing(CapturingFunction, FunctionFlags),
     Ordinary {
         flags: FunctionFlags,
         body: RcStatementList,
         params: Box<[FormalParameter]>,
         environment: Environment,
+        /// The object whose prototype `super` property lookups start from.
+        home_object: Option<GcObject>,
+        /// The source text of the function, returned by `Function.prototype.toString()`.
+        source: Option<RcString>,
+        /// The name of the script the function was defined in.
+        script_name: Option<RcString>,
     },
 }
 
 impl Function {
-    // Adds the final rest parameters to the Environment as an array
-    pub(crate) fn add_rest_param(
-        &self,
-        param: &FormalParameter,
-        index: usize,
-        args_list: &[Value],
-        interpreter: &mut Context,
-        local_env: &Environment,
-    ) {
-        // Create array of values
-        let array = Array::new_array(interpreter).unwrap();
-        Array::add_to_array_object(&array, &args_list[index..]).unwrap();
-
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
-
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), array);
-    }
-
-    // Adds an argument to the environment
-    pub(crate) fn add_arguments_to_environment(
-        &self,
-        param: &FormalParameter,
-        value: Value,
-        local_env: &Environment,
-    ) {
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
-
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), value);
+    /// Binds the arguments of a call to the parameters of a function, evaluating the default
+    /// values of missing arguments and destructuring patterns.
+    ///
+    /// The function environment must be the current environment.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
+    pub(crate) fn bind_parameters(
+        params: &[FormalParameter],
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<()> {
+        for (i, param) in params.iter().enumerate() {
+            // Rest Parameters
+            if param.is_rest_param() {
+                let array = Array::new_array(ctx)?;
+                Array::add_to_array_object(&array, args.get(i..).unwrap_or_default(), ctx)?;
+                param.binding().bind(array, BindingKind::Let, ctx)?;
+                break;
+            }
+
+            let value = match (args.get(i), param.init()) {
+                (Some(value), _) if !value.is_undefined() => value.clone(),
+                (_, Some(init)) => init.run(ctx)?,
+                _ => Value::undefined(),
+            };
+            param.binding().bind(value, BindingKind::Let, ctx)?;
+        }
+        Ok(())
     }
 
     /// Returns true if the function object is callable.
     pub fn is_callable(&self) -> bool {
         match self {
-            Self::BuiltIn(_, flags) => flags.is_callable(),
+            Self::BuiltIn(_, flags) | Self::Capturing(_, flags) => flags.is_callable(),
             Self::Ordinary { flags, .. } => flags.is_callable(),
         }
     }
@@ -161,10 +324,61 @@ impl Function {
     /// Returns true if the function object is constructable.
     pub fn is_constructable(&self) -> bool {
         match self {
-            Self::BuiltIn(_, flags) => flags.is_constructable(),
+            Self::BuiltIn(_, flags) | Self::Capturing(_, flags) => flags.is_constructable(),
             Self::Ordinary { flags, .. } => flags.is_constructable(),
         }
     }
+
+    /// Sets the `[[HomeObject]]` of an ordinary function.
+    ///
+    /// This has no effect on built-in functions.
+    pub(crate) fn set_home_object(&mut self, object: GcObject) {
+        if let Self::Ordinary { home_object, .. } = self {
+            *home_object = Some(object);
+        }
+    }
+}
+
+/// Boa representation of a bound function exotic object.
+///
+/// A bound function wraps a target function, calling it with a fixed `this` value and a list
+/// of arguments that are prepended to the arguments of each call.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct BoundFunction {
+    target_function: GcObject,
+    this: Value,
+    args: Vec<Value>,
+}
+
+impl BoundFunction {
+    /// Creates a new bound function of `target_function`.
+    pub fn new(target_function: GcObject, this: Value, args: Vec<Value>) -> Self {
+        Self {
+            target_function,
+            this,
+            args,
+        }
+    }
+
+    /// The wrapped function object, `[[BoundTargetFunction]]`.
+    pub fn target_function(&self) -> &GcObject {
+        &self.target_function
+    }
+
+    /// The `this` value passed to the target function, `[[BoundThis]]`.
+    pub fn this(&self) -> &Value {
+        &self.this
+    }
+
+    /// The arguments prepended to each call, `[[BoundArguments]]`.
+    pub fn args(&self) -> &[Value] {
+        &self.args
+    }
 }
 
 /// Arguments.
@@ -203,10 +417,10 @@ pub fn make_constructor_fn(
     name: &str,
     length: usize,
     body: NativeFunction,
-    global: &Value,
     prototype: Value,
     constructable: bool,
     callable: bool,
+    context: &Context,
 ) -> Value {
     let _timer =
         BoaProfiler::global().start_event(&format!("make_constructor_fn: {}", name), "init");
@@ -219,8 +433,14 @@ pub fn make_constructor_fn(
 
     // Get reference to Function.prototype
     // Create the function object and point its instance prototype to Function.prototype
-    let mut constructor =
-        Object::function(function, global.get_field("Function").get_field(PROTOTYPE));
+    let mut constructor = Object::function(
+        function,
+        context
+            .standard_objects()
+            .function_object()
+            .prototype()
+            .into(),
+    );
 
     let length = DataDescriptor::new(
         length,
@@ -283,9 +503,10 @@ pub fn make_builtin_fn<N>(
     let mut function = Object::function(
         Function::BuiltIn(function.into(), FunctionFlags::CALLABLE),
         interpreter
-            .global_object()
-            .get_field("Function")
-            .get_field("prototype"),
+            .standard_objects()
+            .function_object()
+            .prototype()
+            .into(),
     );
     function.insert_property("length", length, Attribute::all());
 
@@ -295,6 +516,30 @@ pub fn make_builtin_fn<N>(
         .insert_property(name, function, Attribute::all());
 }
 
+/// Creates a new built-in function object, that is given `captures` each time it is called.
+///
+/// These are the functions created by the engine at runtime, like the resolving functions of a
+/// promise.
+pub(crate) fn make_capturing_fn(
+    function: CapturingNativeFunction,
+    captures: Captures,
+    length: usize,
+    ctx: &Context,
+) -> GcObject {
+    let mut function = Object::function(
+        Function::Capturing(
+            CapturingFunction::new(function, captures),
+            FunctionFlags::CALLABLE,
+        ),
+        ctx.standard_objects().function_object().prototype().into(),
+    );
+    let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+    function.insert_property("name", "", attribute);
+    function.insert_property("length", length, attribute);
+
+    GcObject::new(function)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BuiltInFunctionObject;
 
@@ -332,6 +577,152 @@ impl BuiltInFunctionObject {
         let start = if !args.is_empty() { 1 } else { 0 };
         context.call(this, &this_arg, &args[start..])
     }
+
+    /// `Function.prototype.apply`
+    ///
+    /// The apply() method calls a function with a given `this` value, and the arguments
+    /// provided as an array-like object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.apply
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/apply
+    fn apply(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !this.is_function() {
+            return context.throw_type_error(format!("{} is not a function", this.display()));
+        }
+        let this_arg = args.get(0).cloned().unwrap_or_default();
+        let arg_array = args.get(1).cloned().unwrap_or_default();
+        if arg_array.is_null_or_undefined() {
+            // TODO?: 3.a. PrepareForTailCall
+            return context.call(this, &this_arg, &[]);
+        }
+        let arg_list = arg_array.create_list_from_array_like(context)?;
+        // TODO?: 5. PrepareForTailCall
+        context.call(this, &this_arg, &arg_list)
+    }
+
+    /// `Function.prototype.bind`
+    ///
+    /// The bind() method creates a new function that calls this function with the given `this`
+    /// value, and with the given arguments before the ones provided in each call.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.bind
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind
+    fn bind(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = match this {
+            Value::Object(ref object) if object.is_callable() => object.clone(),
+            _ => return context.throw_type_error(format!("{} is not a function", this.display())),
+        };
+        let this_arg = args.get(0).cloned().unwrap_or_default();
+        let bound_args = args.get(1..).unwrap_or(&[]).to_vec();
+        let arg_count = bound_args.len();
+
+        let mut length = 0.0;
+        if target
+            .get_own_property(&"length".into(), context)?
+            .is_some()
+        {
+            let target_length = target.get(&"length".into(), &target.clone().into(), context)?;
+            if let Some(target_length) = target_length.as_number() {
+                length = if target_length == f64::INFINITY {
+                    f64::INFINITY
+                } else if target_length == f64::NEG_INFINITY {
+                    0.0
+                } else {
+                    // `ToIntegerOrInfinity` of a number never fails.
+                    let target_length = Value::from(target_length).to_integer(context)?;
+                    (target_length - arg_count as f64).max(0.0)
+                };
+            }
+        }
+
+        let target_name = target.get(&"name".into(), &target.clone().into(), context)?;
+        let target_name = match target_name {
+            Value::String(ref name) => name.clone(),
+            _ => RcString::from(""),
+        };
+
+        // <https://tc39.es/ecma262/#sec-boundfunctioncreate>
+        let prototype = target.get_prototype_of(context)?;
+        let mut function = Object::create(prototype);
+        function.data = ObjectData::BoundFunction(BoundFunction::new(target, this_arg, bound_args));
+        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        function.insert_property("length", length, attribute);
+        function.insert_property(
+            "name",
+            RcString::from("bound ").concat(&target_name),
+            attribute,
+        );
+
+        Ok(function.into())
+    }
+
+    /// `Function.prototype.toString`
+    ///
+    /// The toString() method returns the source code of ordinary functions, and a
+    /// `NativeFunction` string for built-in and bound functions.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString
+    fn to_string(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) if object.is_callable() => object.clone(),
+            _ => {
+                return context.throw_type_error(
+                    "Function.prototype.toString requires that 'this' be a Function",
+                )
+            }
+        };
+
+        if let Some(Function::Ordinary {
+            source: Some(ref source),
+            ..
+        }) = object.borrow().as_function()
+        {
+            return Ok(source.clone().into());
+        }
+
+        // Bound functions do not show their name, like in other engines.
+        let name = if object.borrow().is_bound_function() {
+            String::new()
+        } else {
+            match object.get(&"name".into(), &this.clone(), context)? {
+                Value::String(ref name) => name.to_string(),
+                _ => String::new(),
+            }
+        };
+        Ok(format!("function {}() {{ [native code] }}", name).into())
+    }
+
+    /// `Function.prototype[Symbol.hasInstance]`
+    ///
+    /// Checks if the argument inherits from the `prototype` property of the function, which is
+    /// the default behaviour of the `instanceof` operator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype-@@hasinstance
+    fn has_instance(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default();
+        match this {
+            Value::Object(ref function) => {
+                Ok(function.ordinary_has_instance(&object, context)?.into())
+            }
+            _ => Ok(false.into()),
+        }
+    }
 }
 
 impl BuiltIn for BuiltInFunctionObject {
@@ -352,6 +743,14 @@ impl BuiltIn for BuiltInFunctionObject {
             .constructable(false)
             .build_function_prototype(&function_prototype);
 
+        let symbol_has_instance = context.well_known_symbols().has_instance_symbol();
+        let has_instance = FunctionBuilder::new(context, Self::has_instance)
+            .name("[Symbol.hasInstance]")
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
         let function_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -359,7 +758,15 @@ impl BuiltIn for BuiltInFunctionObject {
         )
         .name(Self::NAME)
         .length(Self::LENGTH)
+        .method(Self::apply, "apply", 2)
+        .method(Self::bind, "bind", 1)
         .method(Self::call, "call", 1)
+        .method(Self::to_string, "toString", 0)
+        .property(
+            symbol_has_instance,
+            has_instance,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        )
         .build();
 
         (Self::NAME, function_object.into(), Self::attribute())
@@ -1,4 +1,9 @@
-use crate::{forward, forward_val, Context};
+use crate::{
+    forward, forward_val,
+    object::{ConstructorBuilder, FunctionBuilder, ObjectInitializer},
+    Context, Value,
+};
+use std::{cell::Cell, rc::Rc};
 
 #[allow(clippy::float_cmp)]
 #[test]
@@ -137,7 +142,7 @@ fn function_prototype_call_throw() {
     let value = forward_val(&mut engine, throw).unwrap_err();
     assert!(value.is_object());
     let string = value.to_string(&mut engine).unwrap();
-    assert!(string.starts_with("TypeError"))
+    assert!(string.to_std_string_lossy().starts_with("TypeError"))
 }
 
 #[test]
@@ -163,3 +168,326 @@ fn function_prototype_call_multiple_args() {
         .unwrap();
     assert!(boolean);
 }
+
+#[test]
+fn function_prototype_apply() {
+    let mut engine = Context::new();
+    let init = r#"
+        const numbers = [6, 5, 2, 3, 7];
+        function sum() {
+            let total = this.base;
+            for (let i = 0; i < arguments.length; i++) {
+                total += arguments[i];
+            }
+            return total;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "Math.max.apply(null, numbers)"), "7");
+    assert_eq!(
+        forward(&mut engine, "sum.apply({ base: 1 }, numbers)"),
+        "24"
+    );
+    assert_eq!(forward(&mut engine, "sum.apply({ base: 1 })"), "1");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "sum.apply({ base: 1 }, { length: 2, 0: 2, 1: 3 })"
+        ),
+        "6"
+    );
+    assert_eq!(forward(&mut engine, "Function.prototype.apply.length"), "2");
+}
+
+#[test]
+fn function_prototype_apply_throw() {
+    let mut engine = Context::new();
+    let init = r#"
+        let not_callable;
+        try {
+            Function.prototype.apply.call({}, null, []);
+        } catch (e) {
+            not_callable = e;
+        }
+        let not_array_like;
+        try {
+            Math.max.apply(null, 1);
+        } catch (e) {
+            not_array_like = e;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "not_callable instanceof TypeError"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "not_array_like instanceof TypeError"),
+        "true"
+    );
+}
+
+#[test]
+fn function_prototype_bind() {
+    let mut engine = Context::new();
+    let init = r#"
+        function f(a, b, c) {
+            return [this.x, a, b, c];
+        }
+        const bound = f.bind({ x: 1 }, 2);
+        const twice = bound.bind(null, 3);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "bound(3, 4).join()"), "\"1,2,3,4\"");
+    assert_eq!(forward(&mut engine, "twice(4).join()"), "\"1,2,3,4\"");
+    assert_eq!(forward(&mut engine, "bound.name"), "\"bound f\"");
+    assert_eq!(forward(&mut engine, "twice.name"), "\"bound bound f\"");
+    assert_eq!(forward(&mut engine, "bound.length"), "2");
+    assert_eq!(forward(&mut engine, "twice.length"), "1");
+    assert_eq!(forward(&mut engine, "f.bind(null, 1, 2, 3, 4).length"), "0");
+    assert_eq!(forward(&mut engine, "typeof bound"), "\"function\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(bound) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "bound.hasOwnProperty('prototype')"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "Function.prototype.bind.length"), "1");
+}
+
+#[test]
+fn function_prototype_bind_construct() {
+    let mut engine = Context::new();
+    let init = r#"
+        function Point(x, y) {
+            this.x = x;
+            this.y = y;
+        }
+        const YAxisPoint = Point.bind({ ignored: true }, 0);
+        const point = new YAxisPoint(5);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "point.x"), "0");
+    assert_eq!(forward(&mut engine, "point.y"), "5");
+    assert_eq!(forward(&mut engine, "point.ignored"), "undefined");
+    assert_eq!(forward(&mut engine, "point instanceof Point"), "true");
+    assert_eq!(forward(&mut engine, "point instanceof YAxisPoint"), "true");
+
+    let error = forward(
+        &mut engine,
+        r#"
+        try {
+            new (Math.max.bind(null))();
+        } catch (e) {
+            e instanceof TypeError
+        }
+        "#,
+    );
+    assert_eq!(error, "true");
+}
+
+#[test]
+fn bound_arrow_functions_are_not_constructors() {
+    let mut engine = Context::new();
+    for expression in &["new (() => {})()", "new ((() => {}).bind(null))()"] {
+        assert_eq!(
+            forward(
+                &mut engine,
+                &format!(
+                    "try {{ {}; 'no error' }} catch (e) {{ e.name }}",
+                    expression
+                )
+            ),
+            "\"TypeError\"",
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn function_own_property_order() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyNames(function f(a) {}).join()"
+        ),
+        "\"length,name,prototype\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertyNames(class A {}).join()"),
+        "\"length,name,prototype\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertyNames(() => {}).join()"),
+        "\"length,name\""
+    );
+}
+
+#[test]
+fn function_prototype_to_string() {
+    let mut engine = Context::new();
+    let init = r#"
+        function  add ( a, b ) { return a + b; /* comment */ }
+        const arrow = (a) => a * 2;
+        class Foo extends Object { static bar() { return 1; } }
+        const obj = { method(x) { return x; }, get value() { return 1; } };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "add.toString()"),
+        "\"function  add ( a, b ) { return a + b; /* comment */ }\""
+    );
+    assert_eq!(forward(&mut engine, "arrow.toString()"), "\"(a) => a * 2\"");
+    assert_eq!(
+        forward(&mut engine, "Foo.toString()"),
+        "\"class Foo extends Object { static bar() { return 1; } }\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Foo.bar.toString()"),
+        "\"bar() { return 1; }\""
+    );
+    assert_eq!(
+        forward(&mut engine, "obj.method.toString()"),
+        "\"method(x) { return x; }\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(obj, 'value').get.toString()"
+        ),
+        "\"get value() { return 1; }\""
+    );
+    assert_eq!(
+        forward(&mut engine, "(function* gen() { yield 1; }).toString()"),
+        "\"function* gen() { yield 1; }\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Math.max.toString()"),
+        "\"function max() { [native code] }\""
+    );
+    assert_eq!(
+        forward(&mut engine, "add.bind(null).toString()"),
+        "\"function () { [native code] }\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"
+            try {
+                Function.prototype.toString.call({});
+            } catch (e) {
+                e instanceof TypeError
+            }
+            "#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn closure_global_function() {
+    let mut engine = Context::new();
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    engine
+        .register_global_function("count", 0, move |_, _, _| {
+            counter.set(counter.get() + 1);
+            Ok(Value::from(counter.get()))
+        })
+        .unwrap();
+
+    assert_eq!(forward(&mut engine, "count(); count()"), "2");
+    assert_eq!(forward(&mut engine, "count.name"), "\"count\"");
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn closure_with_captures() {
+    let mut engine = Context::new();
+    let object = forward_val(&mut engine, "({ x: 5 })").unwrap();
+    let function = FunctionBuilder::closure_with_captures(
+        &mut engine,
+        |_, args, object, ctx| {
+            let value = args.first().cloned().unwrap_or_default();
+            object.set_field("x", value, ctx)
+        },
+        object.clone(),
+    )
+    .name("setX")
+    .length(1)
+    .build();
+    let global = engine.global_object().clone();
+    global.set_field("setX", function, &mut engine).unwrap();
+
+    forward(&mut engine, "setX(10)");
+    assert_eq!(object.get_field("x", &mut engine).unwrap(), Value::from(10));
+}
+
+#[test]
+fn builder_closures() {
+    let mut engine = Context::new();
+    let prefix = String::from("value: ");
+
+    let object = ObjectInitializer::new(&mut engine)
+        .closure(
+            move |_, args, ctx| {
+                let value = args.first().cloned().unwrap_or_default().to_string(ctx)?;
+                Ok(format!("{}{}", prefix, value).into())
+            },
+            "describe",
+            1,
+        )
+        .build();
+
+    let calls = Rc::new(Cell::new(0));
+    let (method_calls, static_calls) = (calls.clone(), calls.clone());
+    let constructor = ConstructorBuilder::new(&mut engine, |this, _, _| Ok(this.clone()))
+        .name("Counter")
+        .closure_method(
+            move |_, _, _| {
+                method_calls.set(method_calls.get() + 1);
+                Ok(Value::from(method_calls.get()))
+            },
+            "count",
+            0,
+        )
+        .static_closure_method(
+            move |_, _, _| {
+                static_calls.set(static_calls.get() + 10);
+                Ok(Value::from(static_calls.get()))
+            },
+            "countByTen",
+            0,
+        )
+        .build();
+
+    let global = engine.global_object().clone();
+    global.set_field("object", object, &mut engine).unwrap();
+    global
+        .set_field("Counter", constructor, &mut engine)
+        .unwrap();
+
+    assert_eq!(forward(&mut engine, "object.describe(42)"), "\"value: 42\"");
+    assert_eq!(
+        forward(&mut engine, "new Counter().count(); Counter.countByTen()"),
+        "11"
+    );
+    assert_eq!(calls.get(), 11);
+
+    let offset = 100;
+    let function = engine
+        .create_builtin_function("offset", 1, move |_, args, ctx| {
+            let value = args.first().cloned().unwrap_or_default().to_number(ctx)?;
+            Ok(Value::from(value + f64::from(offset)))
+        })
+        .unwrap();
+    global.set_field("offset", function, &mut engine).unwrap();
+    assert_eq!(forward(&mut engine, "offset(1)"), "101");
+}
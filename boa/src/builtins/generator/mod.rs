@@ -0,0 +1,303 @@
+//! This module implements the generator objects.
+//!
+//! A generator object is returned by calling a generator function, it runs the body of the
+//! function step by step, suspending it at every `yield` expression.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
+
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    environment::lexical_environment::Environment,
+    exec::{CallFrame, Executable, InterpreterState, ResumeKind, SuspendedFrame},
+    object::{GcObject, ObjectData},
+    property::{Attribute, DataDescriptor},
+    syntax::ast::node::RcStatementList,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{custom_trace, unsafe_empty_trace, Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a generator object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+enum GeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+unsafe impl Trace for GeneratorState {
+    unsafe_empty_trace!();
+}
+
+/// Everything needed to continue running the body of a suspended generator.
+#[derive(Debug, Clone, Finalize)]
+struct GeneratorContext {
+    /// The body of the generator function.
+    body: RcStatementList,
+    /// The scopes that were active when the generator was suspended, starting with the function
+    /// environment.
+    environments: Vec<Environment>,
+    /// The frames of the nodes that were suspended.
+    frames: Vec<SuspendedFrame>,
+    /// The call frame of the generator function, pushed on the call stack every time the body
+    /// is resumed.
+    ///
+    /// It is `None` until the first suspension when the body starts running within the call of
+    /// its function, which has pushed the frame already.
+    call_frame: Option<CallFrame>,
+}
+
+unsafe impl Trace for GeneratorContext {
+    custom_trace!(this, {
+        mark(&this.body);
+        mark(&this.environments);
+        mark(&this.frames);
+        mark(&this.call_frame);
+    });
+}
+
+/// The internal representation of a generator object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Generator {
+    state: GeneratorState,
+    context: Option<GeneratorContext>,
+}
+
+impl Generator {
+    pub(crate) const NAME: &'static str = "Generator";
+
+    /// Creates a new generator object that runs `body` in the given function environment.
+    ///
+    /// The `call_frame` is pushed on the call stack whenever the body runs. It can be `None` if
+    /// the body is first resumed within the call of the function, the frame of the call is then
+    /// kept once the body is suspended.
+    pub(crate) fn create(
+        ctx: &Context,
+        prototype: Value,
+        body: RcStatementList,
+        environment: Environment,
+        call_frame: Option<CallFrame>,
+    ) -> Value {
+        let generator = Value::new_object(Some(ctx.global_object()));
+        generator.set_data(ObjectData::Generator(Self {
+            state: GeneratorState::SuspendedStart,
+            context: Some(GeneratorContext {
+                body,
+                environments: vec![environment],
+                frames: Vec::new(),
+                call_frame,
+            }),
+        }));
+        generator
+            .as_object_mut()
+            .expect("generator object")
+            .set_prototype_instance(prototype);
+        generator
+    }
+
+    /// `Generator.prototype.next( value )`
+    ///
+    /// Resumes the generator, the `value` becomes the result of the `yield` expression the
+    /// generator was suspended at.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next
+    pub(crate) fn next(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeKind::Next, value, ctx)
+    }
+
+    /// `Generator.prototype.return( value )`
+    ///
+    /// Resumes the generator as if a `return` statement was found at the `yield` expression the
+    /// generator was suspended at.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return
+    pub(crate) fn r#return(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeKind::Return, value, ctx)
+    }
+
+    /// `Generator.prototype.throw( exception )`
+    ///
+    /// Resumes the generator by throwing the `exception` from the `yield` expression the
+    /// generator was suspended at.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw
+    pub(crate) fn throw(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeKind::Throw, value, ctx)
+    }
+
+    /// Implements `GeneratorResume` and `GeneratorResumeAbrupt`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorresume
+    fn resume(this: &Value, kind: ResumeKind, value: Value, ctx: &mut Context) -> Result<Value> {
+        let object = match this {
+            Value::Object(object) if object.borrow().is_generator() => object.clone(),
+            _ => return ctx.throw_type_error("`this` is not a Generator"),
+        };
+
+        let (value, done) = Self::resume_object(&object, kind, value, ctx)?;
+        Ok(create_iter_result_object(ctx, value, done))
+    }
+
+    /// Resumes the body of a generator object, returning the yielded or returned value and
+    /// whether the generator is done.
+    ///
+    /// This is also used to run the body of async functions, which suspend at `await`
+    /// expressions instead.
+    pub(crate) fn resume_object(
+        object: &GcObject,
+        kind: ResumeKind,
+        value: Value,
+        ctx: &mut Context,
+    ) -> Result<(Value, bool)> {
+        let (state, context) = {
+            let mut object = object.borrow_mut();
+            let generator = object.as_generator_mut().expect("generator object");
+            let state = generator.state;
+            match (state, kind) {
+                (GeneratorState::Executing, _) => {
+                    drop(object);
+                    return Err(ctx.construct_type_error("Generator is already running"));
+                }
+                (GeneratorState::Completed, _)
+                | (GeneratorState::SuspendedStart, ResumeKind::Return)
+                | (GeneratorState::SuspendedStart, ResumeKind::Throw) => {
+                    generator.state = GeneratorState::Completed;
+                    generator.context = None;
+                    (state, None)
+                }
+                _ => {
+                    generator.state = GeneratorState::Executing;
+                    (state, generator.context.take())
+                }
+            }
+        };
+
+        let context = match context {
+            Some(context) => context,
+            None => {
+                return match kind {
+                    ResumeKind::Next => Ok((Value::undefined(), true)),
+                    ResumeKind::Return => Ok((value, true)),
+                    ResumeKind::Throw => Err(value),
+                }
+            }
+        };
+
+        let resume_value = if state == GeneratorState::SuspendedYield {
+            Some((kind, value))
+        } else {
+            None
+        };
+
+        let depth = ctx.realm().environment.depth();
+        ctx.realm_mut().environment.restore(context.environments);
+        let outer_frames = ctx.executor().enter_generator(context.frames, resume_value);
+
+        if let Some(ref call_frame) = context.call_frame {
+            ctx.push_call_frame(call_frame.clone());
+        }
+        let result = context.body.run(ctx);
+        let call_frame = match context.call_frame {
+            Some(call_frame) => {
+                ctx.pop_call_frame();
+                Some(call_frame)
+            }
+            None => ctx.call_stack().last().cloned(),
+        };
+
+        let frames = ctx.executor().leave_generator(outer_frames);
+        let environments = ctx.realm_mut().environment.split_off(depth);
+        let returned = *ctx.executor().get_current_state() == InterpreterState::Return;
+        ctx.executor()
+            .set_current_state(InterpreterState::Executing);
+
+        let mut object = object.borrow_mut();
+        let generator = object.as_generator_mut().expect("generator object");
+        if let Some(frames) = frames {
+            // The generator was suspended, the error holds the yielded value.
+            generator.state = GeneratorState::SuspendedYield;
+            generator.context = Some(GeneratorContext {
+                body: context.body,
+                environments,
+                frames,
+                call_frame,
+            });
+            drop(object);
+
+            let value = result.err().unwrap_or_default();
+            Ok((value, false))
+        } else {
+            generator.state = GeneratorState::Completed;
+            drop(object);
+
+            // A generator that was resumed with `return()` unwinds with the returned value as an
+            // error.
+            let value = match result {
+                Ok(value) | Err(value) if returned => value,
+                Ok(_) => Value::undefined(),
+                Err(err) => return Err(err),
+            };
+            Ok((value, true))
+        }
+    }
+
+    /// Create the %GeneratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-prototype
+    pub(crate) fn create_prototype(ctx: &mut Context, iterator_prototype: Value) -> Value {
+        let global = ctx.global_object();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let generator = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &generator, 1, ctx);
+        make_builtin_fn(Self::r#return, "return", &generator, 1, ctx);
+        make_builtin_fn(Self::throw, "throw", &generator, 1, ctx);
+        generator
+            .as_object_mut()
+            .expect("generator prototype object")
+            .set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = ctx.well_known_symbols().to_string_tag_symbol();
+        let to_string_tag_property = DataDescriptor::new("Generator", Attribute::CONFIGURABLE);
+        generator.set_property(to_string_tag, to_string_tag_property);
+        generator
+    }
+}
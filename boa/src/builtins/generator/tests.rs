@@ -0,0 +1,447 @@
+use crate::{forward, Context};
+
+#[test]
+fn next() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            yield 1;
+            yield 2;
+            return 3;
+        }
+        var it = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next().value"), "1");
+    assert_eq!(forward(&mut engine, "it.next().value"), "2");
+    let result = forward(
+        &mut engine,
+        "var res = it.next(); [res.value, res.done].join()",
+    );
+    assert_eq!(result, "\"3,true\"");
+    let result = forward(
+        &mut engine,
+        "res = it.next(); res.value === undefined && res.done",
+    );
+    assert_eq!(result, "true");
+}
+
+#[test]
+fn lazy_infinite_sequence() {
+    let mut engine = Context::new();
+    let init = r#"
+        var count = 0;
+        function* naturals() {
+            let i = 0;
+            while (true) {
+                count++;
+                yield i++;
+            }
+        }
+        var it = naturals();
+        it.next();
+        it.next();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next().value"), "2");
+    assert_eq!(forward(&mut engine, "count"), "3");
+}
+
+#[test]
+fn next_value() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            const a = yield "first";
+            const b = yield a + 1;
+            return a + b;
+        }
+        var it = gen();
+        it.next("ignored");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next(10).value"), "11");
+    assert_eq!(forward(&mut engine, "it.next(5).value"), "15");
+}
+
+#[test]
+fn for_of() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            yield "a";
+            yield "b";
+            yield "c";
+        }
+        var result = "";
+        for (const x of gen()) {
+            result += x;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result"), "\"abc\"");
+}
+
+#[test]
+fn for_of_closes_generator() {
+    let mut engine = Context::new();
+    let init = r#"
+        var closed = [];
+        function* gen(name) {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                closed.push(name);
+            }
+        }
+        for (const x of gen("break")) {
+            break;
+        }
+        function f() {
+            for (const x of gen("return")) {
+                return x;
+            }
+        }
+        var returned = f();
+        try {
+            for (const x of gen("throw")) {
+                throw "error";
+            }
+        } catch (e) {}
+        for (const x of gen("continue")) {
+            if (closed.length === 3) {
+                continue;
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "closed.join()"),
+        "\"break,return,throw,continue\""
+    );
+    assert_eq!(forward(&mut engine, "returned"), "1");
+}
+
+#[test]
+fn spread() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen(n) {
+            for (let i = 0; i < n; i++) {
+                yield i;
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "[...gen(4)].join()"), "\"0,1,2,3\"");
+    assert_eq!(forward(&mut engine, "Math.max(...gen(4))"), "3");
+}
+
+#[test]
+fn r#return() {
+    let mut engine = Context::new();
+    let init = r#"
+        var cleaned = false;
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                cleaned = true;
+            }
+        }
+        var it = gen();
+        it.next();
+        var res = it.return(5);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "[res.value, res.done].join()"),
+        "\"5,true\""
+    );
+    assert_eq!(forward(&mut engine, "cleaned"), "true");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn return_before_start() {
+    let mut engine = Context::new();
+    let init = r#"
+        var started = false;
+        function* gen() {
+            started = true;
+            yield 1;
+        }
+        var it = gen();
+        var res = it.return(5);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "[res.value, res.done].join()"),
+        "\"5,true\""
+    );
+    assert_eq!(forward(&mut engine, "started"), "false");
+}
+
+#[test]
+fn throw() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            try {
+                yield 1;
+            } catch (e) {
+                yield "caught " + e;
+            }
+        }
+        var it = gen();
+        it.next();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "it.throw('err').value"),
+        "\"caught err\""
+    );
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+
+    let result = forward(
+        &mut engine,
+        r#"
+        var it = gen();
+        try {
+            it.throw("err");
+        } catch (e) {
+            e
+        }
+        "#,
+    );
+    assert_eq!(result, "\"err\"");
+}
+
+#[test]
+fn yield_in_finally() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            try {
+                yield 1;
+            } finally {
+                yield 2;
+            }
+            yield 3;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "[...gen()].join()"), "\"1,2,3\"");
+}
+
+#[test]
+fn delegate() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* inner() {
+            const x = yield 2;
+            yield x;
+            return "done";
+        }
+        function* outer() {
+            yield 1;
+            const result = yield* inner();
+            yield result;
+            yield* [4, 5];
+        }
+        var it = outer();
+        var values = [it.next().value, it.next().value, it.next(3).value];
+        for (const x of it) {
+            values.push(x);
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "values.join()"), "\"1,2,3,done,4,5\"");
+}
+
+#[test]
+fn generator_method() {
+    let mut engine = Context::new();
+    let init = r#"
+        var obj = {
+            base: 10,
+            *values() {
+                yield this.base;
+                yield this.base + 1;
+            }
+        };
+        class Range {
+            constructor(n) {
+                this.n = n;
+            }
+            *values() {
+                for (let i = 0; i < this.n; i++) {
+                    yield i;
+                }
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "[...obj.values()].join()"),
+        "\"10,11\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[...new Range(3).values()].join()"),
+        "\"0,1,2\""
+    );
+}
+
+#[test]
+fn already_running() {
+    let mut engine = Context::new();
+    let init = r#"
+        var it;
+        function* gen() {
+            try {
+                it.next();
+            } catch (e) {
+                yield e;
+            }
+        }
+        it = gen();
+        "#;
+    forward(&mut engine, init);
+    let result = forward(&mut engine, "it.next().value.constructor.name");
+    assert_eq!(result, "\"TypeError\"");
+}
+
+#[test]
+fn prototype() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {}
+        var it = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Object.getPrototypeOf(it) === gen.prototype"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "it[Symbol.iterator]() === it"), "true");
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(it)"),
+        "\"[object Generator]\""
+    );
+}
+
+#[test]
+fn call_frame_after_yield() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            yield 1;
+            yield new Error("resumed");
+        }
+        var it = gen();
+        it.next();
+        var stack = it.next().value.stack;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "stack.split('\\n')[1]"),
+        "\"    at gen (<anonymous>:4:19)\""
+    );
+    assert!(engine.call_stack().is_empty());
+}
+
+#[test]
+fn yield_in_computed_property_name() {
+    let mut engine = Context::new();
+    let init = r#"
+        var evaluated = 0;
+        function* gen() {
+            return { [(evaluated++, yield 1)]: yield 2 };
+        }
+        var it = gen();
+        it.next();
+        it.next("key");
+        var result = it.next(5).value;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result.key"), "5");
+    assert_eq!(forward(&mut engine, "evaluated"), "1");
+}
+
+#[test]
+fn yield_in_object_pattern_default() {
+    let mut engine = Context::new();
+    let init = r#"
+        var gets = 0;
+        function* gen() {
+            var { a, b = yield 1, ...rest } = {
+                get a() { gets++; return 2; },
+                c: 3,
+            };
+            return [a, b, rest.c].join();
+        }
+        var it = gen();
+        it.next();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next(5).value"), "\"2,5,3\"");
+    assert_eq!(forward(&mut engine, "gets"), "1");
+}
+
+#[test]
+fn yield_in_array_pattern_default() {
+    let mut engine = Context::new();
+    let init = r#"
+        var closed = false;
+        var iterable = {
+            [Symbol.iterator]() {
+                var i = 0;
+                return {
+                    next() { i++; return { value: i < 2 ? 0 : undefined, done: i > 3 }; },
+                    return() { closed = true; return {}; },
+                };
+            },
+        };
+        var a, b;
+        function* gen() {
+            [a, b = yield 1] = [0];
+            let [c, d = yield 2, e] = iterable;
+            return [a, b, c, d, e, closed].join();
+        }
+        var it = gen();
+        it.next();
+        it.next(5);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "closed"), "false");
+    assert_eq!(
+        forward(&mut engine, "it.next(6).value"),
+        "\"0,5,0,6,,true\""
+    );
+}
+
+#[test]
+fn yield_in_binding_pattern_of_loop_and_catch() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            var result = [];
+            for (const { x = yield "x" } of [{}, { x: 1 }]) {
+                result.push(x);
+            }
+            try {
+                throw {};
+            } catch ({ y = yield "y" }) {
+                result.push(y);
+            }
+            return result.join();
+        }
+        var it = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next().value"), "\"x\"");
+    assert_eq!(forward(&mut engine, "it.next(2).value"), "\"y\"");
+    assert_eq!(forward(&mut engine, "it.next(3).value"), "\"2,1,3\"");
+}
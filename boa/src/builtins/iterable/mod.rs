@@ -1,4 +1,7 @@
 use crate::{
+    builtins::generator::Generator,
+    builtins::object::for_in_iterator::ForInIterator,
+    builtins::set::set_iterator::SetIterator,
     builtins::string::string_iterator::StringIterator,
     builtins::ArrayIterator,
     object::{GcObject, ObjectInitializer},
@@ -11,6 +14,9 @@ pub struct IteratorPrototypes {
     iterator_prototype: GcObject,
     array_iterator: GcObject,
     string_iterator: GcObject,
+    set_iterator: GcObject,
+    generator: GcObject,
+    for_in_iterator: GcObject,
 }
 
 impl IteratorPrototypes {
@@ -23,9 +29,18 @@ impl IteratorPrototypes {
             array_iterator: ArrayIterator::create_prototype(ctx, iterator_prototype.clone())
                 .as_gc_object()
                 .expect("Array Iterator Prototype is not an object"),
-            string_iterator: StringIterator::create_prototype(ctx, iterator_prototype)
+            string_iterator: StringIterator::create_prototype(ctx, iterator_prototype.clone())
                 .as_gc_object()
                 .expect("String Iterator Prototype is not an object"),
+            set_iterator: SetIterator::create_prototype(ctx, iterator_prototype.clone())
+                .as_gc_object()
+                .expect("Set Iterator Prototype is not an object"),
+            generator: Generator::create_prototype(ctx, iterator_prototype.clone())
+                .as_gc_object()
+                .expect("Generator Prototype is not an object"),
+            for_in_iterator: ForInIterator::create_prototype(ctx, iterator_prototype)
+                .as_gc_object()
+                .expect("For In Iterator Prototype is not an object"),
         }
     }
 
@@ -40,6 +55,18 @@ impl IteratorPrototypes {
     pub fn string_iterator(&self) -> GcObject {
         self.string_iterator.clone()
     }
+
+    pub fn set_iterator(&self) -> GcObject {
+        self.set_iterator.clone()
+    }
+
+    pub fn generator(&self) -> GcObject {
+        self.generator.clone()
+    }
+
+    pub fn for_in_iterator(&self) -> GcObject {
+        self.for_in_iterator.clone()
+    }
 }
 
 /// CreateIterResultObject( value, done )
@@ -57,16 +84,20 @@ pub fn create_iter_result_object(ctx: &mut Context, value: Value, done: bool) ->
 
 /// Get an iterator record
 pub fn get_iterator(ctx: &mut Context, iterable: Value) -> Result<IteratorRecord> {
-    // TODO: Fix the accessor handling
-    let iterator_function = iterable
-        .get_property(ctx.well_known_symbols().iterator_symbol())
-        .map(|p| p.as_data_descriptor().unwrap().value())
-        .ok_or_else(|| ctx.construct_type_error("Not an iterable"))?;
+    if iterable.is_null_or_undefined() {
+        return Err(ctx.construct_type_error("Not an iterable"));
+    }
+    // Primitives like strings are iterable through their prototype.
+    let iterator_function = iterable.to_object(ctx)?.get(
+        &ctx.well_known_symbols().iterator_symbol().into(),
+        &iterable,
+        ctx,
+    )?;
+    if !iterator_function.is_function() {
+        return Err(ctx.construct_type_error("Not an iterable"));
+    }
     let iterator_object = ctx.call(&iterator_function, &iterable, &[])?;
-    let next_function = iterator_object
-        .get_property("next")
-        .map(|p| p.as_data_descriptor().unwrap().value())
-        .ok_or_else(|| ctx.construct_type_error("Could not find property `next`"))?;
+    let next_function = iterator_object.get_field("next", ctx)?;
     Ok(IteratorRecord::new(iterator_object, next_function))
 }
 
@@ -98,13 +129,23 @@ pub struct IteratorRecord {
 }
 
 impl IteratorRecord {
-    fn new(iterator_object: Value, next_function: Value) -> Self {
+    pub(crate) fn new(iterator_object: Value, next_function: Value) -> Self {
         Self {
             iterator_object,
             next_function,
         }
     }
 
+    /// Gets the iterator object.
+    pub(crate) fn iterator_object(&self) -> &Value {
+        &self.iterator_object
+    }
+
+    /// Gets the `next` method of the iterator.
+    pub(crate) fn next_function(&self) -> &Value {
+        &self.next_function
+    }
+
     /// Get the next value in the iterator
     ///
     /// More information:
@@ -113,20 +154,35 @@ impl IteratorRecord {
     /// [spec]: https://tc39.es/ecma262/#sec-iteratornext
     pub(crate) fn next(&self, ctx: &mut Context) -> Result<IteratorResult> {
         let next = ctx.call(&self.next_function, &self.iterator_object, &[])?;
-        // FIXME: handle accessor descriptors
-        let done = next
-            .get_property("done")
-            .map(|p| p.as_data_descriptor().unwrap().value())
-            .and_then(|v| v.as_boolean())
-            .ok_or_else(|| ctx.construct_type_error("Could not find property `done`"))?;
-
-        // FIXME: handle accessor descriptors
-        let next_result = next
-            .get_property("value")
-            .map(|p| p.as_data_descriptor().unwrap().value())
-            .unwrap_or_default();
+        if !next.is_object() {
+            return Err(ctx.construct_type_error("Iterator result is not an object"));
+        }
+        let done = next.get_field("done", ctx)?.to_boolean();
+        let next_result = next.get_field("value", ctx)?;
         Ok(IteratorResult::new(next_result, done))
     }
+
+    /// Closes an iterator that was not run to completion, calling its `return` method if it
+    /// has one.
+    ///
+    /// An abrupt `completion` takes precedence over any error thrown while closing.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iteratorclose
+    pub(crate) fn close(&self, completion: Result<Value>, ctx: &mut Context) -> Result<Value> {
+        let return_method = self.iterator_object.get_field("return", ctx)?;
+        if return_method.is_null_or_undefined() {
+            return completion;
+        }
+        let inner_result = ctx.call(&return_method, &self.iterator_object, &[]);
+        let completion = completion?;
+        if !inner_result?.is_object() {
+            return ctx.throw_type_error("iterator `return` method must return an object");
+        }
+        Ok(completion)
+    }
 }
 
 #[derive(Debug)]
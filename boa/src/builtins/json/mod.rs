@@ -14,12 +14,12 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
 use crate::{
-    builtins::BuiltIn,
-    object::ObjectInitializer,
+    builtins::{Array, BuiltIn},
+    object::{GcObject, ObjectInitializer},
     property::{Attribute, DataDescriptor, PropertyKey},
+    value::{is_leading_surrogate, is_trailing_surrogate, RcString},
     BoaProfiler, Context, Result, Value,
 };
-use serde_json::{self, Value as JSONValue};
 
 #[cfg(test)]
 mod tests;
@@ -67,19 +67,15 @@ impl Json {
             .unwrap_or_else(Value::undefined)
             .to_string(ctx)?;
 
-        match serde_json::from_str::<JSONValue>(&arg) {
-            Ok(json) => {
-                let j = Value::from_json(json, ctx);
-                match args.get(1) {
-                    Some(reviver) if reviver.is_function() => {
-                        let mut holder = Value::new_object(None);
-                        holder.set_field("", j);
-                        Self::walk(reviver, ctx, &mut holder, &PropertyKey::from(""))
-                    }
-                    _ => Ok(j),
-                }
+        let text: Vec<u16> = arg.code_units().collect();
+        let j = JsonParser::new(&text).parse(ctx)?;
+        match args.get(1) {
+            Some(reviver) if reviver.is_function() => {
+                let mut holder = Value::new_object(None);
+                holder.set_field("", j, ctx)?;
+                Self::walk(reviver, ctx, &mut holder, &PropertyKey::from(""))
             }
-            Err(err) => ctx.throw_syntax_error(err.to_string()),
+            _ => Ok(j),
         }
     }
 
@@ -95,7 +91,7 @@ impl Json {
         holder: &mut Value,
         key: &PropertyKey,
     ) -> Result<Value> {
-        let value = holder.get_field(key.clone());
+        let value = holder.get_field(key.clone(), ctx)?;
 
         if let Value::Object(ref object) = value {
             let keys: Vec<_> = object.borrow().keys().collect();
@@ -104,7 +100,7 @@ impl Json {
                 let v = Self::walk(reviver, ctx, &mut value.clone(), &key);
                 match v {
                     Ok(v) if !v.is_undefined() => {
-                        value.set_field(key, v);
+                        value.set_field(key, v, ctx)?;
                     }
                     Ok(_) => {
                         value.remove_property(key);
@@ -133,69 +129,495 @@ impl Json {
     /// [spec]: https://tc39.es/ecma262/#sec-json.stringify
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify
     pub(crate) fn stringify(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let object = match args.get(0) {
-            Some(obj) if obj.is_symbol() || obj.is_function() || obj.is_undefined() => {
-                return Ok(Value::undefined())
-            }
-            None => return Ok(Value::undefined()),
-            Some(obj) => obj,
-        };
-        let replacer = match args.get(1) {
-            Some(replacer) if replacer.is_object() => replacer,
-            _ => return Ok(Value::from(object.to_json(ctx)?.to_string())),
-        };
+        let value = args.get(0).cloned().unwrap_or_default();
+        let mut serializer = Serializer::default();
 
-        let replacer_as_object = replacer
-            .as_object()
-            .expect("JSON.stringify replacer was an object");
-        if replacer_as_object.is_callable() {
-            object
-                .as_object()
-                .map(|obj| {
-                    let object_to_return = Value::new_object(None);
-                    for (key, val) in obj
-                        .iter()
-                        // FIXME: handle accessor descriptors
-                        .map(|(k, v)| (k, v.as_data_descriptor().unwrap().value()))
-                    {
-                        let this_arg = object.clone();
-                        object_to_return.set_property(
-                            key.to_owned(),
-                            DataDescriptor::new(
-                                ctx.call(
-                                    replacer,
-                                    &this_arg,
-                                    &[Value::from(key.clone()), val.clone()],
-                                )?,
-                                Attribute::all(),
-                            ),
-                        );
+        if let Some(replacer) = args.get(1).and_then(Value::as_gc_object) {
+            if replacer.is_callable() {
+                serializer.replacer_function = Some(replacer.into());
+            } else if Array::is_array_value(&replacer.clone().into(), ctx)? {
+                let mut property_list: Vec<RcString> = Vec::new();
+                for index in 0..replacer.length_of_array_like(ctx)? {
+                    let item = replacer.get(&index.into(), &replacer.clone().into(), ctx)?;
+                    let is_key = match item {
+                        Value::String(_) | Value::Integer(_) | Value::Rational(_) => true,
+                        Value::Object(ref object) => {
+                            let object = object.borrow();
+                            object.as_string().is_some() || object.as_number().is_some()
+                        }
+                        _ => false,
+                    };
+                    if is_key {
+                        let item = item.to_string(ctx)?;
+                        if !property_list.contains(&item) {
+                            property_list.push(item);
+                        }
                     }
-                    Ok(Value::from(object_to_return.to_json(ctx)?.to_string()))
-                })
-                .ok_or_else(Value::undefined)?
-        } else if replacer_as_object.is_array() {
-            let mut obj_to_return = serde_json::Map::new();
-            let fields = replacer_as_object.keys().filter_map(|key| {
-                if key == "length" {
-                    None
+                }
+                serializer.property_list = Some(property_list);
+            }
+        }
+
+        let wrapper = Value::new_object(Some(ctx.global_object()));
+        wrapper.set_property("", DataDescriptor::new(value, Attribute::all()));
+        if serializer.serialize_property(PropertyKey::from(""), &wrapper, ctx)? {
+            Ok(Value::from(RcString::from(serializer.output)))
+        } else {
+            Ok(Value::undefined())
+        }
+    }
+}
+
+/// The state of a `JSON.stringify` call.
+///
+/// The output is built from UTF-16 code units, so lone surrogates in strings and property keys
+/// are escaped instead of being replaced.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-json.stringify
+#[derive(Debug, Default)]
+struct Serializer {
+    replacer_function: Option<Value>,
+    property_list: Option<Vec<RcString>>,
+    stack: Vec<GcObject>,
+    output: Vec<u16>,
+}
+
+impl Serializer {
+    /// Appends the serialization of the property `key` of `holder` to the output.
+    ///
+    /// Returns `false`, without writing anything, if the property has no JSON representation.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonproperty
+    fn serialize_property(
+        &mut self,
+        key: PropertyKey,
+        holder: &Value,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        let mut value = holder.get_field(key.clone(), ctx)?;
+
+        if value.is_object() {
+            let to_json = value.get_field("toJSON", ctx)?;
+            if to_json.is_function() {
+                value = ctx.call(&to_json, &value, &[key.clone().into()])?;
+            }
+        }
+        if let Some(ref replacer) = self.replacer_function {
+            let replacer = replacer.clone();
+            value = ctx.call(&replacer, holder, &[key.into(), value])?;
+        }
+
+        if let Value::Object(ref object) = value {
+            let (number, string, boolean, bigint) = {
+                let object = object.borrow();
+                (
+                    object.as_number(),
+                    object.as_string(),
+                    object.as_boolean(),
+                    object.as_bigint().is_some(),
+                )
+            };
+            if number.is_some() {
+                value = value.to_number(ctx)?.into();
+            } else if string.is_some() {
+                value = value.to_string(ctx)?.into();
+            } else if let Some(boolean) = boolean {
+                value = boolean.into();
+            } else if bigint {
+                return Err(ctx.construct_type_error("BigInt value can't be serialized in JSON"));
+            }
+        }
+
+        match value {
+            Value::Null => self.push_str("null"),
+            Value::Boolean(true) => self.push_str("true"),
+            Value::Boolean(false) => self.push_str("false"),
+            Value::String(ref string) => self.quote(string),
+            Value::Integer(integer) => self.push_str(&integer.to_string()),
+            Value::Rational(number) if number.is_finite() => {
+                let number = value.to_string(ctx)?;
+                self.output.extend(number.code_units());
+            }
+            Value::Rational(_) => self.push_str("null"),
+            Value::BigInt(_) => {
+                return Err(ctx.construct_type_error("BigInt value can't be serialized in JSON"))
+            }
+            Value::Object(ref object) if !object.is_callable() => {
+                if self.stack.iter().any(|seen| GcObject::equals(seen, object)) {
+                    return Err(ctx.construct_type_error("cyclic object value"));
+                }
+                self.stack.push(object.clone());
+                if Array::is_array_value(&value, ctx)? {
+                    self.serialize_array(object, ctx)?;
                 } else {
-                    Some(replacer.get_field(key))
+                    self.serialize_object(object, ctx)?;
+                }
+                self.stack.pop();
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Appends the serialization of an object to the output.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonobject
+    fn serialize_object(&mut self, object: &GcObject, ctx: &mut Context) -> Result<()> {
+        let keys: Vec<PropertyKey> = if let Some(ref list) = self.property_list {
+            list.iter().cloned().map(PropertyKey::from).collect()
+        } else {
+            let mut keys = Vec::new();
+            for key in object.own_property_keys(ctx)? {
+                if let PropertyKey::Symbol(_) = key {
+                    continue;
+                }
+                let enumerable = object
+                    .get_own_property(&key, ctx)?
+                    .is_some_and(|desc| desc.enumerable());
+                if enumerable {
+                    keys.push(key);
+                }
+            }
+            keys
+        };
+
+        let holder = Value::from(object.clone());
+        let mut first = true;
+        self.push_str("{");
+        for key in keys {
+            let start = self.output.len();
+            if !first {
+                self.push_str(",");
+            }
+            match key {
+                PropertyKey::String(ref string) => self.quote(string),
+                PropertyKey::Index(index) => self.quote(&index.to_string().into()),
+                PropertyKey::Symbol(_) => unreachable!("symbol keys are never serialized"),
+            }
+            self.push_str(":");
+            if self.serialize_property(key, &holder, ctx)? {
+                first = false;
+            } else {
+                self.output.truncate(start);
+            }
+        }
+        self.push_str("}");
+        Ok(())
+    }
+
+    /// Appends the serialization of an array to the output.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonarray
+    fn serialize_array(&mut self, array: &GcObject, ctx: &mut Context) -> Result<()> {
+        let holder = Value::from(array.clone());
+        self.push_str("[");
+        for index in 0..array.length_of_array_like(ctx)? {
+            if index > 0 {
+                self.push_str(",");
+            }
+            if !self.serialize_property(index.into(), &holder, ctx)? {
+                self.push_str("null");
+            }
+        }
+        self.push_str("]");
+        Ok(())
+    }
+
+    /// Appends a string as a quoted JSON string literal to the output.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-quotejsonstring
+    fn quote(&mut self, string: &RcString) {
+        self.push_str("\"");
+        let units: Vec<u16> = string.code_units().collect();
+        for (i, &unit) in units.iter().enumerate() {
+            let lone_surrogate = if is_leading_surrogate(unit) {
+                !units.get(i + 1).copied().is_some_and(is_trailing_surrogate)
+            } else if is_trailing_surrogate(unit) {
+                !(i > 0 && is_leading_surrogate(units[i - 1]))
+            } else {
+                false
+            };
+            match unit {
+                0x08 => self.push_str("\\b"),
+                0x09 => self.push_str("\\t"),
+                0x0A => self.push_str("\\n"),
+                0x0C => self.push_str("\\f"),
+                0x0D => self.push_str("\\r"),
+                0x22 => self.push_str("\\\""),
+                0x5C => self.push_str("\\\\"),
+                _ if unit < 0x20 || lone_surrogate => self.push_str(&format!("\\u{:04x}", unit)),
+                _ => self.output.push(unit),
+            }
+        }
+        self.push_str("\"");
+    }
+
+    fn push_str(&mut self, string: &str) {
+        self.output.extend(string.encode_utf16());
+    }
+}
+
+/// The nesting depth at which `JSON.parse` gives up, so deeply nested input can't overflow the
+/// stack.
+const MAX_PARSE_DEPTH: usize = 128;
+
+/// A parser for the JSON text given to `JSON.parse`.
+///
+/// It works on the UTF-16 code units of the text, so escaped and unescaped lone surrogates in
+/// strings are kept as they are.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-json.parse
+#[derive(Debug)]
+struct JsonParser<'a> {
+    text: &'a [u16],
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(text: &'a [u16]) -> Self {
+        Self {
+            text,
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    /// Parses the whole text as a single JSON value.
+    fn parse(mut self, ctx: &mut Context) -> Result<Value> {
+        let value = self.parse_value(ctx)?;
+        self.skip_whitespace();
+        if self.position < self.text.len() {
+            return Err(self.unexpected(ctx));
+        }
+        Ok(value)
+    }
+
+    fn parse_value(&mut self, ctx: &mut Context) -> Result<Value> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(0x7B) => self.nested(ctx, Self::parse_object),
+            Some(0x5B) => self.nested(ctx, Self::parse_array),
+            Some(0x22) => Ok(self.parse_string(ctx)?.into()),
+            Some(0x74) => self.parse_literal("true", Value::from(true), ctx),
+            Some(0x66) => self.parse_literal("false", Value::from(false), ctx),
+            Some(0x6E) => self.parse_literal("null", Value::null(), ctx),
+            Some(unit) if unit == 0x2D || is_digit(unit) => self.parse_number(ctx),
+            _ => Err(self.unexpected(ctx)),
+        }
+    }
+
+    fn nested<F>(&mut self, ctx: &mut Context, parse: F) -> Result<Value>
+    where
+        F: FnOnce(&mut Self, &mut Context) -> Result<Value>,
+    {
+        if self.depth == MAX_PARSE_DEPTH {
+            return Err(ctx.construct_syntax_error("JSON nesting is too deep"));
+        }
+        self.depth += 1;
+        let value = parse(self, ctx);
+        self.depth -= 1;
+        value
+    }
+
+    fn parse_object(&mut self, ctx: &mut Context) -> Result<Value> {
+        self.position += 1;
+        let object = Value::new_object(Some(ctx.global_object()));
+        self.skip_whitespace();
+        if self.eat(0x7D) {
+            return Ok(object);
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(0x22) {
+                return Err(self.unexpected(ctx));
+            }
+            let key = self.parse_string(ctx)?;
+            self.skip_whitespace();
+            if !self.eat(0x3A) {
+                return Err(self.unexpected(ctx));
+            }
+            let value = self.parse_value(ctx)?;
+            object.set_property(
+                key,
+                DataDescriptor::new(
+                    value,
+                    Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+            self.skip_whitespace();
+            if self.eat(0x7D) {
+                return Ok(object);
+            }
+            if !self.eat(0x2C) {
+                return Err(self.unexpected(ctx));
+            }
+        }
+    }
+
+    fn parse_array(&mut self, ctx: &mut Context) -> Result<Value> {
+        self.position += 1;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if !self.eat(0x5D) {
+            loop {
+                elements.push(self.parse_value(ctx)?);
+                self.skip_whitespace();
+                if self.eat(0x5D) {
+                    break;
                 }
-            });
-            for field in fields {
-                if let Some(value) = object
-                    .get_property(field.to_string(ctx)?)
-                    // FIXME: handle accessor descriptors
-                    .map(|prop| prop.as_data_descriptor().unwrap().value().to_json(ctx))
-                    .transpose()?
-                {
-                    obj_to_return.insert(field.to_string(ctx)?.to_string(), value);
+                if !self.eat(0x2C) {
+                    return Err(self.unexpected(ctx));
                 }
             }
-            Ok(Value::from(JSONValue::Object(obj_to_return).to_string()))
+        }
+        Ok(Array::create_array_from_list(elements, ctx))
+    }
+
+    fn parse_string(&mut self, ctx: &mut Context) -> Result<RcString> {
+        self.position += 1;
+        let mut units = Vec::new();
+        loop {
+            let unit = match self.peek() {
+                Some(unit) if unit >= 0x20 => unit,
+                _ => return Err(self.unexpected(ctx)),
+            };
+            self.position += 1;
+            match unit {
+                0x22 => return Ok(RcString::from(units)),
+                0x5C => {
+                    let escaped = match self.peek() {
+                        Some(0x22) => 0x22,
+                        Some(0x5C) => 0x5C,
+                        Some(0x2F) => 0x2F,
+                        Some(0x62) => 0x08,
+                        Some(0x66) => 0x0C,
+                        Some(0x6E) => 0x0A,
+                        Some(0x72) => 0x0D,
+                        Some(0x74) => 0x09,
+                        Some(0x75) => {
+                            let digits = self
+                                .text
+                                .get(self.position + 1..self.position + 5)
+                                .and_then(|digits| String::from_utf16(digits).ok())
+                                .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+                                .and_then(|digits| u16::from_str_radix(&digits, 16).ok());
+                            match digits {
+                                Some(unit) => {
+                                    self.position += 4;
+                                    unit
+                                }
+                                None => return Err(self.unexpected(ctx)),
+                            }
+                        }
+                        _ => return Err(self.unexpected(ctx)),
+                    };
+                    self.position += 1;
+                    units.push(escaped);
+                }
+                unit => units.push(unit),
+            }
+        }
+    }
+
+    fn parse_number(&mut self, ctx: &mut Context) -> Result<Value> {
+        let start = self.position;
+        self.eat(0x2D);
+        if !self.eat(0x30) && self.eat_digits() == 0 {
+            return Err(self.unexpected(ctx));
+        }
+        if self.eat(0x2E) && self.eat_digits() == 0 {
+            return Err(self.unexpected(ctx));
+        }
+        if self.eat(0x65) || self.eat(0x45) {
+            if !self.eat(0x2B) {
+                self.eat(0x2D);
+            }
+            if self.eat_digits() == 0 {
+                return Err(self.unexpected(ctx));
+            }
+        }
+
+        let number: f64 = String::from_utf16_lossy(&self.text[start..self.position])
+            .parse()
+            .expect("a JSON number is a valid float literal");
+        if number.fract() == 0.0
+            && number >= f64::from(i32::MIN)
+            && number <= f64::from(i32::MAX)
+            && !(number == 0.0 && number.is_sign_negative())
+        {
+            Ok(Value::integer(number as i32))
         } else {
-            Ok(Value::from(object.to_json(ctx)?.to_string()))
+            Ok(Value::rational(number))
+        }
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: Value, ctx: &mut Context) -> Result<Value> {
+        for unit in literal.encode_utf16() {
+            if !self.eat(unit) {
+                return Err(self.unexpected(ctx));
+            }
         }
+        Ok(value)
+    }
+
+    fn peek(&self) -> Option<u16> {
+        self.text.get(self.position).copied()
     }
+
+    fn eat(&mut self, unit: u16) -> bool {
+        if self.peek() == Some(unit) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_digits(&mut self) -> usize {
+        let start = self.position;
+        while self.peek().is_some_and(is_digit) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(0x09) | Some(0x0A) | Some(0x0D) | Some(0x20) = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn unexpected(&self, ctx: &mut Context) -> Value {
+        if self.position < self.text.len() {
+            ctx.construct_syntax_error(format!(
+                "unexpected character in JSON at position {}",
+                self.position
+            ))
+        } else {
+            ctx.construct_syntax_error("unexpected end of JSON input")
+        }
+    }
+}
+
+fn is_digit(unit: u16) -> bool {
+    (0x30..=0x39).contains(&unit)
 }
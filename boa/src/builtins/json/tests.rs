@@ -1,4 +1,4 @@
-use crate::{forward, forward_val, object::PROTOTYPE, value::same_value, Context};
+use crate::{forward, forward_val, value::same_value, Context, Value};
 
 #[test]
 fn json_sanity() {
@@ -55,6 +55,19 @@ fn json_stringify_remove_symbols_from_objects() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn json_stringify_property_order() {
+    let mut engine = Context::new();
+
+    let actual = forward(
+        &mut engine,
+        r#"JSON.stringify({ b: 1, a: 2, 10: 3, 2: 4, nested: { z: 1, y: 2 } })"#,
+    );
+    let expected = r#""{"2":4,"10":3,"b":1,"a":2,"nested":{"z":1,"y":2}}""#;
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn json_stringify_replacer_array_strings() {
     let mut engine = Context::new();
@@ -212,19 +225,35 @@ fn json_parse_array_with_reviver() {
     )
     .unwrap();
     assert_eq!(
-        result.get_field("0").to_number(&mut engine).unwrap() as u8,
+        result
+            .get_field("0", &mut engine)
+            .unwrap()
+            .to_number(&mut engine)
+            .unwrap() as u8,
         2u8
     );
     assert_eq!(
-        result.get_field("1").to_number(&mut engine).unwrap() as u8,
+        result
+            .get_field("1", &mut engine)
+            .unwrap()
+            .to_number(&mut engine)
+            .unwrap() as u8,
         4u8
     );
     assert_eq!(
-        result.get_field("2").to_number(&mut engine).unwrap() as u8,
+        result
+            .get_field("2", &mut engine)
+            .unwrap()
+            .to_number(&mut engine)
+            .unwrap() as u8,
         6u8
     );
     assert_eq!(
-        result.get_field("3").to_number(&mut engine).unwrap() as u8,
+        result
+            .get_field("3", &mut engine)
+            .unwrap()
+            .to_number(&mut engine)
+            .unwrap() as u8,
         8u8
     );
 }
@@ -278,14 +307,9 @@ fn json_parse_sets_prototypes() {
         .unwrap()
         .prototype_instance()
         .clone();
-    let global_object_prototype = engine
-        .global_object()
-        .get_field("Object")
-        .get_field(PROTOTYPE);
-    let global_array_prototype = engine
-        .global_object()
-        .get_field("Array")
-        .get_field(PROTOTYPE);
+    let global_object_prototype: Value =
+        engine.standard_objects().object_object().prototype().into();
+    let global_array_prototype: Value = engine.standard_objects().array_object().prototype().into();
     assert_eq!(
         same_value(&object_prototype, &global_object_prototype),
         true
@@ -322,3 +346,56 @@ fn json_parse_with_no_args_throws_syntax_error() {
     let result = forward(&mut engine, "JSON.parse();");
     assert!(result.contains("SyntaxError"));
 }
+
+#[test]
+fn json_lone_surrogates() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, r#"JSON.stringify('\uD800')"#),
+        r#"""\ud800"""#
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"JSON.stringify('\uD83D\uDE00') === '"\uD83D\uDE00"'"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, r#"JSON.parse('"\\udc00"') === '\uDC00'"#),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"
+            var key = Object.keys(JSON.parse(JSON.stringify({ ['a\uD800']: 1 })))[0];
+            key === 'a\uD800'
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, r#"JSON.stringify({ ['\uDFFF']: '\uDFFF' })"#),
+        r#""{"\udfff":"\udfff"}""#
+    );
+}
+
+#[test]
+fn json_stringify_nested_replacers() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"JSON.stringify({ a: [1, { b: 2 }] }, (key, value) => typeof value === 'number' ? value * 10 : value)"#
+        ),
+        forward(&mut engine, r#"'{"a":[10,{"b":20}]}'"#)
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"JSON.stringify({ a: 1, b: { a: 2, c: 3 } }, ['a', 'b'])"#
+        ),
+        forward(&mut engine, r#"'{"a":1,"b":{"a":2}}'"#)
+    );
+}
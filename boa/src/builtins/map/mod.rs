@@ -2,7 +2,7 @@
 
 use crate::{
     builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    object::{ConstructorBuilder, ObjectData},
     property::{Attribute, DataDescriptor},
     BoaProfiler, Context, Result, Value,
 };
@@ -46,14 +46,8 @@ impl Map {
 
     /// Create a new map
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // Set Prototype
-        let prototype = ctx.global_object().get_field("Map").get_field(PROTOTYPE);
-
-        this.as_object_mut()
-            .expect("this is map object")
-            .set_prototype_instance(prototype);
-        // This value is used by console.log and other routines to match Object type
-        // to its Javascript Identifier (global constructor method name)
+        // The prototype of `this` was taken from the `prototype` of `new.target`, which is a
+        // subclass of `Map` in `class Registry extends Map {}`.
 
         // add our arguments in
         let data = match args.len() {
@@ -65,10 +59,10 @@ impl Map {
                         map
                     } else if object.is_array() {
                         let mut map = OrderedMap::new();
-                        let len = args[0].get_field("length").to_integer(ctx)? as i32;
+                        let len = args[0].get_field("length", ctx)?.to_integer(ctx)? as i32;
                         for i in 0..len {
-                            let val = &args[0].get_field(i.to_string());
-                            let (key, value) = Self::get_key_value(val).ok_or_else(|| {
+                            let val = &args[0].get_field(i.to_string(), ctx)?;
+                            let (key, value) = Self::get_key_value(val, ctx)?.ok_or_else(|| {
                                 ctx.construct_type_error(
                                     "iterable for Map should have array-like objects",
                                 )
@@ -286,17 +280,18 @@ impl Map {
     }
 
     /// Helper function to get a key-value pair from an array.
-    fn get_key_value(value: &Value) -> Option<(Value, Value)> {
+    fn get_key_value(value: &Value, ctx: &mut Context) -> Result<Option<(Value, Value)>> {
         if let Value::Object(object) = value {
             if object.borrow().is_array() {
-                let (key, value) = match value.get_field("length").as_number().unwrap() as i32 {
+                let (key, value) = match value.get_field("length", ctx)?.as_number().unwrap() as i32
+                {
                     0 => (Value::Undefined, Value::Undefined),
-                    1 => (value.get_field("0"), Value::Undefined),
-                    _ => (value.get_field("0"), value.get_field("1")),
+                    1 => (value.get_field("0", ctx)?, Value::Undefined),
+                    _ => (value.get_field("0", ctx)?, value.get_field("1", ctx)?),
                 };
-                return Some((key, value));
+                return Ok(Some((key, value)));
             }
         }
-        None
+        Ok(None)
     }
 }
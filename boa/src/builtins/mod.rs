@@ -1,13 +1,18 @@
 //! Builtins live here, such as Object, String, Math, etc.
 
 pub mod array;
+pub mod array_buffer;
+pub mod async_function;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
 pub mod console;
+pub mod data_view;
 pub mod date;
 pub mod error;
+pub mod eval;
 pub mod function;
+pub mod generator;
 pub mod global_this;
 pub mod infinity;
 pub mod iterable;
@@ -17,17 +22,31 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
+pub mod proxy;
+pub mod reflect;
 pub mod regexp;
+pub mod set;
 pub mod string;
 pub mod symbol;
+pub mod typed_array;
 pub mod undefined;
+pub mod uri;
+pub mod weak_map;
+pub mod weak_set;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
+    array_buffer::ArrayBuffer,
     bigint::BigInt,
     boolean::Boolean,
+    data_view::DataView,
     date::Date,
-    error::{Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError},
+    error::{
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
+        UriError,
+    },
+    eval::Eval,
     function::BuiltInFunctionObject,
     global_this::GlobalThis,
     infinity::Infinity,
@@ -37,10 +56,21 @@ pub(crate) use self::{
     nan::NaN,
     number::Number,
     object::Object as BuiltInObjectObject,
+    promise::Promise,
+    proxy::Proxy,
+    reflect::Reflect,
     regexp::RegExp,
+    set::Set,
     string::String,
     symbol::Symbol,
+    typed_array::{
+        BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array,
+        Int8Array, TypedArrayObject, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
+    },
     undefined::Undefined,
+    uri::Uri,
+    weak_map::WeakMap,
+    weak_set::WeakSet,
 };
 use crate::{
     property::{Attribute, DataDescriptor},
@@ -65,6 +95,7 @@ pub fn init(context: &mut Context) {
         NaN::init,
         GlobalThis::init,
         BuiltInFunctionObject::init,
+        Eval::init,
         BuiltInObjectObject::init,
         Math::init,
         Json::init,
@@ -73,6 +104,9 @@ pub fn init(context: &mut Context) {
         Boolean::init,
         Date::init,
         Map::init,
+        Set::init,
+        WeakMap::init,
+        WeakSet::init,
         Number::init,
         String::init,
         RegExp::init,
@@ -84,6 +118,23 @@ pub fn init(context: &mut Context) {
         SyntaxError::init,
         EvalError::init,
         UriError::init,
+        AggregateError::init,
+        Promise::init,
+        Proxy::init,
+        Reflect::init,
+        ArrayBuffer::init,
+        DataView::init,
+        Int8Array::init,
+        Uint8Array::init,
+        Uint8ClampedArray::init,
+        Int16Array::init,
+        Uint16Array::init,
+        Int32Array::init,
+        Uint32Array::init,
+        Float32Array::init,
+        Float64Array::init,
+        BigInt64Array::init,
+        BigUint64Array::init,
         #[cfg(feature = "console")]
         console::Console::init,
     ];
@@ -99,4 +150,10 @@ pub fn init(context: &mut Context) {
         let property = DataDescriptor::new(value, attribute);
         global_object.borrow_mut().insert(name, property);
     }
+
+    // `%TypedArray%` has no global binding, and shares `toString` with `Array.prototype`.
+    TypedArrayObject::init(context);
+
+    // The URI handling functions are several global bindings that share their implementation.
+    Uri::init(context);
 }
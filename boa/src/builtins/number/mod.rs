@@ -13,10 +13,9 @@
 //! [spec]: https://tc39.es/ecma262/#sec-number-object
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number
 
-use super::function::make_builtin_fn;
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData},
+    builtins::{string::String as BuiltInString, BuiltIn},
+    object::{ConstructorBuilder, FunctionBuilder, ObjectData},
     property::Attribute,
     value::{AbstractRelation, Value},
     BoaProfiler, Context, Result,
@@ -52,6 +51,35 @@ impl BuiltIn for Number {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        // `Number.parseInt` and `Number.parseFloat` are the same function objects as the global
+        // `parseInt` and `parseFloat`.
+        let parse_int = FunctionBuilder::new(context, Self::parse_int)
+            .name("parseInt")
+            .length(PARSE_INT_MAX_ARG_COUNT)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let parse_float = FunctionBuilder::new(context, Self::parse_float)
+            .name("parseFloat")
+            .length(PARSE_FLOAT_MAX_ARG_COUNT)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let is_finite = FunctionBuilder::new(context, Self::global_is_finite)
+            .name("isFinite")
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let is_nan = FunctionBuilder::new(context, Self::global_is_nan)
+            .name("isNaN")
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        let function_attribute =
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
         let number_object = ConstructorBuilder::with_standard_object(
             context,
@@ -78,25 +106,20 @@ impl BuiltIn for Number {
         .static_method(Self::number_is_nan, "isNaN", 1)
         .static_method(Self::is_safe_integer, "isSafeInteger", 1)
         .static_method(Self::number_is_integer, "isInteger", 1)
+        .static_property("parseInt", parse_int.clone(), function_attribute)
+        .static_property("parseFloat", parse_float.clone(), function_attribute)
         .build();
 
-        let global = context.global_object().clone();
-        make_builtin_fn(
-            Self::parse_int,
-            "parseInt",
-            &global,
-            PARSE_INT_MAX_ARG_COUNT,
-            context,
-        );
-        make_builtin_fn(
-            Self::parse_float,
-            "parseFloat",
-            &global,
-            PARSE_FLOAT_MAX_ARG_COUNT,
-            context,
-        );
-        make_builtin_fn(Self::global_is_finite, "isFinite", &global, 1, context);
-        make_builtin_fn(Self::global_is_nan, "isNaN", &global, 1, context);
+        {
+            let mut global = context
+                .global_object()
+                .as_object_mut()
+                .expect("global object");
+            global.insert_property("parseInt", parse_int, function_attribute);
+            global.insert_property("parseFloat", parse_float, function_attribute);
+            global.insert_property("isFinite", is_finite, function_attribute);
+            global.insert_property("isNaN", is_nan, function_attribute);
+        }
 
         (Self::NAME, number_object.into(), Self::attribute())
     }
@@ -491,11 +514,11 @@ impl Number {
 
     /// Builtin javascript 'parseInt(str, radix)' function.
     ///
-    /// Parses the given string as an integer using the given radix as a base.
+    /// Parses the integer at the start of the given string, ignoring leading whitespace, using
+    /// the given radix as a base.
     ///
-    /// An argument of type Number (i.e. Integer or Rational) is also accepted in place of string.
-    ///
-    /// The radix must be an integer in the range [2, 36] inclusive.
+    /// The radix must be an integer in the range [2, 36] inclusive. If it is `0` or missing, the
+    /// radix is 16 for strings starting with `0x` or `0X`, and 10 otherwise.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -503,65 +526,60 @@ impl Number {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-parseint-string-radix
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseInt
-    pub(crate) fn parse_int(_this: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
-        if let (Some(val), r) = (args.get(0), args.get(1)) {
-            let mut radix = if let Some(rx) = r {
-                if let Value::Integer(i) = rx {
-                    *i as u32
-                } else {
-                    // Handling a second argument that isn't an integer but was provided so cannot be defaulted.
-                    return Ok(Value::from(f64::NAN));
-                }
-            } else {
-                // No second argument provided therefore radix is unknown
-                0
-            };
-
-            match val {
-                Value::String(s) => {
-                    // Attempt to infer radix from given string.
-
-                    if radix == 0 {
-                        if s.starts_with("0x") || s.starts_with("0X") {
-                            return if let Ok(i) = i32::from_str_radix(&s[2..], 16) {
-                                Ok(Value::integer(i))
-                            } else {
-                                // String can't be parsed.
-                                Ok(Value::from(f64::NAN))
-                            };
-                        } else {
-                            radix = 10
-                        };
-                    }
+    pub(crate) fn parse_int(_this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let input = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+        let mut radix = args.get(1).cloned().unwrap_or_default().to_i32(ctx)?;
+
+        let input = input.to_std_string_lossy();
+        let mut string = input.trim_start_matches(BuiltInString::is_trimmable_whitespace);
+        let sign = if string.starts_with('-') { -1.0 } else { 1.0 };
+        if string.starts_with('-') || string.starts_with('+') {
+            string = &string[1..];
+        }
 
-                    if let Ok(i) = i32::from_str_radix(s, radix) {
-                        Ok(Value::integer(i))
-                    } else {
-                        // String can't be parsed.
-                        Ok(Value::from(f64::NAN))
-                    }
-                }
-                Value::Integer(i) => Ok(Value::integer(*i)),
-                Value::Rational(f) => Ok(Value::integer(*f as i32)),
-                _ => {
-                    // Wrong argument type to parseInt.
-                    Ok(Value::from(f64::NAN))
-                }
+        let mut strip_prefix = true;
+        if radix != 0 {
+            if !(2..=36).contains(&radix) {
+                return Ok(Value::nan());
+            }
+            if radix != 16 {
+                strip_prefix = false;
             }
         } else {
-            // Not enough arguments to parseInt.
-            Ok(Value::from(f64::NAN))
+            radix = 10;
+        }
+        if strip_prefix && (string.starts_with("0x") || string.starts_with("0X")) {
+            string = &string[2..];
+            radix = 16;
         }
+
+        let radix = radix as u32;
+        let end = string
+            .find(|c: char| !c.is_digit(radix))
+            .unwrap_or(string.len());
+        let digits = &string[..end];
+        if digits.is_empty() {
+            return Ok(Value::nan());
+        }
+
+        // Decimal numbers are rounded correctly, the other radixes may lose precision when the
+        // number is too big, which is allowed by the specification.
+        let number = if radix == 10 {
+            digits
+                .parse::<f64>()
+                .expect("a string of decimal digits is a valid float")
+        } else {
+            digits.chars().fold(0.0, |number, digit| {
+                let digit = digit.to_digit(radix).expect("digit was checked above");
+                number * f64::from(radix) + f64::from(digit)
+            })
+        };
+        Ok(Value::from(sign * number))
     }
 
     /// Builtin javascript 'parseFloat(str)' function.
     ///
-    /// Parses the given string as a floating point value.
-    ///
-    /// An argument of type Number (i.e. Integer or Rational) is also accepted in place of string.
-    ///
-    /// To improve performance an Integer type Number is returned in place of a Rational if the given
-    /// string can be parsed and stored as an Integer.
+    /// Parses the decimal number at the start of the given string, ignoring leading whitespace.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -569,32 +587,58 @@ impl Number {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-parsefloat-string
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseFloat
-    pub(crate) fn parse_float(_this: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
-        if let Some(val) = args.get(0) {
-            match val {
-                Value::String(s) => {
-                    if let Ok(i) = s.parse::<i32>() {
-                        // Attempt to parse an integer first so that it can be stored as an integer
-                        // to improve performance
-                        Ok(Value::integer(i))
-                    } else if let Ok(f) = s.parse::<f64>() {
-                        Ok(Value::rational(f))
-                    } else {
-                        // String can't be parsed.
-                        Ok(Value::from(f64::NAN))
-                    }
-                }
-                Value::Integer(i) => Ok(Value::integer(*i)),
-                Value::Rational(f) => Ok(Value::rational(*f)),
-                _ => {
-                    // Wrong argument type to parseFloat.
-                    Ok(Value::from(f64::NAN))
-                }
+    pub(crate) fn parse_float(_this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let input = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+        let input = input.to_std_string_lossy();
+        let string = input.trim_start_matches(BuiltInString::is_trimmable_whitespace);
+
+        // Finds the longest prefix that is a `StrDecimalLiteral`.
+        let bytes = string.as_bytes();
+        let mut end = 0;
+        if let Some(b'+') | Some(b'-') = bytes.first() {
+            end += 1;
+        }
+        if string[end..].starts_with("Infinity") {
+            let number = if string.starts_with('-') {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+            return Ok(Value::from(number));
+        }
+        let count_digits = |start: usize| {
+            bytes[start..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count()
+        };
+        let integer_digits = count_digits(end);
+        end += integer_digits;
+        let mut fraction_digits = 0;
+        if bytes.get(end) == Some(&b'.') {
+            fraction_digits = count_digits(end + 1);
+            if integer_digits > 0 || fraction_digits > 0 {
+                end += 1 + fraction_digits;
             }
-        } else {
-            // Not enough arguments to parseFloat.
-            Ok(Value::from(f64::NAN))
         }
+        if integer_digits == 0 && fraction_digits == 0 {
+            return Ok(Value::nan());
+        }
+        if let Some(b'e') | Some(b'E') = bytes.get(end) {
+            let mut exponent_end = end + 1;
+            if let Some(b'+') | Some(b'-') = bytes.get(exponent_end) {
+                exponent_end += 1;
+            }
+            let exponent_digits = count_digits(exponent_end);
+            if exponent_digits > 0 {
+                end = exponent_end + exponent_digits;
+            }
+        }
+
+        let number = string[..end]
+            .parse::<f64>()
+            .expect("a decimal literal is a valid float");
+        Ok(Value::from(number))
     }
 
     /// Builtin javascript 'isFinite(number)' function.
@@ -494,7 +494,7 @@ fn parse_int_float() {
 fn parse_int_float_str() {
     let mut engine = Context::new();
 
-    assert_eq!(&forward(&mut engine, "parseInt(\"100.5\")"), "NaN");
+    assert_eq!(&forward(&mut engine, "parseInt(\"100.5\")"), "100");
 }
 
 #[test]
@@ -815,3 +815,54 @@ fn number_is_safe_integer() {
         &forward(&mut engine, "Number.isSafeInteger(new Number(5))")
     );
 }
+
+#[test]
+fn parse_int_prefix() {
+    let mut engine = Context::new();
+
+    assert_eq!(&forward(&mut engine, "parseInt(\"  \\n42px\")"), "42");
+    assert_eq!(&forward(&mut engine, "parseInt(\"-0x1F\")"), "-31");
+    assert_eq!(&forward(&mut engine, "parseInt(\"0x1F\", 16)"), "31");
+    assert_eq!(&forward(&mut engine, "parseInt(\"0x1F\", 10)"), "0");
+    assert_eq!(&forward(&mut engine, "parseInt(\"z\", 36)"), "35");
+    assert_eq!(&forward(&mut engine, "parseInt(\"10\", 1)"), "NaN");
+    assert_eq!(&forward(&mut engine, "parseInt(\"10\", 37)"), "NaN");
+    assert_eq!(&forward(&mut engine, "parseInt(\"\")"), "NaN");
+    assert_eq!(&forward(&mut engine, "1 / parseInt(\"-0\")"), "-Infinity");
+}
+
+#[test]
+fn parse_float_prefix() {
+    let mut engine = Context::new();
+
+    assert_eq!(&forward(&mut engine, "parseFloat(\" 3.14abc\")"), "3.14");
+    assert_eq!(&forward(&mut engine, "parseFloat(\".5\")"), "0.5");
+    assert_eq!(&forward(&mut engine, "parseFloat(\"1e3\")"), "1000");
+    assert_eq!(&forward(&mut engine, "parseFloat(\"1e\")"), "1");
+    assert_eq!(
+        &forward(&mut engine, "parseFloat(\"-Infinityx\")"),
+        "-Infinity"
+    );
+    assert_eq!(&forward(&mut engine, "parseFloat(\"0x10\")"), "0");
+    assert_eq!(&forward(&mut engine, "parseFloat(\".\")"), "NaN");
+}
+
+#[test]
+fn global_number_functions() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        &forward(&mut engine, "Number.parseInt === parseInt"),
+        "true"
+    );
+    assert_eq!(
+        &forward(&mut engine, "Number.parseFloat === parseFloat"),
+        "true"
+    );
+    assert_eq!(&forward(&mut engine, "parseInt.length"), "2");
+    assert_eq!(&forward(&mut engine, "isNaN.name"), "\"isNaN\"");
+    assert_eq!(
+        &forward(&mut engine, "Object.keys(globalThis).indexOf('isFinite')"),
+        "-1"
+    );
+}
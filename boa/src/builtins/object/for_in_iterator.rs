@@ -0,0 +1,142 @@
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    object::{GcObject, ObjectData},
+    property::PropertyKey,
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use rustc_hash::FxHashSet;
+use std::collections::VecDeque;
+
+/// The For-In Iterator object represents an iteration over the enumerable string keys of an
+/// object and its prototype chain, as done by the `for...in` statement.
+///
+/// The keys of each object are only read once the iteration reaches it, and a key is skipped if
+/// it was deleted before being visited, or if it is shadowed by an object earlier in the chain.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-for-in-iterator-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct ForInIterator {
+    object: Value,
+    visited_keys: FxHashSet<RcString>,
+    remaining_keys: VecDeque<RcString>,
+    object_was_visited: bool,
+}
+
+impl ForInIterator {
+    pub(crate) const NAME: &'static str = "ForInIterator";
+
+    fn new(object: Value) -> Self {
+        ForInIterator {
+            object,
+            visited_keys: FxHashSet::default(),
+            remaining_keys: VecDeque::default(),
+            object_was_visited: false,
+        }
+    }
+
+    /// CreateForInIterator( object )
+    ///
+    /// Creates a new iterator over the given object.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createforiniterator
+    pub(crate) fn create_for_in_iterator(ctx: &Context, object: Value) -> Result<Value> {
+        let for_in_iterator = Value::new_object(Some(ctx.global_object()));
+        for_in_iterator.set_data(ObjectData::ForInIterator(Self::new(object)));
+        for_in_iterator
+            .as_object_mut()
+            .expect("for in iterator object")
+            .set_prototype_instance(ctx.iterator_prototypes().for_in_iterator().into());
+        Ok(for_in_iterator)
+    }
+
+    /// %ForInIteratorPrototype%.next( )
+    ///
+    /// Gets the next key of the object.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%foriniteratorprototype%.next
+    pub(crate) fn next(this: &Value, _args: &[Value], ctx: &mut Context) -> Result<Value> {
+        if let Value::Object(ref o) = this {
+            let mut for_in_iterator = o.borrow_mut();
+            if let Some(iterator) = for_in_iterator.as_for_in_iterator_mut() {
+                while let Some(object) = iterator.object.as_gc_object() {
+                    if !iterator.object_was_visited {
+                        let keys = object.own_property_keys(ctx)?;
+                        for key in keys {
+                            match key {
+                                PropertyKey::String(ref name) => {
+                                    iterator.remaining_keys.push_back(name.clone())
+                                }
+                                PropertyKey::Index(index) => {
+                                    iterator.remaining_keys.push_back(index.to_string().into())
+                                }
+                                PropertyKey::Symbol(_) => {}
+                            }
+                        }
+                        iterator.object_was_visited = true;
+                    }
+                    while let Some(key) = iterator.remaining_keys.pop_front() {
+                        if iterator.visited_keys.contains(&key) {
+                            continue;
+                        }
+                        // Properties deleted before being visited are skipped.
+                        if let Some(enumerable) = own_property_enumerable(&object, &key, ctx)? {
+                            iterator.visited_keys.insert(key.clone());
+                            if enumerable {
+                                return Ok(create_iter_result_object(ctx, Value::from(key), false));
+                            }
+                        }
+                    }
+                    iterator.object = object.get_prototype_of(ctx)?;
+                    iterator.object_was_visited = false;
+                }
+                Ok(create_iter_result_object(ctx, Value::undefined(), true))
+            } else {
+                ctx.throw_type_error("`this` is not a ForInIterator")
+            }
+        } else {
+            ctx.throw_type_error("`this` is not a ForInIterator")
+        }
+    }
+
+    /// Create the %ForInIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%foriniteratorprototype%-object
+    pub(crate) fn create_prototype(ctx: &mut Context, iterator_prototype: Value) -> Value {
+        let global = ctx.global_object();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let for_in_iterator = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &for_in_iterator, 0, ctx);
+        for_in_iterator
+            .as_object_mut()
+            .expect("for in iterator prototype object")
+            .set_prototype_instance(iterator_prototype);
+        for_in_iterator
+    }
+}
+
+/// Checks if an object has an own property with the given key, and if it is enumerable.
+fn own_property_enumerable(
+    object: &GcObject,
+    key: &RcString,
+    ctx: &mut Context,
+) -> Result<Option<bool>> {
+    let key = PropertyKey::from(key.clone());
+    let desc = object.get_own_property(&key, ctx)?;
+    Ok(desc.map(|desc| desc.enumerable()))
+}
@@ -14,15 +14,20 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, Object as BuiltinObject, ObjectData, ObjectInitializer},
+    builtins::{iterable::get_iterator, Array, BuiltIn},
+    object::{
+        ConstructorBuilder, GcObject, IntegrityLevel, Object as BuiltinObject, ObjectData,
+        ObjectInitializer,
+    },
     property::Attribute,
     property::DataDescriptor,
     property::PropertyDescriptor,
-    value::{same_value, Value},
+    property::PropertyKey,
+    value::{same_value, RcString, Value},
     BoaProfiler, Context, Result,
 };
 
+pub mod for_in_iterator;
 #[cfg(test)]
 mod tests;
 
@@ -30,6 +35,14 @@ mod tests;
 #[derive(Debug, Clone, Copy)]
 pub struct Object;
 
+/// The kind of the properties returned by `EnumerableOwnPropertyNames`.
+#[derive(Debug, Clone, Copy)]
+enum PropertyNameKind {
+    Key,
+    Value,
+    KeyAndValue,
+}
+
 impl BuiltIn for Object {
     const NAME: &'static str = "Object";
 
@@ -67,6 +80,19 @@ impl BuiltIn for Object {
             "getOwnPropertyDescriptors",
             1,
         )
+        .static_method(Self::get_own_property_names, "getOwnPropertyNames", 1)
+        .static_method(Self::get_own_property_symbols, "getOwnPropertySymbols", 1)
+        .static_method(Self::keys, "keys", 1)
+        .static_method(Self::values, "values", 1)
+        .static_method(Self::entries, "entries", 1)
+        .static_method(Self::from_entries, "fromEntries", 1)
+        .static_method(Self::assign, "assign", 2)
+        .static_method(Self::freeze, "freeze", 1)
+        .static_method(Self::is_frozen, "isFrozen", 1)
+        .static_method(Self::seal, "seal", 1)
+        .static_method(Self::is_sealed, "isSealed", 1)
+        .static_method(Self::prevent_extensions, "preventExtensions", 1)
+        .static_method(Self::is_extensible, "isExtensible", 1)
         .build();
 
         (Self::NAME, object.into(), Self::attribute())
@@ -140,7 +166,7 @@ impl Object {
         if let Some(key) = args.get(1) {
             let key = key.to_property_key(ctx)?;
 
-            if let Some(desc) = object.borrow().get_own_property(&key) {
+            if let Some(desc) = object.get_own_property(&key, ctx)? {
                 return Ok(Self::from_property_descriptor(desc, ctx)?);
             }
         }
@@ -166,16 +192,9 @@ impl Object {
         let object = args.get(0).unwrap_or(&Value::undefined()).to_object(ctx)?;
         let descriptors = ctx.construct_object();
 
-        for key in object.borrow().keys() {
-            let descriptor = {
-                let desc = object
-                    .borrow()
-                    .get_own_property(&key)
-                    .expect("Expected property to be on object.");
-                Self::from_property_descriptor(desc, ctx)?
-            };
-
-            if !descriptor.is_undefined() {
+        for key in object.own_property_keys(ctx)? {
+            if let Some(desc) = object.get_own_property(&key, ctx)? {
+                let descriptor = Self::from_property_descriptor(desc, ctx)?;
                 descriptors.borrow_mut().insert(
                     key,
                     PropertyDescriptor::from(DataDescriptor::new(descriptor, Attribute::all())),
@@ -190,7 +209,10 @@ impl Object {
     ///
     /// [ECMAScript reference][spec]
     /// [spec]: https://tc39.es/ecma262/#sec-frompropertydescriptor
-    fn from_property_descriptor(desc: PropertyDescriptor, ctx: &mut Context) -> Result<Value> {
+    pub(crate) fn from_property_descriptor(
+        desc: PropertyDescriptor,
+        ctx: &mut Context,
+    ) -> Result<Value> {
         let mut descriptor = ObjectInitializer::new(ctx);
 
         if let PropertyDescriptor::Data(data_desc) = &desc {
@@ -236,28 +258,68 @@ impl Object {
         Ok(same_value(&x, &y).into())
     }
 
-    /// Get the `prototype` of an object.
-    pub fn get_prototype_of(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let obj = args.get(0).expect("Cannot get object");
-        Ok(obj.as_object().map_or_else(Value::undefined, |object| {
-            object.prototype_instance().clone()
-        }))
+    /// `Object.getPrototypeOf( object )`
+    ///
+    /// Returns the prototype of an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getprototypeof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getPrototypeOf
+    pub fn get_prototype_of(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default().to_object(ctx)?;
+        object.get_prototype_of(ctx)
     }
 
-    /// Set the `prototype` of an object.
-    pub fn set_prototype_of(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let obj = args.get(0).expect("Cannot get object").clone();
-        let proto = args.get(1).expect("Cannot get object").clone();
-        obj.as_object_mut().unwrap().set_prototype_instance(proto);
+    /// `Object.setPrototypeOf( object, prototype )`
+    ///
+    /// Sets the prototype of an object, throwing a `TypeError` if it can't be changed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.setprototypeof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/setPrototypeOf
+    pub fn set_prototype_of(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let obj = args.get(0).cloned().unwrap_or_default();
+        obj.require_object_coercible(ctx)?;
+        let proto = args.get(1).cloned().unwrap_or_default();
+        if !proto.is_object() && !proto.is_null() {
+            return ctx.throw_type_error(format!(
+                "Object prototype may only be an Object or null: {}",
+                proto.display()
+            ));
+        }
+        if let Value::Object(ref object) = obj {
+            if !object.set_prototype_of(proto, ctx)? {
+                return ctx.throw_type_error("can't set prototype of this object");
+            }
+        }
         Ok(obj)
     }
 
-    /// Define a property in an object
+    /// `Object.defineProperty( object, property, descriptor )`
+    ///
+    /// Defines a property on an object, throwing a `TypeError` if it can't be defined.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.defineproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty
     pub fn define_property(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let obj = args.get(0).expect("Cannot get object");
+        let obj = match args.get(0) {
+            Some(Value::Object(ref object)) => object.clone(),
+            _ => return context.throw_type_error("Object.defineProperty called on non-object"),
+        };
         let prop = args
             .get(1)
-            .expect("Cannot get object")
+            .cloned()
+            .unwrap_or_default()
             .to_property_key(context)?;
 
         let desc = if let Value::Object(ref object) = args.get(2).cloned().unwrap_or_default() {
@@ -265,8 +327,10 @@ impl Object {
         } else {
             return context.throw_type_error("Property description must be an object");
         };
-        obj.set_property(prop, desc);
-        Ok(Value::undefined())
+        if !obj.define_own_property(prop.clone(), desc, context)? {
+            return context.throw_type_error(format!("Cannot redefine property: {}", prop));
+        }
+        Ok(obj.into())
     }
 
     /// `Object.defineProperties( proto, [propertiesObject] )`
@@ -290,6 +354,327 @@ impl Object {
             ctx.throw_type_error("Expected an object")
         }
     }
+
+    /// `Object.getOwnPropertyNames( object )`
+    ///
+    /// Returns an array of the own string keyed properties of an object, enumerable or not.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertynames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyNames
+    pub fn get_own_property_names(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default().to_object(ctx)?;
+        let names = object
+            .own_property_keys(ctx)?
+            .into_iter()
+            .filter(|key| !matches!(key, PropertyKey::Symbol(_)))
+            .map(Self::property_key_to_value);
+        Ok(Array::create_array_from_list(names, ctx))
+    }
+
+    /// `Object.getOwnPropertySymbols( object )`
+    ///
+    /// Returns an array of the own symbol keyed properties of an object, enumerable or not.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertysymbols
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertySymbols
+    pub fn get_own_property_symbols(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default().to_object(ctx)?;
+        let symbols = object
+            .own_property_keys(ctx)?
+            .into_iter()
+            .filter(|key| matches!(key, PropertyKey::Symbol(_)))
+            .map(Value::from);
+        Ok(Array::create_array_from_list(symbols, ctx))
+    }
+
+    /// `Object.keys( object )`
+    ///
+    /// Returns an array of the own enumerable string keyed property names of an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys
+    pub fn keys(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default().to_object(ctx)?;
+        let keys = Self::enumerable_own_property_names(&object, PropertyNameKind::Key, ctx)?;
+        Ok(Array::create_array_from_list(keys, ctx))
+    }
+
+    /// `Object.values( object )`
+    ///
+    /// Returns an array of the values of the own enumerable string keyed properties of an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/values
+    pub fn values(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default().to_object(ctx)?;
+        let values = Self::enumerable_own_property_names(&object, PropertyNameKind::Value, ctx)?;
+        Ok(Array::create_array_from_list(values, ctx))
+    }
+
+    /// `Object.entries( object )`
+    ///
+    /// Returns an array of the `[key, value]` pairs of the own enumerable string keyed properties
+    /// of an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries
+    pub fn entries(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default().to_object(ctx)?;
+        let entries =
+            Self::enumerable_own_property_names(&object, PropertyNameKind::KeyAndValue, ctx)?;
+        Ok(Array::create_array_from_list(entries, ctx))
+    }
+
+    /// The abstract operation `EnumerableOwnPropertyNames`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-enumerableownpropertynames
+    fn enumerable_own_property_names(
+        object: &GcObject,
+        kind: PropertyNameKind,
+        ctx: &mut Context,
+    ) -> Result<Vec<Value>> {
+        let receiver = Value::from(object.clone());
+        let mut properties = Vec::new();
+        for key in object.own_property_keys(ctx)? {
+            if let PropertyKey::Symbol(_) = key {
+                continue;
+            }
+            let enumerable = object
+                .get_own_property(&key, ctx)?
+                .map_or(false, |desc| desc.enumerable());
+            if !enumerable {
+                continue;
+            }
+            let property = match kind {
+                PropertyNameKind::Key => Self::property_key_to_value(key),
+                PropertyNameKind::Value => object.get(&key, &receiver, ctx)?,
+                PropertyNameKind::KeyAndValue => {
+                    let value = object.get(&key, &receiver, ctx)?;
+                    let entry = vec![Self::property_key_to_value(key), value];
+                    Array::create_array_from_list(entry, ctx)
+                }
+            };
+            properties.push(property);
+        }
+        Ok(properties)
+    }
+
+    /// Converts a string or index property key to a string value, as returned by `Object.keys`.
+    pub(crate) fn property_key_to_value(key: PropertyKey) -> Value {
+        match key {
+            PropertyKey::Index(index) => index.to_string().into(),
+            key => key.into(),
+        }
+    }
+
+    /// `Object.fromEntries( iterable )`
+    ///
+    /// Creates an object from an iterable of `[key, value]` pairs.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.fromentries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries
+    pub fn from_entries(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        iterable.require_object_coercible(ctx)?;
+        let object = ctx.construct_object();
+
+        let iterator_record = get_iterator(ctx, iterable)?;
+        loop {
+            let next = iterator_record.next(ctx)?;
+            if next.is_done() {
+                break;
+            }
+            let entry = next.value();
+            let status = if entry.is_object() {
+                entry.get_field("0", ctx).and_then(|key| {
+                    let value = entry.get_field("1", ctx)?;
+                    let key = key.to_property_key(ctx)?;
+                    object.create_data_property_or_throw(key, value, ctx)?;
+                    Ok(Value::undefined())
+                })
+            } else {
+                Err(ctx.construct_type_error("iterator value is not an entry object"))
+            };
+            if status.is_err() {
+                return iterator_record.close(status, ctx);
+            }
+        }
+
+        Ok(object.into())
+    }
+
+    /// `Object.assign( target, ...sources )`
+    ///
+    /// Copies the values of the own enumerable properties of the sources to the target object,
+    /// which is returned.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.assign
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign
+    pub fn assign(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = args.get(0).cloned().unwrap_or_default().to_object(ctx)?;
+
+        for source in args.iter().skip(1) {
+            if source.is_null_or_undefined() {
+                continue;
+            }
+            let from = source.to_object(ctx)?;
+            let receiver = Value::from(from.clone());
+            for key in from.own_property_keys(ctx)? {
+                let enumerable = from
+                    .get_own_property(&key, ctx)?
+                    .map_or(false, |desc| desc.enumerable());
+                if enumerable {
+                    let value = from.get(&key, &receiver, ctx)?;
+                    target.set_or_throw(key, value, ctx)?;
+                }
+            }
+        }
+
+        Ok(target.into())
+    }
+
+    /// `Object.freeze( object )`
+    ///
+    /// Makes the properties of an object non-configurable and its data properties non-writable,
+    /// and prevents new properties from being added to it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.freeze
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze
+    pub fn freeze(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default();
+        if let Value::Object(ref obj) = object {
+            if !obj.set_integrity_level(IntegrityLevel::Frozen, ctx)? {
+                return ctx.throw_type_error("cannot freeze object");
+            }
+        }
+        Ok(object)
+    }
+
+    /// `Object.isFrozen( object )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isfrozen
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen
+    pub fn is_frozen(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        match args.get(0) {
+            Some(Value::Object(ref object)) => Ok(object
+                .test_integrity_level(IntegrityLevel::Frozen, ctx)?
+                .into()),
+            _ => Ok(true.into()),
+        }
+    }
+
+    /// `Object.seal( object )`
+    ///
+    /// Makes the properties of an object non-configurable, and prevents new properties from being
+    /// added to it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.seal
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal
+    pub fn seal(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default();
+        if let Value::Object(ref obj) = object {
+            if !obj.set_integrity_level(IntegrityLevel::Sealed, ctx)? {
+                return ctx.throw_type_error("cannot seal object");
+            }
+        }
+        Ok(object)
+    }
+
+    /// `Object.isSealed( object )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.issealed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isSealed
+    pub fn is_sealed(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        match args.get(0) {
+            Some(Value::Object(ref object)) => Ok(object
+                .test_integrity_level(IntegrityLevel::Sealed, ctx)?
+                .into()),
+            _ => Ok(true.into()),
+        }
+    }
+
+    /// `Object.preventExtensions( object )`
+    ///
+    /// Prevents new properties from being added to an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.preventextensions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/preventExtensions
+    pub fn prevent_extensions(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default();
+        if let Value::Object(ref obj) = object {
+            if !obj.prevent_extensions(ctx)? {
+                return ctx.throw_type_error("cannot prevent extensions of object");
+            }
+        }
+        Ok(object)
+    }
+
+    /// `Object.isExtensible( object )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isextensible
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isExtensible
+    pub fn is_extensible(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        match args.get(0) {
+            Some(Value::Object(ref object)) => Ok(object.is_extensible(ctx)?.into()),
+            _ => Ok(false.into()),
+        }
+    }
+
     /// `Object.prototype.toString()`
     ///
     /// This method returns a string representing the object.
@@ -307,13 +692,12 @@ impl Object {
         } else if this.is_null() {
             Ok("[object Null]".into())
         } else {
-            let gc_o = this.to_object(ctx)?;
-            let o = gc_o.borrow();
-            let builtin_tag = match &o.data {
+            let o = this.to_object(ctx)?;
+            let builtin_tag = match &o.borrow().data {
                 ObjectData::Array => "Array",
                 // TODO: Arguments Exotic Objects are currently not supported
                 ObjectData::Function(_) => "Function",
-                ObjectData::Error => "Error",
+                ObjectData::Error(_) => "Error",
                 ObjectData::Boolean(_) => "Boolean",
                 ObjectData::Number(_) => "Number",
                 ObjectData::String(_) => "String",
@@ -322,11 +706,21 @@ impl Object {
                 _ => "Object",
             };
 
-            let tag = o.get(&ctx.well_known_symbols().to_string_tag_symbol().into());
+            let tag = o.get(
+                &ctx.well_known_symbols().to_string_tag_symbol().into(),
+                &o.clone().into(),
+                ctx,
+            )?;
 
-            let tag_str = tag.as_string().map(|s| s.as_str()).unwrap_or(builtin_tag);
+            let tag = match tag.as_string() {
+                Some(tag) => tag.clone(),
+                None => RcString::from(builtin_tag),
+            };
 
-            Ok(format!("[object {}]", tag_str).into())
+            Ok(RcString::from("[object ")
+                .concat(&tag)
+                .concat(&RcString::from("]"))
+                .into())
         }
     }
 
@@ -342,21 +736,13 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.hasownproperty
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwnProperty
     pub fn has_own_property(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let prop = if args.is_empty() {
-            None
-        } else {
-            Some(args.get(0).expect("Cannot get object").to_string(ctx)?)
-        };
-        let own_property = this
-            .as_object()
-            .as_deref()
-            .expect("Cannot get THIS object")
-            .get_own_property(&prop.expect("cannot get prop").into());
-        if own_property.is_none() {
-            Ok(Value::from(false))
-        } else {
-            Ok(Value::from(true))
-        }
+        let key = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+        let object = this.to_object(ctx)?;
+        Ok(object.get_own_property(&key, ctx)?.is_some().into())
     }
 
     pub fn property_is_enumerable(
@@ -370,7 +756,7 @@ impl Object {
         };
 
         let key = key.to_property_key(ctx)?;
-        let own_property = this.to_object(ctx)?.borrow().get_own_property(&key);
+        let own_property = this.to_object(ctx)?.get_own_property(&key, ctx)?;
 
         Ok(own_property.map_or(Value::from(false), |own_prop| {
             Value::from(own_prop.enumerable())
@@ -92,7 +92,8 @@ fn object_is() {
     assert_eq!(forward(&mut engine, "Object.is()"), "true");
     assert_eq!(forward(&mut engine, "Object.is(undefined)"), "true");
     assert!(engine.global_object().is_global());
-    assert!(!engine.global_object().get_field("Object").is_global());
+    let global = engine.global_object().clone();
+    assert!(!global.get_field("Object", &mut engine).unwrap().is_global());
 }
 #[test]
 fn object_has_own_property() {
@@ -261,3 +262,218 @@ fn object_define_properties() {
 
     assert_eq!(forward(&mut ctx, "obj.p"), "42");
 }
+
+#[test]
+fn object_define_property_accessor() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = { _x: 1 };
+
+        Object.defineProperty(obj, "x", {
+            get() { return this._x * 10; },
+            set(value) { this._x = value; },
+            configurable: true
+        });
+        obj.x = 4;
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "obj.x"), "40");
+    assert_eq!(forward(&mut ctx, "obj._x"), "4");
+}
+
+#[test]
+fn object_keys_values_entries() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = { b: 1, a: 2, 2: "x", 1: "y" };
+        obj[Symbol("s")] = 3;
+        Object.defineProperty(obj, "hidden", { value: 4, enumerable: false });
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "Object.keys(obj).join()"), "\"1,2,b,a\"");
+    assert_eq!(
+        forward(&mut ctx, "Object.values(obj).join()"),
+        "\"y,x,1,2\""
+    );
+    assert_eq!(
+        forward(&mut ctx, "Object.entries(obj).join(';')"),
+        "\"1,y;2,x;b,1;a,2\""
+    );
+    assert_eq!(forward(&mut ctx, "Object.keys('ab').join()"), "\"0,1\"");
+}
+
+#[test]
+fn object_keys_insertion_order() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = { c: 1, a: 2, b: 3 };
+        delete obj.c;
+        obj.c = 4;
+        obj.a = 5;
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "Object.keys(obj).join()"), "\"a,b,c\"");
+    assert_eq!(
+        forward(&mut ctx, "JSON.stringify(obj)"),
+        r#""{"a":5,"b":3,"c":4}""#
+    );
+}
+
+#[test]
+fn object_get_own_property_names_and_symbols() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const sym = Symbol("s");
+        const obj = { a: 1 };
+        obj[sym] = 2;
+        Object.defineProperty(obj, "hidden", { value: 3, enumerable: false });
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(
+        forward(&mut ctx, "Object.getOwnPropertyNames(obj).join()"),
+        "\"a,hidden\""
+    );
+    assert_eq!(
+        forward(&mut ctx, "Object.getOwnPropertySymbols(obj).length"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut ctx, "Object.getOwnPropertySymbols(obj)[0] === sym"),
+        "true"
+    );
+}
+
+#[test]
+fn object_from_entries() {
+    let mut ctx = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut ctx,
+            "JSON.stringify(Object.fromEntries([['a', 1], ['b', 2]]))"
+        ),
+        r#""{"a":1,"b":2}""#
+    );
+    assert_eq!(
+        forward(
+            &mut ctx,
+            "try { Object.fromEntries([1]) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn object_assign() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const target = { a: 0 };
+        const result = Object.assign(target, { b: 1 }, null, undefined, "hi");
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "result === target"), "true");
+    assert_eq!(
+        forward(&mut ctx, "JSON.stringify(result)"),
+        r#""{"0":"h","1":"i","a":0,"b":1}""#
+    );
+    assert_eq!(forward(&mut ctx, "Object.assign.length"), "2");
+}
+
+#[test]
+fn object_freeze_and_seal() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const frozen = Object.freeze({ p: 1 });
+        frozen.p = 2;
+        frozen.q = 3;
+        const sealed = Object.seal({ p: 1 });
+        sealed.p = 2;
+        delete sealed.p;
+        sealed.q = 3;
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "frozen.p"), "1");
+    assert_eq!(forward(&mut ctx, "frozen.q"), "undefined");
+    assert_eq!(forward(&mut ctx, "Object.isFrozen(frozen)"), "true");
+    assert_eq!(forward(&mut ctx, "Object.isSealed(frozen)"), "true");
+    assert_eq!(forward(&mut ctx, "sealed.p"), "2");
+    assert_eq!(forward(&mut ctx, "sealed.q"), "undefined");
+    assert_eq!(forward(&mut ctx, "Object.isSealed(sealed)"), "true");
+    assert_eq!(forward(&mut ctx, "Object.isFrozen(sealed)"), "false");
+    assert_eq!(forward(&mut ctx, "Object.isFrozen({})"), "false");
+    assert_eq!(forward(&mut ctx, "Object.isFrozen(1)"), "true");
+    assert_eq!(forward(&mut ctx, "Object.freeze(1)"), "1");
+}
+
+#[test]
+fn object_prevent_extensions() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = Object.preventExtensions({ a: 1 });
+        obj.b = 2;
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "obj.b"), "undefined");
+    assert_eq!(forward(&mut ctx, "Object.isExtensible(obj)"), "false");
+    assert_eq!(forward(&mut ctx, "Object.isExtensible({})"), "true");
+    assert_eq!(forward(&mut ctx, "Object.isExtensible(1)"), "false");
+    assert_eq!(forward(&mut ctx, "Object.isSealed(obj)"), "false");
+    assert_eq!(
+        forward(&mut ctx, "Object.isFrozen(Object.preventExtensions({}))"),
+        "true"
+    );
+}
+
+#[test]
+fn object_define_property_changes_kind() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = { x: 1 };
+        Object.defineProperty(obj, 'x', { get() { return 2; }, configurable: true });
+        Object.defineProperty(obj, 'y', { get() { return 3; }, configurable: true });
+        Object.defineProperty(obj, 'y', { value: 4, writable: true });
+        const fixed = {};
+        Object.defineProperty(fixed, 'z', { value: 5 });
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "obj.x"), "2");
+    assert_eq!(
+        forward(
+            &mut ctx,
+            "typeof Object.getOwnPropertyDescriptor(obj, 'x').get"
+        ),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut ctx, "obj.y"), "4");
+    assert_eq!(
+        forward(
+            &mut ctx,
+            "Object.getOwnPropertyDescriptor(obj, 'y').writable"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut ctx,
+            "try { Object.defineProperty(fixed, 'z', { get() {} }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut ctx, "fixed.z"), "5");
+}
@@ -0,0 +1,1014 @@
+//! This module implements the global `Promise` object.
+//!
+//! A promise represents the eventual result of an asynchronous operation. The reactions
+//! registered on a promise are never run right away, they are queued as jobs on the [`Context`]
+//! and run by [`Context::run_jobs`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+use crate::{
+    builtins::{
+        error::AggregateError,
+        function::{
+            get_captures, make_capturing_fn, new_captures, set_captures, update_captures, Captures,
+            CapturingFunction,
+        },
+        iterable::{get_iterator, IteratorRecord},
+        Array, BuiltIn,
+    },
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData},
+    property::{AccessorDescriptor, Attribute},
+    value::same_value,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-promise-instances
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) enum PromiseState {
+    Pending,
+    Fulfilled(Value),
+    Rejected(Value),
+}
+
+/// Whether a reaction runs when a promise is fulfilled or when it is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+enum ReactionKind {
+    Fulfill,
+    Reject,
+}
+
+unsafe impl Trace for ReactionKind {
+    unsafe_empty_trace!();
+}
+
+/// A function to run once a promise is settled, and the promise it settles in turn.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ReactionRecord {
+    capability: Option<PromiseCapability>,
+    kind: ReactionKind,
+    handler: Option<Value>,
+}
+
+/// A promise together with the functions that resolve or reject it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct PromiseCapability {
+    promise: Value,
+    resolve: Value,
+    reject: Value,
+}
+
+impl PromiseCapability {
+    /// The promise of the capability.
+    pub(crate) fn promise(&self) -> &Value {
+        &self.promise
+    }
+
+    /// The function that resolves the promise.
+    pub(crate) fn resolve(&self) -> &Value {
+        &self.resolve
+    }
+
+    /// The function that rejects the promise.
+    pub(crate) fn reject(&self) -> &Value {
+        &self.reject
+    }
+}
+
+/// The state shared by the resolving functions of a promise.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ResolvingFunctions {
+    promise: GcObject,
+    already_resolved: bool,
+}
+
+/// The state of the executor passed to a promise constructor by `NewPromiseCapability`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct CapabilityExecutor {
+    resolve: Value,
+    reject: Value,
+}
+
+/// A `PromiseReactionJob`, waiting in the job queue.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ReactionJob {
+    reaction: ReactionRecord,
+    argument: Value,
+}
+
+/// A `PromiseResolveThenableJob`, waiting in the job queue.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ResolveThenableJob {
+    promise: GcObject,
+    thenable: Value,
+    then: Value,
+}
+
+/// The state of the functions created by `Promise.prototype.finally()`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Finally {
+    on_finally: Value,
+    constructor: Value,
+}
+
+/// The static methods that combine the results of several promises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+enum Combinator {
+    All,
+    AllSettled,
+    Any,
+    Race,
+}
+
+unsafe impl Trace for Combinator {
+    unsafe_empty_trace!();
+}
+
+/// The results collected by a combinator, shared by all its element functions.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct CombinatorRecord {
+    values: Vec<Value>,
+    remaining: usize,
+}
+
+/// The state of the functions that record the result of a single promise of a combinator.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct CombinatorElement {
+    kind: Combinator,
+    index: usize,
+    already_called: bool,
+    record: Captures,
+    capability: PromiseCapability,
+}
+
+/// The internal representation of a `Promise` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    fulfill_reactions: Vec<ReactionRecord>,
+    reject_reactions: Vec<ReactionRecord>,
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let promise_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().promise_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::then, "then", 2)
+        .method(Self::catch, "catch", 1)
+        .method(Self::finally, "finally", 1)
+        .static_method(Self::all, "all", 1)
+        .static_method(Self::all_settled, "allSettled", 1)
+        .static_method(Self::any, "any", 1)
+        .static_method(Self::race, "race", 1)
+        .static_method(Self::reject, "reject", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+        .callable(false)
+        .build();
+
+        let species = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .callable(true)
+            .constructable(false)
+            .build();
+        promise_object.borrow_mut().insert(
+            species,
+            AccessorDescriptor::new(Some(get_species), None, Attribute::CONFIGURABLE),
+        );
+
+        (Self::NAME, promise_object.into(), Self::attribute())
+    }
+}
+
+impl Promise {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Returns the state of the promise.
+    pub(crate) fn state(&self) -> &PromiseState {
+        &self.state
+    }
+
+    /// `Promise( executor )`
+    ///
+    /// Creates a new promise, calling `executor` with the functions that resolve or reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let executor = args.get(0).cloned().unwrap_or_default();
+        if !executor.is_function() {
+            return ctx.throw_type_error("Promise executor is not a function");
+        }
+
+        this.set_data(ObjectData::Promise(Self {
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+        }));
+        let promise = match this {
+            Value::Object(object) => object.clone(),
+            _ => unreachable!("a constructor is always called with an object"),
+        };
+
+        let (resolve, reject) = Self::create_resolving_functions(&promise, ctx);
+        let reject = Value::from(reject);
+        if let Err(error) = ctx.call(
+            &executor,
+            &Value::undefined(),
+            &[resolve.into(), reject.clone()],
+        ) {
+            ctx.call(&reject, &Value::undefined(), &[error])?;
+        }
+
+        Ok(this.clone())
+    }
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    fn get_species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `Promise.prototype.then( onFulfilled, onRejected )`
+    ///
+    /// Registers the functions to call once the promise is settled, and returns a promise for
+    /// their result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let promise = match this {
+            Value::Object(object) if object.borrow().is_promise() => object.clone(),
+            _ => return ctx.throw_type_error("`this` is not a Promise"),
+        };
+
+        let default = ctx.standard_objects().promise_object().constructor();
+        let constructor: Value = promise.species_constructor(default, ctx)?.into();
+        let capability = Self::new_capability(&constructor, ctx)?;
+
+        let on_fulfilled = args.get(0).cloned().unwrap_or_default();
+        let on_rejected = args.get(1).cloned().unwrap_or_default();
+        Ok(Self::perform_then(
+            &promise,
+            &on_fulfilled,
+            &on_rejected,
+            Some(capability),
+            ctx,
+        ))
+    }
+
+    /// `Promise.prototype.catch( onRejected )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let on_rejected = args.get(0).cloned().unwrap_or_default();
+        invoke(this, "then", &[Value::undefined(), on_rejected], ctx)
+    }
+
+    /// `Promise.prototype.finally( onFinally )`
+    ///
+    /// Registers a function to call once the promise is settled, whatever its result. The
+    /// returned promise settles like this promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let promise = match this {
+            Value::Object(object) => object.clone(),
+            _ => return ctx.throw_type_error("`this` is not an object"),
+        };
+
+        let default = ctx.standard_objects().promise_object().constructor();
+        let constructor: Value = promise.species_constructor(default, ctx)?.into();
+
+        let on_finally = args.get(0).cloned().unwrap_or_default();
+        let (then_finally, catch_finally) = if on_finally.is_function() {
+            let captures = new_captures(Finally {
+                on_finally,
+                constructor,
+            });
+            (
+                make_capturing_fn(Self::then_finally, captures.clone(), 1, ctx).into(),
+                make_capturing_fn(Self::catch_finally, captures, 1, ctx).into(),
+            )
+        } else {
+            (on_finally.clone(), on_finally)
+        };
+
+        invoke(this, "then", &[then_finally, catch_finally], ctx)
+    }
+
+    /// The `thenFinally` function created by `Promise.prototype.finally()`.
+    fn then_finally(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        let finally: Finally = get_captures(captures);
+
+        let result = ctx.call(&finally.on_finally, &Value::undefined(), &[])?;
+        let promise = Self::promise_resolve(&finally.constructor, result, ctx)?;
+        let value_thunk = make_capturing_fn(Self::value_thunk, new_captures(value), 0, ctx);
+        invoke(&promise, "then", &[value_thunk.into()], ctx)
+    }
+
+    /// The `catchFinally` function created by `Promise.prototype.finally()`.
+    fn catch_finally(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        let finally: Finally = get_captures(captures);
+
+        let result = ctx.call(&finally.on_finally, &Value::undefined(), &[])?;
+        let promise = Self::promise_resolve(&finally.constructor, result, ctx)?;
+        let thrower = make_capturing_fn(Self::thrower, new_captures(reason), 0, ctx);
+        invoke(&promise, "then", &[thrower.into()], ctx)
+    }
+
+    /// Returns the captured value.
+    fn value_thunk(_: &Value, _: &[Value], captures: &Captures, _: &mut Context) -> Result<Value> {
+        Ok(get_captures(captures))
+    }
+
+    /// Throws the captured value.
+    fn thrower(_: &Value, _: &[Value], captures: &Captures, _: &mut Context) -> Result<Value> {
+        Err(get_captures(captures))
+    }
+
+    /// `Promise.resolve( x )`
+    ///
+    /// Returns a promise resolved with `x`, or `x` itself if it already is a promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return ctx.throw_type_error("Promise.resolve called on a non-object");
+        }
+        let x = args.get(0).cloned().unwrap_or_default();
+        Self::promise_resolve(this, x, ctx)
+    }
+
+    /// `Promise.reject( r )`
+    ///
+    /// Returns a promise rejected with `r`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        let capability = Self::new_capability(this, ctx)?;
+        ctx.call(&capability.reject, &Value::undefined(), &[reason])?;
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.all( iterable )`
+    ///
+    /// Returns a promise fulfilled with the values of all the given promises, or rejected with
+    /// the reason of the first one that is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::combine(Combinator::All, this, args, ctx)
+    }
+
+    /// `Promise.allSettled( iterable )`
+    ///
+    /// Returns a promise fulfilled with an object describing the outcome of each of the given
+    /// promises, once they are all settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::combine(Combinator::AllSettled, this, args, ctx)
+    }
+
+    /// `Promise.any( iterable )`
+    ///
+    /// Returns a promise fulfilled with the value of the first of the given promises that is
+    /// fulfilled, or rejected with an `AggregateError` if they are all rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any
+    pub(crate) fn any(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::combine(Combinator::Any, this, args, ctx)
+    }
+
+    /// `Promise.race( iterable )`
+    ///
+    /// Returns a promise settled like the first of the given promises to be settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::combine(Combinator::Race, this, args, ctx)
+    }
+
+    /// The steps shared by the combinators, which reject the returned promise if iterating over
+    /// the given promises fails.
+    fn combine(kind: Combinator, this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let capability = Self::new_capability(this, ctx)?;
+        let iterable = args.get(0).cloned().unwrap_or_default();
+
+        match Self::perform_combinator(kind, this, iterable, &capability, ctx) {
+            Ok(promise) => Ok(promise),
+            Err(error) => {
+                ctx.call(&capability.reject, &Value::undefined(), &[error])?;
+                Ok(capability.promise.clone())
+            }
+        }
+    }
+
+    /// Implements `PerformPromiseAll`, `PerformPromiseAllSettled`, `PerformPromiseAny` and
+    /// `PerformPromiseRace`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseall
+    fn perform_combinator(
+        kind: Combinator,
+        constructor: &Value,
+        iterable: Value,
+        capability: &PromiseCapability,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let promise_resolve =
+            constructor
+                .to_object(ctx)?
+                .get(&"resolve".into(), constructor, ctx)?;
+        if !promise_resolve.is_function() {
+            return ctx.throw_type_error("Promise resolve is not a function");
+        }
+        let iterator: IteratorRecord = get_iterator(ctx, iterable)?;
+
+        let record = new_captures(CombinatorRecord {
+            values: Vec::new(),
+            remaining: 1,
+        });
+        let mut index = 0;
+        loop {
+            let next = iterator.next(ctx)?;
+            if next.is_done() {
+                if kind != Combinator::Race {
+                    let finished = update_captures(&record, |record: &mut CombinatorRecord| {
+                        record.remaining -= 1;
+                        record.remaining == 0
+                    });
+                    if finished {
+                        Self::finish_combinator(kind, &record, capability, ctx)?;
+                    }
+                }
+                return Ok(capability.promise.clone());
+            }
+
+            let next_promise = ctx.call(&promise_resolve, constructor, &[next.value()])?;
+            let (on_fulfilled, on_rejected) = if kind == Combinator::Race {
+                (capability.resolve.clone(), capability.reject.clone())
+            } else {
+                update_captures(&record, |record: &mut CombinatorRecord| {
+                    record.values.push(Value::undefined());
+                    record.remaining += 1;
+                });
+                let element = new_captures(CombinatorElement {
+                    kind,
+                    index,
+                    already_called: false,
+                    record: record.clone(),
+                    capability: capability.clone(),
+                });
+                let on_fulfilled = if kind == Combinator::Any {
+                    capability.resolve.clone()
+                } else {
+                    make_capturing_fn(Self::element_fulfilled, element.clone(), 1, ctx).into()
+                };
+                let on_rejected = if kind == Combinator::All {
+                    capability.reject.clone()
+                } else {
+                    make_capturing_fn(Self::element_rejected, element, 1, ctx).into()
+                };
+                (on_fulfilled, on_rejected)
+            };
+
+            invoke(&next_promise, "then", &[on_fulfilled, on_rejected], ctx)?;
+            index += 1;
+        }
+    }
+
+    /// Records the value of a fulfilled promise passed to a combinator.
+    fn element_fulfilled(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::settle_element(captures, ReactionKind::Fulfill, value, ctx)
+    }
+
+    /// Records the reason of a rejected promise passed to a combinator.
+    fn element_rejected(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        Self::settle_element(captures, ReactionKind::Reject, reason, ctx)
+    }
+
+    /// Records the result of a promise passed to a combinator, and settles the promise of the
+    /// combinator once all the results are known.
+    fn settle_element(
+        captures: &Captures,
+        kind: ReactionKind,
+        value: Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let element: CombinatorElement = get_captures(captures);
+        if element.already_called {
+            return Ok(Value::undefined());
+        }
+        update_captures(captures, |element: &mut CombinatorElement| {
+            element.already_called = true;
+        });
+
+        let value = if element.kind == Combinator::AllSettled {
+            let object = Value::from(ctx.construct_object());
+            if kind == ReactionKind::Fulfill {
+                object.set_field("status", "fulfilled", ctx)?;
+                object.set_field("value", value, ctx)?;
+            } else {
+                object.set_field("status", "rejected", ctx)?;
+                object.set_field("reason", value, ctx)?;
+            }
+            object
+        } else {
+            value
+        };
+
+        let index = element.index;
+        let finished = update_captures(&element.record, |record: &mut CombinatorRecord| {
+            record.values[index] = value;
+            record.remaining -= 1;
+            record.remaining == 0
+        });
+        if finished {
+            Self::finish_combinator(element.kind, &element.record, &element.capability, ctx)
+        } else {
+            Ok(Value::undefined())
+        }
+    }
+
+    /// Settles the promise of a combinator with the results it collected.
+    fn finish_combinator(
+        kind: Combinator,
+        record: &Captures,
+        capability: &PromiseCapability,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let values = update_captures(record, |record: &mut CombinatorRecord| {
+            std::mem::take(&mut record.values)
+        });
+
+        if kind == Combinator::Any {
+            let error = AggregateError::create(&values, "All promises were rejected", ctx)?;
+            ctx.call(&capability.reject, &Value::undefined(), &[error])
+        } else {
+            let array = Array::new_array(ctx)?;
+            Array::construct_array(&array, &values, ctx)?;
+            ctx.call(&capability.resolve, &Value::undefined(), &[array])
+        }
+    }
+
+    /// Implements `CreateResolvingFunctions`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    fn create_resolving_functions(promise: &GcObject, ctx: &Context) -> (GcObject, GcObject) {
+        let captures = new_captures(ResolvingFunctions {
+            promise: promise.clone(),
+            already_resolved: false,
+        });
+        let resolve = make_capturing_fn(Self::resolve_function, captures.clone(), 1, ctx);
+        let reject = make_capturing_fn(Self::reject_function, captures, 1, ctx);
+        (resolve, reject)
+    }
+
+    /// Marks the resolving functions as used, returning their promise if they were not used
+    /// before.
+    fn take_resolving_functions(captures: &Captures) -> Option<GcObject> {
+        update_captures(captures, |functions: &mut ResolvingFunctions| {
+            if functions.already_resolved {
+                None
+            } else {
+                functions.already_resolved = true;
+                Some(functions.promise.clone())
+            }
+        })
+    }
+
+    /// The resolve function of a promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    fn resolve_function(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        if let Some(promise) = Self::take_resolving_functions(captures) {
+            let resolution = args.get(0).cloned().unwrap_or_default();
+            Self::resolve_promise(&promise, resolution, ctx);
+        }
+        Ok(Value::undefined())
+    }
+
+    /// The reject function of a promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-reject-functions
+    fn reject_function(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        if let Some(promise) = Self::take_resolving_functions(captures) {
+            let reason = args.get(0).cloned().unwrap_or_default();
+            Self::settle(&promise, ReactionKind::Reject, reason, ctx);
+        }
+        Ok(Value::undefined())
+    }
+
+    /// Resolves `promise` with `resolution`, following it if it is a thenable.
+    fn resolve_promise(promise: &GcObject, resolution: Value, ctx: &mut Context) {
+        let then = match &resolution {
+            Value::Object(object) if GcObject::equals(object, promise) => {
+                let error = ctx.construct_type_error("A promise cannot be resolved with itself");
+                return Self::settle(promise, ReactionKind::Reject, error, ctx);
+            }
+            Value::Object(object) => match object.get(&"then".into(), &resolution, ctx) {
+                Ok(then) => then,
+                Err(error) => return Self::settle(promise, ReactionKind::Reject, error, ctx),
+            },
+            _ => return Self::settle(promise, ReactionKind::Fulfill, resolution, ctx),
+        };
+
+        if !then.is_function() {
+            return Self::settle(promise, ReactionKind::Fulfill, resolution, ctx);
+        }
+
+        let job = new_captures(ResolveThenableJob {
+            promise: promise.clone(),
+            thenable: resolution,
+            then,
+        });
+        ctx.enqueue_job(CapturingFunction::new(Self::resolve_thenable_job, job));
+    }
+
+    /// Implements `FulfillPromise` and `RejectPromise`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn settle(promise: &GcObject, kind: ReactionKind, value: Value, ctx: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object.as_promise_mut().expect("promise object");
+            let fulfill_reactions = std::mem::take(&mut promise.fulfill_reactions);
+            let reject_reactions = std::mem::take(&mut promise.reject_reactions);
+            if kind == ReactionKind::Fulfill {
+                promise.state = PromiseState::Fulfilled(value.clone());
+                fulfill_reactions
+            } else {
+                promise.state = PromiseState::Rejected(value.clone());
+                reject_reactions
+            }
+        };
+
+        // <https://tc39.es/ecma262/#sec-triggerpromisereactions>
+        for reaction in reactions {
+            Self::enqueue_reaction_job(reaction, value.clone(), ctx);
+        }
+    }
+
+    /// Implements `NewPromiseReactionJob`, adding the job to the job queue.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn enqueue_reaction_job(reaction: ReactionRecord, argument: Value, ctx: &mut Context) {
+        let job = new_captures(ReactionJob { reaction, argument });
+        ctx.enqueue_job(CapturingFunction::new(Self::reaction_job, job));
+    }
+
+    /// Runs a promise reaction: calls its handler and settles the promise that depends on it.
+    fn reaction_job(
+        _: &Value,
+        _: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let job: ReactionJob = get_captures(captures);
+        let reaction = &job.reaction;
+
+        let result = match &reaction.handler {
+            Some(handler) => ctx.call(
+                handler,
+                &Value::undefined(),
+                std::slice::from_ref(&job.argument),
+            ),
+            None if reaction.kind == ReactionKind::Fulfill => Ok(job.argument.clone()),
+            None => Err(job.argument.clone()),
+        };
+
+        match &reaction.capability {
+            Some(capability) => match result {
+                Ok(value) => ctx.call(&capability.resolve, &Value::undefined(), &[value]),
+                Err(error) => ctx.call(&capability.reject, &Value::undefined(), &[error]),
+            },
+            None => result.map(|_| Value::undefined()),
+        }
+    }
+
+    /// Calls the `then` method of a thenable a promise was resolved with.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+    fn resolve_thenable_job(
+        _: &Value,
+        _: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let job: ResolveThenableJob = get_captures(captures);
+        let (resolve, reject) = Self::create_resolving_functions(&job.promise, ctx);
+        let reject = Value::from(reject);
+
+        match ctx.call(&job.then, &job.thenable, &[resolve.into(), reject.clone()]) {
+            Ok(value) => Ok(value),
+            Err(error) => ctx.call(&reject, &Value::undefined(), &[error]),
+        }
+    }
+
+    /// Implements `NewPromiseCapability`, creating a new promise with the given constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub(crate) fn new_capability(
+        constructor: &Value,
+        ctx: &mut Context,
+    ) -> Result<PromiseCapability> {
+        let constructor = match constructor {
+            Value::Object(object) if object.is_constructable() => object.clone(),
+            _ => return Err(ctx.construct_type_error("Promise capability needs a constructor")),
+        };
+
+        let captures = new_captures(CapabilityExecutor {
+            resolve: Value::undefined(),
+            reject: Value::undefined(),
+        });
+        let executor = make_capturing_fn(Self::capability_executor, captures.clone(), 2, ctx);
+        let promise =
+            constructor.construct(&[executor.into()], &constructor.clone().into(), ctx)?;
+
+        let executor: CapabilityExecutor = get_captures(&captures);
+        if !executor.resolve.is_function() {
+            return Err(ctx.construct_type_error("Promise resolve function is not callable"));
+        }
+        if !executor.reject.is_function() {
+            return Err(ctx.construct_type_error("Promise reject function is not callable"));
+        }
+
+        Ok(PromiseCapability {
+            promise,
+            resolve: executor.resolve.clone(),
+            reject: executor.reject.clone(),
+        })
+    }
+
+    /// The executor passed to the promise constructor by `NewPromiseCapability`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getcapabilitiesexecutor-functions
+    fn capability_executor(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let executor: CapabilityExecutor = get_captures(captures);
+        if !executor.resolve.is_undefined() || !executor.reject.is_undefined() {
+            return ctx.throw_type_error("Promise executor has already been invoked");
+        }
+
+        set_captures(
+            captures,
+            CapabilityExecutor {
+                resolve: args.get(0).cloned().unwrap_or_default(),
+                reject: args.get(1).cloned().unwrap_or_default(),
+            },
+        );
+        Ok(Value::undefined())
+    }
+
+    /// Implements `PerformPromiseThen`.
+    ///
+    /// The handlers are called once `promise` is settled, and their result settles the promise of
+    /// `capability`, if any.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    pub(crate) fn perform_then(
+        promise: &GcObject,
+        on_fulfilled: &Value,
+        on_rejected: &Value,
+        capability: Option<PromiseCapability>,
+        ctx: &mut Context,
+    ) -> Value {
+        let handler = |handler: &Value| {
+            if handler.is_function() {
+                Some(handler.clone())
+            } else {
+                None
+            }
+        };
+        let result = capability
+            .as_ref()
+            .map(|capability| capability.promise.clone())
+            .unwrap_or_default();
+        let fulfill_reaction = ReactionRecord {
+            capability: capability.clone(),
+            kind: ReactionKind::Fulfill,
+            handler: handler(on_fulfilled),
+        };
+        let reject_reaction = ReactionRecord {
+            capability,
+            kind: ReactionKind::Reject,
+            handler: handler(on_rejected),
+        };
+
+        let settled = {
+            let mut object = promise.borrow_mut();
+            let promise = object.as_promise_mut().expect("promise object");
+            match &promise.state {
+                PromiseState::Pending => {
+                    promise.fulfill_reactions.push(fulfill_reaction);
+                    promise.reject_reactions.push(reject_reaction);
+                    None
+                }
+                PromiseState::Fulfilled(value) => Some((fulfill_reaction, value.clone())),
+                PromiseState::Rejected(reason) => Some((reject_reaction, reason.clone())),
+            }
+        };
+        if let Some((reaction, argument)) = settled {
+            Self::enqueue_reaction_job(reaction, argument, ctx);
+        }
+
+        result
+    }
+
+    /// Implements `PromiseResolve`, converting `x` to a promise created by `constructor`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    pub(crate) fn promise_resolve(
+        constructor: &Value,
+        x: Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        if let Value::Object(object) = &x {
+            if object.borrow().is_promise() {
+                let x_constructor = object.get(&"constructor".into(), &x, ctx)?;
+                if same_value(&x_constructor, constructor) {
+                    return Ok(x);
+                }
+            }
+        }
+
+        let capability = Self::new_capability(constructor, ctx)?;
+        ctx.call(&capability.resolve, &Value::undefined(), &[x])?;
+        Ok(capability.promise.clone())
+    }
+}
+
+/// Calls the method `key` of `value`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-invoke
+fn invoke(value: &Value, key: &str, args: &[Value], ctx: &mut Context) -> Result<Value> {
+    let function = value.to_object(ctx)?.get(&key.into(), value, ctx)?;
+    ctx.call(&function, value, args)
+}
@@ -0,0 +1,227 @@
+use crate::{forward, Context};
+
+#[test]
+fn reactions_run_as_jobs() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        new Promise(function (resolve) {
+            log.push("executor");
+            resolve(1);
+        }).then(function (value) {
+            log.push("then " + value);
+        });
+        log.push("sync");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "log.join()"), "\"executor,sync\"");
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"executor,sync,then 1\""
+    );
+}
+
+#[test]
+fn chaining() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        Promise.resolve(1)
+            .then(function (value) { return value + 1; })
+            .then(function (value) { throw value * 10; })
+            .then(function () { result = "not called"; })
+            .catch(function (reason) { return reason + 1; })
+            .then(function (value) { result = value; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "21");
+}
+
+#[test]
+fn resolve_with_thenable() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        var thenable = { then: function (resolve) { resolve(42); } };
+        Promise.resolve(thenable).then(function (value) { result = value; });
+        new Promise(function (resolve) {
+            resolve(Promise.resolve("inner"));
+        }).then(function (value) { result += value; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"42inner\"");
+}
+
+#[test]
+fn resolve_returns_same_promise() {
+    let mut engine = Context::new();
+    let init = r#"
+        var p = Promise.resolve(1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "Promise.resolve(p) === p"), "true");
+}
+
+#[test]
+fn resolving_functions_are_called_once() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result = [];
+        new Promise(function (resolve, reject) {
+            resolve(1);
+            reject(2);
+            resolve(3);
+        }).then(
+            function (value) { result.push("fulfilled " + value); },
+            function (reason) { result.push("rejected " + reason); }
+        );
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result.join()"), "\"fulfilled 1\"");
+}
+
+#[test]
+fn executor_throws() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        new Promise(function () {
+            throw "error";
+        }).catch(function (reason) { result = reason; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"error\"");
+}
+
+#[test]
+fn resolve_with_itself() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        var resolve;
+        var p = new Promise(function (r) { resolve = r; });
+        resolve(p);
+        p.catch(function (e) { result = e.name; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"TypeError\"");
+}
+
+#[test]
+fn finally() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result = [];
+        Promise.resolve(1)
+            .finally(function () { result.push("finally"); return 2; })
+            .then(function (value) { result.push(value); });
+        Promise.reject(3)
+            .finally(function () { result.push("finally"); })
+            .catch(function (reason) { result.push(reason); });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "result.join()"),
+        "\"finally,finally,1,3\""
+    );
+}
+
+#[test]
+fn all() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        var rejected;
+        Promise.all([1, Promise.resolve(2), new Promise(function (r) { r(3); })])
+            .then(function (values) { result = values.join(); });
+        Promise.all([Promise.resolve(1), Promise.reject("no")])
+            .catch(function (reason) { rejected = reason; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "rejected"), "\"no\"");
+}
+
+#[test]
+fn all_settled() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        Promise.allSettled([Promise.reject(1), 2]).then(function (results) {
+            result = [
+                results[0].status, results[0].reason,
+                results[1].status, results[1].value
+            ].join();
+        });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"rejected,1,fulfilled,2\"");
+}
+
+#[test]
+fn any() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        var error;
+        Promise.any([Promise.reject(1), Promise.resolve(2)])
+            .then(function (value) { result = value; });
+        Promise.any([Promise.reject(1), Promise.reject(2)])
+            .catch(function (e) { error = e; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "2");
+    assert_eq!(forward(&mut engine, "error.name"), "\"AggregateError\"");
+    assert_eq!(forward(&mut engine, "error.errors.join()"), "\"1,2\"");
+}
+
+#[test]
+fn race() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        Promise.race([new Promise(function () {}), Promise.resolve("fast")])
+            .then(function (value) { result = value; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"fast\"");
+}
+
+#[test]
+fn constructor_errors() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Promise(function () {}); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new Promise(1); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn to_string_tag() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.prototype.toString.call(Promise.resolve())"
+        ),
+        "\"[object Promise]\""
+    );
+}
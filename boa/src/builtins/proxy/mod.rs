@@ -0,0 +1,787 @@
+//! This module implements the global `Proxy` object.
+//!
+//! A `Proxy` wraps a target object, and lets the traps of a handler object intercept the
+//! fundamental operations on it, like property lookups, assignments and function calls.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
+
+use crate::{
+    builtins::{Array, BuiltIn, BuiltInObjectObject},
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
+    property::{Attribute, PropertyDescriptor, PropertyKey},
+    value::{same_value, Value},
+    BoaProfiler, Context, Result,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The data of a `Proxy` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Proxy {
+    /// The target and the handler of the proxy, or `None` once it has been revoked.
+    data: Option<(GcObject, GcObject)>,
+    /// Whether the target is a function, which doesn't change when the proxy is revoked.
+    callable: bool,
+    constructable: bool,
+}
+
+impl BuiltIn for Proxy {
+    const NAME: &'static str = "Proxy";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let proxy_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .static_method(Self::revocable, "revocable", 2)
+            .callable(false)
+            .build();
+
+        (Self::NAME, proxy_object.into(), Self::attribute())
+    }
+}
+
+impl Proxy {
+    pub(crate) const LENGTH: usize = 2;
+
+    /// Creates the data of a proxy, checking that the target and the handler are objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxycreate
+    fn create(target: &Value, handler: &Value, ctx: &mut Context) -> Result<Self> {
+        match (target, handler) {
+            (Value::Object(ref target), Value::Object(ref handler)) => Ok(Self {
+                callable: target.borrow().is_function(),
+                constructable: target.is_constructable(),
+                data: Some((target.clone(), handler.clone())),
+            }),
+            _ => Err(ctx.construct_type_error(
+                "Cannot create proxy with a non-object as target or handler",
+            )),
+        }
+    }
+
+    /// `new Proxy( target, handler )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-target-handler
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = args.get(0).cloned().unwrap_or_default();
+        let handler = args.get(1).cloned().unwrap_or_default();
+        let proxy = Self::create(&target, &handler, ctx)?;
+        this.set_data(ObjectData::Proxy(proxy));
+        Ok(this.clone())
+    }
+
+    /// `Proxy.revocable( target, handler )`
+    ///
+    /// Creates a proxy, together with a function that revokes it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy.revocable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/revocable
+    pub(crate) fn revocable(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = args.get(0).cloned().unwrap_or_default();
+        let handler = args.get(1).cloned().unwrap_or_default();
+        let proxy = Self::create(&target, &handler, ctx)?;
+        let proxy = GcObject::new(Object::with_prototype(
+            Value::null(),
+            ObjectData::Proxy(proxy),
+        ));
+
+        let revoke = FunctionBuilder::closure_with_captures(
+            ctx,
+            |_, _, proxy: &GcObject, _| {
+                if let Some(proxy) = proxy.borrow_mut().as_proxy_mut() {
+                    proxy.data = None;
+                }
+                Ok(Value::undefined())
+            },
+            proxy.clone(),
+        )
+        .name("")
+        .length(0)
+        .build();
+
+        let result = ctx.construct_object();
+        {
+            let mut result = result.borrow_mut();
+            result.insert_property("proxy", proxy, Attribute::all());
+            result.insert_property("revoke", revoke, Attribute::all());
+        }
+        Ok(result.into())
+    }
+
+    /// Returns the target of the proxy, or `None` if it has been revoked.
+    #[inline]
+    pub fn target(&self) -> Option<&GcObject> {
+        self.data.as_ref().map(|(target, _)| target)
+    }
+
+    /// Returns the handler of the proxy, or `None` if it has been revoked.
+    #[inline]
+    pub fn handler(&self) -> Option<&GcObject> {
+        self.data.as_ref().map(|(_, handler)| handler)
+    }
+
+    /// Returns `true` if the target of the proxy is a function.
+    #[inline]
+    pub fn is_callable(&self) -> bool {
+        self.callable
+    }
+
+    /// Returns `true` if the target of the proxy is a constructor.
+    #[inline]
+    pub fn is_constructable(&self) -> bool {
+        self.constructable
+    }
+
+    /// Returns the target and the handler of the proxy, and the trap of the handler named
+    /// `name`, if it is defined.
+    ///
+    /// Throws a `TypeError` if the proxy has been revoked or the trap isn't callable.
+    fn trap(
+        &self,
+        name: &str,
+        ctx: &mut Context,
+    ) -> Result<(GcObject, GcObject, Option<GcObject>)> {
+        let (target, handler) = match self.data {
+            Some((ref target, ref handler)) => (target.clone(), handler.clone()),
+            None => {
+                return Err(ctx.construct_type_error(format!(
+                    "Cannot perform '{}' on a proxy that has been revoked",
+                    name
+                )))
+            }
+        };
+        let trap = handler.get(&name.into(), &handler.clone().into(), ctx)?;
+        match trap {
+            Value::Undefined | Value::Null => Ok((target, handler, None)),
+            Value::Object(ref trap) if trap.is_callable() => {
+                Ok((target, handler, Some(trap.clone())))
+            }
+            _ => Err(ctx.construct_type_error(format!("proxy trap '{}' is not a function", name))),
+        }
+    }
+
+    /// `[[GetPrototypeOf]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getprototypeof
+    pub(crate) fn get_prototype_of(&self, ctx: &mut Context) -> Result<Value> {
+        let (target, handler, trap) = self.trap("getPrototypeOf", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.get_prototype_of(ctx),
+        };
+
+        let prototype = trap.call(&handler.into(), &[target.clone().into()], ctx)?;
+        if !prototype.is_object() && !prototype.is_null() {
+            return Err(ctx.construct_type_error(
+                "'getPrototypeOf' on proxy: trap returned neither object nor null",
+            ));
+        }
+        if !target.is_extensible(ctx)? && !same_value(&prototype, &target.get_prototype_of(ctx)?) {
+            return Err(ctx.construct_type_error(
+                "'getPrototypeOf' on proxy: proxy target is non-extensible but the trap did not return its actual prototype",
+            ));
+        }
+        Ok(prototype)
+    }
+
+    /// `[[SetPrototypeOf]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-setprototypeof-v
+    pub(crate) fn set_prototype_of(&self, prototype: Value, ctx: &mut Context) -> Result<bool> {
+        let (target, handler, trap) = self.trap("setPrototypeOf", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.set_prototype_of(prototype, ctx),
+        };
+
+        let args = [target.clone().into(), prototype.clone()];
+        if !trap.call(&handler.into(), &args, ctx)?.to_boolean() {
+            return Ok(false);
+        }
+        if !target.is_extensible(ctx)? && !same_value(&prototype, &target.get_prototype_of(ctx)?) {
+            return Err(ctx.construct_type_error(
+                "'setPrototypeOf' on proxy: trap returned truish for setting a new prototype on the non-extensible proxy target",
+            ));
+        }
+        Ok(true)
+    }
+
+    /// `[[IsExtensible]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-isextensible
+    pub(crate) fn is_extensible(&self, ctx: &mut Context) -> Result<bool> {
+        let (target, handler, trap) = self.trap("isExtensible", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.is_extensible(ctx),
+        };
+
+        let result = trap
+            .call(&handler.into(), &[target.clone().into()], ctx)?
+            .to_boolean();
+        if result != target.is_extensible(ctx)? {
+            return Err(ctx.construct_type_error(
+                "'isExtensible' on proxy: trap result does not reflect extensibility of proxy target",
+            ));
+        }
+        Ok(result)
+    }
+
+    /// `[[PreventExtensions]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-preventextensions
+    pub(crate) fn prevent_extensions(&self, ctx: &mut Context) -> Result<bool> {
+        let (target, handler, trap) = self.trap("preventExtensions", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.prevent_extensions(ctx),
+        };
+
+        let result = trap
+            .call(&handler.into(), &[target.clone().into()], ctx)?
+            .to_boolean();
+        if result && target.is_extensible(ctx)? {
+            return Err(ctx.construct_type_error(
+                "'preventExtensions' on proxy: trap returned truish but the proxy target is extensible",
+            ));
+        }
+        Ok(result)
+    }
+
+    /// `[[GetOwnProperty]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getownproperty-p
+    pub(crate) fn get_own_property(
+        &self,
+        key: &PropertyKey,
+        ctx: &mut Context,
+    ) -> Result<Option<PropertyDescriptor>> {
+        let (target, handler, trap) = self.trap("getOwnPropertyDescriptor", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.get_own_property(key, ctx),
+        };
+
+        let args = [target.clone().into(), key.into()];
+        let result = trap.call(&handler.into(), &args, ctx)?;
+        let target_desc = target.get_own_property(key, ctx)?;
+        let result = match result {
+            Value::Undefined => {
+                if let Some(ref target_desc) = target_desc {
+                    if !target_desc.configurable() {
+                        return Err(ctx.construct_type_error(format!(
+                            "'getOwnPropertyDescriptor' on proxy: trap returned undefined for property '{}' which is non-configurable in the proxy target",
+                            key
+                        )));
+                    }
+                    if !target.is_extensible(ctx)? {
+                        return Err(ctx.construct_type_error(format!(
+                            "'getOwnPropertyDescriptor' on proxy: trap returned undefined for property '{}' which exists in the non-extensible proxy target",
+                            key
+                        )));
+                    }
+                }
+                return Ok(None);
+            }
+            Value::Object(ref result) => result.to_property_descriptor(ctx)?,
+            _ => {
+                return Err(ctx.construct_type_error(format!(
+                    "'getOwnPropertyDescriptor' on proxy: trap returned neither object nor undefined for property '{}'",
+                    key
+                )))
+            }
+        };
+
+        let extensible = target.is_extensible(ctx)?;
+        if !is_compatible_property_descriptor(extensible, &result, target_desc.as_ref()) {
+            return Err(ctx.construct_type_error(format!(
+                "'getOwnPropertyDescriptor' on proxy: trap returned descriptor for property '{}' that is incompatible with the existing property in the proxy target",
+                key
+            )));
+        }
+        if !result.configurable() {
+            match target_desc {
+                Some(ref target_desc) if !target_desc.configurable() => {
+                    if let (
+                        PropertyDescriptor::Data(ref result),
+                        PropertyDescriptor::Data(ref target_desc),
+                    ) = (&result, target_desc)
+                    {
+                        if !result.writable() && target_desc.writable() {
+                            return Err(ctx.construct_type_error(format!(
+                                "'getOwnPropertyDescriptor' on proxy: trap reported non-configurable and writable for property '{}' which is non-configurable, non-writable in the proxy target",
+                                key
+                            )));
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ctx.construct_type_error(format!(
+                        "'getOwnPropertyDescriptor' on proxy: trap reported non-configurability for property '{}' which is either non-existent or configurable in the proxy target",
+                        key
+                    )))
+                }
+            }
+        }
+        Ok(Some(result))
+    }
+
+    /// `[[DefineOwnProperty]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-defineownproperty-p-desc
+    pub(crate) fn define_own_property(
+        &self,
+        key: PropertyKey,
+        desc: PropertyDescriptor,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        let (target, handler, trap) = self.trap("defineProperty", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.define_own_property(key, desc, ctx),
+        };
+
+        let desc_object = BuiltInObjectObject::from_property_descriptor(desc.clone(), ctx)?;
+        let args = [target.clone().into(), key.clone().into(), desc_object];
+        if !trap.call(&handler.into(), &args, ctx)?.to_boolean() {
+            return Ok(false);
+        }
+
+        let target_desc = target.get_own_property(&key, ctx)?;
+        let extensible = target.is_extensible(ctx)?;
+        match target_desc {
+            None => {
+                if !extensible {
+                    return Err(ctx.construct_type_error(format!(
+                        "'defineProperty' on proxy: trap returned truish for adding property '{}' to the non-extensible proxy target",
+                        key
+                    )));
+                }
+                if !desc.configurable() {
+                    return Err(ctx.construct_type_error(format!(
+                        "'defineProperty' on proxy: trap returned truish for defining non-configurable property '{}' which is non-existent in the proxy target",
+                        key
+                    )));
+                }
+            }
+            Some(ref target_desc) => {
+                if !is_compatible_property_descriptor(extensible, &desc, Some(target_desc)) {
+                    return Err(ctx.construct_type_error(format!(
+                        "'defineProperty' on proxy: trap returned truish for adding property '{}' that is incompatible with the existing property in the proxy target",
+                        key
+                    )));
+                }
+                if !desc.configurable() && target_desc.configurable() {
+                    return Err(ctx.construct_type_error(format!(
+                        "'defineProperty' on proxy: trap returned truish for defining non-configurable property '{}' which is configurable in the proxy target",
+                        key
+                    )));
+                }
+                if let (
+                    PropertyDescriptor::Data(ref desc),
+                    PropertyDescriptor::Data(ref target_desc),
+                ) = (&desc, target_desc)
+                {
+                    if !target_desc.configurable() && target_desc.writable() && !desc.writable() {
+                        return Err(ctx.construct_type_error(format!(
+                            "'defineProperty' on proxy: trap returned truish for defining non-configurable property '{}' which cannot be non-writable, unless there exists a corresponding non-configurable, non-writable own property of the target object",
+                            key
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// `[[HasProperty]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p
+    pub(crate) fn has_property(&self, key: &PropertyKey, ctx: &mut Context) -> Result<bool> {
+        let (target, handler, trap) = self.trap("has", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.has_property(key, ctx),
+        };
+
+        let args = [target.clone().into(), key.into()];
+        let result = trap.call(&handler.into(), &args, ctx)?.to_boolean();
+        if !result {
+            if let Some(target_desc) = target.get_own_property(key, ctx)? {
+                if !target_desc.configurable() {
+                    return Err(ctx.construct_type_error(format!(
+                        "'has' on proxy: trap returned falsish for property '{}' which exists in the proxy target as non-configurable",
+                        key
+                    )));
+                }
+                if !target.is_extensible(ctx)? {
+                    return Err(ctx.construct_type_error(format!(
+                        "'has' on proxy: trap returned falsish for property '{}' but the proxy target is not extensible",
+                        key
+                    )));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// `[[Get]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-get-p-receiver
+    pub(crate) fn get(
+        &self,
+        key: &PropertyKey,
+        receiver: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let (target, handler, trap) = self.trap("get", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.get(key, receiver, ctx),
+        };
+
+        let args = [target.clone().into(), key.into(), receiver.clone()];
+        let result = trap.call(&handler.into(), &args, ctx)?;
+        match target.get_own_property(key, ctx)? {
+            Some(PropertyDescriptor::Data(ref target_desc))
+                if !target_desc.configurable()
+                    && !target_desc.writable()
+                    && !same_value(&result, &target_desc.value()) =>
+            {
+                Err(ctx.construct_type_error(format!(
+                    "'get' on proxy: property '{}' is a read-only and non-configurable data property on the proxy target but the proxy did not return its actual value",
+                    key
+                )))
+            }
+            Some(PropertyDescriptor::Accessor(ref target_desc))
+                if !target_desc.configurable()
+                    && target_desc.getter().is_none()
+                    && !result.is_undefined() =>
+            {
+                Err(ctx.construct_type_error(format!(
+                    "'get' on proxy: property '{}' is a non-configurable accessor property on the proxy target and does not have a getter function, but the trap did not return 'undefined'",
+                    key
+                )))
+            }
+            _ => Ok(result),
+        }
+    }
+
+    /// `[[Set]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-set-p-v-receiver
+    pub(crate) fn set(
+        &self,
+        key: PropertyKey,
+        value: Value,
+        receiver: &Value,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        let (target, handler, trap) = self.trap("set", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.set(key, value, receiver, ctx),
+        };
+
+        let args = [
+            target.clone().into(),
+            key.clone().into(),
+            value.clone(),
+            receiver.clone(),
+        ];
+        if !trap.call(&handler.into(), &args, ctx)?.to_boolean() {
+            return Ok(false);
+        }
+        match target.get_own_property(&key, ctx)? {
+            Some(PropertyDescriptor::Data(ref target_desc))
+                if !target_desc.configurable()
+                    && !target_desc.writable()
+                    && !same_value(&value, &target_desc.value()) =>
+            {
+                Err(ctx.construct_type_error(format!(
+                    "'set' on proxy: trap returned truish for property '{}' which exists in the proxy target as a non-configurable and non-writable data property with a different value",
+                    key
+                )))
+            }
+            Some(PropertyDescriptor::Accessor(ref target_desc))
+                if !target_desc.configurable() && target_desc.setter().is_none() =>
+            {
+                Err(ctx.construct_type_error(format!(
+                    "'set' on proxy: trap returned truish for property '{}' which exists in the proxy target as a non-configurable and non-writable accessor property without a setter",
+                    key
+                )))
+            }
+            _ => Ok(true),
+        }
+    }
+
+    /// `[[Delete]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-delete-p
+    pub(crate) fn delete(&self, key: &PropertyKey, ctx: &mut Context) -> Result<bool> {
+        let (target, handler, trap) = self.trap("deleteProperty", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.delete(key, ctx),
+        };
+
+        let args = [target.clone().into(), key.into()];
+        if !trap.call(&handler.into(), &args, ctx)?.to_boolean() {
+            return Ok(false);
+        }
+        match target.get_own_property(key, ctx)? {
+            None => Ok(true),
+            Some(ref target_desc) if !target_desc.configurable() => {
+                Err(ctx.construct_type_error(format!(
+                    "'deleteProperty' on proxy: trap returned truish for property '{}' which is non-configurable in the proxy target",
+                    key
+                )))
+            }
+            Some(_) if !target.is_extensible(ctx)? => Err(ctx.construct_type_error(format!(
+                "'deleteProperty' on proxy: trap returned truish for property '{}' but the proxy target is non-extensible",
+                key
+            ))),
+            Some(_) => Ok(true),
+        }
+    }
+
+    /// `[[OwnPropertyKeys]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-ownpropertykeys
+    pub(crate) fn own_property_keys(&self, ctx: &mut Context) -> Result<Vec<PropertyKey>> {
+        let (target, handler, trap) = self.trap("ownKeys", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.own_property_keys(ctx),
+        };
+
+        let result = trap.call(&handler.into(), &[target.clone().into()], ctx)?;
+        let mut keys: Vec<PropertyKey> = Vec::new();
+        for key in result.create_list_from_array_like(ctx)? {
+            let key = match key {
+                Value::String(ref string) => PropertyKey::from(string.clone()),
+                Value::Symbol(ref symbol) => PropertyKey::from(symbol.clone()),
+                _ => {
+                    return Err(ctx.construct_type_error(format!(
+                        "'ownKeys' on proxy: {} is not a valid property name",
+                        key.display()
+                    )))
+                }
+            };
+            if keys.contains(&key) {
+                return Err(ctx.construct_type_error(format!(
+                    "'ownKeys' on proxy: trap returned duplicate entries for '{}'",
+                    key
+                )));
+            }
+            keys.push(key);
+        }
+
+        let extensible = target.is_extensible(ctx)?;
+        let mut configurable_keys = Vec::new();
+        let mut non_configurable_keys = Vec::new();
+        for key in target.own_property_keys(ctx)? {
+            match target.get_own_property(&key, ctx)? {
+                Some(ref desc) if !desc.configurable() => non_configurable_keys.push(key),
+                _ => configurable_keys.push(key),
+            }
+        }
+        if extensible && non_configurable_keys.is_empty() {
+            return Ok(keys);
+        }
+
+        let mut unchecked_keys = keys.clone();
+        for key in non_configurable_keys {
+            match unchecked_keys
+                .iter()
+                .position(|unchecked| *unchecked == key)
+            {
+                Some(position) => {
+                    unchecked_keys.remove(position);
+                }
+                None => {
+                    return Err(ctx.construct_type_error(format!(
+                        "'ownKeys' on proxy: trap result did not include '{}'",
+                        key
+                    )))
+                }
+            }
+        }
+        if extensible {
+            return Ok(keys);
+        }
+        for key in configurable_keys {
+            match unchecked_keys
+                .iter()
+                .position(|unchecked| *unchecked == key)
+            {
+                Some(position) => {
+                    unchecked_keys.remove(position);
+                }
+                None => {
+                    return Err(ctx.construct_type_error(format!(
+                        "'ownKeys' on proxy: trap result did not include '{}'",
+                        key
+                    )))
+                }
+            }
+        }
+        if !unchecked_keys.is_empty() {
+            return Err(ctx.construct_type_error(
+                "'ownKeys' on proxy: trap returned extra keys but proxy target is non-extensible",
+            ));
+        }
+        Ok(keys)
+    }
+
+    /// `[[Call]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-call-thisargument-argumentslist
+    pub(crate) fn call(&self, this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        if !self.callable {
+            return ctx.throw_type_error("not a function");
+        }
+        let (target, handler, trap) = self.trap("apply", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.call(this, args, ctx),
+        };
+
+        let args = Array::create_array_from_list(args.iter().cloned(), ctx);
+        trap.call(&handler.into(), &[target.into(), this.clone(), args], ctx)
+    }
+
+    /// `[[Construct]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-construct-argumentslist-newtarget
+    pub(crate) fn construct(
+        &self,
+        args: &[Value],
+        new_target: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        if !self.constructable {
+            return ctx.throw_type_error("proxy target is not a constructor");
+        }
+        let (target, handler, trap) = self.trap("construct", ctx)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return target.construct(args, new_target, ctx),
+        };
+
+        let args = Array::create_array_from_list(args.iter().cloned(), ctx);
+        let result = trap.call(
+            &handler.into(),
+            &[target.into(), args, new_target.clone()],
+            ctx,
+        )?;
+        if !result.is_object() {
+            return ctx.throw_type_error("'construct' on proxy: trap returned non-object");
+        }
+        Ok(result)
+    }
+}
+
+/// Checks if a property with the descriptor `desc` could be defined on an object that has
+/// the property `current`, without applying it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iscompatiblepropertydescriptor
+fn is_compatible_property_descriptor(
+    extensible: bool,
+    desc: &PropertyDescriptor,
+    current: Option<&PropertyDescriptor>,
+) -> bool {
+    let current = match current {
+        Some(current) => current,
+        None => return extensible,
+    };
+    if current.configurable() {
+        return true;
+    }
+    if desc.configurable() || desc.enumerable() != current.enumerable() {
+        return false;
+    }
+    match (current, desc) {
+        (PropertyDescriptor::Data(ref current), PropertyDescriptor::Data(ref desc)) => {
+            current.writable() || (!desc.writable() && same_value(&desc.value(), &current.value()))
+        }
+        (PropertyDescriptor::Accessor(ref current), PropertyDescriptor::Accessor(ref desc)) => {
+            let same_function =
+                |current: Option<&GcObject>, desc: Option<&GcObject>| match (current, desc) {
+                    (Some(current), Some(desc)) => GcObject::equals(current, desc),
+                    (None, None) => true,
+                    _ => false,
+                };
+            same_function(current.getter(), desc.getter())
+                && same_function(current.setter(), desc.setter())
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{forward, Context};
+
+#[test]
+fn forwards_without_traps() {
+    let mut engine = Context::new();
+    let init = r#"
+        let target = { a: 1 };
+        let proxy = new Proxy(target, {});
+        proxy.b = 2;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "proxy.a"), "1");
+    assert_eq!(forward(&mut engine, "target.b"), "2");
+    assert_eq!(forward(&mut engine, "'a' in proxy"), "true");
+    assert_eq!(
+        forward(&mut engine, "delete proxy.a; target.a"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut engine, "typeof proxy"), "\"object\"");
+}
+
+#[test]
+fn get_and_set_traps() {
+    let mut engine = Context::new();
+    let init = r#"
+        let log = [];
+        let proxy = new Proxy({}, {
+            get(target, key, receiver) {
+                log.push("get " + key);
+                return key in target ? target[key] : "default";
+            },
+            set(target, key, value) {
+                log.push("set " + key);
+                target[key] = value * 2;
+                return true;
+            }
+        });
+        proxy.x = 21;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "proxy.x"), "42");
+    assert_eq!(forward(&mut engine, "proxy.missing"), "\"default\"");
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"set x,get x,get missing\""
+    );
+}
+
+#[test]
+fn has_delete_and_own_keys_traps() {
+    let mut engine = Context::new();
+    let init = r#"
+        let proxy = new Proxy({ secret: 1, visible: 2 }, {
+            has(target, key) {
+                return key !== "secret" && key in target;
+            },
+            deleteProperty(target, key) {
+                return key !== "secret" && delete target[key];
+            },
+            ownKeys(target) {
+                return ["visible", "extra"];
+            },
+            getOwnPropertyDescriptor(target, key) {
+                return { value: key, enumerable: true, configurable: true };
+            }
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "'secret' in proxy"), "false");
+    assert_eq!(forward(&mut engine, "'visible' in proxy"), "true");
+    assert_eq!(forward(&mut engine, "delete proxy.secret"), "false");
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(proxy).join()"),
+        "\"visible,extra\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "let keys = []; for (let key in proxy) { keys.push(key); } keys.join()"
+        ),
+        "\"visible,extra\""
+    );
+}
+
+#[test]
+fn prototype_and_extensibility_traps() {
+    let mut engine = Context::new();
+    let init = r#"
+        let prototype = { inherited: true };
+        let proxy = new Proxy({}, {
+            getPrototypeOf(target) {
+                return prototype;
+            },
+            setPrototypeOf(target, value) {
+                return false;
+            },
+            preventExtensions(target) {
+                Reflect.preventExtensions(target);
+                return true;
+            }
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Object.getPrototypeOf(proxy) === prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(proxy, null)"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Object.setPrototypeOf(proxy, null) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(proxy)"), "true");
+    assert_eq!(
+        forward(&mut engine, "Reflect.preventExtensions(proxy)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(proxy)"), "false");
+}
+
+#[test]
+fn define_property_trap() {
+    let mut engine = Context::new();
+    let init = r#"
+        let defined = [];
+        let proxy = new Proxy({}, {
+            defineProperty(target, key, desc) {
+                defined.push(key + "=" + desc.value);
+                return Reflect.defineProperty(target, key, desc);
+            }
+        });
+        Object.defineProperty(proxy, "a", { value: 1, configurable: true });
+        proxy.b = 2;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "defined.join()"), "\"a=1,b=2\"");
+    assert_eq!(forward(&mut engine, "proxy.a + proxy.b"), "3");
+}
+
+#[test]
+fn apply_and_construct_traps() {
+    let mut engine = Context::new();
+    let init = r#"
+        function add(a, b) { return a + b; }
+        let calls = 0;
+        let mock = new Proxy(add, {
+            apply(target, thisArg, args) {
+                calls++;
+                return target(...args) * 10;
+            }
+        });
+        class Point {
+            constructor(x) { this.x = x; }
+        }
+        let PointProxy = new Proxy(Point, {
+            construct(target, args, newTarget) {
+                return new target(args[0] + 1);
+            }
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "typeof mock"), "\"function\"");
+    assert_eq!(forward(&mut engine, "mock(1, 2)"), "30");
+    assert_eq!(forward(&mut engine, "mock.call(null, 2, 2)"), "40");
+    assert_eq!(forward(&mut engine, "calls"), "2");
+    assert_eq!(forward(&mut engine, "new PointProxy(1).x"), "2");
+    assert_eq!(
+        forward(&mut engine, "new PointProxy(1) instanceof Point"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "new (new Proxy(Point, {}))(5).x"), "5");
+}
+
+#[test]
+fn invariants() {
+    let mut engine = Context::new();
+    let init = r#"
+        let target = {};
+        Object.defineProperty(target, "fixed", { value: 1 });
+        let proxy = new Proxy(target, {
+            get() { return 2; },
+            has() { return false; },
+            ownKeys() { return []; }
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "try { proxy.fixed } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { 'fixed' in proxy } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Reflect.ownKeys(proxy) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn revocable() {
+    let mut engine = Context::new();
+    let init = r#"
+        let { proxy, revoke } = Proxy.revocable([1, 2], {});
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "proxy[1]"), "2");
+    assert_eq!(forward(&mut engine, "Array.isArray(proxy)"), "true");
+    forward(&mut engine, "revoke()");
+    assert_eq!(
+        forward(&mut engine, "try { proxy[1] } catch (e) { e.toString() }"),
+        "\"TypeError: Cannot perform 'get' on a proxy that has been revoked\""
+    );
+}
+
+#[test]
+fn constructor_errors() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "try { Proxy({}, {}) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new Proxy(1, {}) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Proxy({}, { get: 1 }).x } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
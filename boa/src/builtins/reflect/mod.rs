@@ -0,0 +1,340 @@
+//! This module implements the global `Reflect` object.
+//!
+//! The `Reflect` object provides functions for the internal methods of objects, with the
+//! same names and arguments as the traps of a `Proxy` handler.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-reflect-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect
+
+use crate::{
+    builtins::{Array, BuiltIn, BuiltInObjectObject},
+    object::{GcObject, ObjectInitializer},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `Reflect` global object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Reflect;
+
+impl BuiltIn for Reflect {
+    const NAME: &'static str = "Reflect";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let reflect_object = ObjectInitializer::new(context)
+            .function(Self::apply, "apply", 3)
+            .function(Self::construct, "construct", 2)
+            .function(Self::define_property, "defineProperty", 3)
+            .function(Self::delete_property, "deleteProperty", 2)
+            .function(Self::get, "get", 2)
+            .function(
+                Self::get_own_property_descriptor,
+                "getOwnPropertyDescriptor",
+                2,
+            )
+            .function(Self::get_prototype_of, "getPrototypeOf", 1)
+            .function(Self::has, "has", 2)
+            .function(Self::is_extensible, "isExtensible", 1)
+            .function(Self::own_keys, "ownKeys", 1)
+            .function(Self::prevent_extensions, "preventExtensions", 1)
+            .function(Self::set, "set", 3)
+            .function(Self::set_prototype_of, "setPrototypeOf", 2)
+            .property(
+                to_string_tag,
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+
+        (Self::NAME, reflect_object.into(), Self::attribute())
+    }
+}
+
+impl Reflect {
+    /// Returns the first argument as an object, or throws a `TypeError` naming `function`.
+    fn target(args: &[Value], function: &str, ctx: &mut Context) -> Result<GcObject> {
+        match args.get(0) {
+            Some(Value::Object(ref object)) => Ok(object.clone()),
+            _ => {
+                Err(ctx.construct_type_error(format!("Reflect.{} called on non-object", function)))
+            }
+        }
+    }
+
+    /// `Reflect.apply( target, thisArgument, argumentsList )`
+    ///
+    /// Calls a function with the given `this` value and arguments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.apply
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/apply
+    pub(crate) fn apply(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = match args.get(0) {
+            Some(Value::Object(ref object)) if object.is_callable() => object.clone(),
+            _ => return ctx.throw_type_error("Reflect.apply target is not a function"),
+        };
+        let this = args.get(1).cloned().unwrap_or_default();
+        let args = args
+            .get(2)
+            .cloned()
+            .unwrap_or_default()
+            .create_list_from_array_like(ctx)?;
+        target.call(&this, &args, ctx)
+    }
+
+    /// `Reflect.construct( target, argumentsList [, newTarget] )`
+    ///
+    /// Calls a constructor with the given arguments, like the `new` operator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.construct
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct
+    pub(crate) fn construct(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = match args.get(0) {
+            Some(Value::Object(ref object)) if object.is_constructable() => object.clone(),
+            _ => return ctx.throw_type_error("Reflect.construct target is not a constructor"),
+        };
+        let new_target = match args.get(2) {
+            None => target.clone().into(),
+            Some(Value::Object(ref object)) if object.is_constructable() => object.clone().into(),
+            Some(_) => {
+                return ctx.throw_type_error("Reflect.construct newTarget is not a constructor")
+            }
+        };
+        let args = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .create_list_from_array_like(ctx)?;
+        target.construct(&args, &new_target, ctx)
+    }
+
+    /// `Reflect.defineProperty( target, propertyKey, attributes )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.defineproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/defineProperty
+    pub(crate) fn define_property(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "defineProperty", ctx)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+        let desc = args
+            .get(2)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_descriptor(ctx)?;
+        Ok(target.define_own_property(key, desc, ctx)?.into())
+    }
+
+    /// `Reflect.deleteProperty( target, propertyKey )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.deleteproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/deleteProperty
+    pub(crate) fn delete_property(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "deleteProperty", ctx)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+        Ok(target.delete(&key, ctx)?.into())
+    }
+
+    /// `Reflect.get( target, propertyKey [, receiver] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/get
+    pub(crate) fn get(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "get", ctx)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+        let receiver = args
+            .get(2)
+            .cloned()
+            .unwrap_or_else(|| target.clone().into());
+        target.get(&key, &receiver, ctx)
+    }
+
+    /// `Reflect.getOwnPropertyDescriptor( target, propertyKey )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.getownpropertydescriptor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getOwnPropertyDescriptor
+    pub(crate) fn get_own_property_descriptor(
+        _: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let target = Self::target(args, "getOwnPropertyDescriptor", ctx)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+        match target.get_own_property(&key, ctx)? {
+            Some(desc) => BuiltInObjectObject::from_property_descriptor(desc, ctx),
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// `Reflect.getPrototypeOf( target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.getprototypeof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getPrototypeOf
+    pub(crate) fn get_prototype_of(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "getPrototypeOf", ctx)?;
+        target.get_prototype_of(ctx)
+    }
+
+    /// `Reflect.has( target, propertyKey )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/has
+    pub(crate) fn has(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "has", ctx)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+        Ok(target.has_property(&key, ctx)?.into())
+    }
+
+    /// `Reflect.isExtensible( target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.isextensible
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/isExtensible
+    pub(crate) fn is_extensible(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "isExtensible", ctx)?;
+        Ok(target.is_extensible(ctx)?.into())
+    }
+
+    /// `Reflect.ownKeys( target )`
+    ///
+    /// Returns an array of the own property keys of the target, symbols included.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.ownkeys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/ownKeys
+    pub(crate) fn own_keys(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "ownKeys", ctx)?;
+        let keys = target.own_property_keys(ctx)?;
+        Ok(Array::create_array_from_list(
+            keys.into_iter()
+                .map(BuiltInObjectObject::property_key_to_value),
+            ctx,
+        ))
+    }
+
+    /// `Reflect.preventExtensions( target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.preventextensions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/preventExtensions
+    pub(crate) fn prevent_extensions(
+        _: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let target = Self::target(args, "preventExtensions", ctx)?;
+        Ok(target.prevent_extensions(ctx)?.into())
+    }
+
+    /// `Reflect.set( target, propertyKey, V [, receiver] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set
+    pub(crate) fn set(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "set", ctx)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+        let value = args.get(2).cloned().unwrap_or_default();
+        let receiver = args
+            .get(3)
+            .cloned()
+            .unwrap_or_else(|| target.clone().into());
+        Ok(target.set(key, value, &receiver, ctx)?.into())
+    }
+
+    /// `Reflect.setPrototypeOf( target, prototype )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.setprototypeof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/setPrototypeOf
+    pub(crate) fn set_prototype_of(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "setPrototypeOf", ctx)?;
+        let prototype = args.get(1).cloned().unwrap_or_default();
+        if !prototype.is_object() && !prototype.is_null() {
+            return ctx.throw_type_error("Object prototype may only be an Object or null");
+        }
+        Ok(target.set_prototype_of(prototype, ctx)?.into())
+    }
+}
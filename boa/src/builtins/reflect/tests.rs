@@ -0,0 +1,154 @@
+use crate::{forward, Context};
+
+#[test]
+fn apply() {
+    let mut engine = Context::new();
+    let init = r#"
+        function greet(greeting) { return greeting + ", " + this.name; }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.apply(greet, { name: 'Boa' }, ['Hello'])"
+        ),
+        "\"Hello, Boa\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { Reflect.apply(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn construct() {
+    let mut engine = Context::new();
+    let init = r#"
+        class Base { constructor(x) { this.x = x; } }
+        class Other {}
+        let instance = Reflect.construct(Base, [1], Other);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "instance.x"), "1");
+    assert_eq!(forward(&mut engine, "instance instanceof Other"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Reflect.construct(Math.max, []) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn properties() {
+    let mut engine = Context::new();
+    let init = r#"
+        let object = { a: 1 };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "Reflect.get(object, 'a')"), "1");
+    assert_eq!(forward(&mut engine, "Reflect.set(object, 'b', 2)"), "true");
+    assert_eq!(forward(&mut engine, "Reflect.has(object, 'b')"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(object, 'c', { value: 3 })"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(object, 'c', { value: 4 })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.getOwnPropertyDescriptor(object, 'c').value"
+        ),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.deleteProperty(object, 'c')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.deleteProperty(object, 'a')"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.ownKeys(object).length"), "2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.ownKeys({ 1: 1, b: 2 }).map(key => typeof key).join()"
+        ),
+        "\"string,string\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(object).includes('c')"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Reflect.get(1, 'a') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn getters_and_setters_with_receiver() {
+    let mut engine = Context::new();
+    let init = r#"
+        let object = {
+            get value() { return this.base; },
+            set value(v) { this.base = v; }
+        };
+        let receiver = { base: 10 };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Reflect.get(object, 'value', receiver)"),
+        "10"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.set(object, 'value', 5, receiver)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "receiver.base"), "5");
+}
+
+#[test]
+fn prototypes() {
+    let mut engine = Context::new();
+    let init = r#"
+        let a = {};
+        let b = Object.create(a);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Reflect.getPrototypeOf(b) === a"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(a, b)"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(b, null)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.getPrototypeOf(b)"), "null");
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(a)"), "true");
+    assert_eq!(forward(&mut engine, "Reflect.preventExtensions(a)"), "true");
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(a)"), "false");
+    assert_eq!(forward(&mut engine, "Reflect.set(a, 'x', 1)"), "false");
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(Reflect)"),
+        "\"[object Reflect]\""
+    );
+}
@@ -10,14 +10,15 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{string::get_substitution, Array, BuiltIn},
     gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, ObjectData},
+    object::{ConstructorBuilder, Object, ObjectData},
     property::{Attribute, DataDescriptor},
-    value::{RcString, Value},
+    value::{is_leading_surrogate, is_trailing_surrogate, RcString, Value},
     BoaProfiler, Context, Result,
 };
 use regress::{Flags, Regex};
+use std::ops::Range;
 
 #[cfg(test)]
 mod tests;
@@ -52,7 +53,7 @@ pub struct RegExp {
     /// Flag 'u' - Unicode.
     unicode: bool,
 
-    pub(crate) original_source: String,
+    pub(crate) original_source: RcString,
     original_flags: String,
 }
 
@@ -60,6 +61,278 @@ unsafe impl Trace for RegExp {
     empty_trace!();
 }
 
+/// The first code point of the private use characters that stand in for surrogate code units.
+///
+/// `regress` only matches UTF-8 text, which can't hold surrogates, so every surrogate that has to
+/// be matched on its own is mapped to the character `U+F0000 + (unit - 0xD800)`, both in the
+/// pattern and in the input. Without the `u` flag this is every surrogate, so that patterns match
+/// the string one code unit at a time, and with it only the unpaired ones. The only approximation
+/// is that in unicode mode the characters `U+F0000` to `U+F07FF` of the input can be matched by
+/// lone surrogates of the pattern and the other way around.
+const SURROGATE_BASE: u32 = 0xF0000;
+
+/// Maps a surrogate code unit to the character that stands in for it.
+fn surrogate_char(unit: u16) -> char {
+    char::from_u32(SURROGATE_BASE + u32::from(unit - 0xD800))
+        .expect("surrogates map to valid characters")
+}
+
+/// Returns `true` if the character stands in for a surrogate code unit.
+fn is_surrogate_char(c: char) -> bool {
+    (SURROGATE_BASE..SURROGATE_BASE + 0x800).contains(&u32::from(c))
+}
+
+/// Decodes the code units of a string to the characters that are matched.
+///
+/// Each item is the character and the number of code units it stands for.
+fn match_chars(string: &RcString, unicode: bool) -> Vec<(char, usize)> {
+    let units: Vec<u16> = string.code_units().collect();
+    let mut chars = Vec::with_capacity(units.len());
+    let mut i = 0;
+    while i < units.len() {
+        let unit = units[i];
+        let pair = units
+            .get(i + 1)
+            .copied()
+            .filter(|&next| unicode && is_leading_surrogate(unit) && is_trailing_surrogate(next));
+        if let Some(next) = pair {
+            let code_point =
+                0x10000 + ((u32::from(unit) - 0xD800) << 10) + (u32::from(next) - 0xDC00);
+            chars.push((
+                char::from_u32(code_point).expect("surrogate pairs decode to valid characters"),
+                2,
+            ));
+            i += 2;
+        } else {
+            let c = if is_leading_surrogate(unit) || is_trailing_surrogate(unit) {
+                surrogate_char(unit)
+            } else {
+                char::from_u32(u32::from(unit)).expect("non-surrogate code units are characters")
+            };
+            chars.push((c, 1));
+            i += 1;
+        }
+    }
+    chars
+}
+
+/// Translates a pattern to the text matched by `regress`, mapping its surrogates the same way
+/// as the input of the matches, see [`SURROGATE_BASE`].
+///
+/// Literal surrogates and `\u` escapes of surrogates are mapped, everything else is kept as it
+/// is. Character class ranges that overlap the surrogates are split, so they still cover the
+/// mapped characters.
+fn translate_pattern(source: &RcString, unicode: bool) -> String {
+    /// Writes a code point as a literal class atom.
+    fn push_atom(text: &mut String, value: u32) {
+        if (0xD800..=0xDFFF).contains(&value) {
+            text.push(surrogate_char(value as u16));
+        } else if let Some(c) = char::from_u32(value) {
+            if matches!(c, '\\' | ']' | '[' | '-' | '^') {
+                text.push('\\');
+            }
+            text.push(c);
+        }
+    }
+
+    let chars = match_chars(source, unicode);
+    let hex = |from: usize, to: usize| -> Option<u32> {
+        let digits: String = chars.get(from..to)?.iter().map(|&(c, _)| c).collect();
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        u32::from_str_radix(&digits, 16).ok()
+    };
+
+    let mut text = String::with_capacity(chars.len());
+    let mut in_class = false;
+    // The output length before the last class atom and its value, for ranges.
+    let mut last_atom: Option<(usize, u32)> = None;
+    let mut range_start: Option<(usize, u32)> = None;
+    let mut i = 0;
+    while i < chars.len() {
+        let start = text.len();
+        let first = i;
+        let c = chars[i].0;
+        i += 1;
+        let value = match c {
+            '\\' => match chars.get(i).map(|&(c, _)| c) {
+                Some('u') => {
+                    let braced = if unicode && chars.get(i + 1).map(|&(c, _)| c) == Some('{') {
+                        chars[i + 2..]
+                            .iter()
+                            .position(|&(c, _)| c == '}')
+                            .and_then(|len| Some((hex(i + 2, i + 2 + len)?, len + 3)))
+                    } else {
+                        None
+                    };
+                    if let Some((value, len)) = braced.or_else(|| Some((hex(i + 1, i + 5)?, 5))) {
+                        i += len;
+                        // A pair of escaped surrogates is a single character in unicode mode.
+                        let trailing = if unicode && len == 5 && value & 0xFC00 == 0xD800 {
+                            Some(i)
+                                .filter(|&i| {
+                                    chars.get(i..i + 2).map(|c| (c[0].0, c[1].0))
+                                        == Some(('\\', 'u'))
+                                })
+                                .and_then(|i| hex(i + 2, i + 6))
+                                .filter(|next| next & 0xFC00 == 0xDC00)
+                        } else {
+                            None
+                        };
+                        if let Some(next) = trailing {
+                            i += 6;
+                            let value = 0x10000 + ((value - 0xD800) << 10) + (next - 0xDC00);
+                            push_atom(&mut text, value);
+                            Some(value)
+                        } else if (0xD800..=0xDFFF).contains(&value) {
+                            push_atom(&mut text, value);
+                            Some(value)
+                        } else {
+                            text.extend(chars[first..i].iter().map(|&(c, _)| c));
+                            Some(value)
+                        }
+                    } else {
+                        text.push('\\');
+                        None
+                    }
+                }
+                // An escaped surrogate is the surrogate itself.
+                Some(next) if is_surrogate_char(next) => None,
+                Some(next) => {
+                    text.push('\\');
+                    text.push(next);
+                    i += 1;
+                    None
+                }
+                None => {
+                    text.push('\\');
+                    None
+                }
+            },
+            '[' if !in_class => {
+                in_class = true;
+                text.push(c);
+                if chars.get(i).map(|&(c, _)| c) == Some('^') {
+                    text.push('^');
+                    i += 1;
+                }
+                None
+            }
+            ']' if in_class => {
+                in_class = false;
+                text.push(c);
+                None
+            }
+            '-' if in_class
+                && last_atom.is_some()
+                && chars.get(i).map(|&(c, _)| c) != Some(']') =>
+            {
+                range_start = last_atom.take();
+                text.push(c);
+                continue;
+            }
+            c if is_surrogate_char(c) => {
+                text.push(c);
+                Some(u32::from(c) - SURROGATE_BASE + 0xD800)
+            }
+            c => {
+                text.push(c);
+                Some(u32::from(c))
+            }
+        };
+
+        if !in_class {
+            range_start = None;
+            last_atom = None;
+            continue;
+        }
+        match (range_start.take(), value) {
+            (Some((position, low)), Some(high)) => {
+                // Ranges that don't overlap the surrogates are kept as they are.
+                if low <= high && low <= 0xDFFF && high >= 0xD800 {
+                    text.truncate(position);
+                    for (low, high) in [
+                        (low, high.min(0xD7FF)),
+                        (low.max(0xD800), high.min(0xDFFF)),
+                        (low.max(0xE000), high),
+                    ] {
+                        if low <= high {
+                            push_atom(&mut text, low);
+                            text.push('-');
+                            push_atom(&mut text, high);
+                        }
+                    }
+                }
+                last_atom = None;
+            }
+            _ => last_atom = value.map(|value| (start, value)),
+        }
+    }
+    text
+}
+
+/// A string prepared for matching.
+///
+/// The string is converted to the UTF-8 text matched by `regress`, see [`SURROGATE_BASE`].
+struct MatchInput {
+    string: RcString,
+    text: String,
+    /// The byte offset and the code unit index at which each character of the text starts,
+    /// followed by the ends of the text and the string.
+    boundaries: Vec<(usize, usize)>,
+}
+
+impl MatchInput {
+    fn new(string: RcString, unicode: bool) -> Self {
+        let chars = match_chars(&string, unicode);
+        let mut text = String::with_capacity(chars.len());
+        let mut boundaries = Vec::with_capacity(chars.len() + 1);
+        let mut index = 0;
+        for (c, units) in chars {
+            boundaries.push((text.len(), index));
+            text.push(c);
+            index += units;
+        }
+        boundaries.push((text.len(), index));
+        Self {
+            string,
+            text,
+            boundaries,
+        }
+    }
+
+    /// Converts a byte offset of the matched text to a code unit index.
+    fn index(&self, offset: usize) -> usize {
+        let i = match self
+            .boundaries
+            .binary_search_by_key(&offset, |&(offset, _)| offset)
+        {
+            Ok(i) | Err(i) => i,
+        };
+        self.boundaries.get(i).map_or(self.string.len(), |b| b.1)
+    }
+
+    /// Converts a code unit index to a byte offset of the matched text.
+    ///
+    /// An index that points inside of a surrogate pair is moved to the end of the pair.
+    fn offset(&self, index: usize) -> usize {
+        let i = match self
+            .boundaries
+            .binary_search_by_key(&index, |&(_, index)| index)
+        {
+            Ok(i) | Err(i) => i,
+        };
+        self.boundaries.get(i).map_or(self.text.len(), |b| b.0)
+    }
+
+    /// Gets the substring of the original string in the given byte range of the matched text.
+    fn slice(&self, range: Range<usize>) -> RcString {
+        self.string
+            .substring(self.index(range.start), self.index(range.end))
+    }
+}
+
 impl BuiltIn for RegExp {
     const NAME: &'static str = "RegExp";
 
@@ -70,6 +343,11 @@ impl BuiltIn for RegExp {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        let symbol_match = context.well_known_symbols().match_symbol();
+        let symbol_replace = context.well_known_symbols().replace_symbol();
+        let symbol_search = context.well_known_symbols().search_symbol();
+        let symbol_split = context.well_known_symbols().split_symbol();
+
         let regexp_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -81,6 +359,10 @@ impl BuiltIn for RegExp {
         .method(Self::test, "test", 1)
         .method(Self::exec, "exec", 1)
         .method(Self::to_string, "toString", 0)
+        .method(Self::r#match, (symbol_match, "[Symbol.match]"), 1)
+        .method(Self::replace, (symbol_replace, "[Symbol.replace]"), 2)
+        .method(Self::search, (symbol_search, "[Symbol.search]"), 1)
+        .method(Self::split, (symbol_split, "[Symbol.split]"), 2)
         .build();
 
         // TODO: add them RegExp accessor properties
@@ -96,29 +378,60 @@ impl RegExp {
     /// The amount of arguments this function object takes.
     pub(crate) const LENGTH: usize = 2;
 
-    /// Create a new `RegExp`
-    pub(crate) fn constructor(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let arg = args.get(0).ok_or_else(Value::undefined)?;
-        let mut regex_body = String::new();
-        let mut regex_flags = String::new();
-        match arg {
-            Value::String(ref body) => {
-                // first argument is a string -> use it as regex pattern
-                regex_body = body.to_string();
-            }
-            Value::Object(ref obj) => {
-                let obj = obj.borrow();
-                if let Some(regex) = obj.as_regexp() {
-                    // first argument is another `RegExp` object, so copy its pattern and flags
-                    regex_body = regex.original_source.clone();
-                    regex_flags = regex.original_flags.clone();
-                }
-            }
-            _ => return Err(Value::undefined()),
+    /// The flags of the regular expression, in canonical order.
+    #[inline]
+    pub(crate) fn flags(&self) -> &str {
+        &self.flags
+    }
+
+    /// Abstract operation `RegExpCreate( pattern, flags )`
+    ///
+    /// Creates a new `RegExp` object that inherits from `RegExp.prototype`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexpcreate
+    pub(crate) fn create(pattern: Value, flags: Value, ctx: &mut Context) -> Result<Value> {
+        let prototype = ctx.standard_objects().regexp_object().prototype();
+        let this = Value::from(Object::create(prototype.into()));
+        Self::constructor(&this, &[pattern, flags], ctx)
+    }
+
+    /// Gets the matcher and the `global` and `unicode` flags of `this`, throwing a `TypeError` if
+    /// it isn't a `RegExp` object.
+    fn matcher_of(this: &Value, method: &str, ctx: &mut Context) -> Result<(Regex, bool, bool)> {
+        let regexp = this.as_object().and_then(|obj| {
+            obj.as_regexp()
+                .map(|regex| (regex.matcher.clone(), regex.global, regex.unicode))
+        });
+        match regexp {
+            Some(regexp) => Ok(regexp),
+            None => Err(ctx.construct_type_error(format!(
+                "Method RegExp.prototype.{} called on incompatible receiver {}",
+                method,
+                this.display()
+            ))),
         }
-        // if a second argument is given and it's a string, use it as flags
-        if let Some(Value::String(flags)) = args.get(1) {
-            regex_flags = flags.to_string();
+    }
+
+    /// Create a new `RegExp`
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let arg = args.get(0).cloned().unwrap_or_default();
+        // if the first argument is another `RegExp` object, copy its pattern and flags
+        let original = arg.as_object().and_then(|obj| {
+            obj.as_regexp()
+                .map(|regex| (regex.original_source.clone(), regex.original_flags.clone()))
+        });
+        let (regex_body, mut regex_flags) = match original {
+            Some(original) => original,
+            None if arg.is_undefined() => (RcString::default(), String::new()),
+            None => (arg.to_string(ctx)?, String::new()),
+        };
+        // if a second argument is given, use it as flags
+        match args.get(1) {
+            Some(flags) if !flags.is_undefined() => regex_flags = flags.to_string(ctx)?.to_string(),
+            _ => {}
         }
 
         // parse flags
@@ -154,8 +467,16 @@ impl RegExp {
             sorted_flags.push('y');
         }
 
-        let matcher = Regex::newf(regex_body.as_str(), Flags::from(sorted_flags.as_str()))
-            .expect("failed to create matcher");
+        let pattern = translate_pattern(&regex_body, unicode);
+        let matcher = match Regex::newf(pattern.as_str(), Flags::from(sorted_flags.as_str())) {
+            Ok(matcher) => matcher,
+            Err(error) => {
+                return ctx.throw_syntax_error(format!(
+                    "Invalid regular expression /{}/: {}",
+                    regex_body, error
+                ))
+            }
+        };
         let regexp = RegExp {
             matcher,
             use_last_index: global || sticky,
@@ -307,26 +628,30 @@ impl RegExp {
             .get(0)
             .expect("could not get argument")
             .to_string(ctx)?;
-        let mut last_index = this.get_field("lastIndex").to_index(ctx)?;
+        let mut last_index = this.get_field("lastIndex", ctx)?.to_index(ctx)?;
         let result = if let Some(object) = this.as_object() {
             let regex = object.as_regexp().unwrap();
-            let result =
-                if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next() {
-                    if regex.use_last_index {
-                        last_index = m.total().end;
-                    }
-                    true
-                } else {
-                    if regex.use_last_index {
-                        last_index = 0;
-                    }
-                    false
-                };
+            let input = MatchInput::new(arg_str, regex.unicode);
+            let result = if let Some(m) = regex
+                .matcher
+                .find_from(&input.text, input.offset(last_index))
+                .next()
+            {
+                if regex.use_last_index {
+                    last_index = input.index(m.total().end);
+                }
+                true
+            } else {
+                if regex.use_last_index {
+                    last_index = 0;
+                }
+                false
+            };
             Ok(Value::boolean(result))
         } else {
             panic!("object is not a regexp")
         };
-        this.set_field("lastIndex", Value::from(last_index));
+        this.set_field("lastIndex", Value::from(last_index), ctx)?;
         result
     }
 
@@ -347,32 +672,36 @@ impl RegExp {
             .get(0)
             .expect("could not get argument")
             .to_string(ctx)?;
-        let mut last_index = this.get_field("lastIndex").to_index(ctx)?;
+        let mut last_index = this.get_field("lastIndex", ctx)?.to_index(ctx)?;
         let result = if let Some(object) = this.as_object() {
             let regex = object.as_regexp().unwrap();
+            let input = MatchInput::new(arg_str, regex.unicode);
             let result = {
-                if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next() {
+                if let Some(m) = regex
+                    .matcher
+                    .find_from(&input.text, input.offset(last_index))
+                    .next()
+                {
                     if regex.use_last_index {
-                        last_index = m.total().end;
+                        last_index = input.index(m.total().end);
                     }
                     let groups = m.captures.len() + 1;
                     let mut result = Vec::with_capacity(groups);
                     for i in 0..groups {
                         if let Some(range) = m.group(i) {
-                            result.push(Value::from(
-                                arg_str.get(range).expect("Could not get slice"),
-                            ));
+                            result.push(Value::from(input.slice(range)));
                         } else {
                             result.push(Value::undefined());
                         }
                     }
 
-                    let result = Value::from(result);
+                    let result = Array::create_array_from_list(result, ctx);
                     result.set_property(
                         "index",
-                        DataDescriptor::new(m.total().start, Attribute::all()),
+                        DataDescriptor::new(input.index(m.total().start), Attribute::all()),
                     );
-                    result.set_property("input", DataDescriptor::new(arg_str, Attribute::all()));
+                    result
+                        .set_property("input", DataDescriptor::new(input.string, Attribute::all()));
                     result
                 } else {
                     if regex.use_last_index {
@@ -385,7 +714,7 @@ impl RegExp {
         } else {
             panic!("object is not a regexp")
         };
-        this.set_field("lastIndex", Value::from(last_index));
+        this.set_field("lastIndex", Value::from(last_index), ctx)?;
         result
     }
 
@@ -399,27 +728,194 @@ impl RegExp {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@match
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@match
-    pub(crate) fn r#match(this: &Value, arg: RcString, ctx: &mut Context) -> Result<Value> {
-        let (matcher, flags) = if let Some(object) = this.as_object() {
-            let regex = object.as_regexp().unwrap();
-            (regex.matcher.clone(), regex.flags.clone())
-        } else {
-            panic!("object is not a regexp")
-        };
-        if flags.contains('g') {
+    pub(crate) fn r#match(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (matcher, global, unicode) = Self::matcher_of(this, "[Symbol.match]", ctx)?;
+        let arg = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+        if global {
+            let input = MatchInput::new(arg, unicode);
             let mut matches = Vec::new();
-            for mat in matcher.find_iter(&arg) {
-                matches.push(Value::from(&arg[mat.total()]));
+            for mat in matcher.find_iter(&input.text) {
+                matches.push(Value::from(input.slice(mat.total())));
             }
+            this.set_field("lastIndex", 0, ctx)?;
             if matches.is_empty() {
                 return Ok(Value::null());
             }
-            Ok(Value::from(matches))
+            Ok(Array::create_array_from_list(matches, ctx))
         } else {
             Self::exec(this, &[Value::from(arg)], ctx)
         }
     }
 
+    /// `RegExp.prototype[ @@replace ]( string, replaceValue )`
+    ///
+    /// This method replaces the first match, or every match if the `global` flag is set, of the
+    /// regular expression in a string with a replacement string or the result of a function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@replace
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@replace
+    pub(crate) fn replace(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (matcher, global, unicode) = Self::matcher_of(this, "[Symbol.replace]", ctx)?;
+        let input = MatchInput::new(
+            args.get(0).cloned().unwrap_or_default().to_string(ctx)?,
+            unicode,
+        );
+        let replace_value = args.get(1).cloned().unwrap_or_default();
+        let replacement = if replace_value.is_function() {
+            None
+        } else {
+            Some(replace_value.to_string(ctx)?)
+        };
+
+        let matches: Vec<_> = if global {
+            this.set_field("lastIndex", 0, ctx)?;
+            matcher.find_iter(&input.text).collect()
+        } else {
+            matcher.find(&input.text).into_iter().collect()
+        };
+
+        let string = &input.string;
+        let mut result = Vec::with_capacity(string.len());
+        let mut next_position = 0;
+        for mat in matches {
+            let position = input.index(mat.total().start);
+            let matched = input.slice(mat.total());
+            let captures: Vec<Option<RcString>> = mat
+                .captures
+                .iter()
+                .map(|capture| capture.clone().map(|range| input.slice(range)))
+                .collect();
+
+            let replaced = if let Some(ref replacement) = replacement {
+                get_substitution(&matched, string, position, &captures, replacement)
+            } else {
+                let mut replacer_args = vec![Value::from(matched.clone())];
+                replacer_args.extend(captures.iter().map(|capture| match capture {
+                    Some(capture) => Value::from(capture.clone()),
+                    None => Value::undefined(),
+                }));
+                replacer_args.push(Value::from(position));
+                replacer_args.push(Value::from(string.clone()));
+                ctx.call(&replace_value, &Value::undefined(), &replacer_args)?
+                    .to_string(ctx)?
+            };
+
+            result.extend(string.code_units().take(position).skip(next_position));
+            result.extend(replaced.code_units());
+            next_position = position + matched.len();
+        }
+        result.extend(string.code_units().skip(next_position));
+
+        Ok(RcString::from(result).into())
+    }
+
+    /// `RegExp.prototype[ @@search ]( string )`
+    ///
+    /// This method returns the index of the first match of the regular expression in a string,
+    /// or `-1` if there is none. The `lastIndex` of the regular expression is left untouched.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@search
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@search
+    pub(crate) fn search(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (matcher, _, unicode) = Self::matcher_of(this, "[Symbol.search]", ctx)?;
+        let input = MatchInput::new(
+            args.get(0).cloned().unwrap_or_default().to_string(ctx)?,
+            unicode,
+        );
+
+        match matcher.find(&input.text) {
+            Some(mat) => Ok(input.index(mat.total().start).into()),
+            None => Ok(Value::from(-1)),
+        }
+    }
+
+    /// `RegExp.prototype[ @@split ]( string, limit )`
+    ///
+    /// This method splits a string into an array of substrings, using the matches of the regular
+    /// expression as separators. Captured groups are spliced into the resulting array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@split
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@split
+    pub(crate) fn split(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (matcher, _, unicode) = Self::matcher_of(this, "[Symbol.split]", ctx)?;
+        let input = MatchInput::new(
+            args.get(0).cloned().unwrap_or_default().to_string(ctx)?,
+            unicode,
+        );
+        let arg_str = &input.text;
+        let limit = match args.get(1) {
+            Some(limit) if !limit.is_undefined() => limit.to_u32(ctx)?,
+            _ => u32::MAX,
+        } as usize;
+
+        let mut parts = Vec::new();
+        if limit == 0 {
+            return Ok(Array::create_array_from_list(parts, ctx));
+        }
+
+        let size = arg_str.len();
+        if size == 0 {
+            if matcher.find(arg_str).is_none() {
+                parts.push(Value::from(input.string));
+            }
+            return Ok(Array::create_array_from_list(parts, ctx));
+        }
+
+        // `position` is where the next part starts, `search_from` is where the next separator is searched.
+        let mut position = 0;
+        let mut search_from = 0;
+        while search_from < size {
+            let mat = match matcher.find_from(arg_str, search_from).next() {
+                Some(mat) => mat,
+                None => break,
+            };
+            let range = mat.total();
+            if range.start >= size {
+                break;
+            }
+            if range.end == position {
+                // An empty separator right after the previous one splits nothing.
+                search_from = range.start
+                    + arg_str[range.start..]
+                        .chars()
+                        .next()
+                        .map_or(1, char::len_utf8);
+                continue;
+            }
+
+            parts.push(Value::from(input.slice(position..range.start)));
+            if parts.len() == limit {
+                return Ok(Array::create_array_from_list(parts, ctx));
+            }
+            for capture in mat.captures.iter() {
+                parts.push(match capture {
+                    Some(range) => Value::from(input.slice(range.clone())),
+                    None => Value::undefined(),
+                });
+                if parts.len() == limit {
+                    return Ok(Array::create_array_from_list(parts, ctx));
+                }
+            }
+            position = range.end;
+            search_from = position;
+        }
+        parts.push(Value::from(input.slice(position..size)));
+
+        Ok(Array::create_array_from_list(parts, ctx))
+    }
+
     /// `RegExp.prototype.toString()`
     ///
     /// Return a string representing the regular expression.
@@ -441,7 +937,10 @@ impl RegExp {
                 this.display()
             ));
         };
-        Ok(Value::from(format!("/{}/{}", body, flags)))
+        let string = RcString::from("/")
+            .concat(&body)
+            .concat(&RcString::from(format!("/{}", flags)));
+        Ok(Value::from(string))
     }
 
     /// `RegExp.prototype[ @@matchAll ]( string )`
@@ -455,16 +954,21 @@ impl RegExp {
     /// [spec]: https://tc39.es/ecma262/#sec-regexp-prototype-matchall
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@matchAll
     // TODO: it's returning an array, it should return an iterator
-    pub(crate) fn match_all(this: &Value, arg_str: String) -> Result<Value> {
+    pub(crate) fn match_all(
+        this: &Value,
+        arg_str: RcString,
+        context: &mut Context,
+    ) -> Result<Value> {
         let matches = if let Some(object) = this.as_object() {
             let regex = object.as_regexp().unwrap();
+            let input = MatchInput::new(arg_str, regex.unicode);
             let mut matches = Vec::new();
 
-            for mat in regex.matcher.find_iter(&arg_str) {
+            for mat in regex.matcher.find_iter(&input.text) {
                 let match_vec: Vec<Value> = mat
                     .groups()
                     .map(|group| match group {
-                        Some(range) => Value::from(&arg_str[range]),
+                        Some(range) => Value::from(input.slice(range)),
                         None => Value::undefined(),
                     })
                     .collect();
@@ -473,11 +977,11 @@ impl RegExp {
 
                 match_val.set_property(
                     "index",
-                    DataDescriptor::new(mat.total().start, Attribute::all()),
+                    DataDescriptor::new(input.index(mat.total().start), Attribute::all()),
                 );
                 match_val.set_property(
                     "input",
-                    DataDescriptor::new(arg_str.clone(), Attribute::all()),
+                    DataDescriptor::new(input.string.clone(), Attribute::all()),
                 );
                 matches.push(match_val);
 
@@ -493,7 +997,7 @@ impl RegExp {
 
         let length = matches.len();
         let result = Value::from(matches);
-        result.set_field("length", Value::from(length));
+        result.set_field("length", Value::from(length), context)?;
         result.set_data(ObjectData::Array);
 
         Ok(result)
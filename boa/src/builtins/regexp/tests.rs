@@ -98,3 +98,64 @@ fn to_string() {
     );
     assert_eq!(forward(&mut engine, "/\\n/g.toString()"), "\"/\\n/g\"");
 }
+
+#[test]
+fn symbol_methods() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "/b/[Symbol.replace]('abc', 'x')"),
+        "\"axc\""
+    );
+    assert_eq!(forward(&mut engine, "/c/[Symbol.search]('abc')"), "2");
+    assert_eq!(
+        forward(&mut engine, "/,/[Symbol.split]('a,b,c', 2).join('|')"),
+        "\"a|b\""
+    );
+    assert_eq!(
+        forward(&mut engine, "/[a-z]/g[Symbol.match]('a1b2').join()"),
+        "\"a,b\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { RegExp.prototype[Symbol.match].call({}, 'a') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn invalid_pattern() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "try { new RegExp('(') } catch (e) { e.name }"),
+        "\"SyntaxError\""
+    );
+    assert_eq!(forward(&mut engine, "new RegExp().test('')"), "true");
+}
+
+#[test]
+fn surrogates() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, r"/\uD83D/.test('😀')"), "true");
+    assert_eq!(forward(&mut engine, r"/\uD83D/u.test('😀')"), "false");
+    assert_eq!(forward(&mut engine, "/^.$/.test('😀')"), "false");
+    assert_eq!(forward(&mut engine, "/^.$/u.test('😀')"), "true");
+    assert_eq!(
+        forward(&mut engine, r"/\uD800/.exec('a\uD800b').index"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut engine, r"/^[\uD800-\uDFFF]$/.test('\uDC01')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, r"/[\u0000-\uFFFF]/.test('\uD800')"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, r"'😀😀'.match(/\uDE00/g).length"), "2");
+    assert_eq!(
+        forward(&mut engine, r"'x\uDC00y'.replace(/\uDC00/, '!')"),
+        "\"x!y\""
+    );
+}
@@ -0,0 +1,331 @@
+//! This module implements the global `Set` object.
+//!
+//! A set is a collection of unique values, which are iterated in insertion order.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-set-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set
+
+#![allow(clippy::mutable_key_type)]
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    object::{ConstructorBuilder, FunctionBuilder, ObjectData},
+    property::{AccessorDescriptor, Attribute},
+    BoaProfiler, Context, Result, Value,
+};
+use ordered_set::OrderedSet;
+use set_iterator::{SetIterationKind, SetIterator};
+
+pub mod ordered_set;
+pub mod set_iterator;
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Set;
+
+impl BuiltIn for Set {
+    const NAME: &'static str = "Set";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let symbol_iterator = context.well_known_symbols().iterator_symbol();
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let values_function = FunctionBuilder::new(context, Self::values)
+            .name("values")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let set_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().set_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::add, "add", 1)
+        .method(Self::clear, "clear", 0)
+        .method(Self::delete, "delete", 1)
+        .method(Self::entries, "entries", 0)
+        .method(Self::for_each, "forEach", 1)
+        .method(Self::has, "has", 1)
+        .property(
+            "keys",
+            values_function.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            "values",
+            values_function.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            symbol_iterator,
+            values_function,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+        .callable(false)
+        .build();
+
+        let get_size = FunctionBuilder::new(context, Self::size)
+            .name("get size")
+            .callable(true)
+            .constructable(false)
+            .build();
+        context
+            .standard_objects()
+            .set_object()
+            .prototype()
+            .borrow_mut()
+            .insert(
+                "size",
+                AccessorDescriptor::new(Some(get_size), None, Attribute::CONFIGURABLE),
+            );
+
+        (Self::NAME, set_object.into(), Self::attribute())
+    }
+}
+
+impl Set {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `Set( [ iterable ] )`
+    ///
+    /// Creates a new set, adding the values of `iterable` to it with its `add` method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterable
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        this.set_data(ObjectData::Set(OrderedSet::new()));
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(this.clone());
+        }
+
+        let adder = this.get_field("add", ctx)?;
+        if !adder.is_function() {
+            return ctx.throw_type_error("'add' of the Set is not a function");
+        }
+
+        let iterator_record = get_iterator(ctx, iterable)?;
+        loop {
+            let next = iterator_record.next(ctx)?;
+            if next.is_done() {
+                break;
+            }
+            let status = ctx.call(&adder, this, &[next.value()]);
+            if status.is_err() {
+                return iterator_record.close(status, ctx);
+            }
+        }
+
+        Ok(this.clone())
+    }
+
+    /// `get Set.prototype.size`
+    ///
+    /// Returns the number of values in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-set.prototype.size
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/size
+    pub(crate) fn size(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        if let Value::Object(ref object) = this {
+            if let Some(set) = object.borrow().as_set_ref() {
+                return Ok(set.len().into());
+            }
+        }
+
+        ctx.throw_type_error("'this' is not a Set")
+    }
+
+    /// `Set.prototype.add( value )`
+    ///
+    /// This method appends the value to the set, if it is not already in it. Returns the set object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add
+    pub(crate) fn add(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = match args.get(0) {
+            // -0 is normalized to +0, like the spec requires.
+            Some(value) if value.as_number() == Some(0.0) => Value::from(0),
+            Some(value) => value.clone(),
+            None => Value::undefined(),
+        };
+
+        if let Value::Object(ref object) = this {
+            if let Some(set) = object.borrow_mut().as_set_mut() {
+                set.insert(value);
+                return Ok(this.clone());
+            }
+        }
+
+        ctx.throw_type_error("'this' is not a Set")
+    }
+
+    /// `Set.prototype.clear( )`
+    ///
+    /// This method removes all the values from the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.clear
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/clear
+    pub(crate) fn clear(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        if let Value::Object(ref object) = this {
+            if let Some(set) = object.borrow_mut().as_set_mut() {
+                set.clear();
+                return Ok(Value::undefined());
+            }
+        }
+
+        ctx.throw_type_error("'this' is not a Set")
+    }
+
+    /// `Set.prototype.delete( value )`
+    ///
+    /// This method removes the value from the set. Returns true if it was in the set, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+
+        if let Value::Object(ref object) = this {
+            if let Some(set) = object.borrow_mut().as_set_mut() {
+                return Ok(set.remove(&value).into());
+            }
+        }
+
+        ctx.throw_type_error("'this' is not a Set")
+    }
+
+    /// `Set.prototype.entries( )`
+    ///
+    /// This method returns an iterator over `[value, value]` pairs for each value in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/entries
+    pub(crate) fn entries(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_iterator(this, SetIterationKind::KeyAndValue, ctx)
+    }
+
+    /// `Set.prototype.forEach( callbackFn [ , thisArg ] )`
+    ///
+    /// This method executes the provided callback function for each value in the set.
+    ///
+    /// Values added while iterating are visited too.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/forEach
+    pub(crate) fn for_each(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) if object.borrow().is_set() => object,
+            _ => return ctx.throw_type_error("'this' is not a Set"),
+        };
+
+        let callback = args.get(0).cloned().unwrap_or_default();
+        if !callback.is_function() {
+            return ctx.throw_type_error("Set.prototype.forEach: callback is not a function");
+        }
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        let mut index = 0;
+        loop {
+            let value = match object
+                .borrow()
+                .as_set_ref()
+                .and_then(|set| set.get_index(index))
+            {
+                Some(value) => value.clone(),
+                None => break,
+            };
+            ctx.call(&callback, &this_arg, &[value.clone(), value, this.clone()])?;
+            index += 1;
+        }
+
+        Ok(Value::undefined())
+    }
+
+    /// `Set.prototype.has( value )`
+    ///
+    /// This method checks if the set contains the given value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/has
+    pub(crate) fn has(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+
+        if let Value::Object(ref object) = this {
+            if let Some(set) = object.borrow().as_set_ref() {
+                return Ok(set.contains(&value).into());
+            }
+        }
+
+        ctx.throw_type_error("'this' is not a Set")
+    }
+
+    /// `Set.prototype.values( )`
+    ///
+    /// This method returns an iterator over the values of the set. It is also `keys` and
+    /// `@@iterator` of the set prototype.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values
+    pub(crate) fn values(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_iterator(this, SetIterationKind::Value, ctx)
+    }
+
+    /// Helper function to create an iterator over `this`, if it is a set.
+    fn create_iterator(this: &Value, kind: SetIterationKind, ctx: &mut Context) -> Result<Value> {
+        match this {
+            Value::Object(ref object) if object.borrow().is_set() => {
+                Ok(SetIterator::create_set_iterator(ctx, this.clone(), kind))
+            }
+            _ => ctx.throw_type_error("'this' is not a Set"),
+        }
+    }
+}
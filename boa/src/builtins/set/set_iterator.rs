@@ -0,0 +1,123 @@
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, Array, Value},
+    object::ObjectData,
+    property::{Attribute, DataDescriptor},
+    BoaProfiler, Context, Result,
+};
+use gc::{Finalize, Trace};
+
+#[derive(Debug, Clone, Finalize, Trace)]
+pub enum SetIterationKind {
+    Value,
+    KeyAndValue,
+}
+
+/// The Set Iterator object represents an iteration over a set. It implements the iterator protocol.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set-iterator-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct SetIterator {
+    set: Value,
+    next_index: usize,
+    kind: SetIterationKind,
+}
+
+impl SetIterator {
+    pub(crate) const NAME: &'static str = "SetIterator";
+
+    fn new(set: Value, kind: SetIterationKind) -> Self {
+        SetIterator {
+            set,
+            kind,
+            next_index: 0,
+        }
+    }
+
+    /// CreateSetIterator( set, kind )
+    ///
+    /// Creates a new iterator over the given set.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createsetiterator
+    pub(crate) fn create_set_iterator(ctx: &Context, set: Value, kind: SetIterationKind) -> Value {
+        let set_iterator = Value::new_object(Some(ctx.global_object()));
+        set_iterator.set_data(ObjectData::SetIterator(Self::new(set, kind)));
+        set_iterator
+            .as_object_mut()
+            .expect("set iterator object")
+            .set_prototype_instance(ctx.iterator_prototypes().set_iterator().into());
+        set_iterator
+    }
+
+    /// %SetIteratorPrototype%.next( )
+    ///
+    /// Gets the next value of the set.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%setiteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (value, kind) = if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(set_iterator) = object.as_set_iterator_mut() {
+                let value = match set_iterator.set {
+                    Value::Object(ref set) => set
+                        .borrow()
+                        .as_set_ref()
+                        .and_then(|set| set.get_index(set_iterator.next_index))
+                        .cloned(),
+                    _ => None,
+                };
+                if let Some(value) = value {
+                    set_iterator.next_index += 1;
+                    (value, set_iterator.kind.clone())
+                } else {
+                    set_iterator.set = Value::undefined();
+                    return Ok(create_iter_result_object(ctx, Value::undefined(), true));
+                }
+            } else {
+                return ctx.throw_type_error("`this` is not a SetIterator");
+            }
+        } else {
+            return ctx.throw_type_error("`this` is not a SetIterator");
+        };
+
+        let result = match kind {
+            SetIterationKind::Value => value,
+            SetIterationKind::KeyAndValue => {
+                Array::create_array_from_list(vec![value.clone(), value], ctx)
+            }
+        };
+        Ok(create_iter_result_object(ctx, result, false))
+    }
+
+    /// Create the %SetIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%setiteratorprototype%-object
+    pub(crate) fn create_prototype(ctx: &mut Context, iterator_prototype: Value) -> Value {
+        let global = ctx.global_object();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let set_iterator = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &set_iterator, 0, ctx);
+        set_iterator
+            .as_object_mut()
+            .expect("set iterator prototype object")
+            .set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = ctx.well_known_symbols().to_string_tag_symbol();
+        let to_string_tag_property = DataDescriptor::new("Set Iterator", Attribute::CONFIGURABLE);
+        set_iterator.set_property(to_string_tag, to_string_tag_property);
+        set_iterator
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct_empty() {
+    let mut engine = Context::new();
+    let init = r#"
+        var empty = new Set();
+        "#;
+    forward(&mut engine, init);
+    let result = forward(&mut engine, "empty.size");
+    assert_eq!(result, "0");
+}
+
+#[test]
+fn construct_from_iterable() {
+    let mut engine = Context::new();
+    let init = r#"
+        let set = new Set([1, 2, 2, "3", 1]);
+        let fromString = new Set("hello");
+        let copy = new Set(set);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "set.size"), "3");
+    assert_eq!(forward(&mut engine, "fromString.size"), "4");
+    assert_eq!(forward(&mut engine, "copy.size"), "3");
+    assert_eq!(
+        forward(&mut engine, "try { Set() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new Set(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn add_has_delete_clear() {
+    let mut engine = Context::new();
+    let init = r#"
+        let set = new Set();
+        let object = {};
+        set.add(1).add(object).add(NaN).add(-0);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "set.size"), "4");
+    assert_eq!(forward(&mut engine, "set.has(1)"), "true");
+    assert_eq!(forward(&mut engine, "set.has(0.5 * 2)"), "true");
+    assert_eq!(forward(&mut engine, "set.has(object)"), "true");
+    assert_eq!(forward(&mut engine, "set.has({})"), "false");
+    assert_eq!(forward(&mut engine, "set.has(NaN)"), "true");
+    assert_eq!(forward(&mut engine, "set.has(0)"), "true");
+    assert_eq!(forward(&mut engine, "Object.is([...set][3], 0)"), "true");
+    assert_eq!(forward(&mut engine, "set.delete(1)"), "true");
+    assert_eq!(forward(&mut engine, "set.delete(1)"), "false");
+    assert_eq!(forward(&mut engine, "set.size"), "3");
+    forward(&mut engine, "set.clear()");
+    assert_eq!(forward(&mut engine, "set.size"), "0");
+}
+
+#[test]
+fn iterators() {
+    let mut engine = Context::new();
+    let init = r#"
+        let set = new Set(["a", "b", "c"]);
+        let values = [];
+        for (let value of set) {
+            values.push(value);
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "values.join()"), "\"a,b,c\"");
+    assert_eq!(forward(&mut engine, "[...set.keys()].join()"), "\"a,b,c\"");
+    assert_eq!(
+        forward(&mut engine, "[...set.entries()].join(';')"),
+        "\"a,a;b,b;c,c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Set.prototype.keys === Set.prototype.values"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Set.prototype[Symbol.iterator] === Set.prototype.values"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(set.values())"),
+        "\"[object Set Iterator]\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "let iterator = set.values(); iterator.next(); set.add('d'); [...iterator].join()"
+        ),
+        "\"b,c,d\""
+    );
+}
+
+#[test]
+fn for_each() {
+    let mut engine = Context::new();
+    let init = r#"
+        let set = new Set([1, 2]);
+        let visited = [];
+        set.forEach(function(value, key, s) {
+            visited.push(value + ":" + key + ":" + (s === set) + ":" + this.tag);
+            if (value === 1) {
+                set.add(3);
+            }
+        }, { tag: "t" });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "visited.join()"),
+        "\"1:1:true:t,2:2:true:t,3:3:true:t\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { set.forEach(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn size_and_this_errors() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "typeof Object.getOwnPropertyDescriptor(Set.prototype, 'size').get"
+        ),
+        "\"function\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Set.prototype.size } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Set.prototype.add.call(new Map(), 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(new Set())"),
+        "\"[object Set]\""
+    );
+}
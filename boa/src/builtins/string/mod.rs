@@ -14,47 +14,123 @@ pub mod string_iterator;
 mod tests;
 
 use crate::{
-    builtins::{string::string_iterator::StringIterator, BuiltIn, RegExp},
+    builtins::{string::string_iterator::StringIterator, Array, BuiltIn, RegExp},
     object::{ConstructorBuilder, Object, ObjectData},
-    property::Attribute,
-    value::{RcString, Value},
+    property::{Attribute, DataDescriptor},
+    value::{RcString, RcSymbol, Value},
     BoaProfiler, Context, Result,
 };
-use regress::Regex;
 use std::{
-    char::decode_utf16,
     cmp::{max, min},
     f64::NAN,
+    slice,
     string::String as StdString,
 };
-
-pub(crate) fn code_point_at(string: RcString, position: i32) -> Option<(u32, u8, bool)> {
-    let size = string.encode_utf16().count() as i32;
-    if position < 0 || position >= size {
-        return None;
-    }
-    let mut encoded = string.encode_utf16();
-    let first = encoded.nth(position as usize)?;
-    if !is_leading_surrogate(first) && !is_trailing_surrogate(first) {
-        return Some((first as u32, 1, false));
-    }
-    if is_trailing_surrogate(first) || position + 1 == size {
-        return Some((first as u32, 1, true));
-    }
-    let second = encoded.next()?;
-    if !is_trailing_surrogate(second) {
-        return Some((first as u32, 1, true));
+use unicode_normalization::UnicodeNormalization;
+
+/// Abstract operation `GetSubstitution`.
+///
+/// Expands the `$` patterns of a replacement string, given the `matched` substring found at the
+/// code unit index `position` of `string` and the `captures` of the match.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
+pub(crate) fn get_substitution(
+    matched: &RcString,
+    string: &RcString,
+    position: usize,
+    captures: &[Option<RcString>],
+    replacement: &RcString,
+) -> RcString {
+    let m = captures.len();
+    let tail_position = (position + matched.len()).min(string.len());
+    let mut result = Vec::with_capacity(replacement.len());
+    let mut units = replacement.code_units().peekable();
+
+    while let Some(first) = units.next() {
+        if first != u16::from(b'$') {
+            result.push(first);
+            continue;
+        }
+        let second = units
+            .peek()
+            .and_then(|second| std::char::from_u32(u32::from(*second)));
+        match second {
+            Some('$') => {
+                // $$
+                units.next();
+                result.push(first);
+            }
+            Some('&') => {
+                // $&
+                units.next();
+                result.extend(matched.code_units());
+            }
+            Some('`') => {
+                // $`
+                units.next();
+                result.extend(string.code_units().take(position));
+            }
+            Some('\'') => {
+                // $'
+                units.next();
+                result.extend(string.code_units().skip(tail_position));
+            }
+            Some(second) if second.is_ascii_digit() => {
+                // $n and $nn, preferring the two digit group if it exists
+                units.next();
+                let n = second.to_digit(10).unwrap() as usize;
+                let nn = units
+                    .peek()
+                    .and_then(|third| std::char::from_u32(u32::from(*third)))
+                    .and_then(|third| third.to_digit(10))
+                    .map(|third| n * 10 + third as usize)
+                    .filter(|nn| *nn != 0 && *nn <= m);
+                let index = match nn {
+                    Some(nn) => {
+                        units.next();
+                        nn
+                    }
+                    None if n != 0 && n <= m => n,
+                    None => {
+                        result.push(first);
+                        result.push(second as u16);
+                        continue;
+                    }
+                };
+                if let Some(ref capture) = captures[index - 1] {
+                    result.extend(capture.code_units());
+                }
+            }
+            // $< is only a pattern with named capture groups, which aren't supported
+            _ => result.push(first),
+        }
     }
-    let cp = (first as u32 - 0xD800) * 0x400 + (second as u32 - 0xDC00) + 0x10000;
-    Some((cp, 2, false))
-}
 
-fn is_leading_surrogate(value: u16) -> bool {
-    value >= 0xD800 && value <= 0xDBFF
+    result.into()
 }
 
-fn is_trailing_surrogate(value: u16) -> bool {
-    value >= 0xDC00 && value <= 0xDFFF
+/// Applies a conversion of Rust strings, like a case mapping, to a JavaScript string.
+///
+/// Unpaired surrogates can't be part of a Rust string, so they are kept as they are and the
+/// parts between them are converted separately.
+fn convert_str(string: &RcString, convert: fn(&str) -> StdString) -> RcString {
+    let mut result = Vec::with_capacity(string.len());
+    let mut part = StdString::new();
+    for code_point in string.code_points() {
+        match code_point {
+            Ok(c) => part.push(c),
+            Err(error) => {
+                result.extend(convert(&part).encode_utf16());
+                result.push(error.unpaired_surrogate());
+                part.clear();
+            }
+        }
+    }
+    result.extend(convert(&part).encode_utf16());
+    result.into()
 }
 
 /// JavaScript `String` implementation.
@@ -84,6 +160,8 @@ impl BuiltIn for String {
         .property("length", 0, attribute)
         .method(Self::char_at, "charAt", 1)
         .method(Self::char_code_at, "charCodeAt", 1)
+        .method(Self::code_point_at, "codePointAt", 1)
+        .method(Self::at, "at", 1)
         .method(Self::to_string, "toString", 0)
         .method(Self::concat, "concat", 1)
         .method(Self::repeat, "repeat", 1)
@@ -94,6 +172,10 @@ impl BuiltIn for String {
         .method(Self::index_of, "indexOf", 1)
         .method(Self::last_index_of, "lastIndexOf", 1)
         .method(Self::r#match, "match", 1)
+        .method(Self::search, "search", 1)
+        .method(Self::split, "split", 2)
+        .method(Self::normalize, "normalize", 0)
+        .method(Self::locale_compare, "localeCompare", 1)
         .method(Self::pad_end, "padEnd", 1)
         .method(Self::pad_start, "padStart", 1)
         .method(Self::trim, "trim", 0)
@@ -106,7 +188,11 @@ impl BuiltIn for String {
         .method(Self::value_of, "valueOf", 0)
         .method(Self::match_all, "matchAll", 1)
         .method(Self::replace, "replace", 2)
+        .method(Self::replace_all, "replaceAll", 2)
         .method(Self::iterator, (symbol_iterator, "[Symbol.iterator]"), 0)
+        .static_method(Self::raw, "raw", 1)
+        .static_method(Self::from_char_code, "fromCharCode", 1)
+        .static_method(Self::from_code_point, "fromCodePoint", 1)
         .build();
 
         (Self::NAME, string_object.into(), Self::attribute())
@@ -135,9 +221,11 @@ impl String {
             None => RcString::default(),
         };
 
-        let length = string.encode_utf16().count();
-
-        this.set_field("length", Value::from(length as i32));
+        let length = DataDescriptor::new(
+            string.len(),
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        this.set_property("length", length);
 
         this.set_data(ObjectData::String(string.clone()));
 
@@ -192,22 +280,13 @@ impl String {
             .expect("failed to get argument for String method")
             .to_integer(ctx)? as i32;
 
-        // Calling .len() on a string would give the wrong result, as they are bytes not the number of
-        // unicode code points
-        // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of
-        // bytes is an O(1) operation.
-        let length = primitive_val.chars().count();
-
         // We should return an empty string is pos is out of range
-        if pos >= length as i32 || pos < 0 {
+        if pos >= primitive_val.len() as i32 || pos < 0 {
             return Ok("".into());
         }
 
         Ok(Value::from(
-            primitive_val
-                .chars()
-                .nth(pos as usize)
-                .expect("failed to get value"),
+            primitive_val.substring(pos as usize, pos as usize + 1),
         ))
     }
 
@@ -230,24 +309,78 @@ impl String {
         // Then we convert it into a Rust String by wrapping it in from_value
         let primitive_val = this.to_string(ctx)?;
 
-        // Calling .len() on a string would give the wrong result, as they are bytes not the number of unicode code points
-        // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of bytes is an O(1) operation.
-        let length = primitive_val.chars().count();
         let pos = args
             .get(0)
             .expect("failed to get argument for String method")
             .to_integer(ctx)? as i32;
 
-        if pos >= length as i32 || pos < 0 {
+        // If there is no element at that index, the result is NaN
+        if pos < 0 {
             return Ok(Value::from(NAN));
         }
+        match primitive_val.code_unit_at(pos as usize) {
+            Some(code_unit) => Ok(Value::from(f64::from(code_unit))),
+            None => Ok(Value::from(NAN)),
+        }
+    }
 
-        let utf16_val = primitive_val
-            .encode_utf16()
-            .nth(pos as usize)
-            .expect("failed to get utf16 value");
-        // If there is no element at that index, the result is NaN
-        Ok(Value::from(f64::from(utf16_val)))
+    /// `String.prototype.codePointAt( index )`
+    ///
+    /// The `codePointAt()` method returns a non-negative integer that is the Unicode code point value
+    /// of the character starting at the given UTF-16 code unit index.
+    ///
+    /// `codePointAt()` returns `undefined` if the given index is less than `0`, or if it is equal to or greater than the `length` of the string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.codepointat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/codePointAt
+    pub(crate) fn code_point_at(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.require_object_coercible(ctx)?;
+        let string = object.to_string(ctx)?;
+        let position = args.get(0).cloned().unwrap_or_default().to_integer(ctx)?;
+
+        if position < 0.0 || position >= string.len() as f64 {
+            return Ok(Value::undefined());
+        }
+
+        match string.code_point_at(position as usize) {
+            Some((code_point, _, _)) => Ok(Value::from(code_point)),
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// `String.prototype.at( index )`
+    ///
+    /// The `at()` method returns the UTF-16 code unit at the given index. Negative integers count back from the last one.
+    ///
+    /// `at()` returns `undefined` if the index is out of range.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-relative-indexing-method/#sec-string.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/at
+    pub(crate) fn at(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.require_object_coercible(ctx)?;
+        let string = object.to_string(ctx)?;
+        let relative_index = args.get(0).cloned().unwrap_or_default().to_integer(ctx)?;
+
+        let length = string.len() as f64;
+        let index = if relative_index >= 0.0 {
+            relative_index
+        } else {
+            length + relative_index
+        };
+        if index < 0.0 || index >= length {
+            return Ok(Value::undefined());
+        }
+
+        let index = index as usize;
+        Ok(Value::from(string.substring(index, index + 1)))
     }
 
     /// `String.prototype.concat( str1[, ...strN] )`
@@ -266,13 +399,13 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/concat
     pub(crate) fn concat(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let object = this.require_object_coercible(ctx)?;
-        let mut string = object.to_string(ctx)?.to_string();
+        let mut string: Vec<u16> = object.to_string(ctx)?.code_units().collect();
 
         for arg in args {
-            string.push_str(&arg.to_string(ctx)?);
+            string.extend(arg.to_string(ctx)?.code_units());
         }
 
-        Ok(Value::from(string))
+        Ok(Value::from(RcString::from(string)))
     }
 
     /// `String.prototype.repeat( count )`
@@ -304,7 +437,8 @@ impl String {
                 return ctx
                     .throw_range_error("repeat count must not overflow maximum string length");
             }
-            Ok(string.repeat(n as usize).into())
+            let code_units: Vec<u16> = string.code_units().collect();
+            Ok(RcString::from(code_units.repeat(n as usize)).into())
         } else {
             Ok("".into())
         }
@@ -335,9 +469,7 @@ impl String {
             .expect("failed to get argument in slice")
             .to_integer(ctx)? as i32;
 
-        // Calling .len() on a string would give the wrong result, as they are bytes not the number of unicode code points
-        // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of bytes is an O(1) operation.
-        let length = primitive_val.chars().count() as i32;
+        let length = primitive_val.len() as i32;
 
         let from = if start < 0 {
             max(length.wrapping_add(start), 0)
@@ -350,14 +482,12 @@ impl String {
             min(end, length)
         };
 
-        let span = max(to.wrapping_sub(from), 0);
-
-        let new_str: StdString = primitive_val
-            .chars()
-            .skip(from as usize)
-            .take(span as usize)
-            .collect();
-        Ok(Value::from(new_str))
+        if from >= to {
+            return Ok(Value::from(""));
+        }
+        Ok(Value::from(
+            primitive_val.substring(from as usize, to as usize),
+        ))
     }
 
     /// `String.prototype.startWith( searchString[, position] )`
@@ -385,8 +515,8 @@ impl String {
 
         let search_string = arg.to_string(ctx)?;
 
-        let length = primitive_val.chars().count() as i32;
-        let search_length = search_string.chars().count() as i32;
+        let length = primitive_val.len() as i32;
+        let search_length = search_string.len() as i32;
 
         // If less than 2 args specified, position is 'undefined', defaults to 0
         let position = if args.len() < 2 {
@@ -401,9 +531,9 @@ impl String {
         if end > length {
             Ok(Value::from(false))
         } else {
-            // Only use the part of the string from "start"
-            let this_string: StdString = primitive_val.chars().skip(start as usize).collect();
-            Ok(Value::from(this_string.starts_with(search_string.as_str())))
+            Ok(Value::from(
+                primitive_val.has_at(&search_string, start as usize),
+            ))
         }
     }
 
@@ -432,8 +562,8 @@ impl String {
 
         let search_string = arg.to_string(ctx)?;
 
-        let length = primitive_val.chars().count() as i32;
-        let search_length = search_string.chars().count() as i32;
+        let length = primitive_val.len() as i32;
+        let search_length = search_string.len() as i32;
 
         // If less than 2 args specified, end_position is 'undefined', defaults to
         // length of this
@@ -451,9 +581,9 @@ impl String {
         if start < 0 {
             Ok(Value::from(false))
         } else {
-            // Only use the part of the string up to "end"
-            let this_string: StdString = primitive_val.chars().take(end as usize).collect();
-            Ok(Value::from(this_string.ends_with(search_string.as_str())))
+            Ok(Value::from(
+                primitive_val.has_at(&search_string, start as usize),
+            ))
         }
     }
 
@@ -482,7 +612,7 @@ impl String {
 
         let search_string = arg.to_string(ctx)?;
 
-        let length = primitive_val.chars().count() as i32;
+        let length = primitive_val.len() as i32;
 
         // If less than 2 args specified, position is 'undefined', defaults to 0
         let position = if args.len() < 2 {
@@ -495,34 +625,72 @@ impl String {
 
         let start = min(max(position, 0), length);
 
-        // Take the string from "this" and use only the part of it after "start"
-        let this_string: StdString = primitive_val.chars().skip(start as usize).collect();
-
-        Ok(Value::from(this_string.contains(search_string.as_str())))
+        Ok(Value::from(
+            primitive_val
+                .index_of(&search_string, start as usize)
+                .is_some(),
+        ))
     }
 
-    /// Return either the string itself or the string of the regex equivalent
-    fn get_regex_string(value: &Value) -> StdString {
+    fn is_regexp_object(value: &Value) -> bool {
         match value {
-            Value::String(ref body) => body.to_string(),
-            Value::Object(ref obj) => {
-                let obj = obj.borrow();
+            Value::Object(ref obj) => obj.borrow().is_regexp(),
+            _ => false,
+        }
+    }
 
-                if let Some(regexp) = obj.as_regexp() {
-                    // first argument is another `RegExp` object, so copy its pattern and flags
-                    return regexp.original_source.clone();
-                }
-                "undefined".to_string()
-            }
-            _ => "undefined".to_string(),
+    /// Abstract operation `IsRegExp( argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isregexp
+    fn is_regexp(value: &Value, ctx: &mut Context) -> Result<bool> {
+        if !value.is_object() {
+            return Ok(false);
+        }
+        let matcher = value.get_field(ctx.well_known_symbols().match_symbol(), ctx)?;
+        if !matcher.is_undefined() {
+            return Ok(matcher.to_boolean());
         }
+        Ok(Self::is_regexp_object(value))
     }
 
-    fn is_regexp_object(value: &Value) -> bool {
-        match value {
-            Value::Object(ref obj) => obj.borrow().is_regexp(),
-            _ => false,
+    /// Calls the method stored under the well-known `symbol` of `value`, if `value` isn't `undefined`
+    /// or `null` and has such a method.
+    ///
+    /// This is how the methods taking a pattern let `RegExp` objects, or any other object
+    /// implementing `Symbol.match`, `Symbol.replace`, `Symbol.search` or `Symbol.split`, do the work.
+    fn call_symbol_method(
+        value: &Value,
+        symbol: RcSymbol,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Option<Value>> {
+        if value.is_null_or_undefined() {
+            return Ok(None);
+        }
+        let method = value.to_object(ctx)?.get(&symbol.into(), value, ctx)?;
+        if method.is_null_or_undefined() {
+            return Ok(None);
+        }
+        if !method.is_function() {
+            return Err(ctx.construct_type_error(format!("{} is not a function", method.display())));
         }
+        ctx.call(&method, value, args).map(Some)
+    }
+
+    /// Creates a `RegExp` from `regexp` and invokes its method stored under the well-known `symbol`.
+    fn invoke_regexp_method(
+        string: RcString,
+        regexp: Value,
+        flags: Value,
+        symbol: RcSymbol,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let rx = RegExp::create(regexp, flags, ctx)?;
+        let method = rx.get_field(symbol, ctx)?;
+        ctx.call(&method, &rx, &[Value::from(string)])
     }
 
     /// `String.prototype.replace( regexp|substr, newSubstr|function )`
@@ -541,148 +709,153 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replace
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace
     pub(crate) fn replace(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // TODO: Support Symbol replacer
-        let primitive_val = this.to_string(ctx)?;
-        if args.is_empty() {
-            return Ok(Value::from(primitive_val));
+        let object = this.require_object_coercible(ctx)?;
+        let search_value = args.get(0).cloned().unwrap_or_default();
+        let replace_value = args.get(1).cloned().unwrap_or_default();
+
+        let symbol = ctx.well_known_symbols().replace_symbol();
+        let replacer_args = [object.clone(), replace_value.clone()];
+        if let Some(result) = Self::call_symbol_method(&search_value, symbol, &replacer_args, ctx)?
+        {
+            return Ok(result);
         }
 
-        let regex_body = Self::get_regex_string(args.get(0).expect("Value needed"));
-        let re = Regex::new(&regex_body).expect("unable to convert regex to regex object");
-        let mat = match re.find(&primitive_val) {
-            Some(mat) => mat,
-            None => return Ok(Value::from(primitive_val)),
+        let string = object.to_string(ctx)?;
+        let search_string = search_value.to_string(ctx)?;
+        let replacement = Self::replacement_string(&replace_value, ctx)?;
+
+        let position = match string.index_of(&search_string, 0) {
+            Some(position) => position,
+            None => return Ok(Value::from(string)),
         };
-        let caps = re
-            .find(&primitive_val)
-            .expect("unable to get capture groups from text")
-            .captures;
-
-        let replace_value = if args.len() > 1 {
-            // replace_object could be a string or function or not exist at all
-            let replace_object: &Value = args.get(1).expect("second argument expected");
-            match replace_object {
-                Value::String(val) => {
-                    // https://tc39.es/ecma262/#table-45
-                    let mut result = StdString::new();
-                    let mut chars = val.chars().peekable();
-
-                    let m = caps.len();
-
-                    while let Some(first) = chars.next() {
-                        if first == '$' {
-                            let second = chars.next();
-                            let second_is_digit = second.map_or(false, |ch| ch.is_digit(10));
-                            // we use peek so that it is still in the iterator if not used
-                            let third = if second_is_digit { chars.peek() } else { None };
-                            let third_is_digit = third.map_or(false, |ch| ch.is_digit(10));
-
-                            match (second, third) {
-                                (Some('$'), _) => {
-                                    // $$
-                                    result.push('$');
-                                }
-                                (Some('&'), _) => {
-                                    // $&
-                                    result.push_str(&primitive_val[mat.total()]);
-                                }
-                                (Some('`'), _) => {
-                                    // $`
-                                    let start_of_match = mat.total().start;
-                                    result.push_str(&primitive_val[..start_of_match]);
-                                }
-                                (Some('\''), _) => {
-                                    // $'
-                                    let end_of_match = mat.total().end;
-                                    result.push_str(&primitive_val[end_of_match..]);
-                                }
-                                (Some(second), Some(third))
-                                    if second_is_digit && third_is_digit =>
-                                {
-                                    // $nn
-                                    let tens = second.to_digit(10).unwrap() as usize;
-                                    let units = third.to_digit(10).unwrap() as usize;
-                                    let nn = 10 * tens + units;
-                                    if nn == 0 || nn > m {
-                                        result.push(first);
-                                        result.push(second);
-                                        if let Some(ch) = chars.next() {
-                                            result.push(ch);
-                                        }
-                                    } else {
-                                        let group = match mat.group(nn) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                        chars.next(); // consume third
-                                    }
-                                }
-                                (Some(second), _) if second_is_digit => {
-                                    // $n
-                                    let n = second.to_digit(10).unwrap() as usize;
-                                    if n == 0 || n > m {
-                                        result.push(first);
-                                        result.push(second);
-                                    } else {
-                                        let group = match mat.group(n) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                    }
-                                }
-                                (Some('<'), _) => {
-                                    // $<
-                                    todo!("named capture groups")
-                                }
-                                _ => {
-                                    // $?, ? is none of the above
-                                    // we can consume second because it isn't $
-                                    result.push(first);
-                                    if let Some(second) = second {
-                                        result.push(second);
-                                    }
-                                }
-                            }
-                        } else {
-                            result.push(first);
-                        }
-                    }
+        let replaced = Self::replace_at(
+            &string,
+            &search_string,
+            position,
+            &replace_value,
+            replacement.as_ref(),
+            ctx,
+        )?;
+
+        let mut result = Vec::with_capacity(string.len());
+        result.extend(string.code_units().take(position));
+        result.extend(replaced.code_units());
+        result.extend(string.code_units().skip(position + search_string.len()));
+        Ok(RcString::from(result).into())
+    }
 
-                    result
-                }
-                Value::Object(_) => {
-                    // This will return the matched substring first, then captured parenthesized groups later
-                    let mut results: Vec<Value> = mat
-                        .groups()
-                        .map(|group| match group {
-                            Some(range) => Value::from(&primitive_val[range]),
-                            None => Value::undefined(),
-                        })
-                        .collect();
-
-                    // Returns the starting byte offset of the match
-                    let start = mat.total().start;
-                    results.push(Value::from(start));
-                    // Push the whole string being examined
-                    results.push(Value::from(primitive_val.to_string()));
-
-                    let result = ctx.call(&replace_object, this, &results)?;
-
-                    result.to_string(ctx)?.to_string()
-                }
-                _ => "undefined".to_string(),
+    /// `String.prototype.replaceAll( pattern, replacement )`
+    ///
+    /// The `replaceAll()` method returns a new string with all matches of a `pattern` replaced by a `replacement`.
+    ///
+    /// The `pattern` can be a string or a global `RegExp`, and the `replacement` can be a string or a function
+    /// to be called for each match.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replaceall
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll
+    pub(crate) fn replace_all(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.require_object_coercible(ctx)?;
+        let search_value = args.get(0).cloned().unwrap_or_default();
+        let replace_value = args.get(1).cloned().unwrap_or_default();
+
+        if Self::is_regexp(&search_value, ctx)? {
+            let flags = search_value.get_field("flags", ctx)?;
+            let flags = if flags.is_undefined() {
+                // `RegExp.prototype.flags` isn't implemented yet, so use the flags of the matcher.
+                search_value
+                    .as_object()
+                    .and_then(|obj| obj.as_regexp().map(|regex| regex.flags().to_owned()))
+                    .unwrap_or_default()
+            } else {
+                flags.to_string(ctx)?.to_string()
+            };
+            if !flags.contains('g') {
+                return ctx.throw_type_error(
+                    "String.prototype.replaceAll called with a non-global RegExp argument",
+                );
             }
+        }
+
+        let symbol = ctx.well_known_symbols().replace_symbol();
+        let replacer_args = [object.clone(), replace_value.clone()];
+        if let Some(result) = Self::call_symbol_method(&search_value, symbol, &replacer_args, ctx)?
+        {
+            return Ok(result);
+        }
+
+        let string = object.to_string(ctx)?;
+        let search_string = search_value.to_string(ctx)?;
+        let replacement = Self::replacement_string(&replace_value, ctx)?;
+
+        // An empty search string matches before every code unit and at the end of the string.
+        let advance_by = max(1, search_string.len());
+        let mut positions = Vec::new();
+        let mut next_position = string.index_of(&search_string, 0);
+        while let Some(position) = next_position {
+            positions.push(position);
+            next_position = string.index_of(&search_string, position + advance_by);
+        }
+
+        let mut result = Vec::with_capacity(string.len());
+        let mut end_of_last_match = 0;
+        for position in positions {
+            let replaced = Self::replace_at(
+                &string,
+                &search_string,
+                position,
+                &replace_value,
+                replacement.as_ref(),
+                ctx,
+            )?;
+            result.extend(string.code_units().take(position).skip(end_of_last_match));
+            result.extend(replaced.code_units());
+            end_of_last_match = position + search_string.len();
+        }
+        result.extend(string.code_units().skip(end_of_last_match));
+
+        Ok(RcString::from(result).into())
+    }
+
+    /// Converts the replacement argument of `replace` and `replaceAll` to a string, unless it is a function.
+    fn replacement_string(replace_value: &Value, ctx: &mut Context) -> Result<Option<RcString>> {
+        if replace_value.is_function() {
+            Ok(None)
         } else {
-            "undefined".to_string()
-        };
+            replace_value.to_string(ctx).map(Some)
+        }
+    }
 
-        Ok(Value::from(primitive_val.replacen(
-            &primitive_val[mat.total()],
-            &replace_value,
-            1,
-        )))
+    /// Computes the replacement of the `matched` string found at the code unit index `position` of `string`,
+    /// either by expanding the `replacement` string or by calling the `replace_value` function.
+    fn replace_at(
+        string: &RcString,
+        matched: &RcString,
+        position: usize,
+        replace_value: &Value,
+        replacement: Option<&RcString>,
+        ctx: &mut Context,
+    ) -> Result<RcString> {
+        if let Some(replacement) = replacement {
+            return Ok(get_substitution(
+                matched,
+                string,
+                position,
+                &[],
+                replacement,
+            ));
+        }
+
+        let replacer_args = [
+            Value::from(matched.clone()),
+            Value::from(position),
+            Value::from(string.clone()),
+        ];
+        let replaced = ctx.call(replace_value, &Value::undefined(), &replacer_args)?;
+        replaced.to_string(ctx)
     }
 
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -708,24 +881,17 @@ impl String {
             .unwrap_or_else(Value::undefined)
             .to_string(ctx)?;
 
-        let length = string.chars().count();
+        let length = string.len();
         let start = args
             .get(1)
             .map(|position| position.to_integer(ctx))
             .transpose()?
             .map_or(0, |position| position.max(0.0).min(length as f64) as usize);
 
-        if search_string.is_empty() {
-            return Ok(start.min(length).into());
-        }
-
-        if start < length {
-            if let Some(position) = string.find(search_string.as_str()) {
-                return Ok(string[..position].chars().count().into());
-            }
+        match string.index_of(&search_string, start) {
+            Some(position) => Ok(position.into()),
+            None => Ok(Value::from(-1)),
         }
-
-        Ok(Value::from(-1))
     }
 
     /// `String.prototype.lastIndexOf( searchValue[, fromIndex] )`
@@ -751,7 +917,7 @@ impl String {
             .unwrap_or_else(Value::undefined)
             .to_string(ctx)?;
 
-        let length = string.chars().count();
+        let length = string.len();
         let start = args
             .get(1)
             .map(|position| position.to_integer(ctx))
@@ -763,8 +929,8 @@ impl String {
         }
 
         if start < length {
-            if let Some(position) = string.rfind(search_string.as_str()) {
-                return Ok(string[..position].chars().count().into());
+            if let Some(position) = string.last_index_of(&search_string, length) {
+                return Ok(position.into());
             }
         }
 
@@ -783,8 +949,103 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/match
     /// [regex]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
     pub(crate) fn r#match(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let re = RegExp::constructor(&Value::from(Object::default()), &[args[0].clone()], ctx)?;
-        RegExp::r#match(&re, this.to_string(ctx)?, ctx)
+        let object = this.require_object_coercible(ctx)?;
+        let regexp = args.get(0).cloned().unwrap_or_default();
+
+        let symbol = ctx.well_known_symbols().match_symbol();
+        if let Some(result) =
+            Self::call_symbol_method(&regexp, symbol.clone(), slice::from_ref(object), ctx)?
+        {
+            return Ok(result);
+        }
+
+        let string = object.to_string(ctx)?;
+        Self::invoke_regexp_method(string, regexp, Value::undefined(), symbol, ctx)
+    }
+
+    /// `String.prototype.search( regexp )`
+    ///
+    /// The `search()` method executes a search for a match between a [`regular expression`][regex] and the string,
+    /// returning the index of the first match or `-1` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.search
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/search
+    /// [regex]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
+    pub(crate) fn search(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.require_object_coercible(ctx)?;
+        let regexp = args.get(0).cloned().unwrap_or_default();
+
+        let symbol = ctx.well_known_symbols().search_symbol();
+        if let Some(result) =
+            Self::call_symbol_method(&regexp, symbol.clone(), slice::from_ref(object), ctx)?
+        {
+            return Ok(result);
+        }
+
+        let string = object.to_string(ctx)?;
+        Self::invoke_regexp_method(string, regexp, Value::undefined(), symbol, ctx)
+    }
+
+    /// `String.prototype.split( separator[, limit] )`
+    ///
+    /// The `split()` method divides a string into an ordered list of substrings by searching for a `separator`,
+    /// and returns them in an array.
+    ///
+    /// The `separator` can be a string or a `RegExp`. At most `limit` substrings are returned.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.split
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split
+    pub(crate) fn split(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.require_object_coercible(ctx)?;
+        let separator = args.get(0).cloned().unwrap_or_default();
+        let limit = args.get(1).cloned().unwrap_or_default();
+
+        let symbol = ctx.well_known_symbols().split_symbol();
+        let splitter_args = [object.clone(), limit.clone()];
+        if let Some(result) = Self::call_symbol_method(&separator, symbol, &splitter_args, ctx)? {
+            return Ok(result);
+        }
+
+        let string = object.to_string(ctx)?;
+        let limit = if limit.is_undefined() {
+            u32::MAX
+        } else {
+            limit.to_u32(ctx)?
+        } as usize;
+        let separator_string = separator.to_string(ctx)?;
+
+        let parts: Vec<Value> = if limit == 0 {
+            Vec::new()
+        } else if separator.is_undefined() {
+            vec![Value::from(string)]
+        } else if separator_string.is_empty() {
+            (0..string.len().min(limit))
+                .map(|index| Value::from(string.substring(index, index + 1)))
+                .collect()
+        } else {
+            let mut parts = Vec::new();
+            let mut position = 0;
+            while parts.len() < limit {
+                let end = string.index_of(&separator_string, position);
+                let part = string.substring(position, end.unwrap_or_else(|| string.len()));
+                parts.push(Value::from(part));
+                match end {
+                    Some(end) => position = end + separator_string.len(),
+                    None => break,
+                }
+            }
+            parts
+        };
+
+        Ok(Array::create_array_from_list(parts, ctx))
     }
 
     /// Abstract method `StringPad`.
@@ -803,21 +1064,23 @@ impl String {
             return Ok(Value::from(primitive));
         }
 
-        let filter = fill_string.as_deref().unwrap_or(" ");
+        let filler = fill_string.unwrap_or_else(|| RcString::from(" "));
+        if filler.is_empty() {
+            return Ok(Value::from(primitive));
+        }
 
         let fill_len = max_length.wrapping_sub(primitive_length);
-        let mut fill_str = StdString::new();
-
-        while fill_str.len() < fill_len as usize {
-            fill_str.push_str(filter);
-        }
-        // Cut to size max_length
-        let concat_fill_str: StdString = fill_str.chars().take(fill_len as usize).collect();
+        let fill: Vec<u16> = filler
+            .code_units()
+            .cycle()
+            .take(fill_len as usize)
+            .collect();
+        let fill = RcString::from(fill);
 
         if at_start {
-            Ok(Value::from(format!("{}{}", concat_fill_str, &primitive)))
+            Ok(Value::from(fill.concat(&primitive)))
         } else {
-            Ok(Value::from(format!("{}{}", primitive, &concat_fill_str)))
+            Ok(Value::from(primitive.concat(&fill)))
         }
     }
 
@@ -877,7 +1140,7 @@ impl String {
 
     /// Helper function to check if a `char` is trimmable.
     #[inline]
-    fn is_trimmable_whitespace(c: char) -> bool {
+    pub(crate) fn is_trimmable_whitespace(c: char) -> bool {
         // The rust implementation of `trim` does not regard the same characters whitespace as ecma standard does
         //
         // Rust uses \p{White_Space} by default, which also includes:
@@ -896,6 +1159,28 @@ impl String {
         )
     }
 
+    /// Removes the whitespace code units from the start and/or the end of a string.
+    fn trim_whitespace(string: &RcString, start: bool, end: bool) -> RcString {
+        let is_whitespace = |code_unit: u16| {
+            std::char::from_u32(u32::from(code_unit)).map_or(false, Self::is_trimmable_whitespace)
+        };
+        let mut from = 0;
+        let mut to = string.len();
+        if start {
+            from = string
+                .code_units()
+                .position(|code_unit| !is_whitespace(code_unit))
+                .unwrap_or(to);
+        }
+        if end {
+            to = string
+                .code_units()
+                .rposition(|code_unit| !is_whitespace(code_unit))
+                .map_or(from, |index| index + 1);
+        }
+        string.substring(from, to)
+    }
+
     /// String.prototype.trim()
     ///
     /// The `trim()` method removes whitespace from both ends of a string.
@@ -911,9 +1196,7 @@ impl String {
     pub(crate) fn trim(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
         let this = this.require_object_coercible(ctx)?;
         let string = this.to_string(ctx)?;
-        Ok(Value::from(
-            string.trim_matches(Self::is_trimmable_whitespace),
-        ))
+        Ok(Value::from(Self::trim_whitespace(&string, true, true)))
     }
 
     /// `String.prototype.trimStart()`
@@ -930,9 +1213,7 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimStart
     pub(crate) fn trim_start(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
         let string = this.to_string(ctx)?;
-        Ok(Value::from(
-            string.trim_start_matches(Self::is_trimmable_whitespace),
-        ))
+        Ok(Value::from(Self::trim_whitespace(&string, true, false)))
     }
 
     /// String.prototype.trimEnd()
@@ -950,9 +1231,7 @@ impl String {
     pub(crate) fn trim_end(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
         let this = this.require_object_coercible(ctx)?;
         let string = this.to_string(ctx)?;
-        Ok(Value::from(
-            string.trim_end_matches(Self::is_trimmable_whitespace),
-        ))
+        Ok(Value::from(Self::trim_whitespace(&string, false, true)))
     }
 
     /// `String.prototype.toLowerCase()`
@@ -972,7 +1251,7 @@ impl String {
         let this_str = this.to_string(ctx)?;
         // The Rust String is mapped to uppercase using the builtin .to_lowercase().
         // There might be corner cases where it does not behave exactly like Javascript expects
-        Ok(Value::from(this_str.to_lowercase()))
+        Ok(Value::from(convert_str(&this_str, str::to_lowercase)))
     }
 
     /// `String.prototype.toUpperCase()`
@@ -994,7 +1273,69 @@ impl String {
         let this_str = this.to_string(ctx)?;
         // The Rust String is mapped to uppercase using the builtin .to_uppercase().
         // There might be corner cases where it does not behave exactly like Javascript expects
-        Ok(Value::from(this_str.to_uppercase()))
+        Ok(Value::from(convert_str(&this_str, str::to_uppercase)))
+    }
+
+    /// `String.prototype.normalize( [form] )`
+    ///
+    /// The `normalize()` method returns the Unicode Normalization Form of the string.
+    ///
+    /// The `form` is one of `"NFC"`, `"NFD"`, `"NFKC"` or `"NFKD"`, and defaults to `"NFC"`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.normalize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/normalize
+    pub(crate) fn normalize(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.require_object_coercible(ctx)?;
+        let string = object.to_string(ctx)?;
+        let form = match args.get(0) {
+            Some(form) if !form.is_undefined() => form.to_string(ctx)?,
+            _ => RcString::from("NFC"),
+        };
+
+        let normalize: fn(&str) -> StdString = match form.to_std_string_lossy().as_str() {
+            "NFC" => |string| string.nfc().collect(),
+            "NFD" => |string| string.nfd().collect(),
+            "NFKC" => |string| string.nfkc().collect(),
+            "NFKD" => |string| string.nfkd().collect(),
+            _ => {
+                return ctx.throw_range_error(format!(
+                    "The normalization form should be one of NFC, NFD, NFKC, NFKD, got {}",
+                    form
+                ))
+            }
+        };
+
+        Ok(convert_str(&string, normalize).into())
+    }
+
+    /// `String.prototype.localeCompare( that )`
+    ///
+    /// The `localeCompare()` method returns a number indicating whether the string comes before,
+    /// after or is the same as the given string in sort order.
+    ///
+    /// Boa doesn't have locale data, so the strings are compared by code points after being
+    /// normalized, which makes canonically equivalent strings compare as equal. Unpaired
+    /// surrogates are compared as `U+FFFD`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.localecompare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+    pub(crate) fn locale_compare(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.require_object_coercible(ctx)?;
+        let string = object.to_string(ctx)?;
+        let that = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+
+        let string = string.to_std_string_lossy();
+        let that = that.to_std_string_lossy();
+        let ordering = string.nfc().cmp(that.nfc());
+        Ok(Value::from(ordering as i32))
     }
 
     /// `String.prototype.substring( indexStart[, indexEnd] )`
@@ -1019,7 +1360,7 @@ impl String {
                 .expect("failed to get argument for String method")
                 .to_integer(ctx)? as i32
         };
-        let length = primitive_val.encode_utf16().count() as i32;
+        let length = primitive_val.len() as i32;
         // If less than 2 args specified, end is the length of the this object converted to a String
         let end = if args.len() < 2 {
             length
@@ -1037,14 +1378,7 @@ impl String {
         let to = max(final_start, final_end) as usize;
         // Extract the part of the string contained between the start index and the end index
         // where start is guaranteed to be smaller or equals to end
-        let extracted_string: std::result::Result<StdString, _> = decode_utf16(
-            primitive_val
-                .encode_utf16()
-                .skip(from)
-                .take(to.wrapping_sub(from)),
-        )
-        .collect();
-        Ok(Value::from(extracted_string.expect("Invalid string")))
+        Ok(Value::from(primitive_val.substring(from, to)))
     }
 
     /// `String.prototype.substr( start[, length] )`
@@ -1070,7 +1404,7 @@ impl String {
                 .expect("failed to get argument for String method")
                 .to_integer(ctx)? as i32
         };
-        let length = primitive_val.chars().count() as i32;
+        let length = primitive_val.len() as i32;
         // If less than 2 args specified, end is +infinity, the maximum number value.
         // Using i32::max_value() should be safe because the final length used is at most
         // the number of code units from start to the end of the string,
@@ -1094,14 +1428,90 @@ impl String {
         if result_length <= 0 {
             Ok(Value::from(""))
         } else {
-            let extracted_string: StdString = primitive_val
-                .chars()
-                .skip(start as usize)
-                .take(result_length as usize)
-                .collect();
+            let start = start as usize;
+            Ok(Value::from(
+                primitive_val.substring(start, start + result_length as usize),
+            ))
+        }
+    }
 
-            Ok(Value::from(extracted_string))
+    /// `String.raw( template, ...substitutions )`
+    ///
+    /// The `String.raw()` static method is a tag function of template literals. It returns the
+    /// raw strings of the template, interleaved with the given substitutions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.raw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw
+    pub(crate) fn raw(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let cooked = args.get(0).cloned().unwrap_or_default().to_object(ctx)?;
+        let raw = cooked
+            .get(&"raw".into(), &cooked.clone().into(), ctx)?
+            .to_object(ctx)?;
+        let literal_segments = raw
+            .get(&"length".into(), &raw.clone().into(), ctx)?
+            .to_length(ctx)?;
+
+        let mut result = Vec::new();
+        for i in 0..literal_segments {
+            let segment = raw.get(&i.into(), &raw.clone().into(), ctx)?;
+            result.extend(segment.to_string(ctx)?.code_units());
+            if i + 1 == literal_segments {
+                break;
+            }
+            if let Some(substitution) = args.get(i + 1) {
+                result.extend(substitution.to_string(ctx)?.code_units());
+            }
+        }
+        Ok(RcString::from(result).into())
+    }
+
+    /// `String.fromCharCode( num1[, ...numN] )`
+    ///
+    /// The `String.fromCharCode()` static method returns a string created from the specified sequence of UTF-16 code units.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.fromcharcode
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode
+    pub(crate) fn from_char_code(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let mut code_units = Vec::with_capacity(args.len());
+        for arg in args {
+            code_units.push(arg.to_u32(ctx)? as u16);
+        }
+
+        Ok(RcString::from(code_units).into())
+    }
+
+    /// `String.fromCodePoint( num1[, ...numN] )`
+    ///
+    /// The `String.fromCodePoint()` static method returns a string created from the specified sequence of code points.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.fromcodepoint
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint
+    pub(crate) fn from_code_point(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let mut result = Vec::with_capacity(args.len());
+        for arg in args {
+            let next_code_point = arg.to_number(ctx)?;
+            if next_code_point.fract() != 0.0 || !(0.0..=1_114_111.0).contains(&next_code_point) {
+                return ctx.throw_range_error(format!("Invalid code point {}", arg.display()));
+            }
+            match std::char::from_u32(next_code_point as u32) {
+                Some(c) => result.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+                // Surrogate code points are their own code unit.
+                None => result.push(next_code_point as u16),
+            }
         }
+        Ok(RcString::from(result).into())
     }
 
     /// String.prototype.valueOf()
@@ -1158,7 +1568,7 @@ impl String {
             ),
         }?;
 
-        RegExp::match_all(&re, this.to_string(ctx)?.to_string())
+        RegExp::match_all(&re, this.to_string(ctx)?, ctx)
     }
 
     pub(crate) fn iterator(this: &Value, _args: &[Value], ctx: &mut Context) -> Result<Value> {
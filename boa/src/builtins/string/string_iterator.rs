@@ -1,4 +1,3 @@
-use crate::builtins::string::code_point_at;
 use crate::{
     builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
     object::ObjectData,
@@ -39,21 +38,17 @@ impl StringIterator {
                     return Ok(create_iter_result_object(ctx, Value::undefined(), true));
                 }
                 let native_string = string_iterator.string.to_string(ctx)?;
-                let len = native_string.encode_utf16().count() as i32;
-                let position = string_iterator.next_index;
-                if position >= len {
-                    string_iterator.string = Value::undefined();
-                    return Ok(create_iter_result_object(ctx, Value::undefined(), true));
-                }
-                let (_, code_unit_count, _) =
-                    code_point_at(native_string, position).expect("Invalid code point position");
+                let position = string_iterator.next_index as usize;
+                let code_unit_count = match native_string.code_point_at(position) {
+                    Some((_, code_unit_count, _)) => code_unit_count,
+                    None => {
+                        string_iterator.string = Value::undefined();
+                        return Ok(create_iter_result_object(ctx, Value::undefined(), true));
+                    }
+                };
                 string_iterator.next_index += code_unit_count as i32;
-                let result_string = crate::builtins::string::String::substring(
-                    &string_iterator.string,
-                    &[position.into(), string_iterator.next_index.into()],
-                    ctx,
-                )?;
-                Ok(create_iter_result_object(ctx, result_string, false))
+                let result_string = native_string.substring(position, position + code_unit_count);
+                Ok(create_iter_result_object(ctx, result_string.into(), false))
             } else {
                 ctx.throw_type_error("`this` is not an ArrayIterator")
             }
@@ -1,8 +1,6 @@
 use crate::{forward, forward_val, Context};
 
-///TODO: re-enable when getProperty() is finished;
 #[test]
-#[ignore]
 fn length() {
     //TEST262: https://github.com/tc39/test262/blob/master/test/built-ins/String/length.js
     let mut engine = Context::new();
@@ -16,10 +14,6 @@ fn length() {
     let a = forward(&mut engine, "a.length");
     assert_eq!(a, "1");
     let b = forward(&mut engine, "b.length");
-    // TODO: fix this
-    // unicode surrogate pair length should be 1
-    // utf16/usc2 length should be 2
-    // utf8 length should be 4
     assert_eq!(b, "2");
     let c = forward(&mut engine, "c.length");
     assert_eq!(c, "3");
@@ -864,3 +858,281 @@ fn unicode_iter() {
     assert_eq!(forward(&mut engine, "next.value"), "undefined");
     assert_eq!(forward(&mut engine, "next.done"), "true");
 }
+
+#[test]
+fn raw() {
+    let mut context = Context::new();
+    let init = r#"
+        var a = String.raw`a\n${1 + 1}b\u{41}`;
+        var b = String.raw({ raw: ["x", "y", "z"] }, 1, 2, 3);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "a"), "\"a\\n2b\\u{41}\"");
+    assert_eq!(forward(&mut context, "b"), "\"x1y2z\"");
+}
+
+#[test]
+fn split() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "'a,b,,c'.split(',').length"), "4");
+    assert_eq!(forward(&mut engine, "'a,b,,c'.split(',')[2]"), "\"\"");
+    assert_eq!(
+        forward(&mut engine, "'a,b,c'.split(',', 2).join()"),
+        "\"a,b\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.split('').join('-')"),
+        "\"a-b-c\""
+    );
+    assert_eq!(forward(&mut engine, "'abc'.split()[0]"), "\"abc\"");
+    assert_eq!(forward(&mut engine, "''.split('').length"), "0");
+    assert_eq!(forward(&mut engine, "''.split(',').length"), "1");
+    assert_eq!(forward(&mut engine, "'abc'.split('', 0).length"), "0");
+    assert_eq!(
+        forward(&mut engine, "Array.isArray('a b'.split(' '))"),
+        "true"
+    );
+}
+
+#[test]
+fn split_with_regex() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "'a1b22c'.split(/\\d+/).join()"),
+        "\"a,b,c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'a1b2c'.split(/(\\d)/).join()"),
+        "\"a,1,b,2,c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.split(/(?:)/).join()"),
+        "\"a,b,c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'a-b-c'.split(/-/, 2).join()"),
+        "\"a,b\""
+    );
+    assert_eq!(forward(&mut engine, "''.split(/x/).length"), "1");
+    assert_eq!(forward(&mut engine, "''.split(/(?:)/).length"), "0");
+}
+
+#[test]
+fn search() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "'abc'.search(/c/)"), "2");
+    assert_eq!(forward(&mut engine, "'abc'.search('b')"), "1");
+    assert_eq!(forward(&mut engine, "'abc'.search(/z/)"), "-1");
+    assert_eq!(forward(&mut engine, "'a.c'.search('.')"), "0");
+    assert_eq!(forward(&mut engine, "'undefined'.search()"), "0");
+}
+
+#[test]
+fn replace_string_pattern() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "'a.b.c'.replace('.', '!')"),
+        "\"a!b.c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.replace('b', '[$&$`$\\'$$]')"),
+        "\"a[bac$]c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.replace('b', (m, p, s) => m + p + s)"),
+        "\"ab1abcc\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'aaa'.replace(/a/g, '$&$&')"),
+        "\"aaaaaa\""
+    );
+}
+
+#[test]
+fn replace_all() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "'aXbXc'.replaceAll('X', '-')"),
+        "\"a-b-c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.replaceAll('', '_')"),
+        "\"_a_b_c_\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'aaa'.replaceAll('aa', 'b')"),
+        "\"ba\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'aaa'.replaceAll(/a/g, (m, p) => p)"),
+        "\"012\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { 'a'.replaceAll(/a/, 'b') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn symbol_delegation() {
+    let mut engine = Context::new();
+    let init = r#"
+        var matcher = {};
+        matcher[Symbol.match] = function (s) { return "match:" + s; };
+        matcher[Symbol.replace] = function (s, r) { return "replace:" + s + r; };
+        matcher[Symbol.search] = function (s) { return 42; };
+        matcher[Symbol.split] = function (s, l) { return "split:" + s + l; };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "'str'.match(matcher)"),
+        "\"match:str\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'str'.replace(matcher, '!')"),
+        "\"replace:str!\""
+    );
+    assert_eq!(forward(&mut engine, "'str'.search(matcher)"), "42");
+    assert_eq!(
+        forward(&mut engine, "'str'.split(matcher, 3)"),
+        "\"split:str3\""
+    );
+
+    forward(
+        &mut engine,
+        "RegExp.prototype[Symbol.split] = function () { return 'patched'; }",
+    );
+    assert_eq!(forward(&mut engine, "'a-b'.split(/-/)"), "\"patched\"");
+}
+
+#[test]
+fn normalize() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "'A\\u030A'.normalize().length"), "1");
+    assert_eq!(
+        forward(&mut engine, "'\\u00C5'.normalize('NFD').length"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut engine, "'\\uFB01'.normalize('NFKC')"),
+        "\"fi\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { 'a'.normalize('x') } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn code_point_at_and_at() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "'😀a'.codePointAt(0)"), "128512");
+    assert_eq!(forward(&mut engine, "'😀a'.codePointAt(1)"), "56832");
+    assert_eq!(forward(&mut engine, "'😀a'.codePointAt(2)"), "97");
+    assert_eq!(forward(&mut engine, "'abc'.codePointAt(3)"), "undefined");
+    assert_eq!(forward(&mut engine, "'abc'.at(0)"), "\"a\"");
+    assert_eq!(forward(&mut engine, "'abc'.at(-1)"), "\"c\"");
+    assert_eq!(forward(&mut engine, "'abc'.at(3)"), "undefined");
+    assert_eq!(forward(&mut engine, "'abc'.at(-4)"), "undefined");
+}
+
+#[test]
+fn locale_compare() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "'a'.localeCompare('b')"), "-1");
+    assert_eq!(forward(&mut engine, "'b'.localeCompare('a')"), "1");
+    assert_eq!(forward(&mut engine, "'a'.localeCompare('a')"), "0");
+    assert_eq!(
+        forward(&mut engine, "'\\u00C5'.localeCompare('A\\u030A')"),
+        "0"
+    );
+}
+
+#[test]
+fn from_char_code_and_from_code_point() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "String.fromCharCode(72, 105, 65601)"),
+        "\"HiA\""
+    );
+    assert_eq!(
+        forward(&mut engine, "String.fromCharCode(0xD83D, 0xDE00)"),
+        "\"😀\""
+    );
+    assert_eq!(
+        forward(&mut engine, "String.fromCodePoint(128512, 65)"),
+        "\"😀A\""
+    );
+    assert_eq!(forward(&mut engine, "String.fromCodePoint()"), "\"\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { String.fromCodePoint(1.5) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { String.fromCodePoint(0x110000) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn code_unit_semantics() {
+    let mut engine = Context::new();
+    let init = r#"
+        var emoji = '😀';
+        var mixed = 'é😀a';
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "emoji.length"), "2");
+    assert_eq!(forward(&mut engine, "emoji.charCodeAt(0)"), "55357");
+    assert_eq!(forward(&mut engine, "emoji.charCodeAt(1)"), "56832");
+    assert_eq!(forward(&mut engine, "emoji[1].charCodeAt(0)"), "56832");
+    assert_eq!(forward(&mut engine, "mixed.indexOf('a')"), "3");
+    assert_eq!(forward(&mut engine, "mixed.slice(1, 3) === emoji"), "true");
+    assert_eq!(forward(&mut engine, "mixed.substring(2).length"), "2");
+    assert_eq!(
+        forward(&mut engine, "mixed.substr(-2, 1).charCodeAt(0)"),
+        "56832"
+    );
+    assert_eq!(forward(&mut engine, "emoji.split('').length"), "2");
+    assert_eq!(forward(&mut engine, "[...mixed].length"), "3");
+    assert_eq!(forward(&mut engine, "'a😀'.padEnd(5, emoji).length"), "5");
+    assert_eq!(forward(&mut engine, "'😀' < '\\uFFFF'"), "true");
+}
+
+#[test]
+fn lone_surrogates() {
+    let mut engine = Context::new();
+    let init = r#"
+        var high = '\uD83D';
+        var low = String.fromCharCode(0xDE00);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "high.length"), "1");
+    assert_eq!(forward(&mut engine, "high.charCodeAt(0)"), "55357");
+    assert_eq!(forward(&mut engine, "high + low === '😀'"), "true");
+    assert_eq!(forward(&mut engine, "'\\uD83D\\uDE00' === '😀'"), "true");
+    assert_eq!(forward(&mut engine, "'😀'.slice(0, 1) === high"), "true");
+    assert_eq!(forward(&mut engine, "low.codePointAt(0)"), "56832");
+    assert_eq!(
+        forward(&mut engine, "String.fromCodePoint(0xD800).charCodeAt(0)"),
+        "55296"
+    );
+    assert_eq!(
+        forward(&mut engine, "(high + 'A').toLowerCase().length"),
+        "2"
+    );
+}
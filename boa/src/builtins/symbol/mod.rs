@@ -295,8 +295,8 @@ impl Symbol {
     pub(crate) const LENGTH: usize = 0;
 
     /// Returns the `Symbol`s description.
-    pub fn description(&self) -> Option<&str> {
-        self.description.as_deref()
+    pub fn description(&self) -> Option<&RcString> {
+        self.description.as_ref()
     }
 
     /// Returns the `Symbol`s hash.
@@ -352,7 +352,10 @@ impl Symbol {
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_string(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
         let symbol = Self::this_symbol_value(this, ctx)?;
-        let description = symbol.description().unwrap_or("");
-        Ok(Value::from(format!("Symbol({})", description)))
+        let description = symbol.description().cloned().unwrap_or_default();
+        Ok(RcString::from("Symbol(")
+            .concat(&description)
+            .concat(&RcString::from(")"))
+            .into())
     }
 }
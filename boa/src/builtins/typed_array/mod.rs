@@ -0,0 +1,1750 @@
+//! This module implements the `%TypedArray%` intrinsic object and the typed array constructors,
+//! like `Uint8Array` or `Float64Array`.
+//!
+//! A typed array is a view on an `ArrayBuffer` whose elements are all of one numeric type. Its
+//! integer indexed properties are not stored on the object, they are read from and written to
+//! the buffer.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-typedarray-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray
+
+use crate::{
+    builtins::{
+        array::array_iterator::{ArrayIterationKind, ArrayIterator},
+        array_buffer::ArrayBuffer,
+        function::NativeFunction,
+        iterable::get_iterator,
+        number::f64_to_uint32,
+        Array, BigInt, BuiltIn, Number,
+    },
+    gc::{empty_trace, Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    value::{same_value_zero, PreferredType, RcString, Value},
+    BoaProfiler, Context, Result,
+};
+use std::cmp::Ordering;
+
+#[cfg(test)]
+mod tests;
+
+/// The element types of typed arrays.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-the-typedarray-constructors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+    BigInt64,
+    BigUint64,
+}
+
+unsafe impl Trace for TypedArrayKind {
+    empty_trace!();
+}
+
+impl TypedArrayKind {
+    /// The name of the constructor of typed arrays of this type.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Int8 => "Int8Array",
+            Self::Uint8 => "Uint8Array",
+            Self::Uint8Clamped => "Uint8ClampedArray",
+            Self::Int16 => "Int16Array",
+            Self::Uint16 => "Uint16Array",
+            Self::Int32 => "Int32Array",
+            Self::Uint32 => "Uint32Array",
+            Self::Float32 => "Float32Array",
+            Self::Float64 => "Float64Array",
+            Self::BigInt64 => "BigInt64Array",
+            Self::BigUint64 => "BigUint64Array",
+        }
+    }
+
+    /// The size of an element in bytes.
+    #[inline]
+    pub fn element_size(self) -> usize {
+        match self {
+            Self::Int8 | Self::Uint8 | Self::Uint8Clamped => 1,
+            Self::Int16 | Self::Uint16 => 2,
+            Self::Int32 | Self::Uint32 | Self::Float32 => 4,
+            Self::Float64 | Self::BigInt64 | Self::BigUint64 => 8,
+        }
+    }
+
+    /// Returns `true` if the elements are `BigInt`s rather than numbers.
+    #[inline]
+    pub fn is_bigint(self) -> bool {
+        matches!(self, Self::BigInt64 | Self::BigUint64)
+    }
+
+    /// Converts `value` to an element of this type, as the little endian bits of its raw bytes.
+    ///
+    /// This is `ToBigInt` or `ToNumber`, followed by `NumericToRawBytes`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
+    pub(crate) fn to_raw(self, value: &Value, ctx: &mut Context) -> Result<u64> {
+        if self.is_bigint() {
+            // Unlike `BigInt( value )`, `ToBigInt` doesn't accept numbers.
+            let primitive = value.to_primitive(ctx, PreferredType::Number)?;
+            if primitive.is_number() {
+                return Err(ctx.construct_type_error("cannot convert a number to a BigInt"));
+            }
+            return Ok(primitive.to_bigint(ctx)?.to_u64_wrapping());
+        }
+
+        let number = value.to_number(ctx)?;
+        Ok(match self {
+            Self::Int8 | Self::Uint8 | Self::Int16 | Self::Uint16 | Self::Int32 | Self::Uint32 => {
+                // Truncating the 32 bit conversion gives the conversions of the smaller types.
+                u64::from(f64_to_uint32(number))
+            }
+            Self::Uint8Clamped => u64::from(Self::to_uint8_clamp(number)),
+            Self::Float32 => u64::from((number as f32).to_bits()),
+            Self::Float64 => number.to_bits(),
+            Self::BigInt64 | Self::BigUint64 => unreachable!(),
+        })
+    }
+
+    /// `ToUint8Clamp( argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-touint8clamp
+    fn to_uint8_clamp(number: f64) -> u8 {
+        if number.is_nan() || number <= 0.0 {
+            return 0;
+        }
+        if number >= 255.0 {
+            return 255;
+        }
+        let floor = number.floor();
+        let rounded = match (number - floor).partial_cmp(&0.5) {
+            Some(Ordering::Greater) => floor + 1.0,
+            Some(Ordering::Less) => floor,
+            // Ties round to even.
+            _ if floor % 2.0 == 0.0 => floor,
+            _ => floor + 1.0,
+        };
+        rounded as u8
+    }
+
+    /// `RawBytesToNumeric( type, rawBytes, isLittleEndian )`
+    ///
+    /// Converts the little endian bits of an element of this type to its value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rawbytestonumeric
+    pub(crate) fn raw_bytes_to_numeric(self, raw: u64) -> Value {
+        match self {
+            Self::Int8 => Value::from(i32::from(raw as u8 as i8)),
+            Self::Uint8 | Self::Uint8Clamped => Value::from(i32::from(raw as u8)),
+            Self::Int16 => Value::from(i32::from(raw as u16 as i16)),
+            Self::Uint16 => Value::from(i32::from(raw as u16)),
+            Self::Int32 => Value::from(raw as u32 as i32),
+            Self::Uint32 => Value::from(raw as u32),
+            Self::Float32 => Value::from(f64::from(f32::from_bits(raw as u32))),
+            Self::Float64 => Value::from(f64::from_bits(raw)),
+            Self::BigInt64 => Value::from(BigInt::from(raw as i64)),
+            Self::BigUint64 => Value::from(BigInt::from(u128::from(raw))),
+        }
+    }
+}
+
+/// `CanonicalNumericIndexString( argument )`
+///
+/// Returns the number a property key stands for, if it is the canonical string of a number.
+/// Such keys are never looked up on the prototype chain of typed arrays, even when they are not
+/// valid indices, like `"-1"` or `"1.5"`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalnumericindexstring
+pub(crate) fn canonical_numeric_index(key: &PropertyKey) -> Option<f64> {
+    match key {
+        PropertyKey::Index(index) => Some(f64::from(*index)),
+        PropertyKey::String(ref string) => {
+            let string = string.to_std_string().ok()?;
+            if string == "-0" {
+                return Some(-0.0);
+            }
+            let number = string.parse::<f64>().ok()?;
+            if Number::to_native_string(number) == string {
+                Some(number)
+            } else {
+                None
+            }
+        }
+        PropertyKey::Symbol(_) => None,
+    }
+}
+
+/// The data of a typed array object, a view on a range of an `ArrayBuffer`.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct TypedArray {
+    kind: TypedArrayKind,
+    buffer: GcObject,
+    byte_offset: usize,
+    length: usize,
+}
+
+impl TypedArray {
+    /// The element type of the typed array.
+    #[inline]
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    /// The `ArrayBuffer` object the typed array is a view on.
+    #[inline]
+    pub fn buffer(&self) -> &GcObject {
+        &self.buffer
+    }
+
+    /// Returns `true` if the buffer of the typed array has been detached.
+    #[inline]
+    pub fn is_detached(&self) -> bool {
+        self.buffer
+            .borrow()
+            .as_array_buffer()
+            .map_or(true, ArrayBuffer::is_detached)
+    }
+
+    /// The amount of elements, which is `0` once the buffer has been detached.
+    #[inline]
+    pub fn length(&self) -> usize {
+        if self.is_detached() {
+            0
+        } else {
+            self.length
+        }
+    }
+
+    /// The offset of the view in its buffer, which is `0` once the buffer has been detached.
+    #[inline]
+    pub fn byte_offset(&self) -> usize {
+        if self.is_detached() {
+            0
+        } else {
+            self.byte_offset
+        }
+    }
+
+    /// The length of the view in bytes, which is `0` once the buffer has been detached.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.length() * self.kind.element_size()
+    }
+
+    /// `IsValidIntegerIndex( O, index )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isvalidintegerindex
+    fn is_valid_integer_index(&self, index: f64) -> bool {
+        index.fract() == 0.0
+            && !(index == 0.0 && index.is_sign_negative())
+            && index >= 0.0
+            && index < self.length() as f64
+    }
+
+    /// `IntegerIndexedElementGet( O, index )`
+    ///
+    /// Returns `None` if the index isn't valid.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementget
+    pub(crate) fn get_element(&self, index: f64) -> Option<Value> {
+        if !self.is_valid_integer_index(index) {
+            return None;
+        }
+        let byte_index = self.byte_offset + index as usize * self.kind.element_size();
+        let buffer = self.buffer.borrow();
+        let buffer = buffer.as_array_buffer()?;
+        Some(buffer.get_value_from_buffer(byte_index, self.kind, true))
+    }
+
+    /// Writes an element that has already been converted by [`TypedArrayKind::to_raw`].
+    ///
+    /// Returns `false` if the index isn't valid, which can happen if converting the value
+    /// detached the buffer.
+    pub(crate) fn set_raw_element(&self, index: f64, raw: u64) -> bool {
+        if !self.is_valid_integer_index(index) {
+            return false;
+        }
+        let byte_index = self.byte_offset + index as usize * self.kind.element_size();
+        let mut buffer = self.buffer.borrow_mut();
+        match buffer.as_array_buffer_mut() {
+            Some(buffer) => {
+                buffer.set_value_in_buffer(byte_index, self.kind, raw, true);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// `IntegerIndexedElementSet( O, index, value )`
+    ///
+    /// The value is converted even when the index isn't valid.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementset
+    pub(crate) fn set_element(&self, index: f64, value: &Value, ctx: &mut Context) -> Result<()> {
+        let raw = self.kind.to_raw(value, ctx)?;
+        self.set_raw_element(index, raw);
+        Ok(())
+    }
+
+    /// The `[[GetOwnProperty]]` of integer indexed exotic objects, for a numeric key.
+    pub(crate) fn get_own_property(&self, index: f64) -> Option<PropertyDescriptor> {
+        self.get_element(index)
+            .map(|value| DataDescriptor::new(value, Attribute::all()).into())
+    }
+
+    /// The `[[HasProperty]]` of integer indexed exotic objects, for a numeric key.
+    pub(crate) fn has_property(&self, index: f64) -> bool {
+        self.is_valid_integer_index(index)
+    }
+
+    /// The `[[DefineOwnProperty]]` of integer indexed exotic objects, for a numeric key.
+    ///
+    /// Elements are always writable, enumerable and configurable data properties, so only the
+    /// value of the descriptor is used, and accessor descriptors are rejected.
+    pub(crate) fn define_own_property(
+        &self,
+        index: f64,
+        desc: PropertyDescriptor,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        if !self.is_valid_integer_index(index) {
+            return Ok(false);
+        }
+        match desc {
+            PropertyDescriptor::Accessor(_) => Ok(false),
+            PropertyDescriptor::Data(ref data) => {
+                self.set_element(index, &data.value(), ctx)?;
+                Ok(true)
+            }
+        }
+    }
+
+    /// The `[[Delete]]` of integer indexed exotic objects, for a numeric key.
+    pub(crate) fn delete(&self, index: f64) -> bool {
+        !self.is_valid_integer_index(index)
+    }
+
+    /// The integer indices of the typed array, which come first in its own property keys.
+    pub(crate) fn own_indices(&self) -> impl Iterator<Item = PropertyKey> {
+        (0..self.length() as u32).map(PropertyKey::Index)
+    }
+
+    /// `AllocateTypedArray( constructorName, newTarget, defaultProto, length )`
+    ///
+    /// Creates the data of a typed array of `length` elements, on a new buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatetypedarray
+    fn allocate(kind: TypedArrayKind, length: usize, ctx: &mut Context) -> Result<Self> {
+        let byte_length = match length.checked_mul(kind.element_size()) {
+            Some(byte_length) => byte_length,
+            None => return Err(ctx.construct_range_error("Invalid typed array length")),
+        };
+        Ok(Self {
+            kind,
+            buffer: ArrayBuffer::allocate(byte_length, ctx)?,
+            byte_offset: 0,
+            length,
+        })
+    }
+}
+
+/// JavaScript `%TypedArray%` intrinsic object implementation, the parent of the typed array
+/// constructors.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TypedArrayObject;
+
+impl TypedArrayObject {
+    const NAME: &'static str = "TypedArray";
+
+    /// Builds the `%TypedArray%` intrinsic object.
+    ///
+    /// It has no global binding, so unlike the other builtins it is not initialized through
+    /// [`BuiltIn`]. It must be initialized after `Array`, whose `toString` it shares.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let symbol_iterator = context.well_known_symbols().iterator_symbol();
+        let values_function = FunctionBuilder::new(context, Self::values)
+            .name("values")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let array_prototype: Value = context.standard_objects().array_object().prototype().into();
+        let to_string_function = array_prototype
+            .get_field("toString", context)
+            .expect("Array.prototype.toString is a data property");
+
+        let typed_array_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().typed_array_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .property(
+            "values",
+            values_function.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            symbol_iterator,
+            values_function,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            "toString",
+            to_string_function,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::copy_within, "copyWithin", 2)
+        .method(Self::entries, "entries", 0)
+        .method(Self::every, "every", 1)
+        .method(Self::fill, "fill", 1)
+        .method(Self::filter, "filter", 1)
+        .method(Self::find, "find", 1)
+        .method(Self::find_index, "findIndex", 1)
+        .method(Self::for_each, "forEach", 1)
+        .method(Self::includes, "includes", 1)
+        .method(Self::index_of, "indexOf", 1)
+        .method(Self::join, "join", 1)
+        .method(Self::keys, "keys", 0)
+        .method(Self::last_index_of, "lastIndexOf", 1)
+        .method(Self::map, "map", 1)
+        .method(Self::reduce, "reduce", 1)
+        .method(Self::reduce_right, "reduceRight", 1)
+        .method(Self::reverse, "reverse", 0)
+        .method(Self::set, "set", 1)
+        .method(Self::slice, "slice", 2)
+        .method(Self::some, "some", 1)
+        .method(Self::sort, "sort", 1)
+        .method(Self::subarray, "subarray", 2)
+        .method(Self::to_locale_string, "toLocaleString", 0)
+        .static_method(Self::from, "from", 1)
+        .static_method(Self::of, "of", 0)
+        .build();
+
+        let prototype = context.standard_objects().typed_array_object().prototype();
+        let getters: [(&str, NativeFunction, &str); 4] = [
+            ("buffer", Self::get_buffer, "get buffer"),
+            ("byteLength", Self::get_byte_length, "get byteLength"),
+            ("byteOffset", Self::get_byte_offset, "get byteOffset"),
+            ("length", Self::get_length, "get length"),
+        ];
+        for (key, getter, name) in getters.iter() {
+            let getter = FunctionBuilder::new(context, *getter)
+                .name(*name)
+                .callable(true)
+                .constructable(false)
+                .build();
+            prototype.borrow_mut().insert(
+                *key,
+                AccessorDescriptor::new(Some(getter), None, Attribute::CONFIGURABLE),
+            );
+        }
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let get_to_string_tag = FunctionBuilder::new(context, Self::get_to_string_tag)
+            .name("get [Symbol.toStringTag]")
+            .callable(true)
+            .constructable(false)
+            .build();
+        prototype.borrow_mut().insert(
+            to_string_tag,
+            AccessorDescriptor::new(Some(get_to_string_tag), None, Attribute::CONFIGURABLE),
+        );
+
+        let species = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .callable(true)
+            .constructable(false)
+            .build();
+        typed_array_object.borrow_mut().insert(
+            species,
+            AccessorDescriptor::new(Some(get_species), None, Attribute::CONFIGURABLE),
+        );
+    }
+
+    /// Builds the constructor of the typed arrays of type `kind`, inheriting from `%TypedArray%`.
+    fn init_constructor(
+        context: &mut Context,
+        kind: TypedArrayKind,
+        constructor: NativeFunction,
+    ) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(kind.name(), "init");
+
+        let parent = context.standard_objects().typed_array_object().clone();
+        let bytes_per_element = kind.element_size();
+        let object = ConstructorBuilder::with_standard_object(
+            context,
+            constructor,
+            context
+                .standard_objects()
+                .typed_array_constructor(kind)
+                .clone(),
+        )
+        .name(kind.name())
+        .length(3)
+        .property("BYTES_PER_ELEMENT", bytes_per_element, Attribute::empty())
+        .static_property("BYTES_PER_ELEMENT", bytes_per_element, Attribute::empty())
+        .inherit(parent.prototype().into())
+        .callable(false)
+        .build();
+        object
+            .borrow_mut()
+            .set_prototype_instance(parent.constructor().into());
+
+        (
+            kind.name(),
+            object.into(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+    }
+
+    /// `%TypedArray%( )`
+    ///
+    /// The intrinsic is abstract and always throws.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%
+    fn constructor(_: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        ctx.throw_type_error("Abstract class TypedArray not directly constructable")
+    }
+
+    /// `TypedArray( ...args )`
+    ///
+    /// The shared implementation of the typed array constructors. The first argument can be a
+    /// length, a typed array, an `ArrayBuffer` followed by an offset and length, or an iterable
+    /// or array-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray
+    fn construct(
+        this: &Value,
+        args: &[Value],
+        kind: TypedArrayKind,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let first = args.get(0).cloned().unwrap_or_default();
+        let data = if let Value::Object(ref object) = first {
+            let source = object.borrow().as_typed_array().cloned();
+            let is_buffer = object.borrow().is_array_buffer();
+            if let Some(source) = source {
+                Self::initialize_from_typed_array(kind, &source, ctx)?
+            } else if is_buffer {
+                Self::initialize_from_array_buffer(kind, object, args, ctx)?
+            } else {
+                Self::initialize_from_object(kind, &first, ctx)?
+            }
+        } else {
+            let length = first.to_index(ctx)?;
+            TypedArray::allocate(kind, length, ctx)?
+        };
+        this.set_data(ObjectData::TypedArray(data));
+        Ok(this.clone())
+    }
+
+    /// `InitializeTypedArrayFromTypedArray( O, srcArray )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromtypedarray
+    fn initialize_from_typed_array(
+        kind: TypedArrayKind,
+        source: &TypedArray,
+        ctx: &mut Context,
+    ) -> Result<TypedArray> {
+        if source.is_detached() {
+            return Err(ctx.construct_type_error("Cannot construct from a detached typed array"));
+        }
+        if source.kind.is_bigint() != kind.is_bigint() {
+            return Err(ctx.construct_type_error(
+                "Cannot mix BigInt and other types, use explicit conversions",
+            ));
+        }
+        let length = source.length();
+        let data = TypedArray::allocate(kind, length, ctx)?;
+        for index in 0..length {
+            let value = source.get_element(index as f64).unwrap_or_default();
+            data.set_element(index as f64, &value, ctx)?;
+        }
+        Ok(data)
+    }
+
+    /// `InitializeTypedArrayFromArrayBuffer( O, buffer, byteOffset, length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromarraybuffer
+    fn initialize_from_array_buffer(
+        kind: TypedArrayKind,
+        buffer: &GcObject,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<TypedArray> {
+        let element_size = kind.element_size();
+        let offset = args.get(1).cloned().unwrap_or_default().to_index(ctx)?;
+        if offset % element_size != 0 {
+            return Err(ctx.construct_range_error(format!(
+                "start offset of {} should be a multiple of {}",
+                kind.name(),
+                element_size
+            )));
+        }
+        let length = args.get(2).cloned().unwrap_or_default();
+        let new_length = if length.is_undefined() {
+            None
+        } else {
+            Some(length.to_index(ctx)?)
+        };
+
+        let buffer_byte_length = match buffer.borrow().as_array_buffer() {
+            Some(data) if !data.is_detached() => data.byte_length(),
+            _ => return Err(ctx.construct_type_error("Cannot construct on a detached ArrayBuffer")),
+        };
+        let new_byte_length = match new_length {
+            None => {
+                if buffer_byte_length % element_size != 0 {
+                    return Err(ctx.construct_range_error(format!(
+                        "byte length of {} should be a multiple of {}",
+                        kind.name(),
+                        element_size
+                    )));
+                }
+                match buffer_byte_length.checked_sub(offset) {
+                    Some(new_byte_length) => new_byte_length,
+                    None => {
+                        return Err(ctx.construct_range_error(format!(
+                            "start offset {} is outside the bounds of the buffer",
+                            offset
+                        )))
+                    }
+                }
+            }
+            Some(new_length) => {
+                let new_byte_length = new_length.saturating_mul(element_size);
+                if offset.saturating_add(new_byte_length) > buffer_byte_length {
+                    return Err(ctx.construct_range_error(format!(
+                        "Invalid typed array length: {}",
+                        new_length
+                    )));
+                }
+                new_byte_length
+            }
+        };
+
+        Ok(TypedArray {
+            kind,
+            buffer: buffer.clone(),
+            byte_offset: offset,
+            length: new_byte_length / element_size,
+        })
+    }
+
+    /// `InitializeTypedArrayFromList( O, values )` and
+    /// `InitializeTypedArrayFromArrayLike( O, arrayLike )`
+    ///
+    /// Iterable objects are iterated, other objects are read as array-likes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromlist
+    fn initialize_from_object(
+        kind: TypedArrayKind,
+        object: &Value,
+        ctx: &mut Context,
+    ) -> Result<TypedArray> {
+        let values = Self::iterable_or_array_like_to_list(object, ctx)?;
+        let data = TypedArray::allocate(kind, values.len(), ctx)?;
+        for (index, value) in values.iter().enumerate() {
+            data.set_element(index as f64, value, ctx)?;
+        }
+        Ok(data)
+    }
+
+    /// Collects the values of `object`, with its `@@iterator` method if it has one, or as an
+    /// array-like otherwise.
+    fn iterable_or_array_like_to_list(source: &Value, ctx: &mut Context) -> Result<Vec<Value>> {
+        let object: Value = source.to_object(ctx)?.into();
+        let iterator_symbol = ctx.well_known_symbols().iterator_symbol();
+        let using_iterator = object.get_field(iterator_symbol, ctx)?;
+        if using_iterator.is_null_or_undefined() {
+            return object.create_list_from_array_like(ctx);
+        }
+        if !using_iterator.is_function() {
+            return Err(ctx.construct_type_error("@@iterator is not a function"));
+        }
+
+        let iterator_record = get_iterator(ctx, source.clone())?;
+        let mut values = Vec::new();
+        loop {
+            let next = iterator_record.next(ctx)?;
+            if next.is_done() {
+                break;
+            }
+            values.push(next.value());
+        }
+        Ok(values)
+    }
+
+    /// `ValidateTypedArray( O )`
+    ///
+    /// Returns the object and data of `this`, which must be a typed array on a buffer that
+    /// hasn't been detached.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validatetypedarray
+    fn validate(this: &Value, ctx: &mut Context) -> Result<(GcObject, TypedArray)> {
+        let (object, data) = Self::this_typed_array(this, ctx)?;
+        if data.is_detached() {
+            return Err(ctx.construct_type_error("typed array buffer is detached"));
+        }
+        Ok((object, data))
+    }
+
+    /// Returns the object and data of `this`, which must be a typed array.
+    fn this_typed_array(this: &Value, ctx: &mut Context) -> Result<(GcObject, TypedArray)> {
+        if let Value::Object(ref object) = this {
+            if let Some(data) = object.borrow().as_typed_array() {
+                return Ok((object.clone(), data.clone()));
+            }
+        }
+        Err(ctx.construct_type_error("'this' is not a typed array"))
+    }
+
+    /// `TypedArrayCreate( constructor, argumentList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#typedarray-create
+    fn create(
+        constructor: &GcObject,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<(GcObject, TypedArray)> {
+        let new = constructor.construct(args, &constructor.clone().into(), ctx)?;
+        let (object, data) = Self::validate(&new, ctx)?;
+        if let [length] = args {
+            match length.as_number() {
+                Some(length) if (data.length() as f64) < length => {
+                    return Err(ctx.construct_type_error("created typed array is too small"));
+                }
+                _ => {}
+            }
+        }
+        Ok((object, data))
+    }
+
+    /// `TypedArraySpeciesCreate( exemplar, argumentList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#typedarray-species-create
+    fn species_create(
+        exemplar: &GcObject,
+        kind: TypedArrayKind,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<(GcObject, TypedArray)> {
+        let default = ctx
+            .standard_objects()
+            .typed_array_constructor(kind)
+            .constructor();
+        let constructor = exemplar.species_constructor(default, ctx)?;
+        let (object, data) = Self::create(&constructor, args, ctx)?;
+        if data.kind.is_bigint() != kind.is_bigint() {
+            return Err(ctx.construct_type_error(
+                "species constructor created a typed array of another content type",
+            ));
+        }
+        Ok((object, data))
+    }
+
+    /// Gets the callback argument of the iteration methods, which must be a function.
+    fn callback(args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let callback = args.get(0).cloned().unwrap_or_default();
+        if !callback.is_function() {
+            return Err(ctx.construct_type_error("callback is not a function"));
+        }
+        Ok(callback)
+    }
+
+    /// `get %TypedArray% [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%-@@species
+    fn get_species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.from( source [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// Creates a new typed array from an iterable or array-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/from
+    fn from(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let constructor = match this {
+            Value::Object(ref object) if object.is_constructable() => object.clone(),
+            _ => return ctx.throw_type_error("'this' is not a constructor"),
+        };
+        let map_function = args.get(1).cloned().unwrap_or_default();
+        if !map_function.is_undefined() && !map_function.is_function() {
+            return ctx.throw_type_error("map function is not a function");
+        }
+        let this_arg = args.get(2).cloned().unwrap_or_default();
+
+        let source = args.get(0).cloned().unwrap_or_default();
+        let values = Self::iterable_or_array_like_to_list(&source, ctx)?;
+
+        let (target, _) = Self::create(&constructor, &[values.len().into()], ctx)?;
+        let receiver: Value = target.clone().into();
+        for (index, value) in values.into_iter().enumerate() {
+            let value = if map_function.is_undefined() {
+                value
+            } else {
+                ctx.call(&map_function, &this_arg, &[value, index.into()])?
+            };
+            target.set(index.into(), value, &receiver, ctx)?;
+        }
+        Ok(receiver)
+    }
+
+    /// `%TypedArray%.of( ...items )`
+    ///
+    /// Creates a new typed array holding the arguments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/of
+    fn of(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let constructor = match this {
+            Value::Object(ref object) if object.is_constructable() => object.clone(),
+            _ => return ctx.throw_type_error("'this' is not a constructor"),
+        };
+
+        let (target, _) = Self::create(&constructor, &[args.len().into()], ctx)?;
+        let receiver: Value = target.clone().into();
+        for (index, value) in args.iter().enumerate() {
+            target.set(index.into(), value.clone(), &receiver, ctx)?;
+        }
+        Ok(receiver)
+    }
+
+    /// `get %TypedArray%.prototype.buffer`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.buffer
+    fn get_buffer(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (_, data) = Self::this_typed_array(this, ctx)?;
+        Ok(data.buffer.clone().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.bytelength
+    fn get_byte_length(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (_, data) = Self::this_typed_array(this, ctx)?;
+        Ok(data.byte_length().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteOffset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.byteoffset
+    fn get_byte_offset(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (_, data) = Self::this_typed_array(this, ctx)?;
+        Ok(data.byte_offset().into())
+    }
+
+    /// `get %TypedArray%.prototype.length`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.length
+    fn get_length(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (_, data) = Self::this_typed_array(this, ctx)?;
+        Ok(data.length().into())
+    }
+
+    /// `get %TypedArray%.prototype [ @@toStringTag ]`
+    ///
+    /// Returns the name of the constructor of typed arrays, and undefined for other values.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-@@tostringtag
+    fn get_to_string_tag(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        if let Value::Object(ref object) = this {
+            if let Some(data) = object.borrow().as_typed_array() {
+                return Ok(data.kind.name().into());
+            }
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `%TypedArray%.prototype.copyWithin( target, start [ , end ] )`
+    ///
+    /// Copies the elements from `start` up to `end` to the position `target`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.copywithin
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/copyWithin
+    fn copy_within(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (_, data) = Self::validate(this, ctx)?;
+        let len = data.length();
+        let arg = |index: usize| args.get(index).cloned().unwrap_or_default();
+        let to = arg(0).to_relative_index(len, 0, ctx)?;
+        let from = arg(1).to_relative_index(len, 0, ctx)?;
+        let end = arg(2).to_relative_index(len, len, ctx)?;
+        let count = end.saturating_sub(from).min(len - to);
+        if count > 0 {
+            if data.is_detached() {
+                return ctx.throw_type_error("typed array buffer is detached");
+            }
+            let size = data.kind.element_size();
+            let offset = data.byte_offset;
+            let mut buffer = data.buffer.borrow_mut();
+            let bytes = buffer
+                .as_array_buffer_mut()
+                .and_then(ArrayBuffer::data_mut)
+                .expect("buffer should not be detached");
+            bytes.copy_within(
+                offset + from * size..offset + (from + count) * size,
+                offset + to * size,
+            );
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.entries( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/entries
+    fn entries(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::validate(this, ctx)?;
+        ArrayIterator::create_array_iterator(ctx, this.clone(), ArrayIterationKind::KeyAndValue)
+    }
+
+    /// `%TypedArray%.prototype.keys( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/keys
+    fn keys(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::validate(this, ctx)?;
+        ArrayIterator::create_array_iterator(ctx, this.clone(), ArrayIterationKind::Key)
+    }
+
+    /// `%TypedArray%.prototype.values( )`
+    ///
+    /// This is also the `@@iterator` method of typed arrays.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/values
+    fn values(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::validate(this, ctx)?;
+        ArrayIterator::create_array_iterator(ctx, this.clone(), ArrayIterationKind::Value)
+    }
+
+    /// Calls `callback` with each element, index and the typed array, until it returns
+    /// `stop_when`.
+    ///
+    /// Returns the index and element the iteration stopped at, if it did.
+    fn find_element(
+        this: &Value,
+        args: &[Value],
+        stop_when: bool,
+        ctx: &mut Context,
+    ) -> Result<Option<(usize, Value)>> {
+        let (object, data) = Self::validate(this, ctx)?;
+        let callback = Self::callback(args, ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..data.length() {
+            let value = object.get(&index.into(), this, ctx)?;
+            let result = ctx.call(
+                &callback,
+                &this_arg,
+                &[value.clone(), index.into(), this.clone()],
+            )?;
+            if result.to_boolean() == stop_when {
+                return Ok(Some((index, value)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// `%TypedArray%.prototype.every( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.every
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/every
+    fn every(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(Self::find_element(this, args, false, ctx)?.is_none().into())
+    }
+
+    /// `%TypedArray%.prototype.some( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.some
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/some
+    fn some(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(Self::find_element(this, args, true, ctx)?.is_some().into())
+    }
+
+    /// `%TypedArray%.prototype.find( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.find
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/find
+    fn find(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(Self::find_element(this, args, true, ctx)?
+            .map(|(_, value)| value)
+            .unwrap_or_default())
+    }
+
+    /// `%TypedArray%.prototype.findIndex( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/findIndex
+    fn find_index(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(match Self::find_element(this, args, true, ctx)? {
+            Some((index, _)) => index.into(),
+            None => Value::from(-1),
+        })
+    }
+
+    /// `%TypedArray%.prototype.forEach( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/forEach
+    fn for_each(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (object, data) = Self::validate(this, ctx)?;
+        let callback = Self::callback(args, ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..data.length() {
+            let value = object.get(&index.into(), this, ctx)?;
+            ctx.call(&callback, &this_arg, &[value, index.into(), this.clone()])?;
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `%TypedArray%.prototype.fill( value [ , start [ , end ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.fill
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/fill
+    fn fill(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (_, data) = Self::validate(this, ctx)?;
+        let len = data.length();
+        let raw = data
+            .kind
+            .to_raw(&args.get(0).cloned().unwrap_or_default(), ctx)?;
+        let start = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(len, 0, ctx)?;
+        let end = args
+            .get(2)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(len, len, ctx)?;
+        if data.is_detached() {
+            return ctx.throw_type_error("typed array buffer is detached");
+        }
+        for index in start..end {
+            data.set_raw_element(index as f64, raw);
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.filter( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.filter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/filter
+    fn filter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (object, data) = Self::validate(this, ctx)?;
+        let callback = Self::callback(args, ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let mut kept = Vec::new();
+        for index in 0..data.length() {
+            let value = object.get(&index.into(), this, ctx)?;
+            let selected = ctx.call(
+                &callback,
+                &this_arg,
+                &[value.clone(), index.into(), this.clone()],
+            )?;
+            if selected.to_boolean() {
+                kept.push(value);
+            }
+        }
+
+        let (new, _) = Self::species_create(&object, data.kind, &[kept.len().into()], ctx)?;
+        let receiver: Value = new.clone().into();
+        for (index, value) in kept.into_iter().enumerate() {
+            new.set(index.into(), value, &receiver, ctx)?;
+        }
+        Ok(receiver)
+    }
+
+    /// Finds the index of `args[0]` in the typed array, searching from `args[1]` forwards, or
+    /// backwards if `reverse` is set. `includes` compares with `SameValueZero` and the others
+    /// with strict equality.
+    fn search(
+        this: &Value,
+        args: &[Value],
+        reverse: bool,
+        same_value: bool,
+        ctx: &mut Context,
+    ) -> Result<Option<usize>> {
+        let (_, data) = Self::validate(this, ctx)?;
+        let len = data.length();
+        if len == 0 {
+            return Ok(None);
+        }
+        let target = args.get(0).cloned().unwrap_or_default();
+        let from = match args.get(1) {
+            Some(from) => from.to_integer(ctx)?,
+            None if reverse => len as f64 - 1.0,
+            None => 0.0,
+        };
+        let indices: Box<dyn Iterator<Item = usize>> = if reverse {
+            let start = if from >= 0.0 {
+                from.min(len as f64 - 1.0)
+            } else {
+                len as f64 + from
+            };
+            if start < 0.0 {
+                return Ok(None);
+            }
+            Box::new((0..=start as usize).rev())
+        } else {
+            let start = if from >= 0.0 {
+                from
+            } else {
+                (len as f64 + from).max(0.0)
+            };
+            if start >= len as f64 {
+                return Ok(None);
+            }
+            Box::new(start as usize..len)
+        };
+
+        for index in indices {
+            // Elements past the end of a detached buffer are undefined, which `includes`
+            // finds, but don't exist for `indexOf` and `lastIndexOf`.
+            let element = data.get_element(index as f64);
+            let found = match element {
+                Some(ref element) if same_value => same_value_zero(element, &target),
+                Some(ref element) => element.strict_equals(&target),
+                None if same_value => target.is_undefined(),
+                None => false,
+            };
+            if found {
+                return Ok(Some(index));
+            }
+        }
+        Ok(None)
+    }
+
+    /// `%TypedArray%.prototype.includes( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.includes
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/includes
+    fn includes(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(Self::search(this, args, false, true, ctx)?.is_some().into())
+    }
+
+    /// `%TypedArray%.prototype.indexOf( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.indexof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/indexOf
+    fn index_of(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(match Self::search(this, args, false, false, ctx)? {
+            Some(index) => index.into(),
+            None => Value::from(-1),
+        })
+    }
+
+    /// `%TypedArray%.prototype.lastIndexOf( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.lastindexof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/lastIndexOf
+    fn last_index_of(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(match Self::search(this, args, true, false, ctx)? {
+            Some(index) => index.into(),
+            None => Value::from(-1),
+        })
+    }
+
+    /// `%TypedArray%.prototype.join( separator )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.join
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/join
+    fn join(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (_, data) = Self::validate(this, ctx)?;
+        let len = data.length();
+        let separator = match args.get(0) {
+            None | Some(Value::Undefined) => ",".into(),
+            Some(separator) => separator.to_string(ctx)?,
+        };
+
+        let mut result = Vec::new();
+        for index in 0..len {
+            if index > 0 {
+                result.extend(separator.code_units());
+            }
+            if let Some(element) = data.get_element(index as f64) {
+                result.extend(element.to_string(ctx)?.code_units());
+            }
+        }
+        Ok(RcString::from(result).into())
+    }
+
+    /// `%TypedArray%.prototype.toLocaleString( [ reserved1 [ , reserved2 ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/toLocaleString
+    fn to_locale_string(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (object, data) = Self::validate(this, ctx)?;
+        let mut result = Vec::new();
+        for index in 0..data.length() {
+            if index > 0 {
+                result.push(u16::from(b','));
+            }
+            let element = object.get(&index.into(), this, ctx)?;
+            if !element.is_null_or_undefined() {
+                let method =
+                    element
+                        .to_object(ctx)?
+                        .get(&"toLocaleString".into(), &element, ctx)?;
+                let string = ctx.call(&method, &element, &[])?;
+                result.extend(string.to_string(ctx)?.code_units());
+            }
+        }
+        Ok(RcString::from(result).into())
+    }
+
+    /// `%TypedArray%.prototype.map( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.map
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/map
+    fn map(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (object, data) = Self::validate(this, ctx)?;
+        let callback = Self::callback(args, ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let len = data.length();
+
+        let (new, _) = Self::species_create(&object, data.kind, &[len.into()], ctx)?;
+        let receiver: Value = new.clone().into();
+        for index in 0..len {
+            let value = object.get(&index.into(), this, ctx)?;
+            let mapped = ctx.call(&callback, &this_arg, &[value, index.into(), this.clone()])?;
+            new.set(index.into(), mapped, &receiver, ctx)?;
+        }
+        Ok(receiver)
+    }
+
+    /// Implements `reduce` and, when `reverse` is set, `reduceRight`.
+    fn fold(this: &Value, args: &[Value], reverse: bool, ctx: &mut Context) -> Result<Value> {
+        let (object, data) = Self::validate(this, ctx)?;
+        let callback = Self::callback(args, ctx)?;
+        let len = data.length();
+        let mut indices: Box<dyn Iterator<Item = usize>> = if reverse {
+            Box::new((0..len).rev())
+        } else {
+            Box::new(0..len)
+        };
+
+        let mut accumulator = match args.get(1) {
+            Some(initial) => initial.clone(),
+            None => match indices.next() {
+                Some(index) => object.get(&index.into(), this, ctx)?,
+                None => return ctx.throw_type_error("Reduce of empty array with no initial value"),
+            },
+        };
+        for index in indices {
+            let value = object.get(&index.into(), this, ctx)?;
+            accumulator = ctx.call(
+                &callback,
+                &Value::undefined(),
+                &[accumulator, value, index.into(), this.clone()],
+            )?;
+        }
+        Ok(accumulator)
+    }
+
+    /// `%TypedArray%.prototype.reduce( callbackfn [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduce
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reduce
+    fn reduce(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::fold(this, args, false, ctx)
+    }
+
+    /// `%TypedArray%.prototype.reduceRight( callbackfn [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduceright
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reduceRight
+    fn reduce_right(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::fold(this, args, true, ctx)
+    }
+
+    /// `%TypedArray%.prototype.reverse( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reverse
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reverse
+    fn reverse(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (_, data) = Self::validate(this, ctx)?;
+        let size = data.kind.element_size();
+        let start = data.byte_offset;
+        let mut buffer = data.buffer.borrow_mut();
+        let bytes = buffer
+            .as_array_buffer_mut()
+            .and_then(ArrayBuffer::data_mut)
+            .expect("buffer should not be detached");
+        let elements = &mut bytes[start..start + data.length * size];
+        // Reversing all the bytes reverses the order of the elements, and the bytes of each
+        // element, which are then put back in order.
+        elements.reverse();
+        for element in elements.chunks_mut(size) {
+            element.reverse();
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.set( source [ , offset ] )`
+    ///
+    /// Writes the values of a typed array or an array-like object, starting at `offset`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/set
+    fn set(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (_, target) = Self::this_typed_array(this, ctx)?;
+        let offset = args.get(1).cloned().unwrap_or_default().to_integer(ctx)?;
+        if offset < 0.0 {
+            return ctx.throw_range_error("offset is out of bounds");
+        }
+        if target.is_detached() {
+            return ctx.throw_type_error("typed array buffer is detached");
+        }
+        let target_length = target.length();
+
+        let source = args.get(0).cloned().unwrap_or_default();
+        let typed_source = match source {
+            Value::Object(ref object) => object.borrow().as_typed_array().cloned(),
+            _ => None,
+        };
+        if let Some(source) = typed_source {
+            if source.is_detached() {
+                return ctx.throw_type_error("source typed array buffer is detached");
+            }
+            if source.kind.is_bigint() != target.kind.is_bigint() {
+                return ctx.throw_type_error(
+                    "Cannot mix BigInt and other types, use explicit conversions",
+                );
+            }
+            let source_length = source.length();
+            if source_length as f64 + offset > target_length as f64 {
+                return ctx.throw_range_error("offset is out of bounds");
+            }
+            // The source is read entirely before writing, in case both share a buffer.
+            let values = (0..source_length)
+                .map(|index| source.get_element(index as f64).unwrap_or_default())
+                .collect::<Vec<_>>();
+            for (index, value) in values.iter().enumerate() {
+                target.set_element(offset + index as f64, value, ctx)?;
+            }
+            return Ok(Value::undefined());
+        }
+
+        let source = source.to_object(ctx)?;
+        let source: Value = source.into();
+        let source_length = source.get_field("length", ctx)?.to_length(ctx)?;
+        if source_length as f64 + offset > target_length as f64 {
+            return ctx.throw_range_error("offset is out of bounds");
+        }
+        for index in 0..source_length {
+            let value = source.get_field(index, ctx)?;
+            let raw = target.kind.to_raw(&value, ctx)?;
+            if target.is_detached() {
+                return ctx.throw_type_error("typed array buffer is detached");
+            }
+            target.set_raw_element(offset + index as f64, raw);
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `%TypedArray%.prototype.slice( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/slice
+    fn slice(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (object, data) = Self::validate(this, ctx)?;
+        let len = data.length();
+        let start = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(len, 0, ctx)?;
+        let end = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(len, len, ctx)?;
+        let count = end.saturating_sub(start);
+
+        let (new, new_data) = Self::species_create(&object, data.kind, &[count.into()], ctx)?;
+        if count > 0 {
+            if data.is_detached() {
+                return ctx.throw_type_error("typed array buffer is detached");
+            }
+            for index in 0..count {
+                let value = data.get_element((start + index) as f64).unwrap_or_default();
+                if new_data.kind == data.kind {
+                    let raw = data.kind.to_raw(&value, ctx)?;
+                    new_data.set_raw_element(index as f64, raw);
+                } else {
+                    new.set(index.into(), value, &new.clone().into(), ctx)?;
+                }
+            }
+        }
+        Ok(new.into())
+    }
+
+    /// `%TypedArray%.prototype.sort( comparefn )`
+    ///
+    /// Without a compare function, numbers are sorted by value, with `NaN` last.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/sort
+    fn sort(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let compare_function = args.get(0).cloned().unwrap_or_default();
+        if !compare_function.is_undefined() && !compare_function.is_function() {
+            return ctx.throw_type_error("compare function is not a function");
+        }
+        let (_, data) = Self::validate(this, ctx)?;
+        let len = data.length();
+        let values = (0..len)
+            .map(|index| data.get_element(index as f64).unwrap_or_default())
+            .collect();
+
+        let sorted = Array::sort_values(values, |x, y| {
+            if compare_function.is_undefined() {
+                return Ok(Self::compare_elements(x, y));
+            }
+            let result = ctx.call(
+                &compare_function,
+                &Value::undefined(),
+                &[x.clone(), y.clone()],
+            )?;
+            let result = result.to_number(ctx)?;
+            if data.is_detached() {
+                return Err(ctx.construct_type_error("typed array buffer is detached"));
+            }
+            Ok(result.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+        })?;
+        for (index, value) in sorted.iter().enumerate() {
+            let raw = data.kind.to_raw(value, ctx)?;
+            data.set_raw_element(index as f64, raw);
+        }
+        Ok(this.clone())
+    }
+
+    /// The default comparison of `sort`, for two elements of the same typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraysortcompare
+    fn compare_elements(x: &Value, y: &Value) -> Ordering {
+        if let (Some(x), Some(y)) = (x.as_bigint(), y.as_bigint()) {
+            return x.cmp(y);
+        }
+        let x = x.as_number().unwrap_or(f64::NAN);
+        let y = y.as_number().unwrap_or(f64::NAN);
+        match (x.is_nan(), y.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            // -0 sorts before +0.
+            _ if x == 0.0 && y == 0.0 => y.is_sign_negative().cmp(&x.is_sign_negative()),
+            _ => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        }
+    }
+
+    /// `%TypedArray%.prototype.subarray( begin, end )`
+    ///
+    /// Returns a new typed array on the same buffer, viewing the elements from `begin` up to
+    /// `end`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.subarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/subarray
+    fn subarray(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (object, data) = Self::this_typed_array(this, ctx)?;
+        let len = data.length();
+        let begin = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(len, 0, ctx)?;
+        let end = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(len, len, ctx)?;
+        let new_length = end.saturating_sub(begin);
+        let begin_byte_offset = data.byte_offset() + begin * data.kind.element_size();
+
+        let args = [
+            data.buffer.clone().into(),
+            begin_byte_offset.into(),
+            new_length.into(),
+        ];
+        let (new, _) = Self::species_create(&object, data.kind, &args, ctx)?;
+        Ok(new.into())
+    }
+}
+
+/// Declares the constructor of the typed arrays of an element type.
+macro_rules! typed_array_constructor {
+    ($(#[$attr:meta])* $name:ident, $kind:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy)]
+        pub(crate) struct $name;
+
+        impl BuiltIn for $name {
+            const NAME: &'static str = stringify!($name);
+
+            fn attribute() -> Attribute {
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+            }
+
+            fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+                TypedArrayObject::init_constructor(context, $kind, Self::constructor)
+            }
+        }
+
+        impl $name {
+            fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+                TypedArrayObject::construct(this, args, $kind, ctx)
+            }
+        }
+    };
+}
+
+typed_array_constructor!(
+    /// JavaScript `Int8Array` built-in implementation.
+    Int8Array,
+    TypedArrayKind::Int8
+);
+typed_array_constructor!(
+    /// JavaScript `Uint8Array` built-in implementation.
+    Uint8Array,
+    TypedArrayKind::Uint8
+);
+typed_array_constructor!(
+    /// JavaScript `Uint8ClampedArray` built-in implementation.
+    Uint8ClampedArray,
+    TypedArrayKind::Uint8Clamped
+);
+typed_array_constructor!(
+    /// JavaScript `Int16Array` built-in implementation.
+    Int16Array,
+    TypedArrayKind::Int16
+);
+typed_array_constructor!(
+    /// JavaScript `Uint16Array` built-in implementation.
+    Uint16Array,
+    TypedArrayKind::Uint16
+);
+typed_array_constructor!(
+    /// JavaScript `Int32Array` built-in implementation.
+    Int32Array,
+    TypedArrayKind::Int32
+);
+typed_array_constructor!(
+    /// JavaScript `Uint32Array` built-in implementation.
+    Uint32Array,
+    TypedArrayKind::Uint32
+);
+typed_array_constructor!(
+    /// JavaScript `Float32Array` built-in implementation.
+    Float32Array,
+    TypedArrayKind::Float32
+);
+typed_array_constructor!(
+    /// JavaScript `Float64Array` built-in implementation.
+    Float64Array,
+    TypedArrayKind::Float64
+);
+typed_array_constructor!(
+    /// JavaScript `BigInt64Array` built-in implementation.
+    BigInt64Array,
+    TypedArrayKind::BigInt64
+);
+typed_array_constructor!(
+    /// JavaScript `BigUint64Array` built-in implementation.
+    BigUint64Array,
+    TypedArrayKind::BigUint64
+);
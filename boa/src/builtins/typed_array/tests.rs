@@ -0,0 +1,281 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct() {
+    let mut engine = Context::new();
+    let init = r#"
+        var zeroes = new Int16Array(3);
+        var fromArray = new Uint8Array([1, 2, 300]);
+        var fromTyped = new Float64Array(fromArray);
+        var fromIterable = new Int32Array(new Set([4, 5]));
+        var buffer = new ArrayBuffer(8);
+        var onBuffer = new Uint16Array(buffer, 2, 2);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "zeroes.length"), "3");
+    assert_eq!(forward(&mut engine, "zeroes.byteLength"), "6");
+    assert_eq!(forward(&mut engine, "zeroes[1]"), "0");
+    assert_eq!(forward(&mut engine, "fromArray.join()"), "\"1,2,44\"");
+    assert_eq!(forward(&mut engine, "fromTyped.join()"), "\"1,2,44\"");
+    assert_eq!(forward(&mut engine, "fromIterable.join()"), "\"4,5\"");
+    assert_eq!(forward(&mut engine, "onBuffer.buffer === buffer"), "true");
+    assert_eq!(forward(&mut engine, "onBuffer.byteOffset"), "2");
+    assert_eq!(forward(&mut engine, "onBuffer.length"), "2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Uint16Array(buffer, 1) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { Uint8Array(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new BigInt64Array(fromArray) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn prototype_chain() {
+    let mut engine = Context::new();
+    let init = r#"
+        var TypedArray = Object.getPrototypeOf(Int8Array);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "TypedArray.name"), "\"TypedArray\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(Float32Array.prototype) === TypedArray.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Float32Array.BYTES_PER_ELEMENT"), "4");
+    assert_eq!(
+        forward(&mut engine, "BigUint64Array.prototype.BYTES_PER_ELEMENT"),
+        "8"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Uint8ClampedArray(1)[Symbol.toStringTag]"),
+        "\"Uint8ClampedArray\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new TypedArray() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn element_conversions() {
+    let mut engine = Context::new();
+    let init = r#"
+        var int8 = new Int8Array(1);
+        var clamped = new Uint8ClampedArray(4);
+        var float32 = new Float32Array(1);
+        var big = new BigUint64Array(1);
+        int8[0] = 200;
+        clamped[0] = 300;
+        clamped[1] = -5;
+        clamped[2] = 2.5;
+        clamped[3] = 3.5;
+        float32[0] = 1.1;
+        big[0] = -1n;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "int8[0]"), "-56");
+    assert_eq!(forward(&mut engine, "clamped.join()"), "\"255,0,2,4\"");
+    assert_eq!(
+        forward(&mut engine, "float32[0] === Math.fround(1.1)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "big[0]"), "18446744073709551615n");
+    assert_eq!(
+        forward(&mut engine, "try { big[0] = 1 } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn integer_indexed_properties() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = new Uint8Array([1, 2]);
+        array[5] = 3;
+        array[-1] = 4;
+        array.foo = "bar";
+        Object.prototype[3] = "inherited";
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array[5]"), "undefined");
+    assert_eq!(forward(&mut engine, "array[-1]"), "undefined");
+    assert_eq!(forward(&mut engine, "array[3]"), "undefined");
+    assert_eq!(forward(&mut engine, "array.foo"), "\"bar\"");
+    assert_eq!(forward(&mut engine, "1 in array"), "true");
+    assert_eq!(forward(&mut engine, "2 in array"), "false");
+    assert_eq!(forward(&mut engine, "delete array[0]"), "false");
+    assert_eq!(forward(&mut engine, "delete array[2]"), "true");
+    assert_eq!(forward(&mut engine, "Reflect.ownKeys(array).length"), "3");
+    assert_eq!(forward(&mut engine, "Reflect.ownKeys(array)[2]"), "\"foo\"");
+}
+
+#[test]
+fn iteration_methods() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = new Int16Array([3, 1, 4, 1, 5]);
+        var sum = 0;
+        array.forEach(function (value) { sum += value; });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "sum"), "14");
+    assert_eq!(forward(&mut engine, "[...array].length"), "5");
+    assert_eq!(
+        forward(&mut engine, "[...array.keys()].join()"),
+        "\"0,1,2,3,4\""
+    );
+    assert_eq!(
+        forward(&mut engine, "array.entries().next().value.join()"),
+        "\"0,3\""
+    );
+    assert_eq!(forward(&mut engine, "array.every(x => x > 0)"), "true");
+    assert_eq!(forward(&mut engine, "array.some(x => x > 4)"), "true");
+    assert_eq!(forward(&mut engine, "array.find(x => x > 3)"), "4");
+    assert_eq!(forward(&mut engine, "array.findIndex(x => x > 3)"), "2");
+    assert_eq!(
+        forward(&mut engine, "array.filter(x => x > 1).join()"),
+        "\"3,4,5\""
+    );
+    assert_eq!(
+        forward(&mut engine, "array.map(x => x * 2).join()"),
+        "\"6,2,8,2,10\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(array.map(x => x * 2)) === Int16Array.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "array.reduce((a, b) => a + b)"), "14");
+    assert_eq!(
+        forward(&mut engine, "array.reduceRight((a, b) => a + '' + b, '')"),
+        "\"51413\""
+    );
+}
+
+#[test]
+fn search_methods() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = new Float64Array([1, NaN, 2, 1]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array.indexOf(1)"), "0");
+    assert_eq!(forward(&mut engine, "array.indexOf(1, 1)"), "3");
+    assert_eq!(forward(&mut engine, "array.lastIndexOf(1)"), "3");
+    assert_eq!(forward(&mut engine, "array.lastIndexOf(1, -2)"), "0");
+    assert_eq!(forward(&mut engine, "array.indexOf(NaN)"), "-1");
+    assert_eq!(forward(&mut engine, "array.includes(NaN)"), "true");
+    assert_eq!(forward(&mut engine, "array.includes(2, 3)"), "false");
+}
+
+#[test]
+fn mutation_methods() {
+    let mut engine = Context::new();
+    let init = r#"
+        var filled = new Uint8Array(5).fill(7, 1, -1);
+        var copied = new Uint8Array([1, 2, 3, 4, 5]).copyWithin(0, 3);
+        var reversed = new Uint16Array([1, 256, 3]).reverse();
+        var target = new Int8Array(5);
+        target.set([1, 2], 1);
+        target.set(new Int8Array([9]), 4);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "filled.join()"), "\"0,7,7,7,0\"");
+    assert_eq!(forward(&mut engine, "copied.join()"), "\"4,5,3,4,5\"");
+    assert_eq!(forward(&mut engine, "reversed.join()"), "\"3,256,1\"");
+    assert_eq!(forward(&mut engine, "target.join('-')"), "\"0-1-2-0-9\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { target.set([1, 2], 4) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn sort() {
+    let mut engine = Context::new();
+    let init = r#"
+        var numbers = new Float64Array([3, NaN, -0, 10, 0, -1]).sort();
+        var descending = new Int32Array([3, 10, 2]).sort((a, b) => b - a);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "numbers.join()"),
+        "\"-1,0,0,3,10,NaN\""
+    );
+    assert_eq!(forward(&mut engine, "Object.is(numbers[1], -0)"), "true");
+    assert_eq!(forward(&mut engine, "descending.join()"), "\"10,3,2\"");
+}
+
+#[test]
+fn slice_and_subarray() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = new Uint8Array([1, 2, 3, 4, 5]);
+        var sliced = array.slice(1, -1);
+        var sub = array.subarray(1, -1);
+        sliced[0] = 20;
+        sub[1] = 30;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "sliced.join()"), "\"20,3,4\"");
+    assert_eq!(forward(&mut engine, "sub.join()"), "\"2,30,4\"");
+    assert_eq!(forward(&mut engine, "array.join()"), "\"1,2,30,4,5\"");
+    assert_eq!(forward(&mut engine, "sub.byteOffset"), "1");
+    assert_eq!(forward(&mut engine, "sub.buffer === array.buffer"), "true");
+}
+
+#[test]
+fn from_and_of() {
+    let mut engine = Context::new();
+    let init = r#"
+        var fromString = Uint8Array.from("123");
+        var mapped = Int16Array.from([1, 2, 3], x => x * -1);
+        var ofValues = Float32Array.of(1, 2.5);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "fromString.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "mapped.join()"), "\"-1,-2,-3\"");
+    assert_eq!(forward(&mut engine, "ofValues.join()"), "\"1,2.5\"");
+    assert_eq!(
+        forward(&mut engine, "ofValues[Symbol.toStringTag]"),
+        "\"Float32Array\""
+    );
+}
+
+#[test]
+fn to_string() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = new Uint8Array([1, 2, 3]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array.toString()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "array.toLocaleString()"), "\"1,2,3\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Uint8Array.prototype.toString === Array.prototype.toString"
+        ),
+        "true"
+    );
+}
@@ -0,0 +1,233 @@
+//! This module implements the global URI handling functions.
+//!
+//! `encodeURI()` and `encodeURIComponent()` replace the characters of a string that are not
+//! allowed in a URI by their UTF-8 escape sequences, while `decodeURI()` and
+//! `decodeURIComponent()` do the opposite.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-uri-handling-functions
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
+
+use crate::{
+    builtins::function::NativeFunction,
+    object::FunctionBuilder,
+    property::Attribute,
+    value::{RcString, Value},
+    BoaProfiler, Context, Result,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The characters that have a special meaning in a URI, `uriReserved` in the specification.
+const URI_RESERVED: &str = ";/?:@&=+$,";
+
+/// The characters that are never escaped, `uriUnescaped` in the specification, without the
+/// ASCII letters and digits.
+const URI_UNESCAPED_MARKS: &str = "-_.!~*'()";
+
+/// The global URI handling functions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Uri;
+
+impl Uri {
+    /// Initializes the URI handling functions on the global object.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event("uri", "init");
+
+        let functions: [(NativeFunction, &str); 4] = [
+            (Self::encode_uri, "encodeURI"),
+            (Self::encode_uri_component, "encodeURIComponent"),
+            (Self::decode_uri, "decodeURI"),
+            (Self::decode_uri_component, "decodeURIComponent"),
+        ];
+        for &(function, name) in functions.iter() {
+            let function = FunctionBuilder::new(context, function)
+                .name(name)
+                .length(1)
+                .callable(true)
+                .constructable(false)
+                .build();
+            context
+                .global_object()
+                .as_object_mut()
+                .expect("global object")
+                .insert_property(
+                    name,
+                    function,
+                    Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                );
+        }
+    }
+
+    /// `encodeURI( uri )`
+    ///
+    /// Escapes every character except the ones that are unescaped or have a special meaning in a
+    /// URI, and `#`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-encodeuri-uri
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
+    fn encode_uri(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let uri = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+        Self::encode(&uri, |c| URI_RESERVED.contains(c) || c == '#', ctx)
+    }
+
+    /// `encodeURIComponent( uriComponent )`
+    ///
+    /// Escapes every character except the unescaped ones.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-encodeuricomponent-uricomponent
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURIComponent
+    fn encode_uri_component(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let component = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+        Self::encode(&component, |_| false, ctx)
+    }
+
+    /// `decodeURI( encodedURI )`
+    ///
+    /// Decodes every escape sequence except the ones of the characters that have a special
+    /// meaning in a URI, and `#`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-decodeuri-encodeduri
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
+    fn decode_uri(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let uri = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+        Self::decode(&uri, |c| URI_RESERVED.contains(c) || c == '#', ctx)
+    }
+
+    /// `decodeURIComponent( encodedURIComponent )`
+    ///
+    /// Decodes every escape sequence.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-decodeuricomponent-encodeduricomponent
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURIComponent
+    fn decode_uri_component(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let component = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+        Self::decode(&component, |_| false, ctx)
+    }
+
+    /// Escapes the code points of `string` that are neither unescaped nor accepted by
+    /// `is_unescaped_extra` as `%XY` sequences of their UTF-8 bytes.
+    ///
+    /// Throws a `URIError` if `string` contains unpaired surrogates.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-encode
+    fn encode<F>(string: &RcString, is_unescaped_extra: F, ctx: &mut Context) -> Result<Value>
+    where
+        F: Fn(char) -> bool,
+    {
+        let mut result = String::with_capacity(string.len());
+        for code_point in string.code_points() {
+            let c = match code_point {
+                Ok(c) => c,
+                Err(_) => return ctx.throw_uri_error("URI malformed"),
+            };
+            if c.is_ascii_alphanumeric() || URI_UNESCAPED_MARKS.contains(c) || is_unescaped_extra(c)
+            {
+                result.push(c);
+            } else {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    result.push_str(&format!("%{:02X}", byte));
+                }
+            }
+        }
+        Ok(result.into())
+    }
+
+    /// Replaces the `%XY` escape sequences of `string` by the UTF-8 encoded characters they
+    /// represent, except the ASCII characters accepted by `is_reserved`, which are left escaped.
+    ///
+    /// Throws a `URIError` if an escape sequence is malformed or isn't valid UTF-8.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-decode
+    fn decode<F>(string: &RcString, is_reserved: F, ctx: &mut Context) -> Result<Value>
+    where
+        F: Fn(char) -> bool,
+    {
+        let code_units: Vec<u16> = string.code_units().collect();
+        let mut result = Vec::with_capacity(code_units.len());
+        let mut k = 0;
+        while k < code_units.len() {
+            if code_units[k] != u16::from(b'%') {
+                result.push(code_units[k]);
+                k += 1;
+                continue;
+            }
+
+            let first = match Self::decode_byte(&code_units, k) {
+                Some(byte) => byte,
+                None => return ctx.throw_uri_error("URI malformed"),
+            };
+            if first < 0x80 {
+                if is_reserved(char::from(first)) {
+                    result.extend_from_slice(&code_units[k..k + 3]);
+                } else {
+                    result.push(u16::from(first));
+                }
+                k += 3;
+                continue;
+            }
+
+            // The number of bytes of the UTF-8 character is the number of leading one bits.
+            let n = first.leading_ones() as usize;
+            if n == 1 || n > 4 {
+                return ctx.throw_uri_error("URI malformed");
+            }
+            let mut bytes = vec![first];
+            for j in 1..n {
+                match Self::decode_byte(&code_units, k + 3 * j) {
+                    Some(byte) if byte & 0xC0 == 0x80 => bytes.push(byte),
+                    _ => return ctx.throw_uri_error("URI malformed"),
+                }
+            }
+            // This rejects overlong encodings and surrogates.
+            let c = match std::str::from_utf8(&bytes) {
+                Ok(c) => c,
+                Err(_) => return ctx.throw_uri_error("URI malformed"),
+            };
+            result.extend(c.encode_utf16());
+            k += 3 * n;
+        }
+        Ok(RcString::from(result).into())
+    }
+
+    /// Decodes the byte of the `%XY` escape sequence at index `k`, if there is one.
+    fn decode_byte(code_units: &[u16], k: usize) -> Option<u8> {
+        if code_units.get(k) != Some(&u16::from(b'%')) {
+            return None;
+        }
+        let digit = |index: usize| {
+            code_units
+                .get(index)
+                .and_then(|&unit| std::char::from_u32(u32::from(unit)))
+                .and_then(|c| c.to_digit(16))
+        };
+        Some((digit(k + 1)? * 16 + digit(k + 2)?) as u8)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{forward, Context};
+
+#[test]
+fn encode_uri() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        forward(&mut engine, "encodeURI('http://a.b/c d?e=f&g#h')"),
+        "\"http://a.b/c%20d?e=f&g#h\""
+    );
+    assert_eq!(forward(&mut engine, "encodeURI('\\u00e9')"), "\"%C3%A9\"");
+    assert_eq!(
+        forward(&mut engine, "encodeURI('\\ud83d\\ude00')"),
+        "\"%F0%9F%98%80\""
+    );
+}
+
+#[test]
+fn encode_uri_component() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        forward(&mut engine, "encodeURIComponent('a b/c?d#e')"),
+        "\"a%20b%2Fc%3Fd%23e\""
+    );
+    assert_eq!(
+        forward(&mut engine, "encodeURIComponent(\"-_.!~*'()\")"),
+        "\"-_.!~*'()\""
+    );
+}
+
+#[test]
+fn decode_uri() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        forward(&mut engine, "decodeURI('a%20b%2Fc%23d')"),
+        "\"a b%2Fc%23d\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "decodeURI('%F0%9F%98%80') === '\\ud83d\\ude00'"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn decode_uri_component() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        forward(&mut engine, "decodeURIComponent('a%20b%2Fc%23d')"),
+        "\"a b/c#d\""
+    );
+    assert_eq!(
+        forward(&mut engine, "decodeURIComponent('%c3%a9') === '\\u00e9'"),
+        "true"
+    );
+}
+
+#[test]
+fn uri_errors() {
+    let mut engine = Context::new();
+    let check = |src: &str| format!("try {{ {}; 'no error' }} catch (e) {{ e.name }}", src);
+
+    for src in &[
+        "encodeURI('\\ud800')",
+        "encodeURIComponent('\\udc00a')",
+        "decodeURI('%')",
+        "decodeURI('%G0')",
+        "decodeURIComponent('%80')",
+        "decodeURIComponent('%C3')",
+        "decodeURIComponent('%C0%80')",
+        "decodeURIComponent('%ED%A0%80')",
+    ] {
+        assert_eq!(forward(&mut engine, &check(src)), "\"URIError\"", "{}", src);
+    }
+}
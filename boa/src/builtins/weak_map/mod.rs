@@ -0,0 +1,206 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! A weak map associates values to object keys without keeping the keys alive. The entries
+//! are stored in the [`EphemeronTable`] of their key, so a `WeakMap` object only holds its
+//! [`WeakCollection`] identity.
+//!
+//! A value is kept alive by its key as long as the map has not been dropped. Once the map is
+//! garbage collected, its values are freed by the following collection.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+//! [`EphemeronTable`]: crate::gc::EphemeronTable
+//! [`WeakCollection`]: crate::gc::WeakCollection
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    gc::{WeakCollection, WeakCollectionId},
+    object::{ConstructorBuilder, ObjectData},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let weak_map_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_map_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+        .callable(false)
+        .build();
+
+        (Self::NAME, weak_map_object.into(), Self::attribute())
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakMap( [ iterable ] )`
+    ///
+    /// Creates a new weak map, adding the `[key, value]` entries of `iterable` to it with its
+    /// `set` method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        this.set_data(ObjectData::WeakMap(WeakCollection::new()));
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(this.clone());
+        }
+
+        let adder = this.get_field("set", ctx)?;
+        if !adder.is_function() {
+            return ctx.throw_type_error("'set' of the WeakMap is not a function");
+        }
+
+        let iterator_record = get_iterator(ctx, iterable)?;
+        loop {
+            let next = iterator_record.next(ctx)?;
+            if next.is_done() {
+                break;
+            }
+            let entry = next.value();
+            let status = if entry.is_object() {
+                entry.get_field("0", ctx).and_then(|key| {
+                    let value = entry.get_field("1", ctx)?;
+                    ctx.call(&adder, this, &[key, value])
+                })
+            } else {
+                Err(ctx.construct_type_error("iterator value is not an entry object"))
+            };
+            if status.is_err() {
+                return iterator_record.close(status, ctx);
+            }
+        }
+
+        Ok(this.clone())
+    }
+
+    /// Helper function to get the identity of `this`, if it is a weak map.
+    fn this_id(this: &Value, ctx: &mut Context) -> Result<WeakCollectionId> {
+        if let Value::Object(ref object) = this {
+            if let Some(id) = object.borrow().as_weak_map() {
+                return Ok(id);
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a WeakMap"))
+    }
+
+    /// `WeakMap.prototype.delete( key )`
+    ///
+    /// This method removes the entry of the key, if it exists. Returns true if there was an entry, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let id = Self::this_id(this, ctx)?;
+
+        match args.get(0) {
+            Some(Value::Object(ref key)) => Ok(key.borrow_mut().ephemerons_mut().remove(id).into()),
+            _ => Ok(false.into()),
+        }
+    }
+
+    /// `WeakMap.prototype.get( key )`
+    ///
+    /// This method returns the value associated with the key, or undefined if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let id = Self::this_id(this, ctx)?;
+
+        match args.get(0) {
+            Some(Value::Object(ref key)) => Ok(key
+                .borrow()
+                .ephemerons()
+                .get(id)
+                .cloned()
+                .unwrap_or_default()),
+            _ => Ok(Value::undefined()),
+        }
+    }
+
+    /// `WeakMap.prototype.has( key )`
+    ///
+    /// This method checks if the weak map contains an entry with the given key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let id = Self::this_id(this, ctx)?;
+
+        match args.get(0) {
+            Some(Value::Object(ref key)) => Ok(key.borrow().ephemerons().get(id).is_some().into()),
+            _ => Ok(false.into()),
+        }
+    }
+
+    /// `WeakMap.prototype.set( key, value )`
+    ///
+    /// This method associates the value with the key, which must be an object. Returns the weak map object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let id = Self::this_id(this, ctx)?;
+
+        match args.get(0) {
+            Some(Value::Object(ref key)) => {
+                let value = args.get(1).cloned().unwrap_or_default();
+                key.borrow_mut().ephemerons_mut().insert(id, value);
+                Ok(this.clone())
+            }
+            _ => ctx.throw_type_error("Invalid value used as weak map key"),
+        }
+    }
+}
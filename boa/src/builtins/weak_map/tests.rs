@@ -0,0 +1,122 @@
+use crate::{
+    forward,
+    gc::{force_collect, tracking::tracked_object},
+    Context, Value,
+};
+
+#[test]
+fn construct() {
+    let mut engine = Context::new();
+    let init = r#"
+        let a = {};
+        let b = [];
+        let map = new WeakMap([[a, 1], [b, 2]]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "map.get(a)"), "1");
+    assert_eq!(forward(&mut engine, "map.get(b)"), "2");
+    assert_eq!(
+        forward(&mut engine, "try { WeakMap() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new WeakMap([1]) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new WeakMap([[1, 1]]) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn get_set_has_delete() {
+    let mut engine = Context::new();
+    let init = r#"
+        let key = {};
+        let first = new WeakMap();
+        let second = new WeakMap();
+        first.set(key, "first").set(first, "itself");
+        second.set(key, "second");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "first.get(key)"), "\"first\"");
+    assert_eq!(forward(&mut engine, "second.get(key)"), "\"second\"");
+    assert_eq!(forward(&mut engine, "first.get(first)"), "\"itself\"");
+    assert_eq!(forward(&mut engine, "first.has(key)"), "true");
+    assert_eq!(forward(&mut engine, "first.has({})"), "false");
+    assert_eq!(forward(&mut engine, "first.get(1)"), "undefined");
+    assert_eq!(forward(&mut engine, "first.has(1)"), "false");
+    assert_eq!(forward(&mut engine, "first.delete(key)"), "true");
+    assert_eq!(forward(&mut engine, "first.delete(key)"), "false");
+    assert_eq!(forward(&mut engine, "first.has(key)"), "false");
+    assert_eq!(forward(&mut engine, "second.has(key)"), "true");
+    assert_eq!(
+        forward(&mut engine, "try { first.set(1, 1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { WeakMap.prototype.get.call({}, key) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(first)"),
+        "\"[object WeakMap]\""
+    );
+}
+
+#[test]
+fn does_not_keep_keys_alive() {
+    let mut engine = Context::new();
+    let (key, key_dropped) = tracked_object();
+    let (value, value_dropped) = tracked_object();
+    let global = engine.global_object().clone();
+    global.set_field("key", key, &mut engine).unwrap();
+    global.set_field("value", value, &mut engine).unwrap();
+    forward(&mut engine, "var map = new WeakMap(); map.set(key, value);");
+    global
+        .set_field("value", Value::null(), &mut engine)
+        .unwrap();
+
+    force_collect();
+    assert!(!key_dropped.get());
+    assert!(!value_dropped.get());
+    assert_eq!(forward(&mut engine, "map.has(key)"), "true");
+
+    global.set_field("key", Value::null(), &mut engine).unwrap();
+    force_collect();
+    assert!(key_dropped.get());
+    assert!(value_dropped.get());
+}
+
+#[test]
+fn releases_values_of_dropped_maps() {
+    let mut engine = Context::new();
+    let (key, key_dropped) = tracked_object();
+    let (value, value_dropped) = tracked_object();
+    let global = engine.global_object().clone();
+    global.set_field("key", key, &mut engine).unwrap();
+    global.set_field("value", value, &mut engine).unwrap();
+    forward(
+        &mut engine,
+        "var map = new WeakMap(); map.set(key, value); var other = new WeakMap([[key, 1]]);",
+    );
+    global
+        .set_field("value", Value::null(), &mut engine)
+        .unwrap();
+    forward(&mut engine, "map = null;");
+
+    // The first collection frees the map, the second one its values.
+    force_collect();
+    force_collect();
+    assert!(value_dropped.get());
+    assert!(!key_dropped.get());
+    assert_eq!(forward(&mut engine, "other.get(key)"), "1");
+    assert_eq!(forward(&mut engine, "other.set(key, 2).get(key)"), "2");
+}
@@ -0,0 +1,175 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! A weak set is a collection of objects that does not keep them alive. Like for `WeakMap`,
+//! membership is recorded in the [`EphemeronTable`] of each value, so a `WeakSet` object only
+//! holds its [`WeakCollection`] identity.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+//! [`EphemeronTable`]: crate::gc::EphemeronTable
+//! [`WeakCollection`]: crate::gc::WeakCollection
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    gc::{WeakCollection, WeakCollectionId},
+    object::{ConstructorBuilder, ObjectData},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl BuiltIn for WeakSet {
+    const NAME: &'static str = "WeakSet";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let weak_set_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_set_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::add, "add", 1)
+        .method(Self::delete, "delete", 1)
+        .method(Self::has, "has", 1)
+        .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+        .callable(false)
+        .build();
+
+        (Self::NAME, weak_set_object.into(), Self::attribute())
+    }
+}
+
+impl WeakSet {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakSet( [ iterable ] )`
+    ///
+    /// Creates a new weak set, adding the values of `iterable` to it with its `add` method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset-iterable
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        this.set_data(ObjectData::WeakSet(WeakCollection::new()));
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(this.clone());
+        }
+
+        let adder = this.get_field("add", ctx)?;
+        if !adder.is_function() {
+            return ctx.throw_type_error("'add' of the WeakSet is not a function");
+        }
+
+        let iterator_record = get_iterator(ctx, iterable)?;
+        loop {
+            let next = iterator_record.next(ctx)?;
+            if next.is_done() {
+                break;
+            }
+            let status = ctx.call(&adder, this, &[next.value()]);
+            if status.is_err() {
+                return iterator_record.close(status, ctx);
+            }
+        }
+
+        Ok(this.clone())
+    }
+
+    /// Helper function to get the identity of `this`, if it is a weak set.
+    fn this_id(this: &Value, ctx: &mut Context) -> Result<WeakCollectionId> {
+        if let Value::Object(ref object) = this {
+            if let Some(id) = object.borrow().as_weak_set() {
+                return Ok(id);
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a WeakSet"))
+    }
+
+    /// `WeakSet.prototype.add( value )`
+    ///
+    /// This method adds the value, which must be an object, to the weak set. Returns the weak set object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let id = Self::this_id(this, ctx)?;
+
+        match args.get(0) {
+            Some(Value::Object(ref value)) => {
+                value
+                    .borrow_mut()
+                    .ephemerons_mut()
+                    .insert(id, Value::undefined());
+                Ok(this.clone())
+            }
+            _ => ctx.throw_type_error("Invalid value used in weak set"),
+        }
+    }
+
+    /// `WeakSet.prototype.delete( value )`
+    ///
+    /// This method removes the value from the weak set. Returns true if it was in the set, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let id = Self::this_id(this, ctx)?;
+
+        match args.get(0) {
+            Some(Value::Object(ref value)) => {
+                Ok(value.borrow_mut().ephemerons_mut().remove(id).into())
+            }
+            _ => Ok(false.into()),
+        }
+    }
+
+    /// `WeakSet.prototype.has( value )`
+    ///
+    /// This method checks if the weak set contains the given value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let id = Self::this_id(this, ctx)?;
+
+        match args.get(0) {
+            Some(Value::Object(ref value)) => {
+                Ok(value.borrow().ephemerons().get(id).is_some().into())
+            }
+            _ => Ok(false.into()),
+        }
+    }
+}
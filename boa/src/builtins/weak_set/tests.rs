@@ -0,0 +1,75 @@
+use crate::{
+    forward,
+    gc::{force_collect, tracking::tracked_object},
+    Context, Value,
+};
+
+#[test]
+fn construct() {
+    let mut engine = Context::new();
+    let init = r#"
+        let a = {};
+        let b = {};
+        let set = new WeakSet([a]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "set.has(a)"), "true");
+    assert_eq!(forward(&mut engine, "set.has(b)"), "false");
+    assert_eq!(
+        forward(&mut engine, "try { WeakSet() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new WeakSet([1]) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn add_has_delete() {
+    let mut engine = Context::new();
+    let init = r#"
+        let value = {};
+        let first = new WeakSet();
+        let second = new WeakSet();
+        first.add(value);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "first.has(value)"), "true");
+    assert_eq!(forward(&mut engine, "second.has(value)"), "false");
+    assert_eq!(forward(&mut engine, "first.add(value) === first"), "true");
+    assert_eq!(forward(&mut engine, "first.has(1)"), "false");
+    assert_eq!(forward(&mut engine, "first.delete(value)"), "true");
+    assert_eq!(forward(&mut engine, "first.delete(value)"), "false");
+    assert_eq!(forward(&mut engine, "first.has(value)"), "false");
+    assert_eq!(
+        forward(&mut engine, "try { first.add(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { WeakSet.prototype.has.call(new Set(), value) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn does_not_keep_values_alive() {
+    let mut engine = Context::new();
+    let (value, dropped) = tracked_object();
+    let global = engine.global_object().clone();
+    global.set_field("value", value, &mut engine).unwrap();
+    forward(&mut engine, "var set = new WeakSet([value]);");
+
+    force_collect();
+    assert!(!dropped.get());
+    assert_eq!(forward(&mut engine, "set.has(value)"), "true");
+
+    global
+        .set_field("value", Value::null(), &mut engine)
+        .unwrap();
+    force_collect();
+    assert!(dropped.get());
+}
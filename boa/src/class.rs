@@ -29,7 +29,7 @@
 //!         // This is equivalent to `String(arg)`.
 //!         let kind = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
 //!
-//!         let animal = match kind.as_str() {
+//!         let animal = match kind.to_std_string_lossy().as_str() {
 //!             "cat" => Self::Cat,
 //!             "dog" => Self::Dog,
 //!             _ => Self::Other,
@@ -61,8 +61,8 @@
 //! [class-trait]: ./trait.Class.html
 
 use crate::{
-    builtins::function::NativeFunction,
-    object::{ConstructorBuilder, GcObject, NativeObject, ObjectData},
+    builtins::function::ClosureFunction,
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, NativeObject, ObjectData},
     property::{Attribute, PropertyKey},
     Context, Result, Value,
 };
@@ -129,33 +129,59 @@ impl<'context> ClassBuilder<'context> {
 
     /// Add a method to the class.
     ///
-    /// It is added to `prototype`.
+    /// It is added to `prototype`. The method can be a function or a closure, see
+    /// [`ClosureFunction`][closure] for the state a closure can capture.
+    ///
+    /// [closure]: ../builtins/function/trait.ClosureFunction.html
     #[inline]
-    pub fn method<N>(&mut self, name: N, length: usize, function: NativeFunction) -> &mut Self
+    pub fn method<N, F>(&mut self, name: N, length: usize, function: F) -> &mut Self
     where
         N: AsRef<str>,
+        F: ClosureFunction,
     {
-        self.builder.method(function, name.as_ref(), length);
+        let function = self.build_method(name.as_ref(), length, function);
+        self.builder.property(
+            name.as_ref(),
+            function,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
         self
     }
 
     /// Add a static method to the class.
     ///
-    /// It is added to class object itself.
+    /// It is added to class object itself. The method can be a function or a closure, see
+    /// [`ClosureFunction`][closure] for the state a closure can capture.
+    ///
+    /// [closure]: ../builtins/function/trait.ClosureFunction.html
     #[inline]
-    pub fn static_method<N>(
-        &mut self,
-        name: N,
-        length: usize,
-        function: NativeFunction,
-    ) -> &mut Self
+    pub fn static_method<N, F>(&mut self, name: N, length: usize, function: F) -> &mut Self
     where
         N: AsRef<str>,
+        F: ClosureFunction,
     {
-        self.builder.static_method(function, name.as_ref(), length);
+        let function = self.build_method(name.as_ref(), length, function);
+        self.builder.static_property(
+            name.as_ref(),
+            function,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
         self
     }
 
+    /// Creates the function object of a method.
+    fn build_method<F>(&mut self, name: &str, length: usize, function: F) -> GcObject
+    where
+        F: ClosureFunction,
+    {
+        FunctionBuilder::closure(self.context(), function)
+            .name(name)
+            .length(length)
+            .callable(true)
+            .constructable(false)
+            .build()
+    }
+
     /// Add a property to the class, with the specified attribute.
     ///
     /// It is added to `prototype`.
@@ -3,14 +3,16 @@
 use crate::{
     builtins::{
         self,
-        function::{Function, FunctionFlags, NativeFunction},
+        array_buffer::ArrayBuffer,
+        function::{CapturingFunction, ClosureFunction, Function, FunctionFlags},
         iterable::IteratorPrototypes,
         symbol::{Symbol, WellKnownSymbols},
+        typed_array::TypedArrayKind,
     },
     class::{Class, ClassBuilder},
-    exec::Interpreter,
-    object::{GcObject, Object, ObjectData, PROTOTYPE},
-    property::{DataDescriptor, PropertyKey},
+    exec::{CallFrame, Interpreter},
+    object::{FunctionBuilder, GcObject, Object, PROTOTYPE},
+    property::{Attribute, DataDescriptor, PropertyKey},
     realm::Realm,
     syntax::{
         ast::{
@@ -18,14 +20,14 @@ use crate::{
                 statement_list::RcStatementList, Call, FormalParameter, Identifier, New,
                 StatementList,
             },
-            Const, Node,
+            Const, Node, NodeKind, Position,
         },
         Parser,
     },
     value::{RcString, RcSymbol, Value},
     BoaProfiler, Executable, Result,
 };
-use std::result::Result as StdResult;
+use std::collections::VecDeque;
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
@@ -83,6 +85,15 @@ pub struct StandardObjects {
     syntax_error: StandardConstructor,
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
+    aggregate_error: StandardConstructor,
+    promise: StandardConstructor,
+    set: StandardConstructor,
+    weak_map: StandardConstructor,
+    weak_set: StandardConstructor,
+    array_buffer: StandardConstructor,
+    data_view: StandardConstructor,
+    typed_array: StandardConstructor,
+    typed_arrays: [StandardConstructor; 11],
 }
 
 impl StandardObjects {
@@ -164,6 +175,53 @@ impl StandardObjects {
     pub fn uri_error_object(&self) -> &StandardConstructor {
         &self.uri_error
     }
+
+    #[inline]
+    pub fn aggregate_error_object(&self) -> &StandardConstructor {
+        &self.aggregate_error
+    }
+
+    #[inline]
+    pub fn promise_object(&self) -> &StandardConstructor {
+        &self.promise
+    }
+
+    #[inline]
+    pub fn set_object(&self) -> &StandardConstructor {
+        &self.set
+    }
+
+    #[inline]
+    pub fn weak_map_object(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn weak_set_object(&self) -> &StandardConstructor {
+        &self.weak_set
+    }
+
+    #[inline]
+    pub fn array_buffer_object(&self) -> &StandardConstructor {
+        &self.array_buffer
+    }
+
+    #[inline]
+    pub fn data_view_object(&self) -> &StandardConstructor {
+        &self.data_view
+    }
+
+    /// The `%TypedArray%` intrinsic object, the parent of the typed array constructors.
+    #[inline]
+    pub fn typed_array_object(&self) -> &StandardConstructor {
+        &self.typed_array
+    }
+
+    /// The constructor of the typed arrays of type `kind`, like `Uint8Array`.
+    #[inline]
+    pub fn typed_array_constructor(&self, kind: TypedArrayKind) -> &StandardConstructor {
+        &self.typed_arrays[kind as usize]
+    }
 }
 
 /// Javascript context. It is the primary way to interact with the runtime.
@@ -196,6 +254,20 @@ pub struct Context {
 
     /// Cached standard objects and their prototypes
     standard_objects: StandardObjects,
+
+    /// The jobs waiting to be run, like the reactions of settled promises.
+    ///
+    /// <https://tc39.es/ecma262/#sec-jobs>
+    job_queue: VecDeque<CapturingFunction>,
+
+    /// The `%eval%` intrinsic, used to recognize direct calls to `eval`.
+    eval_function: Option<GcObject>,
+
+    /// The frames of the functions that are being executed, the innermost one last.
+    call_stack: Vec<CallFrame>,
+
+    /// The name of the script that is being evaluated.
+    script_name: Option<RcString>,
 }
 
 impl Default for Context {
@@ -212,6 +284,10 @@ impl Default for Context {
             well_known_symbols,
             iterator_prototypes: IteratorPrototypes::default(),
             standard_objects: Default::default(),
+            job_queue: VecDeque::new(),
+            eval_function: None,
+            call_stack: Vec::new(),
+            script_name: None,
         };
 
         // Add new builtIns to Context Realm
@@ -241,6 +317,48 @@ impl Context {
         &mut self.executor
     }
 
+    /// Sets the `%eval%` intrinsic function.
+    #[inline]
+    pub(crate) fn set_eval_function(&mut self, function: GcObject) {
+        self.eval_function = Some(function);
+    }
+
+    /// Returns `true` if the value is the `%eval%` intrinsic function, that is, if a call to
+    /// `eval` that resolves to it is a direct eval.
+    #[inline]
+    pub(crate) fn is_eval_function(&self, value: &Value) -> bool {
+        match (value, &self.eval_function) {
+            (Value::Object(ref object), Some(ref eval)) => GcObject::equals(object, eval),
+            _ => false,
+        }
+    }
+
+    /// Gets the frames of the functions that are being executed, the innermost one last.
+    #[inline]
+    pub fn call_stack(&self) -> &[CallFrame] {
+        &self.call_stack
+    }
+
+    /// Pushes the frame of a function call, or of the global code of a script.
+    #[inline]
+    pub(crate) fn push_call_frame(&mut self, frame: CallFrame) {
+        self.call_stack.push(frame);
+    }
+
+    /// Pops the frame of the innermost function call, once it returns.
+    #[inline]
+    pub(crate) fn pop_call_frame(&mut self) {
+        self.call_stack.pop();
+    }
+
+    /// Records the position the innermost frame is executing at.
+    #[inline]
+    pub(crate) fn set_call_position(&mut self, position: Position) {
+        if let Some(frame) = self.call_stack.last_mut() {
+            frame.set_position(position);
+        }
+    }
+
     /// A helper function for getting a immutable reference to the `console` object.
     #[cfg(feature = "console")]
     pub(crate) fn console(&self) -> &Console {
@@ -279,11 +397,8 @@ impl Context {
     /// Construct an empty object.
     #[inline]
     pub fn construct_object(&self) -> GcObject {
-        let object_prototype = self
-            .global_object()
-            .get_field("Object")
-            .get_field(PROTOTYPE);
-        GcObject::new(Object::create(object_prototype))
+        let object_prototype = self.standard_objects().object_object().prototype();
+        GcObject::new(Object::create(object_prototype.into()))
     }
 
     /// <https://tc39.es/ecma262/#sec-call>
@@ -350,7 +465,7 @@ impl Context {
     {
         New::from(Call::new(
             Identifier::from("ReferenceError"),
-            vec![Const::from(message.into() + " is not defined").into()],
+            vec![Const::from(message.into()).into()],
         ))
         .run(self)
         .expect("Into<String> used as message")
@@ -428,23 +543,23 @@ impl Context {
     }
 
     /// Utility to create a function Value for Function Declarations, Arrow Functions or Function Expressions
-    pub(crate) fn create_function<P, B>(
+    ///
+    /// The `length` and `name` of the function are defined before its `prototype`, which only
+    /// constructors and generator functions have.
+    pub(crate) fn create_function<N, P, B>(
         &mut self,
+        name: N,
         params: P,
         body: B,
         flags: FunctionFlags,
+        source: Option<RcString>,
     ) -> Value
     where
+        N: Into<Value>,
         P: Into<Box<[FormalParameter]>>,
         B: Into<StatementList>,
     {
-        let function_prototype = self
-            .global_object()
-            .get_field("Function")
-            .get_field(PROTOTYPE);
-
-        // Every new function has a prototype property pre-made
-        let proto = Value::new_object(Some(self.global_object()));
+        let function_prototype = self.standard_objects().function_object().prototype();
 
         let params = params.into();
         let params_len = params.len();
@@ -453,136 +568,139 @@ impl Context {
             body: RcStatementList::from(body.into()),
             params,
             environment: self.realm.environment.get_current_environment().clone(),
+            home_object: None,
+            source,
+            script_name: self.script_name.clone(),
         };
 
-        let new_func = Object::function(func, function_prototype);
+        let new_func = Object::function(func, function_prototype.into());
 
         let val = Value::from(new_func);
 
-        // Set constructor field to the newly created Value (function object)
-        proto.set_field("constructor", val.clone());
+        val.set_property(
+            "length",
+            DataDescriptor::new(
+                params_len,
+                Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+        val.set_property(
+            "name",
+            DataDescriptor::new(
+                name,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
 
-        val.set_field(PROTOTYPE, proto);
-        val.set_field("length", Value::from(params_len));
+        let is_generator = flags.is_generator();
+        if flags.is_constructable() || is_generator {
+            let proto = Value::new_object(Some(self.global_object()));
+            if is_generator {
+                // The prototype of generator objects created by the function.
+                proto
+                    .as_object_mut()
+                    .expect("prototype object")
+                    .set_prototype_instance(self.iterator_prototypes().generator().into());
+            } else {
+                // Set constructor field to the newly created Value (function object)
+                proto.set_property(
+                    "constructor",
+                    DataDescriptor::new(
+                        val.clone(),
+                        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                    ),
+                );
+            }
+            val.set_property(
+                PROTOTYPE,
+                DataDescriptor::new(
+                    proto,
+                    Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+        }
 
         val
     }
 
     /// Create a new builin function.
-    pub fn create_builtin_function(
+    ///
+    /// The function can be a Rust function or a closure, see
+    /// [`ClosureFunction`](builtins/function/trait.ClosureFunction.html) for the state a
+    /// closure can capture.
+    pub fn create_builtin_function<F>(
         &mut self,
         name: &str,
         length: usize,
-        body: NativeFunction,
-    ) -> Result<GcObject> {
-        let function_prototype = self
-            .global_object()
-            .get_field("Function")
-            .get_field(PROTOTYPE);
+        body: F,
+    ) -> Result<GcObject>
+    where
+        F: ClosureFunction,
+    {
+        let function_prototype = self.standard_objects().function_object().prototype();
 
         // Every new function has a prototype property pre-made
         let proto = Value::new_object(Some(self.global_object()));
         let mut function = Object::function(
-            Function::BuiltIn(body.into(), FunctionFlags::CALLABLE),
-            function_prototype,
+            Function::Capturing(
+                CapturingFunction::from_closure(body),
+                FunctionFlags::CALLABLE,
+            ),
+            function_prototype.into(),
         );
-        function.set(PROTOTYPE.into(), proto);
-        function.set("length".into(), length.into());
-        function.set("name".into(), name.into());
+        let attribute = Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE;
+        function.insert_property(PROTOTYPE, proto, attribute);
+        function.insert_property("length", length, attribute);
+        function.insert_property("name", name, attribute);
 
         Ok(GcObject::new(function))
     }
 
     /// Register a global function.
-    pub fn register_global_function(
-        &mut self,
-        name: &str,
-        length: usize,
-        body: NativeFunction,
-    ) -> Result<()> {
-        let function = self.create_builtin_function(name, length, body)?;
-        self.global_object().set_field(name, function);
-        Ok(())
-    }
-
-    /// Converts an array object into a rust vector of values.
     ///
-    /// This is useful for the spread operator, for any other object an `Err` is returned
-    /// TODO: Not needed for spread of arrays. Check in the future for Map and remove if necessary
-    pub(crate) fn extract_array_properties(&mut self, value: &Value) -> StdResult<Vec<Value>, ()> {
-        if let Value::Object(ref x) = value {
-            // Check if object is array
-            if let ObjectData::Array = x.borrow().data {
-                let length = value.get_field("length").as_number().unwrap() as i32;
-                let values = (0..length)
-                    .map(|idx| value.get_field(idx.to_string()))
-                    .collect();
-                return Ok(values);
-            }
-            // Check if object is a Map
-            else if let ObjectData::Map(ref map) = x.borrow().data {
-                let values = map
-                    .iter()
-                    .map(|(key, value)| {
-                        // Construct a new array containing the key-value pair
-                        let array = Value::new_object(Some(
-                            &self
-                                .realm()
-                                .environment
-                                .get_global_object()
-                                .expect("Could not get global object"),
-                        ));
-                        array.set_data(ObjectData::Array);
-                        array
-                            .as_object_mut()
-                            .expect("object")
-                            .set_prototype_instance(
-                                self.realm()
-                                    .environment
-                                    .get_binding_value("Array")
-                                    .expect("Array was not initialized")
-                                    .get_field(PROTOTYPE),
-                            );
-                        array.set_field("0", key);
-                        array.set_field("1", value);
-                        array.set_field("length", Value::from(2));
-                        array
-                    })
-                    .collect();
-                return Ok(values);
-            }
-
-            return Err(());
-        }
-
-        Err(())
+    /// The function can be a Rust function or a closure, see
+    /// [`ClosureFunction`](builtins/function/trait.ClosureFunction.html) for the state a
+    /// closure can capture.
+    pub fn register_global_function<F>(&mut self, name: &str, length: usize, body: F) -> Result<()>
+    where
+        F: ClosureFunction,
+    {
+        let function = FunctionBuilder::closure(self, body)
+            .name(name)
+            .length(length)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let global = self.global_object().clone();
+        global.set_field(name, function, self)?;
+        Ok(())
     }
 
     /// https://tc39.es/ecma262/#sec-hasproperty
-    pub(crate) fn has_property(&self, obj: &Value, key: &PropertyKey) -> bool {
-        if let Some(obj) = obj.as_object() {
-            obj.has_property(key)
+    pub(crate) fn has_property(&mut self, obj: &Value, key: &PropertyKey) -> Result<bool> {
+        if let Value::Object(ref obj) = obj {
+            obj.has_property(key, self)
         } else {
-            false
+            Ok(false)
         }
     }
 
     pub(crate) fn set_value(&mut self, node: &Node, value: Value) -> Result<Value> {
-        match node {
-            Node::Identifier(ref name) => {
+        match node.kind() {
+            NodeKind::Identifier(ref name) => {
                 self.realm
                     .environment
                     .set_mutable_binding(name.as_ref(), value.clone(), true);
                 Ok(value)
             }
-            Node::GetConstField(ref get_const_field_node) => Ok(get_const_field_node
+            NodeKind::GetConstField(ref get_const_field_node) => get_const_field_node
                 .obj()
                 .run(self)?
-                .set_field(get_const_field_node.field(), value)),
-            Node::GetField(ref get_field) => {
+                .set_field(get_const_field_node.field(), value, self),
+            NodeKind::GetField(ref get_field) => {
                 let field = get_field.field().run(self)?;
                 let key = field.to_property_key(self)?;
-                Ok(get_field.obj().run(self)?.set_field(key, value))
+                get_field.obj().run(self)?.set_field(key, value, self)
             }
             _ => panic!("TypeError: invalid assignment to {}", node),
         }
@@ -617,6 +735,28 @@ impl Context {
         Ok(())
     }
 
+    /// Creates a new `ArrayBuffer` object that owns `data`.
+    ///
+    /// The bytes are not copied, and can be read and written from JavaScript through typed
+    /// arrays and `DataView`s.
+    ///
+    /// # Example
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let buffer = context.create_array_buffer(vec![1, 2, 3, 4]);
+    /// let global = context.global_object().clone();
+    /// global.set_field("buffer", buffer, &mut context).unwrap();
+    ///
+    /// let value = context.eval("new Uint8Array(buffer)[2]").unwrap();
+    /// assert_eq!(value.as_number(), Some(3.0));
+    /// ```
+    #[inline]
+    pub fn create_array_buffer(&mut self, data: Vec<u8>) -> GcObject {
+        ArrayBuffer::create(data, self)
+    }
+
     /// Evaluates the given code.
     ///
     /// # Examples
@@ -638,7 +778,13 @@ impl Context {
             .map_err(|e| e.to_string());
 
         let execution_result = match parsing_result {
-            Ok(statement_list) => statement_list.run(self),
+            Ok(statement_list) => {
+                // The global code of the script gets a frame like functions do.
+                self.push_call_frame(CallFrame::new(None, self.script_name.clone()));
+                let result = statement_list.run(self);
+                self.pop_call_frame();
+                result
+            }
             Err(e) => self.throw_syntax_error(e),
         };
 
@@ -649,6 +795,67 @@ impl Context {
         execution_result
     }
 
+    /// Evaluates the given code as the script named `script_name`.
+    ///
+    /// The name is recorded in the call frames of the code, so that it shows up in the `stack`
+    /// property of errors.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let value = context
+    ///     .eval_script("function f() { return new Error(); }\nf().stack", "main.js")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     value.as_string().unwrap().to_string(),
+    ///     "Error\n    at f (main.js:1:23)\n    at main.js:2:1"
+    /// );
+    /// ```
+    pub fn eval_script(&mut self, src: &str, script_name: &str) -> Result<Value> {
+        let previous = self.script_name.replace(script_name.into());
+        let result = self.eval(src);
+        self.script_name = previous;
+        result
+    }
+
+    /// Adds a job to the end of the job queue.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    pub(crate) fn enqueue_job(&mut self, job: CapturingFunction) {
+        self.job_queue.push_back(job);
+    }
+
+    /// Runs the pending jobs, like the reactions of settled promises, until the job queue is
+    /// empty.
+    ///
+    /// Jobs are never run by [`eval`](#method.eval), so this should be called once the script
+    /// has been evaluated. If a job throws, the error is returned and the remaining jobs stay in
+    /// the queue.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// context.eval("var result; Promise.resolve(2).then(x => { result = x * 2; });").unwrap();
+    /// assert!(context.eval("result").unwrap().is_undefined());
+    ///
+    /// context.run_jobs().unwrap();
+    /// assert_eq!(context.eval("result").unwrap().as_number().unwrap(), 4.0);
+    /// ```
+    pub fn run_jobs(&mut self) -> Result<()> {
+        while let Some(job) = self.job_queue.pop_front() {
+            job.call(&Value::undefined(), &[], self)?;
+        }
+        Ok(())
+    }
+
     /// Returns a structure that contains the JavaScript well known symbols.
     ///
     /// # Examples
@@ -657,7 +864,7 @@ impl Context {
     /// let mut context = Context::new();
     ///
     /// let iterator = context.well_known_symbols().iterator_symbol();
-    /// assert_eq!(iterator.description(), Some("Symbol.iterator"));
+    /// assert_eq!(iterator.description().unwrap(), "Symbol.iterator");
     /// ```
     /// This is equivalent to `let iterator = Symbol.iterator` in JavaScript.
     #[inline]
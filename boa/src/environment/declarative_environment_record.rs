@@ -8,8 +8,9 @@
 use crate::{
     environment::{
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
     },
+    object::GcObject,
     Value,
 };
 use gc::{Finalize, Trace};
@@ -149,14 +150,32 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         false
     }
 
-    fn get_this_binding(&self) -> Value {
-        Value::undefined()
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(Value::undefined())
+    }
+
+    fn bind_this_value(&mut self, _value: Value) -> Result<Value, EnvironmentError> {
+        Err(EnvironmentError::new(
+            "Cannot bind this value to a non function environment",
+        ))
     }
 
     fn has_super_binding(&self) -> bool {
         false
     }
 
+    fn get_super_base(&self) -> Value {
+        Value::undefined()
+    }
+
+    fn get_function_object(&self) -> Option<GcObject> {
+        None
+    }
+
+    fn get_new_target(&self) -> Value {
+        Value::undefined()
+    }
+
     fn with_base_object(&self) -> Value {
         Value::undefined()
     }
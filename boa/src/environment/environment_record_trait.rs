@@ -9,7 +9,8 @@
 //! There are 5 Environment record kinds. They all have methods in common, these are implemented as a the `EnvironmentRecordTrait`
 //!
 use crate::{
-    environment::lexical_environment::{Environment, EnvironmentType},
+    environment::lexical_environment::{Environment, EnvironmentError, EnvironmentType},
+    object::GcObject,
     Value,
 };
 use gc::{Finalize, Trace};
@@ -60,13 +61,35 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Return true if it does and false if it does not.
     fn has_this_binding(&self) -> bool;
 
-    /// Return the `this` binding from the environment
-    fn get_this_binding(&self) -> Value;
+    /// Return the `this` binding from the environment.
+    ///
+    /// An error is returned if the binding has not been initialized yet, which happens when `this`
+    /// is referenced in a derived constructor before `super()` was called.
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError>;
+
+    /// Bind the `this` value of a function Environment Record and return it.
+    ///
+    /// An error is returned if the environment does not have a `this` binding or if it was
+    /// already initialized.
+    fn bind_this_value(&mut self, value: Value) -> Result<Value, EnvironmentError>;
 
     /// Determine if an Environment Record establishes a super method binding.
     /// Return true if it does and false if it does not.
     fn has_super_binding(&self) -> bool;
 
+    /// Return the object on which `super` property lookups start.
+    ///
+    /// This is the prototype of the `[[HomeObject]]` of the function, or undefined if there is none.
+    fn get_super_base(&self) -> Value;
+
+    /// Return the function object whose invocation caused this Environment Record to be created, if any.
+    fn get_function_object(&self) -> Option<GcObject>;
+
+    /// Return the `[[NewTarget]]` of the environment.
+    ///
+    /// This is undefined unless the Environment Record was created by a `[[Construct]]` call.
+    fn get_new_target(&self) -> Value;
+
     /// If this Environment Record is associated with a with statement, return the with object.
     /// Otherwise, return undefined.
     fn with_base_object(&self) -> Value;
@@ -12,7 +12,7 @@ use crate::{
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecordBinding,
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
     },
     object::GcObject,
     Value,
@@ -59,31 +59,7 @@ pub struct FunctionEnvironmentRecord {
     pub outer_env: Option<Environment>,
 }
 
-impl FunctionEnvironmentRecord {
-    pub fn bind_this_value(&mut self, value: Value) {
-        match self.this_binding_status {
-            // You can not bind an arrow function, their `this` value comes from the lexical scope above
-            BindingStatus::Lexical => {
-                // TODO: change this when error handling comes into play
-                panic!("Cannot bind to an arrow function!");
-            }
-            // You can not bind a function twice
-            BindingStatus::Initialized => {
-                // TODO: change this when error handling comes into play
-                panic!("Reference Error: Cannot bind to an initialised function!");
-            }
-
-            BindingStatus::Uninitialized => {
-                self.this_value = value;
-                self.this_binding_status = BindingStatus::Initialized;
-            }
-        }
-    }
-}
-
 impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
-    // TODO: get_super_base can't implement until GetPrototypeof is implemented on object
-
     fn has_binding(&self, name: &str) -> bool {
         self.env_rec.contains_key(name)
     }
@@ -105,18 +81,35 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         );
     }
 
-    fn get_this_binding(&self) -> Value {
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
         match self.this_binding_status {
             BindingStatus::Lexical => {
                 // TODO: change this when error handling comes into play
                 panic!("There is no this for a lexical function record");
             }
-            BindingStatus::Uninitialized => {
+            BindingStatus::Uninitialized => Err(EnvironmentError::new(
+                "Must call super constructor in derived class before accessing 'this' or returning from derived constructor",
+            )),
+            BindingStatus::Initialized => Ok(self.this_value.clone()),
+        }
+    }
+
+    fn bind_this_value(&mut self, value: Value) -> Result<Value, EnvironmentError> {
+        match self.this_binding_status {
+            // You can not bind an arrow function, their `this` value comes from the lexical scope above
+            BindingStatus::Lexical => {
                 // TODO: change this when error handling comes into play
-                panic!("Reference Error: Uninitialised binding for this function");
+                panic!("Cannot bind to an arrow function!");
+            }
+            // You can not bind a function twice
+            BindingStatus::Initialized => Err(EnvironmentError::new(
+                "Super constructor may only be called once",
+            )),
+            BindingStatus::Uninitialized => {
+                self.this_value = value.clone();
+                self.this_binding_status = BindingStatus::Initialized;
+                Ok(value)
             }
-
-            BindingStatus::Initialized => self.this_value.clone(),
         }
     }
 
@@ -217,6 +210,21 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         }
     }
 
+    fn get_super_base(&self) -> Value {
+        match &self.home_object {
+            Value::Object(home) => home.borrow().prototype_instance().clone(),
+            _ => Value::undefined(),
+        }
+    }
+
+    fn get_function_object(&self) -> Option<GcObject> {
+        Some(self.function.clone())
+    }
+
+    fn get_new_target(&self) -> Value {
+        self.new_target.clone()
+    }
+
     fn has_this_binding(&self) -> bool {
         !matches!(self.this_binding_status, BindingStatus::Lexical)
     }
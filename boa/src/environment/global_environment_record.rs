@@ -11,9 +11,10 @@ use crate::{
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
         object_environment_record::ObjectEnvironmentRecord,
     },
+    object::GcObject,
     property::{Attribute, DataDescriptor},
     Value,
 };
@@ -90,8 +91,8 @@ impl GlobalEnvironmentRecord {
 }
 
 impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
-    fn get_this_binding(&self) -> Value {
-        self.global_this_binding.clone()
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(self.global_this_binding.clone())
     }
 
     fn has_binding(&self, name: &str) -> bool {
@@ -169,10 +170,28 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         true
     }
 
+    fn bind_this_value(&mut self, _value: Value) -> Result<Value, EnvironmentError> {
+        Err(EnvironmentError::new(
+            "Cannot bind this value to the global environment",
+        ))
+    }
+
     fn has_super_binding(&self) -> bool {
         false
     }
 
+    fn get_super_base(&self) -> Value {
+        Value::undefined()
+    }
+
+    fn get_function_object(&self) -> Option<GcObject> {
+        None
+    }
+
+    fn get_new_target(&self) -> Value {
+        Value::undefined()
+    }
+
     fn with_base_object(&self) -> Value {
         Value::undefined()
     }
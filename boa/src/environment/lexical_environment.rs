@@ -92,8 +92,12 @@ impl LexicalEnvironment {
     }
 
     pub fn push(&mut self, env: Environment) {
-        let current_env: Environment = self.get_current_environment().clone();
-        env.borrow_mut().set_outer_environment(current_env);
+        // Function environments already point to the scope the function was defined in,
+        // which must not be replaced by the scope of the caller.
+        if env.borrow().get_outer_environment().is_none() {
+            let current_env: Environment = self.get_current_environment().clone();
+            env.borrow_mut().set_outer_environment(current_env);
+        }
         self.environment_stack.push_back(env);
     }
 
@@ -101,8 +105,28 @@ impl LexicalEnvironment {
         self.environment_stack.pop_back()
     }
 
-    pub fn environments(&self) -> impl Iterator<Item = &Environment> {
-        self.environment_stack.iter().rev()
+    /// Returns the number of environments on the stack.
+    pub(crate) fn depth(&self) -> usize {
+        self.environment_stack.len()
+    }
+
+    /// Removes all the environments above the given depth and returns them.
+    ///
+    /// This is used to save the scopes of a suspended generator.
+    pub(crate) fn split_off(&mut self, depth: usize) -> Vec<Environment> {
+        self.environment_stack.split_off(depth).into()
+    }
+
+    /// Pushes back environments that were removed with [`split_off`](#method.split_off).
+    pub(crate) fn restore(&mut self, environments: Vec<Environment>) {
+        self.environment_stack.extend(environments);
+    }
+
+    /// Returns an iterator over the scope chain, starting from the current environment.
+    pub fn environments(&self) -> impl Iterator<Item = Environment> {
+        std::iter::successors(Some(self.get_current_environment_ref().clone()), |env| {
+            env.borrow().get_outer_environment()
+        })
     }
 
     pub fn get_global_object(&self) -> Option<Value> {
@@ -113,11 +137,23 @@ impl LexicalEnvironment {
             .get_global_object()
     }
 
-    pub fn get_this_binding(&self) -> Value {
+    pub fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
         self.environments()
             .find(|env| env.borrow().has_this_binding())
             .map(|env| env.borrow().get_this_binding())
-            .unwrap_or_else(Value::undefined)
+            .unwrap_or_else(|| Ok(Value::undefined()))
+    }
+
+    /// Returns the closest environment that has a `this` binding.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getthisenvironment
+    pub fn get_this_environment(&self) -> Environment {
+        self.environments()
+            .find(|env| env.borrow().has_this_binding())
+            .expect("The global environment always has a this binding")
     }
 
     pub fn create_mutable_binding(&mut self, name: String, deletion: bool, scope: VariableScope) {
@@ -166,7 +202,8 @@ impl LexicalEnvironment {
                     })
                     .expect("No function or global environment");
 
-                env.borrow_mut().create_immutable_binding(name, deletion)
+                let mut env = env.borrow_mut();
+                env.create_immutable_binding(name, deletion)
             }
         }
     }
@@ -234,19 +271,23 @@ pub fn new_function_environment(
     this: Option<Value>,
     outer: Option<Environment>,
     binding_status: BindingStatus,
+    home_object: Value,
+    new_target: Value,
 ) -> Environment {
     let mut func_env = FunctionEnvironmentRecord {
         env_rec: FxHashMap::default(),
         function: f,
         this_binding_status: binding_status,
-        home_object: Value::undefined(),
-        new_target: Value::undefined(),
+        home_object,
+        new_target,
         outer_env: outer, // this will come from Environment set as a private property of F - https://tc39.es/ecma262/#sec-ecmascript-function-objects
         this_value: Value::undefined(),
     };
     // If a `this` value has been passed, bind it to the environment
     if let Some(v) = this {
-        func_env.bind_this_value(v);
+        func_env
+            .bind_this_value(v)
+            .expect("A new function environment can always be bound");
     }
     Gc::new(GcCell::new(Box::new(func_env)))
 }
@@ -9,8 +9,9 @@
 use crate::{
     environment::{
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
     },
+    object::GcObject,
     property::{Attribute, DataDescriptor},
     Value,
 };
@@ -73,7 +74,8 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
 
     fn get_binding_value(&self, name: &str, strict: bool) -> Value {
         if self.bindings.has_field(name) {
-            self.bindings.get_field(name)
+            // TODO: call getters of the binding object, this needs access to the context.
+            self.bindings.get_data_field(name)
         } else {
             if strict {
                 // TODO: throw error here
@@ -92,14 +94,32 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         false
     }
 
-    fn get_this_binding(&self) -> Value {
-        Value::undefined()
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(Value::undefined())
+    }
+
+    fn bind_this_value(&mut self, _value: Value) -> Result<Value, EnvironmentError> {
+        Err(EnvironmentError::new(
+            "Cannot bind this value to a non function environment",
+        ))
     }
 
     fn has_super_binding(&self) -> bool {
         false
     }
 
+    fn get_super_base(&self) -> Value {
+        Value::undefined()
+    }
+
+    fn get_function_object(&self) -> Option<GcObject> {
+        None
+    }
+
+    fn get_new_target(&self) -> Value {
+        Value::undefined()
+    }
+
     fn with_base_object(&self) -> Value {
         // Object Environment Records return undefined as their
         // WithBaseObject unless their withEnvironment flag is true.
@@ -0,0 +1,81 @@
+//! This module implements the `CallFrame` structure, an entry of the call stack.
+
+use crate::{syntax::ast::Position, value::RcString};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+use std::fmt;
+
+/// A function call that is being executed, or the global code of a script.
+///
+/// The frames are recorded by the [`Context`](crate::Context) while the code runs, and errors
+/// keep a copy of them from the moment they were created.
+#[derive(Debug, Clone, PartialEq, Eq, Finalize)]
+pub struct CallFrame {
+    /// The name of the function, or `None` for anonymous functions and global code.
+    function_name: Option<RcString>,
+    /// The name of the script the code comes from.
+    script_name: Option<RcString>,
+    /// The position of the code that the frame is executing.
+    position: Option<Position>,
+}
+
+unsafe impl Trace for CallFrame {
+    unsafe_empty_trace!();
+}
+
+impl CallFrame {
+    /// Creates a new call frame, that hasn't made any call yet.
+    pub(crate) fn new(function_name: Option<RcString>, script_name: Option<RcString>) -> Self {
+        Self {
+            function_name,
+            script_name,
+            position: None,
+        }
+    }
+
+    /// Gets the name of the called function, `None` for anonymous functions and global code.
+    #[inline]
+    pub fn function_name(&self) -> Option<&RcString> {
+        self.function_name.as_ref()
+    }
+
+    /// Gets the name of the script the code of the frame comes from, if it has one.
+    #[inline]
+    pub fn script_name(&self) -> Option<&RcString> {
+        self.script_name.as_ref()
+    }
+
+    /// Gets the position in the script where the frame is executing.
+    ///
+    /// This is the start of the last node that was executed, or of the call that created the
+    /// next frame. It is `None` for built-in functions.
+    #[inline]
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Sets the position where the frame is executing.
+    #[inline]
+    pub(crate) fn set_position(&mut self, position: Position) {
+        self.position = Some(position);
+    }
+}
+
+impl fmt::Display for CallFrame {
+    /// Formats the frame like a line of the `stack` property of errors, as `name (script:1:2)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.function_name() {
+            write!(f, "{} (", name)?;
+        }
+        match self.script_name {
+            Some(ref script_name) => write!(f, "{}", script_name)?,
+            None => f.write_str("<anonymous>")?,
+        }
+        if let Some(position) = self.position {
+            write!(f, ":{}", position)?;
+        }
+        if self.function_name.is_some() {
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
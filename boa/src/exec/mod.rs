@@ -1,16 +1,20 @@
 //! Execution of the AST, this is where the interpreter actually runs
 
+mod call_frame;
 #[cfg(test)]
 mod tests;
 
+pub use self::call_frame::CallFrame;
+
 use crate::{Context, Result, Value};
+use gc::{custom_trace, Finalize, Trace};
 
 pub trait Executable {
     /// Runs this executable in the given context.
     fn run(&self, interpreter: &mut Context) -> Result<Value>;
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum InterpreterState {
     Executing,
     Return,
@@ -18,11 +22,56 @@ pub(crate) enum InterpreterState {
     Continue(Option<Box<str>>),
 }
 
+/// The progress of a node whose evaluation was suspended by a `yield` expression.
+///
+/// When a generator yields, every node between the `yield` and the generator body records how
+/// far it got while the stack unwinds. Resuming the generator runs the same nodes again, and
+/// each of them picks its frame back up instead of starting over.
+#[derive(Debug, Clone, Default, Finalize)]
+pub(crate) struct SuspendedFrame {
+    /// The step of its evaluation the node was at.
+    pub(crate) step: usize,
+    /// The intermediate values the node had already computed.
+    pub(crate) values: Vec<Value>,
+}
+
+// Implemented by hand so that the frame does not get a `Drop` implementation, which allows
+// moving the values out of it.
+unsafe impl Trace for SuspendedFrame {
+    custom_trace!(this, {
+        mark(&this.values);
+    });
+}
+
+impl SuspendedFrame {
+    /// Creates a new suspended frame.
+    pub(crate) fn new(step: usize, values: Vec<Value>) -> Self {
+        Self { step, values }
+    }
+}
+
+/// The way a suspended generator is resumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResumeKind {
+    Next,
+    Throw,
+    Return,
+}
+
 /// A Javascript intepreter
 #[derive(Debug)]
 pub struct Interpreter {
     /// the current state of the interpreter.
     state: InterpreterState,
+
+    /// Whether the stack is being unwound because a generator yielded.
+    suspending: bool,
+
+    /// The frames of the nodes that are being suspended or resumed.
+    frames: Vec<SuspendedFrame>,
+
+    /// The value sent to a suspended generator, and how it was resumed.
+    resume_value: Option<(ResumeKind, Value)>,
 }
 
 impl Default for Interpreter {
@@ -36,6 +85,9 @@ impl Interpreter {
     pub fn new() -> Self {
         Self {
             state: InterpreterState::Executing,
+            suspending: false,
+            frames: Vec::new(),
+            resume_value: None,
         }
     }
 
@@ -48,4 +100,81 @@ impl Interpreter {
     pub(crate) fn get_current_state(&self) -> &InterpreterState {
         &self.state
     }
+
+    /// Returns `true` if the stack is being unwound because a generator yielded.
+    #[inline]
+    pub(crate) fn is_suspending(&self) -> bool {
+        self.suspending
+    }
+
+    /// Suspends the running generator, yielding `value`.
+    ///
+    /// The returned error unwinds the stack up to the generator, `frame` is the frame of the
+    /// node that yielded.
+    pub(crate) fn suspend(&mut self, frame: SuspendedFrame, value: Value) -> Result<Value> {
+        self.suspending = true;
+        self.frames.push(frame);
+        Err(value)
+    }
+
+    /// Saves the frame of a node if `result` is unwinding a suspended generator.
+    ///
+    /// The frame is only built when it is needed.
+    #[inline]
+    pub(crate) fn save_frame<T, F>(&mut self, result: Result<T>, frame: F) -> Result<T>
+    where
+        F: FnOnce() -> SuspendedFrame,
+    {
+        if result.is_err() && self.suspending {
+            self.frames.push(frame());
+        }
+        result
+    }
+
+    /// Takes the frame of the node that is being resumed, if a generator is being resumed.
+    #[inline]
+    pub(crate) fn resume_frame(&mut self) -> Option<SuspendedFrame> {
+        if self.suspending {
+            None
+        } else {
+            self.frames.pop()
+        }
+    }
+
+    /// Takes the value the running generator was resumed with.
+    pub(crate) fn take_resume_value(&mut self) -> (ResumeKind, Value) {
+        self.resume_value
+            .take()
+            .unwrap_or((ResumeKind::Next, Value::undefined()))
+    }
+
+    /// Prepares the interpreter to run a generator with the given saved frames.
+    ///
+    /// Returns the frames of the outer evaluation, which must be given back to
+    /// [`leave_generator`](#method.leave_generator).
+    pub(crate) fn enter_generator(
+        &mut self,
+        frames: Vec<SuspendedFrame>,
+        resume_value: Option<(ResumeKind, Value)>,
+    ) -> Vec<SuspendedFrame> {
+        self.resume_value = resume_value;
+        std::mem::replace(&mut self.frames, frames)
+    }
+
+    /// Restores the outer evaluation after running a generator.
+    ///
+    /// Returns the frames of the generator if it was suspended, or `None` if it completed.
+    pub(crate) fn leave_generator(
+        &mut self,
+        outer_frames: Vec<SuspendedFrame>,
+    ) -> Option<Vec<SuspendedFrame>> {
+        self.resume_value = None;
+        let frames = std::mem::replace(&mut self.frames, outer_frames);
+        if self.suspending {
+            self.suspending = false;
+            Some(frames)
+        } else {
+            None
+        }
+    }
 }
@@ -663,6 +663,18 @@ fn unary_delete() {
     assert_eq!(&exec(delete_recursive), "true");
 }
 
+#[test]
+fn unary_delete_does_not_read_property() {
+    let scenario = r#"
+        let reads = 0;
+        const a = { get b() { reads++; return 1; } };
+        const c = delete a.b;
+        const d = delete a["b"];
+        [c, d, reads, "b" in a].join()
+    "#;
+    assert_eq!(&exec(scenario), "\"true,true,0,false\"");
+}
+
 #[cfg(test)]
 mod in_operator {
     use super::*;
@@ -782,7 +794,7 @@ mod in_operator {
         let foo_val = forward_val(&mut engine, "Foo").unwrap();
         assert!(bar_obj
             .prototype_instance()
-            .strict_equals(&foo_val.get_field("prototype")));
+            .strict_equals(&foo_val.get_field("prototype", &mut engine).unwrap()));
     }
 }
 
@@ -1517,3 +1529,454 @@ fn test_strict_mode_dup_func_parameters() {
 
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
+
+#[test]
+fn closure_captures_outer_scope() {
+    let scenario = r#"
+        function counter() {
+            let count = 0;
+            return function() {
+                count += 1;
+                return count;
+            };
+        }
+        let next = counter();
+        next();
+        next();
+        "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn class_declaration_constructor_and_methods() {
+    let scenario = r#"
+        class Point {
+            constructor(x, y) {
+                this.x = x;
+                this.y = y;
+            }
+
+            sum() {
+                return this.x + this.y;
+            }
+        }
+        new Point(3, 4).sum();
+        "#;
+
+    assert_eq!(&exec(scenario), "7");
+}
+
+#[test]
+fn class_computed_method_names() {
+    let scenario = r#"
+        var key = "gre" + "et";
+        class List {
+            constructor() {
+                this.items = [1, 2];
+            }
+            [key]() {
+                return "hello";
+            }
+            *[Symbol.iterator]() {
+                yield* this.items;
+            }
+            static get [key + "ing"]() {
+                return "hi";
+            }
+        }
+        var list = new List();
+        [
+            list.greet(),
+            [...list].join(),
+            List.greeting,
+            List.prototype[Symbol.iterator].name,
+        ].join(" ");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"hello 1,2 hi [Symbol.iterator]\"");
+}
+
+#[test]
+fn object_computed_property_names() {
+    let scenario = r#"
+        var i = 0;
+        var obj = {
+            ["a" + ++i]: i,
+            [Symbol.toStringTag]: "Custom",
+            ["b" + ++i]() { return i; },
+        };
+        [obj.a1, obj.b2(), obj.b2.name, String(obj)].join(" ");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1 2 b2 [object Custom]\"");
+}
+
+#[test]
+fn class_extends_builtin_constructors() {
+    let scenario = r#"
+        class List extends Array {
+            sum() {
+                return this.reduce((a, b) => a + b, 0);
+            }
+        }
+        class Registry extends Map {
+            names() {
+                var names = [];
+                this.forEach((value, key) => names.push(key));
+                return names.join();
+            }
+        }
+        var list = new List(1, 2, 3);
+        var registry = new Registry([["a", 1], ["b", 2]]);
+        [
+            list instanceof List,
+            list instanceof Array,
+            Array.isArray(list),
+            list.length,
+            list.sum(),
+            registry instanceof Registry,
+            registry.size,
+            registry.names(),
+        ].join(" ");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true true true 3 6 true 2 a,b\"");
+}
+
+#[test]
+fn class_static_methods() {
+    let scenario = r#"
+        class A {
+            static create() {
+                return new A();
+            }
+        }
+        typeof A.create() + " " + typeof A.prototype.create;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"object undefined\"");
+}
+
+#[test]
+fn class_getters_and_setters() {
+    let scenario = r#"
+        class Temperature {
+            constructor() {
+                this.celsius = 0;
+            }
+            get fahrenheit() {
+                return this.celsius * 1.8 + 32;
+            }
+            set fahrenheit(value) {
+                this.celsius = (value - 32) / 1.8;
+            }
+        }
+        let t = new Temperature();
+        t.fahrenheit = 212;
+        t.celsius + t.fahrenheit;
+        "#;
+
+    assert_eq!(&exec(scenario), "312");
+}
+
+#[test]
+fn class_constructor_without_new() {
+    let scenario = r#"
+        class A {}
+        try {
+            A();
+        } catch (e) {
+            e.message;
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"Class constructor A cannot be invoked without 'new'\""
+    );
+}
+
+#[test]
+fn class_extends_super_call() {
+    let scenario = r#"
+        class Animal {
+            constructor(name) {
+                this.name = name;
+            }
+            speak() {
+                return this.name + " makes a sound";
+            }
+        }
+        class Dog extends Animal {
+            constructor(name) {
+                super(name);
+                this.kind = "dog";
+            }
+            speak() {
+                return super.speak() + " (woof)";
+            }
+        }
+        let dog = new Dog("Rex");
+        dog.speak() + ", " + dog.kind;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"Rex makes a sound (woof), dog\"");
+}
+
+#[test]
+fn class_default_derived_constructor() {
+    let scenario = r#"
+        class A {
+            constructor(a, b) {
+                this.total = a + b;
+            }
+        }
+        class B extends A {}
+        new B(1, 2).total;
+        "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn class_super_property_getter() {
+    let scenario = r#"
+        class A {
+            get value() {
+                return this.base;
+            }
+        }
+        class B extends A {
+            constructor() {
+                super();
+                this.base = 20;
+            }
+            get value() {
+                return super.value + 1;
+            }
+        }
+        new B().value;
+        "#;
+
+    assert_eq!(&exec(scenario), "21");
+}
+
+#[test]
+fn class_this_before_super_call() {
+    let scenario = r#"
+        class A {}
+        class B extends A {
+            constructor() {
+                this.x = 1;
+                super();
+            }
+        }
+        try {
+            new B();
+        } catch (e) {
+            e.name;
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "\"ReferenceError\"");
+}
+
+#[test]
+fn class_extends_invalid_value() {
+    let scenario = r#"
+        try {
+            class A extends 5 {}
+        } catch (e) {
+            e.message;
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"Class extends value 5 is not a constructor or null\""
+    );
+}
+
+#[test]
+fn class_expression_name_is_local() {
+    let scenario = r#"
+        let A = class Inner {
+            name() {
+                return Inner.name;
+            }
+        };
+        new A().name();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"Inner\"");
+}
+
+#[test]
+fn class_constructor_returning_object() {
+    let scenario = r#"
+        class A {
+            constructor() {
+                this.a = 1;
+                return { b: 2 };
+            }
+        }
+        let a = new A();
+        a.b + " " + a.a;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2 undefined\"");
+}
+
+#[test]
+fn object_destructuring_declaration() {
+    let scenario = r#"
+        var { a, b: c = 5, ...rest } = { a: 1, x: 2, y: 3 };
+        const { m: { n } } = { m: { n: 4 } };
+        a + " " + c + " " + rest.a + " " + (rest.x + rest.y) + " " + n;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1 5 undefined 5 4\"");
+}
+
+#[test]
+fn array_destructuring_declaration() {
+    let scenario = r#"
+        let [a, , b = 3, ...rest] = [1, 2, undefined, 4, 5];
+        a + " " + b + " " + rest.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1 3 4,5\"");
+}
+
+#[test]
+fn destructuring_null_throws() {
+    let scenario = r#"
+        try {
+            let { a } = null;
+        } catch (e) {
+            e.name;
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "\"TypeError\"");
+}
+
+#[test]
+fn destructuring_parameters() {
+    let scenario = r#"
+        function f({ x, y } = { x: 1, y: 2 }, [z] = [3]) {
+            return x + y + z;
+        }
+        let g = ([a, b], { c = 10 }) => a * b + c;
+        f() + " " + f({ x: 10, y: 20 }, [30]) + " " + g([2, 3], {});
+        "#;
+
+    assert_eq!(&exec(scenario), "\"6 60 16\"");
+}
+
+#[test]
+fn default_and_rest_parameters() {
+    let scenario = r#"
+        function f(a, b = a + 1, ...rest) {
+            return b + ":" + rest.length;
+        }
+        f(1) + " " + f(1, 5, 6, 7);
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2:0 5:2\"");
+}
+
+#[test]
+fn destructuring_catch_parameter() {
+    let scenario = r#"
+        try {
+            throw { code: 7 };
+        } catch ({ code }) {
+            code;
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "7");
+}
+
+#[test]
+fn destructuring_for_of() {
+    let scenario = r#"
+        let result = "";
+        for (const [k, v] of [[1, 2], [3, 4]]) {
+            result += k + v;
+        }
+        result;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"37\"");
+}
+
+#[test]
+fn destructuring_assignment() {
+    let scenario = r#"
+        let a = 1, b = 2;
+        [a, b] = [b, a];
+        let o = {};
+        ({ x: o.x, y: o.y = "d" } = { x: "c" });
+        a + " " + b + " " + o.x + " " + o.y;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2 1 c d\"");
+}
+
+#[test]
+fn destructuring_computed_keys() {
+    let scenario = r#"
+        let k = "b", sym = Symbol("s");
+        var { [k]: v, ["c" + 1]: w = 3, [sym]: x, ...rest } = { a: 1, b: 2, [sym]: 4 };
+        let o = {};
+        ({ [k + k]: o.y } = { bb: 5 });
+        v + " " + w + " " + x + " " + Object.keys(rest).join() + " " + o.y;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2 3 4 a 5\"");
+}
+
+#[test]
+fn object_shorthand_and_spread() {
+    let scenario = r#"
+        let a = 1, b = 2;
+        let o = { a, b };
+        let p = { ...o, c: 3 };
+        p.a + p.b + p.c;
+        "#;
+
+    assert_eq!(&exec(scenario), "6");
+}
+
+#[test]
+fn object_literal_accessors() {
+    let scenario = r#"
+        let log = [];
+        let o = {
+            _v: 1,
+            get v() { log.push("get"); return this._v; },
+            set v(x) { log.push("set " + x); this._v = x; },
+        };
+        o.v = 5;
+        log.push(o.v);
+        log.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"set 5,get,5\"");
+}
+
+#[test]
+fn inherited_accessors_and_read_only_properties() {
+    let scenario = r#"
+        let proto = { set v(x) { this._v = x * 2; }, get r() { return 1; } };
+        let o = Object.create(proto);
+        o.v = 2;
+        o.r = 3;
+        [o._v, o.hasOwnProperty("v"), o.r, o.hasOwnProperty("r")].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"4,false,1,false\"");
+}
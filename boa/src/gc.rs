@@ -4,8 +4,177 @@
 // when it should be `empty_trace`.
 #![allow(clippy::unsafe_removed_from_name)]
 
+use crate::Value;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::{self, Debug},
+};
+
 pub use crate::object::GcObject;
 pub use ::gc::{
     custom_trace, force_collect, unsafe_empty_trace as empty_trace, Finalize, GcCellRef as Ref,
     GcCellRefMut as RefMut, Trace,
 };
+
+/// The identity of a weak collection, a `WeakMap` or a `WeakSet`.
+///
+/// Identifiers are never reused, so the entries left behind by a collection that has been
+/// garbage collected can not be observed by a newer one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Finalize)]
+pub struct WeakCollectionId(u64);
+
+unsafe impl Trace for WeakCollectionId {
+    empty_trace!();
+}
+
+impl WeakCollectionId {
+    /// Creates a new unique identifier.
+    pub(crate) fn new() -> Self {
+        thread_local! {
+            static NEXT_ID: Cell<u64> = const { Cell::new(0) };
+        }
+
+        NEXT_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            Self(id)
+        })
+    }
+}
+
+thread_local! {
+    /// The collections that have been dropped.
+    ///
+    /// Their entries may still be stored in the tables of their keys, which stop tracing them.
+    /// The identifiers are never reused, so this set is never pruned.
+    static DEAD_COLLECTIONS: RefCell<FxHashSet<WeakCollectionId>> = RefCell::default();
+}
+
+impl WeakCollectionId {
+    /// Returns `true` if the collection has not been dropped.
+    #[inline]
+    fn is_alive(self) -> bool {
+        DEAD_COLLECTIONS.with(|dead| !dead.borrow().contains(&self))
+    }
+}
+
+/// The identity of a weak collection, owned by the collection object.
+///
+/// Dropping it records that the collection is dead, which releases its entries.
+#[derive(Debug, Finalize)]
+pub struct WeakCollection(WeakCollectionId);
+
+unsafe impl Trace for WeakCollection {
+    empty_trace!();
+}
+
+impl WeakCollection {
+    /// Creates a weak collection with a new identifier.
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self(WeakCollectionId::new())
+    }
+
+    /// Returns the identifier of the collection.
+    #[inline]
+    pub(crate) fn id(&self) -> WeakCollectionId {
+        self.0
+    }
+}
+
+impl Drop for WeakCollection {
+    fn drop(&mut self) {
+        // The set is already gone if this is dropped while the thread shuts down.
+        let _ = DEAD_COLLECTIONS.try_with(|dead| dead.borrow_mut().insert(self.0));
+    }
+}
+
+/// The entries of weak collections that are keyed by an object.
+///
+/// `rust-gc` has no support for ephemerons, so a weak collection can not hold its keys weakly
+/// while keeping their values alive. Instead the entries are stored inverted, in a table owned
+/// by the key object and indexed by the collection. A value is then traced only while its key
+/// is reachable, and the collection never holds a reference to its keys.
+///
+/// The table does not know when a collection becomes unreachable, so this only approximates
+/// ephemerons: entries of a collection that has been dropped are no longer traced, which frees
+/// their values in the first garbage collection after the one that freed the collection. The
+/// entries themselves are purged the next time the table is modified. A dead entry may hold a
+/// value that has already been freed, so it must never be read.
+#[derive(Default, Finalize)]
+pub struct EphemeronTable(FxHashMap<WeakCollectionId, Value>);
+
+unsafe impl Trace for EphemeronTable {
+    custom_trace!(this, {
+        for (id, value) in &this.0 {
+            if id.is_alive() {
+                mark(value);
+            }
+        }
+    });
+}
+
+impl Debug for EphemeronTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().filter(|(id, _)| id.is_alive()))
+            .finish()
+    }
+}
+
+impl EphemeronTable {
+    /// Returns the value of the entry of the collection `id`, if there is one.
+    #[inline]
+    pub(crate) fn get(&self, id: WeakCollectionId) -> Option<&Value> {
+        self.0.get(&id)
+    }
+
+    /// Inserts or replaces the entry of the collection `id`.
+    #[inline]
+    pub(crate) fn insert(&mut self, id: WeakCollectionId, value: Value) {
+        self.purge();
+        self.0.insert(id, value);
+    }
+
+    /// Removes the entry of the collection `id`, returning `true` if there was one.
+    #[inline]
+    pub(crate) fn remove(&mut self, id: WeakCollectionId) -> bool {
+        self.purge();
+        self.0.remove(&id).is_some()
+    }
+
+    /// Removes the entries of the collections that have been dropped.
+    fn purge(&mut self) {
+        self.0.retain(|id, _| id.is_alive());
+    }
+}
+
+/// Objects for tests that observe when the garbage collector frees them.
+#[cfg(test)]
+pub(crate) mod tracking {
+    use super::{empty_trace, Finalize, GcObject, Trace};
+    use crate::{object::Object, Value};
+    use std::{cell::Cell, rc::Rc};
+
+    /// A native object that records when it is dropped.
+    #[derive(Debug, Finalize)]
+    struct DropFlag(Rc<Cell<bool>>);
+
+    unsafe impl Trace for DropFlag {
+        empty_trace!();
+    }
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    /// Creates an object that sets the returned flag when it is freed.
+    pub(crate) fn tracked_object() -> (Value, Rc<Cell<bool>>) {
+        let dropped = Rc::new(Cell::new(false));
+        let object = GcObject::new(Object::native_object(DropFlag(dropped.clone())));
+        (object.into(), dropped)
+    }
+}
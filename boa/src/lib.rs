@@ -42,6 +42,10 @@ This is an experimental Javascript lexer, parser and compiler written in Rust. C
     missing_doc_code_examples
 )]
 
+// Allows the `boa_derive` macros, which refer to `::boa`, to be used in the tests of this crate.
+#[cfg(test)]
+extern crate self as boa;
+
 pub mod builtins;
 pub mod class;
 pub mod environment;
@@ -4,15 +4,22 @@
 
 use super::{Object, PROTOTYPE};
 use crate::{
-    builtins::function::{
-        create_unmapped_arguments_object, BuiltInFunction, Function, NativeFunction,
+    builtins::{
+        async_function::AsyncFunction,
+        function::{
+            create_unmapped_arguments_object, BuiltInFunction, CapturingFunction, Function,
+            FunctionFlags, NativeFunction,
+        },
+        generator::Generator,
     },
     environment::{
-        function_environment_record::BindingStatus, lexical_environment::new_function_environment,
+        function_environment_record::BindingStatus,
+        lexical_environment::{new_function_environment, Environment},
     },
+    exec::{CallFrame, InterpreterState},
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
-    syntax::ast::node::RcStatementList,
-    value::PreferredType,
+    syntax::ast::node::{FormalParameter, RcStatementList},
+    value::{PreferredType, RcString},
     Context, Executable, Result, Value,
 };
 use gc::{Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace};
@@ -25,6 +32,15 @@ use std::{
     result::Result as StdResult,
 };
 
+/// The integrity level an object can be locked to, with `Object.seal` or `Object.freeze`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityLevel {
+    /// The object isn't extensible and its properties are non-configurable.
+    Sealed,
+    /// The object is sealed and its data properties are non-writable as well.
+    Frozen,
+}
+
 /// A wrapper type for an immutably borrowed `Object`.
 pub type Ref<'object> = GcCellRef<'object, Object>;
 
@@ -38,8 +54,15 @@ pub struct GcObject(Gc<GcCell<Object>>);
 // This is needed for the call method since we cannot mutate the function itself since we
 // already borrow it so we get the function body clone it then drop the borrow and run the body
 enum FunctionBody {
-    BuiltIn(NativeFunction),
-    Ordinary(RcStatementList),
+    BuiltInFunction(NativeFunction),
+    BuiltInConstructor(NativeFunction),
+    Ordinary {
+        body: RcStatementList,
+        params: Box<[FormalParameter]>,
+        environment: Environment,
+        flags: FunctionFlags,
+    },
+    Capturing(CapturingFunction),
 }
 
 impl GcObject {
@@ -103,6 +126,18 @@ impl GcObject {
         std::ptr::eq(lhs.as_ref(), rhs.as_ref())
     }
 
+    /// Returns `true` if the object is a function that can be called.
+    #[inline]
+    pub fn is_callable(&self) -> bool {
+        self.borrow().is_callable()
+    }
+
+    /// Returns `true` if the object is a function that can be used as a constructor.
+    #[inline]
+    pub fn is_constructable(&self) -> bool {
+        self.borrow().is_constructable()
+    }
+
     /// Call this object.
     ///
     ///# Panics
@@ -110,51 +145,158 @@ impl GcObject {
     // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
     // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-call-thisargument-argumentslist>
     #[track_caller]
+    #[inline]
     pub fn call(&self, this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        self.call_construct(this, args, ctx, false)
+    }
+
+    /// Construct an instance of this object with the specified arguments.
+    ///
+    /// The `new_target` is the constructor that `new` was initially applied to, its `prototype`
+    /// property is used as the prototype of the newly created object.
+    ///
+    ///# Panics
+    /// Panics if the object is currently mutably borrowed.
+    // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget>
+    #[track_caller]
+    #[inline]
+    pub fn construct(
+        &self,
+        args: &[Value],
+        new_target: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        self.call_construct(new_target, args, ctx, true)
+    }
+
+    /// Internal implementation of [`call`](#method.call) and [`construct`](#method.construct).
+    ///
+    /// When `construct` is `true`, `this_target` is the `new_target` of the construction,
+    /// otherwise it is the `this` value of the call.
+    #[track_caller]
+    fn call_construct(
+        &self,
+        this_target: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+        construct: bool,
+    ) -> Result<Value> {
+        if let Some(proxy) = self.proxy() {
+            return if construct {
+                proxy.construct(args, this_target, ctx)
+            } else {
+                proxy.call(this_target, args, ctx)
+            };
+        }
+
+        // <https://tc39.es/ecma262/#sec-bound-function-exotic-objects-call-thisargument-argumentslist>
+        let bound = self.borrow().as_bound_function().cloned();
+        if let Some(bound) = bound {
+            let target = bound.target_function();
+            let args: Vec<Value> = bound.args().iter().chain(args).cloned().collect();
+            return if construct {
+                // <https://tc39.es/ecma262/#sec-bound-function-exotic-objects-construct-argumentslist-newtarget>
+                let new_target = match this_target {
+                    Value::Object(ref object) if GcObject::equals(object, self) => {
+                        target.clone().into()
+                    }
+                    _ => this_target.clone(),
+                };
+                target.construct(&args, &new_target, ctx)
+            } else {
+                target.call(bound.this(), &args, ctx)
+            };
+        }
+
         let this_function_object = self.clone();
-        let f_body = if let Some(function) = self.borrow().as_function() {
-            if function.is_callable() {
-                match function {
-                    Function::BuiltIn(BuiltInFunction(function), _) => {
-                        FunctionBody::BuiltIn(*function)
+        let mut script_name = None;
+        let body = if let Some(function) = self.borrow().as_function() {
+            if construct && !function.is_constructable() {
+                let name = self.get(&"name".into(), &self.clone().into(), ctx)?;
+                let name = name.as_string().map(|name| name.to_string());
+                return ctx.throw_type_error(format!(
+                    "{} is not a constructor",
+                    name.unwrap_or_default()
+                ));
+            } else if !construct && !function.is_callable() {
+                return ctx.throw_type_error("function object is not callable");
+            }
+
+            match function {
+                Function::BuiltIn(BuiltInFunction(function), _) => {
+                    if construct {
+                        FunctionBody::BuiltInConstructor(*function)
+                    } else {
+                        FunctionBody::BuiltInFunction(*function)
                     }
-                    Function::Ordinary {
-                        body,
-                        params,
-                        environment,
-                        flags,
-                    } => {
-                        // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
-                        // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
-                        let local_env = new_function_environment(
-                            this_function_object,
-                            if flags.is_lexical_this_mode() {
-                                None
-                            } else {
-                                Some(this.clone())
-                            },
-                            Some(environment.clone()),
-                            // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
-                            if flags.is_lexical_this_mode() {
-                                BindingStatus::Lexical
-                            } else {
-                                BindingStatus::Uninitialized
-                            },
-                        );
-
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, ctx, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function.add_arguments_to_environment(param, value, &local_env);
-                        }
-
-                        // Add arguments object
+                }
+                Function::Capturing(function, _) => FunctionBody::Capturing(function.clone()),
+                Function::Ordinary {
+                    body,
+                    params,
+                    environment,
+                    flags,
+                    home_object,
+                    script_name: function_script_name,
+                    ..
+                } => {
+                    script_name = function_script_name.clone();
+
+                    if !construct && flags.is_class_constructor() {
+                        let name = self.get(&"name".into(), &self.clone().into(), ctx)?;
+                        let name = name.as_string().map(|name| name.to_string());
+                        return ctx.throw_type_error(format!(
+                            "Class constructor {} cannot be invoked without 'new'",
+                            name.unwrap_or_default()
+                        ));
+                    }
+
+                    let this = if flags.is_lexical_this_mode() || (construct && flags.is_derived())
+                    {
+                        // Arrow functions take `this` from the enclosing scope and derived
+                        // constructors get it from the `super()` call.
+                        None
+                    } else if construct {
+                        // <https://tc39.es/ecma262/#sec-ordinarycreatefromconstructor>
+                        let prototype = this_target.get_field(PROTOTYPE, ctx)?;
+                        let prototype = if prototype.is_object() {
+                            prototype
+                        } else {
+                            ctx.standard_objects().object_object().prototype().into()
+                        };
+                        Some(Object::create(prototype).into())
+                    } else {
+                        Some(this_target.clone())
+                    };
+
+                    // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
+                    // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
+                    let local_env = new_function_environment(
+                        this_function_object,
+                        this,
+                        Some(environment.clone()),
+                        // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
+                        if flags.is_lexical_this_mode() {
+                            BindingStatus::Lexical
+                        } else {
+                            BindingStatus::Uninitialized
+                        },
+                        home_object
+                            .clone()
+                            .map(Value::from)
+                            .unwrap_or_else(Value::undefined),
+                        if construct {
+                            this_target.clone()
+                        } else {
+                            Value::undefined()
+                        },
+                    );
+
+                    // Add arguments object, unless a parameter shadows it
+                    if !params
+                        .iter()
+                        .any(|param| param.binding().name() == Some("arguments"))
+                    {
                         let arguments_obj = create_unmapped_arguments_object(args);
                         local_env
                             .borrow_mut()
@@ -162,113 +304,506 @@ impl GcObject {
                         local_env
                             .borrow_mut()
                             .initialize_binding("arguments", arguments_obj);
+                    }
 
-                        ctx.realm_mut().environment.push(local_env);
-
-                        FunctionBody::Ordinary(body.clone())
+                    // The parameters are bound once the function object is no longer borrowed,
+                    // as evaluating default values can run arbitrary code.
+                    FunctionBody::Ordinary {
+                        body: body.clone(),
+                        params: params.clone(),
+                        environment: local_env,
+                        flags: *flags,
                     }
                 }
-            } else {
-                return ctx.throw_type_error("function object is not callable");
             }
         } else {
             return ctx.throw_type_error("not a function");
         };
 
-        match f_body {
-            FunctionBody::BuiltIn(func) => func(this, args, ctx),
-            FunctionBody::Ordinary(body) => {
+        ctx.push_call_frame(CallFrame::new(self.function_name(), script_name));
+        let result = self.call_body(body, this_target, args, ctx, construct);
+        ctx.pop_call_frame();
+        result
+    }
+
+    /// Gets the `name` of a function object for its call frame, if it is a non-empty string.
+    fn function_name(&self) -> Option<RcString> {
+        let name = self.borrow().get_own_property(&"name".into())?;
+        match name.as_data_descriptor()?.value() {
+            Value::String(ref name) if !name.is_empty() => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    /// Runs the body of a function, once its call frame is pushed.
+    fn call_body(
+        &self,
+        body: FunctionBody,
+        this_target: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+        construct: bool,
+    ) -> Result<Value> {
+        match body {
+            FunctionBody::BuiltInFunction(function) => function(this_target, args, ctx),
+            FunctionBody::Capturing(function) => function.call(this_target, args, ctx),
+            FunctionBody::BuiltInConstructor(function) => {
+                let prototype = this_target.get_field(PROTOTYPE, ctx)?;
+                let prototype = if prototype.is_object() {
+                    prototype
+                } else {
+                    ctx.standard_objects().object_object().prototype().into()
+                };
+                let this: Value = Object::create(prototype).into();
+                function(&this, args, ctx)?;
+                Ok(this)
+            }
+            FunctionBody::Ordinary {
+                body,
+                params,
+                environment,
+                flags,
+            } => {
+                // Add argument bindings to the function environment
+                ctx.realm_mut().environment.push(environment);
+                if let Err(err) = Function::bind_parameters(&params, args, ctx) {
+                    ctx.realm_mut().environment.pop();
+                    return Err(err);
+                }
+
+                if flags.is_generator() {
+                    // The body of a generator runs later, when the generator is resumed.
+                    // <https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluatebody>
+                    let environment = ctx
+                        .realm_mut()
+                        .environment
+                        .pop()
+                        .expect("function environment");
+                    let prototype = self.get(&PROTOTYPE.into(), &self.clone().into(), ctx)?;
+                    let prototype = if prototype.is_object() {
+                        prototype
+                    } else {
+                        ctx.iterator_prototypes().generator().into()
+                    };
+                    let call_frame = ctx.call_stack().last().cloned();
+                    return Ok(Generator::create(
+                        ctx,
+                        prototype,
+                        body,
+                        environment,
+                        call_frame,
+                    ));
+                } else if flags.is_async() {
+                    // The body of an async function runs until the first `await`, and then
+                    // from the job queue.
+                    let environment = ctx
+                        .realm_mut()
+                        .environment
+                        .pop()
+                        .expect("function environment");
+                    return AsyncFunction::start(ctx, body, environment);
+                }
+
                 let result = body.run(ctx);
+                let returned =
+                    matches!(ctx.executor().get_current_state(), InterpreterState::Return);
+                ctx.executor()
+                    .set_current_state(InterpreterState::Executing);
+
+                // local_env gets dropped here, its no longer needed
+                let this = if construct {
+                    Some(ctx.realm().environment.get_this_binding())
+                } else {
+                    None
+                };
                 ctx.realm_mut().environment.pop();
 
-                result
+                let result = result?;
+                match this {
+                    None => Ok(result),
+                    Some(_) if returned && result.is_object() => Ok(result),
+                    Some(_) if returned && flags.is_derived() && !result.is_undefined() => ctx
+                        .throw_type_error(
+                            "Derived constructors may only return object or undefined",
+                        ),
+                    Some(this) => {
+                        this.map_err(|err| ctx.construct_reference_error(err.to_string()))
+                    }
+                }
             }
         }
     }
 
-    /// Construct an instance of this object with the specified arguments.
+    /// `[[Get]]` with a receiver, calling the getter if the property is an accessor.
     ///
-    ///# Panics
-    /// Panics if the object is currently mutably borrowed.
-    // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget>
-    #[track_caller]
-    pub fn construct(&self, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let this: Value = Object::create(self.borrow().get(&PROTOTYPE.into())).into();
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver
+    pub fn get(&self, key: &PropertyKey, receiver: &Value, ctx: &mut Context) -> Result<Value> {
+        if let Some(proxy) = self.proxy() {
+            return proxy.get(key, receiver, ctx);
+        }
+        if let Some((array, index)) = self.typed_array_index(key) {
+            return Ok(array.get_element(index).unwrap_or_default());
+        }
 
-        let this_function_object = self.clone();
-        let body = if let Some(function) = self.borrow().as_function() {
-            if function.is_constructable() {
-                match function {
-                    Function::BuiltIn(BuiltInFunction(function), _) => {
-                        FunctionBody::BuiltIn(*function)
-                    }
-                    Function::Ordinary {
-                        body,
-                        params,
-                        environment,
-                        flags,
-                    } => {
-                        // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
-                        // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
-                        let local_env = new_function_environment(
-                            this_function_object,
-                            Some(this.clone()),
-                            Some(environment.clone()),
-                            // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
-                            if flags.is_lexical_this_mode() {
-                                BindingStatus::Lexical
-                            } else {
-                                BindingStatus::Uninitialized
-                            },
-                        );
-
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, ctx, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function.add_arguments_to_environment(param, value, &local_env);
-                        }
-
-                        // Add arguments object
-                        let arguments_obj = create_unmapped_arguments_object(args);
-                        local_env
-                            .borrow_mut()
-                            .create_mutable_binding("arguments".to_string(), false);
-                        local_env
-                            .borrow_mut()
-                            .initialize_binding("arguments", arguments_obj);
+        let desc = self.borrow().get_own_property(key);
+        match &desc {
+            None => {
+                // parent will either be null or an Object
+                let parent = self.borrow().get_prototype_of();
+                match parent {
+                    Value::Object(ref parent) => parent.get(key, receiver, ctx),
+                    _ => Ok(Value::undefined()),
+                }
+            }
+            Some(PropertyDescriptor::Data(desc)) => Ok(desc.value()),
+            Some(PropertyDescriptor::Accessor(desc)) => match desc.getter() {
+                Some(getter) => getter.call(receiver, &[], ctx),
+                None => Ok(Value::undefined()),
+            },
+        }
+    }
 
-                        ctx.realm_mut().environment.push(local_env);
+    /// `[[Set]]` with a receiver, calling the setter if the property is an accessor.
+    ///
+    /// Returns `false` if the property could not be set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-set-p-v-receiver
+    pub fn set(
+        &self,
+        key: PropertyKey,
+        value: Value,
+        receiver: &Value,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        if let Some(proxy) = self.proxy() {
+            return proxy.set(key, value, receiver, ctx);
+        }
+        if let Some((array, index)) = self.typed_array_index(&key) {
+            if let Value::Object(ref receiver) = receiver {
+                if GcObject::equals(self, receiver) {
+                    array.set_element(index, &value, ctx)?;
+                    return Ok(true);
+                }
+            }
+        }
+
+        let desc = self.borrow().get_own_property(&key);
+        match &desc {
+            None => {
+                let parent = self.borrow().get_prototype_of();
+                match parent {
+                    Value::Object(ref parent) => parent.set(key, value, receiver, ctx),
+                    _ => Self::set_on_receiver(key, value, receiver, ctx),
+                }
+            }
+            Some(PropertyDescriptor::Data(desc)) => {
+                if !desc.writable() {
+                    return Ok(false);
+                }
+                Self::set_on_receiver(key, value, receiver, ctx)
+            }
+            Some(PropertyDescriptor::Accessor(desc)) => match desc.setter() {
+                Some(setter) => {
+                    setter.call(receiver, &[value], ctx)?;
+                    Ok(true)
+                }
+                None => Ok(false),
+            },
+        }
+    }
+
+    /// Writes a data property on the receiver of a `[[Set]]`, keeping the attributes of an
+    /// existing own property.
+    ///
+    /// Writing an index of an array past its end grows its `length`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinarysetwithowndescriptor
+    fn set_on_receiver(
+        key: PropertyKey,
+        value: Value,
+        receiver: &Value,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        let receiver = match receiver {
+            Value::Object(ref receiver) => receiver,
+            _ => return Ok(false),
+        };
+        let existing = receiver.get_own_property(&key, ctx)?;
+        let desc = match existing {
+            Some(PropertyDescriptor::Data(ref existing)) if existing.writable() => {
+                DataDescriptor::new(value, existing.attributes())
+            }
+            Some(_) => return Ok(false),
+            None => DataDescriptor::new(
+                value,
+                Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        };
+        receiver.define_own_property(key, desc.into(), ctx)
+    }
+
+    /// `CreateDataPropertyOrThrow( O, P, V )`
+    ///
+    /// Defines a writable, enumerable and configurable data property, throwing a `TypeError`
+    /// if it can't be defined.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createdatapropertyorthrow
+    pub(crate) fn create_data_property_or_throw<K, V>(
+        &self,
+        key: K,
+        value: V,
+        ctx: &mut Context,
+    ) -> Result<()>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        let key = key.into();
+        let desc = DataDescriptor::new(value, Attribute::all());
+        if !self.define_own_property(key.clone(), desc.into(), ctx)? {
+            return Err(ctx.construct_type_error(format!("cannot define property '{}'", key)));
+        }
+        Ok(())
+    }
+
+    /// `Set( O, P, V, true )`
+    ///
+    /// Sets a property with this object as the receiver, throwing a `TypeError` if it can't be
+    /// set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-o-p-v-throw
+    pub(crate) fn set_or_throw<K, V>(&self, key: K, value: V, ctx: &mut Context) -> Result<()>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        let key = key.into();
+        if !self.set(key.clone(), value.into(), &self.clone().into(), ctx)? {
+            return Err(ctx.construct_type_error(format!("cannot assign to property '{}'", key)));
+        }
+        Ok(())
+    }
+
+    /// `DeletePropertyOrThrow( O, P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-deletepropertyorthrow
+    pub(crate) fn delete_property_or_throw<K>(&self, key: K, ctx: &mut Context) -> Result<()>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        if !self.delete(&key, ctx)? {
+            return Err(ctx.construct_type_error(format!("cannot delete property '{}'", key)));
+        }
+        Ok(())
+    }
 
-                        FunctionBody::Ordinary(body.clone())
+    /// `SetIntegrityLevel( O, level )`
+    ///
+    /// Returns `false` if the object can't be made non-extensible.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+    pub(crate) fn set_integrity_level(
+        &self,
+        level: IntegrityLevel,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        if !self.prevent_extensions(ctx)? {
+            return Ok(false);
+        }
+        for key in self.own_property_keys(ctx)? {
+            let desc = match self.get_own_property(&key, ctx)? {
+                Some(PropertyDescriptor::Data(ref data)) => {
+                    let mut data = data.clone();
+                    if level == IntegrityLevel::Frozen {
+                        data.set_writable(false);
                     }
+                    data.set_configurable(false);
+                    PropertyDescriptor::from(data)
                 }
-            } else {
-                let name = this.get_field("name").display().to_string();
-                return ctx.throw_type_error(format!("{} is not a constructor", name));
+                Some(PropertyDescriptor::Accessor(ref accessor)) => {
+                    let mut accessor = accessor.clone();
+                    accessor.set_configurable(false);
+                    PropertyDescriptor::from(accessor)
+                }
+                None => continue,
+            };
+            if !self.define_own_property(key.clone(), desc, ctx)? {
+                return Err(ctx.construct_type_error(format!("cannot redefine property '{}'", key)));
             }
-        } else {
-            return ctx.throw_type_error("not a function");
+        }
+        Ok(true)
+    }
+
+    /// `TestIntegrityLevel( O, level )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-testintegritylevel
+    pub(crate) fn test_integrity_level(
+        &self,
+        level: IntegrityLevel,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        if self.is_extensible(ctx)? {
+            return Ok(false);
+        }
+        for key in self.own_property_keys(ctx)? {
+            if let Some(desc) = self.get_own_property(&key, ctx)? {
+                if desc.configurable() {
+                    return Ok(false);
+                }
+                let writable = desc
+                    .as_data_descriptor()
+                    .map_or(false, DataDescriptor::writable);
+                if level == IntegrityLevel::Frozen && writable {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// `LengthOfArrayLike( obj )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-lengthofarraylike
+    pub(crate) fn length_of_array_like(&self, ctx: &mut Context) -> Result<usize> {
+        self.get(&"length".into(), &self.clone().into(), ctx)?
+            .to_length(ctx)
+    }
+
+    /// `SpeciesConstructor( O, defaultConstructor )`
+    ///
+    /// Returns the constructor to use to create objects derived from this one, which is the
+    /// `@@species` of its `constructor`, or `default` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-speciesconstructor
+    pub(crate) fn species_constructor(
+        &self,
+        default: GcObject,
+        ctx: &mut Context,
+    ) -> Result<GcObject> {
+        let constructor = self.get(&"constructor".into(), &self.clone().into(), ctx)?;
+        let constructor = match constructor {
+            Value::Undefined => return Ok(default),
+            Value::Object(ref constructor) => constructor.clone(),
+            _ => return Err(ctx.construct_type_error("object constructor is not an object")),
         };
 
-        match body {
-            FunctionBody::BuiltIn(function) => {
-                function(&this, args, ctx)?;
-                Ok(this)
+        let species = ctx.well_known_symbols().species_symbol();
+        let species = constructor.get(&species.into(), &constructor.clone().into(), ctx)?;
+        match species {
+            Value::Undefined | Value::Null => Ok(default),
+            Value::Object(ref object) if object.is_constructable() => Ok(object.clone()),
+            _ => Err(ctx.construct_type_error("object constructor species is not a constructor")),
+        }
+    }
+
+    /// Checks if `object` inherits from the `prototype` property of this function, using the
+    /// default algorithm of `instanceof`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryhasinstance
+    pub fn ordinary_has_instance(&self, object: &Value, ctx: &mut Context) -> Result<bool> {
+        let bound_target = self
+            .borrow()
+            .as_bound_function()
+            .map(|bound| bound.target_function().clone());
+        if let Some(target) = bound_target {
+            return object.instance_of(&target.into(), ctx);
+        }
+        // Built-in constructors that throw when called without `new` are still functions.
+        if !self.borrow().is_function() {
+            return Ok(false);
+        }
+
+        let mut object = match object {
+            Value::Object(ref object) => object.clone(),
+            _ => return Ok(false),
+        };
+        let prototype = self.get(&PROTOTYPE.into(), &self.clone().into(), ctx)?;
+        let prototype = match prototype {
+            Value::Object(ref prototype) => prototype.clone(),
+            _ => {
+                return Err(ctx
+                    .construct_type_error("function has non-object prototype in instanceof check"))
             }
-            FunctionBody::Ordinary(body) => {
-                let _ = body.run(ctx);
+        };
 
-                // local_env gets dropped here, its no longer needed
-                let binding = ctx.realm_mut().environment.get_this_binding();
-                Ok(binding)
+        loop {
+            let parent = object.get_prototype_of(ctx)?;
+            match parent {
+                Value::Object(ref parent) if GcObject::equals(parent, &prototype) => {
+                    return Ok(true)
+                }
+                Value::Object(ref parent) => object = parent.clone(),
+                _ => return Ok(false),
+            }
+        }
+    }
+
+    /// Copies the own enumerable properties of `source` onto this object, skipping the
+    /// `excluded` keys.
+    ///
+    /// Nothing is copied if `source` is `null` or `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-copydataproperties
+    pub(crate) fn copy_data_properties(
+        &self,
+        source: &Value,
+        excluded: &[PropertyKey],
+        ctx: &mut Context,
+    ) -> Result<()> {
+        if source.is_null_or_undefined() {
+            return Ok(());
+        }
+        let from = source.to_object(ctx)?;
+        let keys = from.own_property_keys(ctx)?;
+        for key in keys {
+            if excluded.contains(&key) {
+                continue;
+            }
+            let desc = from.get_own_property(&key, ctx)?;
+            if desc.map_or(false, |desc| desc.enumerable()) {
+                let value = from.get(&key, source, ctx)?;
+                self.borrow_mut()
+                    .insert_property(key, value, Attribute::all());
             }
         }
+        Ok(())
     }
 
     /// Converts an object to a primitive.
@@ -327,7 +862,7 @@ impl GcObject {
         let this = Value::from(self.clone());
         for name in &method_names {
             // a. Let method be ? Get(O, name).
-            let method: Value = this.get_field(*name);
+            let method: Value = this.get_field(*name, interpreter)?;
             // b. If IsCallable(method) is true, then
             if method.is_function() {
                 // i. Let result be ? Call(method, O).
@@ -349,12 +884,12 @@ impl GcObject {
         if rec_limiter.live {
             Err(interpreter.construct_type_error("cyclic object value"))
         } else if self.borrow().is_array() {
-            let mut keys: Vec<u32> = self.borrow().index_property_keys().cloned().collect();
+            let mut keys: Vec<u32> = self.borrow().index_property_keys().collect();
             keys.sort_unstable();
             let mut arr: Vec<JSONValue> = Vec::with_capacity(keys.len());
             let this = Value::from(self.clone());
             for key in keys {
-                let value = this.get_field(key);
+                let value = this.get_field(key, interpreter)?;
                 if value.is_undefined() || value.is_function() || value.is_symbol() {
                     arr.push(JSONValue::Null);
                 } else {
@@ -363,11 +898,12 @@ impl GcObject {
             }
             Ok(JSONValue::Array(arr))
         } else {
+            // The map keeps the insertion order, so the properties are serialized in the order
+            // of their keys.
             let mut new_obj = Map::new();
             let this = Value::from(self.clone());
-            for k in self.borrow().keys() {
-                let key = k.clone();
-                let value = this.get_field(k.to_string());
+            for key in self.own_property_keys(interpreter)? {
+                let value = this.get_field(key.clone(), interpreter)?;
                 if !value.is_undefined() && !value.is_function() && !value.is_symbol() {
                     new_obj.insert(key.to_string(), value.to_json(interpreter)?);
                 }
@@ -376,46 +912,55 @@ impl GcObject {
         }
     }
 
+    /// Converts this object to a property descriptor, reading its fields with `[[Get]]`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-topropertydescriptor
     pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PropertyDescriptor> {
+        let this = Value::from(self.clone());
         let mut attribute = Attribute::empty();
 
         let enumerable_key = PropertyKey::from("enumerable");
-        if self.borrow().has_property(&enumerable_key)
-            && self.borrow().get(&enumerable_key).to_boolean()
+        if self.has_property(&enumerable_key, context)?
+            && this.get_field(enumerable_key, context)?.to_boolean()
         {
             attribute |= Attribute::ENUMERABLE;
         }
 
         let configurable_key = PropertyKey::from("configurable");
-        if self.borrow().has_property(&configurable_key)
-            && self.borrow().get(&configurable_key).to_boolean()
+        if self.has_property(&configurable_key, context)?
+            && this.get_field(configurable_key, context)?.to_boolean()
         {
             attribute |= Attribute::CONFIGURABLE;
         }
 
         let mut value = None;
         let value_key = PropertyKey::from("value");
-        if self.borrow().has_property(&value_key) {
-            value = Some(self.borrow().get(&value_key));
+        if self.has_property(&value_key, context)? {
+            value = Some(this.get_field(value_key, context)?);
         }
 
         let mut has_writable = false;
         let writable_key = PropertyKey::from("writable");
-        if self.borrow().has_property(&writable_key) {
+        if self.has_property(&writable_key, context)? {
             has_writable = true;
-            if self.borrow().get(&writable_key).to_boolean() {
+            if this.get_field(writable_key, context)?.to_boolean() {
                 attribute |= Attribute::WRITABLE;
             }
         }
 
+        let mut has_accessor = false;
         let mut get = None;
         let get_key = PropertyKey::from("get");
-        if self.borrow().has_property(&get_key) {
-            let getter = self.borrow().get(&get_key);
-            match getter {
-                Value::Object(ref object) if object.borrow().is_callable() => {
+        if self.has_property(&get_key, context)? {
+            has_accessor = true;
+            match this.get_field(get_key, context)? {
+                Value::Object(ref object) if object.is_callable() => {
                     get = Some(object.clone());
                 }
+                Value::Undefined => {}
                 _ => {
                     return Err(
                         context.construct_type_error("Property descriptor getter must be callable")
@@ -426,12 +971,13 @@ impl GcObject {
 
         let mut set = None;
         let set_key = PropertyKey::from("set");
-        if self.borrow().has_property(&set_key) {
-            let setter = self.borrow().get(&set_key);
-            match setter {
-                Value::Object(ref object) if object.borrow().is_callable() => {
+        if self.has_property(&set_key, context)? {
+            has_accessor = true;
+            match this.get_field(set_key, context)? {
+                Value::Object(ref object) if object.is_callable() => {
                     set = Some(object.clone());
                 }
+                Value::Undefined => {}
                 _ => {
                     return Err(
                         context.construct_type_error("Property descriptor setter must be callable")
@@ -440,7 +986,7 @@ impl GcObject {
             };
         }
 
-        if get.is_some() || set.is_some() {
+        if has_accessor {
             if value.is_some() || has_writable {
                 return Err(context.construct_type_error("Invalid property descriptor. Cannot both specify accessors and a value or writable attribute"));
             }
@@ -0,0 +1,318 @@
+//! This module implements the storage of the integer indexed properties of an object.
+//!
+//! Most indexed properties belong to arrays that are filled from the start and only ever hold
+//! plain data properties. Those are stored in a `Vec<Value>`, which avoids hashing on every
+//! element access. As soon as an object gets a hole or an element with a non-default descriptor,
+//! the storage falls back to a sparse map of property descriptors. It is not made dense again
+//! when the holes get filled, only once every indexed property has been removed, so code that
+//! walks the elements of a sparse array should only visit the indices that are present.
+
+use crate::{
+    gc::{custom_trace, Finalize, Trace},
+    property::{Attribute, DataDescriptor, PropertyDescriptor},
+    value::Value,
+};
+use gc::Gc;
+use rustc_hash::FxHashMap;
+use std::{
+    cell::UnsafeCell,
+    collections::hash_map,
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    slice,
+};
+
+/// The storage of the integer indexed properties of an `Object`.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) enum IndexedProperties {
+    /// The elements `0..len` are all writable, enumerable and configurable data properties.
+    Dense(DenseElements),
+    /// Indexed properties with holes or with non-default attributes.
+    Sparse(FxHashMap<u32, PropertyDescriptor>),
+}
+
+impl Default for IndexedProperties {
+    #[inline]
+    fn default() -> Self {
+        Self::Dense(DenseElements::default())
+    }
+}
+
+impl IndexedProperties {
+    /// Gets the property descriptor of the given index.
+    #[inline]
+    pub(crate) fn get(&self, index: u32) -> Option<PropertyDescriptor> {
+        match self {
+            Self::Dense(ref elements) => elements
+                .as_slice()
+                .get(index as usize)
+                .map(|value| DataDescriptor::new(value.clone(), Attribute::all()).into()),
+            Self::Sparse(ref map) => map.get(&index).cloned(),
+        }
+    }
+
+    /// Inserts a property, returning the previous one if there was any.
+    ///
+    /// The dense storage is kept as long as the property is a default data property that
+    /// replaces an existing element or is appended right after the last one.
+    pub(crate) fn insert(
+        &mut self,
+        index: u32,
+        property: PropertyDescriptor,
+    ) -> Option<PropertyDescriptor> {
+        if let Self::Dense(ref mut elements) = self {
+            let position = index as usize;
+            if let PropertyDescriptor::Data(ref data) = property {
+                if data.attributes() == Attribute::all() && position <= elements.len() {
+                    let value = data.value();
+                    if position == elements.len() {
+                        elements.push(value);
+                        return None;
+                    }
+                    let old = elements.replace(position, value);
+                    return Some(DataDescriptor::new(old, Attribute::all()).into());
+                }
+            }
+            self.convert_to_sparse();
+        }
+
+        match self {
+            Self::Sparse(ref mut map) => map.insert(index, property),
+            Self::Dense(_) => unreachable!("indexed properties were converted to sparse"),
+        }
+    }
+
+    /// Removes a property, returning it if it existed.
+    pub(crate) fn remove(&mut self, index: u32) -> Option<PropertyDescriptor> {
+        match self {
+            Self::Dense(ref mut elements) => {
+                let position = index as usize;
+                if position >= elements.len() {
+                    return None;
+                }
+                if position + 1 == elements.len() {
+                    let value = elements.pop()?;
+                    return Some(DataDescriptor::new(value, Attribute::all()).into());
+                }
+                self.convert_to_sparse();
+                self.remove(index)
+            }
+            Self::Sparse(ref mut map) => {
+                let property = map.remove(&index);
+                if map.is_empty() {
+                    *self = Self::default();
+                }
+                property
+            }
+        }
+    }
+
+    /// Removes every array index greater or equal than `len`, starting from the highest one.
+    /// `u32::MAX` isn't an array index, so it is kept.
+    ///
+    /// If a non-configurable property is found the removal stops there and its index is
+    /// returned as the error.
+    pub(crate) fn truncate(&mut self, len: u32) -> Result<(), u32> {
+        match self {
+            Self::Dense(ref mut elements) => {
+                elements.truncate(len as usize);
+                Ok(())
+            }
+            Self::Sparse(ref mut map) => {
+                let mut indices: Vec<u32> = map
+                    .keys()
+                    .copied()
+                    .filter(|index| *index >= len && *index != u32::MAX)
+                    .collect();
+                indices.sort_unstable_by(|a, b| b.cmp(a));
+                let mut result = Ok(());
+                for index in indices {
+                    if !map[&index].configurable() {
+                        result = Err(index);
+                        break;
+                    }
+                    map.remove(&index);
+                }
+                if map.is_empty() {
+                    *self = Self::default();
+                }
+                result
+            }
+        }
+    }
+
+    /// An iterator visiting all index-property pairs. The element type is `(u32, PropertyDescriptor)`.
+    ///
+    /// Dense elements are visited in ascending order, sparse ones in arbitrary order.
+    #[inline]
+    pub(crate) fn iter(&self) -> IndexedPropertiesIter<'_> {
+        match self {
+            Self::Dense(ref elements) => {
+                IndexedPropertiesIter::Dense(elements.as_slice().iter().enumerate())
+            }
+            Self::Sparse(ref map) => IndexedPropertiesIter::Sparse(map.iter()),
+        }
+    }
+
+    /// Moves the dense elements into a sparse map.
+    fn convert_to_sparse(&mut self) {
+        if let Self::Dense(ref mut elements) = self {
+            let map = elements
+                .take()
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    (
+                        index as u32,
+                        DataDescriptor::new(value, Attribute::all()).into(),
+                    )
+                })
+                .collect();
+            *self = Self::Sparse(map);
+        }
+    }
+}
+
+/// The values of the dense storage.
+///
+/// A `GcCell` roots everything it holds while it is mutably borrowed, so values stored directly
+/// in an object made writing a single element linear in the length of the array. They live in a
+/// separate allocation instead, of which only the pointer gets rooted together with the object.
+///
+/// Values inside that allocation are unrooted, like the contents of any other `Gc`: they are
+/// unrooted when moved in and rooted again when moved out. The allocation is never shared, so
+/// the borrows of the owning object also guard it. It must not be mutated while a new `Gc` is
+/// allocated, as that could start a collection which traces it.
+#[derive(Default, Trace, Finalize)]
+pub(crate) struct DenseElements(Option<Gc<ElementsCell>>);
+
+#[derive(Default, Finalize)]
+struct ElementsCell(UnsafeCell<Vec<Value>>);
+
+unsafe impl Trace for ElementsCell {
+    custom_trace!(this, {
+        // SAFETY: The elements are only mutated through `DenseElements`, which never allocates
+        // while doing so, so no collection can run during a mutation.
+        for value in unsafe { &*this.0.get() } {
+            mark(value);
+        }
+    });
+}
+
+impl DenseElements {
+    /// Returns the number of elements.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns the elements as a slice.
+    #[inline]
+    pub(crate) fn as_slice(&self) -> &[Value] {
+        match self.0 {
+            // SAFETY: Mutations need a mutable reference to `self`.
+            Some(ref cell) => unsafe { &*cell.0.get() },
+            None => &[],
+        }
+    }
+
+    /// Returns the vector of elements, allocating it if needed.
+    fn vec_mut(&mut self) -> &mut Vec<Value> {
+        let cell = self
+            .0
+            .get_or_insert_with(|| Gc::new(ElementsCell::default()));
+        // SAFETY: The allocation is owned by `self`, which is mutably borrowed.
+        unsafe { &mut *cell.0.get() }
+    }
+
+    /// Appends an element.
+    pub(crate) fn push(&mut self, value: Value) {
+        // SAFETY: The value is moved into the `Gc` allocation.
+        unsafe { value.unroot() };
+        self.vec_mut().push(value);
+    }
+
+    /// Replaces the element at `position`, returning the previous one.
+    pub(crate) fn replace(&mut self, position: usize, value: Value) -> Value {
+        // SAFETY: The new value is moved into the `Gc` allocation and the old one out of it.
+        unsafe { value.unroot() };
+        let old = std::mem::replace(&mut self.vec_mut()[position], value);
+        unsafe { old.root() };
+        old
+    }
+
+    /// Removes the last element and returns it.
+    pub(crate) fn pop(&mut self) -> Option<Value> {
+        self.0.as_ref()?;
+        let value = self.vec_mut().pop()?;
+        // SAFETY: The value is moved out of the `Gc` allocation.
+        unsafe { value.root() };
+        Some(value)
+    }
+
+    /// Shortens the elements to `len`, dropping the rest.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.vec_mut().truncate(len);
+        }
+    }
+
+    /// Moves all the elements out.
+    pub(crate) fn take(&mut self) -> Vec<Value> {
+        if self.0.is_none() {
+            return Vec::new();
+        }
+        let values = std::mem::take(self.vec_mut());
+        for value in &values {
+            // SAFETY: The values are moved out of the `Gc` allocation.
+            unsafe { value.root() };
+        }
+        values
+    }
+}
+
+impl Debug for DenseElements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+/// An iterator over the entries of `IndexedProperties`.
+#[derive(Debug, Clone)]
+pub(crate) enum IndexedPropertiesIter<'a> {
+    Dense(std::iter::Enumerate<slice::Iter<'a, Value>>),
+    Sparse(hash_map::Iter<'a, u32, PropertyDescriptor>),
+}
+
+impl Iterator for IndexedPropertiesIter<'_> {
+    type Item = (u32, PropertyDescriptor);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Dense(ref mut iter) => {
+                let (index, value) = iter.next()?;
+                Some((
+                    index as u32,
+                    DataDescriptor::new(value.clone(), Attribute::all()).into(),
+                ))
+            }
+            Self::Sparse(ref mut iter) => {
+                let (index, property) = iter.next()?;
+                Some((*index, property.clone()))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Dense(ref iter) => iter.size_hint(),
+            Self::Sparse(ref iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for IndexedPropertiesIter<'_> {}
+
+impl FusedIterator for IndexedPropertiesIter<'_> {}
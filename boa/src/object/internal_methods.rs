@@ -6,8 +6,12 @@
 //! [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots
 
 use crate::{
+    builtins::{
+        proxy::Proxy,
+        typed_array::{canonical_numeric_index, TypedArray},
+    },
     object::{GcObject, Object},
-    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     value::{same_value, Value},
     BoaProfiler, Context, Result,
 };
@@ -55,6 +59,37 @@ impl Object {
         true
     }
 
+    /// Freezes the object, making its properties non-configurable and its data properties
+    /// non-writable, and disabling extensibility.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+    pub fn freeze(&mut self) -> bool {
+        if !self.prevent_extensions() {
+            return false;
+        }
+        for key in self.own_property_keys() {
+            let desc = match self.get_own_property(&key) {
+                Some(PropertyDescriptor::Data(ref data)) => {
+                    let mut data = data.clone();
+                    data.set_writable(false);
+                    data.set_configurable(false);
+                    PropertyDescriptor::from(data)
+                }
+                Some(PropertyDescriptor::Accessor(ref accessor)) => {
+                    let mut accessor = accessor.clone();
+                    accessor.set_configurable(false);
+                    PropertyDescriptor::from(accessor)
+                }
+                None => continue,
+            };
+            self.insert(key, desc);
+        }
+        true
+    }
+
     /// Delete property.
     pub fn delete(&mut self, key: &PropertyKey) -> bool {
         match self.get_own_property(key) {
@@ -67,72 +102,122 @@ impl Object {
         }
     }
 
-    /// [[Get]]
-    /// https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver
-    pub fn get(&self, key: &PropertyKey) -> Value {
-        match self.get_own_property(key) {
-            None => {
-                // parent will either be null or an Object
-                let parent = self.get_prototype_of();
-                if parent.is_null() {
-                    return Value::undefined();
-                }
+    /// Define an own property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-defineownproperty-p-desc
+    pub fn define_own_property<K>(&mut self, key: K, desc: PropertyDescriptor) -> bool
+    where
+        K: Into<PropertyKey>,
+    {
+        let _timer = BoaProfiler::global().start_event("Object::define_own_property", "object");
 
-                parent.get_field(key.clone())
-            }
-            Some(ref desc) => match desc {
-                PropertyDescriptor::Accessor(_) => todo!(),
-                PropertyDescriptor::Data(desc) => desc.value(),
-            },
+        let key = key.into();
+        if self.is_array() {
+            self.array_define_own_property(key, desc)
+        } else {
+            self.ordinary_define_own_property(key, desc)
         }
     }
 
-    /// [[Set]]
-    /// <https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-set-p-v-receiver>
-    pub fn set(&mut self, key: PropertyKey, val: Value) -> bool {
-        let _timer = BoaProfiler::global().start_event("Object::set", "object");
-
-        // Fetch property key
-        let own_desc = if let Some(desc) = self.get_own_property(&key) {
-            desc
-        } else {
-            let parent = self.get_prototype_of();
-            if !parent.is_null() {
-                // TODO: come back to this
-            }
-            DataDescriptor::new(
-                Value::undefined(),
-                Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
-            )
-            .into()
+    /// Define an own property on an array, keeping its `length` in sync with its elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array-exotic-objects-defineownproperty-p-desc
+    fn array_define_own_property(&mut self, key: PropertyKey, desc: PropertyDescriptor) -> bool {
+        let length = match self.get_own_property(&"length".into()) {
+            Some(PropertyDescriptor::Data(ref length)) => length.clone(),
+            _ => return self.ordinary_define_own_property(key, desc),
         };
+        let old_len = length.value().as_number().unwrap_or_default() as u32;
 
-        match &own_desc {
-            PropertyDescriptor::Data(desc) => {
-                if !desc.writable() {
+        match key {
+            PropertyKey::String(ref string) if string == "length" => match desc {
+                PropertyDescriptor::Data(ref desc) => {
+                    let new_len = desc
+                        .value()
+                        .as_number()
+                        .map_or(old_len, |new_len| new_len as u32);
+                    self.array_set_length(old_len, length.writable(), new_len, desc.attributes())
+                }
+                PropertyDescriptor::Accessor(_) => self.ordinary_define_own_property(key, desc),
+            },
+            PropertyKey::Index(index) if index != u32::MAX => {
+                if index >= old_len && !length.writable() {
                     return false;
                 }
-
-                let desc = DataDescriptor::new(val, own_desc.attributes()).into();
-                self.define_own_property(key, desc)
+                if !self.ordinary_define_own_property(key, desc) {
+                    return false;
+                }
+                if index >= old_len {
+                    self.insert(
+                        "length",
+                        DataDescriptor::new(index + 1, length.attributes()),
+                    );
+                }
+                true
             }
-            PropertyDescriptor::Accessor(_) => todo!(),
+            _ => self.ordinary_define_own_property(key, desc),
         }
     }
 
-    /// Define an own property.
+    /// Sets the `length` of an array, deleting the elements past the new length.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-defineownproperty-p-desc
-    pub fn define_own_property<K>(&mut self, key: K, desc: PropertyDescriptor) -> bool
-    where
-        K: Into<PropertyKey>,
-    {
-        let _timer = BoaProfiler::global().start_event("Object::define_own_property", "object");
+    /// [spec]: https://tc39.es/ecma262/#sec-arraysetlength
+    fn array_set_length(
+        &mut self,
+        old_len: u32,
+        old_len_writable: bool,
+        new_len: u32,
+        attributes: Attribute,
+    ) -> bool {
+        if new_len >= old_len {
+            return self.ordinary_define_own_property(
+                "length".into(),
+                DataDescriptor::new(new_len, attributes).into(),
+            );
+        }
+        if !old_len_writable {
+            return false;
+        }
 
-        let key = key.into();
+        // The length is only made read-only once the elements have been deleted.
+        let new_writable = attributes.writable();
+        let mut attributes = attributes | Attribute::WRITABLE;
+        if !self.ordinary_define_own_property(
+            "length".into(),
+            DataDescriptor::new(new_len, attributes).into(),
+        ) {
+            return false;
+        }
+        attributes.set_writable(new_writable);
+
+        match self.indexed_properties.truncate(new_len) {
+            Ok(()) => {
+                self.insert("length", DataDescriptor::new(new_len, attributes));
+                true
+            }
+            Err(index) => {
+                self.insert("length", DataDescriptor::new(index + 1, attributes));
+                false
+            }
+        }
+    }
+
+    /// The ordinary `[[DefineOwnProperty]]`, used by every object that isn't exotic for `key`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
+    fn ordinary_define_own_property(&mut self, key: PropertyKey, desc: PropertyDescriptor) -> bool {
         let extensible = self.is_extensible();
 
         let current = if let Some(desc) = self.get_own_property(&key) {
@@ -169,23 +254,13 @@ impl Object {
                     }
                 }
             }
-            (PropertyDescriptor::Data(current), PropertyDescriptor::Accessor(_)) => {
+            // Converting between a data and an accessor property only needs the current
+            // property to be configurable, the new descriptor then replaces it below.
+            (PropertyDescriptor::Data(_), PropertyDescriptor::Accessor(_))
+            | (PropertyDescriptor::Accessor(_), PropertyDescriptor::Data(_)) => {
                 if !current.configurable() {
                     return false;
                 }
-
-                let current = AccessorDescriptor::new(None, None, current.attributes());
-                self.insert(key, current);
-                return true;
-            }
-            (PropertyDescriptor::Accessor(current), PropertyDescriptor::Data(_)) => {
-                if !current.configurable() {
-                    return false;
-                }
-
-                let current = DataDescriptor::new(Value::undefined(), current.attributes());
-                self.insert(key, current);
-                return true;
             }
             (PropertyDescriptor::Accessor(current), PropertyDescriptor::Accessor(desc)) => {
                 if !current.configurable() {
@@ -219,13 +294,35 @@ impl Object {
     pub fn get_own_property(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
         let _timer = BoaProfiler::global().start_event("Object::get_own_property", "object");
 
-        let property = match key {
-            PropertyKey::Index(index) => self.indexed_properties.get(&index),
-            PropertyKey::String(ref st) => self.string_properties.get(st),
-            PropertyKey::Symbol(ref symbol) => self.symbol_properties.get(symbol),
-        };
+        match key {
+            PropertyKey::Index(index) => self
+                .indexed_properties
+                .get(*index)
+                .or_else(|| self.string_get_own_property(*index)),
+            PropertyKey::String(ref st) => self.string_properties.get(st).cloned(),
+            PropertyKey::Symbol(ref symbol) => self.symbol_properties.get(symbol).cloned(),
+        }
+    }
 
-        property.cloned()
+    /// Gets the property of a `String` object for one of the code units of its string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-stringgetownproperty
+    fn string_get_own_property(&self, index: u32) -> Option<PropertyDescriptor> {
+        let string = self.as_string()?;
+        let index = index as usize;
+        if index >= string.len() {
+            return None;
+        }
+        Some(
+            DataDescriptor::new(
+                string.substring(index, index + 1),
+                Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+            )
+            .into(),
+        )
     }
 
     /// Essential internal method OwnPropertyKeys
@@ -235,7 +332,19 @@ impl Object {
     ///
     /// [spec](https://tc39.es/ecma262/#table-essential-internal-methods)
     pub fn own_property_keys(&self) -> Vec<PropertyKey> {
-        self.keys().collect()
+        // The indices of the string of a `String` object are own properties as well.
+        let string_length = self.as_string().map_or(0, |string| string.len() as u32);
+        let mut indices: Vec<u32> = (0..string_length)
+            .chain(self.index_property_keys())
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+            .into_iter()
+            .map(PropertyKey::from)
+            .chain(self.string_property_keys().cloned().map(PropertyKey::from))
+            .chain(self.symbol_property_keys().cloned().map(PropertyKey::from))
+            .collect()
     }
 
     /// The abstract operation ObjectDefineProperties
@@ -252,7 +361,7 @@ impl Object {
         for next_key in keys {
             if let Some(prop_desc) = props.borrow().get_own_property(&next_key) {
                 if prop_desc.enumerable() {
-                    let desc_obj = props.borrow().get(&next_key);
+                    let desc_obj = props.get(&next_key, &props.clone().into(), ctx)?;
                     let desc = desc_obj.to_property_descriptor(ctx)?;
                     descriptors.push((next_key, desc));
                 }
@@ -266,47 +375,6 @@ impl Object {
         Ok(())
     }
 
-    /// `Object.setPropertyOf(obj, prototype)`
-    ///
-    /// This method sets the prototype (i.e., the internal `[[Prototype]]` property)
-    /// of a specified object to another object or `null`.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///  - [MDN documentation][mdn]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-setprototypeof-v
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/setPrototypeOf
-    pub fn set_prototype_of(&mut self, _val: Value) -> bool {
-        // debug_assert!(val.is_object() || val.is_null());
-        // let current = self.prototype.clone();
-        // if same_value(&current, &val) {
-        //     return true;
-        // }
-        // if !self.is_extensible() {
-        //     return false;
-        // }
-        // let mut p = val.clone();
-        // let mut done = false;
-        // while !done {
-        //     if p.is_null() {
-        //         done = true
-        //     } else if same_value(&Value::from(self.clone()), &p) {
-        //         return false;
-        //     } else {
-        //         let prototype = p
-        //             .as_object()
-        //             .expect("prototype should be null or object")
-        //             .prototype
-        //             .clone();
-        //         p = prototype;
-        //     }
-        // }
-        // self.prototype = val;
-        // true
-        todo!("Object.setPropertyOf(obj, prototype)")
-    }
-
     /// Returns either the prototype or null
     ///
     /// More information:
@@ -342,7 +410,7 @@ impl Object {
     #[inline]
     pub(crate) fn remove_property(&mut self, key: &PropertyKey) -> Option<PropertyDescriptor> {
         match key {
-            PropertyKey::Index(index) => self.indexed_properties.remove(&index),
+            PropertyKey::Index(index) => self.indexed_properties.remove(*index),
             PropertyKey::String(ref string) => self.string_properties.remove(string),
             PropertyKey::Symbol(ref symbol) => self.symbol_properties.remove(symbol),
         }
@@ -366,3 +434,248 @@ impl Object {
         self.insert(key.into(), DataDescriptor::new(value, attribute))
     }
 }
+
+/// The internal methods of objects, with the traps of a `Proxy` taking precedence over the
+/// ordinary behaviour.
+///
+/// These can run arbitrary code, so they must be called when the object isn't borrowed.
+impl GcObject {
+    /// Returns the proxy data of this object, if it is a `Proxy`.
+    #[inline]
+    pub(crate) fn proxy(&self) -> Option<Proxy> {
+        self.borrow().as_proxy().cloned()
+    }
+
+    /// Returns the typed array data of this object and the number `key` stands for, if this is
+    /// a typed array and `key` is a numeric string. Such keys are integer indexed elements,
+    /// which never reach the ordinary properties of the object or its prototype chain.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects
+    pub(crate) fn typed_array_index(&self, key: &PropertyKey) -> Option<(TypedArray, f64)> {
+        let object = self.borrow();
+        let array = object.as_typed_array()?;
+        let index = canonical_numeric_index(key)?;
+        Some((array.clone(), index))
+    }
+
+    /// `[[GetPrototypeOf]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getprototypeof
+    pub fn get_prototype_of(&self, ctx: &mut Context) -> Result<Value> {
+        match self.proxy() {
+            Some(proxy) => proxy.get_prototype_of(ctx),
+            None => Ok(self.borrow().get_prototype_of()),
+        }
+    }
+
+    /// `[[SetPrototypeOf]]`
+    ///
+    /// Returns `false` if the object isn't extensible, or if the change would create a
+    /// prototype chain cycle.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-setprototypeof-v
+    pub fn set_prototype_of(&self, prototype: Value, ctx: &mut Context) -> Result<bool> {
+        debug_assert!(prototype.is_object() || prototype.is_null());
+        if let Some(proxy) = self.proxy() {
+            return proxy.set_prototype_of(prototype, ctx);
+        }
+
+        let current = self.borrow().get_prototype_of();
+        if same_value(&current, &prototype) {
+            return Ok(true);
+        }
+        if !self.borrow().is_extensible() {
+            return Ok(false);
+        }
+        let mut parent = prototype.clone();
+        while let Value::Object(ref object) = parent {
+            if GcObject::equals(object, self) {
+                return Ok(false);
+            }
+            // The prototype of a proxy can't be checked without running its traps.
+            if object.borrow().is_proxy() {
+                break;
+            }
+            let next = object.borrow().get_prototype_of();
+            parent = next;
+        }
+        self.borrow_mut().set_prototype_instance(prototype);
+        Ok(true)
+    }
+
+    /// `[[IsExtensible]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-isextensible
+    pub fn is_extensible(&self, ctx: &mut Context) -> Result<bool> {
+        match self.proxy() {
+            Some(proxy) => proxy.is_extensible(ctx),
+            None => Ok(self.borrow().is_extensible()),
+        }
+    }
+
+    /// `[[PreventExtensions]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-preventextensions
+    pub fn prevent_extensions(&self, ctx: &mut Context) -> Result<bool> {
+        match self.proxy() {
+            Some(proxy) => proxy.prevent_extensions(ctx),
+            None => Ok(self.borrow_mut().prevent_extensions()),
+        }
+    }
+
+    /// `[[GetOwnProperty]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getownproperty-p
+    pub fn get_own_property(
+        &self,
+        key: &PropertyKey,
+        ctx: &mut Context,
+    ) -> Result<Option<PropertyDescriptor>> {
+        if let Some((array, index)) = self.typed_array_index(key) {
+            return Ok(array.get_own_property(index));
+        }
+        match self.proxy() {
+            Some(proxy) => proxy.get_own_property(key, ctx),
+            None => Ok(self.borrow().get_own_property(key)),
+        }
+    }
+
+    /// `[[DefineOwnProperty]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-defineownproperty-p-desc
+    pub fn define_own_property(
+        &self,
+        key: PropertyKey,
+        desc: PropertyDescriptor,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        if let Some((array, index)) = self.typed_array_index(&key) {
+            return array.define_own_property(index, desc, ctx);
+        }
+        if let Some(proxy) = self.proxy() {
+            return proxy.define_own_property(key, desc, ctx);
+        }
+        let desc = match (&key, &desc) {
+            (PropertyKey::String(ref string), PropertyDescriptor::Data(ref data))
+                if string == "length" && self.borrow().is_array() =>
+            {
+                self.array_length_descriptor(data, ctx)?
+            }
+            _ => desc,
+        };
+        Ok(self.borrow_mut().define_own_property(key, desc))
+    }
+
+    /// Converts the value of a descriptor for the `length` of an array to a valid array length,
+    /// throwing a `RangeError` if it isn't one.
+    ///
+    /// Our descriptors always carry a value, so an `undefined` one keeps the current length.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraysetlength
+    fn array_length_descriptor(
+        &self,
+        desc: &DataDescriptor,
+        ctx: &mut Context,
+    ) -> Result<PropertyDescriptor> {
+        let value = desc.value();
+        if value.is_undefined() {
+            let length = self.borrow().get_own_property(&"length".into());
+            let value = length
+                .as_ref()
+                .and_then(PropertyDescriptor::as_data_descriptor)
+                .map(DataDescriptor::value)
+                .unwrap_or_default();
+            return Ok(DataDescriptor::new(value, desc.attributes()).into());
+        }
+        let new_len = value.to_u32(ctx)?;
+        let number_len = value.to_number(ctx)?;
+        if f64::from(new_len) != number_len {
+            return Err(ctx.construct_range_error("Invalid array length"));
+        }
+        Ok(DataDescriptor::new(new_len, desc.attributes()).into())
+    }
+
+    /// `[[HasProperty]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-hasproperty-p
+    pub fn has_property(&self, key: &PropertyKey, ctx: &mut Context) -> Result<bool> {
+        if let Some(proxy) = self.proxy() {
+            return proxy.has_property(key, ctx);
+        }
+        if let Some((array, index)) = self.typed_array_index(key) {
+            return Ok(array.has_property(index));
+        }
+
+        if self.borrow().get_own_property(key).is_some() {
+            return Ok(true);
+        }
+        let parent = self.borrow().get_prototype_of();
+        match parent {
+            Value::Object(ref parent) => parent.has_property(key, ctx),
+            _ => Ok(false),
+        }
+    }
+
+    /// `[[Delete]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-delete-p
+    pub fn delete(&self, key: &PropertyKey, ctx: &mut Context) -> Result<bool> {
+        if let Some((array, index)) = self.typed_array_index(key) {
+            return Ok(array.delete(index));
+        }
+        match self.proxy() {
+            Some(proxy) => proxy.delete(key, ctx),
+            None => Ok(self.borrow_mut().delete(key)),
+        }
+    }
+
+    /// `[[OwnPropertyKeys]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-ownpropertykeys
+    pub fn own_property_keys(&self, ctx: &mut Context) -> Result<Vec<PropertyKey>> {
+        if let Some(proxy) = self.proxy() {
+            return proxy.own_property_keys(ctx);
+        }
+        let array = self.borrow().as_typed_array().cloned();
+        match array {
+            Some(array) => Ok(array
+                .own_indices()
+                .chain(self.borrow().own_property_keys())
+                .collect()),
+            None => Ok(self.borrow().own_property_keys()),
+        }
+    }
+}
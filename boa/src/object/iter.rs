@@ -1,9 +1,10 @@
-use super::{Object, PropertyDescriptor, PropertyKey};
+use super::{indexed_properties::IndexedPropertiesIter, Object, PropertyDescriptor, PropertyKey};
 use crate::value::{RcString, RcSymbol};
-use std::{collections::hash_map, iter::FusedIterator};
+use indexmap::map;
+use std::iter::FusedIterator;
 
 impl Object {
-    /// An iterator visiting all key-value pairs in arbitrary order. The iterator element type is `(PropertyKey, &'a Property)`.
+    /// An iterator visiting all key-value pairs in arbitrary order. The iterator element type is `(PropertyKey, Property)`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -23,7 +24,7 @@ impl Object {
         Keys(self.iter())
     }
 
-    /// An iterator visiting all values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all values in arbitrary order. The iterator element type is `Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -31,7 +32,7 @@ impl Object {
         Values(self.iter())
     }
 
-    /// An iterator visiting all symbol key-value pairs in arbitrary order. The iterator element type is `(&'a RcSymbol, &'a Property)`.
+    /// An iterator visiting all symbol key-value pairs in insertion order. The iterator element type is `(&'a RcSymbol, &'a Property)`.
     ///
     ///
     /// This iterator does not recurse down the prototype chain.
@@ -40,7 +41,7 @@ impl Object {
         SymbolProperties(self.symbol_properties.iter())
     }
 
-    /// An iterator visiting all symbol keys in arbitrary order. The iterator element type is `&'a RcSymbol`.
+    /// An iterator visiting all symbol keys in insertion order. The iterator element type is `&'a RcSymbol`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -48,7 +49,7 @@ impl Object {
         SymbolPropertyKeys(self.symbol_properties.keys())
     }
 
-    /// An iterator visiting all symbol values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all symbol values in insertion order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -56,7 +57,7 @@ impl Object {
         SymbolPropertyValues(self.symbol_properties.values())
     }
 
-    /// An iterator visiting all indexed key-value pairs in arbitrary order. The iterator element type is `(&'a u32, &'a Property)`.
+    /// An iterator visiting all indexed key-value pairs in arbitrary order. The iterator element type is `(u32, Property)`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -64,23 +65,23 @@ impl Object {
         IndexProperties(self.indexed_properties.iter())
     }
 
-    /// An iterator visiting all index keys in arbitrary order. The iterator element type is `&'a u32`.
+    /// An iterator visiting all index keys in arbitrary order. The iterator element type is `u32`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn index_property_keys(&self) -> IndexPropertyKeys<'_> {
-        IndexPropertyKeys(self.indexed_properties.keys())
+        IndexPropertyKeys(self.indexed_properties.iter())
     }
 
-    /// An iterator visiting all index values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all index values in arbitrary order. The iterator element type is `Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn index_property_values(&self) -> IndexPropertyValues<'_> {
-        IndexPropertyValues(self.indexed_properties.values())
+        IndexPropertyValues(self.indexed_properties.iter())
     }
 
-    /// An iterator visiting all string key-value pairs in arbitrary order. The iterator element type is `(&'a RcString, &'a Property)`.
+    /// An iterator visiting all string key-value pairs in insertion order. The iterator element type is `(&'a RcString, &'a Property)`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -88,7 +89,7 @@ impl Object {
         StringProperties(self.string_properties.iter())
     }
 
-    /// An iterator visiting all string keys in arbitrary order. The iterator element type is `&'a RcString`.
+    /// An iterator visiting all string keys in insertion order. The iterator element type is `&'a RcString`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -96,7 +97,7 @@ impl Object {
         StringPropertyKeys(self.string_properties.keys())
     }
 
-    /// An iterator visiting all string values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all string values in insertion order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -108,21 +109,21 @@ impl Object {
 /// An iterator over the property entries of an `Object`
 #[derive(Debug, Clone)]
 pub struct Iter<'a> {
-    indexed_properties: hash_map::Iter<'a, u32, PropertyDescriptor>,
-    string_properties: hash_map::Iter<'a, RcString, PropertyDescriptor>,
-    symbol_properties: hash_map::Iter<'a, RcSymbol, PropertyDescriptor>,
+    indexed_properties: IndexedPropertiesIter<'a>,
+    string_properties: map::Iter<'a, RcString, PropertyDescriptor>,
+    symbol_properties: map::Iter<'a, RcSymbol, PropertyDescriptor>,
 }
 
-impl<'a> Iterator for Iter<'a> {
-    type Item = (PropertyKey, &'a PropertyDescriptor);
+impl Iterator for Iter<'_> {
+    type Item = (PropertyKey, PropertyDescriptor);
     fn next(&mut self) -> Option<Self::Item> {
         if let Some((key, value)) = self.indexed_properties.next() {
-            Some(((*key).into(), value))
+            Some((key.into(), value))
         } else if let Some((key, value)) = self.string_properties.next() {
-            Some((key.clone().into(), value))
+            Some((key.clone().into(), value.clone()))
         } else {
             let (key, value) = self.symbol_properties.next()?;
-            Some((key.clone().into(), value))
+            Some((key.clone().into(), value.clone()))
         }
     }
 }
@@ -140,7 +141,7 @@ impl FusedIterator for Iter<'_> {}
 #[derive(Debug, Clone)]
 pub struct Keys<'a>(Iter<'a>);
 
-impl<'a> Iterator for Keys<'a> {
+impl Iterator for Keys<'_> {
     type Item = PropertyKey;
     fn next(&mut self) -> Option<Self::Item> {
         let (key, _) = self.0.next()?;
@@ -161,8 +162,8 @@ impl FusedIterator for Keys<'_> {}
 #[derive(Debug, Clone)]
 pub struct Values<'a>(Iter<'a>);
 
-impl<'a> Iterator for Values<'a> {
-    type Item = &'a PropertyDescriptor;
+impl Iterator for Values<'_> {
+    type Item = PropertyDescriptor;
     fn next(&mut self) -> Option<Self::Item> {
         let (_, value) = self.0.next()?;
         Some(value)
@@ -180,7 +181,7 @@ impl FusedIterator for Values<'_> {}
 
 /// An iterator over the `Symbol` property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct SymbolProperties<'a>(hash_map::Iter<'a, RcSymbol, PropertyDescriptor>);
+pub struct SymbolProperties<'a>(map::Iter<'a, RcSymbol, PropertyDescriptor>);
 
 impl<'a> Iterator for SymbolProperties<'a> {
     type Item = (&'a RcSymbol, &'a PropertyDescriptor);
@@ -207,7 +208,7 @@ impl FusedIterator for SymbolProperties<'_> {}
 
 /// An iterator over the keys (`RcSymbol`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct SymbolPropertyKeys<'a>(hash_map::Keys<'a, RcSymbol, PropertyDescriptor>);
+pub struct SymbolPropertyKeys<'a>(map::Keys<'a, RcSymbol, PropertyDescriptor>);
 
 impl<'a> Iterator for SymbolPropertyKeys<'a> {
     type Item = &'a RcSymbol;
@@ -234,7 +235,7 @@ impl FusedIterator for SymbolPropertyKeys<'_> {}
 
 /// An iterator over the `Symbol` values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct SymbolPropertyValues<'a>(hash_map::Values<'a, RcSymbol, PropertyDescriptor>);
+pub struct SymbolPropertyValues<'a>(map::Values<'a, RcSymbol, PropertyDescriptor>);
 
 impl<'a> Iterator for SymbolPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
@@ -261,10 +262,10 @@ impl FusedIterator for SymbolPropertyValues<'_> {}
 
 /// An iterator over the indexed property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct IndexProperties<'a>(hash_map::Iter<'a, u32, PropertyDescriptor>);
+pub struct IndexProperties<'a>(IndexedPropertiesIter<'a>);
 
-impl<'a> Iterator for IndexProperties<'a> {
-    type Item = (&'a u32, &'a PropertyDescriptor);
+impl Iterator for IndexProperties<'_> {
+    type Item = (u32, PropertyDescriptor);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
@@ -288,14 +289,15 @@ impl FusedIterator for IndexProperties<'_> {}
 
 /// An iterator over the index keys (`u32`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct IndexPropertyKeys<'a>(hash_map::Keys<'a, u32, PropertyDescriptor>);
+pub struct IndexPropertyKeys<'a>(IndexedPropertiesIter<'a>);
 
-impl<'a> Iterator for IndexPropertyKeys<'a> {
-    type Item = &'a u32;
+impl Iterator for IndexPropertyKeys<'_> {
+    type Item = u32;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        let (key, _) = self.0.next()?;
+        Some(key)
     }
 
     #[inline]
@@ -315,14 +317,15 @@ impl FusedIterator for IndexPropertyKeys<'_> {}
 
 /// An iterator over the index values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct IndexPropertyValues<'a>(hash_map::Values<'a, u32, PropertyDescriptor>);
+pub struct IndexPropertyValues<'a>(IndexedPropertiesIter<'a>);
 
-impl<'a> Iterator for IndexPropertyValues<'a> {
-    type Item = &'a PropertyDescriptor;
+impl Iterator for IndexPropertyValues<'_> {
+    type Item = PropertyDescriptor;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        let (_, value) = self.0.next()?;
+        Some(value)
     }
 
     #[inline]
@@ -342,7 +345,7 @@ impl FusedIterator for IndexPropertyValues<'_> {}
 
 /// An iterator over the `String` property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct StringProperties<'a>(hash_map::Iter<'a, RcString, PropertyDescriptor>);
+pub struct StringProperties<'a>(map::Iter<'a, RcString, PropertyDescriptor>);
 
 impl<'a> Iterator for StringProperties<'a> {
     type Item = (&'a RcString, &'a PropertyDescriptor);
@@ -369,7 +372,7 @@ impl FusedIterator for StringProperties<'_> {}
 
 /// An iterator over the string keys (`RcString`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyKeys<'a>(hash_map::Keys<'a, RcString, PropertyDescriptor>);
+pub struct StringPropertyKeys<'a>(map::Keys<'a, RcString, PropertyDescriptor>);
 
 impl<'a> Iterator for StringPropertyKeys<'a> {
     type Item = &'a RcString;
@@ -396,7 +399,7 @@ impl FusedIterator for StringPropertyKeys<'_> {}
 
 /// An iterator over the string values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyValues<'a>(hash_map::Values<'a, RcString, PropertyDescriptor>);
+pub struct StringPropertyValues<'a>(map::Values<'a, RcString, PropertyDescriptor>);
 
 impl<'a> Iterator for StringPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
@@ -3,18 +3,29 @@
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
-        function::{BuiltInFunction, Function, FunctionFlags, NativeFunction},
+        array_buffer::ArrayBuffer,
+        data_view::DataView,
+        function::{
+            BoundFunction, BuiltInFunction, CapturingFunction, ClosureFunction, Function,
+            FunctionFlags, NativeFunction,
+        },
+        generator::Generator,
         map::ordered_map::OrderedMap,
+        object::for_in_iterator::ForInIterator,
+        promise::Promise,
+        proxy::Proxy,
+        set::{ordered_set::OrderedSet, set_iterator::SetIterator},
         string::string_iterator::StringIterator,
+        typed_array::TypedArray,
         BigInt, Date, RegExp,
     },
     context::StandardConstructor,
-    gc::{Finalize, Trace},
+    exec::CallFrame,
+    gc::{EphemeronTable, Finalize, Trace, WeakCollection, WeakCollectionId},
     property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     value::{RcBigInt, RcString, RcSymbol, Value},
-    BoaProfiler, Context,
+    BoaProfiler, Context, Result,
 };
-use rustc_hash::FxHashMap;
 use std::{
     any::Any,
     fmt::{self, Debug, Display},
@@ -22,11 +33,15 @@ use std::{
 };
 
 mod gcobject;
+mod indexed_properties;
 mod internal_methods;
 mod iter;
+mod property_map;
 
-pub use gcobject::{GcObject, RecursionLimiter, Ref, RefMut};
+pub use gcobject::{GcObject, IntegrityLevel, RecursionLimiter, Ref, RefMut};
+pub(crate) use indexed_properties::IndexedProperties;
 pub use iter::*;
+pub(crate) use property_map::PropertyMap;
 
 /// Static `prototype`, usually set on constructors as a key to point to their respective prototype object.
 pub static PROTOTYPE: &str = "prototype";
@@ -59,35 +74,51 @@ impl<T: Any + Debug + Trace> NativeObject for T {
 pub struct Object {
     /// The type of the object.
     pub data: ObjectData,
-    indexed_properties: FxHashMap<u32, PropertyDescriptor>,
+    /// Integer indexed properties
+    indexed_properties: IndexedProperties,
     /// Properties
-    string_properties: FxHashMap<RcString, PropertyDescriptor>,
+    string_properties: PropertyMap<RcString>,
     /// Symbol Properties
-    symbol_properties: FxHashMap<RcSymbol, PropertyDescriptor>,
+    symbol_properties: PropertyMap<RcSymbol>,
     /// Instance prototype `__proto__`.
     prototype: Value,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// The entries of the weak collections this object is a key of.
+    ephemerons: EphemeronTable,
 }
 
 /// Defines the different types of objects.
 #[derive(Debug, Trace, Finalize)]
 pub enum ObjectData {
     Array,
+    ArrayBuffer(ArrayBuffer),
     ArrayIterator(ArrayIterator),
+    DataView(DataView),
     Map(OrderedMap<Value, Value>),
     RegExp(Box<RegExp>),
     BigInt(RcBigInt),
     Boolean(bool),
+    BoundFunction(BoundFunction),
+    ForInIterator(ForInIterator),
     Function(Function),
+    Generator(Generator),
+    Promise(Promise),
+    Proxy(Proxy),
+    Set(OrderedSet<Value>),
+    SetIterator(SetIterator),
     String(RcString),
     StringIterator(StringIterator),
     Number(f64),
     Symbol(RcSymbol),
-    Error,
+    TypedArray(TypedArray),
+    /// The frames of the call stack when the error was created, innermost first.
+    Error(Vec<CallFrame>),
     Ordinary,
     Date(Date),
     Global,
+    WeakMap(WeakCollection),
+    WeakSet(WeakCollection),
     NativeObject(Box<dyn NativeObject>),
 }
 
@@ -98,20 +129,32 @@ impl Display for ObjectData {
             "{}",
             match self {
                 Self::Array => "Array",
+                Self::ArrayBuffer(_) => "ArrayBuffer",
                 Self::ArrayIterator(_) => "ArrayIterator",
+                Self::DataView(_) => "DataView",
+                Self::BoundFunction(_) => "BoundFunction",
+                Self::ForInIterator(_) => "ForInIterator",
                 Self::Function(_) => "Function",
+                Self::Generator(_) => "Generator",
+                Self::Promise(_) => "Promise",
+                Self::Proxy(_) => "Proxy",
                 Self::RegExp(_) => "RegExp",
                 Self::Map(_) => "Map",
+                Self::Set(_) => "Set",
+                Self::SetIterator(_) => "SetIterator",
                 Self::String(_) => "String",
                 Self::StringIterator(_) => "StringIterator",
                 Self::Symbol(_) => "Symbol",
-                Self::Error => "Error",
+                Self::TypedArray(_) => "TypedArray",
+                Self::Error(_) => "Error",
                 Self::Ordinary => "Ordinary",
                 Self::Boolean(_) => "Boolean",
                 Self::Number(_) => "Number",
                 Self::BigInt(_) => "BigInt",
                 Self::Date(_) => "Date",
                 Self::Global => "Global",
+                Self::WeakMap(_) => "WeakMap",
+                Self::WeakSet(_) => "WeakSet",
                 Self::NativeObject(_) => "NativeObject",
             }
         )
@@ -124,11 +167,12 @@ impl Default for Object {
     fn default() -> Self {
         Self {
             data: ObjectData::Ordinary,
-            indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            indexed_properties: IndexedProperties::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            ephemerons: EphemeronTable::default(),
         }
     }
 }
@@ -146,11 +190,12 @@ impl Object {
 
         Self {
             data: ObjectData::Function(function),
-            indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            indexed_properties: IndexedProperties::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype,
             extensible: true,
+            ephemerons: EphemeronTable::default(),
         }
     }
 
@@ -173,11 +218,12 @@ impl Object {
     pub fn boolean(value: bool) -> Self {
         Self {
             data: ObjectData::Boolean(value),
-            indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            indexed_properties: IndexedProperties::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            ephemerons: EphemeronTable::default(),
         }
     }
 
@@ -186,11 +232,12 @@ impl Object {
     pub fn number(value: f64) -> Self {
         Self {
             data: ObjectData::Number(value),
-            indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            indexed_properties: IndexedProperties::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            ephemerons: EphemeronTable::default(),
         }
     }
 
@@ -202,11 +249,12 @@ impl Object {
     {
         Self {
             data: ObjectData::String(value.into()),
-            indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            indexed_properties: IndexedProperties::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            ephemerons: EphemeronTable::default(),
         }
     }
 
@@ -215,11 +263,12 @@ impl Object {
     pub fn bigint(value: RcBigInt) -> Self {
         Self {
             data: ObjectData::BigInt(value),
-            indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            indexed_properties: IndexedProperties::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            ephemerons: EphemeronTable::default(),
         }
     }
 
@@ -231,11 +280,12 @@ impl Object {
     {
         Self {
             data: ObjectData::NativeObject(Box::new(value)),
-            indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            indexed_properties: IndexedProperties::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            ephemerons: EphemeronTable::default(),
         }
     }
 
@@ -247,7 +297,12 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
     #[inline]
     pub fn is_callable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_callable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_callable(),
+            ObjectData::BoundFunction(_) => true,
+            ObjectData::Proxy(ref proxy) => proxy.is_callable(),
+            _ => false,
+        }
     }
 
     /// It determines if Object is a function object with a [[Construct]] internal method.
@@ -258,7 +313,14 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
     #[inline]
     pub fn is_constructable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_constructable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_constructable(),
+            ObjectData::BoundFunction(ref function) => {
+                function.target_function().is_constructable()
+            }
+            ObjectData::Proxy(ref proxy) => proxy.is_constructable(),
+            _ => false,
+        }
     }
 
     /// Checks if it an `Array` object.
@@ -305,6 +367,42 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_for_in_iterator_mut(&mut self) -> Option<&mut ForInIterator> {
+        match &mut self.data {
+            ObjectData::ForInIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a generator object.
+    #[inline]
+    pub fn is_generator(&self) -> bool {
+        matches!(self.data, ObjectData::Generator(_))
+    }
+
+    #[inline]
+    pub fn as_generator_mut(&mut self) -> Option<&mut Generator> {
+        match &mut self.data {
+            ObjectData::Generator(generator) => Some(generator),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Promise` object.
+    #[inline]
+    pub fn is_promise(&self) -> bool {
+        matches!(self.data, ObjectData::Promise(_))
+    }
+
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData::Promise(promise) => Some(promise),
+            _ => None,
+        }
+    }
+
     /// Checks if it is a `Map` object.pub
     #[inline]
     pub fn is_map(&self) -> bool {
@@ -327,6 +425,127 @@ impl Object {
         }
     }
 
+    /// Checks if it is a `Set` object.
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        matches!(self.data, ObjectData::Set(_))
+    }
+
+    #[inline]
+    pub fn as_set_ref(&self) -> Option<&OrderedSet<Value>> {
+        match self.data {
+            ObjectData::Set(ref set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_set_mut(&mut self) -> Option<&mut OrderedSet<Value>> {
+        match &mut self.data {
+            ObjectData::Set(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_set_iterator_mut(&mut self) -> Option<&mut SetIterator> {
+        match &mut self.data {
+            ObjectData::SetIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `WeakMap` object.
+    #[inline]
+    pub fn is_weak_map(&self) -> bool {
+        matches!(self.data, ObjectData::WeakMap(_))
+    }
+
+    /// Returns the identity of the `WeakMap`, if it is one.
+    #[inline]
+    pub fn as_weak_map(&self) -> Option<WeakCollectionId> {
+        match self.data {
+            ObjectData::WeakMap(ref collection) => Some(collection.id()),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `WeakSet` object.
+    #[inline]
+    pub fn is_weak_set(&self) -> bool {
+        matches!(self.data, ObjectData::WeakSet(_))
+    }
+
+    /// Returns the identity of the `WeakSet`, if it is one.
+    #[inline]
+    pub fn as_weak_set(&self) -> Option<WeakCollectionId> {
+        match self.data {
+            ObjectData::WeakSet(ref collection) => Some(collection.id()),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is an `ArrayBuffer` object.
+    #[inline]
+    pub fn is_array_buffer(&self) -> bool {
+        matches!(self.data, ObjectData::ArrayBuffer(_))
+    }
+
+    #[inline]
+    pub fn as_array_buffer(&self) -> Option<&ArrayBuffer> {
+        match self.data {
+            ObjectData::ArrayBuffer(ref buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_array_buffer_mut(&mut self) -> Option<&mut ArrayBuffer> {
+        match self.data {
+            ObjectData::ArrayBuffer(ref mut buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a typed array object, like a `Uint8Array`.
+    #[inline]
+    pub fn is_typed_array(&self) -> bool {
+        matches!(self.data, ObjectData::TypedArray(_))
+    }
+
+    #[inline]
+    pub fn as_typed_array(&self) -> Option<&TypedArray> {
+        match self.data {
+            ObjectData::TypedArray(ref array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `DataView` object.
+    #[inline]
+    pub fn is_data_view(&self) -> bool {
+        matches!(self.data, ObjectData::DataView(_))
+    }
+
+    #[inline]
+    pub fn as_data_view(&self) -> Option<&DataView> {
+        match self.data {
+            ObjectData::DataView(ref data_view) => Some(data_view),
+            _ => None,
+        }
+    }
+
+    /// The entries of the weak collections that have this object as a key.
+    #[inline]
+    pub fn ephemerons(&self) -> &EphemeronTable {
+        &self.ephemerons
+    }
+
+    #[inline]
+    pub fn ephemerons_mut(&mut self) -> &mut EphemeronTable {
+        &mut self.ephemerons
+    }
+
     /// Checks if it a `String` object.
     #[inline]
     pub fn is_string(&self) -> bool {
@@ -341,10 +560,14 @@ impl Object {
         }
     }
 
-    /// Checks if it a `Function` object.
+    /// Checks if it a `Function` object, or a `Proxy` of a function.
     #[inline]
     pub fn is_function(&self) -> bool {
-        matches!(self.data, ObjectData::Function(_))
+        match self.data {
+            ObjectData::Function(_) | ObjectData::BoundFunction(_) => true,
+            ObjectData::Proxy(ref proxy) => proxy.is_callable(),
+            _ => false,
+        }
     }
 
     #[inline]
@@ -355,6 +578,50 @@ impl Object {
         }
     }
 
+    /// Checks if it is a bound function exotic object.
+    #[inline]
+    pub fn is_bound_function(&self) -> bool {
+        matches!(self.data, ObjectData::BoundFunction(_))
+    }
+
+    #[inline]
+    pub fn as_bound_function(&self) -> Option<&BoundFunction> {
+        match self.data {
+            ObjectData::BoundFunction(ref function) => Some(function),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_function_mut(&mut self) -> Option<&mut Function> {
+        match &mut self.data {
+            ObjectData::Function(function) => Some(function),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Proxy` object.
+    #[inline]
+    pub fn is_proxy(&self) -> bool {
+        matches!(self.data, ObjectData::Proxy(_))
+    }
+
+    #[inline]
+    pub fn as_proxy(&self) -> Option<&Proxy> {
+        match self.data {
+            ObjectData::Proxy(ref proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_proxy_mut(&mut self) -> Option<&mut Proxy> {
+        match self.data {
+            ObjectData::Proxy(ref mut proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
     /// Checks if it a Symbol object.
     #[inline]
     pub fn is_symbol(&self) -> bool {
@@ -372,13 +639,15 @@ impl Object {
     /// Checks if it an Error object.
     #[inline]
     pub fn is_error(&self) -> bool {
-        matches!(self.data, ObjectData::Error)
+        matches!(self.data, ObjectData::Error(_))
     }
 
+    /// Gets the frames of the call stack when the error was created, innermost first, if it is
+    /// an Error object.
     #[inline]
-    pub fn as_error(&self) -> Option<()> {
+    pub fn as_error(&self) -> Option<&[CallFrame]> {
         match self.data {
-            ObjectData::Error => Some(()),
+            ObjectData::Error(ref frames) => Some(frames),
             _ => None,
         }
     }
@@ -524,7 +793,7 @@ impl Object {
 /// There are two implementations:
 ///  - From a single type `T` which implements `Into<FunctionBinding>` which sets the binding
 /// name and the function name to the same value
-///  - From a tuple `(B: Into<PropertyKey>, N: AsRef<str>)` the `B` is the binding name
+///  - From a tuple `(B: Into<PropertyKey>, N: Into<RcString>)` the `B` is the binding name
 /// and the `N` is the function name.
 #[derive(Debug, Clone)]
 pub struct FunctionBinding {
@@ -569,13 +838,30 @@ impl From<RcString> for FunctionBinding {
 impl<B, N> From<(B, N)> for FunctionBinding
 where
     B: Into<PropertyKey>,
-    N: AsRef<str>,
+    N: Into<RcString>,
 {
     #[inline]
     fn from((binding, name): (B, N)) -> Self {
         Self {
             binding: binding.into(),
-            name: name.as_ref().into(),
+            name: name.into(),
+        }
+    }
+}
+
+/// The body of a function created by a [`FunctionBuilder`](struct.FunctionBuilder.html).
+#[derive(Debug, Clone)]
+enum FunctionBuilderBody {
+    Native(BuiltInFunction),
+    Closure(CapturingFunction),
+}
+
+impl FunctionBuilderBody {
+    /// Creates the function with the given flags.
+    fn to_function(&self, flags: FunctionFlags) -> Function {
+        match self {
+            Self::Native(function) => Function::BuiltIn(*function, flags),
+            Self::Closure(function) => Function::Capturing(function.clone(), flags),
         }
     }
 }
@@ -584,8 +870,8 @@ where
 #[derive(Debug)]
 pub struct FunctionBuilder<'context> {
     context: &'context mut Context,
-    function: BuiltInFunction,
-    name: Option<String>,
+    function: FunctionBuilderBody,
+    name: Option<RcString>,
     length: usize,
     callable: bool,
     constructable: bool,
@@ -597,7 +883,68 @@ impl<'context> FunctionBuilder<'context> {
     pub fn new(context: &'context mut Context, function: NativeFunction) -> Self {
         Self {
             context,
-            function: function.into(),
+            function: FunctionBuilderBody::Native(function.into()),
+            name: None,
+            length: 0,
+            callable: true,
+            constructable: false,
+        }
+    }
+
+    /// Create a new `FunctionBuilder` for a closure.
+    ///
+    /// The closure can capture Rust state, but not garbage collected values like `Value`, which
+    /// should be passed to [`closure_with_captures`](#method.closure_with_captures) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::{Context, Value, object::FunctionBuilder};
+    /// let mut context = Context::new();
+    /// let greeting = String::from("Hello");
+    /// let function = FunctionBuilder::closure(&mut context, move |_, args, ctx| {
+    ///     let name = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+    ///     Ok(format!("{}, {}!", greeting, name).into())
+    /// })
+    /// .name("greet")
+    /// .length(1)
+    /// .build();
+    /// ```
+    #[inline]
+    pub fn closure<F>(context: &'context mut Context, function: F) -> Self
+    where
+        F: ClosureFunction,
+    {
+        Self {
+            context,
+            function: FunctionBuilderBody::Closure(CapturingFunction::from_closure(function)),
+            name: None,
+            length: 0,
+            callable: true,
+            constructable: false,
+        }
+    }
+
+    /// Create a new `FunctionBuilder` for a closure, that is given a reference to `captures`
+    /// each time it is called.
+    ///
+    /// The captures are traced by the garbage collector, so they can hold garbage collected
+    /// values. Mutable state can be kept in a `GcCell`.
+    #[inline]
+    pub fn closure_with_captures<F, C>(
+        context: &'context mut Context,
+        function: F,
+        captures: C,
+    ) -> Self
+    where
+        F: Fn(&Value, &[Value], &C, &mut Context) -> Result<Value> + 'static,
+        C: NativeObject,
+    {
+        Self {
+            context,
+            function: FunctionBuilderBody::Closure(CapturingFunction::from_closure_with_captures(
+                function, captures,
+            )),
             name: None,
             length: 0,
             callable: true,
@@ -611,9 +958,9 @@ impl<'context> FunctionBuilder<'context> {
     #[inline]
     pub fn name<N>(&mut self, name: N) -> &mut Self
     where
-        N: AsRef<str>,
+        N: Into<RcString>,
     {
-        self.name = Some(name.as_ref().into());
+        self.name = Some(name.into());
         self
     }
 
@@ -650,10 +997,10 @@ impl<'context> FunctionBuilder<'context> {
     #[inline]
     pub fn build(&mut self) -> GcObject {
         let mut function = Object::function(
-            Function::BuiltIn(
-                self.function,
-                FunctionFlags::from_parameters(self.callable, self.constructable),
-            ),
+            self.function.to_function(FunctionFlags::from_parameters(
+                self.callable,
+                self.constructable,
+            )),
             self.context
                 .standard_objects()
                 .function_object()
@@ -674,8 +1021,7 @@ impl<'context> FunctionBuilder<'context> {
     /// Initializes the `Function.prototype` function object.
     pub(crate) fn build_function_prototype(&mut self, object: &GcObject) {
         let mut object = object.borrow_mut();
-        object.data = ObjectData::Function(Function::BuiltIn(
-            self.function,
+        object.data = ObjectData::Function(self.function.to_function(
             FunctionFlags::from_parameters(self.callable, self.constructable),
         ));
         object.set_prototype_instance(
@@ -753,6 +1099,32 @@ impl<'context> ObjectInitializer<'context> {
         self
     }
 
+    /// Add a closure to the object.
+    ///
+    /// See [`ClosureFunction`](../builtins/function/trait.ClosureFunction.html) for the state
+    /// the closure can capture.
+    #[inline]
+    pub fn closure<F, B>(&mut self, function: F, binding: B, length: usize) -> &mut Self
+    where
+        F: ClosureFunction,
+        B: Into<FunctionBinding>,
+    {
+        let binding = binding.into();
+        let function = FunctionBuilder::closure(self.context, function)
+            .name(binding.name)
+            .length(length)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        self.object.borrow_mut().insert_property(
+            binding.binding,
+            function,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        self
+    }
+
     /// Add a property to the object.
     #[inline]
     pub fn property<K, V>(&mut self, key: K, value: V, attribute: Attribute) -> &mut Self
@@ -778,7 +1150,7 @@ pub struct ConstructorBuilder<'context> {
     constrcutor_function: NativeFunction,
     constructor_object: GcObject,
     prototype: GcObject,
-    name: Option<String>,
+    name: Option<RcString>,
     length: usize,
     callable: bool,
     constructable: bool,
@@ -857,6 +1229,32 @@ impl<'context> ConstructorBuilder<'context> {
         self
     }
 
+    /// Add new closure method to the constructors prototype.
+    ///
+    /// See [`ClosureFunction`](../builtins/function/trait.ClosureFunction.html) for the state
+    /// the closure can capture.
+    #[inline]
+    pub fn closure_method<F, B>(&mut self, function: F, binding: B, length: usize) -> &mut Self
+    where
+        F: ClosureFunction,
+        B: Into<FunctionBinding>,
+    {
+        let binding = binding.into();
+        let function = FunctionBuilder::closure(self.context, function)
+            .name(binding.name)
+            .length(length)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        self.prototype.borrow_mut().insert_property(
+            binding.binding,
+            function,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        self
+    }
+
     /// Add new static method to the constructors object itself.
     #[inline]
     pub fn static_method<B>(
@@ -884,6 +1282,37 @@ impl<'context> ConstructorBuilder<'context> {
         self
     }
 
+    /// Add new static closure method to the constructors object itself.
+    ///
+    /// See [`ClosureFunction`](../builtins/function/trait.ClosureFunction.html) for the state
+    /// the closure can capture.
+    #[inline]
+    pub fn static_closure_method<F, B>(
+        &mut self,
+        function: F,
+        binding: B,
+        length: usize,
+    ) -> &mut Self
+    where
+        F: ClosureFunction,
+        B: Into<FunctionBinding>,
+    {
+        let binding = binding.into();
+        let function = FunctionBuilder::closure(self.context, function)
+            .name(binding.name)
+            .length(length)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        self.constructor_object.borrow_mut().insert_property(
+            binding.binding,
+            function,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        self
+    }
+
     /// Add new property to the constructors prototype.
     #[inline]
     pub fn property<K, V>(&mut self, key: K, value: V, attribute: Attribute) -> &mut Self
@@ -923,9 +1352,9 @@ impl<'context> ConstructorBuilder<'context> {
     #[inline]
     pub fn name<N>(&mut self, name: N) -> &mut Self
     where
-        N: AsRef<str>,
+        N: Into<RcString>,
     {
-        self.name = Some(name.as_ref().into());
+        self.name = Some(name.into());
         self
     }
 
@@ -976,7 +1405,9 @@ impl<'context> ConstructorBuilder<'context> {
             Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
         );
         let name = DataDescriptor::new(
-            self.name.take().unwrap_or_else(|| String::from("[object]")),
+            self.name
+                .take()
+                .unwrap_or_else(|| RcString::from("[object]")),
             Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
         );
 
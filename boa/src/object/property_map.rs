@@ -0,0 +1,83 @@
+//! This module implements the storage of the string and symbol keyed properties of an object.
+//!
+//! The specification requires these keys to be enumerated in the order they were created, so
+//! they are kept in an `IndexMap` instead of a plain hash map.
+
+use crate::{
+    gc::{custom_trace, Finalize, Trace},
+    property::PropertyDescriptor,
+};
+use indexmap::{
+    map::{Iter, Keys, Values},
+    IndexMap,
+};
+use rustc_hash::FxHasher;
+use std::hash::{BuildHasherDefault, Hash};
+
+/// The properties of an `Object` with keys of type `K`, in insertion order.
+#[derive(Debug, Finalize)]
+pub(crate) struct PropertyMap<K: Hash + Eq>(
+    IndexMap<K, PropertyDescriptor, BuildHasherDefault<FxHasher>>,
+);
+
+unsafe impl<K: Hash + Eq + Trace> Trace for PropertyMap<K> {
+    custom_trace!(this, {
+        for (key, property) in this.0.iter() {
+            mark(key);
+            mark(property);
+        }
+    });
+}
+
+impl<K: Hash + Eq> Default for PropertyMap<K> {
+    #[inline]
+    fn default() -> Self {
+        Self(IndexMap::default())
+    }
+}
+
+impl<K: Hash + Eq> PropertyMap<K> {
+    /// Gets the property descriptor of the given key.
+    #[inline]
+    pub(crate) fn get(&self, key: &K) -> Option<&PropertyDescriptor> {
+        self.0.get(key)
+    }
+
+    /// Inserts a property, returning the previous one if there was any.
+    ///
+    /// A property that replaces an existing one keeps its position in the order.
+    #[inline]
+    pub(crate) fn insert(
+        &mut self,
+        key: K,
+        property: PropertyDescriptor,
+    ) -> Option<PropertyDescriptor> {
+        self.0.insert(key, property)
+    }
+
+    /// Removes a property, returning it if it existed.
+    ///
+    /// The properties that follow it are shifted, so their relative order is preserved.
+    #[inline]
+    pub(crate) fn remove(&mut self, key: &K) -> Option<PropertyDescriptor> {
+        self.0.shift_remove(key)
+    }
+
+    /// An iterator visiting all key-property pairs in insertion order.
+    #[inline]
+    pub(crate) fn iter(&self) -> Iter<'_, K, PropertyDescriptor> {
+        self.0.iter()
+    }
+
+    /// An iterator visiting all keys in insertion order.
+    #[inline]
+    pub(crate) fn keys(&self) -> Keys<'_, K, PropertyDescriptor> {
+        self.0.keys()
+    }
+
+    /// An iterator visiting all properties in insertion order.
+    #[inline]
+    pub(crate) fn values(&self) -> Values<'_, K, PropertyDescriptor> {
+        self.0.values()
+    }
+}
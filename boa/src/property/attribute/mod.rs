@@ -60,7 +60,7 @@ impl Attribute {
         if value {
             *self |= Self::WRITABLE;
         } else {
-            *self |= *self & !Self::WRITABLE;
+            *self &= !Self::WRITABLE;
         }
     }
 
@@ -76,7 +76,7 @@ impl Attribute {
         if value {
             *self |= Self::ENUMERABLE;
         } else {
-            *self |= *self & !Self::ENUMERABLE;
+            *self &= !Self::ENUMERABLE;
         }
     }
 
@@ -92,7 +92,7 @@ impl Attribute {
         if value {
             *self |= Self::CONFIGURABLE;
         } else {
-            *self |= *self & !Self::CONFIGURABLE;
+            *self &= !Self::CONFIGURABLE;
         }
     }
 
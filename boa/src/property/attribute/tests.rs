@@ -135,3 +135,16 @@ fn set_configurable_to_false() {
     assert!(!attribute.enumerable());
     assert!(!attribute.configurable());
 }
+
+#[test]
+fn set_flags_to_false_clears_them() {
+    let mut attribute = Attribute::all();
+
+    attribute.set_writable(false);
+    attribute.set_enumerable(false);
+    attribute.set_configurable(false);
+
+    assert!(!attribute.writable());
+    assert!(!attribute.enumerable());
+    assert!(!attribute.configurable());
+}
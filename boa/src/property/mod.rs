@@ -303,7 +303,7 @@ impl PropertyDescriptor {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-ispropertykey
-#[derive(Trace, Finalize, Debug, Clone)]
+#[derive(Trace, Finalize, Debug, Clone, PartialEq, Eq)]
 pub enum PropertyKey {
     String(RcString),
     Symbol(RcSymbol),
@@ -313,7 +313,7 @@ pub enum PropertyKey {
 impl From<RcString> for PropertyKey {
     #[inline]
     fn from(string: RcString) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = string.to_std_string().ok().and_then(|s| s.parse().ok()) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string)
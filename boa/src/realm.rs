@@ -11,6 +11,7 @@ use crate::{
         lexical_environment::LexicalEnvironment,
         object_environment_record::ObjectEnvironmentRecord,
     },
+    object::GcObject,
     BoaProfiler, Value,
 };
 use gc::{Gc, GcCell};
@@ -24,6 +25,8 @@ pub struct Realm {
     pub global_obj: Value,
     pub global_env: Gc<GcCell<GlobalEnvironmentRecord>>,
     pub environment: LexicalEnvironment,
+    /// The template objects of the tagged templates evaluated in this realm, by template site.
+    pub template_map: FxHashMap<usize, GcObject>,
 }
 
 impl Realm {
@@ -43,6 +46,7 @@ impl Realm {
             global_obj: global.clone(),
             global_env,
             environment: LexicalEnvironment::new(global),
+            template_map: FxHashMap::default(),
         }
     }
 }
@@ -1,9 +1,9 @@
 //! Array declaration node.
 
-use super::{join_nodes, Node};
+use super::{join_nodes, Node, NodeKind};
 use crate::{
     builtins::{iterable, Array},
-    exec::Executable,
+    exec::{Executable, SuspendedFrame},
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -29,9 +29,9 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-ArrayLiteral
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ArrayDecl {
-    #[cfg_attr(feature = "serde", serde(flatten))]
     arr: Box<[Node]>,
 }
 
@@ -39,10 +39,18 @@ impl Executable for ArrayDecl {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ArrayDecl", "exec");
         let array = Array::new_array(interpreter)?;
-        let mut elements = Vec::new();
-        for elem in self.as_ref() {
-            if let Node::Spread(ref x) = elem {
-                let val = x.run(interpreter)?;
+        // Each element has its own step, the values of the frame are the elements evaluated
+        // so far.
+        let (start, mut elements) = interpreter
+            .executor()
+            .resume_frame()
+            .map_or((0, Vec::new()), |frame| (frame.step, frame.values));
+        for (i, elem) in self.as_ref().iter().enumerate().skip(start) {
+            let val = elem.run(interpreter);
+            let val = interpreter
+                .executor()
+                .save_frame(val, || SuspendedFrame::new(i, elements.clone()))?;
+            if let NodeKind::Spread(_) = elem.kind() {
                 let iterator_record = iterable::get_iterator(interpreter, val)?;
                 // TODO after proper internal Array representation as per https://github.com/boa-dev/boa/pull/811#discussion_r502460858
                 // next_index variable should be utilized here as per https://tc39.es/ecma262/#sec-runtime-semantics-arrayaccumulation
@@ -57,11 +65,11 @@ impl Executable for ArrayDecl {
                     elements.push(next_value.clone());
                 }
             } else {
-                elements.push(elem.run(interpreter)?);
+                elements.push(val);
             }
         }
 
-        Array::add_to_array_object(&array, &elements)?;
+        Array::add_to_array_object(&array, &elements, interpreter)?;
         Ok(array)
     }
 }
@@ -91,6 +99,6 @@ impl fmt::Display for ArrayDecl {
 
 impl From<ArrayDecl> for Node {
     fn from(arr: ArrayDecl) -> Self {
-        Self::ArrayDecl(arr)
+        NodeKind::ArrayDecl(arr).into()
     }
 }
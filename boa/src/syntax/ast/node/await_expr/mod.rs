@@ -0,0 +1,85 @@
+use crate::{
+    exec::{Executable, ResumeKind, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `await` operator is used to wait for a `Promise` inside an async function.
+///
+/// Syntax: `await expression;`
+///
+/// The `await` expression suspends the async function until the promise is settled. When the
+/// promise is fulfilled, the `await` expression evaluates to its value, and when it is rejected,
+/// the reason is thrown from the `await` expression.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AwaitExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/await
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct AwaitExpr {
+    expr: Box<Node>,
+}
+
+impl AwaitExpr {
+    /// Gets the expression whose value is awaited.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    /// Creates an `AwaitExpr` AST node.
+    pub fn new<E>(expr: E) -> Self
+    where
+        E: Into<Node>,
+    {
+        Self {
+            expr: Box::new(expr.into()),
+        }
+    }
+}
+
+impl Executable for AwaitExpr {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("AwaitExpr", "exec");
+        // Step 0 evaluates the expression and step 1 is a suspended `await`, which is resumed
+        // by the async function once the awaited promise is settled.
+        if let Some(frame) = interpreter.executor().resume_frame() {
+            if frame.step == 1 {
+                let (kind, value) = interpreter.executor().take_resume_value();
+                return match kind {
+                    ResumeKind::Throw => Err(value),
+                    _ => Ok(value),
+                };
+            }
+        }
+
+        let value = self.expr().run(interpreter);
+        let value = interpreter
+            .executor()
+            .save_frame(value, SuspendedFrame::default)?;
+
+        interpreter
+            .executor()
+            .suspend(SuspendedFrame::new(1, Vec::new()), value)
+    }
+}
+
+impl From<AwaitExpr> for Node {
+    fn from(await_expr: AwaitExpr) -> Node {
+        NodeKind::AwaitExpr(await_expr).into()
+    }
+}
+
+impl fmt::Display for AwaitExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "await {}", self.expr)
+    }
+}
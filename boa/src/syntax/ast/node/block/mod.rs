@@ -1,9 +1,10 @@
 //! Block AST node.
 
-use super::{Node, StatementList};
+use super::{Node, NodeKind, StatementList};
 use crate::{
-    environment::lexical_environment::new_declarative_environment, exec::Executable,
-    exec::InterpreterState, BoaProfiler, Context, Result, Value,
+    environment::lexical_environment::new_declarative_environment,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
 use std::fmt;
@@ -30,7 +31,6 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Block {
-    #[cfg_attr(feature = "serde", serde(flatten))]
     statements: StatementList,
 }
 
@@ -51,18 +51,25 @@ impl Block {
 impl Executable for Block {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Block", "exec");
-        {
+        // A suspended block still has its environment on the stack.
+        let start = if let Some(frame) = interpreter.executor().resume_frame() {
+            frame.step
+        } else {
             let env = &mut interpreter.realm_mut().environment;
             env.push(new_declarative_environment(Some(
                 env.get_current_environment_ref().clone(),
             )));
-        }
+            0
+        };
 
         // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
         // The return value is uninitialized, which means it defaults to Value::Undefined
         let mut obj = Value::default();
-        for statement in self.statements() {
-            obj = statement.run(interpreter)?;
+        for (i, statement) in self.statements().iter().enumerate().skip(start) {
+            let result = statement.run(interpreter);
+            obj = interpreter
+                .executor()
+                .save_frame(result, || SuspendedFrame::new(i, Vec::new()))?;
 
             match interpreter.executor().get_current_state() {
                 InterpreterState::Return => {
@@ -111,6 +118,6 @@ impl fmt::Display for Block {
 
 impl From<Block> for Node {
     fn from(block: Block) -> Self {
-        Self::Block(block)
+        NodeKind::Block(block).into()
     }
 }
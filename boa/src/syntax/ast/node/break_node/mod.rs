@@ -1,4 +1,4 @@
-use super::Node;
+use super::{Node, NodeKind};
 use crate::{exec::Executable, exec::InterpreterState, Context, Result, Value};
 use gc::{Finalize, Trace};
 use std::fmt;
@@ -73,6 +73,6 @@ impl fmt::Display for Break {
 
 impl From<Break> for Node {
     fn from(break_smt: Break) -> Node {
-        Self::Break(break_smt)
+        NodeKind::Break(break_smt).into()
     }
 }
@@ -1,8 +1,12 @@
 use crate::{
+    builtins::{eval::Eval, iterable},
     exec::Executable,
-    exec::InterpreterState,
-    syntax::ast::node::{join_nodes, Node},
-    value::{Type, Value},
+    exec::{InterpreterState, SuspendedFrame},
+    syntax::ast::{
+        node::{join_nodes, Node, NodeKind},
+        Span,
+    },
+    value::Value,
     BoaProfiler, Context, Result,
 };
 use gc::{Finalize, Trace};
@@ -56,43 +60,124 @@ impl Call {
     }
 }
 
+/// The first step of a call that evaluates the arguments.
+const ARGS_STEP: usize = 2;
+
 impl Executable for Call {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        self.run_at(None, interpreter)
+    }
+}
+
+impl Call {
+    /// Runs the call, recording the start of `span`, the span of the call node, in the call
+    /// stack when the function is called.
+    pub(in crate::syntax::ast::node) fn run_at(
+        &self,
+        span: Option<Span>,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Call", "exec");
-        let (this, func) = match self.expr() {
-            Node::GetConstField(ref get_const_field) => {
-                let mut obj = get_const_field.obj().run(interpreter)?;
-                if obj.get_type() != Type::Object {
-                    obj = Value::Object(obj.to_object(interpreter)?);
+        // Step 0 evaluates the callee, step 1 the field of a computed member callee, and the
+        // steps after that evaluate the arguments.
+        let (step, values) = interpreter
+            .executor()
+            .resume_frame()
+            .map_or((0, Vec::new()), |frame| (frame.step, frame.values));
+        let (this, func, mut v_args) = if step < ARGS_STEP {
+            let (this, func) = match self.expr().kind() {
+                NodeKind::GetConstField(ref get_const_field) => {
+                    let obj = get_const_field.obj().run(interpreter);
+                    let obj = interpreter
+                        .executor()
+                        .save_frame(obj, SuspendedFrame::default)?;
+                    let object = obj.to_object(interpreter)?;
+                    let func = object.get(&get_const_field.field().into(), &obj, interpreter)?;
+                    (object.into(), func)
                 }
-                (obj.clone(), obj.get_field(get_const_field.field()))
-            }
-            Node::GetField(ref get_field) => {
-                let obj = get_field.obj().run(interpreter)?;
-                let field = get_field.field().run(interpreter)?;
-                (
-                    obj.clone(),
-                    obj.get_field(field.to_property_key(interpreter)?),
-                )
-            }
-            _ => (
-                interpreter.realm().global_obj.clone(),
-                self.expr().run(interpreter)?,
-            ), // 'this' binding should come from the function's self-contained environment
+                NodeKind::GetField(ref get_field) => {
+                    let obj = match values.into_iter().next() {
+                        Some(obj) if step == 1 => obj,
+                        _ => {
+                            let obj = get_field.obj().run(interpreter);
+                            interpreter
+                                .executor()
+                                .save_frame(obj, SuspendedFrame::default)?
+                        }
+                    };
+                    let field = get_field.field().run(interpreter);
+                    let field = interpreter
+                        .executor()
+                        .save_frame(field, || SuspendedFrame::new(1, vec![obj.clone()]))?;
+                    let key = field.to_property_key(interpreter)?;
+                    let func = obj.to_object(interpreter)?.get(&key, &obj, interpreter)?;
+                    (obj, func)
+                }
+                NodeKind::GetSuperField(ref get_super_field) => {
+                    let (base, key, this) = get_super_field.reference(interpreter)?;
+                    let func = base.to_object(interpreter)?.get(&key, &this, interpreter)?;
+                    (this, func)
+                }
+                _ => {
+                    let func = self.expr().run(interpreter);
+                    let func = interpreter
+                        .executor()
+                        .save_frame(func, SuspendedFrame::default)?;
+                    // 'this' binding should come from the function's self-contained environment
+                    (interpreter.realm().global_obj.clone(), func)
+                }
+            };
+            (this, func, Vec::with_capacity(self.args().len()))
+        } else {
+            let mut values = values.into_iter();
+            let this = values.next().unwrap_or_default();
+            let func = values.next().unwrap_or_default();
+            (this, func, values.collect())
+        };
+        let save = |step: usize, this: &Value, func: &Value, v_args: &[Value]| {
+            let mut values = vec![this.clone(), func.clone()];
+            values.extend_from_slice(v_args);
+            SuspendedFrame::new(step, values)
         };
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            if let Node::Spread(ref x) = arg {
-                let val = x.run(interpreter)?;
-                let mut vals = interpreter.extract_array_properties(&val).unwrap();
-                v_args.append(&mut vals);
+        for (i, arg) in self
+            .args()
+            .iter()
+            .enumerate()
+            .skip(step.saturating_sub(ARGS_STEP))
+        {
+            let val = arg.run(interpreter);
+            let val = interpreter
+                .executor()
+                .save_frame(val, || save(ARGS_STEP + i, &this, &func, &v_args))?;
+            if let NodeKind::Spread(_) = arg.kind() {
+                let iterator_record = iterable::get_iterator(interpreter, val)?;
+                loop {
+                    let next = iterator_record.next(interpreter)?;
+                    if next.is_done() {
+                        break;
+                    }
+                    v_args.push(next.value());
+                }
                 break; // after spread we don't accept any new arguments
             }
-            v_args.push(arg.run(interpreter)?);
+            v_args.push(val);
         }
 
         // execute the function call itself
-        let fnct_result = interpreter.call(&func, &this, &v_args);
+        if let Some(span) = span {
+            interpreter.set_call_position(span.start());
+        }
+        let fnct_result = match self.expr().kind() {
+            // A direct call to `eval` evaluates the code in the current scope.
+            // <https://tc39.es/ecma262/#sec-function-calls-runtime-semantics-evaluation>
+            NodeKind::Identifier(ref ident)
+                if ident.as_ref() == "eval" && interpreter.is_eval_function(&func) =>
+            {
+                let x = v_args.first().cloned().unwrap_or_default();
+                Eval::perform_eval(&x, true, interpreter)
+            }
+            _ => interpreter.call(&func, &this, &v_args),
+        };
 
         // unset the early return flag
         interpreter
@@ -113,6 +198,6 @@ impl fmt::Display for Call {
 
 impl From<Call> for Node {
     fn from(call: Call) -> Self {
-        Self::Call(call)
+        NodeKind::Call(call).into()
     }
 }
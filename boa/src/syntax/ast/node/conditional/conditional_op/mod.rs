@@ -1,4 +1,8 @@
-use crate::{exec::Executable, syntax::ast::node::Node, Context, Result, Value};
+use crate::{
+    exec::{Executable, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
+    Context, Result, Value,
+};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -57,11 +61,30 @@ impl ConditionalOp {
 
 impl Executable for ConditionalOp {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        Ok(if self.cond().run(interpreter)?.to_boolean() {
-            self.if_true().run(interpreter)?
+        // Step 0 evaluates the condition, 1 the `true` branch and 2 the `false` branch.
+        let branch = match interpreter.executor().resume_frame() {
+            Some(frame) if frame.step != 0 => frame.step,
+            _ => {
+                let cond = self.cond().run(interpreter);
+                let cond = interpreter
+                    .executor()
+                    .save_frame(cond, SuspendedFrame::default)?;
+                if cond.to_boolean() {
+                    1
+                } else {
+                    2
+                }
+            }
+        };
+
+        let result = if branch == 1 {
+            self.if_true().run(interpreter)
         } else {
-            self.if_false().run(interpreter)?
-        })
+            self.if_false().run(interpreter)
+        };
+        interpreter
+            .executor()
+            .save_frame(result, || SuspendedFrame::new(branch, Vec::new()))
     }
 }
 
@@ -79,6 +102,6 @@ impl fmt::Display for ConditionalOp {
 
 impl From<ConditionalOp> for Node {
     fn from(cond_op: ConditionalOp) -> Node {
-        Self::ConditionalOp(cond_op)
+        NodeKind::ConditionalOp(cond_op).into()
     }
 }
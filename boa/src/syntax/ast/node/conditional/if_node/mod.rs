@@ -1,4 +1,8 @@
-use crate::{exec::Executable, syntax::ast::node::Node, Context, Result, Value};
+use crate::{
+    exec::{Executable, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
+    Context, Result, Value,
+};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -76,13 +80,32 @@ impl If {
 
 impl Executable for If {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        Ok(if self.cond().run(interpreter)?.to_boolean() {
-            self.body().run(interpreter)?
+        // Step 0 evaluates the condition, 1 the body and 2 the `else` node.
+        let branch = match interpreter.executor().resume_frame() {
+            Some(frame) if frame.step != 0 => frame.step,
+            _ => {
+                let cond = self.cond().run(interpreter);
+                let cond = interpreter
+                    .executor()
+                    .save_frame(cond, SuspendedFrame::default)?;
+                if cond.to_boolean() {
+                    1
+                } else {
+                    2
+                }
+            }
+        };
+
+        let result = if branch == 1 {
+            self.body().run(interpreter)
         } else if let Some(ref else_e) = self.else_node() {
-            else_e.run(interpreter)?
+            else_e.run(interpreter)
         } else {
-            Value::undefined()
-        })
+            Ok(Value::undefined())
+        };
+        interpreter
+            .executor()
+            .save_frame(result, || SuspendedFrame::new(branch, Vec::new()))
     }
 }
 
@@ -94,6 +117,6 @@ impl fmt::Display for If {
 
 impl From<If> for Node {
     fn from(if_stm: If) -> Node {
-        Self::If(if_stm)
+        NodeKind::If(if_stm).into()
     }
 }
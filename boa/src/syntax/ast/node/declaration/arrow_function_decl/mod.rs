@@ -1,7 +1,8 @@
 use crate::{
     builtins::function::FunctionFlags,
     exec::Executable,
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::node::{join_nodes, FormalParameter, Node, NodeKind, SourceText, StatementList},
+    value::RcString,
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -28,6 +29,7 @@ use serde::{Deserialize, Serialize};
 pub struct ArrowFunctionDecl {
     params: Box<[FormalParameter]>,
     body: StatementList,
+    source: SourceText,
 }
 
 impl ArrowFunctionDecl {
@@ -40,9 +42,21 @@ impl ArrowFunctionDecl {
         Self {
             params: params.into(),
             body: body.into(),
+            source: SourceText::default(),
         }
     }
 
+    /// Sets the source text of the function, recorded by the parser.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<RcString>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Gets the source text of the function, if it was recorded.
+    pub fn source(&self) -> Option<&RcString> {
+        self.source.get()
+    }
+
     /// Gets the list of parameters of the arrow function.
     pub(crate) fn params(&self) -> &[FormalParameter] {
         &self.params
@@ -69,11 +83,11 @@ impl ArrowFunctionDecl {
 impl Executable for ArrowFunctionDecl {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         Ok(interpreter.create_function(
+            "",
             self.params().to_vec(),
             self.body().to_vec(),
-            FunctionFlags::CALLABLE
-                | FunctionFlags::CONSTRUCTABLE
-                | FunctionFlags::LEXICAL_THIS_MODE,
+            FunctionFlags::CALLABLE | FunctionFlags::LEXICAL_THIS_MODE,
+            self.source().cloned(),
         ))
     }
 }
@@ -86,6 +100,6 @@ impl fmt::Display for ArrowFunctionDecl {
 
 impl From<ArrowFunctionDecl> for Node {
     fn from(decl: ArrowFunctionDecl) -> Self {
-        Self::ArrowFunctionDecl(decl)
+        NodeKind::ArrowFunctionDecl(decl).into()
     }
 }
@@ -0,0 +1,103 @@
+use crate::{
+    builtins::function::FunctionFlags,
+    exec::Executable,
+    syntax::ast::node::{join_nodes, FormalParameter, Node, NodeKind, SourceText, StatementList},
+    value::RcString,
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An async arrow function is an arrow function whose body may contain `await` expressions.
+///
+/// Like async functions, calling it returns a `Promise`, and like arrow functions, it takes
+/// `this` from the enclosing scope and cannot be used as a constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncArrowFunction
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct AsyncArrowFunctionDecl {
+    params: Box<[FormalParameter]>,
+    body: StatementList,
+    source: SourceText,
+}
+
+impl AsyncArrowFunctionDecl {
+    /// Creates a new `AsyncArrowFunctionDecl` AST node.
+    pub(in crate::syntax) fn new<P, B>(params: P, body: B) -> Self
+    where
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            params: params.into(),
+            body: body.into(),
+            source: SourceText::default(),
+        }
+    }
+
+    /// Sets the source text of the function, recorded by the parser.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<RcString>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Gets the source text of the function, if it was recorded.
+    pub fn source(&self) -> Option<&RcString> {
+        self.source.get()
+    }
+
+    /// Gets the list of parameters of the async arrow function.
+    pub(crate) fn params(&self) -> &[FormalParameter] {
+        &self.params
+    }
+
+    /// Gets the body of the async arrow function.
+    pub(crate) fn body(&self) -> &[Node] {
+        &self.body.statements()
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "async (")?;
+        join_nodes(f, &self.params)?;
+        f.write_str(") => ")?;
+        self.body.display(f, indentation)
+    }
+}
+
+impl Executable for AsyncArrowFunctionDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        Ok(interpreter.create_function(
+            "",
+            self.params().to_vec(),
+            self.body().to_vec(),
+            FunctionFlags::CALLABLE | FunctionFlags::LEXICAL_THIS_MODE | FunctionFlags::ASYNC,
+            self.source().cloned(),
+        ))
+    }
+}
+
+impl fmt::Display for AsyncArrowFunctionDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<AsyncArrowFunctionDecl> for Node {
+    fn from(decl: AsyncArrowFunctionDecl) -> Self {
+        NodeKind::AsyncArrowFunctionDecl(decl).into()
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    builtins::function::FunctionFlags,
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    syntax::ast::node::{join_nodes, FormalParameter, Node, NodeKind, SourceText, StatementList},
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `async function` declaration defines an asynchronous function with the specified
+/// parameters.
+///
+/// Calling an async function always returns a `Promise`. The body of the function runs until
+/// the first `await` expression, and is resumed from the job queue once the awaited value is
+/// settled. The promise is resolved with the value returned by the function, or rejected with
+/// the exception thrown from it.
+///
+/// An async function can also be created using an expression (see
+/// [async function expression][async_expr]).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+/// [async_expr]: ../enum.Node.html#variant.AsyncFunctionExpr
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct AsyncFunctionDecl {
+    name: Box<str>,
+    parameters: Box<[FormalParameter]>,
+    body: StatementList,
+    source: SourceText,
+}
+
+impl AsyncFunctionDecl {
+    /// Creates a new async function declaration.
+    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    where
+        N: Into<Box<str>>,
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            name: name.into(),
+            parameters: parameters.into(),
+            body: body.into(),
+            source: SourceText::default(),
+        }
+    }
+
+    /// Sets the source text of the function, recorded by the parser.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<RcString>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Gets the source text of the function, if it was recorded.
+    pub fn source(&self) -> Option<&RcString> {
+        self.source.get()
+    }
+
+    /// Gets the name of the async function declaration.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the list of parameters of the async function declaration.
+    pub fn parameters(&self) -> &[FormalParameter] {
+        &self.parameters
+    }
+
+    /// Gets the body of the async function declaration.
+    pub fn body(&self) -> &[Node] {
+        self.body.statements()
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "async function {}(", self.name)?;
+        join_nodes(f, &self.parameters)?;
+        f.write_str(") {{")?;
+
+        self.body.display(f, indentation + 1)?;
+
+        writeln!(f, "}}")
+    }
+}
+
+impl Executable for AsyncFunctionDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("AsyncFunctionDecl", "exec");
+        let val = interpreter.create_function(
+            self.name(),
+            self.parameters().to_vec(),
+            self.body().to_vec(),
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+            self.source().cloned(),
+        );
+
+        // Assign it in the current environment
+        interpreter.realm_mut().environment.create_mutable_binding(
+            self.name().to_owned(),
+            false,
+            VariableScope::Function,
+        );
+
+        interpreter
+            .realm_mut()
+            .environment
+            .initialize_binding(self.name(), val);
+
+        Ok(Value::undefined())
+    }
+}
+
+impl From<AsyncFunctionDecl> for Node {
+    fn from(decl: AsyncFunctionDecl) -> Self {
+        NodeKind::AsyncFunctionDecl(decl).into()
+    }
+}
+
+impl fmt::Display for AsyncFunctionDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
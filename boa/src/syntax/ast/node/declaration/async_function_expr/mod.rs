@@ -0,0 +1,119 @@
+use crate::{
+    builtins::function::FunctionFlags,
+    exec::Executable,
+    syntax::ast::node::{join_nodes, FormalParameter, Node, NodeKind, SourceText, StatementList},
+    value::RcString,
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `async function` expression defines an async function inside an expression.
+///
+/// An async function can also be created using a declaration (see async function declaration).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct AsyncFunctionExpr {
+    name: Option<Box<str>>,
+    parameters: Box<[FormalParameter]>,
+    body: StatementList,
+    source: SourceText,
+}
+
+impl AsyncFunctionExpr {
+    /// Creates a new async function expression
+    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            name: name.into(),
+            parameters: parameters.into(),
+            body: body.into(),
+            source: SourceText::default(),
+        }
+    }
+
+    /// Sets the source text of the function, recorded by the parser.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<RcString>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Gets the source text of the function, if it was recorded.
+    pub fn source(&self) -> Option<&RcString> {
+        self.source.get()
+    }
+
+    /// Gets the name of the async function expression.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the list of parameters of the async function expression.
+    pub fn parameters(&self) -> &[FormalParameter] {
+        &self.parameters
+    }
+
+    /// Gets the body of the async function expression.
+    pub fn body(&self) -> &[Node] {
+        self.body.statements()
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("async function")?;
+        if let Some(ref name) = self.name {
+            write!(f, " {}", name)?;
+        }
+        f.write_str("(")?;
+        join_nodes(f, &self.parameters)?;
+        f.write_str(") {{")?;
+
+        self.body.display(f, indentation + 1)?;
+
+        writeln!(f, "}}")
+    }
+}
+
+impl Executable for AsyncFunctionExpr {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let val = interpreter.create_function(
+            self.name().unwrap_or(""),
+            self.parameters().to_vec(),
+            self.body().to_vec(),
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+            self.source().cloned(),
+        );
+
+        Ok(val)
+    }
+}
+
+impl fmt::Display for AsyncFunctionExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<AsyncFunctionExpr> for Node {
+    fn from(expr: AsyncFunctionExpr) -> Self {
+        NodeKind::AsyncFunctionExpr(expr).into()
+    }
+}
@@ -0,0 +1,508 @@
+use crate::{
+    builtins::function::FunctionFlags,
+    environment::lexical_environment::{new_declarative_environment, VariableScope},
+    exec::Executable,
+    object::{GcObject, Object, PROTOTYPE},
+    property::{AccessorDescriptor, Attribute, PropertyDescriptor},
+    syntax::ast::node::{
+        function_name, FormalParameter, FunctionExpr, Identifier, MethodDefinitionKind, Node,
+        NodeKind, PropertyName, SourceText, Spread, StatementList, SuperCall,
+    },
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The shared part of class declarations and class expressions.
+///
+/// A class is a template for creating objects. It consists of an optional name, an optional
+/// heritage (the `extends` clause), an optional constructor and a list of methods.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Class {
+    name: Option<Box<str>>,
+    super_ref: Option<Box<Node>>,
+    constructor: Option<FunctionExpr>,
+    elements: Box<[ClassElement]>,
+    source: SourceText,
+}
+
+impl Class {
+    /// Creates a new class.
+    pub(in crate::syntax) fn new<N, S, C, E>(
+        name: N,
+        super_ref: S,
+        constructor: C,
+        elements: E,
+    ) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        S: Into<Option<Node>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            name: name.into(),
+            super_ref: super_ref.into().map(Box::new),
+            constructor: constructor.into(),
+            elements: elements.into(),
+            source: SourceText::default(),
+        }
+    }
+
+    /// Sets the source text of the class, recorded by the parser.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<RcString>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Gets the source text of the class, if it was recorded.
+    ///
+    /// This is also the source text of the class constructor.
+    pub fn source(&self) -> Option<&RcString> {
+        self.source.get()
+    }
+
+    /// Gets the name of the class.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the expression of the `extends` clause, if any.
+    pub fn super_ref(&self) -> Option<&Node> {
+        self.super_ref.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the explicit constructor of the class, if any.
+    pub fn constructor(&self) -> Option<&FunctionExpr> {
+        self.constructor.as_ref()
+    }
+
+    /// Gets the list of methods of the class.
+    pub fn elements(&self) -> &[ClassElement] {
+        &self.elements
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("class")?;
+        if let Some(ref name) = self.name {
+            write!(f, " {}", name)?;
+        }
+        if let Some(ref super_ref) = self.super_ref {
+            write!(f, " extends {}", super_ref)?;
+        }
+        writeln!(f, " {{")?;
+
+        let indent = "    ".repeat(indentation + 1);
+        if let Some(ref constructor) = self.constructor {
+            f.write_str(&indent)?;
+            f.write_str("constructor")?;
+            constructor.display_method(f, indentation + 1)?;
+        }
+        for element in self.elements.iter() {
+            f.write_str(&indent)?;
+            element.display(f, indentation + 1)?;
+        }
+
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
+
+    /// Runs the class definition evaluation and returns the class constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+    pub(in crate::syntax::ast::node) fn evaluate(
+        &self,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Class", "exec");
+
+        // The class body has its own scope, where the class name is bound to the constructor.
+        {
+            let env = &mut interpreter.realm_mut().environment;
+            let class_scope =
+                new_declarative_environment(Some(env.get_current_environment_ref().clone()));
+            env.push(class_scope);
+            if let Some(name) = self.name() {
+                env.create_immutable_binding(name.to_owned(), true, VariableScope::Block);
+            }
+        }
+
+        let result = self.define(interpreter);
+        interpreter.realm_mut().environment.pop();
+        result
+    }
+
+    /// Creates the constructor and the prototype of the class, and defines all methods.
+    fn define(&self, interpreter: &mut Context) -> Result<Value> {
+        let (proto_parent, constructor_parent) = match self.super_ref() {
+            None => (
+                interpreter
+                    .standard_objects()
+                    .object_object()
+                    .prototype()
+                    .into(),
+                interpreter
+                    .standard_objects()
+                    .function_object()
+                    .prototype()
+                    .into(),
+            ),
+            Some(super_ref) => {
+                let superclass = super_ref.run(interpreter)?;
+                if superclass.is_null() {
+                    (
+                        Value::null(),
+                        interpreter
+                            .standard_objects()
+                            .function_object()
+                            .prototype()
+                            .into(),
+                    )
+                } else if superclass
+                    .as_gc_object()
+                    .map(|superclass| superclass.is_constructable())
+                    .unwrap_or(false)
+                {
+                    let proto_parent = superclass.get_field(PROTOTYPE, interpreter)?;
+                    if !proto_parent.is_object() && !proto_parent.is_null() {
+                        return interpreter.throw_type_error(format!(
+                            "Class extends value does not have valid prototype property {}",
+                            proto_parent.display()
+                        ));
+                    }
+                    (proto_parent, superclass)
+                } else {
+                    return interpreter.throw_type_error(format!(
+                        "Class extends value {} is not a constructor or null",
+                        superclass.display()
+                    ));
+                }
+            }
+        };
+
+        let prototype = GcObject::new(Object::create(proto_parent));
+
+        let mut flags = FunctionFlags::CALLABLE
+            | FunctionFlags::CONSTRUCTABLE
+            | FunctionFlags::CLASS_CONSTRUCTOR;
+        if self.super_ref.is_some() {
+            flags |= FunctionFlags::DERIVED;
+        }
+        let constructor = match self.constructor() {
+            Some(constructor) => interpreter.create_function(
+                self.name().unwrap_or(""),
+                constructor.parameters().to_vec(),
+                constructor.body().to_vec(),
+                flags,
+                self.source().cloned(),
+            ),
+            // constructor(...args) { super(...args); }
+            None if self.super_ref.is_some() => interpreter.create_function(
+                self.name().unwrap_or(""),
+                vec![FormalParameter::new("args", None, true)],
+                vec![SuperCall::new(vec![Spread::new(Identifier::from("args")).into()]).into()],
+                flags,
+                self.source().cloned(),
+            ),
+            // constructor() {}
+            None => interpreter.create_function(
+                self.name().unwrap_or(""),
+                Vec::new(),
+                StatementList::from(Vec::new()),
+                flags,
+                self.source().cloned(),
+            ),
+        };
+        let constructor_object = constructor
+            .as_gc_object()
+            .expect("create_function always returns an object");
+
+        {
+            let mut constructor = constructor_object.borrow_mut();
+            constructor.set_prototype_instance(constructor_parent);
+            constructor
+                .as_function_mut()
+                .expect("create_function always returns a function")
+                .set_home_object(prototype.clone());
+            constructor.insert_property(
+                PROTOTYPE,
+                prototype.clone(),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            );
+        }
+        prototype.borrow_mut().insert_property(
+            "constructor",
+            constructor.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+
+        for element in self.elements().iter() {
+            let target = if element.is_static() {
+                constructor_object.clone()
+            } else {
+                prototype.clone()
+            };
+            element.define(&target, interpreter)?;
+        }
+
+        if let Some(name) = self.name() {
+            interpreter
+                .realm_mut()
+                .environment
+                .initialize_binding(name, constructor.clone());
+        }
+
+        Ok(constructor)
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+/// A method, getter or setter defined in the body of a class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes#Class_body_and_method_definitions
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassElement {
+    is_static: bool,
+    kind: MethodDefinitionKind,
+    name: PropertyName,
+    method: FunctionExpr,
+}
+
+impl ClassElement {
+    /// Creates a new class element.
+    pub(in crate::syntax) fn new<N>(
+        is_static: bool,
+        kind: MethodDefinitionKind,
+        name: N,
+        method: FunctionExpr,
+    ) -> Self
+    where
+        N: Into<PropertyName>,
+    {
+        Self {
+            is_static,
+            kind,
+            name: name.into(),
+            method,
+        }
+    }
+
+    /// Returns `true` if the element is defined on the constructor instead of the prototype.
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    /// Gets the kind of the method.
+    pub fn kind(&self) -> MethodDefinitionKind {
+        self.kind
+    }
+
+    /// Gets the name of the method.
+    pub fn name(&self) -> &PropertyName {
+        &self.name
+    }
+
+    /// Gets the function of the method.
+    pub fn method(&self) -> &FunctionExpr {
+        &self.method
+    }
+
+    /// Implements the display formatting with indentation.
+    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if self.is_static {
+            f.write_str("static ")?;
+        }
+        match self.kind {
+            MethodDefinitionKind::Get => f.write_str("get ")?,
+            MethodDefinitionKind::Set => f.write_str("set ")?,
+            MethodDefinitionKind::Generator => f.write_str("*")?,
+            MethodDefinitionKind::Async => f.write_str("async ")?,
+            MethodDefinitionKind::Ordinary => {}
+        }
+        write!(f, "{}", self.name)?;
+        self.method.display_method(f, indentation)
+    }
+
+    /// Defines the method on the prototype or the constructor of the class.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classelementevaluation
+    fn define(&self, target: &GcObject, interpreter: &mut Context) -> Result<()> {
+        let key = self.name.run(interpreter)?.to_property_key(interpreter)?;
+        let flags = match self.kind {
+            MethodDefinitionKind::Generator => FunctionFlags::CALLABLE | FunctionFlags::GENERATOR,
+            MethodDefinitionKind::Async => FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+            _ => FunctionFlags::CALLABLE,
+        };
+        let function = interpreter.create_function(
+            function_name(&key),
+            self.method.parameters().to_vec(),
+            self.method.body().to_vec(),
+            flags,
+            self.method.source().cloned(),
+        );
+        let function = function
+            .as_gc_object()
+            .expect("create_function always returns an object");
+        function
+            .borrow_mut()
+            .as_function_mut()
+            .expect("create_function always returns a function")
+            .set_home_object(target.clone());
+
+        let mut target = target.borrow_mut();
+        let existing = target.get_own_property(&key);
+        match self.kind {
+            MethodDefinitionKind::Ordinary
+            | MethodDefinitionKind::Generator
+            | MethodDefinitionKind::Async => {
+                target.insert_property(
+                    key,
+                    function,
+                    Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                );
+            }
+            MethodDefinitionKind::Get => {
+                let setter = match existing {
+                    Some(PropertyDescriptor::Accessor(ref accessor)) => accessor.setter().cloned(),
+                    _ => None,
+                };
+                target.insert(
+                    key,
+                    AccessorDescriptor::new(
+                        Some(function),
+                        setter,
+                        Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                    ),
+                );
+            }
+            MethodDefinitionKind::Set => {
+                let getter = match existing {
+                    Some(PropertyDescriptor::Accessor(ref accessor)) => accessor.getter().cloned(),
+                    _ => None,
+                };
+                target.insert(
+                    key,
+                    AccessorDescriptor::new(
+                        getter,
+                        Some(function),
+                        Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                    ),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The `class` declaration creates a new class with a given name using prototype-based
+/// inheritance.
+///
+/// Unlike function declarations, class declarations are not hoisted, and the binding they
+/// create is block scoped, like `let`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassDecl {
+    class: Class,
+}
+
+impl ClassDecl {
+    /// Gets the class being declared.
+    pub fn class(&self) -> &Class {
+        &self.class
+    }
+
+    /// Gets the name of the class.
+    pub fn name(&self) -> &str {
+        self.class
+            .name()
+            .expect("class declarations always have a name")
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.class.display(f, indentation)
+    }
+}
+
+impl Executable for ClassDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ClassDecl", "exec");
+        let class = self.class.evaluate(interpreter)?;
+
+        interpreter.realm_mut().environment.create_mutable_binding(
+            self.name().to_owned(),
+            false,
+            VariableScope::Block,
+        );
+        interpreter
+            .realm_mut()
+            .environment
+            .initialize_binding(self.name(), class);
+
+        Ok(Value::undefined())
+    }
+}
+
+impl From<Class> for ClassDecl {
+    fn from(class: Class) -> Self {
+        Self { class }
+    }
+}
+
+impl From<ClassDecl> for Node {
+    fn from(decl: ClassDecl) -> Self {
+        NodeKind::ClassDecl(decl).into()
+    }
+}
+
+impl fmt::Display for ClassDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
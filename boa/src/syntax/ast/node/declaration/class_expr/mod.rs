@@ -0,0 +1,69 @@
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{Class, Node, NodeKind},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `class` expression is one way to define a class.
+///
+/// Class expressions can be named or unnamed. The name given to a named class expression is
+/// local to the class's body.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassExpr {
+    class: Class,
+}
+
+impl ClassExpr {
+    /// Gets the class being defined.
+    pub fn class(&self) -> &Class {
+        &self.class
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.class.display(f, indentation)
+    }
+}
+
+impl Executable for ClassExpr {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ClassExpr", "exec");
+        self.class.evaluate(interpreter)
+    }
+}
+
+impl From<Class> for ClassExpr {
+    fn from(class: Class) -> Self {
+        Self { class }
+    }
+}
+
+impl From<ClassExpr> for Node {
+    fn from(expr: ClassExpr) -> Self {
+        NodeKind::ClassExpr(expr).into()
+    }
+}
+
+impl fmt::Display for ClassExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
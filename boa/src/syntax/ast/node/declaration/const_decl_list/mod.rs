@@ -1,7 +1,10 @@
 use crate::{
-    environment::lexical_environment::VariableScope,
-    exec::Executable,
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    exec::{Executable, SuspendedFrame},
+    syntax::ast::node::{
+        join_nodes,
+        pattern::{Binding, BindingKind},
+        Node, NodeKind,
+    },
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -29,30 +32,37 @@ use serde::{Deserialize, Serialize};
 /// [identifier]: https://developer.mozilla.org/en-US/docs/Glossary/identifier
 /// [expression]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Expressions_and_Operators#Expressions
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ConstDeclList {
-    #[cfg_attr(feature = "serde", serde(flatten))]
     list: Box<[ConstDecl]>,
 }
 
 impl Executable for ConstDeclList {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        for decl in self.as_ref() {
-            let val = if let Some(init) = decl.init() {
-                init.run(interpreter)?
+        // The frame of a declaration that was suspended while binding its value keeps the value.
+        let (start, mut resumed) = match interpreter.executor().resume_frame() {
+            Some(frame) => (frame.step, frame.values.into_iter().next()),
+            None => (0, None),
+        };
+        for (i, decl) in self.as_ref().iter().enumerate().skip(start) {
+            let val = if let Some(val) = resumed.take() {
+                val
+            } else if let Some(init) = decl.init() {
+                let val = init.run(interpreter);
+                interpreter
+                    .executor()
+                    .save_frame(val, || SuspendedFrame::new(i, Vec::new()))?
             } else {
                 return interpreter.throw_syntax_error("missing = in const declaration");
             };
 
+            let res = decl
+                .binding()
+                .bind(val.clone(), BindingKind::Const, interpreter);
             interpreter
-                .realm_mut()
-                .environment
-                .create_immutable_binding(decl.name().to_owned(), false, VariableScope::Block);
-
-            interpreter
-                .realm_mut()
-                .environment
-                .initialize_binding(decl.name(), val);
+                .executor()
+                .save_frame(res, || SuspendedFrame::new(i, vec![val]))?;
         }
         Ok(Value::undefined())
     }
@@ -94,7 +104,7 @@ impl fmt::Display for ConstDeclList {
 
 impl From<ConstDeclList> for Node {
     fn from(list: ConstDeclList) -> Self {
-        Self::ConstDeclList(list)
+        NodeKind::ConstDeclList(list).into()
     }
 }
 
@@ -102,13 +112,13 @@ impl From<ConstDeclList> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ConstDecl {
-    name: Identifier,
+    binding: Binding,
     init: Option<Node>,
 }
 
 impl fmt::Display for ConstDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
+        fmt::Display::fmt(&self.binding, f)?;
         if let Some(ref init) = self.init {
             write!(f, " = {}", init)?;
         }
@@ -118,20 +128,20 @@ impl fmt::Display for ConstDecl {
 
 impl ConstDecl {
     /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: Option<I>) -> Self
+    pub(in crate::syntax) fn new<B, I>(binding: B, init: Option<I>) -> Self
     where
-        N: Into<Identifier>,
+        B: Into<Binding>,
         I: Into<Node>,
     {
         Self {
-            name: name.into(),
+            binding: binding.into(),
             init: init.map(|n| n.into()),
         }
     }
 
-    /// Gets the name of the variable.
-    pub fn name(&self) -> &str {
-        self.name.as_ref()
+    /// Gets the name or the destructuring pattern of the variable.
+    pub fn binding(&self) -> &Binding {
+        &self.binding
     }
 
     /// Gets the initialization node for the variable, if any.
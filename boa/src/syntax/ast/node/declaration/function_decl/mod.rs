@@ -2,7 +2,8 @@ use crate::{
     builtins::function::FunctionFlags,
     environment::lexical_environment::VariableScope,
     exec::Executable,
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::node::{join_nodes, FormalParameter, Node, NodeKind, SourceText, StatementList},
+    value::RcString,
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -35,6 +36,7 @@ pub struct FunctionDecl {
     name: Box<str>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source: SourceText,
 }
 
 impl FunctionDecl {
@@ -49,9 +51,21 @@ impl FunctionDecl {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source: SourceText::default(),
         }
     }
 
+    /// Sets the source text of the function, recorded by the parser.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<RcString>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Gets the source text of the function, if it was recorded.
+    pub fn source(&self) -> Option<&RcString> {
+        self.source.get()
+    }
+
     /// Gets the name of the function declaration.
     pub fn name(&self) -> &str {
         &self.name
@@ -87,13 +101,14 @@ impl Executable for FunctionDecl {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
         let val = interpreter.create_function(
+            self.name(),
             self.parameters().to_vec(),
             self.body().to_vec(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
+            self.source().cloned(),
         );
 
-        // Set the name and assign it in the current environment
-        val.set_field("name", self.name());
+        // Assign it in the current environment
         interpreter.realm_mut().environment.create_mutable_binding(
             self.name().to_owned(),
             false,
@@ -111,7 +126,7 @@ impl Executable for FunctionDecl {
 
 impl From<FunctionDecl> for Node {
     fn from(decl: FunctionDecl) -> Self {
-        Self::FunctionDecl(decl)
+        NodeKind::FunctionDecl(decl).into()
     }
 }
 
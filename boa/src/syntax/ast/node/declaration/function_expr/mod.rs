@@ -1,7 +1,8 @@
 use crate::{
     builtins::function::FunctionFlags,
     exec::Executable,
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::node::{join_nodes, FormalParameter, Node, NodeKind, SourceText, StatementList},
+    value::RcString,
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -32,6 +33,7 @@ pub struct FunctionExpr {
     name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source: SourceText,
 }
 
 impl FunctionExpr {
@@ -46,9 +48,21 @@ impl FunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source: SourceText::default(),
         }
     }
 
+    /// Sets the source text of the function, recorded by the parser.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<RcString>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Gets the source text of the function, if it was recorded.
+    pub fn source(&self) -> Option<&RcString> {
+        self.source.get()
+    }
+
     /// Gets the name of the function declaration.
     pub fn name(&self) -> Option<&str> {
         self.name.as_ref().map(Box::as_ref)
@@ -82,20 +96,34 @@ impl FunctionExpr {
 
         writeln!(f, "}}")
     }
+
+    /// Implements the display formatting of the function as a method, without the `function`
+    /// keyword and the name.
+    pub(in crate::syntax::ast::node) fn display_method(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("(")?;
+        join_nodes(f, &self.parameters)?;
+        f.write_str(") {\n")?;
+
+        self.body.display(f, indentation + 1)?;
+
+        writeln!(f, "{}}}", "    ".repeat(indentation))
+    }
 }
 
 impl Executable for FunctionExpr {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let val = interpreter.create_function(
+            self.name().unwrap_or(""),
             self.parameters().to_vec(),
             self.body().to_vec(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
+            self.source().cloned(),
         );
 
-        if let Some(name) = self.name() {
-            val.set_field("name", Value::from(name));
-        }
-
         Ok(val)
     }
 }
@@ -108,6 +136,6 @@ impl fmt::Display for FunctionExpr {
 
 impl From<FunctionExpr> for Node {
     fn from(expr: FunctionExpr) -> Self {
-        Self::FunctionExpr(expr)
+        NodeKind::FunctionExpr(expr).into()
     }
 }
@@ -0,0 +1,136 @@
+use crate::{
+    builtins::function::FunctionFlags,
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    syntax::ast::node::{join_nodes, FormalParameter, Node, NodeKind, SourceText, StatementList},
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `function*` declaration (generator statement) defines a generator function with the
+/// specified parameters.
+///
+/// Calling a generator function does not run its body, it returns a generator object instead.
+/// Each call to the `next()` method of the generator object runs the body until the next
+/// `yield` expression.
+///
+/// A generator function can also be created using an expression (see
+/// [generator expression][gen_expr]).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function*
+/// [gen_expr]: ../enum.Node.html#variant.GeneratorExpr
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GeneratorDecl {
+    name: Box<str>,
+    parameters: Box<[FormalParameter]>,
+    body: StatementList,
+    source: SourceText,
+}
+
+impl GeneratorDecl {
+    /// Creates a new generator declaration.
+    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    where
+        N: Into<Box<str>>,
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            name: name.into(),
+            parameters: parameters.into(),
+            body: body.into(),
+            source: SourceText::default(),
+        }
+    }
+
+    /// Sets the source text of the function, recorded by the parser.
+    pub(in crate::syntax) fn with_source(mut self, source: Option<RcString>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Gets the source text of the function, if it was recorded.
+    pub fn source(&self) -> Option<&RcString> {
+        self.source.get()
+    }
+
+    /// Gets the name of the generator declaration.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the list of parameters of the generator declaration.
+    pub fn parameters(&self) -> &[FormalParameter] {
+        &self.parameters
+    }
+
+    /// Gets the body of the generator declaration.
+    pub fn body(&self) -> &[Node] {
+        self.body.statements()
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "function* {}(", self.name)?;
+        join_nodes(f, &self.parameters)?;
+        f.write_str(") {{")?;
+
+        self.body.display(f, indentation + 1)?;
+
+        writeln!(f, "}}")
+    }
+}
+
+impl Executable for GeneratorDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("GeneratorDecl", "exec");
+        let val = interpreter.create_function(
+            self.name(),
+            self.parameters().to_vec(),
+            self.body().to_vec(),
+            FunctionFlags::CALLABLE | FunctionFlags::GENERATOR,
+            self.source().cloned(),
+        );
+
+        // Assign it in the current environment
+        interpreter.realm_mut().environment.create_mutable_binding(
+            self.name().to_owned(),
+            false,
+            VariableScope::Function,
+        );
+
+        interpreter
+            .realm_mut()
+            .environment
+            .initialize_binding(self.name(), val);
+
+        Ok(Value::undefined())
+    }
+}
+
+impl From<GeneratorDecl> for Node {
+    fn from(decl: GeneratorDecl) -> Self {
+        NodeKind::GeneratorDecl(decl).into()
+    }
+}
+
+impl fmt::Display for GeneratorDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
@@ -1,17 +1,69 @@
 //! Declaration nodes
 
 pub mod arrow_function_decl;
+pub mod async_arrow_function_decl;
+pub mod async_function_decl;
+pub mod async_function_expr;
+pub mod class_decl;
+pub mod class_expr;
 pub mod const_decl_list;
 pub mod function_decl;
 pub mod function_expr;
+pub mod generator_decl;
+pub mod generator_expr;
 pub mod let_decl_list;
 pub mod var_decl_list;
 
 pub use self::{
     arrow_function_decl::ArrowFunctionDecl,
+    async_arrow_function_decl::AsyncArrowFunctionDecl,
+    async_function_decl::AsyncFunctionDecl,
+    async_function_expr::AsyncFunctionExpr,
+    class_decl::{Class, ClassDecl, ClassElement},
+    class_expr::ClassExpr,
     const_decl_list::{ConstDecl, ConstDeclList},
     function_decl::FunctionDecl,
     function_expr::FunctionExpr,
+    generator_decl::GeneratorDecl,
+    generator_expr::GeneratorExpr,
     let_decl_list::{LetDecl, LetDeclList},
     var_decl_list::{VarDecl, VarDeclList},
 };
+
+use crate::value::RcString;
+use gc::{Finalize, Trace};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The source text of a function or class, as returned by `Function.prototype.toString()`.
+///
+/// It is not part of the structure of the code, so any two source texts compare as equal. This
+/// way, nodes parsed from differently formatted code are still equal.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+#[derive(Clone, Debug, Default, Trace, Finalize)]
+pub struct SourceText(Option<RcString>);
+
+impl SourceText {
+    /// Gets the source text, if it was recorded by the parser.
+    pub fn get(&self) -> Option<&RcString> {
+        self.0.as_ref()
+    }
+}
+
+impl From<Option<RcString>> for SourceText {
+    fn from(source: Option<RcString>) -> Self {
+        Self(source)
+    }
+}
+
+impl PartialEq for SourceText {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
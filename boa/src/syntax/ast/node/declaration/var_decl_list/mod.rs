@@ -1,7 +1,10 @@
 use crate::{
-    environment::lexical_environment::VariableScope,
-    exec::Executable,
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    exec::{Executable, SuspendedFrame},
+    syntax::ast::node::{
+        join_nodes,
+        pattern::{Binding, BindingKind},
+        Node, NodeKind,
+    },
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -29,32 +32,41 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-VariableStatement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/var
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct VarDeclList {
-    #[cfg_attr(feature = "serde", serde(flatten))]
     vars: Box<[VarDecl]>,
 }
 
 impl Executable for VarDeclList {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        for var in self.as_ref() {
-            let val = match var.init() {
-                Some(v) => v.run(interpreter)?,
-                None => Value::undefined(),
+        // The frame of a declaration that was suspended while binding its value keeps the value.
+        let (start, mut resumed) = match interpreter.executor().resume_frame() {
+            Some(frame) => (frame.step, frame.values.into_iter().next()),
+            None => (0, None),
+        };
+        for (i, var) in self.as_ref().iter().enumerate().skip(start) {
+            let val = match (resumed.take(), var.init()) {
+                (Some(val), _) => val,
+                (None, Some(v)) => {
+                    let val = v.run(interpreter);
+                    interpreter
+                        .executor()
+                        .save_frame(val, || SuspendedFrame::new(i, Vec::new()))?
+                }
+                (None, None) => Value::undefined(),
             };
-            let environment = &mut interpreter.realm_mut().environment;
-
-            if environment.has_binding(var.name()) {
-                if var.init().is_some() {
-                    environment.set_mutable_binding(var.name(), val, true);
+            match var.binding() {
+                // A declaration without an initializer doesn't overwrite an existing variable.
+                Binding::Identifier(name)
+                    if var.init().is_none()
+                        && interpreter.realm().environment.has_binding(name.as_ref()) => {}
+                binding => {
+                    let res = binding.bind(val.clone(), BindingKind::Var, interpreter);
+                    interpreter
+                        .executor()
+                        .save_frame(res, || SuspendedFrame::new(i, vec![val]))?;
                 }
-            } else {
-                environment.create_mutable_binding(
-                    var.name().to_owned(),
-                    false,
-                    VariableScope::Function,
-                );
-                environment.initialize_binding(var.name(), val);
             }
         }
         Ok(Value::undefined())
@@ -97,7 +109,7 @@ impl fmt::Display for VarDeclList {
 
 impl From<VarDeclList> for Node {
     fn from(list: VarDeclList) -> Self {
-        Self::VarDeclList(list)
+        NodeKind::VarDeclList(list).into()
     }
 }
 
@@ -105,13 +117,13 @@ impl From<VarDeclList> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct VarDecl {
-    name: Identifier,
+    binding: Binding,
     init: Option<Node>,
 }
 
 impl fmt::Display for VarDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
+        fmt::Display::fmt(&self.binding, f)?;
         if let Some(ref init) = self.init {
             write!(f, " = {}", init)?;
         }
@@ -121,20 +133,20 @@ impl fmt::Display for VarDecl {
 
 impl VarDecl {
     /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: I) -> Self
+    pub(in crate::syntax) fn new<B, I>(binding: B, init: I) -> Self
     where
-        N: Into<Identifier>,
+        B: Into<Binding>,
         I: Into<Option<Node>>,
     {
         Self {
-            name: name.into(),
+            binding: binding.into(),
             init: init.into(),
         }
     }
 
-    /// Gets the name of the variable.
-    pub fn name(&self) -> &str {
-        self.name.as_ref()
+    /// Gets the name or the destructuring pattern of the variable.
+    pub fn binding(&self) -> &Binding {
+        &self.binding
     }
 
     /// Gets the initialization node for the variable, if any.
@@ -1,7 +1,7 @@
 use crate::{
     exec::Executable,
-    syntax::ast::node::Node,
-    value::{Type, Value},
+    syntax::ast::node::{Node, NodeKind},
+    value::Value,
     Context, Result,
 };
 use gc::{Finalize, Trace};
@@ -64,12 +64,10 @@ impl GetConstField {
 
 impl Executable for GetConstField {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut obj = self.obj().run(interpreter)?;
-        if obj.get_type() != Type::Object {
-            obj = Value::Object(obj.to_object(interpreter)?);
-        }
+        let obj = self.obj().run(interpreter)?;
+        let object = obj.to_object(interpreter)?;
 
-        Ok(obj.get_field(self.field()))
+        object.get(&self.field().into(), &obj, interpreter)
     }
 }
 
@@ -81,6 +79,6 @@ impl fmt::Display for GetConstField {
 
 impl From<GetConstField> for Node {
     fn from(get_const_field: GetConstField) -> Self {
-        Self::GetConstField(get_const_field)
+        NodeKind::GetConstField(get_const_field).into()
     }
 }
@@ -1,7 +1,7 @@
 use crate::{
-    exec::Executable,
-    syntax::ast::node::Node,
-    value::{Type, Value},
+    exec::{Executable, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
+    value::Value,
     Context, Result,
 };
 use gc::{Finalize, Trace};
@@ -63,13 +63,23 @@ impl GetField {
 
 impl Executable for GetField {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut obj = self.obj().run(interpreter)?;
-        if obj.get_type() != Type::Object {
-            obj = Value::Object(obj.to_object(interpreter)?);
-        }
-        let field = self.field().run(interpreter)?;
+        // Step 0 evaluates the object and step 1 the field.
+        let obj = match interpreter.executor().resume_frame() {
+            Some(frame) if frame.step == 1 => frame.values.into_iter().next().unwrap_or_default(),
+            _ => {
+                let obj = self.obj().run(interpreter);
+                interpreter
+                    .executor()
+                    .save_frame(obj, SuspendedFrame::default)?
+            }
+        };
+        let field = self.field().run(interpreter);
+        let field = interpreter
+            .executor()
+            .save_frame(field, || SuspendedFrame::new(1, vec![obj.clone()]))?;
+        let object = obj.to_object(interpreter)?;
 
-        Ok(obj.get_field(field.to_property_key(interpreter)?))
+        object.get(&field.to_property_key(interpreter)?, &obj, interpreter)
     }
 }
 
@@ -81,6 +91,6 @@ impl fmt::Display for GetField {
 
 impl From<GetField> for Node {
     fn from(get_field: GetField) -> Self {
-        Self::GetField(get_field)
+        NodeKind::GetField(get_field).into()
     }
 }
@@ -0,0 +1,103 @@
+use crate::{
+    exec::Executable,
+    property::PropertyKey,
+    syntax::ast::node::{Node, NodeKind},
+    value::Value,
+    BoaProfiler, Context, Result,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `super` property accessor provides access to the properties of the prototype of the
+/// object a method is defined on.
+///
+/// Both `super.property` and `super[expression]` are represented by this node. The property is
+/// looked up on the prototype of the `[[HomeObject]]` of the enclosing method, but getters are
+/// called with the current `this` value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetSuperField {
+    field: Box<Node>,
+}
+
+impl GetSuperField {
+    /// Creates a `GetSuperField` AST node.
+    pub fn new<F>(field: F) -> Self
+    where
+        F: Into<Node>,
+    {
+        Self {
+            field: Box::new(field.into()),
+        }
+    }
+
+    pub fn field(&self) -> &Node {
+        &self.field
+    }
+
+    /// Evaluates the super property reference.
+    ///
+    /// Returns the object the lookup starts from, the property key and the `this` value to use
+    /// as the receiver.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-makesuperpropertyreference
+    pub(crate) fn reference(
+        &self,
+        interpreter: &mut Context,
+    ) -> Result<(Value, PropertyKey, Value)> {
+        let this = interpreter
+            .realm()
+            .environment
+            .get_this_binding()
+            .map_err(|err| interpreter.construct_reference_error(err.to_string()))?;
+        let key = self
+            .field()
+            .run(interpreter)?
+            .to_property_key(interpreter)?;
+        let base = interpreter
+            .realm()
+            .environment
+            .get_this_environment()
+            .borrow()
+            .get_super_base();
+
+        if base.is_undefined() {
+            return Err(interpreter.construct_syntax_error("'super' keyword unexpected here"));
+        }
+
+        Ok((base, key, this))
+    }
+}
+
+impl Executable for GetSuperField {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("GetSuperField", "exec");
+        let (base, key, this) = self.reference(interpreter)?;
+        base.to_object(interpreter)?.get(&key, &this, interpreter)
+    }
+}
+
+impl fmt::Display for GetSuperField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "super[{}]", self.field())
+    }
+}
+
+impl From<GetSuperField> for Node {
+    fn from(get_super_field: GetSuperField) -> Self {
+        NodeKind::GetSuperField(get_super_field).into()
+    }
+}
@@ -2,5 +2,8 @@
 
 pub mod get_const_field;
 pub mod get_field;
+pub mod get_super_field;
 
-pub use self::{get_const_field::GetConstField, get_field::GetField};
+pub use self::{
+    get_const_field::GetConstField, get_field::GetField, get_super_field::GetSuperField,
+};
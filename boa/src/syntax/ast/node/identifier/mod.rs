@@ -1,6 +1,10 @@
 //! Local identifier node.
 
-use crate::{exec::Executable, syntax::ast::node::Node, Context, Result, Value};
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{Node, NodeKind},
+    Context, Result, Value,
+};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -36,7 +40,9 @@ impl Executable for Identifier {
             .realm()
             .environment
             .get_binding_value(self.as_ref())
-            .ok_or_else(|| interpreter.construct_reference_error(self.as_ref()))
+            .ok_or_else(|| {
+                interpreter.construct_reference_error(format!("{} is not defined", self))
+            })
     }
 }
 
@@ -63,6 +69,6 @@ where
 
 impl From<Identifier> for Node {
     fn from(local: Identifier) -> Self {
-        Self::Identifier(local)
+        NodeKind::Identifier(local).into()
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeKind},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -71,6 +71,6 @@ impl fmt::Display for Continue {
 
 impl From<Continue> for Node {
     fn from(cont: Continue) -> Node {
-        Self::Continue(cont)
+        NodeKind::Continue(cont).into()
     }
 }
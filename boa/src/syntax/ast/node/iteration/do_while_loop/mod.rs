@@ -1,6 +1,6 @@
 use crate::{
-    exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -68,58 +68,54 @@ impl DoWhileLoop {
 
 impl Executable for DoWhileLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut result = self.body().run(interpreter)?;
-        match interpreter.executor().get_current_state() {
-            InterpreterState::Break(_label) => {
-                // TODO break to label.
-
-                // Loops 'consume' breaks.
-                interpreter
-                    .executor()
-                    .set_current_state(InterpreterState::Executing);
-                return Ok(result);
-            }
-            InterpreterState::Continue(_label) => {
-                // TODO continue to label;
-                interpreter
+        // Step 0 evaluates the body and step 1 the condition.
+        let (mut step, mut result) = match interpreter.executor().resume_frame() {
+            Some(frame) => (
+                frame.step,
+                frame.values.into_iter().next().unwrap_or_default(),
+            ),
+            None => (0, Value::undefined()),
+        };
+        loop {
+            if step == 0 {
+                let body = self.body().run(interpreter);
+                result = interpreter
                     .executor()
-                    .set_current_state(InterpreterState::Executing);
-                // after breaking out of the block, continue execution of the loop
-            }
-            InterpreterState::Return => {
-                return Ok(result);
-            }
-            InterpreterState::Executing => {
-                // Continue execution.
-            }
-        }
+                    .save_frame(body, || SuspendedFrame::new(0, vec![result.clone()]))?;
+                match interpreter.executor().get_current_state() {
+                    InterpreterState::Break(_label) => {
+                        // TODO break to label.
 
-        while self.cond().run(interpreter)?.to_boolean() {
-            result = self.body().run(interpreter)?;
-            match interpreter.executor().get_current_state() {
-                InterpreterState::Break(_label) => {
-                    // TODO break to label.
-
-                    // Loops 'consume' breaks.
-                    interpreter
-                        .executor()
-                        .set_current_state(InterpreterState::Executing);
-                    break;
-                }
-                InterpreterState::Continue(_label) => {
-                    // TODO continue to label.
-                    interpreter
-                        .executor()
-                        .set_current_state(InterpreterState::Executing);
-                    // after breaking out of the block, continue execution of the loop
-                }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
+                        // Loops 'consume' breaks.
+                        interpreter
+                            .executor()
+                            .set_current_state(InterpreterState::Executing);
+                        break;
+                    }
+                    InterpreterState::Continue(_label) => {
+                        // TODO continue to label.
+                        interpreter
+                            .executor()
+                            .set_current_state(InterpreterState::Executing);
+                        // after breaking out of the block, continue execution of the loop
+                    }
+                    InterpreterState::Return => {
+                        return Ok(result);
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution.
+                    }
                 }
             }
+            step = 0;
+
+            let cond = self.cond().run(interpreter);
+            let cond = interpreter
+                .executor()
+                .save_frame(cond, || SuspendedFrame::new(1, vec![result.clone()]))?;
+            if !cond.to_boolean() {
+                break;
+            }
         }
         Ok(result)
     }
@@ -133,6 +129,6 @@ impl fmt::Display for DoWhileLoop {
 
 impl From<DoWhileLoop> for Node {
     fn from(do_while: DoWhileLoop) -> Self {
-        Self::DoWhileLoop(do_while)
+        NodeKind::DoWhileLoop(do_while).into()
     }
 }
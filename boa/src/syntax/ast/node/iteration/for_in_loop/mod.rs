@@ -0,0 +1,245 @@
+use crate::{
+    builtins::{iterable::IteratorRecord, object::for_in_iterator::ForInIterator},
+    environment::lexical_environment::new_declarative_environment,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    syntax::ast::node::{pattern::BindingKind, Assign, Node, NodeKind},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ForInLoop {
+    variable: Box<Node>,
+    expr: Box<Node>,
+    body: Box<Node>,
+}
+
+impl ForInLoop {
+    pub fn new<V, E, B>(variable: V, expr: E, body: B) -> Self
+    where
+        V: Into<Node>,
+        E: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            variable: Box::new(variable.into()),
+            expr: Box::new(expr.into()),
+            body: Box::new(body.into()),
+        }
+    }
+
+    pub fn variable(&self) -> &Node {
+        &self.variable
+    }
+
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        write!(f, "for ({} in {}) {{", self.variable, self.expr)?;
+        self.body().display(f, indentation + 1)?;
+        f.write_str("}")
+    }
+}
+
+impl fmt::Display for ForInLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ForInLoop> for Node {
+    fn from(for_in: ForInLoop) -> Node {
+        NodeKind::ForInLoop(for_in).into()
+    }
+}
+
+impl Executable for ForInLoop {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ForIn", "exec");
+        // Step 0 evaluates the object, step 1 the body and step 2 binds the variable, its frame
+        // also keeps the value of the iteration. A suspended loop still has the environment of
+        // the current iteration on the stack.
+        let (iterator, mut result, mut resumed, mut next_value) =
+            match interpreter.executor().resume_frame() {
+                Some(frame) if frame.step >= 1 => {
+                    let mut values = frame.values.into_iter();
+                    let iterator = IteratorRecord::new(
+                        values.next().unwrap_or_default(),
+                        values.next().unwrap_or_default(),
+                    );
+                    let result = values.next().unwrap_or_default();
+                    (iterator, result, true, values.next())
+                }
+                _ => {
+                    let object = self.expr().run(interpreter);
+                    let object = interpreter
+                        .executor()
+                        .save_frame(object, SuspendedFrame::default)?;
+                    if object.is_null_or_undefined() {
+                        return Ok(Value::undefined());
+                    }
+                    let object = object.to_object(interpreter)?;
+                    let iterator =
+                        ForInIterator::create_for_in_iterator(interpreter, Value::from(object))?;
+                    let next_function = iterator.get_field("next", interpreter)?;
+                    (
+                        IteratorRecord::new(iterator, next_function),
+                        Value::undefined(),
+                        false,
+                        None,
+                    )
+                }
+            };
+
+        loop {
+            if resumed {
+                resumed = false;
+            } else {
+                {
+                    let env = &mut interpreter.realm_mut().environment;
+                    env.push(new_declarative_environment(Some(
+                        env.get_current_environment_ref().clone(),
+                    )));
+                }
+                let iterator_result = iterator.next(interpreter)?;
+                if iterator_result.is_done() {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    break;
+                }
+                next_value = Some(iterator_result.value());
+            }
+
+            if let Some(value) = next_value.take() {
+                let res = self.bind_variable(value.clone(), interpreter);
+                interpreter.executor().save_frame(res, || {
+                    SuspendedFrame::new(
+                        2,
+                        vec![
+                            iterator.iterator_object().clone(),
+                            iterator.next_function().clone(),
+                            result.clone(),
+                            value,
+                        ],
+                    )
+                })?;
+            }
+
+            let body = self.body().run(interpreter);
+            result = interpreter.executor().save_frame(body, || {
+                SuspendedFrame::new(
+                    1,
+                    vec![
+                        iterator.iterator_object().clone(),
+                        iterator.next_function().clone(),
+                        result.clone(),
+                    ],
+                )
+            })?;
+            match interpreter.executor().get_current_state() {
+                InterpreterState::Break(_label) => {
+                    // TODO break to label.
+
+                    // Loops 'consume' breaks.
+                    interpreter
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+                    let _ = interpreter.realm_mut().environment.pop();
+                    break;
+                }
+                InterpreterState::Continue(_label) => {
+                    // TODO continue to label.
+                    interpreter
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+                    // after breaking out of the block, continue execution of the loop
+                }
+                InterpreterState::Return => return Ok(result),
+                InterpreterState::Executing => {
+                    // Continue execution.
+                }
+            }
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+        Ok(result)
+    }
+}
+
+impl ForInLoop {
+    /// Binds the value of an iteration to the variable in the head of the loop.
+    fn bind_variable(&self, value: Value, interpreter: &mut Context) -> Result<()> {
+        match self.variable().kind() {
+            NodeKind::Identifier(_)
+            | NodeKind::GetConstField(_)
+            | NodeKind::GetField(_)
+            | NodeKind::Pattern(_) => {
+                Assign::assign(self.variable(), value, interpreter)?;
+            }
+            NodeKind::VarDeclList(ref list) => {
+                match list.as_ref() {
+                    [var] => {
+                        if var.init().is_some() {
+                            return Err(interpreter.construct_syntax_error("a declaration in the head of a for-in loop can't have an initializer"));
+                        }
+                        var.binding().bind(value, BindingKind::Var, interpreter)?;
+                    }
+                    _ => {
+                        return Err(interpreter.construct_syntax_error(
+                            "only one variable can be declared in the head of a for-in loop",
+                        ))
+                    }
+                }
+            }
+            NodeKind::LetDeclList(ref list) => {
+                match list.as_ref() {
+                    [var] => {
+                        if var.init().is_some() {
+                            return Err(interpreter.construct_syntax_error("a declaration in the head of a for-in loop can't have an initializer"));
+                        }
+                        var.binding().bind(value, BindingKind::Let, interpreter)?;
+                    }
+                    _ => {
+                        return Err(interpreter.construct_syntax_error(
+                            "only one variable can be declared in the head of a for-in loop",
+                        ))
+                    }
+                }
+            }
+            NodeKind::ConstDeclList(ref list) => {
+                match list.as_ref() {
+                    [var] => {
+                        if var.init().is_some() {
+                            return Err(interpreter.construct_syntax_error("a declaration in the head of a for-in loop can't have an initializer"));
+                        }
+                        var.binding().bind(value, BindingKind::Const, interpreter)?;
+                    }
+                    _ => {
+                        return Err(interpreter.construct_syntax_error(
+                            "only one variable can be declared in the head of a for-in loop",
+                        ))
+                    }
+                }
+            }
+            NodeKind::Assign(_) => {
+                return Err(interpreter.construct_syntax_error(
+                    "a declaration in the head of a for-in loop can't have an initializer",
+                ));
+            }
+            _ => {
+                return Err(interpreter
+                    .construct_syntax_error("unknown left hand side in head of for-in loop"))
+            }
+        }
+        Ok(())
+    }
+}
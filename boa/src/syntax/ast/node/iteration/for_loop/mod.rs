@@ -1,7 +1,7 @@
 use crate::{
     environment::lexical_environment::new_declarative_environment,
-    exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -99,47 +99,75 @@ impl ForLoop {
 
 impl Executable for ForLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        // Create the block environment.
         let _timer = BoaProfiler::global().start_event("ForLoop", "exec");
-        {
+        // Step 0 evaluates the initializer, 1 the condition, 2 the body and 3 the final
+        // expression. A suspended loop still has its environment on the stack.
+        let mut step = if let Some(frame) = interpreter.executor().resume_frame() {
+            frame.step
+        } else {
+            // Create the block environment.
             let env = &mut interpreter.realm_mut().environment;
             env.push(new_declarative_environment(Some(
                 env.get_current_environment_ref().clone(),
             )));
+            0
+        };
+
+        if step == 0 {
+            if let Some(init) = self.init() {
+                let result = init.run(interpreter);
+                interpreter
+                    .executor()
+                    .save_frame(result, SuspendedFrame::default)?;
+            }
+            step = 1;
         }
 
-        if let Some(init) = self.init() {
-            init.run(interpreter)?;
-        }
-
-        while self
-            .condition()
-            .map(|cond| cond.run(interpreter).map(|v| v.to_boolean()))
-            .transpose()?
-            .unwrap_or(true)
-        {
-            let result = self.body().run(interpreter)?;
-
-            match interpreter.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, interpreter, break);
-                    break;
-                }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, interpreter, continue);
+        loop {
+            if step == 1 {
+                if let Some(cond) = self.condition() {
+                    let cond = cond.run(interpreter);
+                    let cond = interpreter
+                        .executor()
+                        .save_frame(cond, || SuspendedFrame::new(1, Vec::new()))?;
+                    if !cond.to_boolean() {
+                        break;
+                    }
                 }
+                step = 2;
+            }
 
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
+            if step == 2 {
+                let result = self.body().run(interpreter);
+                let result = interpreter
+                    .executor()
+                    .save_frame(result, || SuspendedFrame::new(2, Vec::new()))?;
+
+                match interpreter.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, interpreter, break);
+                        break;
+                    }
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, interpreter, continue);
+                    }
+
+                    InterpreterState::Return => {
+                        return Ok(result);
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution.
+                    }
                 }
             }
 
             if let Some(final_expr) = self.final_expr() {
-                final_expr.run(interpreter)?;
+                let result = final_expr.run(interpreter);
+                interpreter
+                    .executor()
+                    .save_frame(result, || SuspendedFrame::new(3, Vec::new()))?;
             }
+            step = 1;
         }
 
         // pop the block env
@@ -157,7 +185,7 @@ impl fmt::Display for ForLoop {
 
 impl From<ForLoop> for Node {
     fn from(for_loop: ForLoop) -> Self {
-        Self::ForLoop(for_loop)
+        NodeKind::ForLoop(for_loop).into()
     }
 }
 
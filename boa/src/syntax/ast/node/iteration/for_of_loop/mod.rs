@@ -1,8 +1,8 @@
 use crate::{
-    builtins::iterable::get_iterator,
-    environment::lexical_environment::{new_declarative_environment, VariableScope},
-    exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    builtins::iterable::{get_iterator, IteratorRecord},
+    environment::lexical_environment::new_declarative_environment,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    syntax::ast::node::{pattern::BindingKind, Assign, Node, NodeKind},
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -60,125 +60,92 @@ impl fmt::Display for ForOfLoop {
 
 impl From<ForOfLoop> for Node {
     fn from(for_of: ForOfLoop) -> Node {
-        Self::ForOfLoop(for_of)
+        NodeKind::ForOfLoop(for_of).into()
     }
 }
 
 impl Executable for ForOfLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ForOf", "exec");
-        let iterable = self.iterable().run(interpreter)?;
-        let iterator = get_iterator(interpreter, iterable)?;
-        let mut result = Value::undefined();
+        // Step 0 evaluates the iterable, step 1 the body and step 2 binds the variable, its frame
+        // also keeps the value of the iteration. A suspended loop still has the environment of
+        // the current iteration on the stack.
+        let (iterator, mut result, mut resumed, mut next_value) =
+            match interpreter.executor().resume_frame() {
+                Some(frame) if frame.step >= 1 => {
+                    let mut values = frame.values.into_iter();
+                    let iterator = IteratorRecord::new(
+                        values.next().unwrap_or_default(),
+                        values.next().unwrap_or_default(),
+                    );
+                    let result = values.next().unwrap_or_default();
+                    (iterator, result, true, values.next())
+                }
+                _ => {
+                    let iterable = self.iterable().run(interpreter);
+                    let iterable = interpreter
+                        .executor()
+                        .save_frame(iterable, SuspendedFrame::default)?;
+                    (
+                        get_iterator(interpreter, iterable)?,
+                        Value::undefined(),
+                        false,
+                        None,
+                    )
+                }
+            };
 
         loop {
-            {
-                let env = &mut interpreter.realm_mut().environment;
-                env.push(new_declarative_environment(Some(
-                    env.get_current_environment_ref().clone(),
-                )));
-            }
-            let iterator_result = iterator.next(interpreter)?;
-            if iterator_result.is_done() {
-                break;
-            }
-            let next_result = iterator_result.value();
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    let environment = &mut interpreter.realm_mut().environment;
-
-                    if environment.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        environment.set_mutable_binding(name.as_ref(), next_result.clone(), true);
-                    } else {
-                        environment.create_mutable_binding(
-                            name.as_ref().to_owned(),
-                            true,
-                            VariableScope::Function,
-                        );
-                        environment.initialize_binding(name.as_ref(), next_result.clone());
-                    }
+            if resumed {
+                resumed = false;
+            } else {
+                {
+                    let env = &mut interpreter.realm_mut().environment;
+                    env.push(new_declarative_environment(Some(
+                        env.get_current_environment_ref().clone(),
+                    )));
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut interpreter.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return interpreter.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        if environment.has_binding(var.name()) {
-                            environment.set_mutable_binding(var.name(), next_result, true);
-                        } else {
-                            environment.create_mutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Function,
-                            );
-                            environment.initialize_binding(var.name(), next_result);
-                        }
-                    }
-                    _ => {
-                        return interpreter.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut interpreter.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return interpreter.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        environment.create_mutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        );
-                        environment.initialize_binding(var.name(), next_result);
-                    }
-                    _ => {
-                        return interpreter.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut interpreter.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return interpreter.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        environment.create_immutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        );
-                        environment.initialize_binding(var.name(), next_result);
-                    }
-                    _ => {
-                        return interpreter.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::Assign(_) => {
-                    return interpreter.throw_syntax_error(
-                        "a declaration in the head of a for-of loop can't have an initializer",
-                    );
+                let iterator_result = iterator.next(interpreter)?;
+                if iterator_result.is_done() {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    break;
                 }
-                _ => {
-                    return interpreter
-                        .throw_syntax_error("unknown left hand side in head of for-of loop")
+                next_value = Some(iterator_result.value());
+            }
+
+            if let Some(value) = next_value.take() {
+                let res = self.bind_variable(value.clone(), interpreter);
+                let res = interpreter.executor().save_frame(res, || {
+                    SuspendedFrame::new(
+                        2,
+                        vec![
+                            iterator.iterator_object().clone(),
+                            iterator.next_function().clone(),
+                            result.clone(),
+                            value,
+                        ],
+                    )
+                });
+                if let Err(err) = res {
+                    return close_iterator(&iterator, Err(err), interpreter);
                 }
             }
 
-            result = self.body().run(interpreter)?;
+            let body = self.body().run(interpreter);
+            let body = interpreter.executor().save_frame(body, || {
+                SuspendedFrame::new(
+                    1,
+                    vec![
+                        iterator.iterator_object().clone(),
+                        iterator.next_function().clone(),
+                        result.clone(),
+                    ],
+                )
+            });
+            result = match body {
+                Ok(result) => result,
+                Err(err) => return close_iterator(&iterator, Err(err), interpreter),
+            };
             match interpreter.executor().get_current_state() {
                 InterpreterState::Break(_label) => {
                     // TODO break to label.
@@ -187,7 +154,8 @@ impl Executable for ForOfLoop {
                     interpreter
                         .executor()
                         .set_current_state(InterpreterState::Executing);
-                    break;
+                    let _ = interpreter.realm_mut().environment.pop();
+                    return close_iterator(&iterator, Ok(result), interpreter);
                 }
                 InterpreterState::Continue(_label) => {
                     // TODO continue to label.
@@ -196,7 +164,9 @@ impl Executable for ForOfLoop {
                         .set_current_state(InterpreterState::Executing);
                     // after breaking out of the block, continue execution of the loop
                 }
-                InterpreterState::Return => return Ok(result),
+                InterpreterState::Return => {
+                    return close_iterator(&iterator, Ok(result), interpreter)
+                }
                 InterpreterState::Executing => {
                     // Continue execution.
                 }
@@ -206,3 +176,103 @@ impl Executable for ForOfLoop {
         Ok(result)
     }
 }
+
+impl ForOfLoop {
+    /// Binds the value of an iteration to the variable in the head of the loop.
+    fn bind_variable(&self, value: Value, interpreter: &mut Context) -> Result<()> {
+        match self.variable().kind() {
+            NodeKind::Identifier(_)
+            | NodeKind::GetConstField(_)
+            | NodeKind::GetField(_)
+            | NodeKind::Pattern(_) => {
+                Assign::assign(self.variable(), value, interpreter)?;
+            }
+            NodeKind::VarDeclList(ref list) => {
+                match list.as_ref() {
+                    [var] => {
+                        if var.init().is_some() {
+                            return Err(interpreter.construct_syntax_error("a declaration in the head of a for-of loop can't have an initializer"));
+                        }
+                        var.binding().bind(value, BindingKind::Var, interpreter)?;
+                    }
+                    _ => {
+                        return Err(interpreter.construct_syntax_error(
+                            "only one variable can be declared in the head of a for-of loop",
+                        ))
+                    }
+                }
+            }
+            NodeKind::LetDeclList(ref list) => {
+                match list.as_ref() {
+                    [var] => {
+                        if var.init().is_some() {
+                            return Err(interpreter.construct_syntax_error("a declaration in the head of a for-of loop can't have an initializer"));
+                        }
+                        var.binding().bind(value, BindingKind::Let, interpreter)?;
+                    }
+                    _ => {
+                        return Err(interpreter.construct_syntax_error(
+                            "only one variable can be declared in the head of a for-of loop",
+                        ))
+                    }
+                }
+            }
+            NodeKind::ConstDeclList(ref list) => {
+                match list.as_ref() {
+                    [var] => {
+                        if var.init().is_some() {
+                            return Err(interpreter.construct_syntax_error("a declaration in the head of a for-of loop can't have an initializer"));
+                        }
+                        var.binding().bind(value, BindingKind::Const, interpreter)?;
+                    }
+                    _ => {
+                        return Err(interpreter.construct_syntax_error(
+                            "only one variable can be declared in the head of a for-of loop",
+                        ))
+                    }
+                }
+            }
+            NodeKind::Assign(_) => {
+                return Err(interpreter.construct_syntax_error(
+                    "a declaration in the head of a for-of loop can't have an initializer",
+                ));
+            }
+            _ => {
+                return Err(interpreter
+                    .construct_syntax_error("unknown left hand side in head of for-of loop"))
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Closes the iterator of a loop that exits before the iterator is done, with the completion
+/// of the loop.
+///
+/// The stack unwinding to a `yield` doesn't exit the loop, which continues when the generator
+/// is resumed. The `return` method of the iterator runs like any other function call, so the
+/// state of the loop is restored afterwards, unless closing the iterator throws.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-forin-div-ofbodyevaluation-lhs-stmt-iterator-lhskind-labelset
+fn close_iterator(
+    iterator: &IteratorRecord,
+    completion: Result<Value>,
+    interpreter: &mut Context,
+) -> Result<Value> {
+    if interpreter.executor().is_suspending() {
+        return completion;
+    }
+    let state = interpreter.executor().get_current_state().clone();
+    interpreter
+        .executor()
+        .set_current_state(InterpreterState::Executing);
+    let is_abrupt = completion.is_err();
+    let result = iterator.close(completion, interpreter);
+    if result.is_ok() || is_abrupt {
+        interpreter.executor().set_current_state(state);
+    }
+    result
+}
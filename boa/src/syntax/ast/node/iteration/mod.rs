@@ -1,8 +1,8 @@
 //! Iteration nodes
 
 pub use self::{
-    continue_node::Continue, do_while_loop::DoWhileLoop, for_loop::ForLoop, for_of_loop::ForOfLoop,
-    while_loop::WhileLoop,
+    continue_node::Continue, do_while_loop::DoWhileLoop, for_in_loop::ForInLoop, for_loop::ForLoop,
+    for_of_loop::ForOfLoop, while_loop::WhileLoop,
 };
 
 #[cfg(test)]
@@ -32,6 +32,7 @@ macro_rules! handle_state_with_labels {
 
 pub mod continue_node;
 pub mod do_while_loop;
+pub mod for_in_loop;
 pub mod for_loop;
 pub mod for_of_loop;
 pub mod while_loop;
@@ -353,3 +353,92 @@ fn for_loop_continue_label() {
     "#;
     assert_eq!(&exec(scenario), "10");
 }
+
+#[test]
+fn for_in_own_and_inherited_keys() {
+    let scenario = r#"
+        function Point() {
+            this.x = 1;
+            this.shadowed = 2;
+        }
+        Point.prototype.inherited = 3;
+        Point.prototype.shadowed = 4;
+        let sum = 0;
+        let count = 0;
+        for (let key in new Point()) {
+            sum += new Point()[key];
+            count++;
+        }
+        count + " " + sum
+    "#;
+    assert_eq!(&exec(scenario), "\"3 6\"");
+}
+
+#[test]
+fn for_in_null_and_undefined() {
+    let scenario = r#"
+        let count = 0;
+        for (let key in null) {
+            count++;
+        }
+        for (let key in undefined) {
+            count++;
+        }
+        count
+    "#;
+    assert_eq!(&exec(scenario), "0");
+}
+
+#[test]
+fn for_in_skips_deleted_keys() {
+    let scenario = r#"
+        let obj = { a: 1, b: 2, c: 3 };
+        let count = 0;
+        for (var key in obj) {
+            delete obj.a;
+            delete obj.b;
+            delete obj.c;
+            count++;
+        }
+        count
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn for_in_array_and_string() {
+    let scenario = r#"
+        let keys = "";
+        for (const key in [5, 6]) {
+            keys += key;
+        }
+        for (const key in "ab") {
+            keys += key;
+        }
+        keys
+    "#;
+    assert_eq!(&exec(scenario), "\"0101\"");
+}
+
+#[test]
+fn for_in_assignment_target() {
+    let scenario = r#"
+        let obj = {};
+        for (obj.key in { a: 1 }) {}
+        obj.key
+    "#;
+    assert_eq!(&exec(scenario), "\"a\"");
+}
+
+#[test]
+fn for_in_break() {
+    let scenario = r#"
+        let count = 0;
+        for (let key in { a: 1, b: 2, c: 3 }) {
+            count++;
+            break;
+        }
+        count
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
@@ -1,6 +1,6 @@
 use crate::{
-    exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -66,9 +66,30 @@ impl WhileLoop {
 
 impl Executable for WhileLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut result = Value::undefined();
-        while self.cond().run(interpreter)?.to_boolean() {
-            result = self.expr().run(interpreter)?;
+        // Step 0 evaluates the condition and step 1 the body.
+        let (mut step, mut result) = match interpreter.executor().resume_frame() {
+            Some(frame) => (
+                frame.step,
+                frame.values.into_iter().next().unwrap_or_default(),
+            ),
+            None => (0, Value::undefined()),
+        };
+        loop {
+            if step == 0 {
+                let cond = self.cond().run(interpreter);
+                let cond = interpreter
+                    .executor()
+                    .save_frame(cond, || SuspendedFrame::new(0, vec![result.clone()]))?;
+                if !cond.to_boolean() {
+                    break;
+                }
+            }
+            step = 0;
+
+            let body = self.expr().run(interpreter);
+            result = interpreter
+                .executor()
+                .save_frame(body, || SuspendedFrame::new(1, vec![result.clone()]))?;
             match interpreter.executor().get_current_state() {
                 InterpreterState::Break(label) => {
                     handle_state_with_labels!(self, label, interpreter, break);
@@ -97,6 +118,6 @@ impl fmt::Display for WhileLoop {
 
 impl From<WhileLoop> for Node {
     fn from(while_loop: WhileLoop) -> Self {
-        Self::WhileLoop(while_loop)
+        NodeKind::WhileLoop(while_loop).into()
     }
 }
@@ -1,6 +1,7 @@
 //! This module implements the `Node` structure, which composes the AST.
 
 pub mod array;
+pub mod await_expr;
 pub mod block;
 pub mod break_node;
 pub mod call;
@@ -12,38 +13,50 @@ pub mod iteration;
 pub mod new;
 pub mod object;
 pub mod operator;
+pub mod optional;
+pub mod pattern;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
+pub mod super_call;
 pub mod switch;
+pub mod template;
 pub mod throw;
 pub mod try_node;
+pub mod yield_node;
 
 pub use self::{
     array::ArrayDecl,
+    await_expr::AwaitExpr,
     block::Block,
     break_node::Break,
     call::Call,
     conditional::{ConditionalOp, If},
     declaration::{
-        ArrowFunctionDecl, ConstDecl, ConstDeclList, FunctionDecl, FunctionExpr, LetDecl,
-        LetDeclList, VarDecl, VarDeclList,
+        ArrowFunctionDecl, AsyncArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, Class,
+        ClassDecl, ClassElement, ClassExpr, ConstDecl, ConstDeclList, FunctionDecl, FunctionExpr,
+        GeneratorDecl, GeneratorExpr, LetDecl, LetDeclList, SourceText, VarDecl, VarDeclList,
     },
-    field::{GetConstField, GetField},
+    field::{GetConstField, GetField, GetSuperField},
     identifier::Identifier,
-    iteration::{Continue, DoWhileLoop, ForLoop, ForOfLoop, WhileLoop},
+    iteration::{Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
     new::New,
     object::Object,
     operator::{Assign, BinOp, UnaryOp},
+    optional::{Optional, OptionalOperation, OptionalOperationKind},
+    pattern::{ArrayPattern, Binding, ObjectPattern, Pattern, PatternElement},
     return_smt::Return,
     spread::Spread,
     statement_list::{RcStatementList, StatementList},
+    super_call::SuperCall,
     switch::{Case, Switch},
+    template::{TaggedTemplate, TemplateElement, TemplateLit},
     throw::Throw,
     try_node::{Catch, Finally, Try},
+    yield_node::Yield,
 };
-use super::Const;
-use crate::{exec::Executable, BoaProfiler, Context, Result, Value};
+use super::{Const, Span};
+use crate::{exec::Executable, property::PropertyKey, BoaProfiler, Context, Result, Value};
 use gc::{unsafe_empty_trace, Finalize, Trace};
 use std::{
     cmp::Ordering,
@@ -53,18 +66,123 @@ use std::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// A node of the AST, with the span of the source code it was parsed from.
+///
+/// The span is `None` for nodes that were not created by the parser. It is not part of the
+/// structure of the code, so two nodes are equal if their kinds are, whatever their spans.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize)]
+pub struct Node {
+    kind: NodeKind,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    span: Option<Span>,
+}
+
+impl Node {
+    /// Gets the kind of the node.
+    #[inline]
+    pub fn kind(&self) -> &NodeKind {
+        &self.kind
+    }
+
+    /// Gets the kind of the node mutably.
+    #[inline]
+    pub(crate) fn kind_mut(&mut self) -> &mut NodeKind {
+        &mut self.kind
+    }
+
+    /// Gets the span of the source code the node was parsed from.
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Sets the span of the node, unless it already has one.
+    ///
+    /// The parser gives its span to each node it creates, a node that is passed through
+    /// unchanged, like a parenthesized expression, keeps the span of its own code.
+    #[inline]
+    pub(in crate::syntax) fn with_default_span(mut self, span: Span) -> Self {
+        self.span.get_or_insert(span);
+        self
+    }
+
+    /// Returns a node ordering based on the hoistability of each node.
+    pub(crate) fn hoistable_order(a: &Node, b: &Node) -> Ordering {
+        let is_function = |node: &Node| {
+            matches!(
+                node.kind(),
+                NodeKind::FunctionDecl(_)
+                    | NodeKind::GeneratorDecl(_)
+                    | NodeKind::AsyncFunctionDecl(_)
+            )
+        };
+        match (is_function(a), is_function(b)) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+
+            (_, _) => Ordering::Equal,
+        }
+    }
+
+    /// Creates a `This` AST node.
+    pub fn this() -> Self {
+        NodeKind::This.into()
+    }
+
+    /// Implements the display formatting with indentation.
+    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        self.kind.display(f, indentation)
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl From<NodeKind> for Node {
+    #[inline]
+    fn from(kind: NodeKind) -> Self {
+        Self { kind, span: None }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+/// The different kinds of nodes of the AST.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
-pub enum Node {
+pub enum NodeKind {
     /// Array declaration node. [More information](./array/struct.ArrayDecl.html).
     ArrayDecl(ArrayDecl),
 
     /// An arrow function expression node. [More information](./arrow_function/struct.ArrowFunctionDecl.html).
     ArrowFunctionDecl(ArrowFunctionDecl),
 
+    /// An async arrow function expression node. [More information](./declaration/struct.AsyncArrowFunctionDecl.html).
+    AsyncArrowFunctionDecl(AsyncArrowFunctionDecl),
+
+    /// An async function declaration node. [More information](./declaration/struct.AsyncFunctionDecl.html).
+    AsyncFunctionDecl(AsyncFunctionDecl),
+
+    /// An async function expression node. [More information](./declaration/struct.AsyncFunctionExpr.html).
+    AsyncFunctionExpr(AsyncFunctionExpr),
+
     /// An assignment operator node. [More information](./operator/struct.Assign.html).
     Assign(Assign),
 
+    /// An `await` expression node. [More information](./await_expr/struct.AwaitExpr.html).
+    AwaitExpr(AwaitExpr),
+
     /// A binary operator node. [More information](./operator/struct.BinOp.html).
     BinOp(BinOp),
 
@@ -77,6 +195,12 @@ pub enum Node {
     /// A function call. [More information](./expression/struct.Call.html).
     Call(Call),
 
+    /// A class declaration node. [More information](./declaration/struct.ClassDecl.html).
+    ClassDecl(ClassDecl),
+
+    /// A class expression node. [More information](./declaration/struct.ClassExpr.html).
+    ClassExpr(ClassExpr),
+
     /// A javascript conditional operand ( x ? y : z ). [More information](./conditional/struct.ConditionalOp.html).
     ConditionalOp(ConditionalOp),
 
@@ -107,15 +231,27 @@ pub enum Node {
     /// A function expressino node. [More information](./declaration/struct.FunctionExpr.html).
     FunctionExpr(FunctionExpr),
 
+    /// A generator declaration node. [More information](./declaration/struct.GeneratorDecl.html).
+    GeneratorDecl(GeneratorDecl),
+
+    /// A generator expression node. [More information](./declaration/struct.GeneratorExpr.html).
+    GeneratorExpr(GeneratorExpr),
+
     /// Provides access to an object types' constant properties. [More information](./declaration/struct.GetConstField.html).
     GetConstField(GetConstField),
 
     /// Provides access to object fields. [More information](./declaration/struct.GetField.html).
     GetField(GetField),
 
+    /// Provides access to the properties of the parent class through `super`. [More information](./field/struct.GetSuperField.html).
+    GetSuperField(GetSuperField),
+
     /// A `for` statement. [More information](./iteration/struct.ForLoop.html).
     ForLoop(ForLoop),
 
+    /// A `for...in` statement. [More information](./iteration/struct.ForInLoop.html).
+    ForInLoop(ForInLoop),
+
     /// A `for...of` statement. [More information](./iteration/struct.ForOf.html).
     ForOfLoop(ForOfLoop),
 
@@ -134,6 +270,12 @@ pub enum Node {
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// An optional chain, like `a?.b`. [More information](./optional/struct.Optional.html).
+    Optional(Optional),
+
+    /// A destructuring pattern used as an assignment target. [More information](./pattern/enum.Pattern.html).
+    Pattern(Pattern),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
@@ -143,6 +285,15 @@ pub enum Node {
     /// A spread (...x) statement. [More information](./spread/struct.Spread.html).
     Spread(Spread),
 
+    /// A tagged template, like ``tag`a${b}` ``. [More information](./template/struct.TaggedTemplate.html).
+    TaggedTemplate(TaggedTemplate),
+
+    /// A template literal, like `` `a${b}` ``. [More information](./template/struct.TemplateLit.html).
+    TemplateLit(TemplateLit),
+
+    /// A call to the constructor of the parent class. [More information](./super_call/struct.SuperCall.html).
+    SuperCall(SuperCall),
+
     /// A throw statement. [More information](./throw/struct.Throw.html).
     Throw(Throw),
 
@@ -171,9 +322,12 @@ pub enum Node {
 
     /// A 'while {...}' node. [More information](./iteration/struct.WhileLoop.html).
     WhileLoop(WhileLoop),
+
+    /// A `yield` expression node. [More information](./yield_node/struct.Yield.html).
+    Yield(Yield),
 }
 
-impl Display for Node {
+impl Display for NodeKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
     }
@@ -181,27 +335,11 @@ impl Display for Node {
 
 impl From<Const> for Node {
     fn from(c: Const) -> Self {
-        Self::Const(c)
+        NodeKind::Const(c).into()
     }
 }
 
-impl Node {
-    /// Returns a node ordering based on the hoistability of each node.
-    pub(crate) fn hoistable_order(a: &Node, b: &Node) -> Ordering {
-        match (a, b) {
-            (Node::FunctionDecl(_), Node::FunctionDecl(_)) => Ordering::Equal,
-            (_, Node::FunctionDecl(_)) => Ordering::Greater,
-            (Node::FunctionDecl(_), _) => Ordering::Less,
-
-            (_, _) => Ordering::Equal,
-        }
-    }
-
-    /// Creates a `This` AST node.
-    pub fn this() -> Self {
-        Self::This
-    }
-
+impl NodeKind {
     /// Implements the display formatting with indentation.
     fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         let indent = "    ".repeat(indentation);
@@ -215,6 +353,7 @@ impl Node {
             Self::Const(ref c) => write!(f, "{}", c),
             Self::ConditionalOp(ref cond_op) => Display::fmt(cond_op, f),
             Self::ForLoop(ref for_loop) => for_loop.display(f, indentation),
+            Self::ForInLoop(ref for_in) => for_in.display(f, indentation),
             Self::ForOfLoop(ref for_of) => for_of.display(f, indentation),
             Self::This => write!(f, "this"),
             Self::Try(ref try_catch) => try_catch.display(f, indentation),
@@ -224,18 +363,31 @@ impl Node {
             Self::Block(ref block) => block.display(f, indentation),
             Self::Identifier(ref s) => Display::fmt(s, f),
             Self::New(ref expr) => Display::fmt(expr, f),
+            Self::Optional(ref optional) => Display::fmt(optional, f),
+            Self::TaggedTemplate(ref template) => Display::fmt(template, f),
+            Self::TemplateLit(ref template) => Display::fmt(template, f),
             Self::GetConstField(ref get_const_field) => Display::fmt(get_const_field, f),
             Self::GetField(ref get_field) => Display::fmt(get_field, f),
+            Self::GetSuperField(ref get_super_field) => Display::fmt(get_super_field, f),
+            Self::SuperCall(ref super_call) => Display::fmt(super_call, f),
             Self::WhileLoop(ref while_loop) => while_loop.display(f, indentation),
             Self::DoWhileLoop(ref do_while) => do_while.display(f, indentation),
             Self::If(ref if_smt) => if_smt.display(f, indentation),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
             Self::ArrayDecl(ref arr) => Display::fmt(arr, f),
+            Self::Pattern(ref pattern) => Display::fmt(pattern, f),
             Self::VarDeclList(ref list) => Display::fmt(list, f),
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
             Self::FunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::GeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::GeneratorExpr(ref expr) => expr.display(f, indentation),
+            Self::ClassDecl(ref decl) => decl.display(f, indentation),
+            Self::ClassExpr(ref expr) => expr.display(f, indentation),
             Self::ArrowFunctionDecl(ref decl) => decl.display(f, indentation),
+            Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
+            Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::AsyncArrowFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::BinOp(ref op) => Display::fmt(op, f),
             Self::UnaryOp(ref op) => Display::fmt(op, f),
             Self::Return(ref ret) => Display::fmt(ret, f),
@@ -243,6 +395,8 @@ impl Node {
             Self::Assign(ref op) => Display::fmt(op, f),
             Self::LetDeclList(ref decl) => Display::fmt(decl, f),
             Self::ConstDeclList(ref decl) => Display::fmt(decl, f),
+            Self::Yield(ref yield_expr) => Display::fmt(yield_expr, f),
+            Self::AwaitExpr(ref await_expr) => Display::fmt(await_expr, f),
         }
     }
 }
@@ -250,53 +404,79 @@ impl Node {
 impl Executable for Node {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Executable", "exec");
-        match *self {
-            Node::Call(ref call) => call.run(interpreter),
-            Node::Const(Const::Null) => Ok(Value::null()),
-            Node::Const(Const::Num(num)) => Ok(Value::rational(num)),
-            Node::Const(Const::Int(num)) => Ok(Value::integer(num)),
-            Node::Const(Const::BigInt(ref num)) => Ok(Value::from(num.clone())),
-            Node::Const(Const::Undefined) => Ok(Value::Undefined),
+        // The call stack points to the node being executed, for errors created by it.
+        if let Some(span) = self.span {
+            interpreter.set_call_position(span.start());
+        }
+        match self.kind {
+            NodeKind::Call(ref call) => call.run_at(self.span, interpreter),
+            NodeKind::Const(Const::Null) => Ok(Value::null()),
+            NodeKind::Const(Const::Num(num)) => Ok(Value::rational(num)),
+            NodeKind::Const(Const::Int(num)) => Ok(Value::integer(num)),
+            NodeKind::Const(Const::BigInt(ref num)) => Ok(Value::from(num.clone())),
+            NodeKind::Const(Const::Undefined) => Ok(Value::Undefined),
             // we can't move String from Const into value, because const is a garbage collected value
             // Which means Drop() get's called on Const, but str will be gone at that point.
             // Do Const values need to be garbage collected? We no longer need them once we've generated Values
-            Node::Const(Const::String(ref value)) => Ok(Value::string(value.to_string())),
-            Node::Const(Const::Bool(value)) => Ok(Value::boolean(value)),
-            Node::Block(ref block) => block.run(interpreter),
-            Node::Identifier(ref identifier) => identifier.run(interpreter),
-            Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(interpreter),
-            Node::GetField(ref get_field) => get_field.run(interpreter),
-            Node::WhileLoop(ref while_loop) => while_loop.run(interpreter),
-            Node::DoWhileLoop(ref do_while) => do_while.run(interpreter),
-            Node::ForLoop(ref for_loop) => for_loop.run(interpreter),
-            Node::ForOfLoop(ref for_of_loop) => for_of_loop.run(interpreter),
-            Node::If(ref if_smt) => if_smt.run(interpreter),
-            Node::ConditionalOp(ref op) => op.run(interpreter),
-            Node::Switch(ref switch) => switch.run(interpreter),
-            Node::Object(ref obj) => obj.run(interpreter),
-            Node::ArrayDecl(ref arr) => arr.run(interpreter),
+            NodeKind::Const(Const::String(ref value)) => Ok(Value::from(value.clone())),
+            NodeKind::Const(Const::Bool(value)) => Ok(Value::boolean(value)),
+            NodeKind::Block(ref block) => block.run(interpreter),
+            NodeKind::Identifier(ref identifier) => identifier.run(interpreter),
+            NodeKind::GetConstField(ref get_const_field_node) => {
+                get_const_field_node.run(interpreter)
+            }
+            NodeKind::GetField(ref get_field) => get_field.run(interpreter),
+            NodeKind::GetSuperField(ref get_super_field) => get_super_field.run(interpreter),
+            NodeKind::SuperCall(ref super_call) => super_call.run(interpreter),
+            NodeKind::WhileLoop(ref while_loop) => while_loop.run(interpreter),
+            NodeKind::DoWhileLoop(ref do_while) => do_while.run(interpreter),
+            NodeKind::ForLoop(ref for_loop) => for_loop.run(interpreter),
+            NodeKind::ForInLoop(ref for_in_loop) => for_in_loop.run(interpreter),
+            NodeKind::ForOfLoop(ref for_of_loop) => for_of_loop.run(interpreter),
+            NodeKind::If(ref if_smt) => if_smt.run(interpreter),
+            NodeKind::ConditionalOp(ref op) => op.run(interpreter),
+            NodeKind::Switch(ref switch) => switch.run(interpreter),
+            NodeKind::Object(ref obj) => obj.run(interpreter),
+            NodeKind::ArrayDecl(ref arr) => arr.run(interpreter),
+            NodeKind::Pattern(ref pattern) => pattern.run(interpreter),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
-            Node::FunctionDecl(ref decl) => decl.run(interpreter),
+            NodeKind::FunctionDecl(ref decl) => decl.run(interpreter),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
-            Node::FunctionExpr(ref function_expr) => function_expr.run(interpreter),
-            Node::ArrowFunctionDecl(ref decl) => decl.run(interpreter),
-            Node::BinOp(ref op) => op.run(interpreter),
-            Node::UnaryOp(ref op) => op.run(interpreter),
-            Node::New(ref call) => call.run(interpreter),
-            Node::Return(ref ret) => ret.run(interpreter),
-            Node::Throw(ref throw) => throw.run(interpreter),
-            Node::Assign(ref op) => op.run(interpreter),
-            Node::VarDeclList(ref decl) => decl.run(interpreter),
-            Node::LetDeclList(ref decl) => decl.run(interpreter),
-            Node::ConstDeclList(ref decl) => decl.run(interpreter),
-            Node::Spread(ref spread) => spread.run(interpreter),
-            Node::This => {
+            NodeKind::FunctionExpr(ref function_expr) => function_expr.run(interpreter),
+            NodeKind::GeneratorDecl(ref decl) => decl.run(interpreter),
+            NodeKind::GeneratorExpr(ref expr) => expr.run(interpreter),
+            NodeKind::ArrowFunctionDecl(ref decl) => decl.run(interpreter),
+            NodeKind::AsyncFunctionDecl(ref decl) => decl.run(interpreter),
+            NodeKind::AsyncFunctionExpr(ref expr) => expr.run(interpreter),
+            NodeKind::AsyncArrowFunctionDecl(ref decl) => decl.run(interpreter),
+            NodeKind::ClassDecl(ref decl) => decl.run(interpreter),
+            NodeKind::ClassExpr(ref expr) => expr.run(interpreter),
+            NodeKind::BinOp(ref op) => op.run(interpreter),
+            NodeKind::UnaryOp(ref op) => op.run(interpreter),
+            NodeKind::New(ref call) => call.run_at(self.span, interpreter),
+            NodeKind::Optional(ref optional) => optional.run(interpreter),
+            NodeKind::TaggedTemplate(ref template) => template.run(interpreter),
+            NodeKind::TemplateLit(ref template) => template.run(interpreter),
+            NodeKind::Return(ref ret) => ret.run(interpreter),
+            NodeKind::Throw(ref throw) => throw.run(interpreter),
+            NodeKind::Assign(ref op) => op.run(interpreter),
+            NodeKind::VarDeclList(ref decl) => decl.run(interpreter),
+            NodeKind::LetDeclList(ref decl) => decl.run(interpreter),
+            NodeKind::ConstDeclList(ref decl) => decl.run(interpreter),
+            NodeKind::Spread(ref spread) => spread.run(interpreter),
+            NodeKind::This => {
                 // Will either return `this` binding or undefined
-                Ok(interpreter.realm().environment.get_this_binding())
+                interpreter
+                    .realm()
+                    .environment
+                    .get_this_binding()
+                    .map_err(|err| interpreter.construct_reference_error(err.to_string()))
             }
-            Node::Try(ref try_node) => try_node.run(interpreter),
-            Node::Break(ref break_node) => break_node.run(interpreter),
-            Node::Continue(ref continue_node) => continue_node.run(interpreter),
+            NodeKind::Try(ref try_node) => try_node.run(interpreter),
+            NodeKind::Break(ref break_node) => break_node.run(interpreter),
+            NodeKind::Continue(ref continue_node) => continue_node.run(interpreter),
+            NodeKind::Yield(ref yield_expr) => yield_expr.run(interpreter),
+            NodeKind::AwaitExpr(ref await_expr) => await_expr.run(interpreter),
         }
     }
 }
@@ -335,27 +515,27 @@ where
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub struct FormalParameter {
-    name: Box<str>,
+    binding: Binding,
     init: Option<Node>,
     is_rest_param: bool,
 }
 
 impl FormalParameter {
     /// Creates a new formal parameter.
-    pub(in crate::syntax) fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
+    pub(in crate::syntax) fn new<B>(binding: B, init: Option<Node>, is_rest_param: bool) -> Self
     where
-        N: Into<Box<str>>,
+        B: Into<Binding>,
     {
         Self {
-            name: name.into(),
+            binding: binding.into(),
             init,
             is_rest_param,
         }
     }
 
-    /// Gets the name of the formal parameter.
-    pub fn name(&self) -> &str {
-        &self.name
+    /// Gets the name or the destructuring pattern of the formal parameter.
+    pub fn binding(&self) -> &Binding {
+        &self.binding
     }
 
     /// Gets the initialization node of the formal parameter, if any.
@@ -374,7 +554,7 @@ impl Display for FormalParameter {
         if self.is_rest_param {
             write!(f, "...")?;
         }
-        write!(f, "{}", self.name)?;
+        write!(f, "{}", self.binding)?;
         if let Some(n) = self.init.as_ref() {
             write!(f, " = {}", n)?;
         }
@@ -382,6 +562,98 @@ impl Display for FormalParameter {
     }
 }
 
+/// The name of a property in an object literal or in a class body.
+///
+/// It can be written literally, as an identifier, a string or a number, or be computed from
+/// an expression in brackets, like `[Symbol.iterator]`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Computed_property_names
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub enum PropertyName {
+    /// A name that is known when parsing.
+    Literal(Box<str>),
+    /// A name computed from an expression.
+    Computed(Node),
+}
+
+impl PropertyName {
+    /// Gets the name if it is written literally.
+    pub fn literal(&self) -> Option<&str> {
+        match self {
+            Self::Literal(ref name) => Some(name),
+            Self::Computed(_) => None,
+        }
+    }
+}
+
+impl Executable for PropertyName {
+    /// Evaluates the name to a property key, returned as a string or a symbol.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object-initializer-runtime-semantics-evaluation
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        match self {
+            Self::Literal(ref name) => Ok(Value::from(name.as_ref())),
+            Self::Computed(ref node) => {
+                let key = node.run(interpreter)?.to_property_key(interpreter)?;
+                Ok(key.into())
+            }
+        }
+    }
+}
+
+impl Display for PropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(ref name) => f.write_str(name),
+            Self::Computed(ref node) => write!(f, "[{}]", node),
+        }
+    }
+}
+
+impl From<&str> for PropertyName {
+    fn from(name: &str) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<String> for PropertyName {
+    fn from(name: String) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<Box<str>> for PropertyName {
+    fn from(name: Box<str>) -> Self {
+        Self::Literal(name)
+    }
+}
+
+/// Gets the name of a function that is defined with the property key `key`, which is the
+/// description of a symbol key in brackets.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-setfunctionname
+pub(in crate::syntax::ast::node) fn function_name(key: &PropertyKey) -> Value {
+    match key {
+        PropertyKey::Symbol(ref symbol) => match symbol.description() {
+            Some(description) => format!("[{}]", description).into(),
+            None => Value::from(""),
+        },
+        key => key.into(),
+    }
+}
+
 /// A JavaScript property is a characteristic of an object, often describing attributes associated with a data structure.
 ///
 /// A property has a name (a string) and a value (primitive, method, or object reference).
@@ -416,7 +688,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Property_definitions
-    Property(Box<str>, Node),
+    Property(PropertyName, Node),
 
     /// A property of an object can also refer to a function or a getter or setter method.
     ///
@@ -426,7 +698,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Method_definitions
-    MethodDefinition(MethodDefinitionKind, Box<str>, FunctionExpr),
+    MethodDefinition(MethodDefinitionKind, PropertyName, FunctionExpr),
 
     /// The Rest/Spread Properties for ECMAScript proposal (stage 4) adds spread properties to object literals.
     /// It copies own enumerable properties from a provided object onto a new object.
@@ -440,6 +712,17 @@ pub enum PropertyDefinition {
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Spread_properties
     SpreadObject(Node),
+
+    /// A shorthand property with an initializer, like `{ a = 1 }`.
+    ///
+    /// This is only valid in object literals that are reinterpreted as destructuring
+    /// assignment patterns, evaluating it is a syntax error.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverInitializedName
+    CoverInitializedName(Box<str>, Node),
 }
 
 impl PropertyDefinition {
@@ -454,7 +737,7 @@ impl PropertyDefinition {
     /// Creates a `Property` definition.
     pub fn property<N, V>(name: N, value: V) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
         V: Into<Node>,
     {
         Self::Property(name.into(), value.into())
@@ -463,7 +746,7 @@ impl PropertyDefinition {
     /// Creates a `MethodDefinition`.
     pub fn method_definition<N>(kind: MethodDefinitionKind, name: N, body: FunctionExpr) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
     {
         Self::MethodDefinition(kind, name.into(), body)
     }
@@ -531,7 +814,28 @@ pub enum MethodDefinitionKind {
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions#Method_definition_syntax
     Ordinary,
-    // TODO: support other method definition kinds, like `Generator`.
+
+    /// Generator methods are defined with an asterisk before the name, they return a generator
+    /// object when called.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-GeneratorMethod
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions#Generator_methods
+    Generator,
+
+    /// Async methods are defined with the `async` keyword before the name, they return a
+    /// promise when called.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-AsyncMethod
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions#Async_methods
+    Async,
 }
 
 unsafe impl Trace for MethodDefinitionKind {
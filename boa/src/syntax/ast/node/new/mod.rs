@@ -1,6 +1,9 @@
 use crate::{
-    exec::Executable,
-    syntax::ast::node::{Call, Node},
+    exec::{Executable, SuspendedFrame},
+    syntax::ast::{
+        node::{Call, Node, NodeKind},
+        Span,
+    },
     value::Value,
     BoaProfiler, Context, Result,
 };
@@ -45,16 +48,50 @@ impl New {
 
 impl Executable for New {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        self.run_at(None, interpreter)
+    }
+}
+
+impl New {
+    /// Runs the `new` expression, recording the start of `span`, the span of the `new` node, in
+    /// the call stack when the constructor is called.
+    pub(in crate::syntax::ast::node) fn run_at(
+        &self,
+        span: Option<Span>,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("New", "exec");
 
-        let func_object = self.expr().run(interpreter)?;
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            v_args.push(arg.run(interpreter)?);
+        // Step 0 evaluates the constructor and the steps after that evaluate the arguments.
+        let (func_object, mut v_args, start) = match interpreter.executor().resume_frame() {
+            Some(frame) if frame.step > 0 => {
+                let mut values = frame.values.into_iter();
+                let func_object = values.next().unwrap_or_default();
+                (func_object, values.collect(), frame.step - 1)
+            }
+            _ => {
+                let func_object = self.expr().run(interpreter);
+                let func_object = interpreter
+                    .executor()
+                    .save_frame(func_object, SuspendedFrame::default)?;
+                (func_object, Vec::with_capacity(self.args().len()), 0)
+            }
+        };
+        for (i, arg) in self.args().iter().enumerate().skip(start) {
+            let val = arg.run(interpreter);
+            let val = interpreter.executor().save_frame(val, || {
+                let mut values = vec![func_object.clone()];
+                values.extend_from_slice(&v_args);
+                SuspendedFrame::new(i + 1, values)
+            })?;
+            v_args.push(val);
         }
 
+        if let Some(span) = span {
+            interpreter.set_call_position(span.start());
+        }
         match func_object {
-            Value::Object(ref object) => object.construct(&v_args, interpreter),
+            Value::Object(ref object) => object.construct(&v_args, &func_object, interpreter),
             _ => interpreter
                 .throw_type_error(format!("{} is not a constructor", self.expr().to_string(),)),
         }
@@ -75,6 +112,6 @@ impl fmt::Display for New {
 
 impl From<New> for Node {
     fn from(new: New) -> Self {
-        Self::New(new)
+        NodeKind::New(new).into()
     }
 }
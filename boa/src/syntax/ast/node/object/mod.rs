@@ -1,8 +1,12 @@
 //! Object node.
 
 use crate::{
-    exec::Executable,
-    syntax::ast::node::{MethodDefinitionKind, Node, PropertyDefinition},
+    builtins::function::FunctionFlags,
+    exec::{Executable, SuspendedFrame},
+    property::{AccessorDescriptor, Attribute, PropertyDescriptor, PropertyKey},
+    syntax::ast::node::{
+        function_name, FunctionExpr, MethodDefinitionKind, Node, NodeKind, PropertyDefinition,
+    },
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -60,6 +64,9 @@ impl Object {
                 PropertyDefinition::SpreadObject(key) => {
                     write!(f, "{}    ...{},", indent, key)?;
                 }
+                PropertyDefinition::CoverInitializedName(key, init) => {
+                    write!(f, "{}    {} = {},", indent, key, init)?;
+                }
                 PropertyDefinition::MethodDefinition(_kind, _key, _node) => {
                     // TODO: Implement display for PropertyDefinition::MethodDefinition.
                     unimplemented!("Display for PropertyDefinition::MethodDefinition");
@@ -72,28 +79,71 @@ impl Object {
 
 impl Executable for Object {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let global_val = &interpreter
-            .realm()
-            .environment
-            .get_global_object()
-            .expect("Could not get the global object");
-        let obj = Value::new_object(Some(global_val));
+        // A suspended object literal continues with the object it was building, and the key of
+        // the property it was suspended at once the name has been evaluated.
+        let (start, obj, mut resumed_key) =
+            if let Some(frame) = interpreter.executor().resume_frame() {
+                let mut values = frame.values.into_iter();
+                let obj = values.next().unwrap_or_default();
+                (frame.step, obj, values.next())
+            } else {
+                let global_val = &interpreter
+                    .realm()
+                    .environment
+                    .get_global_object()
+                    .expect("Could not get the global object");
+                (0, Value::new_object(Some(global_val)), None)
+            };
 
         // TODO: Implement the rest of the property types.
-        for property in self.properties().iter() {
+        for (i, property) in self.properties().iter().enumerate().skip(start) {
             match property {
-                PropertyDefinition::Property(key, value) => {
-                    obj.set_field(key.clone(), value.run(interpreter)?);
+                PropertyDefinition::Property(name, value) => {
+                    let key = if let Some(key) = resumed_key.take() {
+                        key
+                    } else {
+                        let key = name.run(interpreter);
+                        interpreter
+                            .executor()
+                            .save_frame(key, || SuspendedFrame::new(i, vec![obj.clone()]))?
+                    };
+                    let value = value.run(interpreter);
+                    let value = interpreter.executor().save_frame(value, || {
+                        SuspendedFrame::new(i, vec![obj.clone(), key.clone()])
+                    })?;
+                    obj.set_field(key.to_property_key(interpreter)?, value, interpreter)?;
+                }
+                PropertyDefinition::IdentifierReference(key) => {
+                    let value = interpreter
+                        .realm()
+                        .environment
+                        .get_binding_value(key)
+                        .ok_or_else(|| {
+                            interpreter.construct_reference_error(format!("{} is not defined", key))
+                        })?;
+                    obj.set_field(key.clone(), value, interpreter)?;
+                }
+                PropertyDefinition::SpreadObject(source) => {
+                    let source = source.run(interpreter);
+                    let source = interpreter
+                        .executor()
+                        .save_frame(source, || SuspendedFrame::new(i, vec![obj.clone()]))?;
+                    obj.as_gc_object()
+                        .expect("object literal")
+                        .copy_data_properties(&source, &[], interpreter)?;
+                }
+                PropertyDefinition::CoverInitializedName(..) => {
+                    return interpreter
+                        .throw_syntax_error("invalid shorthand property initializer");
                 }
                 PropertyDefinition::MethodDefinition(kind, name, func) => {
-                    if let MethodDefinitionKind::Ordinary = kind {
-                        obj.set_field(name.clone(), func.run(interpreter)?);
-                    } else {
-                        // TODO: Implement other types of MethodDefinitionKinds.
-                        unimplemented!("other types of property method definitions.");
-                    }
+                    let key = name.run(interpreter);
+                    let key = interpreter
+                        .executor()
+                        .save_frame(key, || SuspendedFrame::new(i, vec![obj.clone()]))?
+                        .to_property_key(interpreter)?;
+                    define_method(&obj, *kind, key, func, interpreter)?;
                 }
-                i => unimplemented!("{:?} type of property", i),
             }
         }
 
@@ -101,6 +151,51 @@ impl Executable for Object {
     }
 }
 
+/// Defines a method, a getter or a setter of an object literal with the property key `key`.
+fn define_method(
+    obj: &Value,
+    kind: MethodDefinitionKind,
+    key: PropertyKey,
+    func: &FunctionExpr,
+    interpreter: &mut Context,
+) -> Result<()> {
+    let flags = match kind {
+        MethodDefinitionKind::Generator => FunctionFlags::CALLABLE | FunctionFlags::GENERATOR,
+        MethodDefinitionKind::Async => FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+        MethodDefinitionKind::Get | MethodDefinitionKind::Set => FunctionFlags::CALLABLE,
+        MethodDefinitionKind::Ordinary => FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
+    };
+    let function = interpreter.create_function(
+        function_name(&key),
+        func.parameters().to_vec(),
+        func.body().to_vec(),
+        flags,
+        func.source().cloned(),
+    );
+    if !matches!(kind, MethodDefinitionKind::Get | MethodDefinitionKind::Set) {
+        obj.set_field(key, function, interpreter)?;
+        return Ok(());
+    }
+
+    let function = function
+        .as_gc_object()
+        .expect("create_function always returns an object");
+
+    // A getter and a setter with the same name share the property.
+    let existing = obj.as_object().and_then(|obj| obj.get_own_property(&key));
+    let mut accessor = match existing {
+        Some(PropertyDescriptor::Accessor(ref accessor)) => accessor.clone(),
+        _ => AccessorDescriptor::new(None, None, Attribute::ENUMERABLE | Attribute::CONFIGURABLE),
+    };
+    if let MethodDefinitionKind::Get = kind {
+        accessor.set_getter(Some(function));
+    } else {
+        accessor.set_setter(Some(function));
+    }
+    obj.set_property(key, accessor);
+    Ok(())
+}
+
 impl fmt::Display for Object {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -120,6 +215,6 @@ where
 
 impl From<Object> for Node {
     fn from(obj: Object) -> Self {
-        Self::Object(obj)
+        NodeKind::Object(obj).into()
     }
 }
@@ -1,5 +1,9 @@
 use crate::{
-    environment::lexical_environment::VariableScope, exec::Executable, syntax::ast::node::Node,
+    exec::{Executable, SuspendedFrame},
+    syntax::ast::node::{
+        pattern::{bind_identifier, BindingKind},
+        Node, NodeKind,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -48,40 +52,59 @@ impl Assign {
     pub fn rhs(&self) -> &Node {
         &self.rhs
     }
-}
-
-impl Executable for Assign {
-    fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let _timer = BoaProfiler::global().start_event("Assign", "exec");
-        let val = self.rhs().run(interpreter)?;
-        match self.lhs() {
-            Node::Identifier(ref name) => {
-                let environment = &mut interpreter.realm_mut().environment;
 
-                if environment.has_binding(name.as_ref()) {
-                    // Binding already exists
-                    environment.set_mutable_binding(name.as_ref(), val.clone(), true);
-                } else {
-                    environment.create_mutable_binding(
-                        name.as_ref().to_owned(),
-                        true,
-                        VariableScope::Function,
-                    );
-                    environment.initialize_binding(name.as_ref(), val.clone());
-                }
+    /// Assigns `val` to the `lhs` target of an assignment.
+    pub(crate) fn assign(lhs: &Node, val: Value, interpreter: &mut Context) -> Result<()> {
+        match lhs.kind() {
+            NodeKind::Identifier(ref name) => {
+                bind_identifier(name.as_ref(), val, BindingKind::Assignment, interpreter);
             }
-            Node::GetConstField(ref get_const_field) => {
+            NodeKind::GetConstField(ref get_const_field) => {
                 let val_obj = get_const_field.obj().run(interpreter)?;
-                val_obj.set_field(get_const_field.field(), val.clone());
+                if let Some(object) = val_obj.as_gc_object() {
+                    object.set(get_const_field.field().into(), val, &val_obj, interpreter)?;
+                }
             }
-            Node::GetField(ref get_field) => {
+            NodeKind::GetField(ref get_field) => {
                 let object = get_field.obj().run(interpreter)?;
                 let field = get_field.field().run(interpreter)?;
                 let key = field.to_property_key(interpreter)?;
-                object.set_field(key, val.clone());
+                if let Some(gc_object) = object.as_gc_object() {
+                    gc_object.set(key, val, &object, interpreter)?;
+                }
+            }
+            NodeKind::GetSuperField(ref get_super_field) => {
+                let (base, key, this) = get_super_field.reference(interpreter)?;
+                base.to_object(interpreter)?
+                    .set(key, val, &this, interpreter)?;
+            }
+            NodeKind::Pattern(ref pattern) => {
+                pattern.bind(val, BindingKind::Assignment, interpreter)?;
             }
             _ => (),
         }
+        Ok(())
+    }
+}
+
+impl Executable for Assign {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Assign", "exec");
+        // Step 0 evaluates the right hand side and step 1 assigns it to the target, which can
+        // suspend in the default values of a destructuring pattern.
+        let val = match interpreter.executor().resume_frame() {
+            Some(frame) if frame.step == 1 => frame.values.into_iter().next().unwrap_or_default(),
+            _ => {
+                let val = self.rhs().run(interpreter);
+                interpreter
+                    .executor()
+                    .save_frame(val, SuspendedFrame::default)?
+            }
+        };
+        let res = Self::assign(self.lhs(), val.clone(), interpreter);
+        interpreter
+            .executor()
+            .save_frame(res, || SuspendedFrame::new(1, vec![val.clone()]))?;
         Ok(val)
     }
 }
@@ -94,6 +117,6 @@ impl fmt::Display for Assign {
 
 impl From<Assign> for Node {
     fn from(op: Assign) -> Self {
-        Self::Assign(op)
+        NodeKind::Assign(op).into()
     }
 }
@@ -1,7 +1,7 @@
 use crate::{
-    exec::Executable,
+    exec::{Executable, SuspendedFrame},
     syntax::ast::{
-        node::Node,
+        node::{Node, NodeKind},
         op::{self, AssignOp, BitOp, CompOp, LogOp, NumOp},
     },
     Context, Result, Value,
@@ -71,16 +71,49 @@ impl BinOp {
             AssignOp::Shl => x.shl(&y, interpreter),
             AssignOp::Shr => x.shr(&y, interpreter),
             AssignOp::Ushr => x.ushr(&y, interpreter),
+            AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce => Ok(y),
         }
     }
+
+    /// Checks if a logical assignment operator skips the assignment for the current value of
+    /// its target, in which case the right operand is not evaluated.
+    fn short_circuits(op: AssignOp, value: &Value) -> bool {
+        match op {
+            AssignOp::BoolAnd => !value.to_boolean(),
+            AssignOp::BoolOr => value.to_boolean(),
+            AssignOp::Coalesce => !value.is_null_or_undefined(),
+            _ => false,
+        }
+    }
+}
+
+impl BinOp {
+    /// Evaluates both operands, the left one first.
+    ///
+    /// Step 0 evaluates the left operand and step 1 the right one.
+    fn run_operands(&self, interpreter: &mut Context) -> Result<(Value, Value)> {
+        let x = match interpreter.executor().resume_frame() {
+            Some(frame) if frame.step == 1 => frame.values.into_iter().next().unwrap_or_default(),
+            _ => {
+                let x = self.lhs().run(interpreter);
+                interpreter
+                    .executor()
+                    .save_frame(x, SuspendedFrame::default)?
+            }
+        };
+        let y = self.rhs().run(interpreter);
+        let y = interpreter
+            .executor()
+            .save_frame(y, || SuspendedFrame::new(1, vec![x.clone()]))?;
+        Ok((x, y))
+    }
 }
 
 impl Executable for BinOp {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         match self.op() {
             op::BinOp::Num(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 match op {
                     NumOp::Add => x.add(&y, interpreter),
                     NumOp::Sub => x.sub(&y, interpreter),
@@ -91,8 +124,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Bit(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 match op {
                     BitOp::And => x.bitand(&y, interpreter),
                     BitOp::Or => x.bitor(&y, interpreter),
@@ -103,8 +135,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Comp(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 Ok(Value::from(match op {
                     CompOp::Equal => x.equals(&y, interpreter)?,
                     CompOp::NotEqual => !x.equals(&y, interpreter)?,
@@ -122,43 +153,54 @@ impl Executable for BinOp {
                             ));
                         }
                         let key = x.to_property_key(interpreter)?;
-                        interpreter.has_property(&y, &key)
-                    }
-                    CompOp::InstanceOf => {
-                        if !y.is_object() {
-                            return interpreter.throw_type_error(format!(
-                                "right-hand side of 'instanceof' should be an object, got {}",
-                                y.get_type().as_str()
-                            ));
-                        }
-
-                        // spec: https://tc39.es/ecma262/#sec-instanceofoperator
-                        todo!("instanceof operator")
+                        interpreter.has_property(&y, &key)?
                     }
+                    CompOp::InstanceOf => x.instance_of(&y, interpreter)?,
                 }))
             }
             op::BinOp::Log(op) => {
-                // turn a `Value` into a `bool`
-                let to_bool = |value| bool::from(&value);
-                Ok(match op {
-                    LogOp::And => Value::from(
-                        to_bool(self.lhs().run(interpreter)?)
-                            && to_bool(self.rhs().run(interpreter)?),
-                    ),
-                    LogOp::Or => Value::from(
-                        to_bool(self.lhs().run(interpreter)?)
-                            || to_bool(self.rhs().run(interpreter)?),
-                    ),
-                })
+                // Step 0 evaluates the left operand and step 1 the right one.
+                let resumed_rhs = interpreter
+                    .executor()
+                    .resume_frame()
+                    .map_or(false, |frame| frame.step == 1);
+                if !resumed_rhs {
+                    let x = self.lhs().run(interpreter);
+                    let x = interpreter
+                        .executor()
+                        .save_frame(x, SuspendedFrame::default)?;
+                    match op {
+                        LogOp::And if !x.to_boolean() => return Ok(x),
+                        LogOp::Or if x.to_boolean() => return Ok(x),
+                        LogOp::Coalesce if !x.is_null_or_undefined() => return Ok(x),
+                        _ => {}
+                    }
+                }
+                let y = self.rhs().run(interpreter);
+                interpreter
+                    .executor()
+                    .save_frame(y, || SuspendedFrame::new(1, Vec::new()))
             }
-            op::BinOp::Assign(op) => match self.lhs() {
-                Node::Identifier(ref name) => {
-                    let v_a = interpreter
-                        .realm()
-                        .environment
-                        .get_binding_value(name.as_ref())
-                        .ok_or_else(|| interpreter.construct_reference_error(name.as_ref()))?;
-                    let v_b = self.rhs().run(interpreter)?;
+            op::BinOp::Assign(op) => match self.lhs().kind() {
+                NodeKind::Identifier(ref name) => {
+                    let v_a = match interpreter.executor().resume_frame() {
+                        Some(frame) => frame.values.into_iter().next().unwrap_or_default(),
+                        None => interpreter
+                            .realm()
+                            .environment
+                            .get_binding_value(name.as_ref())
+                            .ok_or_else(|| {
+                                interpreter
+                                    .construct_reference_error(format!("{} is not defined", name))
+                            })?,
+                    };
+                    if Self::short_circuits(op, &v_a) {
+                        return Ok(v_a);
+                    }
+                    let v_b = self.rhs().run(interpreter);
+                    let v_b = interpreter
+                        .executor()
+                        .save_frame(v_b, || SuspendedFrame::new(1, vec![v_a.clone()]))?;
                     let value = Self::run_assign(op, v_a, v_b, interpreter)?;
                     interpreter.realm_mut().environment.set_mutable_binding(
                         name.as_ref(),
@@ -167,19 +209,82 @@ impl Executable for BinOp {
                     );
                     Ok(value)
                 }
-                Node::GetConstField(ref get_const_field) => {
-                    let v_r_a = get_const_field.obj().run(interpreter)?;
-                    let v_a = v_r_a.get_field(get_const_field.field());
-                    let v_b = self.rhs().run(interpreter)?;
+                NodeKind::GetConstField(ref get_const_field) => {
+                    // Step 0 evaluates the object and step 1 the right operand.
+                    let (v_r_a, v_a) = match interpreter.executor().resume_frame() {
+                        Some(frame) if frame.step == 1 => {
+                            let mut values = frame.values.into_iter();
+                            let v_r_a = values.next().unwrap_or_default();
+                            (v_r_a, values.next().unwrap_or_default())
+                        }
+                        _ => {
+                            let v_r_a = get_const_field.obj().run(interpreter);
+                            let v_r_a = interpreter
+                                .executor()
+                                .save_frame(v_r_a, SuspendedFrame::default)?;
+                            let v_a = v_r_a.get_field(get_const_field.field(), interpreter)?;
+                            (v_r_a, v_a)
+                        }
+                    };
+                    if Self::short_circuits(op, &v_a) {
+                        return Ok(v_a);
+                    }
+                    let v_b = self.rhs().run(interpreter);
+                    let v_b = interpreter.executor().save_frame(v_b, || {
+                        SuspendedFrame::new(1, vec![v_r_a.clone(), v_a.clone()])
+                    })?;
+                    let value = Self::run_assign(op, v_a, v_b, interpreter)?;
+                    v_r_a.set_field(get_const_field.field(), value.clone(), interpreter)?;
+                    Ok(value)
+                }
+                NodeKind::GetField(ref get_field) => {
+                    // Step 0 evaluates the object, step 1 the field and step 2 the right operand.
+                    let (step, values) = interpreter
+                        .executor()
+                        .resume_frame()
+                        .map_or((0, Vec::new()), |frame| (frame.step, frame.values));
+                    let mut values = values.into_iter();
+                    let v_r_a = if step == 0 {
+                        let v_r_a = get_field.obj().run(interpreter);
+                        interpreter
+                            .executor()
+                            .save_frame(v_r_a, SuspendedFrame::default)?
+                    } else {
+                        values.next().unwrap_or_default()
+                    };
+                    let (key, v_a) = if step == 2 {
+                        let key = values.next().unwrap_or_default();
+                        (key, values.next().unwrap_or_default())
+                    } else {
+                        let key = get_field.field().run(interpreter);
+                        let key = interpreter
+                            .executor()
+                            .save_frame(key, || SuspendedFrame::new(1, vec![v_r_a.clone()]))?;
+                        let key = Value::from(key.to_property_key(interpreter)?);
+                        let v_a =
+                            v_r_a.get_field(key.to_property_key(interpreter)?, interpreter)?;
+                        (key, v_a)
+                    };
+                    if Self::short_circuits(op, &v_a) {
+                        return Ok(v_a);
+                    }
+                    let v_b = self.rhs().run(interpreter);
+                    let v_b = interpreter.executor().save_frame(v_b, || {
+                        SuspendedFrame::new(2, vec![v_r_a.clone(), key.clone(), v_a.clone()])
+                    })?;
                     let value = Self::run_assign(op, v_a, v_b, interpreter)?;
-                    v_r_a.set_field(get_const_field.field(), value.clone());
+                    v_r_a.set_field(
+                        key.to_property_key(interpreter)?,
+                        value.clone(),
+                        interpreter,
+                    )?;
                     Ok(value)
                 }
-                _ => Ok(Value::undefined()),
+                _ => interpreter.throw_syntax_error("invalid assignment target"),
             },
             op::BinOp::Comma => {
-                self.lhs().run(interpreter)?;
-                Ok(self.rhs().run(interpreter)?)
+                let (_, y) = self.run_operands(interpreter)?;
+                Ok(y)
             }
         }
     }
@@ -193,6 +298,6 @@ impl fmt::Display for BinOp {
 
 impl From<BinOp> for Node {
     fn from(op: BinOp) -> Self {
-        Self::BinOp(op)
+        NodeKind::BinOp(op).into()
     }
 }
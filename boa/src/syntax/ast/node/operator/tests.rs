@@ -26,3 +26,211 @@ fn assignmentoperator_rhs_throws_error() {
 
     assert_eq!(&exec(scenario), "\"ReferenceError: b is not defined\"");
 }
+
+#[test]
+fn logical_operators_return_operands() {
+    let scenario = r#"
+        [0 || "a", 1 || "a", 0 && "a", 1 && "a", null ?? "a", 0 ?? "a", undefined ?? "a"].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a,1,0,a,a,0,a\"");
+}
+
+#[test]
+fn logical_assignment() {
+    let scenario = r#"
+        let a = null, b = 0, c = 1, d = 1, e = 0, f = 1;
+        a ??= 2;
+        b ??= 2;
+        c &&= 3;
+        e &&= 3;
+        d ||= 4;
+        f = 0;
+        f ||= 4;
+        [a, b, c, d, e, f].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2,0,3,1,0,4\"");
+}
+
+#[test]
+fn logical_assignment_short_circuits() {
+    let scenario = r#"
+        let calls = 0;
+        const o = { a: 1, b: null };
+        const key = "a";
+        o.a ||= ++calls;
+        o[key] ??= ++calls;
+        o.b &&= ++calls;
+        o.b ??= ++calls;
+        [o.a, o.b, calls].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,1,1\"");
+}
+
+#[test]
+fn optional_chaining() {
+    let scenario = r#"
+        const o = { a: { b: 2 }, n: null };
+        [o?.a?.b, o.n?.b === undefined, o.x?.b === undefined, o.a?.["b"], o.n?.[0] === undefined].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2,true,true,2,true\"");
+}
+
+#[test]
+fn delete_optional_chaining() {
+    let scenario = r#"
+        const o = { a: { b: 1, c: 2 }, k: "c" };
+        const n = null;
+        let keys = 0;
+        [
+            delete o?.a.b,
+            delete o.a?.[o.k],
+            "b" in o.a || "c" in o.a,
+            delete n?.a,
+            delete n?.[keys++],
+            delete o.x?.y.z,
+            keys,
+        ].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,false,true,true,true,0\"");
+}
+
+#[test]
+fn delete_non_reference() {
+    let scenario = r#"
+        let calls = 0;
+        const f = () => ++calls;
+        [delete f(), delete (1 + 2), delete `a`, calls].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,true,1\"");
+}
+
+#[test]
+fn conditional_before_decimal() {
+    let scenario = r#"
+        const x = true;
+        x?.5:1
+        "#;
+
+    assert_eq!(&exec(scenario), "0.5");
+}
+
+#[test]
+fn optional_call_keeps_this() {
+    let scenario = r#"
+        const o = { v: 3, f() { return this.v; } };
+        [o.f?.(), o?.f(), o["f"]?.(), o.g?.() === undefined].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"3,3,3,true\"");
+}
+
+#[test]
+fn optional_chaining_short_circuits() {
+    let scenario = r#"
+        let calls = 0;
+        const o = null;
+        o?.a.b.c(++calls);
+        o?.[++calls];
+        calls
+        "#;
+
+    assert_eq!(&exec(scenario), "0");
+}
+
+#[test]
+fn instanceof_operator() {
+    let scenario = r#"
+        class A {}
+        class B extends A {}
+        function F() {}
+        const b = new B();
+        [b instanceof B, b instanceof A, b instanceof Object, b instanceof F, new F() instanceof F, 1 instanceof F, null instanceof Object].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,true,false,true,false,false\"");
+}
+
+#[test]
+fn instanceof_builtin_constructors() {
+    let scenario = r#"
+        const buffer = new ArrayBuffer(8);
+        const instances = [
+            [{}, Object],
+            [function () {}, Function],
+            [[], Array],
+            [new String(""), String],
+            [new Number(1), Number],
+            [new Boolean(true), Boolean],
+            [new Date(), Date],
+            [/a/, RegExp],
+            [new Error(), Error],
+            [new RangeError(), RangeError],
+            [new ReferenceError(), ReferenceError],
+            [new TypeError(), TypeError],
+            [new SyntaxError(), SyntaxError],
+            [new EvalError(), EvalError],
+            [new URIError(), URIError],
+            [new AggregateError([]), AggregateError],
+            [new Map(), Map],
+            [new Set(), Set],
+            [new WeakMap(), WeakMap],
+            [new WeakSet(), WeakSet],
+            [new Promise(() => {}), Promise],
+            [buffer, ArrayBuffer],
+            [new DataView(buffer), DataView],
+            [new Int8Array(1), Int8Array],
+            [new Uint8Array(1), Uint8Array],
+            [new Uint8ClampedArray(1), Uint8ClampedArray],
+            [new Int16Array(1), Int16Array],
+            [new Uint16Array(1), Uint16Array],
+            [new Int32Array(1), Int32Array],
+            [new Uint32Array(1), Uint32Array],
+            [new Float32Array(1), Float32Array],
+            [new Float64Array(1), Float64Array],
+            [new BigInt64Array(1), BigInt64Array],
+            [new BigUint64Array(1), BigUint64Array],
+        ];
+        instances
+            .filter(([instance, constructor]) => !(instance instanceof constructor))
+            .map(([instance, constructor]) => constructor.name)
+            .join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"\"");
+}
+
+#[test]
+fn instanceof_symbol_has_instance() {
+    let scenario = r#"
+        const Even = {};
+        Even[Symbol.hasInstance] = n => n % 2 === 0;
+        [2 instanceof Even, 3 instanceof Even, Function.prototype[Symbol.hasInstance].call(Array, [])].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,false,true\"");
+}
+
+#[test]
+fn instanceof_invalid_target() {
+    let scenario = r#"
+        const errors = [];
+        const invalid = {};
+        invalid[Symbol.hasInstance] = 1;
+        for (const target of [1, {}, invalid]) {
+            try {
+                ({} instanceof target);
+            } catch (e) {
+                errors.push(e instanceof TypeError);
+            }
+        }
+        errors.join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,true\"");
+}
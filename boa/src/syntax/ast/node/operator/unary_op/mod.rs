@@ -1,6 +1,9 @@
 use crate::{
     exec::Executable,
-    syntax::ast::{node::Node, op},
+    syntax::ast::{
+        node::{Node, NodeKind},
+        op,
+    },
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -49,6 +52,9 @@ impl UnaryOp {
 
 impl Executable for UnaryOp {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        if self.op() == op::UnaryOp::Delete {
+            return self.delete(interpreter).map(Value::from);
+        }
         let x = self.target().run(interpreter)?;
 
         Ok(match self.op() {
@@ -85,35 +91,76 @@ impl Executable for UnaryOp {
                 })
             }
             op::UnaryOp::Void => Value::undefined(),
-            op::UnaryOp::Delete => match *self.target() {
-                Node::GetConstField(ref get_const_field) => Value::boolean(
-                    get_const_field
-                        .obj()
-                        .run(interpreter)?
-                        .remove_property(get_const_field.field()),
-                ),
-                Node::GetField(ref get_field) => {
-                    let obj = get_field.obj().run(interpreter)?;
-                    let field = &get_field.field().run(interpreter)?;
-                    let res = obj.remove_property(field.to_string(interpreter)?.as_str());
-                    return Ok(Value::boolean(res));
-                }
-                Node::Identifier(_) => Value::boolean(false),
-                Node::ArrayDecl(_)
-                | Node::Block(_)
-                | Node::Const(_)
-                | Node::FunctionDecl(_)
-                | Node::FunctionExpr(_)
-                | Node::New(_)
-                | Node::Object(_)
-                | Node::UnaryOp(_) => Value::boolean(true),
-                _ => panic!("SyntaxError: wrong delete argument {}", self),
-            },
+            op::UnaryOp::Delete => unreachable!("delete doesn't evaluate its target"),
             op::UnaryOp::TypeOf => Value::from(x.get_type().as_str()),
         })
     }
 }
 
+impl UnaryOp {
+    /// Deletes the property referenced by the target of a `delete` operator.
+    ///
+    /// Only the object and the key of a property access are evaluated, the property itself is
+    /// not read.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-delete-operator-runtime-semantics-evaluation
+    fn delete(&self, interpreter: &mut Context) -> Result<bool> {
+        match *self.target().kind() {
+            NodeKind::GetConstField(ref get_const_field) => {
+                let obj = get_const_field
+                    .obj()
+                    .run(interpreter)?
+                    .to_object(interpreter)?;
+                let key = get_const_field.field().into();
+                obj.delete(&key, interpreter)
+            }
+            NodeKind::GetField(ref get_field) => {
+                let obj = get_field.obj().run(interpreter)?.to_object(interpreter)?;
+                let key = get_field.field().run(interpreter)?;
+                let key = key.to_property_key(interpreter)?;
+                obj.delete(&key, interpreter)
+            }
+            NodeKind::Optional(ref optional) => optional.delete(interpreter),
+            NodeKind::GetSuperField(_) => {
+                Err(interpreter.construct_reference_error("cannot delete a super property"))
+            }
+            NodeKind::Identifier(_) => Ok(false),
+            // Any other expression is not a reference, it is evaluated and nothing is deleted.
+            NodeKind::ArrayDecl(_)
+            | NodeKind::ArrowFunctionDecl(_)
+            | NodeKind::AsyncArrowFunctionDecl(_)
+            | NodeKind::AsyncFunctionExpr(_)
+            | NodeKind::Assign(_)
+            | NodeKind::AwaitExpr(_)
+            | NodeKind::BinOp(_)
+            | NodeKind::Block(_)
+            | NodeKind::Call(_)
+            | NodeKind::ClassExpr(_)
+            | NodeKind::ConditionalOp(_)
+            | NodeKind::Const(_)
+            | NodeKind::FunctionDecl(_)
+            | NodeKind::FunctionExpr(_)
+            | NodeKind::GeneratorExpr(_)
+            | NodeKind::New(_)
+            | NodeKind::Object(_)
+            | NodeKind::TaggedTemplate(_)
+            | NodeKind::TemplateLit(_)
+            | NodeKind::This
+            | NodeKind::UnaryOp(_)
+            | NodeKind::Yield(_) => {
+                self.target().run(interpreter)?;
+                Ok(true)
+            }
+            _ => {
+                Err(interpreter.construct_syntax_error(format!("invalid delete operand {}", self)))
+            }
+        }
+    }
+}
+
 impl fmt::Display for UnaryOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}{}", self.op, self.target)
@@ -122,6 +169,6 @@ impl fmt::Display for UnaryOp {
 
 impl From<UnaryOp> for Node {
     fn from(op: UnaryOp) -> Self {
-        Self::UnaryOp(op)
+        NodeKind::UnaryOp(op).into()
     }
 }
@@ -0,0 +1,270 @@
+use crate::{
+    builtins::iterable,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    syntax::ast::node::{join_nodes, Node, NodeKind},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::{fmt, mem};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The kind of an operation of an optional chain.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum OptionalOperationKind {
+    /// A property access with the dot notation, like `a?.b`.
+    GetConstField(Box<str>),
+
+    /// A property access with the bracket notation, like `a?.[b]`.
+    GetField(Node),
+
+    /// A function call, like `a?.()`.
+    Call(Box<[Node]>),
+}
+
+/// An operation of an optional chain.
+///
+/// A shorted operation is one that directly follows a `?.` token: the rest of the chain is
+/// skipped, and the whole chain evaluates to `undefined`, if the value it is applied to is
+/// `null` or `undefined`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalOperation {
+    kind: OptionalOperationKind,
+    shorted: bool,
+}
+
+impl OptionalOperation {
+    /// Creates a new optional chain operation.
+    pub fn new(kind: OptionalOperationKind, shorted: bool) -> Self {
+        Self { kind, shorted }
+    }
+
+    /// Gets the kind of the operation.
+    pub fn kind(&self) -> &OptionalOperationKind {
+        &self.kind
+    }
+
+    /// Checks if the operation follows a `?.` token.
+    pub fn shorted(&self) -> bool {
+        self.shorted
+    }
+}
+
+impl fmt::Display for OptionalOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shorted {
+            f.write_str("?.")?;
+        }
+        match &self.kind {
+            OptionalOperationKind::GetConstField(field) if self.shorted => write!(f, "{}", field),
+            OptionalOperationKind::GetConstField(field) => write!(f, ".{}", field),
+            OptionalOperationKind::GetField(field) => write!(f, "[{}]", field),
+            OptionalOperationKind::Call(args) => {
+                f.write_str("(")?;
+                join_nodes(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The optional chaining operator allows reading the value of a property located deep within
+/// a chain of connected objects, or calling a function, without having to check that each
+/// reference in the chain is valid.
+///
+/// Syntax: `a?.b`, `a?.[b]` or `a?.()`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Optional {
+    target: Box<Node>,
+    chain: Box<[OptionalOperation]>,
+}
+
+impl Optional {
+    /// Creates an `Optional` AST node.
+    pub fn new<T, C>(target: T, chain: C) -> Self
+    where
+        T: Into<Node>,
+        C: Into<Box<[OptionalOperation]>>,
+    {
+        Self {
+            target: Box::new(target.into()),
+            chain: chain.into(),
+        }
+    }
+
+    /// Gets the target of the chain.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the operations of the chain.
+    pub fn chain(&self) -> &[OptionalOperation] {
+        &self.chain
+    }
+}
+
+impl Executable for Optional {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Optional", "exec");
+        let value = self.run_chain(self.chain().len(), interpreter)?;
+        Ok(value.map_or_else(Value::undefined, |(_, value)| value))
+    }
+}
+
+impl Optional {
+    /// Evaluates the target and the first `len` operations of the chain.
+    ///
+    /// Returns the value with the object it was read from, or `None` if the chain
+    /// short-circuits.
+    fn run_chain(&self, len: usize, interpreter: &mut Context) -> Result<Option<(Value, Value)>> {
+        // Step 0 evaluates the target, and step `i + 1` the operation `i` of the chain. A
+        // suspended call also keeps the arguments that were already evaluated.
+        let (step, values) = interpreter
+            .executor()
+            .resume_frame()
+            .map_or((0, Vec::new()), |frame| (frame.step, frame.values));
+        let (mut this, mut value, mut v_args) = if step == 0 {
+            let value = self.target().run(interpreter);
+            let value = interpreter
+                .executor()
+                .save_frame(value, SuspendedFrame::default)?;
+            (Value::undefined(), value, Vec::new())
+        } else {
+            let mut values = values.into_iter();
+            let this = values.next().unwrap_or_default();
+            let value = values.next().unwrap_or_default();
+            (this, value, values.collect())
+        };
+
+        for (i, operation) in self
+            .chain()
+            .iter()
+            .enumerate()
+            .take(len)
+            .skip(step.saturating_sub(1))
+        {
+            let resumed = step == i + 1;
+            if !resumed && operation.shorted() && value.is_null_or_undefined() {
+                return Ok(None);
+            }
+            let save = |this: &Value, value: &Value, v_args: &[Value]| {
+                let mut values = vec![this.clone(), value.clone()];
+                values.extend_from_slice(v_args);
+                SuspendedFrame::new(i + 1, values)
+            };
+            match operation.kind() {
+                OptionalOperationKind::GetConstField(field) => {
+                    let object = value.to_object(interpreter)?;
+                    let field = object.get(&field.as_ref().into(), &value, interpreter)?;
+                    this = mem::replace(&mut value, field);
+                }
+                OptionalOperationKind::GetField(field) => {
+                    let key = field.run(interpreter);
+                    let key = interpreter
+                        .executor()
+                        .save_frame(key, || save(&this, &value, &[]))?;
+                    let key = key.to_property_key(interpreter)?;
+                    let object = value.to_object(interpreter)?;
+                    let field = object.get(&key, &value, interpreter)?;
+                    this = mem::replace(&mut value, field);
+                }
+                OptionalOperationKind::Call(args) => {
+                    for arg in args.iter().skip(v_args.len()) {
+                        let val = arg.run(interpreter);
+                        let val = interpreter
+                            .executor()
+                            .save_frame(val, || save(&this, &value, &v_args))?;
+                        if let NodeKind::Spread(_) = arg.kind() {
+                            let iterator_record = iterable::get_iterator(interpreter, val)?;
+                            loop {
+                                let next = iterator_record.next(interpreter)?;
+                                if next.is_done() {
+                                    break;
+                                }
+                                v_args.push(next.value());
+                            }
+                            break; // after spread we don't accept any new arguments
+                        }
+                        v_args.push(val);
+                    }
+                    if this.is_undefined() {
+                        this = interpreter.realm().global_obj.clone();
+                    }
+                    let result = interpreter.call(&value, &this, &mem::take(&mut v_args));
+                    interpreter
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+                    value = result?;
+                    this = Value::undefined();
+                }
+            }
+        }
+        Ok(Some((this, value)))
+    }
+
+    /// Deletes the property the chain ends with, like `delete a?.b`.
+    ///
+    /// Nothing is deleted, and the result is `true`, if the chain short-circuits or if it ends
+    /// with a call.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-delete-operator-runtime-semantics-evaluation
+    pub(crate) fn delete(&self, interpreter: &mut Context) -> Result<bool> {
+        let len = self.chain().len();
+        let last = match self.chain().last() {
+            Some(last) if !matches!(last.kind(), OptionalOperationKind::Call(_)) => last,
+            _ => {
+                self.run(interpreter)?;
+                return Ok(true);
+            }
+        };
+        let (this, object) = match self.run_chain(len - 1, interpreter)? {
+            Some(reference) => reference,
+            None => return Ok(true),
+        };
+        if last.shorted() && object.is_null_or_undefined() {
+            return Ok(true);
+        }
+        let key = match last.kind() {
+            OptionalOperationKind::GetConstField(field) => field.as_ref().into(),
+            OptionalOperationKind::GetField(field) => {
+                let key = field.run(interpreter);
+                interpreter
+                    .executor()
+                    .save_frame(key, || SuspendedFrame::new(len, vec![this, object.clone()]))?
+                    .to_property_key(interpreter)?
+            }
+            OptionalOperationKind::Call(_) => unreachable!("a chain ending with a call"),
+        };
+        object.to_object(interpreter)?.delete(&key, interpreter)
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.target)?;
+        for operation in self.chain.iter() {
+            write!(f, "{}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Optional> for Node {
+    fn from(optional: Optional) -> Self {
+        NodeKind::Optional(optional).into()
+    }
+}
@@ -0,0 +1,592 @@
+//! Destructuring pattern nodes.
+//!
+//! Destructuring patterns unpack the values of arrays and the properties of objects into
+//! distinct variables. They can be used in declarations, function and catch parameters, the
+//! head of `for...of` loops and as the left hand side of an assignment.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-destructuring-binding-patterns
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+
+use crate::{
+    builtins::{
+        iterable::{get_iterator, IteratorRecord},
+        Array,
+    },
+    environment::lexical_environment::VariableScope,
+    exec::{Executable, SuspendedFrame},
+    property::PropertyKey,
+    syntax::ast::node::{Assign, Identifier, Node, NodeKind, PropertyName},
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How the names found in a pattern are bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BindingKind {
+    /// A `var` declaration, the binding is created in the function scope if it doesn't exist.
+    Var,
+    /// A `let` declaration, or a function or catch parameter.
+    Let,
+    /// A `const` declaration.
+    Const,
+    /// A destructuring assignment, the targets can be any assignable expression.
+    Assignment,
+}
+
+/// Binds `value` to the variable `name`.
+pub(crate) fn bind_identifier(name: &str, value: Value, kind: BindingKind, ctx: &mut Context) {
+    let environment = &mut ctx.realm_mut().environment;
+    match kind {
+        BindingKind::Var | BindingKind::Assignment if environment.has_binding(name) => {
+            environment.set_mutable_binding(name, value, true);
+        }
+        BindingKind::Var | BindingKind::Assignment => {
+            environment.create_mutable_binding(
+                name.to_owned(),
+                kind == BindingKind::Assignment,
+                VariableScope::Function,
+            );
+            environment.initialize_binding(name, value);
+        }
+        BindingKind::Let => {
+            environment.create_mutable_binding(name.to_owned(), false, VariableScope::Block);
+            environment.initialize_binding(name, value);
+        }
+        BindingKind::Const => {
+            environment.create_immutable_binding(name.to_owned(), false, VariableScope::Block);
+            environment.initialize_binding(name, value);
+        }
+    }
+}
+
+/// Binds `value` to a pattern target, which is either an identifier, a nested pattern or, in
+/// assignments, any assignable expression.
+fn bind_target(target: &Node, value: Value, kind: BindingKind, ctx: &mut Context) -> Result<()> {
+    match target.kind() {
+        NodeKind::Pattern(pattern) => pattern.bind(value, kind, ctx),
+        NodeKind::Identifier(name) => {
+            bind_identifier(name.as_ref(), value, kind, ctx);
+            Ok(())
+        }
+        _ => Assign::assign(target, value, ctx),
+    }
+}
+
+/// The target of a declaration or a parameter, either a single name or a destructuring
+/// pattern.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum Binding {
+    Identifier(Identifier),
+    Pattern(Pattern),
+}
+
+impl Binding {
+    /// Binds `value` to the names of this binding.
+    pub(crate) fn bind(&self, value: Value, kind: BindingKind, ctx: &mut Context) -> Result<()> {
+        match self {
+            Self::Identifier(name) => {
+                bind_identifier(name.as_ref(), value, kind, ctx);
+                Ok(())
+            }
+            Self::Pattern(pattern) => pattern.bind(value, kind, ctx),
+        }
+    }
+
+    /// Gets the name of the binding, if it is not a pattern.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Identifier(name) => Some(name.as_ref()),
+            Self::Pattern(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(name) => fmt::Display::fmt(name, f),
+            Self::Pattern(pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+impl From<Identifier> for Binding {
+    fn from(name: Identifier) -> Self {
+        Self::Identifier(name)
+    }
+}
+
+impl From<&str> for Binding {
+    fn from(name: &str) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Box<str>> for Binding {
+    fn from(name: Box<str>) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Pattern> for Binding {
+    fn from(pattern: Pattern) -> Self {
+        Self::Pattern(pattern)
+    }
+}
+
+impl From<Binding> for Node {
+    fn from(binding: Binding) -> Self {
+        match &binding {
+            Binding::Identifier(name) => NodeKind::Identifier(name.clone()).into(),
+            Binding::Pattern(pattern) => NodeKind::Pattern(pattern.clone()).into(),
+        }
+    }
+}
+
+/// A destructuring pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum Pattern {
+    Object(ObjectPattern),
+    Array(ArrayPattern),
+}
+
+impl Pattern {
+    /// Destructures `value`, binding each target of the pattern.
+    pub(crate) fn bind(&self, value: Value, kind: BindingKind, ctx: &mut Context) -> Result<()> {
+        match self {
+            Self::Object(pattern) => pattern.bind(value, kind, ctx),
+            Self::Array(pattern) => pattern.bind(value, kind, ctx),
+        }
+    }
+}
+
+impl Executable for Pattern {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        // Patterns are only ever used as binding or assignment targets.
+        interpreter.throw_syntax_error("invalid destructuring pattern")
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(pattern) => fmt::Display::fmt(pattern, f),
+            Self::Array(pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+impl From<ObjectPattern> for Pattern {
+    fn from(pattern: ObjectPattern) -> Self {
+        Self::Object(pattern)
+    }
+}
+
+impl From<ArrayPattern> for Pattern {
+    fn from(pattern: ArrayPattern) -> Self {
+        Self::Array(pattern)
+    }
+}
+
+impl From<Pattern> for Node {
+    fn from(pattern: Pattern) -> Self {
+        NodeKind::Pattern(pattern).into()
+    }
+}
+
+/// A single element of a pattern, with an optional default value used when the destructured
+/// value is `undefined`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct PatternElement {
+    target: Node,
+    init: Option<Node>,
+}
+
+impl PatternElement {
+    /// Creates a new pattern element.
+    pub(in crate::syntax) fn new<T>(target: T, init: Option<Node>) -> Self
+    where
+        T: Into<Node>,
+    {
+        Self {
+            target: target.into(),
+            init,
+        }
+    }
+
+    /// Gets the target the value is bound to.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the default value of the element, if any.
+    pub fn init(&self) -> Option<&Node> {
+        self.init.as_ref()
+    }
+
+    /// Binds `value` to the target, using the default value if it is `undefined`.
+    ///
+    /// A suspended element ignores `value`, step 0 continues with the default value and step 1
+    /// with the target, the frame keeps the value that is bound.
+    fn bind(&self, value: Value, kind: BindingKind, ctx: &mut Context) -> Result<()> {
+        let (step, value) = match ctx.executor().resume_frame() {
+            Some(frame) => (
+                frame.step,
+                frame.values.into_iter().next().unwrap_or_default(),
+            ),
+            None => (0, value),
+        };
+        let value = match self.init {
+            Some(ref init) if step == 0 && value.is_undefined() => {
+                let value = init.run(ctx);
+                ctx.executor().save_frame(value, SuspendedFrame::default)?
+            }
+            _ => value,
+        };
+        let result = bind_target(&self.target, value.clone(), kind, ctx);
+        ctx.executor()
+            .save_frame(result, || SuspendedFrame::new(1, vec![value]))
+    }
+}
+
+impl fmt::Display for PatternElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.target, f)?;
+        if let Some(ref init) = self.init {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
+
+/// An object destructuring pattern, like `{ a, b: [c, d], ...rest }`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#Object_destructuring
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ObjectPattern {
+    properties: Box<[(PropertyName, PatternElement)]>,
+    rest: Option<Box<Node>>,
+}
+
+impl ObjectPattern {
+    /// Creates a new object pattern.
+    pub(in crate::syntax) fn new<P>(properties: P, rest: Option<Node>) -> Self
+    where
+        P: Into<Box<[(PropertyName, PatternElement)]>>,
+    {
+        Self {
+            properties: properties.into(),
+            rest: rest.map(Box::new),
+        }
+    }
+
+    /// Gets the destructured properties, with the element each property is bound to.
+    pub fn properties(&self) -> &[(PropertyName, PatternElement)] {
+        &self.properties
+    }
+
+    /// Gets the target of the rest element, if any.
+    pub fn rest(&self) -> Option<&Node> {
+        self.rest.as_deref()
+    }
+
+    /// Destructures the properties of `value`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-propertybindinginitialization
+    fn bind(&self, value: Value, kind: BindingKind, ctx: &mut Context) -> Result<()> {
+        // Step 0 evaluates the key of the next property and step 1 binds the property of the
+        // last key, their frame keeps the destructured value and the keys evaluated so far.
+        // Step 2 binds the rest element, its frame keeps the rest object.
+        let (step, mut values) = match ctx.executor().resume_frame() {
+            Some(frame) => (frame.step, frame.values.into_iter()),
+            None if value.is_null_or_undefined() => {
+                return Err(
+                    ctx.construct_type_error(format!("cannot destructure '{}'", value.display()))
+                );
+            }
+            None => (0, vec![value].into_iter()),
+        };
+
+        if step == 2 {
+            let rest_object = values.next().unwrap_or_default();
+            let rest = self
+                .rest
+                .as_ref()
+                .expect("only a rest element can suspend at step 2");
+            let result = bind_target(rest, rest_object.clone(), kind, ctx);
+            return ctx
+                .executor()
+                .save_frame(result, || SuspendedFrame::new(2, vec![rest_object]));
+        }
+
+        let value = values.next().unwrap_or_default();
+        let mut bound = values
+            .map(|key| key.to_property_key(ctx))
+            .collect::<Result<Vec<_>>>()?;
+        let frame = |step: usize, bound: &[PropertyKey]| {
+            let mut values = vec![value.clone()];
+            values.extend(bound.iter().map(Value::from));
+            SuspendedFrame::new(step, values)
+        };
+
+        let object = value.to_object(ctx)?;
+        let mut resumed = step == 1;
+        let start = if resumed {
+            bound.len() - 1
+        } else {
+            bound.len()
+        };
+        for (key, element) in self.properties.iter().skip(start) {
+            let property = if resumed {
+                // The element continues with the value in its own frame.
+                resumed = false;
+                Value::undefined()
+            } else {
+                let key = key.run(ctx);
+                let key = ctx
+                    .executor()
+                    .save_frame(key, || frame(0, &bound))?
+                    .to_property_key(ctx)?;
+                let property = object.get(&key, &value, ctx)?;
+                bound.push(key);
+                property
+            };
+            let result = element.bind(property, kind, ctx);
+            ctx.executor().save_frame(result, || frame(1, &bound))?;
+        }
+
+        if let Some(ref rest) = self.rest {
+            let rest_object = Value::new_object(Some(ctx.global_object()));
+            rest_object
+                .as_gc_object()
+                .expect("rest object")
+                .copy_data_properties(&value, &bound, ctx)?;
+            let result = bind_target(rest, rest_object.clone(), kind, ctx);
+            ctx.executor()
+                .save_frame(result, || SuspendedFrame::new(2, vec![rest_object]))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ObjectPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        let mut first = true;
+        for (key, element) in self.properties.iter() {
+            if !first {
+                f.write_str(",")?;
+            }
+            first = false;
+            match element.target().kind() {
+                NodeKind::Identifier(name) if key.literal() == Some(name.as_ref()) => {
+                    write!(f, " {}", element)?
+                }
+                _ => write!(f, " {}: {}", key, element)?,
+            }
+        }
+        if let Some(ref rest) = self.rest {
+            if !first {
+                f.write_str(",")?;
+            }
+            write!(f, " ...{}", rest)?;
+        }
+        f.write_str(" }")
+    }
+}
+
+impl From<ObjectPattern> for Node {
+    fn from(pattern: ObjectPattern) -> Self {
+        NodeKind::Pattern(pattern.into()).into()
+    }
+}
+
+/// An array destructuring pattern, like `[a, , [b, c] = [], ...rest]`.
+///
+/// Array patterns destructure any iterable value, holes skip a value of the iterator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#Array_destructuring
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ArrayPattern {
+    elements: Box<[Option<PatternElement>]>,
+    rest: Option<Box<Node>>,
+}
+
+impl ArrayPattern {
+    /// Creates a new array pattern, `None` elements are holes.
+    pub(in crate::syntax) fn new<E>(elements: E, rest: Option<Node>) -> Self
+    where
+        E: Into<Box<[Option<PatternElement>]>>,
+    {
+        Self {
+            elements: elements.into(),
+            rest: rest.map(Box::new),
+        }
+    }
+
+    /// Gets the elements of the pattern, `None` elements are holes.
+    pub fn elements(&self) -> &[Option<PatternElement>] {
+        &self.elements
+    }
+
+    /// Gets the target of the rest element, if any.
+    pub fn rest(&self) -> Option<&Node> {
+        self.rest.as_deref()
+    }
+
+    /// Destructures `value` using the iterator protocol.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-iteratorbindinginitialization
+    fn bind(&self, value: Value, kind: BindingKind, ctx: &mut Context) -> Result<()> {
+        // A suspended pattern continues with the iterator, and whether it is done, at the element
+        // of the step. The step after the last element binds the rest element, its frame also
+        // keeps the array of the remaining values.
+        let (start, iterator, mut done, mut resumed) = match ctx.executor().resume_frame() {
+            Some(frame) => {
+                let mut values = frame.values.into_iter();
+                let iterator = IteratorRecord::new(
+                    values.next().unwrap_or_default(),
+                    values.next().unwrap_or_default(),
+                );
+                let done = values.next().unwrap_or_default().to_boolean();
+                (
+                    frame.step,
+                    iterator,
+                    done,
+                    Some(values.next().unwrap_or_default()),
+                )
+            }
+            None => (0, get_iterator(ctx, value)?, false, None),
+        };
+        let frame = |step: usize, done: bool, rest: Option<&Value>| {
+            let mut values = vec![
+                iterator.iterator_object().clone(),
+                iterator.next_function().clone(),
+                done.into(),
+            ];
+            values.extend(rest.cloned());
+            SuspendedFrame::new(step, values)
+        };
+
+        let mut result = Ok(());
+        for (i, element) in self.elements.iter().enumerate().skip(start) {
+            // A resumed element continues with the value in its own frame.
+            let next = if resumed.take().is_some() || done {
+                Value::undefined()
+            } else {
+                let next = iterator.next(ctx)?;
+                done = next.is_done();
+                next.value()
+            };
+            if let Some(element) = element {
+                result = element.bind(next, kind, ctx);
+                if ctx.executor().is_suspending() {
+                    // The iterator is not closed, the pattern continues when it is resumed.
+                    return ctx.executor().save_frame(result, || frame(i, done, None));
+                } else if result.is_err() {
+                    break;
+                }
+            }
+        }
+
+        if let (Some(rest), Ok(())) = (&self.rest, &result) {
+            let array = if let Some(array) = resumed {
+                array
+            } else {
+                let mut values = Vec::new();
+                while !done {
+                    let next = iterator.next(ctx)?;
+                    done = next.is_done();
+                    if !done {
+                        values.push(next.value());
+                    }
+                }
+                let array = Array::new_array(ctx)?;
+                Array::construct_array(&array, &values, ctx)?;
+                array
+            };
+            result = bind_target(rest, array.clone(), kind, ctx);
+            if ctx.executor().is_suspending() {
+                return ctx
+                    .executor()
+                    .save_frame(result, || frame(self.elements.len(), done, Some(&array)));
+            }
+        }
+
+        if done {
+            result
+        } else {
+            iterator
+                .close(result.map(|_| Value::undefined()), ctx)
+                .map(|_| ())
+        }
+    }
+}
+
+impl fmt::Display for ArrayPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        let mut first = true;
+        for element in self.elements.iter() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            if let Some(element) = element {
+                fmt::Display::fmt(element, f)?;
+            }
+        }
+        if let Some(ref rest) = self.rest {
+            if !first {
+                f.write_str(", ")?;
+            }
+            write!(f, "...{}", rest)?;
+        } else if let Some(None) = self.elements.last() {
+            // A trailing hole needs its own comma.
+            f.write_str(",")?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl From<ArrayPattern> for Node {
+    fn from(pattern: ArrayPattern) -> Self {
+        NodeKind::Pattern(pattern.into()).into()
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeKind},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -60,20 +60,20 @@ impl Return {
 impl Executable for Return {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let result = match self.expr() {
-            Some(ref v) => v.run(interpreter),
-            None => Ok(Value::undefined()),
+            Some(ref v) => v.run(interpreter)?,
+            None => Value::undefined(),
         };
         // Set flag for return
         interpreter
             .executor()
             .set_current_state(InterpreterState::Return);
-        result
+        Ok(result)
     }
 }
 
 impl From<Return> for Node {
     fn from(return_smt: Return) -> Node {
-        Node::Return(return_smt)
+        NodeKind::Return(return_smt).into()
     }
 }
 
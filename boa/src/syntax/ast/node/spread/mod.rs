@@ -1,4 +1,8 @@
-use crate::{exec::Executable, syntax::ast::node::Node, Context, Result, Value};
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{Node, NodeKind},
+    Context, Result, Value,
+};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -59,6 +63,6 @@ impl fmt::Display for Spread {
 
 impl From<Spread> for Node {
     fn from(spread: Spread) -> Node {
-        Self::Spread(spread)
+        NodeKind::Spread(spread).into()
     }
 }
@@ -1,8 +1,8 @@
 //! Statement list node.
 
 use crate::{
-    exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
     BoaProfiler, Context, Result, Value,
 };
 use gc::{unsafe_empty_trace, Finalize, Trace};
@@ -15,16 +15,16 @@ use serde::{Deserialize, Serialize};
 
 /// List of statements.
 ///
-/// Similar to `Node::Block` but without the braces.
+/// Similar to `NodeKind::Block` but without the braces.
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct StatementList {
-    #[cfg_attr(feature = "serde", serde(flatten))]
     statements: Box<[Node]>,
 }
 
@@ -46,8 +46,11 @@ impl StatementList {
             f.write_str(&indent)?;
             node.display(f, indentation + 1)?;
 
-            match node {
-                Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) => {}
+            match node.kind() {
+                NodeKind::Block(_)
+                | NodeKind::If(_)
+                | NodeKind::Switch(_)
+                | NodeKind::WhileLoop(_) => {}
                 _ => write!(f, ";")?,
             }
             writeln!(f)?;
@@ -63,11 +66,18 @@ impl Executable for StatementList {
         // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
         // The return value is uninitialized, which means it defaults to Value::Undefined
         let mut obj = Value::default();
+        let start = interpreter
+            .executor()
+            .resume_frame()
+            .map_or(0, |frame| frame.step);
         interpreter
             .executor()
             .set_current_state(InterpreterState::Executing);
-        for (i, item) in self.statements().iter().enumerate() {
-            let val = item.run(interpreter)?;
+        for (i, item) in self.statements().iter().enumerate().skip(start) {
+            let val = item.run(interpreter);
+            let val = interpreter
+                .executor()
+                .save_frame(val, || SuspendedFrame::new(i, Vec::new()))?;
             match interpreter.executor().get_current_state() {
                 InterpreterState::Return => {
                     // Early return.
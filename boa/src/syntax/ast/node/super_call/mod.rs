@@ -0,0 +1,114 @@
+use crate::{
+    builtins::iterable,
+    exec::{Executable, SuspendedFrame},
+    syntax::ast::node::{join_nodes, Node, NodeKind},
+    value::Value,
+    BoaProfiler, Context, Result,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `super(...)` call invokes the constructor of the parent class.
+///
+/// It can only be used in the constructor of a derived class, where it must be called before
+/// `this` can be accessed. The object returned by the parent constructor becomes the `this`
+/// value of the derived constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperCall
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct SuperCall {
+    args: Box<[Node]>,
+}
+
+impl SuperCall {
+    /// Creates a new `SuperCall` AST node.
+    pub fn new<A>(args: A) -> Self
+    where
+        A: Into<Box<[Node]>>,
+    {
+        Self { args: args.into() }
+    }
+
+    /// Retrieves the arguments passed to the parent constructor.
+    pub fn args(&self) -> &[Node] {
+        &self.args
+    }
+}
+
+impl Executable for SuperCall {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("SuperCall", "exec");
+        let this_env = interpreter.realm().environment.get_this_environment();
+
+        let new_target = this_env.borrow().get_new_target();
+        if new_target.is_undefined() {
+            return Err(interpreter.construct_syntax_error("'super' keyword unexpected here"));
+        }
+
+        // <https://tc39.es/ecma262/#sec-getsuperconstructor>
+        let super_constructor = this_env
+            .borrow()
+            .get_function_object()
+            .map(|function| function.borrow().get_prototype_of())
+            .unwrap_or_else(Value::undefined);
+        let super_constructor = match super_constructor.as_gc_object() {
+            Some(constructor) if constructor.is_constructable() => constructor,
+            _ => return interpreter.throw_type_error("Super constructor is not a constructor"),
+        };
+
+        // Each argument has its own step, the values of the frame are the arguments evaluated
+        // so far.
+        let (start, mut v_args) = interpreter
+            .executor()
+            .resume_frame()
+            .map_or((0, Vec::with_capacity(self.args().len())), |frame| {
+                (frame.step, frame.values)
+            });
+        for (i, arg) in self.args().iter().enumerate().skip(start) {
+            let val = arg.run(interpreter);
+            let val = interpreter
+                .executor()
+                .save_frame(val, || SuspendedFrame::new(i, v_args.clone()))?;
+            if let NodeKind::Spread(_) = arg.kind() {
+                let iterator_record = iterable::get_iterator(interpreter, val)?;
+                loop {
+                    let next = iterator_record.next(interpreter)?;
+                    if next.is_done() {
+                        break;
+                    }
+                    v_args.push(next.value());
+                }
+                break; // after spread we don't accept any new arguments
+            }
+            v_args.push(val);
+        }
+
+        let this = super_constructor.construct(&v_args, &new_target, interpreter)?;
+        let result = this_env.borrow_mut().bind_this_value(this);
+        result.map_err(|err| interpreter.construct_reference_error(err.to_string()))
+    }
+}
+
+impl fmt::Display for SuperCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("super(")?;
+        join_nodes(f, &self.args)?;
+        f.write_str(")")
+    }
+}
+
+impl From<SuperCall> for Node {
+    fn from(call: SuperCall) -> Self {
+        NodeKind::SuperCall(call).into()
+    }
+}
@@ -1,8 +1,8 @@
 //! Switch node.
 //!
 use crate::{
-    exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -123,23 +123,54 @@ impl Switch {
 
 impl Executable for Switch {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let val = self.val().run(interpreter)?;
+        // Step 0 evaluates the discriminant, then each case has a step for its condition and one
+        // for its body, and each statement of the default case has its own step.
+        let (start, val) = if let Some(frame) = interpreter.executor().resume_frame() {
+            let val = frame.values.into_iter().next().unwrap_or_default();
+            (frame.step, val)
+        } else {
+            let val = self.val().run(interpreter);
+            let val = interpreter
+                .executor()
+                .save_frame(val, SuspendedFrame::default)?;
+            (1, val)
+        };
+        let save = |step: usize| {
+            let val = val.clone();
+            move || SuspendedFrame::new(step, vec![val])
+        };
+        let default_step = 1 + self.cases().len() * 2;
+
+        let resumed_in_body = start < default_step && start % 2 == 0;
+
         let mut result = Value::null();
-        let mut matched = false;
+        let mut matched = resumed_in_body;
         interpreter
             .executor()
             .set_current_state(InterpreterState::Executing);
 
         // If a case block does not end with a break statement then subsequent cases will be run without
         // checking their conditions until a break is encountered.
-        let mut fall_through: bool = false;
+        let mut fall_through: bool = resumed_in_body;
+
+        for (i, case) in self.cases().iter().enumerate() {
+            let cond_step = 1 + i * 2;
+            let body_step = cond_step + 1;
+            if body_step < start || start >= default_step {
+                continue;
+            }
 
-        for case in self.cases().iter() {
             let cond = case.condition();
             let block = case.body();
-            if fall_through || val.strict_equals(&cond.run(interpreter)?) {
+            if !fall_through && start <= cond_step {
+                let cond = cond.run(interpreter);
+                let cond = interpreter.executor().save_frame(cond, save(cond_step))?;
+                fall_through = val.strict_equals(&cond);
+            }
+            if fall_through {
                 matched = true;
-                let result = block.run(interpreter)?;
+                let result = block.run(interpreter);
+                let result = interpreter.executor().save_frame(result, save(body_step))?;
                 match interpreter.executor().get_current_state() {
                     InterpreterState::Return => {
                         // Early return.
@@ -159,19 +190,22 @@ impl Executable for Switch {
                     }
                     InterpreterState::Executing => {
                         // Continuing execution / falling through to next case statement(s).
-                        fall_through = true;
                     }
                 }
             }
         }
 
-        if !matched {
+        if !matched || start >= default_step {
             if let Some(default) = self.default() {
                 interpreter
                     .executor()
                     .set_current_state(InterpreterState::Executing);
-                for (i, item) in default.iter().enumerate() {
-                    let val = item.run(interpreter)?;
+                let skip = start.saturating_sub(default_step);
+                for (i, item) in default.iter().enumerate().skip(skip) {
+                    let val = item.run(interpreter);
+                    let val = interpreter
+                        .executor()
+                        .save_frame(val, save(default_step + i))?;
                     match interpreter.executor().get_current_state() {
                         InterpreterState::Return => {
                             // Early return.
@@ -207,6 +241,6 @@ impl fmt::Display for Switch {
 
 impl From<Switch> for Node {
     fn from(switch: Switch) -> Self {
-        Self::Switch(switch)
+        NodeKind::Switch(switch).into()
     }
 }
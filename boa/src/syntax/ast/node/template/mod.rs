@@ -0,0 +1,322 @@
+//! Template literal node.
+
+use crate::{
+    builtins::Array,
+    exec::{Executable, SuspendedFrame},
+    property::{Attribute, DataDescriptor},
+    syntax::ast::node::{Node, NodeKind},
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::{
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// An element of a template literal, either a string or a substitution.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum TemplateElement {
+    /// The cooked value of a string of the template.
+    String(Box<str>),
+
+    /// A substitution, like `${a}`.
+    Expr(Node),
+}
+
+/// Template literals are string literals allowing embedded expressions.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-template-literals
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct TemplateLit {
+    elements: Box<[TemplateElement]>,
+}
+
+impl TemplateLit {
+    /// Creates a new `TemplateLit` AST node.
+    pub fn new<E>(elements: E) -> Self
+    where
+        E: Into<Box<[TemplateElement]>>,
+    {
+        Self {
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the elements of the template literal.
+    pub fn elements(&self) -> &[TemplateElement] {
+        &self.elements
+    }
+}
+
+impl Executable for TemplateLit {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "exec");
+        // Step `i` evaluates the element `i`, the string built so far being saved.
+        let (step, mut result) = match interpreter.executor().resume_frame() {
+            Some(frame) => {
+                let result = frame.values.into_iter().next().unwrap_or_default();
+                let result: Vec<u16> = result.to_string(interpreter)?.code_units().collect();
+                (frame.step, result)
+            }
+            None => (0, Vec::new()),
+        };
+
+        for (i, element) in self.elements.iter().enumerate().skip(step) {
+            match element {
+                TemplateElement::String(s) => result.extend(s.encode_utf16()),
+                TemplateElement::Expr(node) => {
+                    let value = node.run(interpreter);
+                    let value = interpreter.executor().save_frame(value, || {
+                        SuspendedFrame::new(i, vec![Value::from(RcString::from(&result[..]))])
+                    })?;
+                    result.extend(value.to_string(interpreter)?.code_units());
+                }
+            }
+        }
+        Ok(RcString::from(result).into())
+    }
+}
+
+impl fmt::Display for TemplateLit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("`")?;
+        for element in self.elements.iter() {
+            match element {
+                TemplateElement::String(s) => write!(f, "{}", s)?,
+                TemplateElement::Expr(node) => write!(f, "${{{}}}", node)?,
+            }
+        }
+        f.write_str("`")
+    }
+}
+
+impl From<TemplateLit> for Node {
+    fn from(template: TemplateLit) -> Self {
+        NodeKind::TemplateLit(template).into()
+    }
+}
+
+/// The first step of the evaluation of a tagged template that evaluates its substitutions.
+const SUBSTITUTIONS_STEP: usize = 2;
+
+/// The identifier of the next tagged template parsed, used to cache its template object.
+static NEXT_TEMPLATE_SITE: AtomicUsize = AtomicUsize::new(0);
+
+/// A tagged template calls a function with the strings and the substitutions of a template.
+///
+/// The strings are passed as a frozen array, with a `raw` property holding the raw strings. This
+/// template object is created once per tagged template in the source code, and reused every
+/// time it is evaluated.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize)]
+pub struct TaggedTemplate {
+    tag: Box<Node>,
+    raws: Box<[Box<str>]>,
+    cookeds: Box<[Option<Box<str>>]>,
+    exprs: Box<[Node]>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    site: usize,
+}
+
+impl TaggedTemplate {
+    /// Creates a new `TaggedTemplate` AST node.
+    ///
+    /// A cooked string is `None` if its raw string contains an invalid escape sequence.
+    pub fn new<T, R, C, E>(tag: T, raws: R, cookeds: C, exprs: E) -> Self
+    where
+        T: Into<Node>,
+        R: Into<Box<[Box<str>]>>,
+        C: Into<Box<[Option<Box<str>>]>>,
+        E: Into<Box<[Node]>>,
+    {
+        Self {
+            tag: Box::new(tag.into()),
+            raws: raws.into(),
+            cookeds: cookeds.into(),
+            exprs: exprs.into(),
+            site: NEXT_TEMPLATE_SITE.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Gets the tag function of the template.
+    pub fn tag(&self) -> &Node {
+        &self.tag
+    }
+
+    /// Gets the raw strings of the template.
+    pub fn raws(&self) -> &[Box<str>] {
+        &self.raws
+    }
+
+    /// Gets the cooked strings of the template.
+    pub fn cookeds(&self) -> &[Option<Box<str>>] {
+        &self.cookeds
+    }
+
+    /// Gets the substitutions of the template.
+    pub fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
+
+    /// Gets the template object of this tagged template, creating it on the first evaluation.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-gettemplateobject
+    fn template_object(&self, interpreter: &mut Context) -> Result<Value> {
+        if let Some(template) = interpreter.realm().template_map.get(&self.site) {
+            return Ok(template.clone().into());
+        }
+
+        let cookeds: Vec<Value> = self
+            .cookeds
+            .iter()
+            .map(|cooked| cooked.as_deref().map_or_else(Value::undefined, Value::from))
+            .collect();
+        let raws: Vec<Value> = self
+            .raws
+            .iter()
+            .map(|raw| Value::from(raw.as_ref()))
+            .collect();
+
+        let template = Array::new_array(interpreter)?;
+        Array::add_to_array_object(&template, &cookeds, interpreter)?;
+        let raw = Array::new_array(interpreter)?;
+        Array::add_to_array_object(&raw, &raws, interpreter)?;
+        let raw = raw.as_gc_object().expect("raw strings array");
+        raw.borrow_mut().freeze();
+
+        let template = template.as_gc_object().expect("template object array");
+        template
+            .borrow_mut()
+            .insert("raw", DataDescriptor::new(raw, Attribute::NON_ENUMERABLE));
+        template.borrow_mut().freeze();
+
+        interpreter
+            .realm_mut()
+            .template_map
+            .insert(self.site, template.clone());
+        Ok(template.into())
+    }
+}
+
+impl Executable for TaggedTemplate {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
+        // Step 0 evaluates the tag, step 1 the field of a computed member tag, and the steps
+        // after that evaluate the substitutions.
+        let (step, values) = interpreter
+            .executor()
+            .resume_frame()
+            .map_or((0, Vec::new()), |frame| (frame.step, frame.values));
+        let (this, func, mut args) = if step < SUBSTITUTIONS_STEP {
+            let (this, func) = match self.tag().kind() {
+                NodeKind::GetConstField(ref get_const_field) => {
+                    let obj = get_const_field.obj().run(interpreter);
+                    let obj = interpreter
+                        .executor()
+                        .save_frame(obj, SuspendedFrame::default)?;
+                    let object = obj.to_object(interpreter)?;
+                    let func = object.get(&get_const_field.field().into(), &obj, interpreter)?;
+                    (obj, func)
+                }
+                NodeKind::GetField(ref get_field) => {
+                    let obj = match values.into_iter().next() {
+                        Some(obj) if step == 1 => obj,
+                        _ => {
+                            let obj = get_field.obj().run(interpreter);
+                            interpreter
+                                .executor()
+                                .save_frame(obj, SuspendedFrame::default)?
+                        }
+                    };
+                    let field = get_field.field().run(interpreter);
+                    let field = interpreter
+                        .executor()
+                        .save_frame(field, || SuspendedFrame::new(1, vec![obj.clone()]))?;
+                    let key = field.to_property_key(interpreter)?;
+                    let func = obj.to_object(interpreter)?.get(&key, &obj, interpreter)?;
+                    (obj, func)
+                }
+                _ => {
+                    let func = self.tag().run(interpreter);
+                    let func = interpreter
+                        .executor()
+                        .save_frame(func, SuspendedFrame::default)?;
+                    (interpreter.realm().global_obj.clone(), func)
+                }
+            };
+            let mut args = Vec::with_capacity(self.exprs.len() + 1);
+            args.push(self.template_object(interpreter)?);
+            (this, func, args)
+        } else {
+            let mut values = values.into_iter();
+            let this = values.next().unwrap_or_default();
+            let func = values.next().unwrap_or_default();
+            (this, func, values.collect())
+        };
+
+        for (i, expr) in self.exprs.iter().enumerate().skip(args.len() - 1) {
+            let value = expr.run(interpreter);
+            let value = interpreter.executor().save_frame(value, || {
+                let mut values = vec![this.clone(), func.clone()];
+                values.extend_from_slice(&args);
+                SuspendedFrame::new(SUBSTITUTIONS_STEP + i, values)
+            })?;
+            args.push(value);
+        }
+
+        interpreter.call(&func, &this, &args)
+    }
+}
+
+impl PartialEq for TaggedTemplate {
+    // The template site is an identity, two tagged templates parsed from the same code are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.tag == other.tag
+            && self.raws == other.raws
+            && self.cookeds == other.cookeds
+            && self.exprs == other.exprs
+    }
+}
+
+impl fmt::Display for TaggedTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}`", self.tag)?;
+        for (raw, expr) in self.raws.iter().zip(self.exprs.iter()) {
+            write!(f, "{}${{{}}}", raw, expr)?;
+        }
+        if let Some(last) = self.raws.last() {
+            write!(f, "{}", last)?;
+        }
+        f.write_str("`")
+    }
+}
+
+impl From<TaggedTemplate> for Node {
+    fn from(template: TaggedTemplate) -> Self {
+        NodeKind::TaggedTemplate(template).into()
+    }
+}
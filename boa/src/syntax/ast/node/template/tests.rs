@@ -0,0 +1,66 @@
+use crate::exec;
+
+#[test]
+fn template_literal() {
+    let scenario = r#"
+        let a = 10, b = "x";
+        `a${a}b${b + "y"}c${`nested ${a * 2}`}`
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a10bxycnested 20\"");
+}
+
+#[test]
+fn tagged_template() {
+    let scenario = r#"
+        function tag(strings, ...values) {
+            return strings.join("|") + ":" + strings.raw.join("|") + ":" + values.join("|");
+        }
+        tag`a${1}\n${2}c`
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a|\n|c:a|\\n|c:1|2\"");
+}
+
+#[test]
+fn tagged_template_object_is_cached() {
+    let scenario = r#"
+        function tag(strings) {
+            return strings;
+        }
+        function get() {
+            return tag`a${1}b`;
+        }
+        const first = get();
+        first[0] = "changed";
+        [first === get(), tag`a${1}b` === get(), first[0], first.length].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,false,a,2\"");
+}
+
+#[test]
+fn tagged_template_invalid_escape() {
+    let scenario = r#"
+        (function (strings) {
+            return strings[0] === undefined && strings.raw[0] === "\\unicode";
+        })`\unicode`
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn tagged_template_member_this() {
+    let scenario = r#"
+        const o = {
+            value: 3,
+            tag() {
+                return this.value;
+            }
+        };
+        o.tag`a` + o["tag"]`b`
+        "#;
+
+    assert_eq!(&exec(scenario), "6");
+}
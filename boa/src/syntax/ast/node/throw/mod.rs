@@ -1,4 +1,8 @@
-use crate::{exec::Executable, syntax::ast::node::Node, Context, Result, Value};
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{Node, NodeKind},
+    Context, Result, Value,
+};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -56,6 +60,6 @@ impl fmt::Display for Throw {
 
 impl From<Throw> for Node {
     fn from(trw: Throw) -> Node {
-        Self::Throw(trw)
+        NodeKind::Throw(trw).into()
     }
 }
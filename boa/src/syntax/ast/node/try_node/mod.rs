@@ -1,7 +1,10 @@
 use crate::{
-    environment::lexical_environment::{new_declarative_environment, VariableScope},
-    exec::Executable,
-    syntax::ast::node::{Block, Identifier, Node},
+    environment::lexical_environment::new_declarative_environment,
+    exec::{Executable, InterpreterState, SuspendedFrame},
+    syntax::ast::node::{
+        pattern::{Binding, BindingKind},
+        Block, Node, NodeKind,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -94,47 +97,169 @@ impl Try {
 impl Executable for Try {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Try", "exec");
-        let res = self.block().run(interpreter).map_or_else(
-            |err| {
-                if let Some(catch) = self.catch() {
+        // Step 0 runs the block and step 1 the catch block, or binds the catch parameter when the
+        // frame keeps the error. The steps after that run the finally block, and record how the
+        // previous steps completed.
+        let frame = interpreter.executor().resume_frame().unwrap_or_default();
+
+        let res = match frame.step {
+            0 => {
+                let res = self.block().run(interpreter);
+                let res = interpreter
+                    .executor()
+                    .save_frame(res, SuspendedFrame::default);
+                match res {
+                    // A generator that is returning unwinds the stack with an error, which must
+                    // not be caught.
+                    Err(err)
+                        if !interpreter.executor().is_suspending()
+                            && interpreter.executor().get_current_state()
+                                != &InterpreterState::Return =>
                     {
-                        let env = &mut interpreter.realm_mut().environment;
-                        env.push(new_declarative_environment(Some(
-                            env.get_current_environment_ref().clone(),
-                        )));
-
-                        if let Some(param) = catch.parameter() {
-                            env.create_mutable_binding(
-                                param.to_owned(),
-                                false,
-                                VariableScope::Block,
-                            );
-
-                            env.initialize_binding(param, err);
+                        if let Some(catch) = self.catch() {
+                            {
+                                let env = &mut interpreter.realm_mut().environment;
+                                env.push(new_declarative_environment(Some(
+                                    env.get_current_environment_ref().clone(),
+                                )));
+                            }
+
+                            self.bind_catch_parameter(catch, err, interpreter)
+                        } else {
+                            Err(err)
                         }
                     }
+                    res => res,
+                }
+            }
+            1 => {
+                let catch = self
+                    .catch()
+                    .expect("only a try with a catch block can suspend in it");
+                match frame.values.first() {
+                    Some(err) => self.bind_catch_parameter(catch, err.clone(), interpreter),
+                    None => self.run_catch(catch, interpreter),
+                }
+            }
+            _ => Ok(Value::undefined()),
+        };
+
+        if interpreter.executor().is_suspending() {
+            return res;
+        }
 
-                    let res = catch.block().run(interpreter);
+        if let Some(finally) = self.finally() {
+            let (res, state) = if frame.step >= FINALLY_STEP {
+                completion_from_frame(frame)
+            } else {
+                let state = interpreter.executor().get_current_state().clone();
+                (res, state)
+            };
 
-                    // pop the block env
-                    let _ = interpreter.realm_mut().environment.pop();
+            interpreter
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+            let finally_res = finally.run(interpreter);
+            let finally_res = interpreter
+                .executor()
+                .save_frame(finally_res, || completion_to_frame(&res, &state))?;
 
-                    res
-                } else {
-                    Err(err)
-                }
-            },
-            Ok,
-        );
+            // A `return`, `break` or `continue` in the finally block overrides the completion
+            // of the other blocks.
+            if interpreter.executor().get_current_state() != &InterpreterState::Executing {
+                return Ok(finally_res);
+            }
+            interpreter.executor().set_current_state(state);
+            return res;
+        }
 
-        if let Some(finally) = self.finally() {
-            finally.run(interpreter)?;
+        res
+    }
+}
+
+impl Try {
+    /// Binds the error to the parameter of the catch block and runs it, the environment of the
+    /// catch block has already been pushed.
+    fn bind_catch_parameter(
+        &self,
+        catch: &Catch,
+        err: Value,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
+        let bound = match catch.parameter() {
+            Some(param) => param.bind(err.clone(), BindingKind::Let, interpreter),
+            None => Ok(()),
+        };
+        match bound {
+            Ok(()) => self.run_catch(catch, interpreter),
+            Err(suspended) if interpreter.executor().is_suspending() => interpreter
+                .executor()
+                .save_frame(Err(suspended), || SuspendedFrame::new(1, vec![err])),
+            Err(err) => {
+                let _ = interpreter.realm_mut().environment.pop();
+                Err(err)
+            }
+        }
+    }
+
+    /// Runs the catch block, whose environment has already been pushed.
+    fn run_catch(&self, catch: &Catch, interpreter: &mut Context) -> Result<Value> {
+        let res = catch.block().run(interpreter);
+        if interpreter.executor().is_suspending() {
+            return interpreter
+                .executor()
+                .save_frame(res, || SuspendedFrame::new(1, Vec::new()));
         }
 
+        // pop the block env
+        let _ = interpreter.realm_mut().environment.pop();
+
         res
     }
 }
 
+/// The first step of a `try` statement that runs the finally block.
+const FINALLY_STEP: usize = 2;
+
+/// Creates the frame of a suspended finally block, which records how the `try` and `catch`
+/// blocks completed.
+fn completion_to_frame(res: &Result<Value>, state: &InterpreterState) -> SuspendedFrame {
+    let (kind, label) = match state {
+        InterpreterState::Executing => (0, None),
+        InterpreterState::Return => (1, None),
+        InterpreterState::Break(label) => (2, label.as_ref()),
+        InterpreterState::Continue(label) => (3, label.as_ref()),
+    };
+    let (value, is_err) = match res {
+        Ok(value) => (value.clone(), 0),
+        Err(value) => (value.clone(), 1),
+    };
+    let label = label.map_or_else(Value::undefined, |label| Value::from(label.to_string()));
+    SuspendedFrame::new(FINALLY_STEP + kind * 2 + is_err, vec![value, label])
+}
+
+/// Restores the completion of the `try` and `catch` blocks from the frame of a suspended
+/// finally block.
+fn completion_from_frame(frame: SuspendedFrame) -> (Result<Value>, InterpreterState) {
+    let kind = (frame.step - FINALLY_STEP) / 2;
+    let is_err = (frame.step - FINALLY_STEP) % 2 == 1;
+    let mut values = frame.values.into_iter();
+    let value = values.next().unwrap_or_default();
+    let label = values.next().and_then(|label| {
+        label
+            .as_string()
+            .map(|label| label.to_std_string_lossy().into())
+    });
+    let state = match kind {
+        1 => InterpreterState::Return,
+        2 => InterpreterState::Break(label),
+        3 => InterpreterState::Continue(label),
+        _ => InterpreterState::Executing,
+    };
+    let res = if is_err { Err(value) } else { Ok(value) };
+    (res, state)
+}
+
 impl fmt::Display for Try {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -143,7 +268,7 @@ impl fmt::Display for Try {
 
 impl From<Try> for Node {
     fn from(try_catch: Try) -> Self {
-        Self::Try(try_catch)
+        NodeKind::Try(try_catch).into()
     }
 }
 
@@ -151,7 +276,7 @@ impl From<Try> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Catch {
-    parameter: Option<Identifier>,
+    parameter: Option<Binding>,
     block: Block,
 }
 
@@ -160,7 +285,7 @@ impl Catch {
     pub(in crate::syntax) fn new<OI, I, B>(parameter: OI, block: B) -> Self
     where
         OI: Into<Option<I>>,
-        I: Into<Identifier>,
+        I: Into<Binding>,
         B: Into<Block>,
     {
         Self {
@@ -170,8 +295,8 @@ impl Catch {
     }
 
     /// Gets the parameter of the catch block.
-    pub fn parameter(&self) -> Option<&str> {
-        self.parameter.as_ref().map(Identifier::as_ref)
+    pub fn parameter(&self) -> Option<&Binding> {
+        self.parameter.as_ref()
     }
 
     /// Retrieves the catch execution block.
@@ -0,0 +1,193 @@
+use crate::{
+    builtins::iterable,
+    exec::{Executable, InterpreterState, ResumeKind, SuspendedFrame},
+    syntax::ast::node::{Node, NodeKind},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `yield` keyword is used to pause and resume a generator function.
+///
+/// Syntax: `yield [expression];` or `yield* expression;`
+///
+/// The `yield` expression suspends the generator and returns the value of its expression to the
+/// caller of the generator's `next()` method. When the generator is resumed, the `yield`
+/// expression evaluates to the argument given to `next()`.
+///
+/// `yield*` delegates to another iterable object, yielding each of its values.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Yield {
+    expr: Option<Box<Node>>,
+    delegate: bool,
+}
+
+impl Yield {
+    /// Gets the expression whose value is yielded.
+    pub fn expr(&self) -> Option<&Node> {
+        self.expr.as_ref().map(Box::as_ref)
+    }
+
+    /// Returns `true` if this is a `yield*` expression.
+    pub fn delegate(&self) -> bool {
+        self.delegate
+    }
+
+    /// Creates a `Yield` AST node.
+    pub fn new<E, OE>(expr: OE, delegate: bool) -> Self
+    where
+        E: Into<Node>,
+        OE: Into<Option<E>>,
+    {
+        Self {
+            expr: expr.into().map(E::into).map(Box::new),
+            delegate,
+        }
+    }
+
+    /// Completes the `yield` expression with the value the generator was resumed with.
+    fn resume(interpreter: &mut Context) -> Result<Value> {
+        let (kind, value) = interpreter.executor().take_resume_value();
+        match kind {
+            ResumeKind::Next => Ok(value),
+            ResumeKind::Throw => Err(value),
+            ResumeKind::Return => Self::return_from_generator(interpreter, value),
+        }
+    }
+
+    /// Returns from the generator, as if a `return` statement was found.
+    ///
+    /// The value unwinds the stack as an error, which `try` statements do not catch while the
+    /// interpreter is returning.
+    fn return_from_generator(interpreter: &mut Context, value: Value) -> Result<Value> {
+        interpreter
+            .executor()
+            .set_current_state(InterpreterState::Return);
+        Err(value)
+    }
+
+    /// Forwards the value the generator was resumed with to the iterator of a `yield*`
+    /// expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluation
+    fn delegate_to(
+        interpreter: &mut Context,
+        iterator: Value,
+        next: Value,
+        kind: ResumeKind,
+        received: Value,
+    ) -> Result<Value> {
+        let result = match kind {
+            ResumeKind::Next => interpreter.call(&next, &iterator, &[received])?,
+            ResumeKind::Throw => {
+                let throw = iterator.get_field("throw", interpreter)?;
+                if throw.is_undefined() || throw.is_null() {
+                    let r#return = iterator.get_field("return", interpreter)?;
+                    if !r#return.is_undefined() && !r#return.is_null() {
+                        interpreter.call(&r#return, &iterator, &[])?;
+                    }
+                    return interpreter
+                        .throw_type_error("The delegated iterator does not have a throw method");
+                }
+                interpreter.call(&throw, &iterator, &[received])?
+            }
+            ResumeKind::Return => {
+                let r#return = iterator.get_field("return", interpreter)?;
+                if r#return.is_undefined() || r#return.is_null() {
+                    return Self::return_from_generator(interpreter, received);
+                }
+                interpreter.call(&r#return, &iterator, &[received])?
+            }
+        };
+        if !result.is_object() {
+            return interpreter.throw_type_error("Iterator result is not an object");
+        }
+
+        let value = result.get_field("value", interpreter)?;
+        if result.get_field("done", interpreter)?.to_boolean() {
+            return match kind {
+                ResumeKind::Return => Self::return_from_generator(interpreter, value),
+                _ => Ok(value),
+            };
+        }
+        interpreter
+            .executor()
+            .suspend(SuspendedFrame::new(2, vec![iterator, next]), value)
+    }
+}
+
+impl Executable for Yield {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Yield", "exec");
+        // Step 0 evaluates the expression, step 1 is a suspended `yield` and step 2 is a
+        // suspended `yield*`, whose values are the delegated iterator and its `next` method.
+        match interpreter.executor().resume_frame() {
+            Some(frame) if frame.step == 1 => return Self::resume(interpreter),
+            Some(frame) if frame.step == 2 => {
+                let mut values = frame.values.into_iter();
+                let iterator = values.next().unwrap_or_default();
+                let next = values.next().unwrap_or_default();
+                let (kind, received) = interpreter.executor().take_resume_value();
+                return Self::delegate_to(interpreter, iterator, next, kind, received);
+            }
+            _ => {}
+        }
+
+        let value = match self.expr() {
+            Some(expr) => {
+                let value = expr.run(interpreter);
+                interpreter
+                    .executor()
+                    .save_frame(value, SuspendedFrame::default)?
+            }
+            None => Value::undefined(),
+        };
+
+        if self.delegate {
+            let iterator_record = iterable::get_iterator(interpreter, value)?;
+            let iterator = iterator_record.iterator_object().clone();
+            let next = iterator_record.next_function().clone();
+            return Self::delegate_to(
+                interpreter,
+                iterator,
+                next,
+                ResumeKind::Next,
+                Value::undefined(),
+            );
+        }
+
+        interpreter
+            .executor()
+            .suspend(SuspendedFrame::new(1, Vec::new()), value)
+    }
+}
+
+impl From<Yield> for Node {
+    fn from(yield_expr: Yield) -> Node {
+        NodeKind::Yield(yield_expr).into()
+    }
+}
+
+impl fmt::Display for Yield {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keyword = if self.delegate { "yield*" } else { "yield" };
+        match self.expr() {
+            Some(ex) => write!(f, "{} {}", keyword, ex),
+            None => f.write_str(keyword),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! This module implements the `Pos` structure, which represents a position in the source code.
 
+use gc::{unsafe_empty_trace, Finalize, Trace};
 use std::{cmp::Ordering, fmt, num::NonZeroU32};
 
 #[cfg(feature = "serde")]
@@ -47,6 +48,12 @@ impl Position {
     }
 }
 
+impl Finalize for Position {}
+
+unsafe impl Trace for Position {
+    unsafe_empty_trace!();
+}
+
 impl fmt::Display for Position {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}:{}", self.line_number, self.column_number)
@@ -96,6 +103,12 @@ impl Span {
     }
 }
 
+impl Finalize for Span {}
+
+unsafe impl Trace for Span {
+    unsafe_empty_trace!();
+}
+
 impl From<Position> for Span {
     fn from(pos: Position) -> Self {
         Self {
@@ -1,7 +1,10 @@
 //! Module implementing the lexer cursor. This is used for managing the input byte stream.
 
 use crate::{profiler::BoaProfiler, syntax::ast::Position};
-use std::io::{self, Bytes, Error, ErrorKind, Read};
+use std::{
+    collections::VecDeque,
+    io::{self, Bytes, Error, ErrorKind, Read},
+};
 
 /// Cursor over the source code.
 #[derive(Debug)]
@@ -9,6 +12,8 @@ pub(super) struct Cursor<R> {
     iter: InnerIter<R>,
     pos: Position,
     strict_mode: bool,
+    /// The byte offsets in the source code where each line starts.
+    line_starts: Vec<usize>,
 }
 
 impl<R> Cursor<R> {
@@ -30,6 +35,34 @@ impl<R> Cursor<R> {
     fn next_line(&mut self) {
         let next_line = self.pos.line_number() + 1;
         self.pos = Position::new(next_line, 1);
+        self.line_starts.push(self.iter.consumed());
+    }
+
+    /// Gets the source code between two positions, if it has already been read.
+    pub(super) fn source_text(&self, start: Position, end: Position) -> Option<&str> {
+        let start = self.offset(start)?;
+        let end = self.offset(end)?;
+        std::str::from_utf8(self.iter.source.get(start..end)?).ok()
+    }
+
+    /// Gets the byte offset of a position in the source code read so far.
+    fn offset(&self, pos: Position) -> Option<usize> {
+        let line_start = *self.line_starts.get(pos.line_number() as usize - 1)?;
+        let mut column = pos.column_number() - 1;
+        for (offset, byte) in self.iter.source[line_start..].iter().enumerate() {
+            // Continuation bytes of UTF-8 characters don't start a new column.
+            if byte & 0xC0 != 0x80 {
+                if column == 0 {
+                    return Some(line_start + offset);
+                }
+                column -= 1;
+            }
+        }
+        if column == 0 {
+            Some(self.iter.source.len())
+        } else {
+            None
+        }
     }
 
     #[inline]
@@ -54,6 +87,7 @@ where
             iter: InnerIter::new(inner.bytes()),
             pos: Position::new(1, 1),
             strict_mode: false,
+            line_starts: vec![0],
         }
     }
 
@@ -65,6 +99,14 @@ where
         self.iter.peek_char()
     }
 
+    /// Peeks the character after the next one.
+    #[inline]
+    pub(super) fn peek_second(&mut self) -> Result<Option<char>, Error> {
+        let _timer = BoaProfiler::global().start_event("cursor::peek_second()", "Lexing");
+
+        self.iter.peek_second_char()
+    }
+
     /// Compares the character passed in to the next character, if they match true is returned and the buffer is incremented
     #[inline]
     pub(super) fn next_is(&mut self, peek: char) -> io::Result<bool> {
@@ -72,7 +114,7 @@ where
 
         Ok(match self.peek()? {
             Some(next) if next == peek => {
-                let _ = self.iter.next_char();
+                let _ = self.next_char()?;
                 true
             }
             _ => false,
@@ -147,7 +189,24 @@ where
     pub(super) fn fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
         let _timer = BoaProfiler::global().start_event("cursor::fill_bytes()", "Lexing");
 
-        self.iter.fill_bytes(buf)
+        for byte in buf.iter_mut() {
+            *byte = match self.next_char()? {
+                Some(chr) if chr.is_ascii() => chr as u8,
+                Some(_) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "non-ASCII byte found",
+                    ))
+                }
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "unexpected EOF when filling buffer",
+                    ))
+                }
+            };
+        }
+        Ok(())
     }
 
     /// Retrieves the next UTF-8 character.
@@ -179,7 +238,10 @@ where
 #[derive(Debug)]
 struct InnerIter<R> {
     iter: Bytes<R>,
-    peeked_char: Option<Option<char>>,
+    /// The characters that were peeked but not consumed yet, at most two.
+    peeked: VecDeque<Option<char>>,
+    /// Every byte read so far, including the ones of the peeked character.
+    source: Vec<u8>,
 }
 
 impl<R> InnerIter<R> {
@@ -188,52 +250,54 @@ impl<R> InnerIter<R> {
     fn new(iter: Bytes<R>) -> Self {
         Self {
             iter,
-            peeked_char: None,
+            peeked: VecDeque::with_capacity(2),
+            source: Vec::new(),
         }
     }
+
+    /// Gets the number of bytes consumed so far, which excludes the peeked characters.
+    #[inline]
+    fn consumed(&self) -> usize {
+        let peeked: usize = self.peeked.iter().flatten().map(|chr| chr.len_utf8()).sum();
+        self.source.len() - peeked
+    }
 }
 
 impl<R> InnerIter<R>
 where
     R: Read,
 {
-    /// It will fill the buffer with checked ASCII bytes.
-    ///
-    /// This expects for the buffer to be fully filled. If it's not, it will fail with an
-    /// `UnexpectedEof` I/O error.
+    /// Peeks the next UTF-8 checked character.
     #[inline]
-    fn fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
-        for byte in buf.iter_mut() {
-            *byte = self.next_ascii()?.ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::UnexpectedEof,
-                    "unexpected EOF when filling buffer",
-                )
-            })?;
+    pub(super) fn peek_char(&mut self) -> Result<Option<char>, Error> {
+        if self.peeked.is_empty() {
+            let chr = self.read_char()?;
+            self.peeked.push_back(chr);
         }
-        Ok(())
+        Ok(self.peeked[0])
     }
 
-    /// Peeks the next UTF-8 checked character.
+    /// Peeks the UTF-8 checked character after the next one.
     #[inline]
-    pub(super) fn peek_char(&mut self) -> Result<Option<char>, Error> {
-        if let Some(v) = self.peeked_char {
-            Ok(v)
-        } else {
-            let chr = self.next_char()?;
-            self.peeked_char = Some(chr);
-            Ok(chr)
+    fn peek_second_char(&mut self) -> Result<Option<char>, Error> {
+        while self.peeked.len() < 2 {
+            let chr = self.read_char()?;
+            self.peeked.push_back(chr);
         }
+        Ok(self.peeked[1])
     }
 
     /// Retrieves the next UTF-8 checked character.
     fn next_char(&mut self) -> io::Result<Option<char>> {
-        if let Some(v) = self.peeked_char {
-            let _ = self.peeked_char.take();
-            return Ok(v);
+        match self.peeked.pop_front() {
+            Some(chr) => Ok(chr),
+            None => self.read_char(),
         }
+    }
 
-        let first_byte = match self.iter.next().transpose()? {
+    /// Reads the next UTF-8 checked character from the source.
+    fn read_char(&mut self) -> io::Result<Option<char>> {
+        let first_byte = match self.next_byte()? {
             Some(b) => b,
             None => return Ok(None),
         };
@@ -255,9 +319,8 @@ where
             };
 
             for b in buf.iter_mut().take(num_bytes).skip(1) {
-                let next = match self.iter.next() {
-                    Some(Ok(b)) => b,
-                    Some(Err(e)) => return Err(e),
+                let next = match self.next_byte()? {
+                    Some(b) => b,
                     None => {
                         return Err(io::Error::new(
                             io::ErrorKind::InvalidData,
@@ -289,16 +352,13 @@ where
         Ok(Some(chr))
     }
 
-    /// Retrieves the next ASCII checked character.
+    /// Reads the next byte of the source code, keeping it in the source buffer.
     #[inline]
-    fn next_ascii(&mut self) -> io::Result<Option<u8>> {
-        match self.next_char() {
-            Ok(Some(chr)) if chr.is_ascii() => Ok(Some(chr as u8)),
-            Ok(None) => Ok(None),
-            _ => Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "non-ASCII byte found",
-            )),
+    fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        let byte = self.iter.next().transpose()?;
+        if let Some(byte) = byte {
+            self.source.push(byte);
         }
+        Ok(byte)
     }
 }
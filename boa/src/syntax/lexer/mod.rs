@@ -44,6 +44,7 @@ use crate::syntax::ast::{Punctuator, Span};
 pub use crate::{profiler::BoaProfiler, syntax::ast::Position};
 pub use error::Error;
 use std::io::Read;
+pub use template::TemplateString;
 pub use token::{Token, TokenKind};
 
 trait Tokenizer<R> {
@@ -100,6 +101,12 @@ impl<R> Lexer<R> {
         self.cursor.set_strict_mode(strict_mode)
     }
 
+    /// Gets the source code between two positions, if the lexer has already read it.
+    #[inline]
+    pub(super) fn source_text(&self, start: Position, end: Position) -> Option<&str> {
+        self.cursor.source_text(start, end)
+    }
+
     /// Creates a new lexer.
     #[inline]
     pub fn new(reader: R) -> Self
@@ -169,6 +176,14 @@ impl<R> Lexer<R> {
         }
     }
 
+    /// Lexes the rest of a template literal, after the `}` closing a substitution was consumed.
+    pub(crate) fn lex_template(&mut self, start: Position) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        TemplateLiteral.lex(&mut self.cursor, start)
+    }
+
     /// Retrieves the next token from the lexer.
     // We intentionally don't implement Iterator trait as Result<Option> is cleaner to handle.
     #[allow(clippy::should_implement_trait)]
@@ -209,6 +224,9 @@ impl<R> Lexer<R> {
                 Punctuator::Colon.into(),
                 Span::new(start, self.cursor.pos()),
             )),
+            '.' if matches!(self.cursor.peek()?, Some('0'..='9')) => {
+                NumberLiteral::new(next_chr).lex(&mut self.cursor, start)
+            }
             '.' => SpreadLiteral::new().lex(&mut self.cursor, start),
             '(' => Ok(Token::new(
                 Punctuator::OpenParen.into(),
@@ -238,12 +256,8 @@ impl<R> Lexer<R> {
                 Punctuator::CloseBracket.into(),
                 Span::new(start, self.cursor.pos()),
             )),
-            '?' => Ok(Token::new(
-                Punctuator::Question.into(),
-                Span::new(start, self.cursor.pos()),
-            )),
             '/' => self.lex_slash_token(start),
-            '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' => {
+            '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' | '?' => {
                 Operator::new(next_chr).lex(&mut self.cursor, start)
             }
             _ => {
@@ -147,138 +147,149 @@ impl<R> Tokenizer<R> for NumberLiteral {
 
         let c = cursor.peek();
 
-        if self.init == '0' {
-            if let Some(ch) = c? {
-                match ch {
-                    'x' | 'X' => {
-                        // Remove the initial '0' from buffer.
-                        cursor.next_char()?.expect("x or X character vanished");
-                        buf.pop();
-
-                        // HexIntegerLiteral
-                        kind = NumericKind::Integer(16);
-                    }
-                    'o' | 'O' => {
-                        // Remove the initial '0' from buffer.
-                        cursor.next_char()?.expect("o or O character vanished");
-                        buf.pop();
+        if self.init == '.' {
+            // A number that starts with its decimal separator, like `.5`.
+            kind = NumericKind::Rational;
+            cursor.take_while_pred(&mut buf, &|c: char| c.is_digit(10))?;
+            if let Some('e') | Some('E') = cursor.peek()? {
+                cursor.next_char()?.expect("e or E character vanished"); // Consume the ExponentIndicator.
+                buf.push('E');
+                take_signed_integer(&mut buf, cursor, &kind)?;
+            }
+        } else {
+            if self.init == '0' {
+                if let Some(ch) = c? {
+                    match ch {
+                        'x' | 'X' => {
+                            // Remove the initial '0' from buffer.
+                            cursor.next_char()?.expect("x or X character vanished");
+                            buf.pop();
+
+                            // HexIntegerLiteral
+                            kind = NumericKind::Integer(16);
+                        }
+                        'o' | 'O' => {
+                            // Remove the initial '0' from buffer.
+                            cursor.next_char()?.expect("o or O character vanished");
+                            buf.pop();
 
-                        // OctalIntegerLiteral
-                        kind = NumericKind::Integer(8);
-                    }
-                    'b' | 'B' => {
-                        // Remove the initial '0' from buffer.
-                        cursor.next_char()?.expect("b or B character vanished");
-                        buf.pop();
+                            // OctalIntegerLiteral
+                            kind = NumericKind::Integer(8);
+                        }
+                        'b' | 'B' => {
+                            // Remove the initial '0' from buffer.
+                            cursor.next_char()?.expect("b or B character vanished");
+                            buf.pop();
 
-                        // BinaryIntegerLiteral
-                        kind = NumericKind::Integer(2);
-                    }
-                    'n' => {
-                        cursor.next_char()?.expect("n character vanished");
-
-                        // DecimalBigIntegerLiteral '0n'
-                        return Ok(Token::new(
-                            TokenKind::NumericLiteral(Numeric::BigInt(0.into())),
-                            Span::new(start_pos, cursor.pos()),
-                        ));
-                    }
-                    ch => {
-                        if ch.is_digit(8) {
-                            // LegacyOctalIntegerLiteral
-                            if cursor.strict_mode() {
-                                // LegacyOctalIntegerLiteral is forbidden with strict mode true.
-                                return Err(Error::syntax(
-                                    "implicit octal literals are not allowed in strict mode",
-                                    start_pos,
-                                ));
-                            } else {
-                                // Remove the initial '0' from buffer.
-                                buf.pop();
-
-                                buf.push(cursor.next_char()?.expect("'0' character vanished"));
-
-                                kind = NumericKind::Integer(8);
-                            }
-                        } else if ch.is_digit(10) {
-                            // Indicates a numerical digit comes after then 0 but it isn't an octal digit
-                            // so therefore this must be a number with an unneeded leading 0. This is
-                            // forbidden in strict mode.
-                            if cursor.strict_mode() {
-                                return Err(Error::syntax(
-                                    "leading 0's are not allowed in strict mode",
-                                    start_pos,
-                                ));
-                            } else {
-                                buf.push(cursor.next_char()?.expect("Number digit vanished"));
-                            }
-                        } // Else indicates that the symbol is a non-number.
+                            // BinaryIntegerLiteral
+                            kind = NumericKind::Integer(2);
+                        }
+                        'n' => {
+                            cursor.next_char()?.expect("n character vanished");
+
+                            // DecimalBigIntegerLiteral '0n'
+                            return Ok(Token::new(
+                                TokenKind::NumericLiteral(Numeric::BigInt(0.into())),
+                                Span::new(start_pos, cursor.pos()),
+                            ));
+                        }
+                        ch => {
+                            if ch.is_digit(8) {
+                                // LegacyOctalIntegerLiteral
+                                if cursor.strict_mode() {
+                                    // LegacyOctalIntegerLiteral is forbidden with strict mode true.
+                                    return Err(Error::syntax(
+                                        "implicit octal literals are not allowed in strict mode",
+                                        start_pos,
+                                    ));
+                                } else {
+                                    // Remove the initial '0' from buffer.
+                                    buf.pop();
+
+                                    buf.push(cursor.next_char()?.expect("'0' character vanished"));
+
+                                    kind = NumericKind::Integer(8);
+                                }
+                            } else if ch.is_digit(10) {
+                                // Indicates a numerical digit comes after then 0 but it isn't an octal digit
+                                // so therefore this must be a number with an unneeded leading 0. This is
+                                // forbidden in strict mode.
+                                if cursor.strict_mode() {
+                                    return Err(Error::syntax(
+                                        "leading 0's are not allowed in strict mode",
+                                        start_pos,
+                                    ));
+                                } else {
+                                    buf.push(cursor.next_char()?.expect("Number digit vanished"));
+                                }
+                            } // Else indicates that the symbol is a non-number.
+                        }
                     }
+                } else {
+                    // DecimalLiteral lexing.
+                    // Indicates that the number is just a single 0.
+                    return Ok(Token::new(
+                        TokenKind::NumericLiteral(Numeric::Integer(0)),
+                        Span::new(start_pos, cursor.pos()),
+                    ));
                 }
-            } else {
-                // DecimalLiteral lexing.
-                // Indicates that the number is just a single 0.
-                return Ok(Token::new(
-                    TokenKind::NumericLiteral(Numeric::Integer(0)),
-                    Span::new(start_pos, cursor.pos()),
-                ));
             }
-        }
 
-        // Consume digits until a non-digit character is encountered or all the characters are consumed.
-        cursor.take_while_pred(&mut buf, &|c: char| c.is_digit(kind.base()))?;
+            // Consume digits until a non-digit character is encountered or all the characters are consumed.
+            cursor.take_while_pred(&mut buf, &|c: char| c.is_digit(kind.base()))?;
 
-        // The non-digit character could be:
-        // 'n' To indicate a BigIntLiteralSuffix.
-        // '.' To indicate a decimal seperator.
-        // 'e' | 'E' To indicate an ExponentPart.
-        match cursor.peek()? {
-            Some('n') => {
-                // DecimalBigIntegerLiteral
-                // Lexing finished.
+            // The non-digit character could be:
+            // 'n' To indicate a BigIntLiteralSuffix.
+            // '.' To indicate a decimal seperator.
+            // 'e' | 'E' To indicate an ExponentPart.
+            match cursor.peek()? {
+                Some('n') => {
+                    // DecimalBigIntegerLiteral
+                    // Lexing finished.
 
-                // Consume the n
-                cursor.next_char()?.expect("n character vanished");
+                    // Consume the n
+                    cursor.next_char()?.expect("n character vanished");
 
-                kind = kind.to_bigint();
-            }
-            Some('.') => {
-                if kind.base() == 10 {
-                    // Only base 10 numbers can have a decimal seperator.
-                    // Number literal lexing finished if a . is found for a number in a different base.
+                    kind = kind.to_bigint();
+                }
+                Some('.') => {
+                    if kind.base() == 10 {
+                        // Only base 10 numbers can have a decimal seperator.
+                        // Number literal lexing finished if a . is found for a number in a different base.
 
-                    cursor.next_char()?.expect(". token vanished");
-                    buf.push('.'); // Consume the .
-                    kind = NumericKind::Rational;
+                        cursor.next_char()?.expect(". token vanished");
+                        buf.push('.'); // Consume the .
+                        kind = NumericKind::Rational;
 
-                    // Consume digits until a non-digit character is encountered or all the characters are consumed.
-                    cursor.take_while_pred(&mut buf, &|c: char| c.is_digit(kind.base()))?;
+                        // Consume digits until a non-digit character is encountered or all the characters are consumed.
+                        cursor.take_while_pred(&mut buf, &|c: char| c.is_digit(kind.base()))?;
 
-                    // The non-digit character at this point must be an 'e' or 'E' to indicate an Exponent Part.
-                    // Another '.' or 'n' is not allowed.
-                    match cursor.peek()? {
-                        Some('e') | Some('E') => {
-                            // Consume the ExponentIndicator.
-                            cursor.next_char()?.expect("e or E token vanished");
+                        // The non-digit character at this point must be an 'e' or 'E' to indicate an Exponent Part.
+                        // Another '.' or 'n' is not allowed.
+                        match cursor.peek()? {
+                            Some('e') | Some('E') => {
+                                // Consume the ExponentIndicator.
+                                cursor.next_char()?.expect("e or E token vanished");
 
-                            buf.push('E');
+                                buf.push('E');
 
-                            take_signed_integer(&mut buf, cursor, &kind)?;
-                        }
-                        Some(_) | None => {
-                            // Finished lexing.
+                                take_signed_integer(&mut buf, cursor, &kind)?;
+                            }
+                            Some(_) | None => {
+                                // Finished lexing.
+                            }
                         }
                     }
                 }
-            }
-            Some('e') | Some('E') => {
-                kind = NumericKind::Rational;
-                cursor.next_char()?.expect("e or E character vanished"); // Consume the ExponentIndicator.
-                buf.push('E');
-                take_signed_integer(&mut buf, cursor, &kind)?;
-            }
-            Some(_) | None => {
-                // Indicates lexing finished.
+                Some('e') | Some('E') => {
+                    kind = NumericKind::Rational;
+                    cursor.next_char()?.expect("e or E character vanished"); // Consume the ExponentIndicator.
+                    buf.push('E');
+                    take_signed_integer(&mut buf, cursor, &kind)?;
+                }
+                Some(_) | None => {
+                    // Indicates lexing finished.
+                }
             }
         }
 
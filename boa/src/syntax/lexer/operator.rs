@@ -19,7 +19,6 @@ macro_rules! vop {
             None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some('=') => {
                 $cursor.next_char()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             }
             Some(_) => $op,
@@ -30,12 +29,10 @@ macro_rules! vop {
             None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some('=') => {
                 $cursor.next_char()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             },
             $($case => {
                 $cursor.next_char()?.expect("Token vanished");
-                $cursor.next_column();
                 $block
             })+,
             _ => $op,
@@ -45,7 +42,6 @@ macro_rules! vop {
         match $cursor.peek().ok_or_else(|| Error::syntax("could not preview next value", $cursor.pos()))? {
             $($case => {
                 $cursor.next_char()?;
-                $cursor.next_column();
                 $block
             })+,
             _ => $op
@@ -118,11 +114,34 @@ impl<R> Tokenizer<R> for Operator {
                 Ok(Punctuator::Mod)
             ),
             '|' => op!(cursor, start_pos, Ok(Punctuator::AssignOr), Ok(Punctuator::Or), {
-                Some('|') => Ok(Punctuator::BoolOr)
+                Some('|') => vop!(cursor, Ok(Punctuator::AssignBoolOr), Ok(Punctuator::BoolOr))
             }),
             '&' => op!(cursor, start_pos, Ok(Punctuator::AssignAnd), Ok(Punctuator::And), {
-                Some('&') => Ok(Punctuator::BoolAnd)
+                Some('&') => vop!(cursor, Ok(Punctuator::AssignBoolAnd), Ok(Punctuator::BoolAnd))
             }),
+            '?' => match cursor.peek()? {
+                Some('?') => {
+                    let _ = cursor.next_char()?.expect("? vanished");
+                    op!(
+                        cursor,
+                        start_pos,
+                        Ok(Punctuator::AssignCoalesce),
+                        Ok(Punctuator::Coalesce)
+                    )
+                }
+                // `?.` followed by a digit is a conditional operator and a number, as in `a?.5:1`.
+                Some('.') if !matches!(cursor.peek_second()?, Some('0'..='9')) => {
+                    let _ = cursor.next_char()?.expect(". vanished");
+                    Ok(Token::new(
+                        Punctuator::Optional.into(),
+                        Span::new(start_pos, cursor.pos()),
+                    ))
+                }
+                _ => Ok(Token::new(
+                    Punctuator::Question.into(),
+                    Span::new(start_pos, cursor.pos()),
+                )),
+            },
             '^' => op!(
                 cursor,
                 start_pos,
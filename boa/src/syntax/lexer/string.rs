@@ -9,8 +9,6 @@ use crate::{
     },
 };
 use std::{
-    char::{decode_utf16, from_u32},
-    convert::TryFrom,
     io::{self, ErrorKind, Read},
     str,
 };
@@ -41,6 +39,111 @@ impl StringLiteral {
 
         Self { terminator }
     }
+
+    /// Lexes an escape sequence or a line continuation, the `\\` being already consumed.
+    ///
+    /// Returns the code point of the escape sequence, which is a single UTF-16 code unit for a
+    /// `\uXXXX` escape and can then be an unpaired surrogate. Returns `None` for a line
+    /// continuation, which doesn't add any character.
+    pub(super) fn take_escape_sequence_or_line_continuation<R>(
+        cursor: &mut Cursor<R>,
+        next_chr_start: Position,
+    ) -> Result<Option<u32>, Error>
+    where
+        R: Read,
+    {
+        let _timer = BoaProfiler::global().start_event("StringLiteral - escape sequence", "Lexing");
+
+        let escape = cursor.next_char()?.ok_or_else(|| {
+            Error::from(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "unterminated escape sequence in string literal",
+            ))
+        })?;
+        if escape != '\n' {
+            let escaped_ch = match escape {
+                'n' => u32::from('\n'),
+                'r' => u32::from('\r'),
+                't' => u32::from('\t'),
+                'b' => 0x08,
+                'f' => 0x0c,
+                '0' => 0,
+                'x' => {
+                    let mut nums = [0u8; 2];
+                    cursor.fill_bytes(&mut nums)?;
+                    str::from_utf8(&nums)
+                        .ok()
+                        .and_then(|nums| u32::from_str_radix(nums, 16).ok())
+                        .ok_or_else(|| {
+                            Error::syntax("malformed hexadecimal escape sequence", cursor.pos())
+                        })?
+                }
+                'u' => {
+                    // Support \u{X..X} (Unicode Codepoint)
+                    if cursor.next_is('{')? {
+                        // The biggest code point is 0x10FFFF
+                        // TODO: use bytes for a bit better performance (using stack)
+                        let mut code_point = String::with_capacity(6);
+                        cursor.take_until('}', &mut code_point)?; // Consumes the '}'.
+
+                        // We know this is a single unicode codepoint, convert to u32
+                        let as_num = u32::from_str_radix(&code_point, 16).map_err(|_| {
+                            Error::syntax(
+                                "malformed Unicode character escape sequence",
+                                cursor.pos(),
+                            )
+                        })?;
+                        if as_num > 0x10_FFFF {
+                            return Err(Error::syntax("Unicode codepoint must not be greater than 0x10FFFF in escape sequence", cursor.pos()));
+                        }
+                        as_num
+                    } else {
+                        // Collect the code unit after \u e.g \uD83D will give "D83D". A surrogate
+                        // pair is written as two escapes, each giving one of its code units.
+                        let mut code_unit = [0u8; 4];
+                        cursor.fill_bytes(&mut code_unit)?;
+
+                        str::from_utf8(&code_unit)
+                            .ok()
+                            .and_then(|code_unit| u16::from_str_radix(code_unit, 16).ok())
+                            .map(u32::from)
+                            .ok_or_else(|| {
+                                Error::syntax(
+                                    "malformed Unicode character escape sequence",
+                                    cursor.pos(),
+                                )
+                            })?
+                    }
+                }
+                '\'' | '"' | '\\' | '`' | '$' => u32::from(escape),
+                ch => {
+                    let details = format!(
+                        "invalid escape sequence `{}` at line {}, column {}",
+                        next_chr_start.line_number(),
+                        next_chr_start.column_number(),
+                        ch
+                    );
+                    return Err(Error::syntax(details, cursor.pos()));
+                }
+            };
+            Ok(Some(escaped_ch))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Pushes the UTF-16 encoding of a code point to a buffer.
+///
+/// The code point can be a surrogate, which is pushed as is.
+pub(super) fn push_code_point(buf: &mut Vec<u16>, code_point: u32) {
+    if code_point < 0x1_0000 {
+        buf.push(code_point as u16);
+    } else {
+        let code_point = code_point - 0x1_0000;
+        buf.push(0xD800 + (code_point >> 10) as u16);
+        buf.push(0xDC00 + (code_point & 0x3FF) as u16);
+    }
 }
 
 /// Terminator for the string.
@@ -57,7 +160,7 @@ impl<R> Tokenizer<R> for StringLiteral {
     {
         let _timer = BoaProfiler::global().start_event("StringLiteral", "Lexing");
 
-        let mut buf = String::new();
+        let mut buf = Vec::new();
         loop {
             let next_chr_start = cursor.pos();
             let next_chr = cursor.next_char()?.ok_or_else(|| {
@@ -75,131 +178,13 @@ impl<R> Tokenizer<R> for StringLiteral {
                     break;
                 }
                 '\\' => {
-                    let _timer = BoaProfiler::global()
-                        .start_event("StringLiteral - escape sequence", "Lexing");
-
-                    let escape = cursor.next_char()?.ok_or_else(|| {
-                        Error::from(io::Error::new(
-                            ErrorKind::UnexpectedEof,
-                            "unterminated escape sequence in string literal",
-                        ))
-                    })?;
-                    if escape != '\n' {
-                        let escaped_ch = match escape {
-                            'n' => '\n',
-                            'r' => '\r',
-                            't' => '\t',
-                            'b' => '\x08',
-                            'f' => '\x0c',
-                            '0' => '\0',
-                            'x' => {
-                                let mut nums = [0u8; 2];
-                                cursor.fill_bytes(&mut nums)?;
-                                let nums = str::from_utf8(&nums).expect("non-UTF-8 bytes found");
-
-                                let as_num = match u64::from_str_radix(&nums, 16) {
-                                    Ok(v) => v,
-                                    Err(_) => 0,
-                                };
-                                match from_u32(as_num as u32) {
-                                    Some(v) => v,
-                                    None => {
-                                        return Err(Error::syntax(
-                                            format!(
-                                                "{}: {} is not a valid Unicode scalar value",
-                                                cursor.pos(),
-                                                as_num
-                                            ),
-                                            cursor.pos(),
-                                        ))
-                                    }
-                                }
-                            }
-                            'u' => {
-                                // There are 2 types of codepoints. Surragate codepoints and
-                                // unicode codepoints. UTF-16 could be surrogate codepoints,
-                                // "\uXXXX\uXXXX" which make up a single unicode codepoint. We will
-                                //  need to loop to make sure we catch all UTF-16 codepoints
-
-                                // Support \u{X..X} (Unicode Codepoint)
-                                if cursor.next_is('{')? {
-                                    cursor.next_char()?.expect("{ character vanished"); // Consume the '{'.
-
-                                    // The biggest code point is 0x10FFFF
-                                    // TODO: use bytes for a bit better performance (using stack)
-                                    let mut code_point = String::with_capacity(6);
-                                    cursor.take_until('}', &mut code_point)?;
-
-                                    cursor.next_char()?.expect("} character vanished"); // Consume the '}'.
-
-                                    // We know this is a single unicode codepoint, convert to u32
-                                    let as_num =
-                                        u32::from_str_radix(&code_point, 16).map_err(|_| {
-                                            Error::syntax(
-                                                "malformed Unicode character escape sequence",
-                                                cursor.pos(),
-                                            )
-                                        })?;
-                                    if as_num > 0x10_FFFF {
-                                        return Err(Error::syntax("Unicode codepoint must not be greater than 0x10FFFF in escape sequence", cursor.pos()));
-                                    }
-                                    char::try_from(as_num).map_err(|_| {
-                                        Error::syntax(
-                                            "invalid Unicode escape sequence",
-                                            cursor.pos(),
-                                        )
-                                    })?
-                                } else {
-                                    let mut codepoints: Vec<u16> = vec![];
-                                    loop {
-                                        // Collect each character after \u e.g \uD83D will give "D83D"
-                                        let mut code_point = [0u8; 4];
-                                        cursor.fill_bytes(&mut code_point)?;
-
-                                        // Convert to u16
-                                        let as_num = match u16::from_str_radix(
-                                            str::from_utf8(&code_point)
-                                                .expect("the cursor returned invalid UTF-8"),
-                                            16,
-                                        ) {
-                                            Ok(v) => v,
-                                            Err(_) => 0,
-                                        };
-
-                                        codepoints.push(as_num);
-
-                                        // Check for another UTF-16 codepoint
-                                        if cursor.next_is('\\')? && cursor.next_is('u')? {
-                                            continue;
-                                        }
-                                        break;
-                                    }
-
-                                    // codepoints length should either be 1 (unicode codepoint) or
-                                    // 2 (surrogate codepoint). Rust's decode_utf16 will deal with
-                                    // it regardless
-                                    // TODO: do not panic with invalid code points.
-                                    decode_utf16(codepoints.iter().copied())
-                                        .next()
-                                        .expect("Could not get next codepoint")
-                                        .expect("Could not get next codepoint")
-                                }
-                            }
-                            '\'' | '"' | '\\' => escape,
-                            ch => {
-                                let details = format!(
-                                    "invalid escape sequence `{}` at line {}, column {}",
-                                    next_chr_start.line_number(),
-                                    next_chr_start.column_number(),
-                                    ch
-                                );
-                                return Err(Error::syntax(details, cursor.pos()));
-                            }
-                        };
-                        buf.push(escaped_ch);
+                    if let Some(escaped_ch) =
+                        Self::take_escape_sequence_or_line_continuation(cursor, next_chr_start)?
+                    {
+                        push_code_point(&mut buf, escaped_ch);
                     }
                 }
-                next_ch => buf.push(next_ch),
+                next_ch => push_code_point(&mut buf, u32::from(next_ch)),
             }
         }
 
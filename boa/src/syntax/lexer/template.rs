@@ -5,14 +5,91 @@ use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{Position, Span},
-        lexer::{Token, TokenKind},
+        lexer::{
+            string::{push_code_point, StringLiteral},
+            Token, TokenKind,
+        },
     },
 };
 use std::io::{self, ErrorKind, Read};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The raw text of a piece of a template literal, between its delimiters.
+///
+/// The cooked value, with its escape sequences interpreted, is only computed when needed, since
+/// a tagged template allows invalid escape sequences.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-tv-and-trv
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug)]
+pub struct TemplateString {
+    /// The start position of the template string, used for error reporting.
+    start_pos: Position,
+    raw: Box<str>,
+}
+
+impl TemplateString {
+    /// Creates a new template string from its raw text.
+    pub fn new<R>(raw: R, start_pos: Position) -> Self
+    where
+        R: Into<Box<str>>,
+    {
+        Self {
+            start_pos,
+            raw: raw.into(),
+        }
+    }
+
+    /// Gets the raw text of the template string.
+    ///
+    /// Line terminators are normalized to `\n`, but escape sequences are kept as written.
+    pub fn as_raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Creates the cooked value of the template string, interpreting its escape sequences.
+    ///
+    /// Unpaired surrogates written as escape sequences are replaced by `U+FFFD`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-tv-and-trv
+    pub fn to_owned_cooked(&self) -> Result<Box<str>, Error> {
+        let mut cursor = Cursor::new(self.raw.as_bytes());
+        let mut buf = Vec::with_capacity(self.raw.len());
+        while let Some(ch) = cursor.next_char()? {
+            if ch == '\\' {
+                let escaped_ch = StringLiteral::take_escape_sequence_or_line_continuation(
+                    &mut cursor,
+                    self.start_pos,
+                )
+                .map_err(|_| {
+                    Error::syntax(
+                        "invalid escape sequence in template literal",
+                        self.start_pos,
+                    )
+                })?;
+                if let Some(escaped_ch) = escaped_ch {
+                    push_code_point(&mut buf, escaped_ch);
+                }
+            } else {
+                push_code_point(&mut buf, u32::from(ch));
+            }
+        }
+        Ok(String::from_utf16_lossy(&buf).into())
+    }
+}
+
 /// Template literal lexing.
 ///
-/// Expects: Initial ` to already be consumed by cursor.
+/// Expects: Initial ` to already be consumed by cursor, or the `}` closing a substitution when
+/// lexing the rest of a template.
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
@@ -32,21 +109,48 @@ impl<R> Tokenizer<R> for TemplateLiteral {
 
         let mut buf = String::new();
         loop {
-            match cursor.next_char()? {
-                None => {
-                    return Err(Error::from(io::Error::new(
-                        ErrorKind::UnexpectedEof,
-                        "Unterminated template literal",
-                    )));
+            let next_chr = cursor.next_char()?.ok_or_else(|| {
+                Error::from(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "Unterminated template literal",
+                ))
+            })?;
+            match next_chr {
+                // Template literal finished.
+                '`' => {
+                    let template_string = TemplateString::new(buf, start_pos);
+                    return Ok(Token::new(
+                        TokenKind::template_no_substitution(template_string),
+                        Span::new(start_pos, cursor.pos()),
+                    ));
+                }
+                // A substitution starts.
+                '$' if cursor.next_is('{')? => {
+                    let template_string = TemplateString::new(buf, start_pos);
+                    return Ok(Token::new(
+                        TokenKind::template_middle(template_string),
+                        Span::new(start_pos, cursor.pos()),
+                    ));
                 }
-                Some('`') => break,                 // Template literal finished.
-                Some(next_ch) => buf.push(next_ch), // TODO when there is an expression inside the literal
+                // The escaped character is kept in the raw text, and can't end the template.
+                '\\' => {
+                    buf.push('\\');
+                    match cursor.next_char()? {
+                        Some('\r') => {
+                            cursor.next_is('\n')?;
+                            buf.push('\n');
+                        }
+                        Some(escape) => buf.push(escape),
+                        None => {}
+                    }
+                }
+                // Line terminators are normalized to `\n`.
+                '\r' => {
+                    cursor.next_is('\n')?;
+                    buf.push('\n');
+                }
+                next_ch => buf.push(next_ch),
             }
         }
-
-        Ok(Token::new(
-            TokenKind::template_literal(buf),
-            Span::new(start_pos, cursor.pos()),
-        ))
     }
 }
@@ -74,10 +74,57 @@ fn check_template_literal_simple() {
 
     assert_eq!(
         lexer.next().unwrap().unwrap().kind(),
-        &TokenKind::template_literal("I'm a template literal")
+        &TokenKind::template_no_substitution(TemplateString::new(
+            "I'm a template literal",
+            Position::new(1, 1)
+        ))
     );
 }
 
+#[test]
+fn check_template_literal_substitution() {
+    let s = "`a\\n${b}c`";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let token = lexer.next().unwrap().unwrap();
+    if let TokenKind::TemplateMiddle(template_string) = token.kind() {
+        assert_eq!(template_string.as_raw(), "a\\n");
+        assert_eq!(template_string.to_owned_cooked().unwrap().as_ref(), "a\n");
+    } else {
+        panic!("expected a template middle, got {:?}", token);
+    }
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::identifier("b")
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::Punctuator(Punctuator::CloseBlock)
+    );
+
+    let token = lexer.lex_template(Position::new(1, 9)).unwrap();
+    if let TokenKind::TemplateNoSubstitution(template_string) = token.kind() {
+        assert_eq!(template_string.as_raw(), "c");
+    } else {
+        panic!("expected a template tail, got {:?}", token);
+    }
+}
+
+#[test]
+fn check_template_literal_invalid_escape() {
+    let s = "`\\unicode`";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    if let TokenKind::TemplateNoSubstitution(template_string) =
+        lexer.next().unwrap().unwrap().kind()
+    {
+        assert_eq!(template_string.as_raw(), "\\unicode");
+        assert!(template_string.to_owned_cooked().is_err());
+    } else {
+        panic!("expected a template literal");
+    }
+}
+
 #[test]
 fn check_template_literal_unterminated() {
     let s = "`I'm a template";
@@ -93,7 +140,8 @@ fn check_punctuators() {
     // https://tc39.es/ecma262/#sec-punctuators
     let s = "{ ( ) [ ] . ... ; , < > <= >= == != === !== \
              + - * % -- << >> >>> & | ^ ! ~ && || ? : \
-             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= =>";
+             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => \
+             ?? ?. &&= ||= ??=";
     let mut lexer = Lexer::new(s.as_bytes());
 
     let expected = [
@@ -146,6 +194,31 @@ fn check_punctuators() {
         TokenKind::Punctuator(Punctuator::AssignOr),
         TokenKind::Punctuator(Punctuator::AssignXor),
         TokenKind::Punctuator(Punctuator::Arrow),
+        TokenKind::Punctuator(Punctuator::Coalesce),
+        TokenKind::Punctuator(Punctuator::Optional),
+        TokenKind::Punctuator(Punctuator::AssignBoolAnd),
+        TokenKind::Punctuator(Punctuator::AssignBoolOr),
+        TokenKind::Punctuator(Punctuator::AssignCoalesce),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn optional_chaining_before_digit() {
+    // `?.` followed by a decimal digit is a `?` and a number.
+    let s = "a?.5:1 a?.b";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Question),
+        TokenKind::numeric_literal(0.5),
+        TokenKind::Punctuator(Punctuator::Colon),
+        TokenKind::numeric_literal(1),
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Optional),
+        TokenKind::identifier("b"),
     ];
 
     expect_tokens(&mut lexer, &expected);
@@ -258,7 +331,7 @@ fn check_positions() {
 
 #[test]
 fn check_positions_codepoint() {
-    let s = r#"console.log("hello world\u{{2764}}"); // Test"#;
+    let s = r#"console.log("hello world\u{2764}"); // Test"#;
     // --------123456789
     let mut lexer = Lexer::new(s.as_bytes());
 
@@ -296,6 +369,27 @@ fn check_positions_codepoint() {
     );
 }
 
+#[test]
+fn check_positions_multi_char_operator() {
+    let s = "a === b => c";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 1), (1, 2)));
+
+    // The strict equality token is three columns wide.
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 3), (1, 6)));
+
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 7), (1, 8)));
+
+    // The arrow token is two columns wide.
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 9), (1, 11)));
+
+    assert_eq!(
+        lexer.next().unwrap().unwrap().span(),
+        span((1, 12), (1, 13))
+    );
+}
+
 #[test]
 fn check_line_numbers() {
     let s = "x\ny\n";
@@ -402,8 +496,8 @@ fn implicit_octal_edge_case() {
 
     let expected = [
         TokenKind::numeric_literal(36),
-        TokenKind::Punctuator(Punctuator::Dot),
-        TokenKind::numeric_literal(5),
+        // Legacy octal literals have no decimal part, so `.5` is a number on its own.
+        TokenKind::numeric_literal(0.5),
         TokenKind::numeric_literal(94.5),
     ];
 
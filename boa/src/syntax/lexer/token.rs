@@ -5,11 +5,12 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-tokens
 
-use super::regex::RegExpFlags;
+use super::{regex::RegExpFlags, template::TemplateString};
 
 use crate::{
     builtins::BigInt,
     syntax::ast::{Keyword, Punctuator, Span},
+    value::RcString,
 };
 
 use std::fmt::{self, Debug, Display, Formatter};
@@ -123,9 +124,13 @@ pub enum TokenKind {
     Punctuator(Punctuator),
 
     /// A string literal.
-    StringLiteral(Box<str>),
+    StringLiteral(RcString),
 
-    TemplateLiteral(Box<str>),
+    /// A template literal without substitutions, or the tail of a template literal.
+    TemplateNoSubstitution(TemplateString),
+
+    /// The head or a middle part of a template literal, followed by a substitution.
+    TemplateMiddle(TemplateString),
 
     /// A regular expression, consisting of body and flags.
     RegularExpressionLiteral(Box<str>, RegExpFlags),
@@ -201,17 +206,19 @@ impl TokenKind {
     /// Creates a `StringLiteral` token type.
     pub fn string_literal<S>(lit: S) -> Self
     where
-        S: Into<Box<str>>,
+        S: Into<RcString>,
     {
         Self::StringLiteral(lit.into())
     }
 
-    /// Creates a `TemplateLiteral` token type.
-    pub fn template_literal<S>(lit: S) -> Self
-    where
-        S: Into<Box<str>>,
-    {
-        Self::TemplateLiteral(lit.into())
+    /// Creates a `TemplateNoSubstitution` token type.
+    pub fn template_no_substitution(template_string: TemplateString) -> Self {
+        Self::TemplateNoSubstitution(template_string)
+    }
+
+    /// Creates a `TemplateMiddle` token type.
+    pub fn template_middle(template_string: TemplateString) -> Self {
+        Self::TemplateMiddle(template_string)
     }
 
     /// Creates a `RegularExpressionLiteral` token kind.
@@ -247,7 +254,9 @@ impl Display for TokenKind {
             Self::NumericLiteral(Numeric::BigInt(ref num)) => write!(f, "{}n", num),
             Self::Punctuator(ref punc) => write!(f, "{}", punc),
             Self::StringLiteral(ref lit) => write!(f, "{}", lit),
-            Self::TemplateLiteral(ref lit) => write!(f, "{}", lit),
+            Self::TemplateNoSubstitution(ref ts) | Self::TemplateMiddle(ref ts) => {
+                write!(f, "{}", ts.as_raw())
+            }
             Self::RegularExpressionLiteral(ref body, ref flags) => write!(f, "/{}/{}", body, flags),
             Self::LineTerminator => write!(f, "line terminator"),
             Self::Comment => write!(f, "comment"),
@@ -0,0 +1,221 @@
+//! Class definition parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+//! [spec]: https://tc39.es/ecma262/#sec-class-definitions
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{self, ClassElement, FunctionExpr, MethodDefinitionKind, Node},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::{is_async_method, LeftHandSideExpression, MethodDefinition, PropertyName},
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Class tail parsing.
+///
+/// Parses everything after the name of the class: the optional heritage and the class body.
+/// The output is the heritage, the constructor and the rest of the class elements.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ClassTail {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassTail {
+    /// Creates a new `ClassTail` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassTail
+where
+    R: Read,
+{
+    type Output = (Option<Node>, Option<FunctionExpr>, Vec<ClassElement>);
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassTail", "Parsing");
+
+        let super_ref = if cursor.next_if(Keyword::Extends)?.is_some() {
+            Some(LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?)
+        } else {
+            None
+        };
+
+        cursor.expect(Punctuator::OpenBlock, "class tail")?;
+
+        let (constructor, elements) =
+            ClassBody::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok((super_ref, constructor, elements))
+    }
+}
+
+/// Class body parsing.
+///
+/// Parses the class elements up to and including the closing brace.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassBody
+#[derive(Debug, Clone, Copy)]
+struct ClassBody {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassBody {
+    /// Creates a new `ClassBody` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassBody
+where
+    R: Read,
+{
+    type Output = (Option<FunctionExpr>, Vec<ClassElement>);
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassBody", "Parsing");
+        let mut constructor = None;
+        let mut elements = Vec::new();
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+            if cursor.next_if(Punctuator::Semicolon)?.is_some() {
+                continue;
+            }
+
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let element =
+                ClassElementParser::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+            if !element.is_static() && element.name().literal() == Some("constructor") {
+                if element.kind() != MethodDefinitionKind::Ordinary {
+                    return Err(ParseError::general(
+                        "class constructor may not be an accessor",
+                        position,
+                    ));
+                }
+                if constructor.is_some() {
+                    return Err(ParseError::general(
+                        "a class may only have one constructor",
+                        position,
+                    ));
+                }
+                constructor = Some(element.method().clone());
+            } else {
+                elements.push(element);
+            }
+        }
+
+        Ok((constructor, elements))
+    }
+}
+
+/// Class element parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+#[derive(Debug, Clone, Copy)]
+struct ClassElementParser {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassElementParser {
+    /// Creates a new `ClassElementParser` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassElementParser
+where
+    R: Read,
+{
+    type Output = ClassElement;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassElement", "Parsing");
+
+        let mut is_static = false;
+        if cursor.peek(0)?.map(|tok| tok.kind()) == Some(&TokenKind::identifier("static"))
+            && cursor.peek(1)?.map(|tok| tok.kind())
+                != Some(&TokenKind::Punctuator(Punctuator::OpenParen))
+        {
+            is_static = true;
+            cursor.next()?.expect("static token vanished");
+        }
+
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        let name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let is_async = name.literal() == Some("async") && is_async_method(cursor)?;
+        if !is_async
+            && cursor.peek(0)?.map(|tok| tok.kind())
+                != Some(&TokenKind::Punctuator(Punctuator::OpenParen))
+            && !matches!(name.literal(), Some("get") | Some("set") | Some("*"))
+        {
+            return Err(ParseError::unexpected(token, "class element"));
+        }
+
+        let start = token.span().start();
+        match MethodDefinition::new(self.allow_yield, self.allow_await, name, start)
+            .parse(cursor)?
+        {
+            node::PropertyDefinition::MethodDefinition(kind, ref name, ref method) => Ok(
+                ClassElement::new(is_static, kind, name.clone(), method.clone()),
+            ),
+            _ => unreachable!("a method definition always returns a method"),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            Class, ClassDecl, ClassElement, ClassExpr, FormalParameter, FunctionExpr,
+            GetConstField, GetSuperField, Identifier, LetDecl, LetDeclList, MethodDefinitionKind,
+            Node, PropertyName, Return, SuperCall,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks a class declaration with a constructor and a method.
+#[test]
+fn check_class_declaration() {
+    check_parser(
+        "class A {
+            constructor(a) {}
+            foo() { return 1; }
+        }",
+        vec![ClassDecl::from(Class::new(
+            Box::from("A"),
+            None,
+            FunctionExpr::new(None, vec![FormalParameter::new("a", None, false)], vec![]),
+            vec![ClassElement::new(
+                false,
+                MethodDefinitionKind::Ordinary,
+                "foo",
+                FunctionExpr::new(None, vec![], vec![Return::new(Const::from(1), None).into()]),
+            )],
+        ))
+        .into()],
+    );
+}
+
+/// Checks methods with computed names.
+#[test]
+fn check_class_computed_method_names() {
+    check_parser(
+        "class A {
+            [key]() {}
+            static *[Symbol.iterator]() {}
+            get ['value']() {}
+        }",
+        vec![ClassDecl::from(Class::new(
+            Box::from("A"),
+            None,
+            None,
+            vec![
+                ClassElement::new(
+                    false,
+                    MethodDefinitionKind::Ordinary,
+                    PropertyName::Computed(Identifier::from("key").into()),
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+                ClassElement::new(
+                    true,
+                    MethodDefinitionKind::Generator,
+                    PropertyName::Computed(
+                        GetConstField::new(Identifier::from("Symbol"), "iterator").into(),
+                    ),
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+                ClassElement::new(
+                    false,
+                    MethodDefinitionKind::Get,
+                    PropertyName::Computed(Const::from("value").into()),
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+            ],
+        ))
+        .into()],
+    );
+}
+
+/// Checks static methods and accessors inside a class body.
+#[test]
+fn check_class_static_and_accessors() {
+    check_parser(
+        "class A {
+            static create() {}
+            get value() {}
+            set value(v) {};
+        }",
+        vec![ClassDecl::from(Class::new(
+            Box::from("A"),
+            None,
+            None,
+            vec![
+                ClassElement::new(
+                    true,
+                    MethodDefinitionKind::Ordinary,
+                    "create",
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+                ClassElement::new(
+                    false,
+                    MethodDefinitionKind::Get,
+                    "value",
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+                ClassElement::new(
+                    false,
+                    MethodDefinitionKind::Set,
+                    "value",
+                    FunctionExpr::new(None, vec![FormalParameter::new("v", None, false)], vec![]),
+                ),
+            ],
+        ))
+        .into()],
+    );
+}
+
+/// Checks the `extends` clause and the `super` keyword.
+#[test]
+fn check_class_extends_super() {
+    check_parser(
+        "class B extends A {
+            constructor() { super(); }
+            foo() { return super.foo; }
+        }",
+        vec![ClassDecl::from(Class::new(
+            Box::from("B"),
+            Node::from(Identifier::from("A")),
+            FunctionExpr::new(None, vec![], vec![SuperCall::new(vec![]).into()]),
+            vec![ClassElement::new(
+                false,
+                MethodDefinitionKind::Ordinary,
+                "foo",
+                FunctionExpr::new(
+                    None,
+                    vec![],
+                    vec![Return::new(GetSuperField::new(Const::from("foo")), None).into()],
+                ),
+            )],
+        ))
+        .into()],
+    );
+}
+
+/// Checks anonymous class expressions.
+#[test]
+fn check_class_expression() {
+    check_parser(
+        "let a = class extends b.c {};",
+        vec![LetDeclList::from(vec![LetDecl::new::<_, Option<Node>>(
+            "a",
+            Some(
+                ClassExpr::from(Class::new(
+                    None,
+                    Node::from(GetConstField::new(Identifier::from("b"), "c")),
+                    None,
+                    vec![],
+                ))
+                .into(),
+            ),
+        )])
+        .into()],
+    );
+}
+
+/// Checks that a class can only have one constructor.
+#[test]
+fn check_duplicate_constructor() {
+    check_invalid("class A { constructor() {} constructor() {} }");
+}
+
+/// Checks that a constructor can not be an accessor.
+#[test]
+fn check_accessor_constructor() {
+    check_invalid("class A { get constructor() {} }");
+}
@@ -82,6 +82,19 @@ where
         self.lexer.lex_slash_token(start).map_err(|e| e.into())
     }
 
+    /// Lexes the rest of a template literal assuming that the `}` closing a substitution has
+    /// already been consumed.
+    pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
+        let _timer = BoaProfiler::global().start_event("cursor::lex_template()", "Parsing");
+        if self.read_index != self.write_index {
+            return Err(ParseError::general(
+                "unexpected token after template substitution",
+                start,
+            ));
+        }
+        self.lexer.lex_template(start).map_err(|e| e.into())
+    }
+
     #[inline]
     pub(super) fn strict_mode(&self) -> bool {
         self.lexer.strict_mode()
@@ -92,6 +105,12 @@ where
         self.lexer.set_strict_mode(strict_mode)
     }
 
+    /// Gets the source code between two positions.
+    #[inline]
+    pub(super) fn source_text(&self, start: Position, end: Position) -> Option<&str> {
+        self.lexer.source_text(start, end)
+    }
+
     /// Fills the peeking buffer with the next token.
     ///
     /// It will not fill two line terminators one after the other.
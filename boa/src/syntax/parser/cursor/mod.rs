@@ -2,9 +2,12 @@
 mod buffered_lexer;
 
 use super::ParseError;
-use crate::syntax::{
-    ast::Punctuator,
-    lexer::{InputElement, Lexer, Position, Token, TokenKind},
+use crate::{
+    syntax::{
+        ast::{Punctuator, Span},
+        lexer::{InputElement, Lexer, Position, Token, TokenKind},
+    },
+    value::RcString,
 };
 use buffered_lexer::BufferedLexer;
 use std::io::Read;
@@ -22,6 +25,8 @@ pub(super) enum SemicolonResult<'s> {
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    /// The end position of the last token that was consumed.
+    last_end: Position,
 }
 
 impl<R> Cursor<R>
@@ -33,6 +38,7 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            last_end: Position::new(1, 1),
         }
     }
 
@@ -43,12 +49,25 @@ where
 
     #[inline]
     pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.buffered_lexer.lex_regex(start)
+        let token = self.buffered_lexer.lex_regex(start)?;
+        self.last_end = token.span().end();
+        Ok(token)
+    }
+
+    #[inline]
+    pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
+        let token = self.buffered_lexer.lex_template(start)?;
+        self.last_end = token.span().end();
+        Ok(token)
     }
 
     #[inline]
     pub(super) fn next(&mut self) -> Result<Option<Token>, ParseError> {
-        self.buffered_lexer.next(true)
+        let token = self.buffered_lexer.next(true)?;
+        if let Some(ref token) = token {
+            self.last_end = token.span().end();
+        }
+        Ok(token)
     }
 
     #[inline]
@@ -66,6 +85,30 @@ where
         self.buffered_lexer.set_strict_mode(strict_mode)
     }
 
+    /// Gets the source code from `start` to the end of the last consumed token, which is kept
+    /// by functions for `Function.prototype.toString()`.
+    #[inline]
+    pub(super) fn source_text(&self, start: Position) -> Option<RcString> {
+        self.buffered_lexer
+            .source_text(start, self.last_end)
+            .map(RcString::from)
+    }
+
+    /// Gets the start of the next token, or the end of the last consumed token if there are no
+    /// more tokens, which is where the node that is parsed next starts.
+    #[inline]
+    pub(super) fn next_start(&mut self) -> Result<Position, ParseError> {
+        let start = self.peek(0)?.map(|token| token.span().start());
+        Ok(start.unwrap_or(self.last_end))
+    }
+
+    /// Gets the span from `start` to the end of the last consumed token, which is the span of a
+    /// node that started at `start`.
+    #[inline]
+    pub(super) fn span_from(&self, start: Position) -> Span {
+        Span::new(start, self.last_end.max(start))
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     ///
     /// Note: it will consume the next token only if the next token is the expected type.
@@ -151,6 +194,32 @@ where
         }
     }
 
+    /// Peeks the next token (`skip_n == 0`) or the one after it (`skip_n == 1`), returning `None`
+    /// if it is preceded by a line terminator.
+    ///
+    /// This is needed to tell apart constructs like `async function`, where a line terminator
+    /// between the two tokens changes the meaning of the code.
+    #[inline]
+    pub(super) fn peek_no_lineterminator(
+        &mut self,
+        skip_n: usize,
+    ) -> Result<Option<&Token>, ParseError> {
+        debug_assert!(skip_n <= 1, "only the two next tokens can be peeked");
+
+        // The buffered lexer keeps the line terminator before the next token, which `peek` skips.
+        let leading_line_terminator = matches!(
+            self.buffered_lexer.peek(0, false)?,
+            Some(token) if token.kind() == &TokenKind::LineTerminator
+        );
+        let token = match (skip_n, leading_line_terminator) {
+            (0, true) => None,
+            (0, false) => self.buffered_lexer.peek(0, false)?,
+            (_, true) => self.buffered_lexer.peek(2, false)?,
+            (_, false) => self.buffered_lexer.peek(1, false)?,
+        };
+        Ok(token.filter(|token| token.kind() != &TokenKind::LineTerminator))
+    }
+
     /// Advance the cursor to the next token and retrieve it, only if it's of `kind` type.
     ///
     /// When the next token is a `kind` token, get the token, otherwise return `None`.
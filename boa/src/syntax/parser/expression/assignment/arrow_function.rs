@@ -72,6 +72,7 @@ where
         let _timer = BoaProfiler::global().start_event("ArrowFunction", "Parsing");
 
         let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = next_token.span().start();
         let params = if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind() {
             // CoverParenthesizedExpressionAndArrowParameterList
             cursor.expect(Punctuator::OpenParen, "arrow function")?;
@@ -89,25 +90,28 @@ where
         cursor.peek_expect_no_lineterminator(0)?;
 
         cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
-        let body = ConciseBody::new(self.allow_in).parse(cursor)?;
-        Ok(ArrowFunctionDecl::new(params, body))
+        let body = ConciseBody::new(self.allow_in, false).parse(cursor)?;
+        Ok(ArrowFunctionDecl::new(params, body).with_source(cursor.source_text(start)))
     }
 }
 
 /// <https://tc39.es/ecma262/#prod-ConciseBody>
 #[derive(Debug, Clone, Copy)]
-struct ConciseBody {
+pub(super) struct ConciseBody {
     allow_in: AllowIn,
+    allow_await: AllowAwait,
 }
 
 impl ConciseBody {
     /// Creates a new `ConcideBody` parser.
-    fn new<I>(allow_in: I) -> Self
+    pub(super) fn new<I, A>(allow_in: I, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
+        A: Into<AllowAwait>,
     {
         Self {
             allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
         }
     }
 }
@@ -122,12 +126,12 @@ where
         match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let _ = cursor.next();
-                let body = FunctionBody::new(false, false).parse(cursor)?;
+                let body = FunctionBody::new(false, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
             _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
+                ExpressionBody::new(self.allow_in, self.allow_await).parse(cursor)?,
                 None,
             )
             .into()])),
@@ -0,0 +1,123 @@
+//! Async arrow function parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+//! [spec]: https://tc39.es/ecma262/#sec-async-arrow-function-definitions
+
+use super::{arrow_function::ConciseBody, pattern};
+use crate::syntax::lexer::TokenKind;
+use crate::{
+    syntax::{
+        ast::{
+            node::{AsyncArrowFunctionDecl, Call, FormalParameter},
+            Position, Punctuator,
+        },
+        parser::{
+            error::{ErrorContext, ParseError},
+            statement::BindingIdentifier,
+            AllowIn, AllowYield, Cursor, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Async arrow function parsing, for functions with a single parameter without parentheses.
+///
+/// Async arrow functions with parenthesized parameters are first parsed as a call to `async`, and
+/// converted with [`parse_cover_call`].
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncArrowFunction
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AsyncArrowFunction {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+}
+
+impl AsyncArrowFunction {
+    /// Creates a new `AsyncArrowFunction` parser.
+    pub(super) fn new<I, Y>(allow_in: I, allow_yield: Y) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for AsyncArrowFunction
+where
+    R: Read,
+{
+    type Output = AsyncArrowFunctionDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AsyncArrowFunction", "Parsing");
+
+        let start = cursor
+            .expect(TokenKind::identifier("async"), "async arrow function")?
+            .span()
+            .start();
+        let param = BindingIdentifier::new(self.allow_yield, true)
+            .parse(cursor)
+            .context("async arrow function")?;
+
+        cursor.peek_expect_no_lineterminator(0)?;
+
+        cursor.expect(
+            TokenKind::Punctuator(Punctuator::Arrow),
+            "async arrow function",
+        )?;
+        let body = ConciseBody::new(self.allow_in, true).parse(cursor)?;
+        Ok(
+            AsyncArrowFunctionDecl::new(vec![FormalParameter::new(param, None, false)], body)
+                .with_source(cursor.source_text(start)),
+        )
+    }
+}
+
+/// Finishes parsing an async arrow function whose parameters were parsed as the arguments of a
+/// call to `async`.
+///
+/// The cursor must be right before the `=>` token.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-CoverCallExpressionAndAsyncArrowHead
+pub(super) fn parse_cover_call<R, I>(
+    call: &Call,
+    position: Position,
+    allow_in: I,
+    cursor: &mut Cursor<R>,
+) -> Result<AsyncArrowFunctionDecl, ParseError>
+where
+    R: Read,
+    I: Into<AllowIn>,
+{
+    let params = call
+        .args()
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| pattern::formal_parameter(arg, i + 1 == call.args().len(), position))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    cursor.expect(
+        TokenKind::Punctuator(Punctuator::Arrow),
+        "async arrow function",
+    )?;
+    let body = ConciseBody::new(allow_in, true).parse(cursor)?;
+    Ok(AsyncArrowFunctionDecl::new(params, body).with_source(cursor.source_text(position)))
+}
@@ -12,7 +12,7 @@ use crate::{
     syntax::{
         ast::{node::ConditionalOp, Node, Punctuator},
         parser::{
-            expression::{AssignmentExpression, LogicalORExpression},
+            expression::{AssignmentExpression, ShortCircuitExpression},
             AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser,
         },
     },
@@ -65,8 +65,8 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("ConditionalExpression", "Parsing");
 
-        // TODO: coalesce expression
-        let lhs = LogicalORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+        let start = cursor.next_start()?;
+        let lhs = ShortCircuitExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor)?;
 
         if let Some(tok) = cursor.peek(0)? {
@@ -80,7 +80,8 @@ where
                 let else_clause =
                     AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                         .parse(cursor)?;
-                return Ok(ConditionalOp::new(lhs, then_clause, else_clause).into());
+                let node = Node::from(ConditionalOp::new(lhs, then_clause, else_clause));
+                return Ok(node.with_default_span(cursor.span_from(start)));
             }
         }
 
@@ -65,6 +65,7 @@ where
             TokenKind::Keyword(Keyword::Delete)
                 | TokenKind::Keyword(Keyword::Void)
                 | TokenKind::Keyword(Keyword::TypeOf)
+                | TokenKind::Keyword(Keyword::Await)
                 | TokenKind::Punctuator(Punctuator::Add)
                 | TokenKind::Punctuator(Punctuator::Sub)
                 | TokenKind::Punctuator(Punctuator::Not)
@@ -88,11 +89,13 @@ where
             return UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor);
         }
 
+        let start = cursor.next_start()?;
         let lhs = UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
         if let Some(tok) = cursor.peek(0)? {
             if let TokenKind::Punctuator(Punctuator::Exp) = tok.kind() {
                 cursor.next()?.expect("** token vanished"); // Consume the token.
-                return Ok(BinOp::new(NumOp::Exp, lhs, self.parse(cursor)?).into());
+                let node = Node::from(BinOp::new(NumOp::Exp, lhs, self.parse(cursor)?));
+                return Ok(node.with_default_span(cursor.span_from(start)));
             }
         }
         Ok(lhs)
@@ -8,15 +8,21 @@
 //! [spec]: https://tc39.es/ecma262/#sec-assignment-operators
 
 mod arrow_function;
+mod async_arrow_function;
 mod conditional;
 mod exponentiation;
+mod pattern;
+mod yield_expression;
 
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
-use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
+use self::{
+    arrow_function::ArrowFunction, async_arrow_function::AsyncArrowFunction,
+    conditional::ConditionalExpression, yield_expression::YieldExpression,
+};
+use crate::syntax::lexer::{Error as LexError, InputElement, Token, TokenKind};
 use crate::{
     syntax::{
         ast::{
-            node::{Assign, BinOp, Node},
+            node::{ArrowFunctionDecl, Assign, BinOp, Identifier, Node, NodeKind},
             Keyword, Punctuator,
         },
         parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
@@ -24,6 +30,7 @@ use crate::{
     BoaProfiler,
 };
 pub(super) use exponentiation::ExponentiationExpression;
+pub(in crate::syntax::parser) use pattern::assignment_pattern;
 
 use std::io::Read;
 
@@ -32,9 +39,9 @@ use std::io::Read;
 /// This can be one of the following:
 ///
 ///  - [`ConditionalExpression`](../conditional_operator/struct.ConditionalExpression.html)
-///  - `YieldExpression`
+///  - [`YieldExpression`](./yield_expression/struct.YieldExpression.html)
 ///  - [`ArrowFunction`](../../function/arrow_function/struct.ArrowFunction.html)
-///  - `AsyncArrowFunction`
+///  - [`AsyncArrowFunction`](./async_arrow_function/struct.AsyncArrowFunction.html)
 ///  - [`LeftHandSideExpression`][lhs] `=` `AssignmentExpression`
 ///  - [`LeftHandSideExpression`][lhs] `AssignmentOperator` `AssignmentExpression`
 ///
@@ -81,9 +88,38 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("AssignmentExpression", "Parsing");
         cursor.set_goal(InputElement::Div);
+        let start = cursor.next_start()?;
+
+        // async a=>{}
+        let is_async = cursor.peek(0)?.map(Token::kind) == Some(&TokenKind::identifier("async"));
+        let next_kind = if is_async {
+            cursor
+                .peek_no_lineterminator(1)?
+                .map(|tok| tok.kind().clone())
+        } else {
+            None
+        };
+        if let Some(TokenKind::Identifier(_)) = next_kind {
+            if cursor.peek(2)?.map(Token::kind) == Some(&TokenKind::Punctuator(Punctuator::Arrow)) {
+                return AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                    .parse(cursor)
+                    .map(|node| Node::from(node).with_default_span(cursor.span_from(start)));
+            }
+        }
+        // async (a,b)=>{} is parsed as a call to `async` first.
+        let is_async_call = next_kind == Some(TokenKind::Punctuator(Punctuator::OpenParen));
+        let first_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let position = first_token.span().start();
+        // ([a, b]) => {} is parsed as a parenthesized expression first.
+        let is_paren = first_token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen);
 
         // Arrow function
         match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            // yield a
+            TokenKind::Keyword(Keyword::Yield) if self.allow_yield.0 => {
+                return YieldExpression::new(self.allow_in, self.allow_await).parse(cursor);
+            }
+
             // a=>{}
             TokenKind::Identifier(_)
             | TokenKind::Keyword(Keyword::Yield)
@@ -96,7 +132,7 @@ where
                             self.allow_await,
                         )
                         .parse(cursor)
-                        .map(Node::ArrowFunctionDecl);
+                        .map(|node| Node::from(node).with_default_span(cursor.span_from(start)));
                     }
                 }
             }
@@ -116,7 +152,9 @@ where
                                         self.allow_await,
                                     )
                                     .parse(cursor)
-                                    .map(Node::ArrowFunctionDecl);
+                                    .map(|node| {
+                                        Node::from(node).with_default_span(cursor.span_from(start))
+                                    });
                                 }
                             }
                         }
@@ -127,7 +165,9 @@ where
                                 self.allow_await,
                             )
                             .parse(cursor)
-                            .map(Node::ArrowFunctionDecl);
+                            .map(|node| {
+                                Node::from(node).with_default_span(cursor.span_from(start))
+                            });
                         }
                         TokenKind::Identifier(_) => {
                             if let Some(t) = cursor.peek(2)? {
@@ -140,7 +180,10 @@ where
                                             self.allow_await,
                                         )
                                         .parse(cursor)
-                                        .map(Node::ArrowFunctionDecl);
+                                        .map(|node| {
+                                            Node::from(node)
+                                                .with_default_span(cursor.span_from(start))
+                                        });
                                     }
                                     TokenKind::Punctuator(Punctuator::CloseParen) => {
                                         // Need to check if the token after the close paren is an arrow, if so then this is an ArrowFunction
@@ -154,7 +197,10 @@ where
                                                     self.allow_await,
                                                 )
                                                 .parse(cursor)
-                                                .map(Node::ArrowFunctionDecl);
+                                                .map(|node| {
+                                                    Node::from(node)
+                                                        .with_default_span(cursor.span_from(start))
+                                                });
                                             }
                                         }
                                     }
@@ -175,13 +221,48 @@ where
         let mut lhs = ConditionalExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor)?;
 
+        if is_async_call {
+            if let NodeKind::Call(ref call) = lhs.kind() {
+                if call.expr() == &Node::from(Identifier::from("async"))
+                    && cursor.peek_no_lineterminator(0)?.map(Token::kind)
+                        == Some(&TokenKind::Punctuator(Punctuator::Arrow))
+                {
+                    return async_arrow_function::parse_cover_call(
+                        call,
+                        position,
+                        self.allow_in,
+                        cursor,
+                    )
+                    .map(|node| Node::from(node).with_default_span(cursor.span_from(start)));
+                }
+            }
+        }
+
+        if is_paren
+            && cursor.peek_no_lineterminator(0)?.map(Token::kind)
+                == Some(&TokenKind::Punctuator(Punctuator::Arrow))
+        {
+            let params = pattern::arrow_parameters(&lhs, position)?;
+            cursor.expect(Punctuator::Arrow, "arrow function")?;
+            let body = arrow_function::ConciseBody::new(self.allow_in, false).parse(cursor)?;
+            let arrow =
+                ArrowFunctionDecl::new(params, body).with_source(cursor.source_text(position));
+            return Ok(Node::from(arrow).with_default_span(cursor.span_from(start)));
+        }
+
         // Review if we are trying to assign to an invalid left hand side expression.
         // TODO: can we avoid cloning?
         if let Some(tok) = cursor.peek(0)?.cloned() {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Assign) => {
                     cursor.next()?.expect("= token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
+                    if let NodeKind::ArrayDecl(_) | NodeKind::Object(_) = lhs.kind() {
+                        let mut pattern = Node::from(assignment_pattern(&lhs, tok.span().start())?);
+                        if let Some(span) = lhs.span() {
+                            pattern = pattern.with_default_span(span);
+                        }
+                        lhs = Assign::new(pattern, self.parse(cursor)?).into();
+                    } else if is_assignable(&lhs) {
                         lhs = Assign::new(lhs, self.parse(cursor)?).into();
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
@@ -208,7 +289,7 @@ where
             }
         }
 
-        Ok(lhs)
+        Ok(lhs.with_default_span(cursor.span_from(start)))
     }
 }
 
@@ -217,5 +298,8 @@ where
 /// [spec]: https://tc39.es/ecma262/#sec-assignment-operators-static-semantics-early-errors
 #[inline]
 pub(crate) fn is_assignable(node: &Node) -> bool {
-    !matches!(node, Node::Const(_) | Node::ArrayDecl(_))
+    !matches!(
+        node.kind(),
+        NodeKind::Const(_) | NodeKind::ArrayDecl(_) | NodeKind::Optional(_)
+    )
 }
@@ -0,0 +1,222 @@
+//! Destructuring assignment pattern parsing.
+//!
+//! Array and object literals are parsed as expressions first, and reinterpreted as patterns
+//! when they turn out to be the target of an assignment, or the parameters of an arrow
+//! function.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+//! [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment
+
+use crate::syntax::{
+    ast::{
+        node::{
+            ArrayPattern, Binding, FormalParameter, Identifier, Node, NodeKind, ObjectPattern,
+            Pattern, PatternElement, PropertyDefinition,
+        },
+        op::BinOp,
+        Const, Position,
+    },
+    parser::ParseError,
+};
+
+/// Reinterprets an array or object literal as an assignment pattern.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AssignmentPattern
+pub(in crate::syntax::parser) fn assignment_pattern(
+    node: &Node,
+    position: Position,
+) -> Result<Pattern, ParseError> {
+    cover_pattern(node, false, position)
+}
+
+/// Reinterprets the parenthesized expression before an `=>` token as the parameters of an
+/// arrow function.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-CoverParenthesizedExpressionAndArrowParameterList
+pub(super) fn arrow_parameters(
+    node: &Node,
+    position: Position,
+) -> Result<Vec<FormalParameter>, ParseError> {
+    let mut nodes = Vec::new();
+    let mut node = node;
+    while let NodeKind::BinOp(bin_op) = node.kind() {
+        if bin_op.op() != BinOp::Comma {
+            break;
+        }
+        nodes.push(bin_op.rhs());
+        node = bin_op.lhs();
+    }
+    nodes.push(node);
+
+    nodes
+        .iter()
+        .rev()
+        .map(|node| formal_parameter(node, false, position))
+        .collect()
+}
+
+/// Reinterprets an argument of a call as a formal parameter, the last one can be a rest
+/// parameter.
+pub(super) fn formal_parameter(
+    node: &Node,
+    is_last: bool,
+    position: Position,
+) -> Result<FormalParameter, ParseError> {
+    match node.kind() {
+        NodeKind::Spread(spread) if is_last => Ok(FormalParameter::new(
+            binding(spread.val(), position)?,
+            None,
+            true,
+        )),
+        NodeKind::Assign(assign) => Ok(FormalParameter::new(
+            binding(assign.lhs(), position)?,
+            Some(assign.rhs().clone()),
+            false,
+        )),
+        _ => Ok(FormalParameter::new(binding(node, position)?, None, false)),
+    }
+}
+
+/// Reinterprets a node as the target of a binding.
+fn binding(node: &Node, position: Position) -> Result<Binding, ParseError> {
+    match node.kind() {
+        NodeKind::Identifier(name) => Ok(name.clone().into()),
+        _ => cover_pattern(node, true, position).map(Binding::from),
+    }
+}
+
+/// Reinterprets a node as a pattern.
+///
+/// When `binding` is `true` the targets of the pattern must be identifiers or patterns,
+/// otherwise they can also be property accesses.
+fn cover_pattern(node: &Node, binding: bool, position: Position) -> Result<Pattern, ParseError> {
+    match node.kind() {
+        NodeKind::ArrayDecl(array) => {
+            let mut elements = Vec::with_capacity(array.as_ref().len());
+            let mut rest = None;
+            for (i, element) in array.as_ref().iter().enumerate() {
+                match element.kind() {
+                    // Elisions are parsed as `undefined`.
+                    NodeKind::Const(Const::Undefined) => elements.push(None),
+                    NodeKind::Spread(spread) if i + 1 == array.as_ref().len() => {
+                        rest = Some(cover_target(spread.val(), binding, position)?);
+                    }
+                    _ => elements.push(Some(cover_element(element, binding, position)?)),
+                }
+            }
+            Ok(ArrayPattern::new(elements, rest).into())
+        }
+        NodeKind::Object(object) => {
+            let mut properties = Vec::with_capacity(object.properties().len());
+            let mut rest = None;
+            for (i, property) in object.properties().iter().enumerate() {
+                let element = match property {
+                    PropertyDefinition::Property(key, value) => {
+                        (key.clone(), cover_element(value, binding, position)?)
+                    }
+                    PropertyDefinition::IdentifierReference(name) => (
+                        name.clone().into(),
+                        PatternElement::new(Identifier::from(name.clone()), None),
+                    ),
+                    PropertyDefinition::CoverInitializedName(name, init) => (
+                        name.clone().into(),
+                        PatternElement::new(Identifier::from(name.clone()), Some(init.clone())),
+                    ),
+                    PropertyDefinition::SpreadObject(target)
+                        if i + 1 == object.properties().len()
+                            && !matches!(
+                                target.kind(),
+                                NodeKind::ArrayDecl(_) | NodeKind::Object(_)
+                            ) =>
+                    {
+                        rest = Some(cover_target(target, binding, position)?);
+                        continue;
+                    }
+                    _ => {
+                        return Err(ParseError::general(
+                            "invalid destructuring pattern",
+                            position,
+                        ))
+                    }
+                };
+                properties.push(element);
+            }
+            Ok(ObjectPattern::new(properties, rest).into())
+        }
+        NodeKind::Pattern(pattern) if !binding || is_binding_pattern(pattern) => {
+            Ok(pattern.clone())
+        }
+        _ => Err(ParseError::general(
+            "invalid destructuring pattern",
+            position,
+        )),
+    }
+}
+
+/// Reinterprets an element of a literal, with an optional default value, as a pattern element.
+fn cover_element(
+    node: &Node,
+    binding: bool,
+    position: Position,
+) -> Result<PatternElement, ParseError> {
+    match node.kind() {
+        NodeKind::Assign(assign) => Ok(PatternElement::new(
+            cover_target(assign.lhs(), binding, position)?,
+            Some(assign.rhs().clone()),
+        )),
+        _ => Ok(PatternElement::new(
+            cover_target(node, binding, position)?,
+            None,
+        )),
+    }
+}
+
+/// Reinterprets a node as the target of a pattern element.
+fn cover_target(node: &Node, binding: bool, position: Position) -> Result<Node, ParseError> {
+    match node.kind() {
+        NodeKind::Identifier(_) => Ok(node.clone()),
+        NodeKind::GetConstField(_) | NodeKind::GetField(_) | NodeKind::GetSuperField(_)
+            if !binding =>
+        {
+            Ok(node.clone())
+        }
+        _ => cover_pattern(node, binding, position).map(Node::from),
+    }
+}
+
+/// Checks that the targets of a pattern that was parsed as an assignment pattern are all
+/// identifiers or binding patterns.
+fn is_binding_pattern(pattern: &Pattern) -> bool {
+    let is_binding_target = |node: &Node| match node.kind() {
+        NodeKind::Identifier(_) => true,
+        NodeKind::Pattern(pattern) => is_binding_pattern(pattern),
+        _ => false,
+    };
+    match pattern {
+        Pattern::Object(object) => {
+            object
+                .properties()
+                .iter()
+                .all(|(_, element)| is_binding_target(element.target()))
+                && object.rest().map_or(true, is_binding_target)
+        }
+        Pattern::Array(array) => {
+            array
+                .elements()
+                .iter()
+                .flatten()
+                .all(|element| is_binding_target(element.target()))
+                && array.rest().map_or(true, is_binding_target)
+        }
+    }
+}
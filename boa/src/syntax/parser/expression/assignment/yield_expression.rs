@@ -0,0 +1,94 @@
+//! Yield expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+//! [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+
+use super::AssignmentExpression;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, Yield},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            cursor::{Cursor, SemicolonResult},
+            AllowAwait, AllowIn, ParseResult, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Yield expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct YieldExpression {
+    allow_in: AllowIn,
+    allow_await: AllowAwait,
+}
+
+impl YieldExpression {
+    /// Creates a new `YieldExpression` parser.
+    pub(super) fn new<I, A>(allow_in: I, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for YieldExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "Parsing");
+
+        let start = cursor.next_start()?;
+        cursor.expect(Keyword::Yield, "yield expression")?;
+
+        // The argument is optional, and must start on the same line as the `yield` keyword.
+        let delegate = match cursor.peek_semicolon()? {
+            SemicolonResult::Found(_) => {
+                let node = Node::from(Yield::new::<Node, _>(None, false));
+                return Ok(node.with_default_span(cursor.span_from(start)));
+            }
+            SemicolonResult::NotFound(tok) => match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Mul) => true,
+                TokenKind::Punctuator(Punctuator::CloseParen)
+                | TokenKind::Punctuator(Punctuator::CloseBracket)
+                | TokenKind::Punctuator(Punctuator::Comma)
+                | TokenKind::Punctuator(Punctuator::Colon) => {
+                    let node = Node::from(Yield::new::<Node, _>(None, false));
+                    return Ok(node.with_default_span(cursor.span_from(start)));
+                }
+                _ => false,
+            },
+        };
+        if delegate {
+            cursor.next()?.expect("* token vanished"); // Consume the token.
+        }
+
+        let expr =
+            AssignmentExpression::new(self.allow_in, true, self.allow_await).parse(cursor)?;
+        Ok(Node::from(Yield::new(expr, delegate)).with_default_span(cursor.span_from(start)))
+    }
+}
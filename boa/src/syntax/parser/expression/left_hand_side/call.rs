@@ -7,7 +7,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Functions
 //! [spec]: https://tc39.es/ecma262/#prod-CallExpression
 
-use super::arguments::Arguments;
+use super::{arguments::Arguments, template::TaggedTemplateLiteral};
 use crate::{
     syntax::{
         ast::{
@@ -65,11 +65,16 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("CallExpression", "Parsing");
 
+        let start = match self.first_member_expr.span() {
+            Some(span) => span.start(),
+            None => cursor.next_start()?,
+        };
         let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         let mut lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
             Node::from(Call::new(self.first_member_expr, args))
+                .with_default_span(cursor.span_from(start))
         } else {
             let next_token = cursor.next()?.expect("token vanished");
             return Err(ParseError::expected(
@@ -112,8 +117,13 @@ where
                     cursor.expect(Punctuator::CloseBracket, "call expression")?;
                     lhs = GetField::new(lhs, idx).into();
                 }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateMiddle(_) => {
+                    lhs = TaggedTemplateLiteral::new(self.allow_yield, self.allow_await, lhs)
+                        .parse(cursor)?;
+                }
                 _ => break,
             }
+            lhs = lhs.with_default_span(cursor.span_from(start));
         }
         Ok(lhs)
     }
@@ -5,15 +5,15 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#prod-MemberExpression
 
-use super::arguments::Arguments;
+use super::{arguments::Arguments, template::TaggedTemplateLiteral};
 use crate::{
     syntax::{
         ast::{
             node::{
-                field::{GetConstField, GetField},
-                Call, New, Node,
+                field::{GetConstField, GetField, GetSuperField},
+                Call, New, Node, SuperCall,
             },
-            Keyword, Punctuator,
+            Const, Keyword, Punctuator,
         },
         lexer::TokenKind,
         parser::{
@@ -61,18 +61,61 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("MemberExpression", "Parsing");
 
-        let mut lhs = if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
-            == &TokenKind::Keyword(Keyword::New)
-        {
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = token.span().start();
+        let token_kind = token.kind().clone();
+        let mut lhs = if token_kind == TokenKind::Keyword(Keyword::New) {
             let _ = cursor.next().expect("new keyword disappeared");
             let lhs = self.parse(cursor)?;
             let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
             let call_node = Call::new(lhs, args);
 
             Node::from(New::from(call_node))
+        } else if token_kind == TokenKind::Keyword(Keyword::Super) {
+            let _ = cursor.next().expect("super keyword disappeared");
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    SuperCall::new(args).into()
+                }
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    cursor.next()?.expect("dot punctuator token disappeared");
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    match token.kind() {
+                        TokenKind::Identifier(name) => {
+                            GetSuperField::new(Const::from(name.as_ref())).into()
+                        }
+                        TokenKind::Keyword(kw) => {
+                            GetSuperField::new(Const::from(kw.to_string())).into()
+                        }
+                        _ => {
+                            return Err(ParseError::expected(
+                                vec![TokenKind::identifier("identifier")],
+                                token,
+                                "super property",
+                            ));
+                        }
+                    }
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    cursor
+                        .next()?
+                        .expect("open bracket punctuator token disappeared");
+                    let idx =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "super property")?;
+                    GetSuperField::new(idx).into()
+                }
+                _ => {
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    return Err(ParseError::unexpected(token, "super keyword"));
+                }
+            }
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
         };
+        lhs = lhs.with_default_span(cursor.span_from(start));
         while let Some(tok) = cursor.peek(0)? {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Dot) => {
@@ -105,8 +148,13 @@ where
                     cursor.expect(Punctuator::CloseBracket, "member expression")?;
                     lhs = GetField::new(lhs, idx).into();
                 }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateMiddle(_) => {
+                    lhs = TaggedTemplateLiteral::new(self.allow_yield, self.allow_await, lhs)
+                        .parse(cursor)?;
+                }
                 _ => break,
             }
+            lhs = lhs.with_default_span(cursor.span_from(start));
         }
 
         Ok(lhs)
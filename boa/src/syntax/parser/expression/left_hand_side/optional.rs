@@ -0,0 +1,162 @@
+//! Optional chain expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+//! [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+
+use super::arguments::Arguments;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, NodeKind, Optional, OptionalOperation, OptionalOperationKind},
+            Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses an optional chain expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+#[derive(Debug)]
+pub(super) struct OptionalExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    target: Node,
+}
+
+impl OptionalExpression {
+    /// Creates a new `OptionalExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, target: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            target,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for OptionalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("OptionalExpression", "Parsing");
+        let start = match self.target.span() {
+            Some(span) => span.start(),
+            None => cursor.next_start()?,
+        };
+
+        // The last property access of the target is part of the chain, so that a call like
+        // `a.b?.()` keeps `a` as its `this` value.
+        let mut chain = Vec::new();
+        let target = match self.target.kind() {
+            NodeKind::GetConstField(field) => {
+                chain.push(OptionalOperation::new(
+                    OptionalOperationKind::GetConstField(field.field().into()),
+                    false,
+                ));
+                field.obj().clone()
+            }
+            NodeKind::GetField(field) => {
+                chain.push(OptionalOperation::new(
+                    OptionalOperationKind::GetField(field.field().clone()),
+                    false,
+                ));
+                field.obj().clone()
+            }
+            _ => self.target.clone(),
+        };
+
+        while let Some(token) = cursor.peek(0)? {
+            let shorted = match token.kind() {
+                TokenKind::Punctuator(Punctuator::Optional) => {
+                    cursor.next()?.expect("?. token vanished");
+                    true
+                }
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    cursor.next()?.expect(". token vanished");
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    let name = match token.kind() {
+                        TokenKind::Identifier(name) => name.clone(),
+                        TokenKind::Keyword(kw) => kw.to_string().into(),
+                        _ => {
+                            return Err(ParseError::expected(
+                                vec![TokenKind::identifier("identifier")],
+                                token,
+                                "optional chain",
+                            ))
+                        }
+                    };
+                    chain.push(OptionalOperation::new(
+                        OptionalOperationKind::GetConstField(name),
+                        false,
+                    ));
+                    continue;
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket)
+                | TokenKind::Punctuator(Punctuator::OpenParen) => false,
+                _ => break,
+            };
+
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            let kind = match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    OptionalOperationKind::Call(args)
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    cursor.next()?.expect("[ token vanished");
+                    let field =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                    OptionalOperationKind::GetField(field)
+                }
+                TokenKind::Identifier(name) => {
+                    let name = name.clone();
+                    cursor.next()?.expect("identifier token vanished");
+                    OptionalOperationKind::GetConstField(name)
+                }
+                TokenKind::Keyword(kw) => {
+                    let name = kw.to_string().into();
+                    cursor.next()?.expect("keyword token vanished");
+                    OptionalOperationKind::GetConstField(name)
+                }
+                _ => {
+                    let token = cursor.next()?.expect("token vanished");
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::Punctuator(Punctuator::OpenParen),
+                            TokenKind::Punctuator(Punctuator::OpenBracket),
+                            TokenKind::identifier("identifier"),
+                        ],
+                        token,
+                        "optional chain",
+                    ));
+                }
+            };
+            chain.push(OptionalOperation::new(kind, shorted));
+        }
+
+        Ok(Node::from(Optional::new(target, chain)).with_default_span(cursor.span_from(start)))
+    }
+}
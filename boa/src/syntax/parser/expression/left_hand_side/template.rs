@@ -0,0 +1,93 @@
+//! Tagged template parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+//! [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+
+use crate::{
+    profiler::BoaProfiler,
+    syntax::{
+        ast::{node::TaggedTemplate, Node, Punctuator},
+        lexer::TokenKind,
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+};
+use std::io::Read;
+
+/// Parses a tagged template.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-TemplateLiteral
+#[derive(Debug, Clone)]
+pub(super) struct TaggedTemplateLiteral {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    tag: Node,
+}
+
+impl TaggedTemplateLiteral {
+    /// Creates a new `TaggedTemplateLiteral` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, tag: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            tag,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for TaggedTemplateLiteral
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplateLiteral", "Parsing");
+        let start = match self.tag.span() {
+            Some(span) => span.start(),
+            None => cursor.next_start()?,
+        };
+
+        let mut raws = Vec::new();
+        let mut cookeds = Vec::new();
+        let mut exprs = Vec::new();
+
+        let mut token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        loop {
+            match token.kind() {
+                TokenKind::TemplateMiddle(template_string) => {
+                    raws.push(template_string.as_raw().into());
+                    // Invalid escape sequences are allowed in tagged templates.
+                    cookeds.push(template_string.to_owned_cooked().ok());
+                    exprs.push(
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?,
+                    );
+                    let close = cursor.expect(Punctuator::CloseBlock, "template literal")?;
+                    token = cursor.lex_template(close.span().end())?;
+                }
+                TokenKind::TemplateNoSubstitution(template_string) => {
+                    raws.push(template_string.as_raw().into());
+                    cookeds.push(template_string.to_owned_cooked().ok());
+                    let node = Node::from(TaggedTemplate::new(self.tag, raws, cookeds, exprs));
+                    return Ok(node.with_default_span(cursor.span_from(start)));
+                }
+                _ => {
+                    return Err(ParseError::unexpected(token, "tagged template literal"));
+                }
+            }
+        }
+    }
+}
@@ -16,13 +16,18 @@ mod unary;
 mod update;
 
 use self::assignment::ExponentiationExpression;
-pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
-use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser};
-use crate::syntax::lexer::{InputElement, TokenKind};
+pub(super) use self::{
+    assignment::{assignment_pattern, AssignmentExpression},
+    left_hand_side::LeftHandSideExpression,
+    primary::{is_async_method, Initializer, MethodDefinition, PropertyName},
+};
+use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser};
+use crate::syntax::lexer::{InputElement, Token, TokenKind};
 use crate::{
     profiler::BoaProfiler,
     syntax::ast::{
         node::{BinOp, Node},
+        op::LogOp,
         Keyword, Punctuator,
     },
 };
@@ -73,6 +78,7 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                 cursor.set_goal($goal.unwrap());
             }
 
+            let start = cursor.next_start()?;
             let mut lhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
             while let Some(tok) = cursor.peek(0)? {
                 match *tok.kind() {
@@ -83,6 +89,7 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                             lhs,
                             $lower::new($( self.$low_param ),*).parse(cursor)?
                         ).into();
+                        lhs = lhs.with_default_span(cursor.span_from(start));
                     }
                     TokenKind::Keyword(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
@@ -91,6 +98,7 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                             lhs,
                             $lower::new($( self.$low_param ),*).parse(cursor)?
                         ).into();
+                        lhs = lhs.with_default_span(cursor.span_from(start));
                     }
                     _ => break
                 }
@@ -140,23 +148,26 @@ expression!(
     None::<InputElement>
 );
 
-/// Parses a logical `OR` expression.
+/// Parses a short-circuit expression, either a logical `OR` expression or a nullish coalescing
+/// expression.
+///
+/// The `??` operator cannot be mixed with `&&` or `||` without parentheses.
 ///
 /// More information:
 ///  - [MDN documentation][mdn]
 ///  - [ECMAScript specification][spec]
 ///
-/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Logical_Operators#Logical_OR_2
-/// [spec]: https://tc39.es/ecma262/#prod-LogicalORExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Nullish_coalescing_operator
+/// [spec]: https://tc39.es/ecma262/#prod-ShortCircuitExpression
 #[derive(Debug, Clone, Copy)]
-struct LogicalORExpression {
+pub(super) struct ShortCircuitExpression {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
-impl LogicalORExpression {
-    /// Creates a new `LogicalORExpression` parser.
+impl ShortCircuitExpression {
+    /// Creates a new `ShortCircuitExpression` parser.
     pub(super) fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
@@ -171,13 +182,64 @@ impl LogicalORExpression {
     }
 }
 
-expression!(
-    LogicalORExpression,
-    LogicalANDExpression,
-    [Punctuator::BoolOr],
-    [allow_in, allow_yield, allow_await],
-    None::<InputElement>
-);
+impl<R> TokenParser<R> for ShortCircuitExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("ShortCircuitExpression", "Parsing");
+
+        let start = cursor.next_start()?;
+        let mut lhs = BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+            .parse(cursor)?;
+
+        let is_coalesce =
+            cursor.peek(0)?.map(Token::kind) == Some(&TokenKind::Punctuator(Punctuator::Coalesce));
+        if is_coalesce {
+            while cursor.next_if(Punctuator::Coalesce)?.is_some() {
+                let rhs =
+                    BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor)?;
+                lhs = BinOp::new(LogOp::Coalesce, lhs, rhs).into();
+                lhs = lhs.with_default_span(cursor.span_from(start));
+            }
+        } else {
+            while cursor.next_if(Punctuator::BoolAnd)?.is_some() {
+                let rhs =
+                    BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor)?;
+                lhs = BinOp::new(LogOp::And, lhs, rhs).into();
+                lhs = lhs.with_default_span(cursor.span_from(start));
+            }
+            while cursor.next_if(Punctuator::BoolOr)?.is_some() {
+                let rhs =
+                    LogicalANDExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor)?;
+                lhs = BinOp::new(LogOp::Or, lhs, rhs).into();
+                lhs = lhs.with_default_span(cursor.span_from(start));
+            }
+        }
+
+        if let Some(tok) = cursor.peek(0)? {
+            let mixed = match tok.kind() {
+                TokenKind::Punctuator(Punctuator::BoolAnd)
+                | TokenKind::Punctuator(Punctuator::BoolOr) => is_coalesce,
+                TokenKind::Punctuator(Punctuator::Coalesce) => !is_coalesce,
+                _ => false,
+            };
+            if mixed {
+                return Err(ParseError::general(
+                    "cannot mix `??` with `&&` or `||` without parentheses",
+                    tok.span().start(),
+                ));
+            }
+        }
+
+        Ok(lhs)
+    }
+}
 
 /// Parses a logical `AND` expression.
 ///
@@ -410,20 +472,54 @@ impl RelationalExpression {
     }
 }
 
-expression!(
-    RelationalExpression,
-    ShiftExpression,
-    [
-        Punctuator::LessThan,
-        Punctuator::GreaterThan,
-        Punctuator::LessThanOrEq,
-        Punctuator::GreaterThanOrEq,
-        Keyword::InstanceOf,
-        Keyword::In
-    ],
-    [allow_yield, allow_await],
-    None::<InputElement>
-);
+impl<R> TokenParser<R> for RelationalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("RelationalExpression", "Parsing");
+
+        let start = cursor.next_start()?;
+        let mut lhs = ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        while let Some(tok) = cursor.peek(0)? {
+            match *tok.kind() {
+                TokenKind::Punctuator(op)
+                    if op == Punctuator::LessThan
+                        || op == Punctuator::GreaterThan
+                        || op == Punctuator::LessThanOrEq
+                        || op == Punctuator::GreaterThanOrEq =>
+                {
+                    let _ = cursor.next().expect("token disappeared");
+                    lhs = BinOp::new(
+                        op.as_binop().expect("Could not get binary operation."),
+                        lhs,
+                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                    )
+                    .into();
+                    lhs = lhs.with_default_span(cursor.span_from(start));
+                }
+                // The `in` operator is not allowed in the head of a `for` statement.
+                TokenKind::Keyword(op)
+                    if op == Keyword::InstanceOf || (op == Keyword::In && self.allow_in.0) =>
+                {
+                    let _ = cursor.next().expect("token disappeared");
+                    lhs = BinOp::new(
+                        op.as_binop().expect("Could not get binary operation."),
+                        lhs,
+                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                    )
+                    .into();
+                    lhs = lhs.with_default_span(cursor.span_from(start));
+                }
+                _ => break,
+            }
+        }
+
+        Ok(lhs)
+    }
+}
 
 /// Parses a bitwise shift expression.
 ///
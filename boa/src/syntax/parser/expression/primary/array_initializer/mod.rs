@@ -67,7 +67,7 @@ where
         loop {
             // TODO: Support all features.
             while cursor.next_if(Punctuator::Comma)?.is_some() {
-                elements.push(Node::Const(Const::Undefined));
+                elements.push(Node::from(Const::Undefined));
             }
 
             if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
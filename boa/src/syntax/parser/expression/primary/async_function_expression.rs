@@ -0,0 +1,69 @@
+//! Async function expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function
+//! [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionExpression
+
+use crate::{
+    syntax::{
+        ast::{node::AsyncFunctionExpr, Punctuator},
+        lexer::TokenKind,
+        parser::{
+            function::{FormalParameters, FunctionBody},
+            statement::BindingIdentifier,
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Async function expression parsing.
+///
+/// The `async` and `function` keywords have already been consumed.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AsyncFunctionExpression;
+
+impl<R> TokenParser<R> for AsyncFunctionExpression
+where
+    R: Read,
+{
+    type Output = AsyncFunctionExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AsyncFunctionExpression", "Parsing");
+
+        let name = if let Some(token) = cursor.peek(0)? {
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => None,
+                _ => Some(BindingIdentifier::new(false, true).parse(cursor)?),
+            }
+        } else {
+            None
+        };
+
+        cursor.expect(Punctuator::OpenParen, "async function expression")?;
+
+        let params = FormalParameters::new(false, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "async function expression")?;
+        cursor.expect(Punctuator::OpenBlock, "async function expression")?;
+
+        let body = FunctionBody::new(false, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseBlock, "async function expression")?;
+
+        Ok(AsyncFunctionExpr::new(name, params, body))
+    }
+}
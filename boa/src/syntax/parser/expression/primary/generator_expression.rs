@@ -0,0 +1,69 @@
+//! Generator expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function*
+//! [spec]: https://tc39.es/ecma262/#prod-GeneratorExpression
+
+use crate::{
+    syntax::{
+        ast::{node::GeneratorExpr, Punctuator},
+        lexer::TokenKind,
+        parser::{
+            function::{FormalParameters, FunctionBody},
+            statement::BindingIdentifier,
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Generator expression parsing.
+///
+/// The `function` keyword and the `*` punctuator have already been consumed.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function*
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct GeneratorExpression;
+
+impl<R> TokenParser<R> for GeneratorExpression
+where
+    R: Read,
+{
+    type Output = GeneratorExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("GeneratorExpression", "Parsing");
+
+        let name = if let Some(token) = cursor.peek(0)? {
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => None,
+                _ => Some(BindingIdentifier::new(true, false).parse(cursor)?),
+            }
+        } else {
+            None
+        };
+
+        cursor.expect(Punctuator::OpenParen, "generator expression")?;
+
+        let params = FormalParameters::new(true, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "generator expression")?;
+        cursor.expect(Punctuator::OpenBlock, "generator expression")?;
+
+        let body = FunctionBody::new(true, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseBlock, "generator expression")?;
+
+        Ok(GeneratorExpr::new(name, params, body))
+    }
+}
@@ -8,28 +8,36 @@
 //! [spec]: https://tc39.es/ecma262/#prod-PrimaryExpression
 
 mod array_initializer;
+mod async_function_expression;
+mod class_expression;
 mod function_expression;
+mod generator_expression;
 mod object_initializer;
+mod template;
 #[cfg(test)]
 mod tests;
 
 use self::{
-    array_initializer::ArrayLiteral, function_expression::FunctionExpression,
-    object_initializer::ObjectLiteral,
+    array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
+    class_expression::ClassExpression, function_expression::FunctionExpression,
+    generator_expression::GeneratorExpression, object_initializer::ObjectLiteral,
+    template::TemplateLiteral,
 };
 use super::Expression;
 use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{
-            node::{Call, Identifier, New, Node},
+            node::{Call, ClassExpr, Identifier, New, Node, NodeKind},
             Const, Keyword, Punctuator,
         },
-        lexer::{token::Numeric, InputElement, TokenKind},
+        lexer::{token::Numeric, InputElement, Token, TokenKind},
         parser::{AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
     },
 };
-pub(in crate::syntax::parser) use object_initializer::Initializer;
+pub(in crate::syntax::parser) use object_initializer::{
+    is_async_method, Initializer, MethodDefinition, PropertyName,
+};
 
 use std::io::Read;
 
@@ -71,11 +79,25 @@ where
         let _timer = BoaProfiler::global().start_event("PrimaryExpression", "Parsing");
 
         let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
 
-        match tok.kind() {
-            TokenKind::Keyword(Keyword::This) => Ok(Node::This),
+        let node = match tok.kind() {
+            TokenKind::Keyword(Keyword::This) => Ok(NodeKind::This.into()),
             TokenKind::Keyword(Keyword::Function) => {
-                FunctionExpression.parse(cursor).map(Node::from)
+                let start = tok.span().start();
+                if cursor.next_if(Punctuator::Mul)?.is_some() {
+                    let function = GeneratorExpression.parse(cursor)?;
+                    Ok(function.with_source(cursor.source_text(start)).into())
+                } else {
+                    let function = FunctionExpression.parse(cursor)?;
+                    Ok(function.with_source(cursor.source_text(start)).into())
+                }
+            }
+            TokenKind::Keyword(Keyword::Class) => {
+                let start = tok.span().start();
+                let class =
+                    ClassExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                Ok(ClassExpr::from(class.with_source(cursor.source_text(start))).into())
             }
             TokenKind::Punctuator(Punctuator::OpenParen) => {
                 cursor.set_goal(InputElement::RegExp);
@@ -88,7 +110,7 @@ where
                 cursor.set_goal(InputElement::RegExp);
                 ArrayLiteral::new(self.allow_yield, self.allow_await)
                     .parse(cursor)
-                    .map(Node::ArrayDecl)
+                    .map(Node::from)
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 cursor.set_goal(InputElement::RegExp);
@@ -98,8 +120,29 @@ where
             }
             TokenKind::BooleanLiteral(boolean) => Ok(Const::from(*boolean).into()),
             TokenKind::NullLiteral => Ok(Const::Null.into()),
+            TokenKind::Identifier(ident)
+                if ident.as_ref() == "async"
+                    && cursor.peek_no_lineterminator(0)?.map(Token::kind)
+                        == Some(&TokenKind::Keyword(Keyword::Function)) =>
+            {
+                let start = tok.span().start();
+                cursor.next()?.expect("function keyword vanished"); // Consume the token.
+                let function = AsyncFunctionExpression.parse(cursor)?;
+                Ok(function.with_source(cursor.source_text(start)).into())
+            }
             TokenKind::Identifier(ident) => Ok(Identifier::from(ident.as_ref()).into()), // TODO: IdentifierReference
-            TokenKind::StringLiteral(s) => Ok(Const::from(s.as_ref()).into()),
+            TokenKind::StringLiteral(s) => Ok(Const::from(s.clone()).into()),
+            TokenKind::TemplateNoSubstitution(template_string) => {
+                Ok(Const::from(template_string.to_owned_cooked()?.as_ref()).into())
+            }
+            TokenKind::TemplateMiddle(template_string) => TemplateLiteral::new(
+                self.allow_yield,
+                self.allow_await,
+                tok.span().start(),
+                &template_string.to_owned_cooked()?,
+            )
+            .parse(cursor)
+            .map(Node::from),
             TokenKind::NumericLiteral(Numeric::Integer(num)) => Ok(Const::from(*num).into()),
             TokenKind::NumericLiteral(Numeric::Rational(num)) => Ok(Const::from(*num).into()),
             TokenKind::NumericLiteral(Numeric::BigInt(num)) => Ok(Const::from(num.clone()).into()),
@@ -129,6 +172,7 @@ where
                 }
             }
             _ => Err(ParseError::unexpected(tok.clone(), "primary expression")),
-        }
+        }?;
+        Ok(node.with_default_span(cursor.span_from(start)))
     }
 }
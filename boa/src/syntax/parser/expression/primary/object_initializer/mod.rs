@@ -9,12 +9,12 @@
 
 #[cfg(test)]
 mod tests;
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Token, TokenKind};
 use crate::{
     syntax::{
         ast::{
             node::{self, FunctionExpr, MethodDefinitionKind, Node, Object},
-            Punctuator,
+            Position, Punctuator,
         },
         parser::{
             expression::AssignmentExpression,
@@ -134,19 +134,43 @@ where
             return Ok(node::PropertyDefinition::SpreadObject(node));
         }
 
-        let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        let prop_name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
         if cursor.next_if(Punctuator::Colon)?.is_some() {
             let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
             return Ok(node::PropertyDefinition::property(prop_name, val));
         }
 
-        if cursor
-            .next_if(TokenKind::Punctuator(Punctuator::OpenParen))?
-            .is_some()
-            || ["get", "set"].contains(&prop_name.as_str())
+        // Shorthand properties, like `{ a }`, or `{ a = 1 }` in assignment patterns.
+        if let TokenKind::Identifier(ref name) = token.kind() {
+            match cursor.peek(0)?.map(Token::kind) {
+                Some(TokenKind::Punctuator(Punctuator::Comma))
+                | Some(TokenKind::Punctuator(Punctuator::CloseBlock)) => {
+                    return Ok(node::PropertyDefinition::identifier_reference(name.clone()));
+                }
+                Some(TokenKind::Punctuator(Punctuator::Assign)) => {
+                    let init =
+                        Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    return Ok(node::PropertyDefinition::CoverInitializedName(
+                        name.clone(),
+                        init,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        let start = token.span().start();
+        if prop_name.literal() == Some("async") && is_async_method(cursor)? {
+            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name, start)
+                .parse(cursor);
+        }
+
+        if cursor.peek(0)?.map(Token::kind) == Some(&TokenKind::Punctuator(Punctuator::OpenParen))
+            || matches!(prop_name.literal(), Some("get") | Some("set") | Some("*"))
         {
-            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
+            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name, start)
                 .parse(cursor);
         }
 
@@ -155,6 +179,52 @@ where
     }
 }
 
+/// Parses a property name, which is either a literal name or an expression in brackets.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct PropertyName {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl PropertyName {
+    /// Creates a new `PropertyName` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for PropertyName
+where
+    R: Read,
+{
+    type Output = node::PropertyName;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("PropertyName", "Parsing");
+
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        if token.kind() == &TokenKind::Punctuator(Punctuator::OpenBracket) {
+            let name = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor)?;
+            cursor.expect(Punctuator::CloseBracket, "computed property name")?;
+            return Ok(node::PropertyName::Computed(name));
+        }
+        Ok(token.to_string().into())
+    }
+}
+
 /// Parses a method definition.
 ///
 /// More information:
@@ -162,24 +232,33 @@ where
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
 #[derive(Debug, Clone)]
-struct MethodDefinition {
+pub(in crate::syntax::parser) struct MethodDefinition {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    identifier: String,
+    name: node::PropertyName,
+    start: Position,
 }
 
 impl MethodDefinition {
     /// Creates a new `MethodDefinition` parser.
-    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I) -> Self
+    ///
+    /// The `name` is the first property name of the method, that has already been parsed from
+    /// `start`. It is the `get`, `set`, `async` or `*` prefix if the method has one.
+    pub(in crate::syntax::parser) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        name: node::PropertyName,
+        start: Position,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
-        I: Into<String>,
     {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            identifier: identifier.into(),
+            name,
+            start,
         }
     }
 }
@@ -193,9 +272,13 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("MethodDefinition", "Parsing");
 
-        let (methodkind, prop_name, params) = match self.identifier.as_str() {
-            idn @ "get" | idn @ "set" => {
-                let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+        // A method can be named `get`, `set` or `async`, like `{ get() {} }`.
+        let is_method_name =
+            cursor.peek(0)?.map(Token::kind) == Some(&TokenKind::Punctuator(Punctuator::OpenParen));
+        let (methodkind, prop_name, params) = match self.name.literal() {
+            Some(idn @ "get") | Some(idn @ "set") if !is_method_name => {
+                let prop_name =
+                    PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::OpenParen),
                     "property method definition",
@@ -221,14 +304,33 @@ where
                     (MethodDefinitionKind::Set, prop_name, params)
                 }
             }
-            prop_name => {
+            Some("*") => {
+                let prop_name =
+                    PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                cursor.expect(
+                    TokenKind::Punctuator(Punctuator::OpenParen),
+                    "generator method definition",
+                )?;
+                let params = FormalParameters::new(false, false).parse(cursor)?;
+                cursor.expect(Punctuator::CloseParen, "generator method definition")?;
+                (MethodDefinitionKind::Generator, prop_name, params)
+            }
+            Some("async") if !is_method_name => {
+                let prop_name =
+                    PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                cursor.expect(
+                    TokenKind::Punctuator(Punctuator::OpenParen),
+                    "async method definition",
+                )?;
+                let params = FormalParameters::new(false, true).parse(cursor)?;
+                cursor.expect(Punctuator::CloseParen, "async method definition")?;
+                (MethodDefinitionKind::Async, prop_name, params)
+            }
+            _ => {
+                cursor.expect(Punctuator::OpenParen, "method definition")?;
                 let params = FormalParameters::new(false, false).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "method definition")?;
-                (
-                    MethodDefinitionKind::Ordinary,
-                    prop_name.to_string(),
-                    params,
-                )
+                (MethodDefinitionKind::Ordinary, self.name, params)
             }
         };
 
@@ -236,7 +338,11 @@ where
             TokenKind::Punctuator(Punctuator::OpenBlock),
             "property method definition",
         )?;
-        let body = FunctionBody::new(false, false).parse(cursor)?;
+        let body = FunctionBody::new(
+            methodkind == MethodDefinitionKind::Generator,
+            methodkind == MethodDefinitionKind::Async,
+        )
+        .parse(cursor)?;
         cursor.expect(
             TokenKind::Punctuator(Punctuator::CloseBlock),
             "property method definition",
@@ -245,11 +351,24 @@ where
         Ok(node::PropertyDefinition::method_definition(
             methodkind,
             prop_name,
-            FunctionExpr::new(None, params, body),
+            FunctionExpr::new(None, params, body).with_source(cursor.source_text(self.start)),
         ))
     }
 }
 
+/// Checks if the `async` token that was just consumed starts an async method, that is, if it is
+/// followed by the name of the method on the same line.
+pub(in crate::syntax::parser) fn is_async_method<R>(
+    cursor: &mut Cursor<R>,
+) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    Ok(cursor.peek_no_lineterminator(0)?.map_or(false, |tok| {
+        tok.kind() != &TokenKind::Punctuator(Punctuator::OpenParen)
+    }))
+}
+
 /// Initializer parsing.
 ///
 /// More information:
@@ -1,8 +1,8 @@
 use crate::syntax::{
     ast::{
         node::{
-            ConstDecl, ConstDeclList, FormalParameter, FunctionExpr, MethodDefinitionKind, Object,
-            PropertyDefinition,
+            ConstDecl, ConstDeclList, FormalParameter, FunctionExpr, Identifier,
+            MethodDefinitionKind, Object, PropertyDefinition, PropertyName,
         },
         Const,
     },
@@ -140,3 +140,62 @@ fn check_object_setter() {
         .into()],
     );
 }
+
+/// Checks that methods can be named `get` and `set`.
+#[test]
+fn check_object_methods_named_get_and_set() {
+    let object_properties = vec![
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            "get",
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            "set",
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+    ];
+
+    check_parser(
+        "const x = {
+            get() {},
+            set() {}
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
+/// Checks properties and methods with computed names.
+#[test]
+fn check_object_computed_property_names() {
+    let object_properties = vec![
+        PropertyDefinition::property(
+            PropertyName::Computed(Identifier::from("a").into()),
+            Const::from(1),
+        ),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            PropertyName::Computed(Identifier::from("b").into()),
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+    ];
+
+    check_parser(
+        "const x = {
+            [a]: 1,
+            [b]() {}
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
@@ -1,4 +1,14 @@
-use crate::syntax::{ast::Const, parser::tests::check_parser};
+use crate::syntax::{
+    ast::{
+        node::{
+            AsyncFunctionExpr, AwaitExpr, BinOp, GeneratorExpr, GetConstField, Identifier, Node,
+            TaggedTemplate, TemplateElement, TemplateLit, VarDecl, VarDeclList, Yield,
+        },
+        op::NumOp,
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
 
 #[test]
 fn check_string() {
@@ -8,3 +18,72 @@ fn check_string() {
     // Check non-empty string
     check_parser("\"hello\"", vec![Const::from("hello").into()]);
 }
+
+#[test]
+fn check_template_literal() {
+    check_parser("`hello`", vec![Const::from("hello").into()]);
+    check_parser(
+        "`a${b + 1}c\\n`",
+        vec![TemplateLit::new(vec![
+            TemplateElement::String("a".into()),
+            TemplateElement::Expr(
+                BinOp::new(NumOp::Add, Identifier::from("b"), Const::from(1)).into(),
+            ),
+            TemplateElement::String("c\n".into()),
+        ])
+        .into()],
+    );
+    check_invalid("`\\unicode`");
+}
+
+#[test]
+fn check_tagged_template() {
+    check_parser(
+        "a.b`x${c}\\unicode`",
+        vec![TaggedTemplate::new(
+            GetConstField::new(Identifier::from("a"), "b"),
+            vec!["x".into(), "\\unicode".into()],
+            vec![Some("x".into()), None],
+            vec![Identifier::from("c").into()],
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_generator_expression() {
+    check_parser(
+        "var gen = function* () { yield; };",
+        vec![VarDeclList::from(vec![VarDecl::new(
+            "gen",
+            Some(
+                GeneratorExpr::new::<Option<Box<str>>, _, _>(
+                    None,
+                    vec![],
+                    vec![Yield::new::<Node, _>(None, false).into()],
+                )
+                .into(),
+            ),
+        )])
+        .into()],
+    );
+}
+
+#[test]
+fn check_async_function_expression() {
+    check_parser(
+        "var f = async function () { await a; };",
+        vec![VarDeclList::from(vec![VarDecl::new(
+            "f",
+            Some(
+                AsyncFunctionExpr::new::<Option<Box<str>>, _, _>(
+                    None,
+                    vec![],
+                    vec![AwaitExpr::new(Identifier::from("a")).into()],
+                )
+                .into(),
+            ),
+        )])
+        .into()],
+    );
+}
@@ -1,10 +1,10 @@
 use crate::syntax::{
-    ast::op::{AssignOp, BitOp, CompOp, NumOp},
+    ast::op::{AssignOp, BitOp, CompOp, LogOp, NumOp},
     ast::{
-        node::{BinOp, Identifier},
+        node::{BinOp, Identifier, Optional, OptionalOperation, OptionalOperationKind},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks numeric operations
@@ -182,6 +182,33 @@ fn check_assign_operations() {
         "a >>>= b",
         vec![BinOp::new(AssignOp::Ushr, Identifier::from("a"), Identifier::from("b")).into()],
     );
+    check_parser(
+        "a &&= b",
+        vec![BinOp::new(
+            AssignOp::BoolAnd,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ||= b",
+        vec![BinOp::new(
+            AssignOp::BoolOr,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ??= b",
+        vec![BinOp::new(
+            AssignOp::Coalesce,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
     check_parser(
         "a %= 10 / 2",
         vec![BinOp::new(
@@ -236,3 +263,81 @@ fn check_relational_operations() {
         vec![BinOp::new(CompOp::In, Identifier::from("p"), Identifier::from("o")).into()],
     );
 }
+
+/// Checks logical operations
+#[test]
+fn check_logical_operations() {
+    check_parser(
+        "a || b && c",
+        vec![BinOp::new(
+            LogOp::Or,
+            Identifier::from("a"),
+            BinOp::new(LogOp::And, Identifier::from("b"), Identifier::from("c")),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ?? b ?? c",
+        vec![BinOp::new(
+            LogOp::Coalesce,
+            BinOp::new(
+                LogOp::Coalesce,
+                Identifier::from("a"),
+                Identifier::from("b"),
+            ),
+            Identifier::from("c"),
+        )
+        .into()],
+    );
+    check_parser(
+        "(a || b) ?? c",
+        vec![BinOp::new(
+            LogOp::Coalesce,
+            BinOp::new(LogOp::Or, Identifier::from("a"), Identifier::from("b")),
+            Identifier::from("c"),
+        )
+        .into()],
+    );
+}
+
+/// Checks that `??` can't be mixed with `&&` or `||` without parentheses.
+#[test]
+fn check_coalesce_mixed_with_logical() {
+    check_invalid("a ?? b || c");
+    check_invalid("a && b ?? c");
+}
+
+/// Checks optional chains
+#[test]
+fn check_optional_chain() {
+    check_parser(
+        "a?.b.c",
+        vec![Optional::new(
+            Identifier::from("a"),
+            vec![
+                OptionalOperation::new(OptionalOperationKind::GetConstField("b".into()), true),
+                OptionalOperation::new(OptionalOperationKind::GetConstField("c".into()), false),
+            ],
+        )
+        .into()],
+    );
+    check_parser(
+        "a.b?.(c)?.[d]",
+        vec![Optional::new(
+            Identifier::from("a"),
+            vec![
+                OptionalOperation::new(OptionalOperationKind::GetConstField("b".into()), false),
+                OptionalOperation::new(
+                    OptionalOperationKind::Call(vec![Identifier::from("c").into()].into()),
+                    true,
+                ),
+                OptionalOperation::new(
+                    OptionalOperationKind::GetField(Identifier::from("d").into()),
+                    true,
+                ),
+            ],
+        )
+        .into()],
+    );
+    check_invalid("a?.b = c");
+}
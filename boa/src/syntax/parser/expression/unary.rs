@@ -11,7 +11,7 @@ use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{
-            node::{self, Node},
+            node::{self, Node, NodeKind},
             op::UnaryOp,
             Keyword, Punctuator,
         },
@@ -63,13 +63,13 @@ where
 
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
         let token_start = tok.span().start();
-        match tok.kind() {
+        let node: Node = match tok.kind() {
             TokenKind::Keyword(Keyword::Delete) => {
                 cursor.next()?.expect("Delete keyword vanished"); // Consume the token.
                 let val = self.parse(cursor)?;
 
                 if cursor.strict_mode() {
-                    if let Node::Identifier(_) = val {
+                    if let NodeKind::Identifier(_) = val.kind() {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Delete <variable> statements not allowed in strict mode".into(),
                             token_start,
@@ -77,33 +77,38 @@ where
                     }
                 }
 
-                Ok(node::UnaryOp::new(UnaryOp::Delete, val).into())
+                node::UnaryOp::new(UnaryOp::Delete, val).into()
             }
             TokenKind::Keyword(Keyword::Void) => {
                 cursor.next()?.expect("Void keyword vanished"); // Consume the token.
-                Ok(node::UnaryOp::new(UnaryOp::Void, self.parse(cursor)?).into())
+                node::UnaryOp::new(UnaryOp::Void, self.parse(cursor)?).into()
             }
             TokenKind::Keyword(Keyword::TypeOf) => {
                 cursor.next()?.expect("TypeOf keyword vanished"); // Consume the token.
-                Ok(node::UnaryOp::new(UnaryOp::TypeOf, self.parse(cursor)?).into())
+                node::UnaryOp::new(UnaryOp::TypeOf, self.parse(cursor)?).into()
             }
             TokenKind::Punctuator(Punctuator::Add) => {
                 cursor.next()?.expect("+ token vanished"); // Consume the token.
-                Ok(node::UnaryOp::new(UnaryOp::Plus, self.parse(cursor)?).into())
+                node::UnaryOp::new(UnaryOp::Plus, self.parse(cursor)?).into()
             }
             TokenKind::Punctuator(Punctuator::Sub) => {
                 cursor.next()?.expect("- token vanished"); // Consume the token.
-                Ok(node::UnaryOp::new(UnaryOp::Minus, self.parse(cursor)?).into())
+                node::UnaryOp::new(UnaryOp::Minus, self.parse(cursor)?).into()
             }
             TokenKind::Punctuator(Punctuator::Neg) => {
                 cursor.next()?.expect("~ token vanished"); // Consume the token.
-                Ok(node::UnaryOp::new(UnaryOp::Tilde, self.parse(cursor)?).into())
+                node::UnaryOp::new(UnaryOp::Tilde, self.parse(cursor)?).into()
             }
             TokenKind::Punctuator(Punctuator::Not) => {
                 cursor.next()?.expect("! token vanished"); // Consume the token.
-                Ok(node::UnaryOp::new(UnaryOp::Not, self.parse(cursor)?).into())
+                node::UnaryOp::new(UnaryOp::Not, self.parse(cursor)?).into()
             }
-            _ => UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor),
-        }
+            TokenKind::Keyword(Keyword::Await) if self.allow_await.0 => {
+                cursor.next()?.expect("Await keyword vanished"); // Consume the token.
+                node::AwaitExpr::new(self.parse(cursor)?).into()
+            }
+            _ => return UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor),
+        };
+        Ok(node.with_default_span(cursor.span_from(token_start)))
     }
 }
@@ -56,22 +56,23 @@ where
         let _timer = BoaProfiler::global().start_event("UpdateExpression", "Parsing");
 
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
         match tok.kind() {
             TokenKind::Punctuator(Punctuator::Inc) => {
                 cursor.next()?.expect("Punctuator::Inc token disappeared");
-                return Ok(node::UnaryOp::new(
+                let node = Node::from(node::UnaryOp::new(
                     UnaryOp::IncrementPre,
                     UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                ));
+                return Ok(node.with_default_span(cursor.span_from(start)));
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
                 cursor.next()?.expect("Punctuator::Dec token disappeared");
-                return Ok(node::UnaryOp::new(
+                let node = Node::from(node::UnaryOp::new(
                     UnaryOp::DecrementPre,
                     UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                ));
+                return Ok(node.with_default_span(cursor.span_from(start)));
             }
             _ => {}
         }
@@ -81,11 +82,13 @@ where
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Inc) => {
                     cursor.next()?.expect("Punctuator::Inc token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::IncrementPost, lhs).into());
+                    let node = Node::from(node::UnaryOp::new(UnaryOp::IncrementPost, lhs));
+                    return Ok(node.with_default_span(cursor.span_from(start)));
                 }
                 TokenKind::Punctuator(Punctuator::Dec) => {
                     cursor.next()?.expect("Punctuator::Dec token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::DecrementPost, lhs).into());
+                    let node = Node::from(node::UnaryOp::new(UnaryOp::DecrementPost, lhs));
+                    return Ok(node.with_default_span(cursor.span_from(start)));
                 }
                 _ => {}
             }
@@ -19,7 +19,7 @@ use crate::{
         lexer::{InputElement, TokenKind},
         parser::{
             expression::Initializer,
-            statement::{BindingIdentifier, StatementList},
+            statement::{BindingTarget, StatementList},
             AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
@@ -154,8 +154,7 @@ where
         let _timer = BoaProfiler::global().start_event("BindingRestElement", "Parsing");
         cursor.expect(Punctuator::Spread, "rest parameter")?;
 
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        // TODO: BindingPattern
+        let param = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         Ok(Self::Output::new(param, None, true))
     }
@@ -198,9 +197,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("FormalParameter", "Parsing");
 
-        // TODO: BindingPattern
-
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let param = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             // Check that this is an initilizer before attempting parse.
@@ -266,8 +263,8 @@ where
                 TokenKind::Punctuator(Punctuator::CloseBlock) => {
                     return Ok(Vec::new().into());
                 }
-                TokenKind::StringLiteral(string) | TokenKind::TemplateLiteral(string) => {
-                    if string == &"use strict".into() {
+                TokenKind::StringLiteral(string) => {
+                    if string == "use strict" {
                         cursor.set_strict_mode(true);
                     }
                 }
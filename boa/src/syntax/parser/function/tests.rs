@@ -1,8 +1,10 @@
 use crate::syntax::{
     ast::node::{
-        ArrowFunctionDecl, BinOp, FormalParameter, FunctionDecl, Identifier, Node, Return,
+        ArrayPattern, ArrowFunctionDecl, AsyncArrowFunctionDecl, AwaitExpr, BinOp, Call,
+        FormalParameter, FunctionDecl, Identifier, Node, ObjectPattern, Pattern, PatternElement,
+        Return,
     },
-    ast::op::NumOp,
+    ast::{op::NumOp, Const},
     parser::tests::check_parser,
 };
 
@@ -105,6 +107,61 @@ fn check_arrow_rest() {
     );
 }
 
+/// Checks an arrow function with destructured parameters, which are parsed as a parenthesized
+/// expression first.
+#[test]
+fn check_arrow_patterns() {
+    check_parser(
+        "([a], { b = 1 }) => {}",
+        vec![ArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new(
+                    Pattern::from(ArrayPattern::new(
+                        vec![Some(PatternElement::new(Identifier::from("a"), None))],
+                        None,
+                    )),
+                    None,
+                    false,
+                ),
+                FormalParameter::new(
+                    Pattern::from(ObjectPattern::new(
+                        vec![(
+                            "b".into(),
+                            PatternElement::new(Identifier::from("b"), Some(Const::from(1).into())),
+                        )],
+                        None,
+                    )),
+                    None,
+                    false,
+                ),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks a function declaration with a destructured parameter with a default value.
+#[test]
+fn check_function_pattern_default() {
+    check_parser(
+        "function foo({ a } = b) {}",
+        vec![FunctionDecl::new(
+            Box::from("foo"),
+            vec![FormalParameter::new(
+                Pattern::from(ObjectPattern::new(
+                    vec![("a".into(), PatternElement::new(Identifier::from("a"), None))],
+                    None,
+                )),
+                Some(Identifier::from("b").into()),
+                false,
+            )],
+            vec![],
+        )
+        .into()],
+    );
+}
+
 /// Checks an arrow function with expression return.
 #[test]
 fn check_arrow() {
@@ -176,3 +233,46 @@ fn check_arrow_empty_return_semicolon_insertion() {
         .into()],
     );
 }
+
+/// Checks an async arrow function with a single parameter.
+#[test]
+fn check_async_arrow_single_param() {
+    check_parser(
+        "async a => await a",
+        vec![AsyncArrowFunctionDecl::new(
+            vec![FormalParameter::new("a", None, false)],
+            vec![Return::new(AwaitExpr::new(Identifier::from("a")), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks an async arrow function with parenthesized parameters.
+#[test]
+fn check_async_arrow() {
+    check_parser(
+        "async (a, b = 1, ...c) => { await a; }",
+        vec![AsyncArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new("b", Some(Const::from(1).into()), false),
+                FormalParameter::new("c", None, true),
+            ],
+            vec![AwaitExpr::new(Identifier::from("a")).into()],
+        )
+        .into()],
+    );
+}
+
+/// A call to a function named `async` is not an async arrow function.
+#[test]
+fn check_async_call() {
+    check_parser(
+        "async(a, b)",
+        vec![Call::new(
+            Identifier::from("async"),
+            vec![Identifier::from("a").into(), Identifier::from("b").into()],
+        )
+        .into()],
+    );
+}
@@ -1,5 +1,6 @@
 //! Boa parser implementation.
 
+mod class;
 mod cursor;
 pub mod error;
 mod expression;
@@ -71,16 +72,6 @@ impl From<bool> for AllowReturn {
     }
 }
 
-/// Boolean representing if the parser should allow a `default` keyword.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct AllowDefault(bool);
-
-impl From<bool> for AllowDefault {
-    fn from(allow: bool) -> Self {
-        Self(allow)
-    }
-}
-
 #[derive(Debug)]
 pub struct Parser<R> {
     /// Cursor of the parser, pointing to the lexer and used to get tokens for the parser.
@@ -124,8 +115,8 @@ where
         match cursor.peek(0)? {
             Some(tok) => {
                 match tok.kind() {
-                    TokenKind::StringLiteral(string) | TokenKind::TemplateLiteral(string) => {
-                        if string.as_ref() == "use strict" {
+                    TokenKind::StringLiteral(string) => {
+                        if string == "use strict" {
                             cursor.set_strict_mode(true);
                         }
                     }
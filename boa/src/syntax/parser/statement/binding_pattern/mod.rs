@@ -0,0 +1,375 @@
+//! Destructuring binding pattern parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+//! [spec]: https://tc39.es/ecma262/#sec-destructuring-binding-patterns
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{ArrayPattern, Binding, Identifier, ObjectPattern, Pattern, PatternElement},
+            Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::{Initializer, PropertyName},
+            statement::BindingIdentifier,
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Parses either a binding identifier or a binding pattern.
+///
+/// This is the target of variable declarations, parameters and catch parameters.
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingTarget {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingTarget {
+    /// Creates a new `BindingTarget` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingTarget
+where
+    R: Read,
+{
+    type Output = Binding;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock)
+            | TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                BindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Binding::from)
+            }
+            _ => BindingIdentifier::new(self.allow_yield, self.allow_await)
+                .parse(cursor)
+                .map(Binding::from),
+        }
+    }
+}
+
+/// Binding pattern parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingPattern {
+    /// Creates a new `BindingPattern` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingPattern
+where
+    R: Read,
+{
+    type Output = Pattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingPattern", "Parsing");
+
+        let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        match next_token.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                ObjectBindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Pattern::from)
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                ArrayBindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Pattern::from)
+            }
+            _ => Err(ParseError::expected(
+                vec![
+                    TokenKind::Punctuator(Punctuator::OpenBlock),
+                    TokenKind::Punctuator(Punctuator::OpenBracket),
+                ],
+                next_token.clone(),
+                "binding pattern",
+            )),
+        }
+    }
+}
+
+/// Object binding pattern parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ObjectBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ObjectBindingPattern {
+    /// Creates a new `ObjectBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ObjectBindingPattern
+where
+    R: Read,
+{
+    type Output = ObjectPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ObjectBindingPattern", "Parsing");
+        cursor.expect(Punctuator::OpenBlock, "object binding pattern")?;
+
+        let mut properties = Vec::new();
+        let mut rest = None;
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                let name =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                rest = Some(Identifier::from(name).into());
+                cursor.expect(Punctuator::CloseBlock, "object binding pattern")?;
+                break;
+            }
+
+            if let Some(t) = cursor.peek(0)? {
+                if *t.kind() == TokenKind::Punctuator(Punctuator::OpenBracket) {
+                    // A computed key, like `{ [a]: b }`, always has a target.
+                    let key =
+                        PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::Colon, "object binding pattern")?;
+                    let element =
+                        BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    properties.push((key, element));
+
+                    if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                        break;
+                    }
+                    cursor.expect(Punctuator::Comma, "object binding pattern")?;
+                    continue;
+                }
+            }
+
+            let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+            let key: Box<str> = match token.kind() {
+                TokenKind::Identifier(name) => name.clone(),
+                TokenKind::Keyword(_)
+                | TokenKind::StringLiteral(_)
+                | TokenKind::NumericLiteral(_)
+                | TokenKind::BooleanLiteral(_)
+                | TokenKind::NullLiteral => token.to_string().into(),
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![TokenKind::identifier("property name")],
+                        token,
+                        "object binding pattern",
+                    ))
+                }
+            };
+
+            let element = if cursor.next_if(Punctuator::Colon)?.is_some() {
+                BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?
+            } else if let TokenKind::Identifier(_) = token.kind() {
+                // A single name binding, like `{ a = 1 }`.
+                let init = if let Some(t) = cursor.peek(0)? {
+                    if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
+                        Some(
+                            Initializer::new(true, self.allow_yield, self.allow_await)
+                                .parse(cursor)?,
+                        )
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                };
+                PatternElement::new(Identifier::from(key.clone()), init)
+            } else {
+                return Err(ParseError::expected(
+                    vec![TokenKind::Punctuator(Punctuator::Colon)],
+                    cursor.next()?.ok_or(ParseError::AbruptEnd)?,
+                    "object binding pattern",
+                ));
+            };
+            properties.push((key.into(), element));
+
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+            cursor.expect(Punctuator::Comma, "object binding pattern")?;
+        }
+
+        Ok(ObjectPattern::new(properties, rest))
+    }
+}
+
+/// Array binding pattern parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ArrayBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ArrayBindingPattern {
+    /// Creates a new `ArrayBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ArrayBindingPattern
+where
+    R: Read,
+{
+    type Output = ArrayPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ArrayBindingPattern", "Parsing");
+        cursor.expect(Punctuator::OpenBracket, "array binding pattern")?;
+
+        let mut elements = Vec::new();
+        let mut rest = None;
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+
+            // An elision skips a value.
+            if cursor.next_if(Punctuator::Comma)?.is_some() {
+                elements.push(None);
+                continue;
+            }
+
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                let target =
+                    BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                rest = Some(target.into());
+                cursor.expect(Punctuator::CloseBracket, "array binding pattern")?;
+                break;
+            }
+
+            elements.push(Some(
+                BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?,
+            ));
+
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+            cursor.expect(Punctuator::Comma, "array binding pattern")?;
+        }
+
+        Ok(ArrayPattern::new(elements, rest))
+    }
+}
+
+/// Binding element parsing, a binding identifier or pattern with an optional initializer.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+#[derive(Debug, Clone, Copy)]
+struct BindingElement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingElement {
+    /// Creates a new `BindingElement` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingElement
+where
+    R: Read,
+{
+    type Output = PatternElement;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        let init = if let Some(t) = cursor.peek(0)? {
+            if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
+                Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        Ok(PatternElement::new(target, init))
+    }
+}
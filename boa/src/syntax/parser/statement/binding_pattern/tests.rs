@@ -0,0 +1,96 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            ArrayPattern, Identifier, LetDecl, LetDeclList, Node, ObjectPattern, Pattern,
+            PatternElement, PropertyName, VarDecl, VarDeclList,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks an object binding pattern with a renamed property, a default value and a rest element.
+#[test]
+fn object_binding_pattern() {
+    check_parser(
+        "var { a, b: c = 1, ...d } = e;",
+        vec![VarDeclList::from(vec![VarDecl::new(
+            Pattern::from(ObjectPattern::new(
+                vec![
+                    ("a".into(), PatternElement::new(Identifier::from("a"), None)),
+                    (
+                        "b".into(),
+                        PatternElement::new(Identifier::from("c"), Some(Const::from(1).into())),
+                    ),
+                ],
+                Some(Identifier::from("d").into()),
+            )),
+            Some(Identifier::from("e").into()),
+        )])
+        .into()],
+    );
+}
+
+/// Checks an object binding pattern with a computed property name.
+#[test]
+fn object_binding_pattern_computed_key() {
+    check_parser(
+        "var { [a]: b = 1 } = c;",
+        vec![VarDeclList::from(vec![VarDecl::new(
+            Pattern::from(ObjectPattern::new(
+                vec![(
+                    PropertyName::Computed(Identifier::from("a").into()),
+                    PatternElement::new(Identifier::from("b"), Some(Const::from(1).into())),
+                )],
+                None,
+            )),
+            Some(Identifier::from("c").into()),
+        )])
+        .into()],
+    );
+}
+
+/// Checks an array binding pattern with an elision, a nested pattern and a rest element.
+#[test]
+fn array_binding_pattern() {
+    check_parser(
+        "let [a, , [b], ...c] = d;",
+        vec![LetDeclList::from(vec![LetDecl::new(
+            Pattern::from(ArrayPattern::new(
+                vec![
+                    Some(PatternElement::new(Identifier::from("a"), None)),
+                    None,
+                    Some(PatternElement::new(
+                        ArrayPattern::new(
+                            vec![Some(PatternElement::new(Identifier::from("b"), None))],
+                            None,
+                        ),
+                        None,
+                    )),
+                ],
+                Some(Identifier::from("c").into()),
+            )),
+            Some(Node::from(Identifier::from("d"))),
+        )])
+        .into()],
+    );
+}
+
+/// Checks that a rest element must be the last element of a pattern.
+#[test]
+fn rest_element_not_last() {
+    check_invalid("var [...a, b] = c;");
+    check_invalid("var { ...a, b } = c;");
+}
+
+/// Checks that a computed property name requires a target.
+#[test]
+fn computed_key_without_target() {
+    check_invalid("var { [a] } = c;");
+}
+
+/// Checks that a lexical binding pattern requires an initializer.
+#[test]
+fn let_pattern_without_initializer() {
+    check_invalid("let [a];");
+}
@@ -12,7 +12,7 @@ fn inline() {
         "while (true) break;",
         vec![WhileLoop::new(
             Const::from(true),
-            Node::Break(Break::new::<_, Box<str>>(None)),
+            Node::from(Break::new::<_, Box<str>>(None)),
         )
         .into()],
     );
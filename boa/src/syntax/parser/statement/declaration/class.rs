@@ -0,0 +1,76 @@
+//! Class declaration parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+//! [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{Class, ClassDecl},
+            Keyword,
+        },
+        parser::{
+            class::ClassTail, statement::BindingIdentifier, AllowAwait, AllowYield, Cursor,
+            ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Class declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ClassDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassDeclaration {
+    /// Creates a new `ClassDeclaration` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassDeclaration
+where
+    R: Read,
+{
+    type Output = ClassDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassDeclaration", "Parsing");
+        let start = cursor
+            .expect(Keyword::Class, "class declaration")?
+            .span()
+            .start();
+
+        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        let (super_ref, constructor, elements) =
+            ClassTail::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(Class::new(name, super_ref, constructor, elements)
+            .with_source(cursor.source_text(start))
+            .into())
+    }
+}
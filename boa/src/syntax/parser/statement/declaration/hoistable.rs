@@ -7,10 +7,14 @@
 
 use crate::{
     syntax::{
-        ast::{node::FunctionDecl, Keyword, Node, Punctuator},
+        ast::{
+            node::{AsyncFunctionDecl, FunctionDecl, GeneratorDecl},
+            Keyword, Node, Punctuator,
+        },
+        lexer::{Token, TokenKind},
         parser::{
             function::FormalParameters, function::FunctionBody, statement::BindingIdentifier,
-            AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
     BoaProfiler,
@@ -28,21 +32,18 @@ use std::io::Read;
 pub(super) struct HoistableDeclaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    is_default: AllowDefault,
 }
 
 impl HoistableDeclaration {
     /// Creates a new `HoistableDeclaration` parser.
-    pub(super) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
-        D: Into<AllowDefault>,
     {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            is_default: is_default.into(),
         }
     }
 }
@@ -55,13 +56,44 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("HoistableDeclaration", "Parsing");
-        // TODO: check for generators and async functions + generators
-        FunctionDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
-            .parse(cursor)
-            .map(Node::from)
+        let start = cursor.next_start()?;
+        if is_async_function(cursor)? {
+            let function =
+                AsyncFunctionDeclaration::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            return Ok(Node::from(function).with_default_span(cursor.span_from(start)));
+        }
+
+        let is_generator = cursor.peek(1)?.map_or(false, |tok| {
+            tok.kind() == &TokenKind::Punctuator(Punctuator::Mul)
+        });
+        let node = if is_generator {
+            GeneratorDeclaration::new(self.allow_yield, self.allow_await)
+                .parse(cursor)
+                .map(Node::from)
+        } else {
+            FunctionDeclaration::new(self.allow_yield, self.allow_await)
+                .parse(cursor)
+                .map(Node::from)
+        }?;
+        Ok(node.with_default_span(cursor.span_from(start)))
     }
 }
 
+/// Checks if the next tokens start an async function, that is, `async` followed by `function`
+/// on the same line.
+pub(in crate::syntax::parser) fn is_async_function<R>(
+    cursor: &mut Cursor<R>,
+) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    if cursor.peek(0)?.map(Token::kind) != Some(&TokenKind::identifier("async")) {
+        return Ok(false);
+    }
+    Ok(cursor.peek_no_lineterminator(1)?.map(Token::kind)
+        == Some(&TokenKind::Keyword(Keyword::Function)))
+}
+
 /// Function declaration parsing.
 ///
 /// More information:
@@ -74,21 +106,18 @@ where
 struct FunctionDeclaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    is_default: AllowDefault,
 }
 
 impl FunctionDeclaration {
     /// Creates a new `FunctionDeclaration` parser.
-    fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
-        D: Into<AllowDefault>,
     {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            is_default: is_default.into(),
         }
     }
 }
@@ -100,9 +129,11 @@ where
     type Output = FunctionDecl;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        cursor.expect(Keyword::Function, "function declaration")?;
+        let start = cursor
+            .expect(Keyword::Function, "function declaration")?
+            .span()
+            .start();
 
-        // TODO: If self.is_default, then this can be empty.
         let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         cursor.expect(Punctuator::OpenParen, "function declaration")?;
@@ -112,10 +143,126 @@ where
         cursor.expect(Punctuator::CloseParen, "function declaration")?;
         cursor.expect(Punctuator::OpenBlock, "function declaration")?;
 
-        let body = FunctionBody::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let body = FunctionBody::new(false, false).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseBlock, "function declaration")?;
 
-        Ok(FunctionDecl::new(name, params, body))
+        Ok(FunctionDecl::new(name, params, body).with_source(cursor.source_text(start)))
+    }
+}
+
+/// Generator declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function*
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorDeclaration
+#[derive(Debug, Clone, Copy)]
+struct GeneratorDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl GeneratorDeclaration {
+    /// Creates a new `GeneratorDeclaration` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for GeneratorDeclaration
+where
+    R: Read,
+{
+    type Output = GeneratorDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let start = cursor
+            .expect(Keyword::Function, "generator declaration")?
+            .span()
+            .start();
+        cursor.expect(Punctuator::Mul, "generator declaration")?;
+
+        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        cursor.expect(Punctuator::OpenParen, "generator declaration")?;
+
+        let params = FormalParameters::new(true, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "generator declaration")?;
+        cursor.expect(Punctuator::OpenBlock, "generator declaration")?;
+
+        let body = FunctionBody::new(true, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseBlock, "generator declaration")?;
+
+        Ok(GeneratorDecl::new(name, params, body).with_source(cursor.source_text(start)))
+    }
+}
+
+/// Async function declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionDeclaration
+#[derive(Debug, Clone, Copy)]
+struct AsyncFunctionDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl AsyncFunctionDeclaration {
+    /// Creates a new `AsyncFunctionDeclaration` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for AsyncFunctionDeclaration
+where
+    R: Read,
+{
+    type Output = AsyncFunctionDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let start = cursor
+            .expect(TokenKind::identifier("async"), "async function declaration")?
+            .span()
+            .start();
+        cursor.expect(Keyword::Function, "async function declaration")?;
+
+        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        cursor.expect(Punctuator::OpenParen, "async function declaration")?;
+
+        let params = FormalParameters::new(false, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "async function declaration")?;
+        cursor.expect(Punctuator::OpenBlock, "async function declaration")?;
+
+        let body = FunctionBody::new(false, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseBlock, "async function declaration")?;
+
+        Ok(AsyncFunctionDecl::new(name, params, body).with_source(cursor.source_text(start)))
     }
 }
@@ -11,13 +11,13 @@ use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
         ast::{
-            node::{ConstDecl, ConstDeclList, LetDecl, LetDeclList, Node},
+            node::{Binding, ConstDecl, ConstDeclList, LetDecl, LetDeclList, Node},
             Keyword, Punctuator,
         },
         parser::{
             cursor::{Cursor, SemicolonResult},
             expression::Initializer,
-            statement::BindingIdentifier,
+            statement::BindingTarget,
             AllowAwait, AllowIn, AllowYield, ParseError, ParseResult, TokenParser,
         },
     },
@@ -71,8 +71,9 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("LexicalDeclaration", "Parsing");
         let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
 
-        match tok.kind() {
+        let node = match tok.kind() {
             TokenKind::Keyword(Keyword::Const) => BindingList::new(
                 self.allow_in,
                 self.allow_yield,
@@ -90,7 +91,8 @@ where
             )
             .parse(cursor),
             _ => unreachable!("unknown token found: {:?}", tok),
-        }
+        }?;
+        Ok(node.with_default_span(cursor.span_from(start)))
     }
 }
 
@@ -151,14 +153,14 @@ where
         let mut const_decls = Vec::new();
 
         loop {
-            let (ident, init) =
+            let (binding, init) =
                 LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                     .parse(cursor)?;
 
             if self.is_const {
                 if self.const_init_required {
                     if init.is_some() {
-                        const_decls.push(ConstDecl::new(ident, init));
+                        const_decls.push(ConstDecl::new(binding, init));
                     } else {
                         return Err(ParseError::expected(
                             vec![TokenKind::Punctuator(Punctuator::Assign)],
@@ -167,10 +169,20 @@ where
                         ));
                     }
                 } else {
-                    const_decls.push(ConstDecl::new(ident, init))
+                    const_decls.push(ConstDecl::new(binding, init))
                 }
+            } else if self.const_init_required
+                && init.is_none()
+                && matches!(binding, Binding::Pattern(_))
+            {
+                // Destructuring declarations need a value, except in the head of a loop.
+                return Err(ParseError::expected(
+                    vec![TokenKind::Punctuator(Punctuator::Assign)],
+                    cursor.next()?.ok_or(ParseError::AbruptEnd)?,
+                    "lexical declaration",
+                ));
             } else {
-                let_decls.push(LetDecl::new(ident, init));
+                let_decls.push(LetDecl::new(binding, init));
             }
 
             match cursor.peek_semicolon()? {
@@ -240,12 +252,12 @@ impl<R> TokenParser<R> for LexicalBinding
 where
     R: Read,
 {
-    type Output = (Box<str>, Option<Node>);
+    type Output = (Binding, Option<Node>);
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("LexicalBinding", "Parsing");
 
-        let ident = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let binding = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
@@ -260,6 +272,6 @@ where
             None
         };
 
-        Ok((ident, init))
+        Ok((binding, init))
     }
 }
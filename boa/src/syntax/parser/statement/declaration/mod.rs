@@ -7,12 +7,14 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements#Declarations
 //! [spec]:https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
 
+mod class;
 mod hoistable;
 mod lexical;
 #[cfg(test)]
 mod tests;
 
-use self::{hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
+use self::{class::ClassDeclaration, hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
+pub(in crate::syntax::parser) use hoistable::is_async_function;
 
 use crate::syntax::lexer::TokenKind;
 use crate::{
@@ -61,10 +63,17 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("Declaration", "Parsing");
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
 
-        match tok.kind() {
-            TokenKind::Keyword(Keyword::Function) => {
-                HoistableDeclaration::new(self.allow_yield, self.allow_await, false).parse(cursor)
+        let node = match tok.kind() {
+            // `async function` declarations are detected by the `StatementListItem` parser.
+            TokenKind::Keyword(Keyword::Function) | TokenKind::Identifier(_) => {
+                HoistableDeclaration::new(self.allow_yield, self.allow_await).parse(cursor)
+            }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassDeclaration::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Node::from)
             }
             TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
                 LexicalDeclaration::new(
@@ -76,6 +85,7 @@ where
                 .parse(cursor)
             }
             _ => unreachable!("unknown token found: {:?}", tok),
-        }
+        }?;
+        Ok(node.with_default_span(cursor.span_from(start)))
     }
 }
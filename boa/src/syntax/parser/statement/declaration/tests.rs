@@ -1,8 +1,8 @@
 use crate::syntax::{
     ast::{
         node::{
-            ConstDecl, ConstDeclList, FunctionDecl, LetDecl, LetDeclList, Node, VarDecl,
-            VarDeclList,
+            AsyncFunctionDecl, AwaitExpr, ConstDecl, ConstDeclList, FunctionDecl, GeneratorDecl,
+            Identifier, LetDecl, LetDeclList, Node, VarDecl, VarDeclList, Yield,
         },
         Const,
     },
@@ -192,3 +192,85 @@ fn function_declaration_keywords() {
         vec![FunctionDecl::new(Box::from("await"), vec![], vec![]).into()],
     );
 }
+
+/// Generator declaration parsing.
+#[test]
+fn generator_declaration() {
+    check_parser(
+        "function* gen() { yield 1; yield; yield* a; }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![
+                Yield::new(Const::from(1), false).into(),
+                Yield::new::<Node, _>(None, false).into(),
+                Yield::new(Identifier::from("a"), true).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// `yield` is an identifier in the body of a function nested in a generator.
+#[test]
+fn generator_declaration_nested_function() {
+    check_parser(
+        "function* gen() { function f() { var yield = 1; } }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![FunctionDecl::new(
+                Box::from("f"),
+                vec![],
+                vec![
+                    VarDeclList::from(vec![VarDecl::new("yield", Some(Const::from(1).into()))])
+                        .into(),
+                ],
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// A `yield*` expression requires an argument.
+#[test]
+fn generator_declaration_delegate_without_argument() {
+    check_invalid("function* gen() { yield*; }");
+}
+
+/// Async function declaration parsing.
+#[test]
+fn async_function_declaration() {
+    check_parser(
+        "async function f() { var a = await b; }",
+        vec![AsyncFunctionDecl::new(
+            Box::from("f"),
+            vec![],
+            vec![VarDeclList::from(vec![VarDecl::new(
+                "a",
+                Some(AwaitExpr::new(Identifier::from("b")).into()),
+            )])
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// A line terminator after `async` makes it an identifier.
+#[test]
+fn async_function_declaration_line_terminator() {
+    check_parser(
+        "async\nfunction f() {}",
+        vec![
+            FunctionDecl::new(Box::from("f"), vec![], vec![]).into(),
+            Identifier::from("async").into(),
+        ],
+    );
+}
+
+/// `await` is not an identifier in the body of an async function.
+#[test]
+fn async_function_declaration_await_identifier() {
+    check_invalid("async function f() { var await = 1; }");
+}
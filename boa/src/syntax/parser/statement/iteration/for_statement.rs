@@ -11,11 +11,11 @@ use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
         ast::{
-            node::{ForLoop, ForOfLoop, Node},
+            node::{ForInLoop, ForLoop, ForOfLoop, Node, NodeKind},
             Const, Keyword, Punctuator,
         },
         parser::{
-            expression::Expression,
+            expression::{assignment_pattern, Expression},
             statement::declaration::Declaration,
             statement::{variable::VariableDeclarationList, Statement},
             AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
@@ -72,35 +72,54 @@ where
         cursor.expect(Keyword::For, "for statement")?;
         cursor.expect(Punctuator::OpenParen, "for statement")?;
 
+        let init_start = cursor.next_start()?;
         let init = match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
             TokenKind::Keyword(Keyword::Var) => {
                 let _ = cursor.next()?;
-                Some(
-                    VariableDeclarationList::new(false, self.allow_yield, self.allow_await)
-                        .parse(cursor)
-                        .map(Node::from)?,
-                )
+                let list = VariableDeclarationList::new(false, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                Some(Node::from(list).with_default_span(cursor.span_from(init_start)))
             }
             TokenKind::Keyword(Keyword::Let) | TokenKind::Keyword(Keyword::Const) => {
                 Some(Declaration::new(self.allow_yield, self.allow_await, false).parse(cursor)?)
             }
             TokenKind::Punctuator(Punctuator::Semicolon) => None,
-            _ => Some(Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?),
+            // The `in` operator is not allowed here, so that `for (a in b)` is a for...in loop.
+            _ => Some(Expression::new(false, self.allow_yield, self.allow_await).parse(cursor)?),
         };
 
-        // TODO: for..in, for..of
-        match cursor.peek(0)? {
-            Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::In) => {
-                unimplemented!("for...in statement")
-            }
-            Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::Of) && init.is_some() => {
+        match (init.as_ref(), cursor.peek(0)?) {
+            (Some(init), Some(tok))
+                if tok.kind() == &TokenKind::Keyword(Keyword::In)
+                    || tok.kind() == &TokenKind::Keyword(Keyword::Of) =>
+            {
+                let is_for_in = tok.kind() == &TokenKind::Keyword(Keyword::In);
+                let position = tok.span().start();
                 let _ = cursor.next();
-                let iterable =
+                let init = match init.kind() {
+                    NodeKind::ArrayDecl(_) | NodeKind::Object(_) => {
+                        let pattern = Node::from(assignment_pattern(init, position)?);
+                        match init.span() {
+                            Some(span) => pattern.with_default_span(span),
+                            None => pattern,
+                        }
+                    }
+                    _ => init.clone(),
+                };
+                let expr =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
-                cursor.expect(Punctuator::CloseParen, "for of statement")?;
+                if is_for_in {
+                    cursor.expect(Punctuator::CloseParen, "for in statement")?;
+                } else {
+                    cursor.expect(Punctuator::CloseParen, "for of statement")?;
+                }
                 let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)?;
-                return Ok(ForOfLoop::new(init.unwrap(), iterable, body).into());
+                return if is_for_in {
+                    Ok(ForInLoop::new(init, expr, body).into())
+                } else {
+                    Ok(ForOfLoop::new(init, expr, body).into())
+                };
             }
             _ => {}
         }
@@ -1,8 +1,8 @@
 use crate::syntax::{
     ast::{
         node::{
-            field::GetConstField, BinOp, Block, Break, Call, DoWhileLoop, Identifier, UnaryOp,
-            VarDecl, VarDeclList, WhileLoop,
+            field::GetConstField, BinOp, Block, Break, Call, DoWhileLoop, ForInLoop, ForLoop,
+            Identifier, Node, UnaryOp, VarDecl, VarDeclList, WhileLoop,
         },
         op::{self, AssignOp, CompOp},
         Const,
@@ -136,3 +136,36 @@ fn do_while_spaces() {
         .into()],
     );
 }
+
+/// Checks for...in statement parsing.
+#[test]
+fn check_for_in() {
+    check_parser(
+        "for (var key in obj) {}",
+        vec![ForInLoop::new(
+            VarDeclList::from(vec![VarDecl::new("key", None)]),
+            Identifier::from("obj"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+}
+
+/// Checks that the `in` operator can be used in the condition of a `for` statement.
+#[test]
+fn check_for_in_operator_in_condition() {
+    check_parser(
+        "for (; a in b;) {}",
+        vec![ForLoop::new(
+            None::<Node>,
+            Node::from(BinOp::new(
+                CompOp::In,
+                Identifier::from("a"),
+                Identifier::from("b"),
+            )),
+            None::<Node>,
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+}
@@ -2,7 +2,7 @@ use std::io::Read;
 
 use super::{LabelIdentifier, Statement};
 use crate::{
-    syntax::ast::Node,
+    syntax::ast::{Node, NodeKind},
     syntax::{
         ast::Punctuator,
         parser::{
@@ -60,7 +60,7 @@ where
 }
 
 fn set_label_for_node(stmt: &mut Node, name: Box<str>) {
-    if let Node::ForLoop(ref mut for_loop) = stmt {
+    if let NodeKind::ForLoop(ref mut for_loop) = stmt.kind_mut() {
         for_loop.set_label(name)
     }
 }
@@ -7,6 +7,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 //! [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-statements-and-declarations
 
+mod binding_pattern;
 mod block;
 mod break_stm;
 mod continue_stm;
@@ -25,7 +26,7 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::Declaration,
+    declaration::{is_async_function, Declaration},
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -38,6 +39,8 @@ use self::{
 
 use super::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
 
+pub(super) use self::binding_pattern::BindingTarget;
+
 use crate::{
     syntax::{
         ast::{node, Keyword, Node, Punctuator},
@@ -108,8 +111,9 @@ where
         let _timer = BoaProfiler::global().start_event("Statement", "Parsing");
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
 
-        match tok.kind() {
+        let node = match tok.kind() {
             TokenKind::Keyword(Keyword::If) => {
                 IfStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -197,7 +201,8 @@ where
             }
 
             _ => ExpressionStatement::new(self.allow_yield, self.allow_await).parse(cursor),
-        }
+        }?;
+        Ok(node.with_default_span(cursor.span_from(start)))
     }
 }
 
@@ -383,6 +388,10 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementListItem", "Parsing");
         let strict_mode = cursor.strict_mode();
+        if is_async_function(cursor)? {
+            return Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor);
+        }
+
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match *tok.kind() {
@@ -395,7 +404,9 @@ where
                 }
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
-            TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
+            TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Class) => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
             _ => {
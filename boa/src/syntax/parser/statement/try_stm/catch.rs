@@ -1,11 +1,11 @@
 use crate::{
     syntax::{
         ast::{
-            node::{self, Identifier},
+            node::{self, Binding},
             Keyword, Punctuator,
         },
         parser::{
-            statement::{block::Block, BindingIdentifier},
+            statement::{block::Block, BindingTarget},
             AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
@@ -64,7 +64,7 @@ where
         };
 
         // Catch block
-        Ok(node::Catch::new::<_, Identifier, _>(
+        Ok(node::Catch::new::<_, Binding, _>(
             catch_param,
             Block::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?,
         ))
@@ -103,12 +103,9 @@ impl<R> TokenParser<R> for CatchParameter
 where
     R: Read,
 {
-    type Output = Identifier;
+    type Output = Binding;
 
-    fn parse(self, cursor: &mut Cursor<R>) -> Result<Identifier, ParseError> {
-        // TODO: should accept BindingPattern
-        BindingIdentifier::new(self.allow_yield, self.allow_await)
-            .parse(cursor)
-            .map(Identifier::from)
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Binding, ParseError> {
+        BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)
     }
 }
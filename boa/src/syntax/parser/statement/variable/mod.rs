@@ -10,7 +10,7 @@ use crate::{
         parser::{
             cursor::{Cursor, SemicolonResult},
             expression::Initializer,
-            statement::BindingIdentifier,
+            statement::BindingTarget,
             AllowAwait, AllowIn, AllowYield, ParseError, TokenParser,
         },
     },
@@ -167,9 +167,7 @@ where
     type Output = VarDecl;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        // TODO: BindingPattern
-
-        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let binding = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
@@ -181,6 +179,6 @@ where
             None
         };
 
-        Ok(VarDecl::new(name, init))
+        Ok(VarDecl::new(binding, init))
     }
 }
@@ -4,11 +4,11 @@ use super::Parser;
 use crate::syntax::ast::{
     node::{
         field::GetConstField, ArrowFunctionDecl, Assign, BinOp, Call, FormalParameter,
-        FunctionDecl, Identifier, LetDecl, LetDeclList, New, Node, Return, StatementList, UnaryOp,
-        VarDecl, VarDeclList,
+        FunctionDecl, Identifier, LetDecl, LetDeclList, New, Node, NodeKind, Return, StatementList,
+        UnaryOp, VarDecl, VarDeclList,
     },
     op::{self, CompOp, LogOp, NumOp},
-    Const,
+    Const, Position, Span,
 };
 
 /// Checks that the given JavaScript string gives the expected expression.
@@ -292,3 +292,57 @@ fn spread_in_arrow_function() {
         ],
     );
 }
+
+/// Creates the span from `start` to `end`, given as line and column numbers.
+fn span(start: (u32, u32), end: (u32, u32)) -> Option<Span> {
+    Some(Span::new(
+        Position::new(start.0, start.1),
+        Position::new(end.0, end.1),
+    ))
+}
+
+#[test]
+fn node_spans() {
+    let statements = Parser::new("let a = 1;\nfoo(a + 2,\n    (bar).baz);".as_bytes())
+        .parse_all()
+        .expect("failed to parse");
+    let statements = statements.statements();
+
+    assert_eq!(statements[0].span(), span((1, 1), (1, 10)));
+    assert_eq!(statements[1].span(), span((2, 1), (3, 15)));
+
+    let call = match statements[1].kind() {
+        NodeKind::Call(call) => call,
+        kind => panic!("expected a call, got {:?}", kind),
+    };
+    assert_eq!(call.expr().span(), span((2, 1), (2, 4)));
+    assert_eq!(call.args()[0].span(), span((2, 5), (2, 10)));
+    assert_eq!(call.args()[1].span(), span((3, 5), (3, 14)));
+
+    let field = match call.args()[1].kind() {
+        NodeKind::GetConstField(field) => field,
+        kind => panic!("expected a property access, got {:?}", kind),
+    };
+    // A parenthesized expression keeps the span of the expression inside the parentheses.
+    assert_eq!(field.obj().span(), span((3, 6), (3, 9)));
+}
+
+#[test]
+fn node_spans_chain() {
+    let statements = Parser::new("a.b(c)[d] * 2".as_bytes())
+        .parse_all()
+        .expect("failed to parse");
+    let mul = match statements.statements()[0].kind() {
+        NodeKind::BinOp(mul) => mul,
+        kind => panic!("expected a binary operation, got {:?}", kind),
+    };
+    assert_eq!(statements.statements()[0].span(), span((1, 1), (1, 14)));
+
+    let field = match mul.lhs().kind() {
+        NodeKind::GetField(field) => field,
+        kind => panic!("expected a property access, got {:?}", kind),
+    };
+    assert_eq!(mul.lhs().span(), span((1, 1), (1, 10)));
+    assert_eq!(field.obj().span(), span((1, 1), (1, 7)));
+    assert_eq!(field.field().span(), span((1, 8), (1, 9)));
+}
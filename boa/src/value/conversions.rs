@@ -1,4 +1,5 @@
 use super::*;
+use crate::builtins::{function::ClosureFunction, Array};
 use std::convert::TryFrom;
 
 impl From<&Value> for Value {
@@ -40,7 +41,7 @@ impl From<&Box<str>> for Value {
 impl From<char> for Value {
     #[inline]
     fn from(value: char) -> Self {
-        Value::string(value.to_string())
+        Value::string(value)
     }
 }
 
@@ -104,7 +105,11 @@ impl From<RcBigInt> for Value {
 
 impl From<usize> for Value {
     fn from(value: usize) -> Value {
-        Value::integer(value as i32)
+        if let Ok(integer) = i32::try_from(value) {
+            Value::integer(integer)
+        } else {
+            Value::rational(value as f64)
+        }
     }
 }
 
@@ -186,3 +191,321 @@ where
         }
     }
 }
+
+/// Conversion of a JavaScript value into a Rust type.
+///
+/// Unlike the `to_*` methods of `Value`, no type coercion is done: the conversion throws a
+/// `TypeError` if the value does not have the expected type, like a string that is given where
+/// a number is expected.
+///
+/// It can be derived with `#[derive(TryFromJs)]` for structs, which are converted from and to objects
+/// (or arrays for tuple structs), and for enums with unit variants only, which are converted
+/// from and to strings. The name of a property or variant can be changed with the
+/// `#[boa(rename = "name")]` attribute.
+pub trait TryFromJs: Sized {
+    /// Converts the value to this type, or throws a `TypeError`.
+    fn try_from_js(value: &Value, ctx: &mut Context) -> Result<Self>;
+}
+
+/// Conversion of a Rust type into a JavaScript value.
+///
+/// It can be derived with `#[derive(IntoJs)]` for structs, which are converted from and to objects
+/// (or arrays for tuple structs), and for enums with unit variants only, which are converted
+/// from and to strings. The name of a property or variant can be changed with the
+/// `#[boa(rename = "name")]` attribute.
+pub trait IntoJs {
+    /// Converts this type to a value.
+    fn into_js(self, ctx: &mut Context) -> Value;
+}
+
+/// Conversion of the arguments of a function call into a tuple of Rust types.
+///
+/// Missing arguments are `undefined`, and extra arguments are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use boa::{Context, Value, value::TryFromJsArgs};
+/// let mut context = Context::new();
+/// let args = [Value::from("John"), Value::from(19)];
+/// let (name, age): (String, u32) = TryFromJsArgs::try_from_js_args(&args, &mut context).unwrap();
+/// assert_eq!(name, "John");
+/// assert_eq!(age, 19);
+/// ```
+pub trait TryFromJsArgs: Sized {
+    /// Converts the arguments to this type, or throws a `TypeError`.
+    fn try_from_js_args(args: &[Value], ctx: &mut Context) -> Result<Self>;
+}
+
+/// Creates a native function from a closure with typed arguments and return value.
+///
+/// The arguments are converted with [`TryFromJsArgs`](trait.TryFromJsArgs.html), throwing a
+/// `TypeError` if they do not have the expected types.
+///
+/// # Examples
+///
+/// ```
+/// # use boa::{Context, value::typed_function};
+/// let mut context = Context::new();
+/// context
+///     .register_global_function(
+///         "add",
+///         2,
+///         typed_function(|_, (a, b): (f64, f64), _| Ok(a + b)),
+///     )
+///     .unwrap();
+/// ```
+pub fn typed_function<F, A, R>(function: F) -> impl ClosureFunction
+where
+    F: Fn(&Value, A, &mut Context) -> Result<R> + 'static,
+    A: TryFromJsArgs,
+    R: IntoJs,
+{
+    move |this, args, ctx| {
+        let args = A::try_from_js_args(args, ctx)?;
+        let result = function(this, args, ctx)?;
+        Ok(result.into_js(ctx))
+    }
+}
+
+/// Returns the name of the type of a value, for error messages.
+fn type_name(value: &Value) -> &'static str {
+    if value.is_null() {
+        "null"
+    } else {
+        value.get_type().as_str()
+    }
+}
+
+impl TryFromJs for Value {
+    #[inline]
+    fn try_from_js(value: &Value, _: &mut Context) -> Result<Self> {
+        Ok(value.clone())
+    }
+}
+
+impl TryFromJs for bool {
+    fn try_from_js(value: &Value, ctx: &mut Context) -> Result<Self> {
+        match value {
+            Value::Boolean(boolean) => Ok(*boolean),
+            _ => Err(ctx.construct_type_error(format!(
+                "cannot convert value to `bool`: expected a boolean, got {}",
+                type_name(value)
+            ))),
+        }
+    }
+}
+
+impl TryFromJs for RcString {
+    fn try_from_js(value: &Value, ctx: &mut Context) -> Result<Self> {
+        match value {
+            Value::String(ref string) => Ok(string.clone()),
+            _ => Err(ctx.construct_type_error(format!(
+                "cannot convert value to a string: expected a string, got {}",
+                type_name(value)
+            ))),
+        }
+    }
+}
+
+impl TryFromJs for String {
+    #[inline]
+    fn try_from_js(value: &Value, ctx: &mut Context) -> Result<Self> {
+        let string = RcString::try_from_js(value, ctx)?;
+        string.to_std_string().map_err(|_| {
+            ctx.construct_type_error(
+                "cannot convert value to a string: the string contains unpaired surrogates",
+            )
+        })
+    }
+}
+
+impl TryFromJs for f64 {
+    fn try_from_js(value: &Value, ctx: &mut Context) -> Result<Self> {
+        match value.as_number() {
+            Some(number) => Ok(number),
+            None => Err(ctx.construct_type_error(format!(
+                "cannot convert value to `f64`: expected a number, got {}",
+                type_name(value)
+            ))),
+        }
+    }
+}
+
+macro_rules! impl_try_from_js_for_integer {
+    ($($ty:ident),*) => {
+        $(
+            impl TryFromJs for $ty {
+                fn try_from_js(value: &Value, ctx: &mut Context) -> Result<Self> {
+                    let number = f64::try_from_js(value, ctx)?;
+                    // `MAX + 1` is a power of two, so it is exact even when `MAX` itself isn't.
+                    if number.fract() == 0.0
+                        && number >= $ty::MIN as f64
+                        && number < $ty::MAX as f64 + 1.0
+                    {
+                        Ok(number as $ty)
+                    } else {
+                        Err(ctx.construct_type_error(format!(
+                            concat!(
+                                "cannot convert value to `",
+                                stringify!($ty),
+                                "`: {} is not an integer in range"
+                            ),
+                            number
+                        )))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_js_for_integer!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl TryFromJs for GcObject {
+    fn try_from_js(value: &Value, ctx: &mut Context) -> Result<Self> {
+        match value.as_gc_object() {
+            Some(object) => Ok(object),
+            None => Err(ctx.construct_type_error(format!(
+                "cannot convert value to an object: expected an object, got {}",
+                type_name(value)
+            ))),
+        }
+    }
+}
+
+impl<T> TryFromJs for Option<T>
+where
+    T: TryFromJs,
+{
+    /// `undefined` and `null` are converted to `None`.
+    fn try_from_js(value: &Value, ctx: &mut Context) -> Result<Self> {
+        if value.is_null_or_undefined() {
+            Ok(None)
+        } else {
+            T::try_from_js(value, ctx).map(Some)
+        }
+    }
+}
+
+impl<T> TryFromJs for Vec<T>
+where
+    T: TryFromJs,
+{
+    fn try_from_js(value: &Value, ctx: &mut Context) -> Result<Self> {
+        let is_array = match value {
+            Value::Object(ref object) => object.borrow().is_array(),
+            _ => false,
+        };
+        if !is_array {
+            return Err(ctx.construct_type_error(format!(
+                "cannot convert value to a `Vec`: expected an array, got {}",
+                type_name(value)
+            )));
+        }
+
+        let length = value.get_field("length", ctx)?.to_length(ctx)?;
+        let mut elements = Vec::with_capacity(length);
+        for index in 0..length {
+            let element = value.get_field(index, ctx)?;
+            elements.push(T::try_from_js(&element, ctx)?);
+        }
+        Ok(elements)
+    }
+}
+
+macro_rules! impl_try_from_js_args_for_tuple {
+    ($($ty:ident),*) => {
+        impl<$($ty),*> TryFromJsArgs for ($($ty,)*)
+        where
+            $($ty: TryFromJs,)*
+        {
+            #[allow(unused_variables, unused_mut)]
+            fn try_from_js_args(args: &[Value], ctx: &mut Context) -> Result<Self> {
+                let mut args = args.iter();
+                Ok(($(
+                    $ty::try_from_js(&args.next().cloned().unwrap_or_default(), ctx)?,
+                )*))
+            }
+        }
+    };
+}
+
+impl_try_from_js_args_for_tuple!();
+impl_try_from_js_args_for_tuple!(A);
+impl_try_from_js_args_for_tuple!(A, B);
+impl_try_from_js_args_for_tuple!(A, B, C);
+impl_try_from_js_args_for_tuple!(A, B, C, D);
+impl_try_from_js_args_for_tuple!(A, B, C, D, E);
+impl_try_from_js_args_for_tuple!(A, B, C, D, E, F);
+impl_try_from_js_args_for_tuple!(A, B, C, D, E, F, G);
+impl_try_from_js_args_for_tuple!(A, B, C, D, E, F, G, H);
+
+impl IntoJs for Value {
+    #[inline]
+    fn into_js(self, _: &mut Context) -> Value {
+        self
+    }
+}
+
+macro_rules! impl_into_js_with_from {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoJs for $ty {
+                #[inline]
+                fn into_js(self, _: &mut Context) -> Value {
+                    Value::from(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_js_with_from!(bool, f64, i32, u32, String, &str, RcString, GcObject);
+
+macro_rules! impl_into_js_for_number {
+    ($($ty:ident),*) => {
+        $(
+            impl IntoJs for $ty {
+                #[inline]
+                fn into_js(self, _: &mut Context) -> Value {
+                    Value::from(self as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_js_for_number!(i8, i16, i64, u8, u16, u64, usize, f32);
+
+impl IntoJs for () {
+    /// The unit type is converted to `undefined`.
+    #[inline]
+    fn into_js(self, _: &mut Context) -> Value {
+        Value::undefined()
+    }
+}
+
+impl<T> IntoJs for Option<T>
+where
+    T: IntoJs,
+{
+    /// `None` is converted to `undefined`.
+    fn into_js(self, ctx: &mut Context) -> Value {
+        match self {
+            Some(value) => value.into_js(ctx),
+            None => Value::undefined(),
+        }
+    }
+}
+
+impl<T> IntoJs for Vec<T>
+where
+    T: IntoJs,
+{
+    /// A `Vec` is converted to an array.
+    fn into_js(self, ctx: &mut Context) -> Value {
+        let elements: Vec<Value> = self.into_iter().map(|value| value.into_js(ctx)).collect();
+        Array::create_array_from_list(elements, ctx)
+    }
+}
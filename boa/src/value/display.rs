@@ -1,4 +1,5 @@
 use super::*;
+use crate::{builtins::promise::PromiseState, property::PropertyDescriptor};
 
 /// This object is used for displaying a `Value`.
 #[derive(Debug, Clone, Copy)]
@@ -48,16 +49,23 @@ macro_rules! print_obj_value {
     };
     (props of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr, $print_internals:expr) => {
         print_obj_value!(impl $obj, |(key, val)| {
-            let v = &val
-                // FIXME: handle accessor descriptors
-                .as_data_descriptor()
-                .unwrap()
-                .value();
+            let v = match val {
+                PropertyDescriptor::Data(ref data) => {
+                    $display_fn(&data.value(), $encounters, $indent.wrapping_add(4), $print_internals)
+                }
+                PropertyDescriptor::Accessor(ref accessor) => {
+                    match (accessor.getter().is_some(), accessor.setter().is_some()) {
+                        (true, true) => String::from("[Getter/Setter]"),
+                        (true, false) => String::from("[Getter]"),
+                        _ => String::from("[Setter]"),
+                    }
+                }
+            };
 
             format!(
                 "{:>width$}: {}",
                 key,
-                $display_fn(v, $encounters, $indent.wrapping_add(4), $print_internals),
+                v,
                 width = $indent,
             )
         })
@@ -111,20 +119,18 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
 
                         let arr = (0..len)
                             .map(|i| {
-                                // Introduce recursive call to stringify any objects
-                                // which are part of the Array
-                                log_string_from(
-                                    &v.borrow()
-                                        .get_own_property(&i.into())
-                                        // TODO: do this in a better way "unwrap"
-                                        .unwrap()
-                                        // FIXME: handle accessor descriptors
-                                        .as_data_descriptor()
-                                        .unwrap()
-                                        .value(),
-                                    print_internals,
-                                    false,
-                                )
+                                let element = v.borrow().get_own_property(&i.into());
+                                match element {
+                                    // Introduce recursive call to stringify any objects
+                                    // which are part of the Array
+                                    Some(PropertyDescriptor::Data(ref data)) => {
+                                        log_string_from(&data.value(), print_internals, false)
+                                    }
+                                    Some(PropertyDescriptor::Accessor(_)) => {
+                                        String::from("[Accessor]")
+                                    }
+                                    None => String::from("<empty>"),
+                                }
                             })
                             .collect::<Vec<String>>()
                             .join(", ");
@@ -165,6 +171,49 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
                         format!("Map({})", size)
                     }
                 }
+                ObjectData::Set(ref set) => {
+                    if set.is_empty() {
+                        return String::from("Set(0)");
+                    }
+
+                    if print_children {
+                        let values = set
+                            .iter()
+                            .map(|value| log_string_from(value, print_internals, false))
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        format!("Set {{ {} }}", values)
+                    } else {
+                        format!("Set({})", set.len())
+                    }
+                }
+                ObjectData::TypedArray(ref array) => {
+                    let name = array.kind().name();
+                    let len = array.length();
+                    if print_children && len > 0 {
+                        let values = (0..len)
+                            .map(|index| {
+                                let value = array.get_element(index as f64).unwrap_or_default();
+                                log_string_from(&value, print_internals, false)
+                            })
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        format!("{}({}) [ {} ]", name, len, values)
+                    } else {
+                        format!("{}({})", name, len)
+                    }
+                }
+                ObjectData::Promise(ref promise) => match promise.state() {
+                    PromiseState::Pending => String::from("Promise { <pending> }"),
+                    PromiseState::Fulfilled(value) => format!(
+                        "Promise {{ {} }}",
+                        log_string_from(value, print_internals, false)
+                    ),
+                    PromiseState::Rejected(reason) => format!(
+                        "Promise {{ <rejected> {} }}",
+                        log_string_from(reason, print_internals, false)
+                    ),
+                },
                 _ => display_obj(&x, print_internals),
             }
         }
@@ -188,8 +237,8 @@ pub(crate) fn display_obj(v: &Value, print_internals: bool) -> String {
 
     if let Value::Object(object) = v {
         if object.borrow().is_error() {
-            let name = v.get_field("name");
-            let message = v.get_field("message");
+            let name = v.get_data_field("name");
+            let message = v.get_data_field("message");
             return format!("{}: {}", name.display(), message.display());
         }
     }
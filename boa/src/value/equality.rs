@@ -68,16 +68,20 @@ impl Value {
             //    a. Let n be ! StringToBigInt(y).
             //    b. If n is NaN, return false.
             //    c. Return the result of the comparison x == n.
-            (Self::BigInt(ref a), Self::String(ref b)) => match string_to_bigint(b) {
-                Some(ref b) => a.as_inner() == b,
-                None => false,
-            },
+            (Self::BigInt(ref a), Self::String(ref b)) => {
+                match string_to_bigint(&b.to_std_string_lossy()) {
+                    Some(ref b) => a.as_inner() == b,
+                    None => false,
+                }
+            }
 
             // 7. If Type(x) is String and Type(y) is BigInt, return the result of the comparison y == x.
-            (Self::String(ref a), Self::BigInt(ref b)) => match string_to_bigint(a) {
-                Some(ref a) => a == b.as_inner(),
-                None => false,
-            },
+            (Self::String(ref a), Self::BigInt(ref b)) => {
+                match string_to_bigint(&a.to_std_string_lossy()) {
+                    Some(ref a) => a == b.as_inner(),
+                    None => false,
+                }
+            }
 
             // 8. If Type(x) is Boolean, return the result of the comparison ! ToNumber(x) == y.
             (Self::Boolean(x), _) => return other.equals(&Value::from(*x as i32), interpreter),
@@ -190,6 +194,7 @@ fn same_value_non_numeric(x: &Value, y: &Value) -> bool {
     match (x, y) {
         (Value::Null, Value::Null) | (Value::Undefined, Value::Undefined) => true,
         (Value::String(ref x), Value::String(ref y)) => x == y,
+        (Value::Symbol(ref x), Value::Symbol(ref y)) => x == y,
         (Value::Boolean(x), Value::Boolean(y)) => x == y,
         (Value::Object(ref x), Value::Object(ref y)) => GcObject::equals(x, y),
         _ => false,
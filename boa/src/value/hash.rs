@@ -32,7 +32,11 @@ impl Eq for RationalHashable {}
 impl Hash for RationalHashable {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_bits().hash(state);
+        if self.0.is_nan() {
+            f64::NAN.to_bits().hash(state);
+        } else {
+            self.0.to_bits().hash(state);
+        }
     }
 }
 
@@ -43,8 +47,11 @@ impl Hash for Value {
             Self::Null => NullHashable.hash(state),
             Self::String(ref string) => string.hash(state),
             Self::Boolean(boolean) => boolean.hash(state),
-            Self::Integer(integer) => integer.hash(state),
+            // Numbers that are equal under `SameValueZero` must hash the same,
+            // whatever their representation.
+            Self::Integer(integer) => RationalHashable(f64::from(*integer)).hash(state),
             Self::BigInt(ref bigint) => bigint.hash(state),
+            Self::Rational(rational) if *rational == 0.0 => RationalHashable(0.0).hash(state),
             Self::Rational(rational) => RationalHashable(*rational).hash(state),
             Self::Symbol(ref symbol) => Hash::hash(symbol, state),
             Self::Object(ref object) => std::ptr::hash(object.as_ref(), state),
@@ -32,8 +32,11 @@ mod operations;
 mod rcbigint;
 mod rcstring;
 mod rcsymbol;
+#[cfg(feature = "serde")]
+pub mod serde_value;
 mod r#type;
 
+pub use boa_derive::{IntoJs, TryFromJs};
 pub use conversions::*;
 pub use display::ValueDisplay;
 pub use equality::*;
@@ -41,8 +44,11 @@ pub use hash::*;
 pub use operations::*;
 pub use r#type::Type;
 pub use rcbigint::RcBigInt;
-pub use rcstring::RcString;
+pub(crate) use rcstring::{is_leading_surrogate, is_trailing_surrogate};
+pub use rcstring::{CodeUnits, RcString};
 pub use rcsymbol::RcSymbol;
+#[cfg(feature = "serde")]
+pub use serde_value::{from_value, to_value, SerdeError};
 
 /// A Javascript value
 #[derive(Trace, Finalize, Debug, Clone)]
@@ -154,7 +160,7 @@ impl Value {
         let _timer = BoaProfiler::global().start_event("new_object", "value");
 
         if let Some(global) = global {
-            let object_prototype = global.get_field("Object").get_field(PROTOTYPE);
+            let object_prototype = global.get_data_field("Object").get_data_field(PROTOTYPE);
 
             let object = Object::create(object_prototype);
             Self::object(object)
@@ -216,7 +222,7 @@ impl Value {
 
     /// Converts the `Value` to `JSON`.
     pub fn to_json(&self, interpreter: &mut Context) -> Result<JSONValue> {
-        let to_json = self.get_field("toJSON");
+        let to_json = self.get_field("toJSON", interpreter)?;
         if to_json.is_function() {
             let json_value = interpreter.call(&to_json, self, &[])?;
             return json_value.to_json(interpreter);
@@ -462,21 +468,36 @@ impl Value {
         }
     }
 
-    /// Resolve the property in the object and get its value, or undefined if this is not an object or the field doesn't exist
-    /// get_field recieves a Property from get_prop(). It should then return the [[Get]] result value if that's set, otherwise fall back to [[Value]]
-    /// TODO: this function should use the get Value if its set
-    pub fn get_field<K>(&self, key: K) -> Self
+    /// Gets the value of a property of this value, calling its getter if it is an accessor.
+    ///
+    /// Returns undefined if this is not an object or the property doesn't exist.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-o-p
+    pub fn get_field<K>(&self, key: K, ctx: &mut Context) -> Result<Self>
     where
         K: Into<PropertyKey>,
     {
         let _timer = BoaProfiler::global().start_event("Value::get_field", "value");
-        let key = key.into();
+        match self {
+            Self::Object(ref obj) => obj.get(&key.into(), self, ctx),
+            _ => Ok(Value::undefined()),
+        }
+    }
+
+    /// Gets the value of a data property of this value, without calling getters.
+    ///
+    /// Returns undefined if the property doesn't exist or is an accessor. This is used where
+    /// running code is not possible, like when displaying a value.
+    pub(crate) fn get_data_field<K>(&self, key: K) -> Self
+    where
+        K: Into<PropertyKey>,
+    {
         match self.get_property(key) {
-            Some(ref desc) => match desc {
-                PropertyDescriptor::Accessor(_) => todo!(),
-                PropertyDescriptor::Data(desc) => desc.value(),
-            },
-            None => Value::undefined(),
+            Some(PropertyDescriptor::Data(ref data)) => data.value(),
+            _ => Value::undefined(),
         }
     }
 
@@ -492,9 +513,14 @@ impl Value {
             .unwrap_or(false)
     }
 
-    /// Set the field in the value
+    /// Sets the value of a property of this value, calling its setter if it is an accessor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-o-p-v-throw
     #[inline]
-    pub fn set_field<K, V>(&self, key: K, value: V) -> Value
+    pub fn set_field<K, V>(&self, key: K, value: V, ctx: &mut Context) -> Result<Value>
     where
         K: Into<PropertyKey>,
         V: Into<Value>,
@@ -503,17 +529,9 @@ impl Value {
         let value = value.into();
         let _timer = BoaProfiler::global().start_event("Value::set_field", "value");
         if let Self::Object(ref obj) = *self {
-            if let PropertyKey::Index(index) = key {
-                if obj.borrow().is_array() {
-                    let len = self.get_field("length").as_number().unwrap() as u32;
-                    if len < index + 1 {
-                        self.set_field("length", index + 1);
-                    }
-                }
-            }
-            obj.borrow_mut().set(key, value.clone());
+            obj.set(key, value.clone(), self, ctx)?;
         }
-        value
+        Ok(value)
     }
 
     /// Set the kind of an object.
@@ -569,7 +587,10 @@ impl Value {
             Value::Undefined => {
                 Err(ctx.construct_type_error("cannot convert undefined to a BigInt"))
             }
-            Value::String(ref string) => Ok(RcBigInt::from(BigInt::from_string(string, ctx)?)),
+            Value::String(ref string) => Ok(RcBigInt::from(BigInt::from_string(
+                &string.to_std_string_lossy(),
+                ctx,
+            )?)),
             Value::Boolean(true) => Ok(RcBigInt::from(BigInt::from(1))),
             Value::Boolean(false) => Ok(RcBigInt::from(BigInt::from(0))),
             Value::Integer(num) => Ok(RcBigInt::from(BigInt::from(*num))),
@@ -664,7 +685,11 @@ impl Value {
                 let mut object =
                     Object::with_prototype(prototype.into(), ObjectData::String(string.clone()));
                 // Make sure the correct length is set on our new string object
-                object.set("length".into(), string.chars().count().into());
+                object.insert_property(
+                    "length",
+                    string.len(),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+                );
                 Ok(GcObject::new(object))
             }
             Value::Symbol(ref symbol) => {
@@ -812,6 +837,11 @@ impl Value {
             Value::Boolean(b) => Ok(if b { 1.0 } else { 0.0 }),
             // TODO: this is probably not 100% correct, see https://tc39.es/ecma262/#sec-tonumber-applied-to-the-string-type
             Value::String(ref string) => {
+                // Unpaired surrogates can't be part of a numeric literal.
+                let string = match string.to_std_string() {
+                    Ok(string) => string,
+                    Err(_) => return Ok(f64::NAN),
+                };
                 if string.trim().is_empty() {
                     return Ok(0.0);
                 }
@@ -861,6 +891,46 @@ impl Value {
         }
     }
 
+    /// Converts a relative position, like the `start` and `end` arguments of `slice`, to an index
+    /// between `0` and `len`.
+    ///
+    /// Negative positions count back from `len`, and `undefined` gives `default`.
+    pub(crate) fn to_relative_index(
+        &self,
+        len: usize,
+        default: usize,
+        ctx: &mut Context,
+    ) -> Result<usize> {
+        if self.is_undefined() {
+            return Ok(default);
+        }
+        let relative = self.to_integer(ctx)?;
+        let len = len as f64;
+        Ok(if relative < 0.0 {
+            (len + relative).max(0.0)
+        } else {
+            relative.min(len)
+        } as usize)
+    }
+
+    /// Creates a list holding the elements of an array-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createlistfromarraylike
+    pub(crate) fn create_list_from_array_like(&self, ctx: &mut Context) -> Result<Vec<Value>> {
+        if !self.is_object() {
+            return Err(ctx.construct_type_error("CreateListFromArrayLike called on non-object"));
+        }
+        let length = self.get_field("length", ctx)?.to_length(ctx)?;
+        let mut list = Vec::with_capacity(length);
+        for index in 0..length {
+            list.push(self.get_field(index, ctx)?);
+        }
+        Ok(list)
+    }
+
     #[inline]
     pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PropertyDescriptor> {
         if let Self::Object(ref object) = self {
@@ -11,9 +11,9 @@ impl Value {
             (Self::Integer(x), Self::Rational(y)) => Self::rational(f64::from(*x) + y),
             (Self::Rational(x), Self::Integer(y)) => Self::rational(x + f64::from(*y)),
 
-            (Self::String(ref x), Self::String(ref y)) => Self::string(format!("{}{}", x, y)),
-            (Self::String(ref x), ref y) => Self::string(format!("{}{}", x, y.to_string(ctx)?)),
-            (ref x, Self::String(ref y)) => Self::string(format!("{}{}", x.to_string(ctx)?, y)),
+            (Self::String(ref x), Self::String(ref y)) => Self::string(x.concat(y)),
+            (Self::String(ref x), ref y) => Self::string(x.concat(&y.to_string(ctx)?)),
+            (ref x, Self::String(ref y)) => Self::string(x.to_string(ctx)?.concat(y)),
             (Self::BigInt(ref n1), Self::BigInt(ref n2)) => {
                 Self::bigint(n1.as_inner().clone() + n2.as_inner().clone())
             }
@@ -23,8 +23,8 @@ impl Value {
                 self.to_primitive(ctx, PreferredType::Default)?,
                 other.to_primitive(ctx, PreferredType::Default)?,
             ) {
-                (Self::String(ref x), ref y) => Self::string(format!("{}{}", x, y.to_string(ctx)?)),
-                (ref x, Self::String(ref y)) => Self::string(format!("{}{}", x.to_string(ctx)?, y)),
+                (Self::String(ref x), ref y) => Self::string(x.concat(&y.to_string(ctx)?)),
+                (ref x, Self::String(ref y)) => Self::string(x.to_string(ctx)?.concat(y)),
                 (x, y) => match (x.to_numeric(ctx)?, y.to_numeric(ctx)?) {
                     (Numeric::Number(x), Numeric::Number(y)) => Self::rational(x + y),
                     (Numeric::BigInt(ref n1), Numeric::BigInt(ref n2)) => {
@@ -40,6 +40,47 @@ impl Value {
         })
     }
 
+    /// Checks if this value is an instance of `target`, as done by the `instanceof` operator.
+    ///
+    /// The `Symbol.hasInstance` method of `target` is used if it has one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-instanceofoperator
+    pub fn instance_of(&self, target: &Value, ctx: &mut Context) -> Result<bool> {
+        let target = match target {
+            Value::Object(ref target) => target,
+            _ => {
+                return Err(ctx.construct_type_error(format!(
+                    "right-hand side of 'instanceof' should be an object, got {}",
+                    target.get_type().as_str()
+                )))
+            }
+        };
+
+        let has_instance = ctx.well_known_symbols().has_instance_symbol();
+        let handler = target.get(&has_instance.into(), &target.clone().into(), ctx)?;
+        match handler {
+            Value::Undefined | Value::Null => {}
+            Value::Object(ref handler) if handler.is_callable() => {
+                return Ok(handler
+                    .call(&target.clone().into(), std::slice::from_ref(self), ctx)?
+                    .to_boolean());
+            }
+            _ => {
+                return Err(ctx.construct_type_error(
+                    "right-hand side of 'instanceof' has a non-callable Symbol.hasInstance",
+                ))
+            }
+        }
+
+        if !target.borrow().is_function() {
+            return Err(ctx.construct_type_error("right-hand side of 'instanceof' is not callable"));
+        }
+        target.ordinary_has_instance(self, ctx)
+    }
+
     #[inline]
     pub fn sub(&self, other: &Self, ctx: &mut Context) -> Result<Value> {
         Ok(match (self, other) {
@@ -395,10 +436,12 @@ impl Value {
                 Ok(num) => -num,
                 Err(_) => NAN,
             }),
-            Self::String(ref str) => Self::rational(match f64::from_str(str) {
-                Ok(num) => -num,
-                Err(_) => NAN,
-            }),
+            Self::String(ref str) => {
+                Self::rational(match f64::from_str(&str.to_std_string_lossy()) {
+                    Ok(num) => -num,
+                    Err(_) => NAN,
+                })
+            }
             Self::Rational(num) => Self::rational(-num),
             Self::Integer(num) => Self::rational(-f64::from(num)),
             Self::Boolean(true) => Self::integer(1),
@@ -457,29 +500,18 @@ impl Value {
                 };
 
                 match (px, py) {
-                    (Self::String(ref x), Self::String(ref y)) => {
-                        if x.starts_with(y.as_str()) {
-                            return Ok(AbstractRelation::False);
-                        }
-                        if y.starts_with(x.as_str()) {
-                            return Ok(AbstractRelation::True);
-                        }
-                        for (x, y) in x.chars().zip(y.chars()) {
-                            if x != y {
-                                return Ok((x < y).into());
-                            }
-                        }
-                        unreachable!()
-                    }
+                    // Strings are compared by their code units, and a prefix is less than the
+                    // whole string.
+                    (Self::String(ref x), Self::String(ref y)) => (x < y).into(),
                     (Self::BigInt(ref x), Self::String(ref y)) => {
-                        if let Some(y) = string_to_bigint(&y) {
+                        if let Some(y) = string_to_bigint(&y.to_std_string_lossy()) {
                             (*x.as_inner() < y).into()
                         } else {
                             AbstractRelation::Undefined
                         }
                     }
                     (Self::String(ref x), Self::BigInt(ref y)) => {
-                        if let Some(x) = string_to_bigint(&x) {
+                        if let Some(x) = string_to_bigint(&x.to_std_string_lossy()) {
                             (x < *y.as_inner()).into()
                         } else {
                             AbstractRelation::Undefined
@@ -1,112 +1,458 @@
-use std::borrow::Borrow;
-use std::fmt::{self, Display};
-use std::ops::Deref;
-use std::rc::Rc;
+//! This module implements `RcString`, the string type of JavaScript values.
+//!
+//! A JavaScript string is a sequence of UTF-16 code units that doesn't have to be valid UTF-16,
+//! since it can contain unpaired surrogates, so it can't be stored in a Rust `str`. Most strings
+//! only contain code units below `0x100` though, and those are stored in Latin-1, using a single
+//! byte per code unit. Every other string is stored in UTF-16.
+//!
+//! Converting an `RcString` to a Rust `String` has to deal with unpaired surrogates, either by
+//! failing with [`RcString::to_std_string`] or by replacing them with [`RcString::to_std_string_lossy`].
+
+use std::{
+    char::{decode_utf16, DecodeUtf16, REPLACEMENT_CHARACTER},
+    cmp::Ordering,
+    fmt::{self, Display, Write},
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    rc::Rc,
+    slice,
+    string::FromUtf16Error,
+};
 
 use gc::{unsafe_empty_trace, Finalize, Trace};
 
-#[derive(Debug, Finalize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct RcString(Rc<str>);
+/// A reference counted, immutable JavaScript string.
+#[derive(Clone, Finalize)]
+pub struct RcString(Inner);
+
+/// The storage of an `RcString`.
+///
+/// The representation is canonical: a string is stored in Latin-1 if and only if all its code
+/// units are below `0x100`. This way, equal strings always have the same representation.
+#[derive(Clone)]
+enum Inner {
+    Latin1(Rc<[u8]>),
+    Utf16(Rc<[u16]>),
+}
 
 unsafe impl Trace for RcString {
     unsafe_empty_trace!();
 }
 
 impl RcString {
+    /// Creates a string from UTF-16 code units, which may contain unpaired surrogates.
+    pub fn from_utf16(code_units: &[u16]) -> Self {
+        if code_units.iter().all(|unit| *unit < 0x100) {
+            Self(Inner::Latin1(
+                code_units.iter().map(|unit| *unit as u8).collect(),
+            ))
+        } else {
+            Self(Inner::Utf16(Rc::from(code_units)))
+        }
+    }
+
+    /// The length of the string, in UTF-16 code units.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self.0 {
+            Inner::Latin1(ref bytes) => bytes.len(),
+            Inner::Utf16(ref units) => units.len(),
+        }
+    }
+
+    /// Returns `true` if the string has no code units.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the UTF-16 code unit at the given index.
+    #[inline]
+    pub fn code_unit_at(&self, index: usize) -> Option<u16> {
+        match self.0 {
+            Inner::Latin1(ref bytes) => bytes.get(index).map(|byte| u16::from(*byte)),
+            Inner::Utf16(ref units) => units.get(index).copied(),
+        }
+    }
+
+    /// An iterator over the UTF-16 code units of the string.
+    #[inline]
+    pub fn code_units(&self) -> CodeUnits<'_> {
+        match self.0 {
+            Inner::Latin1(ref bytes) => CodeUnits::Latin1(bytes.iter()),
+            Inner::Utf16(ref units) => CodeUnits::Utf16(units.iter()),
+        }
+    }
+
+    /// An iterator over the code points of the string.
+    ///
+    /// Unpaired surrogates are returned as errors.
     #[inline]
-    pub fn as_str(&self) -> &str {
-        &self.0
+    pub fn code_points(&self) -> DecodeUtf16<CodeUnits<'_>> {
+        decode_utf16(self.code_units())
+    }
+
+    /// Gets the code point starting at the given code unit index.
+    ///
+    /// Returns the code point, the amount of code units it takes and whether it is an unpaired surrogate.
+    pub fn code_point_at(&self, index: usize) -> Option<(u32, usize, bool)> {
+        let first = self.code_unit_at(index)?;
+        if !is_leading_surrogate(first) && !is_trailing_surrogate(first) {
+            return Some((u32::from(first), 1, false));
+        }
+        match self.code_unit_at(index + 1) {
+            Some(second) if is_leading_surrogate(first) && is_trailing_surrogate(second) => {
+                let code_point =
+                    (u32::from(first) - 0xD800) * 0x400 + (u32::from(second) - 0xDC00) + 0x10000;
+                Some((code_point, 2, false))
+            }
+            _ => Some((u32::from(first), 1, true)),
+        }
+    }
+
+    /// Returns the substring between the code unit indices `start` and `end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is greater than `end`, or `end` is greater than the length of the string.
+    pub fn substring(&self, start: usize, end: usize) -> Self {
+        if start == 0 && end == self.len() {
+            return self.clone();
+        }
+        match self.0 {
+            Inner::Latin1(ref bytes) => Self(Inner::Latin1(Rc::from(&bytes[start..end]))),
+            Inner::Utf16(ref units) => Self::from_utf16(&units[start..end]),
+        }
+    }
+
+    /// Finds the first occurrence of `search` at a code unit index greater or equal than `from`.
+    pub fn index_of(&self, search: &Self, from: usize) -> Option<usize> {
+        let len = self.len();
+        let search_len = search.len();
+        if from > len || search_len > len - from {
+            return None;
+        }
+        (from..=len - search_len).find(|index| self.has_at(search, *index))
+    }
+
+    /// Finds the last occurrence of `search` at a code unit index lower or equal than `from`.
+    pub fn last_index_of(&self, search: &Self, from: usize) -> Option<usize> {
+        let len = self.len();
+        let search_len = search.len();
+        if search_len > len {
+            return None;
+        }
+        (0..=from.min(len - search_len))
+            .rev()
+            .find(|index| self.has_at(search, *index))
+    }
+
+    /// Returns `true` if `search` appears in the string at the given code unit index.
+    pub fn has_at(&self, search: &Self, index: usize) -> bool {
+        index <= self.len()
+            && search.len() <= self.len() - index
+            && self
+                .code_units()
+                .skip(index)
+                .take(search.len())
+                .eq(search.code_units())
+    }
+
+    /// Concatenates two strings.
+    pub fn concat(&self, other: &Self) -> Self {
+        if other.is_empty() {
+            return self.clone();
+        }
+        if self.is_empty() {
+            return other.clone();
+        }
+        match (&self.0, &other.0) {
+            (Inner::Latin1(ref first), Inner::Latin1(ref second)) => {
+                let mut bytes = Vec::with_capacity(first.len() + second.len());
+                bytes.extend_from_slice(first);
+                bytes.extend_from_slice(second);
+                Self(Inner::Latin1(bytes.into()))
+            }
+            _ => {
+                let units: Vec<u16> = self.code_units().chain(other.code_units()).collect();
+                Self(Inner::Utf16(units.into()))
+            }
+        }
+    }
+
+    /// Converts the string to a Rust `String`.
+    ///
+    /// Fails if the string contains unpaired surrogates, since they can't be represented in UTF-8.
+    pub fn to_std_string(&self) -> Result<String, FromUtf16Error> {
+        match self.0 {
+            Inner::Latin1(ref bytes) => Ok(bytes.iter().map(|byte| char::from(*byte)).collect()),
+            Inner::Utf16(ref units) => String::from_utf16(units),
+        }
+    }
+
+    /// Converts the string to a Rust `String`, replacing unpaired surrogates with `U+FFFD`.
+    pub fn to_std_string_lossy(&self) -> String {
+        match self.0 {
+            Inner::Latin1(ref bytes) => bytes.iter().map(|byte| char::from(*byte)).collect(),
+            Inner::Utf16(ref units) => String::from_utf16_lossy(units),
+        }
     }
 }
 
+/// Returns `true` if the code unit is a leading (high) surrogate.
+#[inline]
+pub(crate) fn is_leading_surrogate(code_unit: u16) -> bool {
+    (0xD800..=0xDBFF).contains(&code_unit)
+}
+
+/// Returns `true` if the code unit is a trailing (low) surrogate.
+#[inline]
+pub(crate) fn is_trailing_surrogate(code_unit: u16) -> bool {
+    (0xDC00..=0xDFFF).contains(&code_unit)
+}
+
 impl Default for RcString {
     #[inline]
     fn default() -> Self {
-        Self(Rc::from(String::new()))
+        Self(Inner::Latin1(Rc::from(&[][..])))
     }
 }
 
 impl Display for RcString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Display::fmt(&self.0, f)
+        for c in self.code_points() {
+            f.write_char(c.unwrap_or(REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for RcString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for c in self.code_points() {
+            match c {
+                Ok(c) => {
+                    for escaped in c.escape_debug() {
+                        f.write_char(escaped)?;
+                    }
+                }
+                Err(error) => write!(f, "\\u{{{:x}}}", error.unpaired_surrogate())?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl PartialEq for RcString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Inner::Latin1(ref first), Inner::Latin1(ref second)) => first == second,
+            (Inner::Utf16(ref first), Inner::Utf16(ref second)) => first == second,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for RcString {}
+
+impl Hash for RcString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The representation is canonical, so equal strings are hashed from the same data.
+        match self.0 {
+            Inner::Latin1(ref bytes) => bytes.hash(state),
+            Inner::Utf16(ref units) => units.hash(state),
+        }
+    }
+}
+
+impl PartialOrd for RcString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RcString {
+    /// Strings are ordered by their code units.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.code_units().cmp(other.code_units())
     }
 }
 
 impl PartialEq<str> for RcString {
     #[inline]
     fn eq(&self, other: &str) -> bool {
-        self.as_str() == other
+        self.code_units().eq(other.encode_utf16())
     }
 }
 
 impl PartialEq<RcString> for str {
     #[inline]
     fn eq(&self, other: &RcString) -> bool {
-        self == other.as_str()
+        other == self
     }
 }
 
 impl PartialEq<&str> for RcString {
     #[inline]
     fn eq(&self, other: &&str) -> bool {
-        self.as_str() == *other
+        self == *other
     }
 }
 
 impl PartialEq<RcString> for &str {
     #[inline]
     fn eq(&self, other: &RcString) -> bool {
-        *self == other.as_str()
+        other == *self
     }
 }
 
-impl Deref for RcString {
-    type Target = str;
+impl From<&str> for RcString {
+    fn from(string: &str) -> Self {
+        if string.chars().all(|c| (c as u32) < 0x100) {
+            Self(Inner::Latin1(string.chars().map(|c| c as u8).collect()))
+        } else {
+            Self(Inner::Utf16(string.encode_utf16().collect()))
+        }
+    }
+}
 
+impl From<String> for RcString {
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    fn from(string: String) -> Self {
+        Self::from(string.as_str())
     }
 }
 
-impl Borrow<str> for RcString {
+impl From<Box<str>> for RcString {
     #[inline]
-    fn borrow(&self) -> &str {
-        self.0.borrow()
+    fn from(string: Box<str>) -> Self {
+        Self::from(&*string)
     }
 }
 
-impl AsRef<str> for RcString {
+impl From<char> for RcString {
     #[inline]
-    fn as_ref(&self) -> &str {
-        self.as_str()
+    fn from(c: char) -> Self {
+        let mut buffer = [0; 2];
+        Self::from_utf16(c.encode_utf16(&mut buffer))
     }
 }
 
-impl From<String> for RcString {
+impl From<&[u16]> for RcString {
     #[inline]
-    fn from(string: String) -> Self {
-        Self(Rc::from(string))
+    fn from(code_units: &[u16]) -> Self {
+        Self::from_utf16(code_units)
     }
 }
 
-impl From<&RcString> for String {
+impl From<Vec<u16>> for RcString {
     #[inline]
-    fn from(string: &RcString) -> Self {
-        string.to_string()
+    fn from(code_units: Vec<u16>) -> Self {
+        Self::from_utf16(&code_units)
     }
 }
 
-impl From<Box<str>> for RcString {
+#[cfg(feature = "serde")]
+impl serde::Serialize for RcString {
+    /// Strings are serialized as Rust strings, unless they contain unpaired surrogates, in which
+    /// case they are serialized as a sequence of code units.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.to_std_string() {
+            Ok(string) => serializer.serialize_str(&string),
+            Err(_) => serializer.collect_seq(self.code_units()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RcString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct RcStringVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for RcStringVisitor {
+            type Value = RcString;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a string or a sequence of UTF-16 code units")
+            }
+
+            fn visit_str<E>(self, string: &str) -> Result<RcString, E> {
+                Ok(RcString::from(string))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<RcString, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut code_units = Vec::new();
+                while let Some(code_unit) = seq.next_element()? {
+                    code_units.push(code_unit);
+                }
+                Ok(RcString::from(code_units))
+            }
+        }
+
+        deserializer.deserialize_any(RcStringVisitor)
+    }
+}
+
+/// An iterator over the UTF-16 code units of an `RcString`.
+#[derive(Debug, Clone)]
+pub enum CodeUnits<'a> {
+    #[doc(hidden)]
+    Latin1(slice::Iter<'a, u8>),
+    #[doc(hidden)]
+    Utf16(slice::Iter<'a, u16>),
+}
+
+impl Iterator for CodeUnits<'_> {
+    type Item = u16;
+
     #[inline]
-    fn from(string: Box<str>) -> Self {
-        Self(Rc::from(string))
+    fn next(&mut self) -> Option<u16> {
+        match self {
+            Self::Latin1(ref mut iter) => iter.next().map(|byte| u16::from(*byte)),
+            Self::Utf16(ref mut iter) => iter.next().copied(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Latin1(ref iter) => iter.size_hint(),
+            Self::Utf16(ref iter) => iter.size_hint(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<u16> {
+        match self {
+            Self::Latin1(ref mut iter) => iter.nth(n).map(|byte| u16::from(*byte)),
+            Self::Utf16(ref mut iter) => iter.nth(n).copied(),
+        }
     }
 }
 
-impl From<&str> for RcString {
+impl DoubleEndedIterator for CodeUnits<'_> {
     #[inline]
-    fn from(string: &str) -> Self {
-        Self(Rc::from(string))
+    fn next_back(&mut self) -> Option<u16> {
+        match self {
+            Self::Latin1(ref mut iter) => iter.next_back().map(|byte| u16::from(*byte)),
+            Self::Utf16(ref mut iter) => iter.next_back().copied(),
+        }
     }
 }
+
+impl ExactSizeIterator for CodeUnits<'_> {}
+
+impl FusedIterator for CodeUnits<'_> {}
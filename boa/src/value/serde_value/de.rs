@@ -0,0 +1,320 @@
+//! Deserialization of Rust types from values.
+
+use super::SerdeError;
+use crate::{property::PropertyKey, value::RcString, Context, Result, Value};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use std::vec;
+
+/// Converts a `Value` into a Rust value.
+///
+/// Getters are called to read the properties of objects. Conversion errors are thrown as
+/// `TypeError`s.
+///
+/// # Examples
+///
+/// ```
+/// # use boa::{Context, value::from_value};
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Config {
+///     name: String,
+///     retries: u32,
+/// }
+///
+/// let mut context = Context::new();
+/// let value = context.eval("({ name: 'db', retries: 3 })").unwrap();
+/// let config: Config = from_value(&value, &mut context).unwrap();
+/// assert_eq!(config.retries, 3);
+/// ```
+pub fn from_value<T>(value: &Value, ctx: &mut Context) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let result = T::deserialize(ValueDeserializer::new(value.clone(), ctx));
+    result.map_err(|error| error.into_value(ctx))
+}
+
+/// A `serde::Deserializer` that reads a value of a `Context`.
+#[derive(Debug)]
+pub struct ValueDeserializer<'a> {
+    value: Value,
+    ctx: &'a mut Context,
+}
+
+impl<'a> ValueDeserializer<'a> {
+    /// Creates a new deserializer that reads `value`.
+    #[inline]
+    pub fn new(value: Value, ctx: &'a mut Context) -> Self {
+        Self { value, ctx }
+    }
+}
+
+/// The result of a deserialization.
+type SerdeResult<T> = std::result::Result<T, SerdeError>;
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> SerdeResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let Self { value, ctx } = self;
+        match value {
+            Value::Undefined | Value::Null => visitor.visit_unit(),
+            Value::Boolean(boolean) => visitor.visit_bool(boolean),
+            Value::Integer(integer) => visitor.visit_i32(integer),
+            Value::Rational(number) => {
+                // Integral numbers are often stored as rationals, but they should still be
+                // accepted by integer types.
+                if number.fract() == 0.0 && number >= i64::MIN as f64 && number < i64::MAX as f64 {
+                    visitor.visit_i64(number as i64)
+                } else {
+                    visitor.visit_f64(number)
+                }
+            }
+            Value::String(ref string) => match string.to_std_string() {
+                Ok(string) => visitor.visit_string(string),
+                Err(_) => Err(de::Error::custom(
+                    "cannot deserialize a string with unpaired surrogates",
+                )),
+            },
+            Value::BigInt(ref bigint) => {
+                if let Some(integer) = bigint.to_i128() {
+                    visitor.visit_i128(integer)
+                } else if let Some(integer) = bigint.to_u128() {
+                    visitor.visit_u128(integer)
+                } else {
+                    Err(de::Error::custom("BigInt is too large to be deserialized"))
+                }
+            }
+            Value::Symbol(_) => Err(de::Error::custom("cannot deserialize a symbol")),
+            Value::Object(ref object) => {
+                if object.borrow().is_function() {
+                    Err(de::Error::custom("cannot deserialize a function"))
+                } else if object.borrow().is_array() {
+                    let length = value.get_field("length", ctx)?.to_length(ctx)?;
+                    visitor.visit_seq(ArrayAccess {
+                        array: value.clone(),
+                        index: 0,
+                        length,
+                        ctx,
+                    })
+                } else {
+                    let keys = enumerable_own_keys(&value);
+                    visitor.visit_map(ObjectAccess {
+                        object: value.clone(),
+                        keys: keys.into_iter(),
+                        value: None,
+                        ctx,
+                    })
+                }
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> SerdeResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        if self.value.is_null_or_undefined() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> SerdeResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> SerdeResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let Self { value, ctx } = self;
+        match value {
+            Value::String(ref string) => visitor.visit_enum(string.to_string().into_deserializer()),
+            Value::Object(_) => {
+                let mut keys = enumerable_own_keys(&value);
+                if keys.len() != 1 {
+                    return Err(de::Error::custom(
+                        "expected an object with a single property for an enum variant",
+                    ));
+                }
+                let variant = keys.remove(0);
+                let value = value.get_field(variant.clone(), ctx)?;
+                visitor.visit_enum(VariantAccess {
+                    variant,
+                    value,
+                    ctx,
+                })
+            }
+            _ => Err(de::Error::custom(
+                "expected a string or an object for an enum variant",
+            )),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Returns the enumerable own string keys of an object.
+fn enumerable_own_keys(object: &Value) -> Vec<RcString> {
+    let object = match object {
+        Value::Object(ref object) => object.borrow(),
+        _ => return Vec::new(),
+    };
+    object
+        .own_property_keys()
+        .into_iter()
+        .filter(|key| {
+            object
+                .get_own_property(key)
+                .map_or(false, |desc| desc.enumerable())
+        })
+        .filter_map(|key| match key {
+            PropertyKey::String(ref string) => Some(string.clone()),
+            PropertyKey::Index(index) => Some(index.to_string().into()),
+            PropertyKey::Symbol(_) => None,
+        })
+        .collect()
+}
+
+/// Gives the elements of an array to a `Visitor`.
+struct ArrayAccess<'a> {
+    array: Value,
+    index: usize,
+    length: usize,
+    ctx: &'a mut Context,
+}
+
+impl<'de> SeqAccess<'de> for ArrayAccess<'_> {
+    type Error = SerdeError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> SerdeResult<Option<T::Value>>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.index >= self.length {
+            return Ok(None);
+        }
+        let element = self.array.get_field(self.index, self.ctx)?;
+        self.index += 1;
+        seed.deserialize(ValueDeserializer::new(element, self.ctx))
+            .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length - self.index)
+    }
+}
+
+/// Gives the enumerable own properties of an object to a `Visitor`.
+struct ObjectAccess<'a> {
+    object: Value,
+    keys: vec::IntoIter<RcString>,
+    value: Option<Value>,
+    ctx: &'a mut Context,
+}
+
+impl<'de> MapAccess<'de> for ObjectAccess<'_> {
+    type Error = SerdeError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> SerdeResult<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.keys.next() {
+            Some(key) => {
+                self.value = Some(self.object.get_field(key.clone(), self.ctx)?);
+                seed.deserialize(key.to_string().into_deserializer())
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> SerdeResult<V::Value>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let value = self
+            .value
+            .take()
+            .expect("next_value_seed should be called after next_key_seed");
+        seed.deserialize(ValueDeserializer::new(value, self.ctx))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+/// Gives an enum variant, stored as an object with a single property, to a `Visitor`.
+struct VariantAccess<'a> {
+    variant: RcString,
+    value: Value,
+    ctx: &'a mut Context,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for VariantAccess<'a> {
+    type Error = SerdeError;
+    type Variant = ValueDeserializer<'a>;
+
+    fn variant_seed<V>(self, seed: V) -> SerdeResult<(V::Value, Self::Variant)>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant: de::value::StringDeserializer<SerdeError> =
+            self.variant.to_string().into_deserializer();
+        let variant = seed.deserialize(variant)?;
+        Ok((variant, ValueDeserializer::new(self.value, self.ctx)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for ValueDeserializer<'_> {
+    type Error = SerdeError;
+
+    fn unit_variant(self) -> SerdeResult<()> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> SerdeResult<T::Value>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> SerdeResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> SerdeResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
@@ -0,0 +1,76 @@
+//! Conversions between `Value` and Rust types implementing `serde`'s `Serialize` and
+//! `Deserialize` traits.
+//!
+//! This is done directly between the Rust types and the values, without going through JSON text:
+//!  - structs and maps are converted from and to objects, and sequences and tuples from and to
+//!    arrays,
+//!  - integers that do not fit in 64 bits (`i128` and `u128`) are converted to `BigInt`s, other
+//!    numbers to `Number`s,
+//!  - `None` and the unit type are converted to `null`, and both `null` and `undefined` are
+//!    converted back,
+//!  - enum variants are externally tagged: unit variants are strings, other variants are objects
+//!    with a single property named like the variant.
+//!
+//! The conversions are enabled with the `serde` feature.
+
+pub mod de;
+pub mod ser;
+
+#[cfg(test)]
+mod tests;
+
+pub use de::{from_value, ValueDeserializer};
+pub use ser::{to_value, ValueSerializer};
+
+use crate::{Context, Value};
+use std::fmt::{self, Display};
+
+/// The error of a conversion between a `Value` and a Rust type.
+#[derive(Debug, Clone)]
+pub enum SerdeError {
+    /// A value thrown by JavaScript code, like a getter, during the conversion.
+    Thrown(Value),
+    /// The value did not match the Rust type, or the Rust value could not be converted.
+    Message(String),
+}
+
+impl SerdeError {
+    /// Converts the error to a value that can be thrown.
+    ///
+    /// Conversion errors become `TypeError`s.
+    pub fn into_value(self, ctx: &mut Context) -> Value {
+        match self {
+            Self::Thrown(value) => value,
+            Self::Message(message) => ctx.construct_type_error(message),
+        }
+    }
+}
+
+impl Display for SerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Thrown(value) => write!(f, "uncaught exception: {}", value.display()),
+            Self::Message(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for SerdeError {}
+
+impl serde::ser::Error for SerdeError {
+    fn custom<T: Display>(message: T) -> Self {
+        Self::Message(message.to_string())
+    }
+}
+
+impl serde::de::Error for SerdeError {
+    fn custom<T: Display>(message: T) -> Self {
+        Self::Message(message.to_string())
+    }
+}
+
+impl From<Value> for SerdeError {
+    fn from(value: Value) -> Self {
+        Self::Thrown(value)
+    }
+}
@@ -0,0 +1,424 @@
+//! Serialization of Rust types into values.
+
+use super::SerdeError;
+use crate::{
+    builtins::{Array, BigInt},
+    object::GcObject,
+    property::{Attribute, PropertyKey},
+    Context, Result, Value,
+};
+use serde::ser::{self, Serialize};
+use std::convert::TryFrom;
+
+/// Converts a Rust value into a `Value`.
+///
+/// Conversion errors are thrown as `TypeError`s.
+///
+/// # Examples
+///
+/// ```
+/// # use boa::{Context, value::to_value};
+/// # use serde::Serialize;
+/// #[derive(Serialize)]
+/// struct Config {
+///     name: String,
+///     retries: u32,
+/// }
+///
+/// let mut context = Context::new();
+/// let config = Config { name: "db".to_string(), retries: 3 };
+/// let value = to_value(&config, &mut context).unwrap();
+/// assert!(value.is_object());
+/// ```
+pub fn to_value<T>(value: &T, ctx: &mut Context) -> Result<Value>
+where
+    T: Serialize + ?Sized,
+{
+    let result = value.serialize(ValueSerializer::new(ctx));
+    result.map_err(|error| error.into_value(ctx))
+}
+
+/// A `serde::Serializer` that creates values in a `Context`.
+#[derive(Debug)]
+pub struct ValueSerializer<'a> {
+    ctx: &'a mut Context,
+}
+
+impl<'a> ValueSerializer<'a> {
+    /// Creates a new serializer that creates values in `ctx`.
+    #[inline]
+    pub fn new(ctx: &'a mut Context) -> Self {
+        Self { ctx }
+    }
+}
+
+/// The result of a serialization.
+type SerdeResult<T> = std::result::Result<T, SerdeError>;
+
+/// Creates an object with a single property named like the variant, holding `value`.
+fn wrap_variant(variant: &'static str, value: Value, ctx: &Context) -> Value {
+    let object = ctx.construct_object();
+    object
+        .borrow_mut()
+        .insert_property(variant, value, Attribute::all());
+    object.into()
+}
+
+impl<'a> ser::Serializer for ValueSerializer<'a> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    type SerializeSeq = SerializeArray<'a>;
+    type SerializeTuple = SerializeArray<'a>;
+    type SerializeTupleStruct = SerializeArray<'a>;
+    type SerializeTupleVariant = SerializeTupleVariant<'a>;
+    type SerializeMap = SerializeObject<'a>;
+    type SerializeStruct = SerializeObject<'a>;
+    type SerializeStructVariant = SerializeStructVariant<'a>;
+
+    fn serialize_bool(self, v: bool) -> SerdeResult<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> SerdeResult<Value> {
+        Ok(Value::from(i32::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> SerdeResult<Value> {
+        Ok(Value::from(i32::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> SerdeResult<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> SerdeResult<Value> {
+        Ok(i32::try_from(v)
+            .map(Value::from)
+            .unwrap_or_else(|_| Value::from(v as f64)))
+    }
+
+    fn serialize_i128(self, v: i128) -> SerdeResult<Value> {
+        Ok(Value::from(BigInt::from(v)))
+    }
+
+    fn serialize_u8(self, v: u8) -> SerdeResult<Value> {
+        Ok(Value::from(i32::from(v)))
+    }
+
+    fn serialize_u16(self, v: u16) -> SerdeResult<Value> {
+        Ok(Value::from(i32::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> SerdeResult<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> SerdeResult<Value> {
+        Ok(i32::try_from(v)
+            .map(Value::from)
+            .unwrap_or_else(|_| Value::from(v as f64)))
+    }
+
+    fn serialize_u128(self, v: u128) -> SerdeResult<Value> {
+        Ok(Value::from(BigInt::from(v)))
+    }
+
+    fn serialize_f32(self, v: f32) -> SerdeResult<Value> {
+        Ok(Value::from(f64::from(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> SerdeResult<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_char(self, v: char) -> SerdeResult<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_str(self, v: &str) -> SerdeResult<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> SerdeResult<Value> {
+        let elements = v.iter().map(|&byte| Value::from(i32::from(byte)));
+        Ok(Array::create_array_from_list(elements, self.ctx))
+    }
+
+    fn serialize_none(self) -> SerdeResult<Value> {
+        Ok(Value::null())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> SerdeResult<Value>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> SerdeResult<Value> {
+        Ok(Value::null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> SerdeResult<Value> {
+        Ok(Value::null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> SerdeResult<Value> {
+        Ok(Value::from(variant))
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> SerdeResult<Value>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> SerdeResult<Value>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(ValueSerializer::new(&mut *self.ctx))?;
+        Ok(wrap_variant(variant, value, self.ctx))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> SerdeResult<Self::SerializeSeq> {
+        Ok(SerializeArray {
+            ctx: self.ctx,
+            elements: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> SerdeResult<Self::SerializeTuple> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> SerdeResult<Self::SerializeTupleStruct> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> SerdeResult<Self::SerializeTupleVariant> {
+        Ok(SerializeTupleVariant {
+            variant,
+            array: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> SerdeResult<Self::SerializeMap> {
+        let object = self.ctx.construct_object();
+        Ok(SerializeObject {
+            ctx: self.ctx,
+            object,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> SerdeResult<Self::SerializeStruct> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> SerdeResult<Self::SerializeStructVariant> {
+        Ok(SerializeStructVariant {
+            variant,
+            object: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+/// Serializes sequences and tuples into arrays.
+#[derive(Debug)]
+pub struct SerializeArray<'a> {
+    ctx: &'a mut Context,
+    elements: Vec<Value>,
+}
+
+impl ser::SerializeSeq for SerializeArray<'_> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> SerdeResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(ValueSerializer::new(&mut *self.ctx))?;
+        self.elements.push(value);
+        Ok(())
+    }
+
+    fn end(self) -> SerdeResult<Value> {
+        Ok(Array::create_array_from_list(self.elements, self.ctx))
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray<'_> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> SerdeResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> SerdeResult<Value> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray<'_> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> SerdeResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> SerdeResult<Value> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// Serializes tuple variants into objects holding an array.
+#[derive(Debug)]
+pub struct SerializeTupleVariant<'a> {
+    variant: &'static str,
+    array: SerializeArray<'a>,
+}
+
+impl ser::SerializeTupleVariant for SerializeTupleVariant<'_> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> SerdeResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeSeq::serialize_element(&mut self.array, value)
+    }
+
+    fn end(self) -> SerdeResult<Value> {
+        let array = Array::create_array_from_list(self.array.elements, self.array.ctx);
+        Ok(wrap_variant(self.variant, array, self.array.ctx))
+    }
+}
+
+/// Serializes maps and structs into objects.
+#[derive(Debug)]
+pub struct SerializeObject<'a> {
+    ctx: &'a mut Context,
+    object: GcObject,
+    key: Option<PropertyKey>,
+}
+
+impl ser::SerializeMap for SerializeObject<'_> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> SerdeResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = key.serialize(ValueSerializer::new(&mut *self.ctx))?;
+        self.key = Some(key.to_property_key(self.ctx)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> SerdeResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_value should be called after serialize_key");
+        let value = value.serialize(ValueSerializer::new(&mut *self.ctx))?;
+        self.object
+            .borrow_mut()
+            .insert_property(key, value, Attribute::all());
+        Ok(())
+    }
+
+    fn end(self) -> SerdeResult<Value> {
+        Ok(self.object.into())
+    }
+}
+
+impl ser::SerializeStruct for SerializeObject<'_> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> SerdeResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(ValueSerializer::new(&mut *self.ctx))?;
+        self.object
+            .borrow_mut()
+            .insert_property(key, value, Attribute::all());
+        Ok(())
+    }
+
+    fn end(self) -> SerdeResult<Value> {
+        Ok(self.object.into())
+    }
+}
+
+/// Serializes struct variants into objects holding an object.
+#[derive(Debug)]
+pub struct SerializeStructVariant<'a> {
+    variant: &'static str,
+    object: SerializeObject<'a>,
+}
+
+impl ser::SerializeStructVariant for SerializeStructVariant<'_> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> SerdeResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeStruct::serialize_field(&mut self.object, key, value)
+    }
+
+    fn end(self) -> SerdeResult<Value> {
+        let object = self.object.object.into();
+        Ok(wrap_variant(self.variant, object, self.object.ctx))
+    }
+}
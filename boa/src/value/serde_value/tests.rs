@@ -0,0 +1,134 @@
+use crate::{
+    forward, forward_val,
+    value::{from_value, to_value},
+    Context,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Point,
+    Circle(f64),
+    Rectangle { width: u32, height: u32 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Scene {
+    name: String,
+    tags: Vec<String>,
+    parent: Option<Box<Scene>>,
+    shapes: Vec<Shape>,
+    counts: BTreeMap<String, i64>,
+}
+
+#[test]
+fn round_trip() {
+    let mut context = Context::new();
+    let mut counts = BTreeMap::new();
+    counts.insert("points".to_string(), 1);
+    counts.insert("large".to_string(), 5_000_000_000);
+    let scene = Scene {
+        name: "main".to_string(),
+        tags: vec!["a".to_string(), "b".to_string()],
+        parent: Some(Box::new(Scene {
+            name: "root".to_string(),
+            tags: Vec::new(),
+            parent: None,
+            shapes: Vec::new(),
+            counts: BTreeMap::new(),
+        })),
+        shapes: vec![
+            Shape::Point,
+            Shape::Circle(1.5),
+            Shape::Rectangle {
+                width: 2,
+                height: 3,
+            },
+        ],
+        counts,
+    };
+
+    let value = to_value(&scene, &mut context).unwrap();
+    let global = context.global_object().clone();
+    global
+        .set_field("scene", value.clone(), &mut context)
+        .unwrap();
+
+    assert_eq!(forward(&mut context, "scene.tags.length"), "2");
+    assert_eq!(forward(&mut context, "scene.parent.parent"), "null");
+    assert_eq!(forward(&mut context, "scene.shapes[0]"), "\"Point\"");
+    assert_eq!(forward(&mut context, "scene.shapes[1].Circle"), "1.5");
+    assert_eq!(
+        forward(&mut context, "scene.shapes[2].Rectangle.height"),
+        "3"
+    );
+    assert_eq!(forward(&mut context, "scene.counts.large"), "5000000000");
+
+    let result: Scene = from_value(&value, &mut context).unwrap();
+    assert_eq!(result, scene);
+}
+
+#[test]
+fn big_integers() {
+    let mut context = Context::new();
+
+    let value = to_value(&i128::MAX, &mut context).unwrap();
+    assert!(value.is_bigint());
+    assert_eq!(from_value::<i128>(&value, &mut context).unwrap(), i128::MAX);
+
+    let value = forward_val(&mut context, "18446744073709551616n").unwrap();
+    assert_eq!(
+        from_value::<u128>(&value, &mut context).unwrap(),
+        u128::from(u64::MAX) + 1
+    );
+}
+
+#[test]
+fn from_script_object() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Point {
+        x: i32,
+        y: f64,
+        label: Option<String>,
+    }
+
+    let mut context = Context::new();
+    let value = forward_val(
+        &mut context,
+        r#"({ x: 1, get y() { return this.x / 2; }, hidden: true })"#,
+    )
+    .unwrap();
+    let point: Point = from_value(&value, &mut context).unwrap();
+    assert_eq!(
+        point,
+        Point {
+            x: 1,
+            y: 0.5,
+            label: None,
+        }
+    );
+
+    let value = forward_val(&mut context, "[1, 2, 3]").unwrap();
+    let numbers: (u8, u8, u8) = from_value(&value, &mut context).unwrap();
+    assert_eq!(numbers, (1, 2, 3));
+}
+
+#[test]
+fn type_mismatch() {
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        #[allow(dead_code)]
+        retries: u32,
+    }
+
+    let mut context = Context::new();
+    let value = forward_val(&mut context, "({ retries: 'many' })").unwrap();
+    let error = from_value::<Config>(&value, &mut context).unwrap_err();
+    let global = context.global_object().clone();
+    global.set_field("error", error, &mut context).unwrap();
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+
+    let value = forward_val(&mut context, "Symbol()").unwrap();
+    assert!(from_value::<String>(&value, &mut context).is_err());
+}
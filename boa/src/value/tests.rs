@@ -29,11 +29,18 @@ fn undefined() {
 
 #[test]
 fn get_set_field() {
+    let mut context = Context::new();
     let obj = Value::new_object(None);
     // Create string and convert it to a Value
     let s = Value::from("bar");
-    obj.set_field("foo", s);
-    assert_eq!(obj.get_field("foo").display().to_string(), "\"bar\"");
+    obj.set_field("foo", s, &mut context).unwrap();
+    assert_eq!(
+        obj.get_field("foo", &mut context)
+            .unwrap()
+            .display()
+            .to_string(),
+        "\"bar\""
+    );
 }
 
 #[test]
@@ -1218,3 +1225,149 @@ mod abstract_relational_comparison {
         check_comparison!(engine, "'InvalidBigInt' >= -100n" => false);
     }
 }
+
+/// Test the typed conversions between Rust types and values.
+mod typed_conversions {
+    use super::*;
+
+    #[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+    struct Person {
+        name: String,
+        #[boa(rename = "yearsOld")]
+        age: u32,
+        nickname: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+    struct Point(i32, i32);
+
+    #[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+    enum Color {
+        Red,
+        #[boa(rename = "green")]
+        Green,
+    }
+
+    #[test]
+    fn primitives() {
+        let mut engine = Context::new();
+        assert_eq!(
+            f64::try_from_js(&Value::from(1.5), &mut engine).unwrap(),
+            1.5
+        );
+        assert_eq!(
+            u8::try_from_js(&Value::from(255), &mut engine).unwrap(),
+            255
+        );
+        assert!(u8::try_from_js(&Value::from(256), &mut engine).is_err());
+        assert!(i32::try_from_js(&Value::from(1.5), &mut engine).is_err());
+        assert!(i32::try_from_js(&Value::from("1"), &mut engine).is_err());
+        assert!(bool::try_from_js(&Value::from(1), &mut engine).is_err());
+        assert_eq!(
+            Option::<String>::try_from_js(&Value::null(), &mut engine).unwrap(),
+            None
+        );
+
+        let array = forward_val(&mut engine, "[1, 2, 3]").unwrap();
+        assert_eq!(
+            Vec::<i32>::try_from_js(&array, &mut engine).unwrap(),
+            vec![1, 2, 3]
+        );
+        let array = vec!["a", "b"].into_js(&mut engine);
+        assert_eq!(
+            Vec::<String>::try_from_js(&array, &mut engine).unwrap(),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn derived_struct() {
+        let mut engine = Context::new();
+        let value = forward_val(&mut engine, "({ name: 'John', yearsOld: 19 })").unwrap();
+        let person = Person::try_from_js(&value, &mut engine).unwrap();
+        assert_eq!(
+            person,
+            Person {
+                name: "John".to_string(),
+                age: 19,
+                nickname: None,
+            }
+        );
+
+        let value = person.into_js(&mut engine);
+        assert_eq!(
+            value.get_field("yearsOld", &mut engine).unwrap(),
+            Value::from(19)
+        );
+        assert!(Person::try_from_js(&Value::from("John"), &mut engine).is_err());
+        let value = forward_val(&mut engine, "({ name: 'John', yearsOld: '19' })").unwrap();
+        assert!(Person::try_from_js(&value, &mut engine).is_err());
+    }
+
+    #[test]
+    fn derived_tuple_struct_and_enum() {
+        let mut engine = Context::new();
+        let value = Point(1, 2).into_js(&mut engine);
+        let global = engine.global_object().clone();
+        global.set_field("point", value, &mut engine).unwrap();
+        assert_eq!(forward(&mut engine, "Array.isArray(point)"), "true");
+        let value = forward_val(&mut engine, "[3, 4]").unwrap();
+        assert_eq!(
+            Point::try_from_js(&value, &mut engine).unwrap(),
+            Point(3, 4)
+        );
+
+        assert_eq!(
+            Color::try_from_js(&Value::from("green"), &mut engine).unwrap(),
+            Color::Green
+        );
+        assert!(Color::try_from_js(&Value::from("Blue"), &mut engine).is_err());
+        assert_eq!(Color::Red.into_js(&mut engine), Value::from("Red"));
+    }
+
+    #[test]
+    fn integer_bounds() {
+        let mut engine = Context::new();
+        let two_pow_63 = 9_223_372_036_854_775_808.0;
+        let two_pow_64 = 18_446_744_073_709_551_616.0;
+
+        assert_eq!(
+            i64::try_from_js(&Value::from(-two_pow_63), &mut engine).unwrap(),
+            i64::MIN
+        );
+        assert!(i64::try_from_js(&Value::from(two_pow_63), &mut engine).is_err());
+        assert!(u64::try_from_js(&Value::from(two_pow_64), &mut engine).is_err());
+        assert_eq!(
+            u32::try_from_js(&Value::from(4_294_967_295.0), &mut engine).unwrap(),
+            u32::MAX
+        );
+        assert!(u32::try_from_js(&Value::from(4_294_967_296.0), &mut engine).is_err());
+        assert!(i8::try_from_js(&Value::from(-129), &mut engine).is_err());
+    }
+
+    #[test]
+    fn typed_function_arguments() {
+        let mut engine = Context::new();
+        engine
+            .register_global_function(
+                "greet",
+                1,
+                typed_function(|_, (person,): (Person,), _| {
+                    Ok(format!("Hello {}, {}!", person.name, person.age))
+                }),
+            )
+            .unwrap();
+
+        assert_eq!(
+            forward(&mut engine, "greet({ name: 'Jane', yearsOld: 30 })"),
+            "\"Hello Jane, 30!\""
+        );
+        assert_eq!(
+            forward(
+                &mut engine,
+                "try { greet(1) } catch (e) { e instanceof TypeError }"
+            ),
+            "true"
+        );
+    }
+}
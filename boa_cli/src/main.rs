@@ -25,7 +25,7 @@
     clippy::as_conversions
 )]
 
-use boa::{syntax::ast::node::StatementList, Context};
+use boa::{syntax::ast::node::StatementList, Context, Value};
 use colored::*;
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
 use std::{fs::read_to_string, path::PathBuf};
@@ -136,6 +136,21 @@ fn dump(src: &str, args: &Opt) -> Result<(), String> {
     Ok(())
 }
 
+/// Formats the call frames recorded by an uncaught error as a backtrace, one frame per line.
+fn backtrace(error: &Value) -> String {
+    let mut backtrace = String::new();
+    if let Some(frames) = error
+        .as_object()
+        .as_ref()
+        .and_then(|object| object.as_error())
+    {
+        for frame in frames {
+            backtrace.push_str(&format!("\n    at {}", frame));
+        }
+    }
+    backtrace
+}
+
 pub fn main() -> Result<(), std::io::Error> {
     let args = Opt::from_args();
 
@@ -149,9 +164,12 @@ pub fn main() -> Result<(), std::io::Error> {
                 eprintln!("{}", e);
             }
         } else {
-            match engine.eval(&buffer) {
+            match engine.eval_script(&buffer, &file.to_string_lossy()) {
                 Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {}", v.display()),
+                Err(v) => eprintln!("Uncaught {}{}", v.display(), backtrace(&v)),
+            }
+            if let Err(v) = engine.run_jobs() {
+                eprintln!("Uncaught {}{}", v.display(), backtrace(&v));
             }
         }
     }
@@ -187,9 +205,20 @@ pub fn main() -> Result<(), std::io::Error> {
                     } else {
                         match engine.eval(line.trim_end()) {
                             Ok(v) => println!("{}", v.display()),
-                            Err(v) => {
-                                eprintln!("{}: {}", "Uncaught".red(), v.display().to_string().red())
-                            }
+                            Err(v) => eprintln!(
+                                "{}: {}{}",
+                                "Uncaught".red(),
+                                v.display().to_string().red(),
+                                backtrace(&v).red()
+                            ),
+                        }
+                        if let Err(v) = engine.run_jobs() {
+                            eprintln!(
+                                "{}: {}{}",
+                                "Uncaught".red(),
+                                v.display().to_string().red(),
+                                backtrace(&v).red()
+                            )
                         }
                     }
                 }
@@ -0,0 +1,285 @@
+//! Derive macros for the `TryFromJs` and `IntoJs` conversion traits of Boa.
+//!
+//! The macros are re-exported by the `boa` crate, next to the traits, in the `value` module.
+//!
+//! - Structs with named fields are converted from and to objects, with a property per field.
+//! - Tuple structs with a single field are converted like the field itself, and other tuple
+//!   structs are converted from and to arrays.
+//! - Enums with unit variants only are converted from and to strings holding the variant name.
+//!
+//! The property name of a field, or the string of a variant, can be changed with the
+//! `#[boa(rename = "name")]` attribute.
+
+#![deny(
+    unused_qualifications,
+    clippy::all,
+    unused_import_braces,
+    unused_lifetimes,
+    unreachable_pub,
+    trivial_numeric_casts,
+    missing_debug_implementations,
+    missing_copy_implementations,
+    deprecated_in_future,
+    meta_variable_misuse,
+    non_ascii_idents,
+    rust_2018_compatibility,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DataEnum, DeriveInput, Error, Fields,
+    Generics, Ident, Lit, Meta, NestedMeta, Path,
+};
+
+/// Derives the `TryFromJs` trait.
+#[proc_macro_derive(TryFromJs, attributes(boa))]
+pub fn derive_try_from_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    try_from_js(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives the `IntoJs` trait.
+#[proc_macro_derive(IntoJs, attributes(boa))]
+pub fn derive_into_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_js(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn try_from_js(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let body = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let fields = fields
+                    .named
+                    .iter()
+                    .map(|field| {
+                        let ident = field.ident.as_ref().expect("named field");
+                        let key = property_name(&field.attrs, ident)?;
+                        Ok(quote! {
+                            #ident: ::boa::value::TryFromJs::try_from_js(
+                                &value.get_field(#key, ctx)?,
+                                ctx,
+                            )?
+                        })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+                let message = format!("cannot convert value to `{}`: expected an object", name);
+                quote! {
+                    if !value.is_object() {
+                        return Err(ctx.construct_type_error(#message));
+                    }
+                    Ok(Self { #(#fields),* })
+                }
+            }
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => quote! {
+                Ok(Self(::boa::value::TryFromJs::try_from_js(value, ctx)?))
+            },
+            Fields::Unnamed(ref fields) => {
+                let fields = (0..fields.unnamed.len() as u32).map(|index| {
+                    quote! {
+                        ::boa::value::TryFromJs::try_from_js(&value.get_field(#index, ctx)?, ctx)?
+                    }
+                });
+                let message = format!("cannot convert value to `{}`: expected an array", name);
+                quote! {
+                    if !value.is_object() {
+                        return Err(ctx.construct_type_error(#message));
+                    }
+                    Ok(Self(#(#fields),*))
+                }
+            }
+            Fields::Unit => quote!(Ok(Self)),
+        },
+        Data::Enum(ref data) => {
+            let variants = variant_names(data, "TryFromJs")?;
+            let arms = variants
+                .iter()
+                .map(|(ident, string)| quote!(Ok(#string) => Ok(Self::#ident)));
+            let message = format!("cannot convert value to `{}`: expected a string", name);
+            let unknown = format!(
+                "cannot convert value to `{}`: unknown variant \"{{}}\"",
+                name
+            );
+            quote! {
+                match value.as_string() {
+                    Some(string) => match string.to_std_string().as_deref() {
+                        #(#arms,)*
+                        _ => Err(ctx.construct_type_error(format!(#unknown, string))),
+                    },
+                    None => Err(ctx.construct_type_error(#message)),
+                }
+            }
+        }
+        Data::Union(ref data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "`TryFromJs` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let generics = add_bounds(&input.generics, parse_quote!(::boa::value::TryFromJs));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::boa::value::TryFromJs for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn try_from_js(
+                value: &::boa::Value,
+                ctx: &mut ::boa::Context,
+            ) -> ::boa::Result<Self> {
+                #body
+            }
+        }
+    })
+}
+
+fn into_js(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let body = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let idents: Vec<_> = fields
+                    .named
+                    .iter()
+                    .map(|field| field.ident.as_ref().expect("named field"))
+                    .collect();
+                let properties = fields
+                    .named
+                    .iter()
+                    .zip(&idents)
+                    .map(|(field, ident)| {
+                        let key = property_name(&field.attrs, ident)?;
+                        Ok(quote! {
+                            let value = ::boa::value::IntoJs::into_js(#ident, ctx);
+                            object.borrow_mut().define_own_property(
+                                #key,
+                                ::boa::property::DataDescriptor::new(
+                                    value,
+                                    ::boa::property::Attribute::all(),
+                                )
+                                .into(),
+                            );
+                        })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+                quote! {
+                    let Self { #(#idents),* } = self;
+                    let object = ctx.construct_object();
+                    #(#properties)*
+                    ::boa::Value::from(object)
+                }
+            }
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => quote! {
+                ::boa::value::IntoJs::into_js(self.0, ctx)
+            },
+            Fields::Unnamed(ref fields) => {
+                let bindings: Vec<_> = (0..fields.unnamed.len())
+                    .map(|index| format_ident!("field{}", index))
+                    .collect();
+                quote! {
+                    let Self(#(#bindings),*) = self;
+                    let elements = vec![#(::boa::value::IntoJs::into_js(#bindings, ctx)),*];
+                    ::boa::value::IntoJs::into_js(elements, ctx)
+                }
+            }
+            Fields::Unit => quote!(::boa::Value::undefined()),
+        },
+        Data::Enum(ref data) => {
+            let variants = variant_names(data, "IntoJs")?;
+            let arms = variants
+                .iter()
+                .map(|(ident, string)| quote!(Self::#ident => ::boa::Value::from(#string)));
+            quote! {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+        Data::Union(ref data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "`IntoJs` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let generics = add_bounds(&input.generics, parse_quote!(::boa::value::IntoJs));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::boa::value::IntoJs for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn into_js(self, ctx: &mut ::boa::Context) -> ::boa::Value {
+                #body
+            }
+        }
+    })
+}
+
+/// Adds the `bound` trait bound to every type parameter of `generics`.
+fn add_bounds(generics: &Generics, bound: Path) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+/// Returns the identifier and the string of every variant of a unit-only enum.
+fn variant_names(data: &DataEnum, derive: &str) -> syn::Result<Vec<(Ident, String)>> {
+    data.variants
+        .iter()
+        .map(|variant| {
+            if let Fields::Unit = variant.fields {
+                let name = property_name(&variant.attrs, &variant.ident)?;
+                Ok((variant.ident.clone(), name))
+            } else {
+                Err(Error::new_spanned(
+                    variant,
+                    format!(
+                        "`{}` can only be derived for enums with unit variants",
+                        derive
+                    ),
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Returns the JavaScript name of a field or variant, taking the `#[boa(rename = "..")]`
+/// attribute into account.
+fn property_name(attrs: &[Attribute], ident: &Ident) -> syn::Result<String> {
+    let mut name = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("boa")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(Error::new_spanned(meta, "expected `boa(rename = \"..\")`")),
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(ref pair)) if pair.path.is_ident("rename") => {
+                    if let Lit::Str(ref string) = pair.lit {
+                        name = Some(string.value());
+                    } else {
+                        return Err(Error::new_spanned(&pair.lit, "expected a string"));
+                    }
+                }
+                nested => return Err(Error::new_spanned(nested, "unknown `boa` attribute")),
+            }
+        }
+    }
+
+    Ok(name.unwrap_or_else(|| {
+        let ident = ident.to_string();
+        ident.trim_start_matches("r#").to_owned()
+    }))
+}
@@ -20,6 +20,7 @@ pub fn evaluate(src: &str) -> Result<String, JsValue> {
         }
     };
     expr.run(&mut engine)
+        .and_then(|v| engine.run_jobs().map(|_| v))
         .map_err(|e| JsValue::from(format!("Uncaught {}", e.display())))
         .map(|v| v.display().to_string())
 }